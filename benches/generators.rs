@@ -43,7 +43,9 @@ fn bench_email_generation(c: &mut Criterion) {
                 let mut faker = Faker::new("en_US").unwrap();
                 faker.seed(42);
                 b.iter(|| {
-                    let emails = faker.emails(black_box(size), false).unwrap();
+                    let emails = faker
+                        .emails(black_box(size), false, None, None, false, false)
+                        .unwrap();
                     black_box(emails)
                 });
             },
@@ -106,7 +108,7 @@ fn bench_single_value_generation(c: &mut Criterion) {
     group.bench_function("email", |b| {
         let mut faker = Faker::new("en_US").unwrap();
         faker.seed(42);
-        b.iter(|| black_box(faker.email()));
+        b.iter(|| black_box(faker.email(None, None, false, false)));
     });
 
     group.bench_function("uuid", |b| {
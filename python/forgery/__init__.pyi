@@ -1,6 +1,7 @@
 """Type stubs for the forgery package."""
 
 from collections.abc import Coroutine
+from decimal import Decimal
 from typing import Any
 
 from forgery._forgery import Faker as Faker
@@ -86,28 +87,198 @@ def last_names(n: int) -> list[str]:
     """
     ...
 
-def email() -> str:
+def name_romanized() -> str:
+    """Generate a single romanized full name (falls back to regular names)."""
+    ...
+
+def names_romanized(n: int) -> list[str]:
+    """Generate a batch of romanized full names.
+
+    Args:
+        n: Number of names to generate.
+
+    Returns:
+        A list of romanized full names.
+
+    Raises:
+        ValueError: If n exceeds the maximum batch size (10 million).
+    """
+    ...
+
+def first_name_romanized() -> str:
+    """Generate a single romanized first name (falls back to regular names)."""
+    ...
+
+def first_names_romanized(n: int) -> list[str]:
+    """Generate a batch of romanized first names.
+
+    Args:
+        n: Number of names to generate.
+
+    Returns:
+        A list of romanized first names.
+
+    Raises:
+        ValueError: If n exceeds the maximum batch size (10 million).
+    """
+    ...
+
+def last_name_romanized() -> str:
+    """Generate a single romanized last name (falls back to regular names)."""
+    ...
+
+def last_names_romanized(n: int) -> list[str]:
+    """Generate a batch of romanized last names.
+
+    Args:
+        n: Number of names to generate.
+
+    Returns:
+        A list of romanized last names.
+
+    Raises:
+        ValueError: If n exceeds the maximum batch size (10 million).
+    """
+    ...
+
+def prefix() -> str:
+    """Generate a single random name prefix (empty for locales without prefixes)."""
+    ...
+
+def prefixes(n: int) -> list[str]:
+    """Generate a batch of random name prefixes.
+
+    Args:
+        n: Number of prefixes to generate.
+
+    Returns:
+        A list of prefixes.
+
+    Raises:
+        ValueError: If n exceeds the maximum batch size (10 million).
+    """
+    ...
+
+def suffix() -> str:
+    """Generate a single random name suffix (empty for locales without suffixes)."""
+    ...
+
+def suffixes(n: int) -> list[str]:
+    """Generate a batch of random name suffixes.
+
+    Args:
+        n: Number of suffixes to generate.
+
+    Returns:
+        A list of suffixes.
+
+    Raises:
+        ValueError: If n exceeds the maximum batch size (10 million).
+    """
+    ...
+
+def name_with_prefix() -> str:
+    """Generate a single full name with a prefix (e.g., "Dr. Jane Smith")."""
+    ...
+
+def names_with_prefix(n: int) -> list[str]:
+    """Generate a batch of full names with a prefix.
+
+    Args:
+        n: Number of names to generate.
+
+    Returns:
+        A list of names.
+
+    Raises:
+        ValueError: If n exceeds the maximum batch size (10 million).
+    """
+    ...
+
+def full_name(full_name_format: str | None = None) -> str:
+    """Generate a single full name from a format template.
+
+    Args:
+        full_name_format: Template using {prefix}, {first}, {middle},
+            {middle_initial}, {last} and {suffix}. Defaults to the locale's
+            name order.
+
+    Raises:
+        ValueError: If the template contains an unknown placeholder.
+    """
+    ...
+
+def full_names(n: int, full_name_format: str | None = None) -> list[str]:
+    """Generate a batch of full names from a format template.
+
+    Args:
+        n: Number of names to generate.
+        full_name_format: Template using {prefix}, {first}, {middle},
+            {middle_initial}, {last} and {suffix}. Defaults to the locale's
+            name order.
+
+    Returns:
+        A list of formatted full names.
+
+    Raises:
+        ValueError: If n exceeds the maximum batch size (10 million) or the
+            template contains an unknown placeholder.
+    """
+    ...
+
+def email(
+    domain: str | None = None,
+    style: str | None = None,
+    leet: bool = False,
+    mixed_case: bool = False,
+) -> str:
     """Generate a single random email address.
 
+    Args:
+        domain: If given, the address uses this domain.
+        style: Local part layout: "first###" (the default), "first.last",
+            "flast", "first_l##" or "any".
+        leet: Replace a, e, i, o, s and t in the local part with 4, 3, 1, 0, 5 and 7.
+        mixed_case: Capitalize the name parts of the local part.
+
     Returns:
         An email address.
+
+    Raises:
+        ValueError: If the domain is not a valid hostname or the style is unknown.
     """
     ...
 
-def emails(n: int) -> list[str]:
+def emails(
+    n: int,
+    domain: str | None = None,
+    style: str | None = None,
+    leet: bool = False,
+    mixed_case: bool = False,
+) -> list[str]:
     """Generate a batch of random email addresses.
 
     Args:
         n: Number of emails to generate.
+        domain: If given, every address uses this domain.
+        style: Local part layout: "first###" (the default), "first.last",
+            "flast", "first_l##" or "any".
+        leet: Replace a, e, i, o, s and t in the local part with 4, 3, 1, 0, 5 and 7.
+        mixed_case: Capitalize the name parts of the local part.
 
     Returns:
         A list of email addresses.
 
     Raises:
-        ValueError: If n exceeds the maximum batch size (10 million).
+        ValueError: If n exceeds the maximum batch size (10 million), the
+            domain is not a valid hostname or the style is unknown.
     """
     ...
 
+def username(style: str = "any", leet: bool = False, mixed_case: bool = False) -> str: ...
+def usernames(
+    n: int, style: str = "any", leet: bool = False, mixed_case: bool = False
+) -> list[str]: ...
 def integer(min: int = 0, max: int = 100) -> int:
     """Generate a single random integer within a range.
 
@@ -151,6 +322,9 @@ def uuids(n: int) -> list[str]:
     """Generate a batch of random UUIDs (version 4)."""
     ...
 
+def uuid7(start: str = "2020-01-01", end: str = "2030-12-31") -> str: ...
+def uuid7s(n: int, start: str = "2020-01-01", end: str = "2030-12-31") -> list[str]: ...
+
 # Float generation
 def float_(min: float = 0.0, max: float = 1.0) -> float: ...
 def floats(n: int, min: float = 0.0, max: float = 1.0) -> list[float]: ...
@@ -158,8 +332,16 @@ def floats(n: int, min: float = 0.0, max: float = 1.0) -> list[float]: ...
 # Hash generation
 def md5() -> str: ...
 def md5s(n: int) -> list[str]: ...
+def sha1() -> str: ...
+def sha1s(n: int) -> list[str]: ...
 def sha256() -> str: ...
 def sha256s(n: int) -> list[str]: ...
+def sha512() -> str: ...
+def sha512s(n: int) -> list[str]: ...
+
+# Binary generation
+def bytes_(n: int, length: int = 16) -> list[bytes]: ...
+def base64_(n: int, decoded_length: int = 32, url_safe: bool = False) -> list[str]: ...
 
 # Color generation
 def color() -> str: ...
@@ -168,22 +350,104 @@ def hex_color() -> str: ...
 def hex_colors(n: int) -> list[str]: ...
 def rgb_color() -> tuple[int, int, int]: ...
 def rgb_colors(n: int) -> list[tuple[int, int, int]]: ...
+def hsl_color() -> tuple[int, int, int]: ...
+def hsl_colors(n: int) -> list[tuple[int, int, int]]: ...
+def cmyk_color() -> tuple[int, int, int, int]: ...
+def cmyk_colors(n: int) -> list[tuple[int, int, int, int]]: ...
+def css_color(format: str | None = None) -> str: ...
+def css_colors(n: int, format: str | None = None) -> list[str]: ...
+def color_palette(size: int = 5, scheme: str = "analogous") -> list[str]: ...
+def color_palettes(n: int, size: int = 5, scheme: str = "analogous") -> list[list[str]]: ...
 
 # DateTime generation
-def date(start: str = "2000-01-01", end: str = "2030-12-31") -> str: ...
-def dates(n: int, start: str = "2000-01-01", end: str = "2030-12-31") -> list[str]: ...
+def date(
+    start: str = "2000-01-01", end: str = "2030-12-31", format: str | None = None
+) -> str: ...
+def dates(
+    n: int, start: str = "2000-01-01", end: str = "2030-12-31", format: str | None = None
+) -> list[str]: ...
+def business_date(
+    start: str = "2000-01-01", end: str = "2030-12-31", holidays: list[str] | None = None
+) -> str: ...
+def business_dates(
+    n: int,
+    start: str = "2000-01-01",
+    end: str = "2030-12-31",
+    holidays: list[str] | None = None,
+) -> list[str]: ...
+def iso_week(start: str = "2000-01-01", end: str = "2030-12-31") -> str: ...
+def iso_weeks(n: int, start: str = "2000-01-01", end: str = "2030-12-31") -> list[str]: ...
+def quarter(start: str = "2000-01-01", end: str = "2030-12-31") -> str: ...
+def quarters(n: int, start: str = "2000-01-01", end: str = "2030-12-31") -> list[str]: ...
+def month(start: str = "2000-01-01", end: str = "2030-12-31") -> str: ...
+def months(n: int, start: str = "2000-01-01", end: str = "2030-12-31") -> list[str]: ...
+def duration(min: int = 0, max: int = 86400, format: str = "iso8601") -> str | int: ...
+def durations(
+    n: int, min: int = 0, max: int = 86400, format: str = "iso8601"
+) -> list[str] | list[int]: ...
 def date_of_birth(min_age: int = 18, max_age: int = 80) -> str: ...
 def dates_of_birth(n: int, min_age: int = 18, max_age: int = 80) -> list[str]: ...
-def datetime_(start: str = "2000-01-01", end: str = "2030-12-31") -> str: ...
-def datetimes(n: int, start: str = "2000-01-01", end: str = "2030-12-31") -> list[str]: ...
+def date_of_birth_with_age(min_age: int = 18, max_age: int = 80) -> tuple[str, int]: ...
+def dates_of_birth_with_age(
+    n: int, min_age: int = 18, max_age: int = 80
+) -> list[tuple[str, int]]: ...
+def datetime_(
+    start: str = "2000-01-01", end: str = "2030-12-31", format: str | None = None
+) -> str: ...
+def datetimes(
+    n: int, start: str = "2000-01-01", end: str = "2030-12-31", format: str | None = None
+) -> list[str]: ...
+def time_(format: str = "%H:%M:%S") -> str: ...
+def times(n: int, format: str = "%H:%M:%S") -> list[str]: ...
+def recent(days: int = 7) -> str: ...
+def recent_datetimes(n: int, days: int = 7) -> list[str]: ...
+def past(years: int = 5) -> str: ...
+def past_datetimes(n: int, years: int = 5) -> list[str]: ...
+def future(years: int = 1) -> str: ...
+def future_datetimes(n: int, years: int = 1) -> list[str]: ...
+def time_series(
+    n: int,
+    start: str,
+    end: str,
+    freq: str = "1h",
+    value: str = "random_walk",
+    jitter: float = 1.0,
+    drift: float = 0.0,
+    base: float = 100.0,
+    amplitude: float = 10.0,
+    period: str = "1d",
+) -> Any: ...
+def sensor_readings(
+    n: int, start: str, end: str, sensors: int = 10, metrics: list[str] | None = None
+) -> list[dict[str, Any]]: ...
+def sensor_readings_arrow(
+    n: int, start: str, end: str, sensors: int = 10, metrics: list[str] | None = None
+) -> Any: ...
 
 # Text generation
-def sentence(word_count: int = 10) -> str: ...
-def sentences(n: int, word_count: int = 10) -> list[str]: ...
-def paragraph(sentence_count: int = 5) -> str: ...
-def paragraphs(n: int, sentence_count: int = 5) -> list[str]: ...
+def word() -> str: ...
+def words(n: int, count: int = 3) -> list[list[str]]: ...
+def sentence(word_count: int | tuple[int, int] = 10, comma_rate: float = 0.0) -> str: ...
+def sentences(
+    n: int, word_count: int | tuple[int, int] = 10, comma_rate: float = 0.0
+) -> list[str]: ...
+def paragraph(
+    sentence_count: int | tuple[int, int] = 5,
+    word_count: int | tuple[int, int] = (5, 15),
+    comma_rate: float = 0.0,
+) -> str: ...
+def paragraphs(
+    n: int,
+    sentence_count: int | tuple[int, int] = 5,
+    word_count: int | tuple[int, int] = (5, 15),
+    comma_rate: float = 0.0,
+) -> list[str]: ...
 def text(min_chars: int = 50, max_chars: int = 200) -> str: ...
 def texts(n: int, min_chars: int = 50, max_chars: int = 200) -> list[str]: ...
+def markdown(paragraphs: int = 3) -> str: ...
+def markdowns(n: int, paragraphs: int = 3) -> list[str]: ...
+def html(elements: int = 5) -> str: ...
+def htmls(n: int, elements: int = 5) -> list[str]: ...
 
 # Address generation
 def street_address() -> str: ...
@@ -192,54 +456,186 @@ def city() -> str: ...
 def cities(n: int) -> list[str]: ...
 def state() -> str: ...
 def states(n: int) -> list[str]: ...
-def country() -> str: ...
-def countries(n: int) -> list[str]: ...
+def state_abbr() -> str: ...
+def state_abbrs(n: int) -> list[str]: ...
+def street_name() -> str: ...
+def street_names(n: int) -> list[str]: ...
+def street_suffix() -> str: ...
+def street_suffixes(n: int) -> list[str]: ...
+def building_number() -> str: ...
+def building_numbers(n: int) -> list[str]: ...
+def country(format: str | None = None) -> str: ...
+def countries(n: int, format: str | None = None) -> list[str]: ...
+def country_code(format: str | None = None) -> str: ...
+def country_codes(n: int, format: str | None = None) -> list[str]: ...
 def zip_code() -> str: ...
 def zip_codes(n: int) -> list[str]: ...
-def address() -> str: ...
-def addresses(n: int) -> list[str]: ...
+def address(unit_rate: float = 0.0, po_box_rate: float = 0.0) -> str: ...
+def addresses(n: int, unit_rate: float = 0.0, po_box_rate: float = 0.0) -> list[str]: ...
+def county(state: str | None = None, with_state: bool = False) -> str: ...
+def counties(n: int, state: str | None = None, with_state: bool = False) -> list[str]: ...
+def address_parts(unit_rate: float = 0.0) -> dict[str, str]: ...
+def address_parts_batch(n: int, unit_rate: float = 0.0) -> list[dict[str, str]]: ...
+def secondary_unit() -> str: ...
+def secondary_units(n: int) -> list[str]: ...
+def po_box() -> str: ...
+def po_boxes(n: int) -> list[str]: ...
+
+# Geographic generation
+def latitude(precision: int = 6) -> float: ...
+def latitudes(n: int, precision: int = 6) -> list[float]: ...
+def longitude(precision: int = 6) -> float: ...
+def longitudes(n: int, precision: int = 6) -> list[float]: ...
+def coordinate(precision: int = 6) -> tuple[float, float]: ...
+def coordinates(n: int, precision: int = 6) -> list[tuple[float, float]]: ...
+def coordinate_in_bbox(
+    min_lat: float, max_lat: float, min_lon: float, max_lon: float, precision: int = 6
+) -> tuple[float, float]: ...
+def coordinates_in_bbox(
+    n: int,
+    min_lat: float,
+    max_lat: float,
+    min_lon: float,
+    max_lon: float,
+    precision: int = 6,
+) -> list[tuple[float, float]]: ...
+def coordinate_in_country(country: str, precision: int = 6) -> tuple[float, float]: ...
+def coordinates_in_country(
+    n: int, country: str, precision: int = 6
+) -> list[tuple[float, float]]: ...
+def gps_track(
+    points: int, start_coord: tuple[float, float] | None = None, max_speed_kmh: float = 50.0
+) -> list[dict[str, Any]]: ...
 
 # Phone generation
-def phone_number() -> str: ...
-def phone_numbers(n: int) -> list[str]: ...
+def phone_number(format: str = "formatted", kind: str = "any", safe: bool = False) -> str: ...
+def phone_numbers(
+    n: int, format: str = "formatted", kind: str = "any", safe: bool = False
+) -> list[str]: ...
+
+# Profile generation
+def profile(min_age: int = 18, max_age: int = 80) -> dict[str, str | int]: ...
+def profiles(n: int, min_age: int = 18, max_age: int = 80) -> list[dict[str, str | int]]: ...
+
+# Demographic generation
+def age(min: int = 18, max: int = 80) -> int: ...
+def ages(n: int, min: int = 18, max: int = 80) -> list[int]: ...
+def gender(weights: dict[str, int] | None = None) -> str: ...
+def genders(n: int, weights: dict[str, int] | None = None) -> list[str]: ...
+def pronoun(weights: dict[str, int] | None = None) -> str: ...
+def pronouns(n: int, weights: dict[str, int] | None = None) -> list[str]: ...
+def marital_status(weights: dict[str, int] | None = None) -> str: ...
+def marital_statuses(n: int, weights: dict[str, int] | None = None) -> list[str]: ...
+
+# National ID generation
+def national_id() -> str: ...
+def national_ids(n: int) -> list[str]: ...
+def drivers_license(region: str | None = None) -> str: ...
+def drivers_licenses(n: int, region: str | None = None) -> list[str]: ...
 
 # Company generation
 def company() -> str: ...
 def companies(n: int) -> list[str]: ...
-def job() -> str: ...
-def jobs(n: int) -> list[str]: ...
-def catch_phrase() -> str: ...
-def catch_phrases(n: int) -> list[str]: ...
+def job(level: str | None = None) -> str: ...
+def jobs(n: int, level: str | None = None) -> list[str]: ...
+def job_level() -> str: ...
+def job_levels(n: int) -> list[str]: ...
+def job_area() -> str: ...
+def job_areas(n: int) -> list[str]: ...
+def job_role() -> str: ...
+def job_roles(n: int) -> list[str]: ...
+def catch_phrase(style: str = "basic") -> str: ...
+def catch_phrases(n: int, style: str = "basic") -> list[str]: ...
+def department() -> str: ...
+def departments(n: int) -> list[str]: ...
+def industry() -> str: ...
+def industries(n: int) -> list[str]: ...
+def naics_code() -> str: ...
+def naics_codes(n: int) -> list[str]: ...
+def sic_code() -> str: ...
+def sic_codes(n: int) -> list[str]: ...
+def company_profile() -> dict[str, str]: ...
+def company_profiles(n: int) -> list[dict[str, str]]: ...
+def ein() -> str: ...
+def eins(n: int) -> list[str]: ...
+def company_tax_id() -> str: ...
+def company_tax_ids(n: int) -> list[str]: ...
 
 # Network generation
-def url() -> str: ...
-def urls(n: int) -> list[str]: ...
+def url(
+    scheme: str = "https",
+    path_depth: int | None = None,
+    query_params: int = 0,
+    fragment: bool = False,
+    extension: str | None = None,
+) -> str: ...
+def urls(
+    n: int,
+    scheme: str = "https",
+    path_depth: int | None = None,
+    query_params: int = 0,
+    fragment: bool = False,
+    extension: str | None = None,
+) -> list[str]: ...
 def domain_name() -> str: ...
 def domain_names(n: int) -> list[str]: ...
-def ipv4() -> str: ...
-def ipv4s(n: int) -> list[str]: ...
-def ipv6() -> str: ...
-def ipv6s(n: int) -> list[str]: ...
-def mac_address() -> str: ...
-def mac_addresses(n: int) -> list[str]: ...
+def ipv4(private: bool = False, network: str | None = None) -> str: ...
+def ipv4s(n: int, private: bool = False, network: str | None = None) -> list[str]: ...
+def ipv4_public() -> str: ...
+def ipv4_publics(n: int) -> list[str]: ...
+def cidr(prefix_range: tuple[int, int] = (8, 30)) -> str: ...
+def cidrs(n: int, prefix_range: tuple[int, int] = (8, 30)) -> list[str]: ...
+def ipv6(compressed: bool = False, uppercase: bool = False, kind: str = "random") -> str: ...
+def ipv6s(
+    n: int, compressed: bool = False, uppercase: bool = False, kind: str = "random"
+) -> list[str]: ...
+def mac_address(separator: str = "colon", uppercase: bool = False, kind: str = "random") -> str: ...
+def mac_addresses(
+    n: int, separator: str = "colon", uppercase: bool = False, kind: str = "random"
+) -> list[str]: ...
+def port(kind: str = "any") -> int: ...
+def ports(n: int, kind: str = "any") -> list[int]: ...
+def http_method() -> str: ...
+def http_methods(n: int) -> list[str]: ...
+def http_status_code(class_: str | None = None) -> int: ...
+def http_status_codes(n: int, class_: str | None = None) -> list[int]: ...
 
 # Email variants
 def safe_email() -> str: ...
 def safe_emails(n: int) -> list[str]: ...
 def free_email() -> str: ...
 def free_emails(n: int) -> list[str]: ...
+def company_email(domain: str | None = None, company: str | None = None) -> str: ...
+def company_emails(
+    n: int, domain: str | None = None, company: str | None = None
+) -> list[str]: ...
 
 # Finance generation
-def credit_card() -> str: ...
-def credit_cards(n: int) -> list[str]: ...
+def credit_card(network: str | None = None) -> str: ...
+def credit_cards(n: int, network: str | None = None) -> list[str]: ...
 def iban() -> str: ...
 def ibans(n: int) -> list[str]: ...
 def bic() -> str: ...
 def bics(n: int) -> list[str]: ...
+def routing_number() -> str: ...
+def routing_numbers(n: int) -> list[str]: ...
+def currency_code() -> str: ...
+def currency_codes(n: int) -> list[str]: ...
+def currency_name() -> str: ...
+def currency_names(n: int) -> list[str]: ...
+def currency_symbol() -> str: ...
+def currency_symbols(n: int) -> list[str]: ...
+def ticker() -> str: ...
+def tickers(n: int) -> list[str]: ...
+def stock_exchange() -> str: ...
+def stock_exchanges(n: int) -> list[str]: ...
+def stocks(n: int) -> list[dict[str, str]]: ...
 def bank_account() -> str: ...
 def bank_accounts(n: int) -> list[str]: ...
 def bank_name() -> str: ...
 def bank_names(n: int) -> list[str]: ...
+def vat_number(country: str | None = None) -> str: ...
+def vat_numbers(n: int, country: str | None = None) -> list[str]: ...
 
 # UK Banking generation
 def sort_code() -> str:
@@ -331,6 +727,138 @@ def transaction_amounts(n: int, min: float, max: float) -> list[float]:
     """
     ...
 
+def price(
+    min: float, max: float, currency: str = "USD", formatted: bool = True
+) -> str | float: ...
+def prices(
+    n: int, min: float, max: float, currency: str = "USD", formatted: bool = True
+) -> list[str] | list[float]: ...
+
+# Commerce generation
+def invoice_number(prefix: str = "INV", digits: int = 6, date: str | None = "year") -> str: ...
+def invoice_numbers(
+    n: int, prefix: str = "INV", digits: int = 6, date: str | None = "year"
+) -> list[str]: ...
+def sku(pattern: str | None = None) -> str: ...
+def skus(n: int, pattern: str | None = None) -> list[str]: ...
+def retail_price(tier: str = "standard", decimal: bool = False) -> float | Decimal: ...
+def retail_prices(
+    n: int, tier: str = "standard", decimal: bool = False
+) -> list[float] | list[Decimal]: ...
+def product_name() -> str: ...
+def product_names(n: int, unique: bool = False) -> list[str]: ...
+
+# Insurance generation
+def policy_number(prefix: str = "POL", digits: int = 8) -> str: ...
+def policy_numbers(n: int, prefix: str = "POL", digits: int = 8) -> list[str]: ...
+def claim_number(prefix: str = "CLM", digits: int = 8) -> str: ...
+def claim_numbers(n: int, prefix: str = "CLM", digits: int = 8) -> list[str]: ...
+
+# Product code generation
+def isbn13() -> str: ...
+def isbn13s(n: int) -> list[str]: ...
+def isbn10() -> str: ...
+def isbn10s(n: int) -> list[str]: ...
+def imei() -> str: ...
+def imeis(n: int) -> list[str]: ...
+def doi() -> str: ...
+def dois(n: int) -> list[str]: ...
+def orcid() -> str: ...
+def orcids(n: int) -> list[str]: ...
+
+# Vehicle generation
+def vin() -> str: ...
+def vins(n: int) -> list[str]: ...
+def vehicle_make() -> str: ...
+def vehicle_makes(n: int) -> list[str]: ...
+def vehicle_model() -> str: ...
+def vehicle_models(n: int) -> list[str]: ...
+def vehicle() -> dict[str, str | int]: ...
+def vehicles(n: int) -> list[dict[str, str | int]]: ...
+
+# Medical generation
+def blood_type() -> str: ...
+def blood_types(n: int) -> list[str]: ...
+def height() -> float: ...
+def heights(n: int) -> list[float]: ...
+def weight() -> float: ...
+def weights(n: int) -> list[float]: ...
+def allergy() -> str: ...
+def allergies(n: int) -> list[str]: ...
+def medication() -> str: ...
+def medications(n: int) -> list[str]: ...
+def icd10_code() -> str: ...
+def icd10_codes(n: int) -> list[str]: ...
+def npi() -> str: ...
+def npis(n: int) -> list[str]: ...
+
+# Travel generation
+def airport_code() -> str: ...
+def airport_codes(n: int) -> list[str]: ...
+def airport_icao_code() -> str: ...
+def airport_icao_codes(n: int) -> list[str]: ...
+def airport_name() -> str: ...
+def airport_names(n: int) -> list[str]: ...
+def airport_city() -> str: ...
+def airport_cities(n: int) -> list[str]: ...
+def flight_number() -> str: ...
+def flight_numbers(n: int, unique: bool = False) -> list[str]: ...
+def airports(n: int) -> list[dict[str, str]]: ...
+
+# Logistics generation
+def tracking_number(carrier: str | None = None) -> str: ...
+def tracking_numbers(n: int, carrier: str | None = None) -> list[str]: ...
+
+# Language generation
+def language_code() -> str: ...
+def language_codes(n: int) -> list[str]: ...
+def language_name() -> str: ...
+def language_names(n: int) -> list[str]: ...
+
+# File system generation
+def file_name(category: str | None = None) -> str: ...
+def file_names(n: int, category: str | None = None) -> list[str]: ...
+def file_extension(category: str | None = None) -> str: ...
+def file_extensions(n: int, category: str | None = None) -> list[str]: ...
+def file_path(depth: int = 2, os: str = "unix", category: str | None = None) -> str: ...
+def file_paths(
+    n: int, depth: int = 2, os: str = "unix", category: str | None = None
+) -> list[str]: ...
+def mime_type(category: str | None = None) -> str: ...
+def mime_types(n: int, category: str | None = None) -> list[str]: ...
+
+# Software generation
+def semver(prerelease: bool = False, build: bool = False) -> str: ...
+def semvers(n: int, prerelease: bool = False, build: bool = False) -> list[str]: ...
+def git_sha(short: bool = False) -> str: ...
+def git_shas(n: int, short: bool = False) -> list[str]: ...
+def commit_message() -> str: ...
+def commit_messages(n: int) -> list[str]: ...
+def docker_image(registry: bool = True, tag: bool = True, digest: bool = False) -> str: ...
+def docker_images(
+    n: int, registry: bool = True, tag: bool = True, digest: bool = False
+) -> list[str]: ...
+def k8s_name(kind: str = "pod") -> str: ...
+def k8s_names(n: int, kind: str = "pod") -> list[str]: ...
+
+# Cloud generation
+def arn(service: str | None = None) -> str: ...
+def arns(n: int, service: str | None = None) -> list[str]: ...
+def s3_uri() -> str: ...
+def s3_uris(n: int) -> list[str]: ...
+def cloud_region(provider: str = "aws") -> str: ...
+def cloud_regions(n: int, provider: str = "aws") -> list[str]: ...
+
+# Log generation
+def access_log() -> str: ...
+def access_logs(n: int) -> list[str]: ...
+def access_log_entry() -> dict[str, str | int]: ...
+def access_log_entries(n: int) -> list[dict[str, str | int]]: ...
+def syslog(format: str = "rfc5424") -> str: ...
+def syslogs(n: int, format: str = "rfc5424") -> list[str]: ...
+def syslog_entry() -> dict[str, str | int]: ...
+def syslog_entries(n: int) -> list[dict[str, str | int]]: ...
+
 # Password generation
 def password(
     length: int = 12,
@@ -375,16 +903,60 @@ def passwords(
         ValueError: If no character sets are enabled or n exceeds batch limit.
     """
     ...
+def password_hash(algorithm: str = "bcrypt") -> str: ...
+def password_hashes(n: int, algorithm: str = "bcrypt") -> list[str]: ...
+def jwt(
+    subject: str | None = None,
+    issuer: str | None = None,
+    start: str = "2020-01-01",
+    end: str = "2030-12-31",
+    ttl: int = 3600,
+    algorithm: str = "HS256",
+) -> str: ...
+def jwts(
+    n: int,
+    subject: str | None = None,
+    issuer: str | None = None,
+    start: str = "2020-01-01",
+    end: str = "2030-12-31",
+    ttl: int = 3600,
+    algorithm: str = "HS256",
+) -> list[str]: ...
+def api_key(prefix: str = "sk_live_", length: int = 32, alphabet: str = "base62") -> str: ...
+def api_keys(
+    n: int, prefix: str = "sk_live_", length: int = 32, alphabet: str = "base62"
+) -> list[str]: ...
+def session_id(format: str = "hex") -> str: ...
+def session_ids(n: int, format: str = "hex") -> list[str]: ...
+def oauth_access_token(format: str = "prefixed") -> str: ...
+def oauth_access_tokens(n: int, format: str = "prefixed") -> list[str]: ...
+def refresh_token(format: str = "prefixed") -> str: ...
+def refresh_tokens(n: int, format: str = "prefixed") -> list[str]: ...
+def totp_secret(length: int = 32) -> str: ...
+def totp_secrets(n: int, length: int = 32) -> list[str]: ...
+def totp_uri(length: int = 32) -> str: ...
+def totp_uris(n: int, length: int = 32) -> list[str]: ...
 
 # Records generation
-FieldValue = str | int | float | tuple[int, int, int]
+FieldValue = (
+    str | int | float | bool | Decimal | tuple[int, int, int] | dict[str, str] | None
+)
 SimpleType = str
 IntRangeSpec = tuple[str, int, int]
 FloatRangeSpec = tuple[str, float, float]
 TextSpec = tuple[str, int, int]
 DateRangeSpec = tuple[str, str, str]
 ChoiceSpec = tuple[str, list[str]]
-FieldSpec = SimpleType | IntRangeSpec | FloatRangeSpec | TextSpec | DateRangeSpec | ChoiceSpec
+NullableSpec = tuple[str, str | tuple[Any, ...], float]
+FieldSpec = (
+    SimpleType
+    | IntRangeSpec
+    | FloatRangeSpec
+    | TextSpec
+    | DateRangeSpec
+    | ChoiceSpec
+    | NullableSpec
+)
 Schema = dict[str, FieldSpec]
 
 def records(n: int, schema: Schema) -> list[dict[str, FieldValue]]:
@@ -396,9 +968,27 @@ def records(n: int, schema: Schema) -> list[dict[str, FieldValue]]:
             - Simple types: "name", "email", "uuid", "int", "float", etc.
             - Integer range: ("int", min, max)
             - Float range: ("float", min, max)
+            - Boolean with a true probability: ("bool", true_rate)
             - Text with limits: ("text", min_chars, max_chars)
             - Date range: ("date", start, end)
             - Choice: ("choice", ["option1", "option2", ...])
+            - Price: ("price", min, max[, currency[, formatted]])
+            - Credit card: ("credit_card", network)
+            - VAT number: ("vat_number", country)
+            - Driver's license: ("drivers_license", region)
+            - Country format: ("country", format)
+            - MIME type: ("mime_type", category)
+            - Port: ("port", kind)
+            - HTTP status code: ("http_status_code", class)
+            - Job title at a level: ("job", level)
+            - CSS color in one syntax: ("css_color", format)
+            - Business date: ("business_date", start, end[, holidays])
+            - Relative datetime: ("recent", days), ("past", years), ("future", years)
+            - Email on a fixed domain: ("email", domain)
+            - Password hash: ("password_hash", algorithm)
+            - API key: ("api_key", prefix[, length[, alphabet]])
+            - Phone format and kind: ("phone", format[, kind[, safe]])
+            - Nullable: ("nullable", spec, null_rate), None at the given rate
 
     Returns:
         A list of dictionaries, each representing a record.
@@ -459,6 +1049,24 @@ def records_arrow(n: int, schema: Schema) -> Any:
     """
     ...
 
+def describe(schema: Schema, n_sample: int = 1000) -> dict[str, dict[str, Any]]:
+    """Generate a sample of records and return per-field statistics.
+
+    Args:
+        schema: Dictionary mapping field names to type specifications.
+        n_sample: Number of records to sample (default: 1000).
+
+    Returns:
+        A dictionary mapping field names to statistics: count, null_count,
+        null_rate, cardinality, min, max, min_length, max_length, and
+        mean_length (length statistics are None for non-string fields).
+
+    Raises:
+        ValueError: If n_sample exceeds the maximum batch size (10 million)
+            or if the schema contains invalid specifications.
+    """
+    ...
+
 # Async Records generation
 
 def records_async(
@@ -597,3 +1205,26 @@ def generate_batch(name: str, n: int) -> list[str]:
         ValueError: If provider doesn't exist or n exceeds batch limit.
     """
     ...
+
+def override_locale_data(
+    first_names: list[str] | None = None,
+    last_names: list[str] | None = None,
+    cities: list[str] | None = None,
+    street_names: list[str] | None = None,
+) -> None:
+    """Layer custom data lists on top of the default Faker's locale data.
+
+    Args:
+        first_names: Replacement first names.
+        last_names: Replacement last names.
+        cities: Replacement city names.
+        street_names: Replacement street names.
+
+    Raises:
+        ValueError: If any supplied list is empty.
+    """
+    ...
+
+def clear_locale_overrides() -> None:
+    """Remove all locale data overrides from the default Faker instance."""
+    ...
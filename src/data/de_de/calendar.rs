@@ -0,0 +1,28 @@
+//! German month and weekday names.
+
+/// German month names.
+pub const MONTH_NAMES: &[&str] = &[
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+/// German weekday names, starting with Monday.
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "Montag",
+    "Dienstag",
+    "Mittwoch",
+    "Donnerstag",
+    "Freitag",
+    "Samstag",
+    "Sonntag",
+];
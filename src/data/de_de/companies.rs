@@ -30,4 +30,5 @@ crate::define_company_data! {
         "Strategien", "Technologien", "Innovationen", "Partnerschaften", "Qualität",
         "Service", "Beratung", "Kompetenz", "Expertise", "Erfahrung",
     ],
+    legal_forms: ["GmbH", "AG", "KG", "OHG", "UG", "SE", "GmbH & Co. KG"],
 }
@@ -4,28 +4,36 @@
 
 mod banks;
 mod bundeslaender;
+mod calendar;
 mod cities;
 mod color_names;
 mod companies;
 mod first_names;
 mod last_names;
+mod name_affixes;
 mod streets;
 
 pub use banks::BANK_NAMES;
 pub use bundeslaender::{BUNDESLAENDER, BUNDESLAENDER_ABBRS};
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
 pub use cities::CITIES;
 pub use color_names::COLOR_NAMES;
 pub use companies::{
-    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_PREFIXES, COMPANY_SUFFIXES, JOB_TITLES,
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_SUFFIXES, JOB_TITLES,
 };
-pub use first_names::FIRST_NAMES;
+pub use first_names::{FIRST_NAMES, MALE_FIRST_NAME_COUNT};
 pub use last_names::LAST_NAMES;
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
 pub use streets::{STREET_NAMES, STREET_SUFFIXES};
 
 // Shared data
 use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
 
-use super::formats::{AddressFormat, PhoneFormat, PostalCodeFormat};
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
 
 /// Phone format patterns for Germany.
 const DE_PHONE_PATTERNS: &[&str] = &[
@@ -35,11 +43,55 @@ const DE_PHONE_PATTERNS: &[&str] = &[
     "0## ########",
 ];
 
+/// Mobile phone patterns for Germany.
+const DE_MOBILE_PATTERNS: &[&str] = &[
+    "015# #######",
+    "016# #######",
+    "017# #######",
+    "+49 15# #######",
+    "+49 17# #######",
+];
+
+/// Landline phone patterns for Germany.
+const DE_LANDLINE_PATTERNS: &[&str] = &[
+    "030 #######",
+    "040 #######",
+    "089 #######",
+    "0221 #######",
+    "+49 30 #######",
+    "+49 89 #######",
+];
+
+/// Fictional mobile numbers for Germany (Bundesnetzagentur numbers reserved for film and TV).
+const DE_FICTIONAL_MOBILE_PATTERNS: &[&str] = &[
+    "0152 28817386",
+    "0152 28895456",
+    "0152 54599371",
+    "0172 9925904",
+    "0172 9968532",
+    "0176 04690522",
+    "+49 152 28817386",
+    "+49 172 9925904",
+];
+
+/// Fictional landline patterns for Germany (Bundesnetzagentur blocks reserved for film and TV).
+const DE_FICTIONAL_LANDLINE_PATTERNS: &[&str] = &[
+    "030 23125 ###",
+    "040 66969 ###",
+    "069 90009 ###",
+    "089 99998 ###",
+    "0221 4710 ###",
+    "+49 30 23125 ###",
+    "+49 89 99998 ###",
+];
+
 /// Postal code patterns for Germany (5 digits).
 const DE_POSTAL_PATTERNS: &[&str] = &["#####"];
 
 /// German phone format specification.
-const DE_PHONE_FORMAT: PhoneFormat = PhoneFormat::new(DE_PHONE_PATTERNS, "+49");
+const DE_PHONE_FORMAT: PhoneFormat = PhoneFormat::with_trunk_prefix(DE_PHONE_PATTERNS, "+49", "0")
+    .with_kinds(DE_MOBILE_PATTERNS, DE_LANDLINE_PATTERNS)
+    .with_fictional(DE_FICTIONAL_MOBILE_PATTERNS, DE_FICTIONAL_LANDLINE_PATTERNS);
 
 /// German postal code format specification.
 const DE_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(DE_POSTAL_PATTERNS);
@@ -50,6 +102,26 @@ const DE_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(DE_POSTAL_PATTE
 const DE_ADDRESS_FORMAT: AddressFormat =
     AddressFormat::with_separator("{street}\n{postal} {city}", false, "");
 
+/// German units follow the street (e.g., "Hauptstraße 5, Wohnung 3").
+const DE_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[
+        UnitFormat::new("Wohnung {n}", 1, 40),
+        UnitFormat::new("App. {n}", 1, 99),
+    ],
+    "{street}, {unit}",
+    "Postfach {n}",
+);
+
+/// German date format.
+const DE_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{dd}.{mm}.{yyyy}",
+    "{weekday}, {d}. {month} {yyyy}",
+    "{HH}:{MM}:{SS}",
+);
+
+/// German number format.
+const DE_NUMBER_FORMAT: NumberFormat = NumberFormat::new(",", ".", "{amount} {symbol}");
+
 /// German locale data provider.
 pub struct DeDEData;
 
@@ -68,9 +140,11 @@ crate::impl_locale_data! {
     countries: COUNTRIES,
     postal_format: DE_POSTAL_FORMAT,
     address_format: DE_ADDRESS_FORMAT,
+    secondary_address_format: DE_SECONDARY_ADDRESS_FORMAT,
     phone_format: DE_PHONE_FORMAT,
     company_prefixes: COMPANY_PREFIXES,
     company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
     job_titles: JOB_TITLES,
     catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
     catch_phrase_nouns: CATCH_PHRASE_NOUNS,
@@ -80,6 +154,13 @@ crate::impl_locale_data! {
     safe_email_domains: SAFE_EMAIL_DOMAINS,
     color_names: COLOR_NAMES,
     bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: DE_DATE_FORMAT,
+    number_format: DE_NUMBER_FORMAT,
 }
 
 #[cfg(test)]
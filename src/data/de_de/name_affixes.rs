@@ -0,0 +1,7 @@
+//! German name prefixes and suffixes.
+
+/// German name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &["Herr", "Frau", "Dr.", "Prof.", "Prof. Dr.", "Dipl.-Ing."];
+
+/// German name suffixes (generational and post-nominal).
+pub const NAME_SUFFIXES: &[&str] = &["B.Sc.", "M.Sc.", "M.A.", "MBA", "LL.M."];
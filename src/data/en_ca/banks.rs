@@ -0,0 +1,20 @@
+//! Bank name data for en_CA locale.
+
+/// Canadian bank names.
+pub const BANK_NAMES: &[&str] = &[
+    "Royal Bank of Canada",
+    "Toronto-Dominion Bank",
+    "Bank of Nova Scotia",
+    "Bank of Montreal",
+    "Canadian Imperial Bank of Commerce",
+    "National Bank of Canada",
+    "Desjardins Group",
+    "Laurentian Bank of Canada",
+    "HSBC Bank Canada",
+    "ATB Financial",
+    "Canadian Western Bank",
+    "EQ Bank",
+    "Tangerine",
+    "Simplii Financial",
+    "Vancity",
+];
@@ -0,0 +1,45 @@
+//! Canadian cities.
+
+/// Major Canadian cities.
+pub const CITIES: &[&str] = &[
+    "Toronto",
+    "Montréal",
+    "Vancouver",
+    "Calgary",
+    "Edmonton",
+    "Ottawa",
+    "Winnipeg",
+    "Québec",
+    "Hamilton",
+    "Kitchener",
+    "London",
+    "Victoria",
+    "Halifax",
+    "Oshawa",
+    "Windsor",
+    "Saskatoon",
+    "Gatineau",
+    "Regina",
+    "Sherbrooke",
+    "St. John's",
+    "Barrie",
+    "Kelowna",
+    "Abbotsford",
+    "Trois-Rivières",
+    "Guelph",
+    "Moncton",
+    "Saint John",
+    "Thunder Bay",
+    "Sudbury",
+    "Lévis",
+    "Kingston",
+    "Charlottetown",
+    "Fredericton",
+    "Whitehorse",
+    "Yellowknife",
+    "Iqaluit",
+    "Laval",
+    "Longueuil",
+    "Mississauga",
+    "Brampton",
+];
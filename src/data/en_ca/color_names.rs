@@ -0,0 +1,35 @@
+//! Canadian color names.
+
+/// Color names (English spelling as used in Canada).
+pub const COLOR_NAMES: &[&str] = &[
+    "Red",
+    "Blue",
+    "Green",
+    "Yellow",
+    "Orange",
+    "Purple",
+    "Pink",
+    "Brown",
+    "Black",
+    "White",
+    "Grey",
+    "Navy",
+    "Teal",
+    "Maroon",
+    "Olive",
+    "Turquoise",
+    "Beige",
+    "Gold",
+    "Silver",
+    "Crimson",
+    "Scarlet",
+    "Burgundy",
+    "Lavender",
+    "Ivory",
+    "Charcoal",
+    "Coral",
+    "Indigo",
+    "Magenta",
+    "Amber",
+    "Maple Red",
+];
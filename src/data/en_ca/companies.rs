@@ -0,0 +1,100 @@
+//! Canadian company data.
+
+crate::define_company_data! {
+    prefixes: [
+        "Northern",
+        "Maple",
+        "Great Lakes",
+        "Pacific",
+        "Atlantic",
+        "Prairie",
+        "Laurentian",
+        "Boreal",
+        "True North",
+        "Dominion",
+        "Royal",
+        "Canadian",
+        "Polar",
+        "Rocky Mountain",
+        "Superior",
+        "Tremblay",
+        "Gagnon",
+        "MacDonald",
+        "Fraser",
+        "Campbell",
+    ],
+    suffixes: [
+        "Inc.",
+        "Ltd.",
+        "Corp.",
+        "Ltée",
+        "Group",
+        "Holdings",
+        "Partners",
+        "& Associates",
+        "Enterprises",
+        "Solutions",
+        "Technologies",
+        "Consulting",
+        "Services",
+        "Industries",
+        "Co.",
+    ],
+    job_titles: [
+        "Software Developer",
+        "Project Manager",
+        "Accountant",
+        "Sales Representative",
+        "Marketing Coordinator",
+        "Registered Nurse",
+        "Civil Engineer",
+        "Financial Analyst",
+        "Human Resources Advisor",
+        "Customer Service Representative",
+        "Data Analyst",
+        "Operations Manager",
+        "Graphic Designer",
+        "Administrative Assistant",
+        "Chief Executive Officer",
+        "Teacher",
+        "Pharmacist",
+        "Electrician",
+        "Paralegal",
+        "Bilingual Customer Service Agent",
+    ],
+    adjectives: [
+        "Innovative",
+        "Reliable",
+        "Scalable",
+        "Sustainable",
+        "Integrated",
+        "Customer-focused",
+        "Bilingual",
+        "Streamlined",
+        "Robust",
+        "Proactive",
+        "Dynamic",
+        "Strategic",
+        "Flexible",
+        "Collaborative",
+        "Forward-thinking",
+    ],
+    nouns: [
+        "solutions",
+        "services",
+        "platforms",
+        "partnerships",
+        "strategies",
+        "products",
+        "systems",
+        "networks",
+        "frameworks",
+        "initiatives",
+        "infrastructure",
+        "capabilities",
+        "processes",
+        "experiences",
+        "communities",
+    ],
+    legal_forms: ["Inc", "Ltd", "Corp", "Ltée"],
+}
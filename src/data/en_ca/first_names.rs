@@ -0,0 +1,133 @@
+//! Canadian first names.
+
+/// Common Canadian first names, English and French-Canadian (includes diacritics):
+/// male names followed by female names.
+pub const FIRST_NAMES: &[&str] = &[
+    "James",
+    "William",
+    "Liam",
+    "Noah",
+    "Benjamin",
+    "Lucas",
+    "Oliver",
+    "Ethan",
+    "Jacob",
+    "Logan",
+    "Owen",
+    "Ryan",
+    "Matthew",
+    "Daniel",
+    "Michael",
+    "Jean",
+    "Pierre",
+    "Luc",
+    "François",
+    "Mathieu",
+    "Étienne",
+    "Gabriel",
+    "Félix",
+    "Antoine",
+    "Olivier",
+    "Louis",
+    "Jérôme",
+    "Benoît",
+    "Samuel",
+    "Marc-André",
+    "Emma",
+    "Olivia",
+    "Charlotte",
+    "Ava",
+    "Sophia",
+    "Emily",
+    "Abigail",
+    "Chloe",
+    "Hannah",
+    "Sarah",
+    "Jessica",
+    "Megan",
+    "Madison",
+    "Grace",
+    "Ashley",
+    "Marie",
+    "Geneviève",
+    "Isabelle",
+    "Chantal",
+    "Mélanie",
+    "Josée",
+    "Nathalie",
+    "Émilie",
+    "Camille",
+    "Léa",
+    "Rosalie",
+    "Florence",
+    "Maude",
+    "Sophie",
+    "Marie-Ève",
+];
+
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 30;
+
+/// ASCII-safe romanized Canadian first names for email generation.
+pub const ROMANIZED_FIRST_NAMES: &[&str] = &[
+    "James",
+    "William",
+    "Liam",
+    "Noah",
+    "Benjamin",
+    "Lucas",
+    "Oliver",
+    "Ethan",
+    "Jacob",
+    "Logan",
+    "Owen",
+    "Ryan",
+    "Matthew",
+    "Daniel",
+    "Michael",
+    "Jean",
+    "Pierre",
+    "Luc",
+    "Francois",
+    "Mathieu",
+    "Etienne",
+    "Gabriel",
+    "Felix",
+    "Antoine",
+    "Olivier",
+    "Louis",
+    "Jerome",
+    "Benoit",
+    "Samuel",
+    "Marc-Andre",
+    "Emma",
+    "Olivia",
+    "Charlotte",
+    "Ava",
+    "Sophia",
+    "Emily",
+    "Abigail",
+    "Chloe",
+    "Hannah",
+    "Sarah",
+    "Jessica",
+    "Megan",
+    "Madison",
+    "Grace",
+    "Ashley",
+    "Marie",
+    "Genevieve",
+    "Isabelle",
+    "Chantal",
+    "Melanie",
+    "Josee",
+    "Nathalie",
+    "Emilie",
+    "Camille",
+    "Lea",
+    "Rosalie",
+    "Florence",
+    "Maude",
+    "Sophie",
+    "Marie-Eve",
+];
@@ -0,0 +1,45 @@
+//! Canadian surnames.
+
+/// Common Canadian surnames: English followed by French-Canadian.
+pub const LAST_NAMES: &[&str] = &[
+    "Smith",
+    "Brown",
+    "Wilson",
+    "MacDonald",
+    "Taylor",
+    "Campbell",
+    "Anderson",
+    "Johnson",
+    "Jones",
+    "Williams",
+    "Martin",
+    "Thompson",
+    "White",
+    "Stewart",
+    "Robinson",
+    "Clark",
+    "Scott",
+    "Young",
+    "Walker",
+    "Fraser",
+    "Tremblay",
+    "Gagnon",
+    "Roy",
+    "Côté",
+    "Bouchard",
+    "Gauthier",
+    "Morin",
+    "Lavoie",
+    "Fortin",
+    "Gagné",
+    "Ouellet",
+    "Pelletier",
+    "Bélanger",
+    "Lévesque",
+    "Bergeron",
+    "Leblanc",
+    "Paquette",
+    "Girard",
+    "Simard",
+    "Boucher",
+];
@@ -0,0 +1,168 @@
+//! English (Canada) locale data.
+//!
+//! Contains name lists and other data for en_CA locale.
+//! Name pools mix English and French-Canadian names to reflect Canada's
+//! bilingual population.
+
+mod banks;
+mod cities;
+mod color_names;
+mod companies;
+mod first_names;
+mod last_names;
+mod name_affixes;
+mod provinces;
+mod streets;
+
+pub use banks::BANK_NAMES;
+pub use cities::CITIES;
+pub use color_names::COLOR_NAMES;
+pub use companies::{
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_SUFFIXES, JOB_TITLES,
+};
+pub use first_names::{FIRST_NAMES, MALE_FIRST_NAME_COUNT, ROMANIZED_FIRST_NAMES};
+pub use last_names::LAST_NAMES;
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
+pub use provinces::{PROVINCES, PROVINCE_ABBRS, PROVINCE_AREA_CODES};
+pub use streets::{STREET_NAMES, STREET_SUFFIXES};
+
+// Shared data from en_US
+use super::en_us::{
+    COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, MONTH_NAMES, SAFE_EMAIL_DOMAINS, TLDS,
+    WEEKDAY_NAMES,
+};
+
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
+
+/// Phone format patterns for Canada (North American Numbering Plan).
+const CA_PHONE_PATTERNS: &[&str] = &["(###) ###-####", "###-###-####", "+1 (###) ###-####"];
+
+/// Fictional phone patterns for Canada: 555-0100 through 555-0199 in any area code.
+const CA_FICTIONAL_PATTERNS: &[&str] = &["(###) 555-01##", "###-555-01##", "+1 (###) 555-01##"];
+
+/// Postal code patterns for Canada (letter-digit-letter digit-letter-digit, e.g., "K1A 0B1").
+const CA_POSTAL_PATTERNS: &[&str] = &["A#A #A#"];
+
+/// Canadian phone format specification.
+const CA_PHONE_FORMAT: PhoneFormat = PhoneFormat::new(CA_PHONE_PATTERNS, "+1")
+    .with_fictional(CA_FICTIONAL_PATTERNS, CA_FICTIONAL_PATTERNS);
+
+/// Canadian postal code format specification.
+const CA_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(CA_POSTAL_PATTERNS);
+
+/// Canadian address format specification.
+/// Canada Post puts the municipality, province abbreviation and postal code on
+/// one line, with two spaces before the postal code (e.g., "Ottawa ON  K1A 0B1").
+const CA_ADDRESS_FORMAT: AddressFormat =
+    AddressFormat::new("{street}\n{city} {region_abbr}  {postal}", true);
+
+/// Canadian units follow the street (e.g., "12 Main Street Suite 210").
+const CA_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[
+        UnitFormat::lettered("Apt {n}", 1, 999),
+        UnitFormat::new("Suite {n}", 100, 999),
+        UnitFormat::new("Unit {n}", 1, 99),
+    ],
+    "{street} {unit}",
+    "PO Box {n}",
+);
+
+/// Canadian date format (ISO-style numeric dates, 12-hour clock).
+const CA_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{yyyy}-{mm}-{dd}",
+    "{weekday}, {month} {d}, {yyyy}",
+    "{h}:{MM}:{SS} {ampm}",
+);
+
+/// Canadian number format.
+const CA_NUMBER_FORMAT: NumberFormat = NumberFormat::new(".", ",", "{symbol}{amount}");
+
+/// English (Canada) locale data provider.
+pub struct EnCaData;
+
+/// Static instance of the English (Canada) locale data.
+pub static EN_CA_DATA: EnCaData = EnCaData;
+
+crate::impl_locale_data! {
+    EnCaData,
+    first_names: FIRST_NAMES,
+    last_names: LAST_NAMES,
+    cities: CITIES,
+    regions: PROVINCES,
+    region_abbrs: PROVINCE_ABBRS,
+    street_names: STREET_NAMES,
+    street_suffixes: STREET_SUFFIXES,
+    countries: COUNTRIES,
+    postal_format: CA_POSTAL_FORMAT,
+    address_format: CA_ADDRESS_FORMAT,
+    secondary_address_format: CA_SECONDARY_ADDRESS_FORMAT,
+    phone_format: CA_PHONE_FORMAT,
+    company_prefixes: COMPANY_PREFIXES,
+    company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
+    job_titles: JOB_TITLES,
+    catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
+    catch_phrase_nouns: CATCH_PHRASE_NOUNS,
+    text_words: LOREM_WORDS,
+    tlds: TLDS,
+    free_email_domains: FREE_EMAIL_DOMAINS,
+    safe_email_domains: SAFE_EMAIL_DOMAINS,
+    color_names: COLOR_NAMES,
+    bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: CA_DATE_FORMAT,
+    number_format: CA_NUMBER_FORMAT,
+    romanized_first_names: ROMANIZED_FIRST_NAMES,
+    region_area_codes: PROVINCE_AREA_CODES,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::traits::LocaleData;
+
+    #[test]
+    fn test_en_ca_data_implements_locale_data() {
+        let data = &EN_CA_DATA;
+        assert!(data.first_names().is_some());
+        assert!(data.last_names().is_some());
+        assert!(data.cities().is_some());
+        assert!(data.regions().is_some());
+        assert!(data.romanized_first_names().is_some());
+    }
+
+    #[test]
+    fn test_ca_phone_format() {
+        let format = CA_PHONE_FORMAT;
+        assert_eq!(format.country_code, "+1");
+    }
+
+    #[test]
+    fn test_provinces_and_territories_count() {
+        assert_eq!(PROVINCES.len(), 13);
+        assert_eq!(PROVINCE_ABBRS.len(), 13);
+    }
+
+    #[test]
+    fn test_bilingual_name_pools() {
+        // Both English and French-Canadian names are present
+        assert!(FIRST_NAMES.contains(&"Emma"));
+        assert!(FIRST_NAMES.contains(&"Geneviève"));
+        assert!(LAST_NAMES.contains(&"Smith"));
+        assert!(LAST_NAMES.contains(&"Tremblay"));
+    }
+
+    #[test]
+    fn test_romanized_first_names_are_ascii() {
+        assert_eq!(FIRST_NAMES.len(), ROMANIZED_FIRST_NAMES.len());
+        assert!(ROMANIZED_FIRST_NAMES.iter().all(|n| n.is_ascii()));
+    }
+}
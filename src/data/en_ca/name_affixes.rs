@@ -0,0 +1,7 @@
+//! Canadian name prefixes and suffixes.
+
+/// Canadian name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &["Mr.", "Mrs.", "Ms.", "Dr.", "Prof.", "M.", "Mme"];
+
+/// Canadian name suffixes (generational and post-nominal).
+pub const NAME_SUFFIXES: &[&str] = &["Jr.", "Sr.", "PhD", "MD", "P.Eng."];
@@ -0,0 +1,40 @@
+//! Canadian provinces and territories.
+
+/// The 10 Canadian provinces followed by the 3 territories.
+pub const PROVINCES: &[&str] = &[
+    "Alberta",
+    "British Columbia",
+    "Manitoba",
+    "New Brunswick",
+    "Newfoundland and Labrador",
+    "Nova Scotia",
+    "Ontario",
+    "Prince Edward Island",
+    "Quebec",
+    "Saskatchewan",
+    "Northwest Territories",
+    "Nunavut",
+    "Yukon",
+];
+
+/// Canada Post abbreviations (in the same order as `PROVINCES`).
+pub const PROVINCE_ABBRS: &[&str] = &[
+    "AB", "BC", "MB", "NB", "NL", "NS", "ON", "PE", "QC", "SK", "NT", "NU", "YT",
+];
+
+/// Telephone area codes for each province (in the same order as `PROVINCES`).
+pub const PROVINCE_AREA_CODES: &[&[&str]] = &[
+    &["403", "587", "780", "825"],                             // AB
+    &["236", "250", "604", "778"],                             // BC
+    &["204", "431"],                                           // MB
+    &["506"],                                                  // NB
+    &["709"],                                                  // NL
+    &["782", "902"],                                           // NS
+    &["416", "437", "519", "613", "647", "705", "807", "905"], // ON
+    &["782", "902"],                                           // PE
+    &["418", "438", "450", "514", "579", "819"],               // QC
+    &["306", "639"],                                           // SK
+    &["867"],                                                  // NT
+    &["867"],                                                  // NU
+    &["867"],                                                  // YT
+];
@@ -0,0 +1,51 @@
+//! Canadian street names and suffixes.
+
+/// Common Canadian street names.
+pub const STREET_NAMES: &[&str] = &[
+    "Main",
+    "King",
+    "Queen",
+    "Yonge",
+    "Bloor",
+    "Dundas",
+    "Wellington",
+    "Victoria",
+    "Maple",
+    "Cedar",
+    "Pine",
+    "Elm",
+    "Church",
+    "Park",
+    "Lakeshore",
+    "Bay",
+    "Front",
+    "College",
+    "Saint-Laurent",
+    "Sainte-Catherine",
+    "Sherbrooke",
+    "Notre-Dame",
+    "Laurier",
+    "Champlain",
+    "Portage",
+    "Jasper",
+    "Granville",
+    "Robson",
+    "Whyte",
+    "Spring Garden",
+];
+
+/// Canadian street suffixes.
+pub const STREET_SUFFIXES: &[&str] = &[
+    "Street",
+    "Avenue",
+    "Road",
+    "Boulevard",
+    "Drive",
+    "Crescent",
+    "Court",
+    "Way",
+    "Lane",
+    "Place",
+    "Trail",
+    "Parkway",
+];
@@ -30,4 +30,5 @@ crate::define_company_data! {
         "frameworks", "methodologies", "synergies", "initiatives", "outcomes",
         "experiences", "innovations", "transformations", "ecosystems", "capabilities",
     ],
+    legal_forms: ["Ltd", "PLC", "LLP"],
 }
@@ -105,3 +105,6 @@ pub const FIRST_NAMES: &[&str] = &[
     "Bethany",
     "Lauren",
 ];
+
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 50;
@@ -10,23 +10,32 @@ mod companies;
 mod counties;
 mod first_names;
 mod last_names;
+mod name_affixes;
 mod streets;
 
 pub use banks::BANK_NAMES;
 pub use cities::CITIES;
 pub use color_names::COLOR_NAMES;
 pub use companies::{
-    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_PREFIXES, COMPANY_SUFFIXES, JOB_TITLES,
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_SUFFIXES, JOB_TITLES,
 };
 pub use counties::{COUNTIES, COUNTY_ABBRS};
-pub use first_names::FIRST_NAMES;
+pub use first_names::{FIRST_NAMES, MALE_FIRST_NAME_COUNT};
 pub use last_names::LAST_NAMES;
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
 pub use streets::{STREET_NAMES, STREET_SUFFIXES};
 
 // Shared data from en_US
-use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
+use super::en_us::{
+    COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, MONTH_NAMES, SAFE_EMAIL_DOMAINS, TLDS,
+    WEEKDAY_NAMES,
+};
 
-use super::formats::{AddressFormat, PhoneFormat, PostalCodeFormat};
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
 
 /// Phone format patterns for UK.
 const UK_PHONE_PATTERNS: &[&str] = &[
@@ -36,12 +45,38 @@ const UK_PHONE_PATTERNS: &[&str] = &[
     "0### #### ####",
 ];
 
+/// Mobile phone patterns for United Kingdom.
+const UK_MOBILE_PATTERNS: &[&str] = &["07### ######", "+44 7### ######"];
+
+/// Landline phone patterns for United Kingdom.
+const UK_LANDLINE_PATTERNS: &[&str] = &[
+    "01### ######",
+    "020 #### ####",
+    "0121 ### ####",
+    "+44 1### ######",
+    "+44 20 #### ####",
+];
+
+/// Fictional mobile patterns for United Kingdom (Ofcom drama range 07700 900000-900999).
+const UK_FICTIONAL_MOBILE_PATTERNS: &[&str] = &["07700 900###", "+44 7700 900###"];
+
+/// Fictional landline patterns for United Kingdom (Ofcom drama ranges).
+const UK_FICTIONAL_LANDLINE_PATTERNS: &[&str] = &[
+    "020 7946 0###",
+    "0113 496 0###",
+    "0161 496 0###",
+    "+44 20 7946 0###",
+    "+44 161 496 0###",
+];
+
 /// Postal code patterns for UK.
 /// UK postcodes follow patterns like: SW1A 1AA, M1 1AE, B33 8TH
 const UK_POSTAL_PATTERNS: &[&str] = &["AA## #AA", "AA# #AA", "A## #AA", "A# #AA"];
 
 /// UK phone format specification.
-const UK_PHONE_FORMAT: PhoneFormat = PhoneFormat::new(UK_PHONE_PATTERNS, "+44");
+const UK_PHONE_FORMAT: PhoneFormat = PhoneFormat::with_trunk_prefix(UK_PHONE_PATTERNS, "+44", "0")
+    .with_kinds(UK_MOBILE_PATTERNS, UK_LANDLINE_PATTERNS)
+    .with_fictional(UK_FICTIONAL_MOBILE_PATTERNS, UK_FICTIONAL_LANDLINE_PATTERNS);
 
 /// UK postal code format specification.
 const UK_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(UK_POSTAL_PATTERNS);
@@ -50,6 +85,27 @@ const UK_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(UK_POSTAL_PATTE
 const UK_ADDRESS_FORMAT: AddressFormat =
     AddressFormat::new("{street}\n{city}\n{region}\n{postal}", true);
 
+/// UK units come before the street (e.g., "Flat 3, 12 High Street").
+const UK_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[
+        UnitFormat::lettered("Flat {n}", 1, 99),
+        UnitFormat::new("Apartment {n}", 1, 199),
+        UnitFormat::new("Unit {n}", 1, 99),
+    ],
+    "{unit}, {street}",
+    "PO Box {n}",
+);
+
+/// UK date format (day first, 24-hour clock).
+const UK_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{dd}/{mm}/{yyyy}",
+    "{weekday} {d} {month} {yyyy}",
+    "{HH}:{MM}:{SS}",
+);
+
+/// UK number format.
+const UK_NUMBER_FORMAT: NumberFormat = NumberFormat::new(".", ",", "{symbol}{amount}");
+
 /// English (UK) locale data provider.
 pub struct EnGbData;
 
@@ -68,9 +124,11 @@ crate::impl_locale_data! {
     countries: COUNTRIES,
     postal_format: UK_POSTAL_FORMAT,
     address_format: UK_ADDRESS_FORMAT,
+    secondary_address_format: UK_SECONDARY_ADDRESS_FORMAT,
     phone_format: UK_PHONE_FORMAT,
     company_prefixes: COMPANY_PREFIXES,
     company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
     job_titles: JOB_TITLES,
     catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
     catch_phrase_nouns: CATCH_PHRASE_NOUNS,
@@ -80,6 +138,13 @@ crate::impl_locale_data! {
     safe_email_domains: SAFE_EMAIL_DOMAINS,
     color_names: COLOR_NAMES,
     bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: UK_DATE_FORMAT,
+    number_format: UK_NUMBER_FORMAT,
 }
 
 #[cfg(test)]
@@ -0,0 +1,7 @@
+//! British name prefixes and suffixes.
+
+/// British name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &["Mr", "Mrs", "Ms", "Miss", "Mx", "Dr", "Prof", "Sir", "Dame"];
+
+/// British name suffixes (generational and post-nominal).
+pub const NAME_SUFFIXES: &[&str] = &["OBE", "MBE", "CBE", "PhD", "KC"];
@@ -0,0 +1,46 @@
+//! Airline data.
+
+/// Major airlines as (IATA carrier code, name), sorted by code.
+pub const AIRLINES: &[(&str, &str)] = &[
+    ("AA", "American Airlines"),
+    ("AC", "Air Canada"),
+    ("AF", "Air France"),
+    ("AI", "Air India"),
+    ("AM", "Aeroméxico"),
+    ("AS", "Alaska Airlines"),
+    ("AY", "Finnair"),
+    ("AZ", "ITA Airways"),
+    ("B6", "JetBlue"),
+    ("BA", "British Airways"),
+    ("CA", "Air China"),
+    ("CX", "Cathay Pacific"),
+    ("DL", "Delta Air Lines"),
+    ("EI", "Aer Lingus"),
+    ("EK", "Emirates"),
+    ("ET", "Ethiopian Airlines"),
+    ("EY", "Etihad Airways"),
+    ("FR", "Ryanair"),
+    ("IB", "Iberia"),
+    ("JL", "Japan Airlines"),
+    ("KE", "Korean Air"),
+    ("KL", "KLM Royal Dutch Airlines"),
+    ("LA", "LATAM Airlines"),
+    ("LH", "Lufthansa"),
+    ("LX", "Swiss International Air Lines"),
+    ("MH", "Malaysia Airlines"),
+    ("MU", "China Eastern Airlines"),
+    ("NH", "All Nippon Airways"),
+    ("NZ", "Air New Zealand"),
+    ("OS", "Austrian Airlines"),
+    ("QF", "Qantas"),
+    ("QR", "Qatar Airways"),
+    ("SA", "South African Airways"),
+    ("SK", "Scandinavian Airlines"),
+    ("SQ", "Singapore Airlines"),
+    ("TK", "Turkish Airlines"),
+    ("TP", "TAP Air Portugal"),
+    ("U2", "easyJet"),
+    ("UA", "United Airlines"),
+    ("VS", "Virgin Atlantic"),
+    ("WN", "Southwest Airlines"),
+];
@@ -0,0 +1,239 @@
+//! Airport data.
+
+/// Major international airports as (IATA code, ICAO code, name, city,
+/// ISO 3166-1 alpha-2 country code).
+pub const AIRPORTS: &[(&str, &str, &str, &str, &str)] = &[
+    (
+        "AMS",
+        "EHAM",
+        "Amsterdam Airport Schiphol",
+        "Amsterdam",
+        "NL",
+    ),
+    (
+        "ARN",
+        "ESSA",
+        "Stockholm Arlanda Airport",
+        "Stockholm",
+        "SE",
+    ),
+    (
+        "ATL",
+        "KATL",
+        "Hartsfield-Jackson Atlanta International Airport",
+        "Atlanta",
+        "US",
+    ),
+    (
+        "BCN",
+        "LEBL",
+        "Josep Tarradellas Barcelona-El Prat Airport",
+        "Barcelona",
+        "ES",
+    ),
+    ("BKK", "VTBS", "Suvarnabhumi Airport", "Bangkok", "TH"),
+    ("BOS", "KBOS", "Logan International Airport", "Boston", "US"),
+    ("BRU", "EBBR", "Brussels Airport", "Brussels", "BE"),
+    (
+        "CDG",
+        "LFPG",
+        "Paris Charles de Gaulle Airport",
+        "Paris",
+        "FR",
+    ),
+    ("CPH", "EKCH", "Copenhagen Airport", "Copenhagen", "DK"),
+    (
+        "DEL",
+        "VIDP",
+        "Indira Gandhi International Airport",
+        "Delhi",
+        "IN",
+    ),
+    (
+        "DEN",
+        "KDEN",
+        "Denver International Airport",
+        "Denver",
+        "US",
+    ),
+    (
+        "DFW",
+        "KDFW",
+        "Dallas Fort Worth International Airport",
+        "Dallas",
+        "US",
+    ),
+    ("DUB", "EIDW", "Dublin Airport", "Dublin", "IE"),
+    ("DXB", "OMDB", "Dubai International Airport", "Dubai", "AE"),
+    (
+        "FCO",
+        "LIRF",
+        "Leonardo da Vinci-Fiumicino Airport",
+        "Rome",
+        "IT",
+    ),
+    ("FRA", "EDDF", "Frankfurt Airport", "Frankfurt", "DE"),
+    (
+        "GRU",
+        "SBGR",
+        "Sao Paulo-Guarulhos International Airport",
+        "Sao Paulo",
+        "BR",
+    ),
+    ("HEL", "EFHK", "Helsinki Airport", "Helsinki", "FI"),
+    (
+        "HKG",
+        "VHHH",
+        "Hong Kong International Airport",
+        "Hong Kong",
+        "HK",
+    ),
+    ("HND", "RJTT", "Tokyo Haneda Airport", "Tokyo", "JP"),
+    (
+        "IAD",
+        "KIAD",
+        "Washington Dulles International Airport",
+        "Washington",
+        "US",
+    ),
+    (
+        "ICN",
+        "RKSI",
+        "Incheon International Airport",
+        "Seoul",
+        "KR",
+    ),
+    ("IST", "LTFM", "Istanbul Airport", "Istanbul", "TR"),
+    (
+        "JFK",
+        "KJFK",
+        "John F. Kennedy International Airport",
+        "New York",
+        "US",
+    ),
+    (
+        "JNB",
+        "FAOR",
+        "O. R. Tambo International Airport",
+        "Johannesburg",
+        "ZA",
+    ),
+    ("KIX", "RJBB", "Kansai International Airport", "Osaka", "JP"),
+    (
+        "LAS",
+        "KLAS",
+        "Harry Reid International Airport",
+        "Las Vegas",
+        "US",
+    ),
+    (
+        "LAX",
+        "KLAX",
+        "Los Angeles International Airport",
+        "Los Angeles",
+        "US",
+    ),
+    ("LGW", "EGKK", "London Gatwick Airport", "London", "GB"),
+    ("LHR", "EGLL", "London Heathrow Airport", "London", "GB"),
+    ("LIS", "LPPT", "Humberto Delgado Airport", "Lisbon", "PT"),
+    (
+        "MAD",
+        "LEMD",
+        "Adolfo Suarez Madrid-Barajas Airport",
+        "Madrid",
+        "ES",
+    ),
+    ("MAN", "EGCC", "Manchester Airport", "Manchester", "GB"),
+    (
+        "MEX",
+        "MMMX",
+        "Mexico City International Airport",
+        "Mexico City",
+        "MX",
+    ),
+    ("MIA", "KMIA", "Miami International Airport", "Miami", "US"),
+    ("MUC", "EDDM", "Munich Airport", "Munich", "DE"),
+    ("MXP", "LIMC", "Milan Malpensa Airport", "Milan", "IT"),
+    ("NRT", "RJAA", "Narita International Airport", "Tokyo", "JP"),
+    (
+        "ORD",
+        "KORD",
+        "O'Hare International Airport",
+        "Chicago",
+        "US",
+    ),
+    ("OSL", "ENGM", "Oslo Airport, Gardermoen", "Oslo", "NO"),
+    (
+        "PEK",
+        "ZBAA",
+        "Beijing Capital International Airport",
+        "Beijing",
+        "CN",
+    ),
+    (
+        "PVG",
+        "ZSPD",
+        "Shanghai Pudong International Airport",
+        "Shanghai",
+        "CN",
+    ),
+    (
+        "SEA",
+        "KSEA",
+        "Seattle-Tacoma International Airport",
+        "Seattle",
+        "US",
+    ),
+    (
+        "SFO",
+        "KSFO",
+        "San Francisco International Airport",
+        "San Francisco",
+        "US",
+    ),
+    ("SIN", "WSSS", "Singapore Changi Airport", "Singapore", "SG"),
+    (
+        "SVO",
+        "UUEE",
+        "Sheremetyevo International Airport",
+        "Moscow",
+        "RU",
+    ),
+    (
+        "SYD",
+        "YSSY",
+        "Sydney Kingsford Smith Airport",
+        "Sydney",
+        "AU",
+    ),
+    (
+        "VIE",
+        "LOWW",
+        "Vienna International Airport",
+        "Vienna",
+        "AT",
+    ),
+    ("WAW", "EPWA", "Warsaw Chopin Airport", "Warsaw", "PL"),
+    (
+        "YUL",
+        "CYUL",
+        "Montreal-Trudeau International Airport",
+        "Montreal",
+        "CA",
+    ),
+    (
+        "YVR",
+        "CYVR",
+        "Vancouver International Airport",
+        "Vancouver",
+        "CA",
+    ),
+    (
+        "YYZ",
+        "CYYZ",
+        "Toronto Pearson International Airport",
+        "Toronto",
+        "CA",
+    ),
+    ("ZRH", "LSZH", "Zurich Airport", "Zurich", "CH"),
+];
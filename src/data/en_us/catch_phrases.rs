@@ -0,0 +1,163 @@
+//! Catch phrase pools for the buzzword, startup and slogan styles.
+
+/// Verbs that open a corporate buzzword phrase.
+pub const BUZZWORD_VERBS: &[&str] = &[
+    "Leverage",
+    "Streamline",
+    "Synergize",
+    "Incentivize",
+    "Monetize",
+    "Orchestrate",
+    "Empower",
+    "Optimize",
+    "Reinvent",
+    "Disrupt",
+    "Scale",
+    "Unlock",
+    "Operationalize",
+    "Harness",
+    "Transform",
+    "Accelerate",
+    "Drive",
+    "Enable",
+    "Architect",
+    "Productize",
+];
+
+/// Adjectives in the middle of a corporate buzzword phrase.
+pub const BUZZWORD_ADJECTIVES: &[&str] = &[
+    "best-of-breed",
+    "cross-functional",
+    "customer-centric",
+    "data-driven",
+    "end-to-end",
+    "enterprise-grade",
+    "frictionless",
+    "holistic",
+    "mission-critical",
+    "next-generation",
+    "omnichannel",
+    "proactive",
+    "robust",
+    "scalable",
+    "seamless",
+    "strategic",
+    "turnkey",
+    "value-added",
+    "vertical",
+    "world-class",
+];
+
+/// Plural nouns that close a corporate buzzword phrase.
+pub const BUZZWORD_NOUNS: &[&str] = &[
+    "synergies",
+    "paradigms",
+    "deliverables",
+    "touchpoints",
+    "ecosystems",
+    "workflows",
+    "value propositions",
+    "core competencies",
+    "action items",
+    "mindshare",
+    "bandwidth",
+    "learnings",
+    "verticals",
+    "stakeholder journeys",
+    "best practices",
+    "growth levers",
+    "KPIs",
+    "roadmaps",
+    "platforms",
+    "efficiencies",
+];
+
+/// Qualifiers that open a startup pitch.
+pub const STARTUP_QUALIFIERS: &[&str] = &[
+    "AI-powered",
+    "Open-source",
+    "Decentralized",
+    "On-demand",
+    "No-code",
+    "Privacy-first",
+    "Real-time",
+    "Subscription",
+    "Peer-to-peer",
+    "Carbon-neutral",
+    "Mobile-first",
+    "Community-driven",
+    "API-first",
+    "Self-serve",
+    "Automated",
+];
+
+/// Products a startup pitch offers.
+pub const STARTUP_PRODUCTS: &[&str] = &[
+    "platform",
+    "marketplace",
+    "app",
+    "copilot",
+    "network",
+    "toolkit",
+    "operating system",
+    "analytics suite",
+    "payments layer",
+    "scheduling assistant",
+    "booking engine",
+    "CRM",
+];
+
+/// Audiences a startup pitch is for, also used by "Built for ..." slogans.
+pub const STARTUP_AUDIENCES: &[&str] = &[
+    "remote teams",
+    "freelancers",
+    "dog owners",
+    "independent restaurants",
+    "small landlords",
+    "busy parents",
+    "indie game studios",
+    "community clinics",
+    "local gyms",
+    "first-time founders",
+    "craft breweries",
+    "field technicians",
+    "wedding planners",
+    "online tutors",
+    "urban gardeners",
+];
+
+/// Imperative verbs for "Think bigger."-style slogans.
+pub const SLOGAN_VERBS: &[&str] = &[
+    "Think", "Build", "Move", "Dream", "Work", "Live", "Create", "Grow", "Go", "Reach",
+];
+
+/// Comparatives that follow a slogan verb.
+pub const SLOGAN_COMPARATIVES: &[&str] = &[
+    "bigger", "smarter", "faster", "further", "better", "bolder", "brighter", "simpler",
+];
+
+/// Subjects for "... made simple." slogans.
+pub const SLOGAN_SUBJECTS: &[&str] = &[
+    "Banking",
+    "Travel",
+    "Payroll",
+    "Learning",
+    "Shopping",
+    "Hiring",
+    "Healthcare",
+    "Insurance",
+    "Moving",
+    "Investing",
+];
+
+/// Qualities for "... by design." slogans.
+pub const SLOGAN_QUALITIES: &[&str] = &[
+    "Secure",
+    "Simple",
+    "Beautiful",
+    "Sustainable",
+    "Human",
+    "Fast",
+    "Private",
+    "Reliable",
+];
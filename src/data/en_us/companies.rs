@@ -72,4 +72,5 @@ crate::define_company_data! {
         "task-force", "throughput", "time-frame", "toolset", "utilization", "website",
         "workforce",
     ],
+    legal_forms: ["Inc", "LLC", "Corp", "Co", "LP"],
 }
@@ -0,0 +1,39 @@
+//! Rough country bounding boxes.
+
+/// Approximate bounding boxes by ISO 3166-1 alpha-2 code, as
+/// (code, min_lat, max_lat, min_lon, max_lon).
+///
+/// Boxes cover the mainland (the contiguous states for the US, metropolitan
+/// France) so generated points fall in or near the country.
+pub const COUNTRY_BOUNDS: &[(&str, f64, f64, f64, f64)] = &[
+    ("AR", -55.1, -21.8, -73.6, -53.6),
+    ("AT", 46.4, 49.0, 9.5, 17.2),
+    ("AU", -43.6, -10.7, 113.3, 153.6),
+    ("BE", 49.5, 51.5, 2.5, 6.4),
+    ("BR", -33.8, 5.3, -74.0, -34.8),
+    ("CA", 41.7, 83.1, -141.0, -52.6),
+    ("CH", 45.8, 47.8, 5.9, 10.5),
+    ("CN", 18.2, 53.6, 73.5, 134.8),
+    ("DE", 47.3, 55.1, 5.9, 15.0),
+    ("DK", 54.6, 57.8, 8.1, 12.7),
+    ("EG", 22.0, 31.7, 24.7, 36.9),
+    ("ES", 36.0, 43.8, -9.3, 4.3),
+    ("FI", 59.8, 70.1, 20.6, 31.6),
+    ("FR", 41.3, 51.1, -5.1, 9.6),
+    ("GB", 49.9, 58.7, -8.2, 1.8),
+    ("IE", 51.4, 55.4, -10.5, -6.0),
+    ("IN", 6.7, 35.5, 68.1, 97.4),
+    ("IT", 36.6, 47.1, 6.6, 18.5),
+    ("JP", 24.0, 45.6, 122.9, 146.0),
+    ("KR", 33.1, 38.6, 124.6, 131.9),
+    ("MX", 14.5, 32.7, -117.1, -86.7),
+    ("NL", 50.8, 53.6, 3.4, 7.2),
+    ("NO", 58.0, 71.2, 4.6, 31.1),
+    ("NZ", -47.3, -34.4, 166.4, 178.6),
+    ("PL", 49.0, 54.8, 14.1, 24.2),
+    ("PT", 36.9, 42.2, -9.5, -6.2),
+    ("RU", 41.2, 81.9, 19.6, 180.0),
+    ("SE", 55.3, 69.1, 11.1, 24.2),
+    ("US", 24.5, 49.4, -124.8, -66.9),
+    ("ZA", -34.8, -22.1, 16.5, 32.9),
+];
@@ -0,0 +1,113 @@
+//! ISO 3166-1 country code data.
+
+/// ISO 3166-1 codes as (English name, alpha-2, alpha-3, numeric), in the
+/// same order as `COUNTRIES`.
+pub const COUNTRY_CODES: &[(&str, &str, &str, &str)] = &[
+    ("Afghanistan", "AF", "AFG", "004"),
+    ("Albania", "AL", "ALB", "008"),
+    ("Algeria", "DZ", "DZA", "012"),
+    ("Argentina", "AR", "ARG", "032"),
+    ("Australia", "AU", "AUS", "036"),
+    ("Austria", "AT", "AUT", "040"),
+    ("Bangladesh", "BD", "BGD", "050"),
+    ("Belgium", "BE", "BEL", "056"),
+    ("Brazil", "BR", "BRA", "076"),
+    ("Bulgaria", "BG", "BGR", "100"),
+    ("Cambodia", "KH", "KHM", "116"),
+    ("Cameroon", "CM", "CMR", "120"),
+    ("Canada", "CA", "CAN", "124"),
+    ("Chile", "CL", "CHL", "152"),
+    ("China", "CN", "CHN", "156"),
+    ("Colombia", "CO", "COL", "170"),
+    ("Croatia", "HR", "HRV", "191"),
+    ("Cuba", "CU", "CUB", "192"),
+    ("Czech Republic", "CZ", "CZE", "203"),
+    ("Denmark", "DK", "DNK", "208"),
+    ("Dominican Republic", "DO", "DOM", "214"),
+    ("Ecuador", "EC", "ECU", "218"),
+    ("Egypt", "EG", "EGY", "818"),
+    ("El Salvador", "SV", "SLV", "222"),
+    ("Estonia", "EE", "EST", "233"),
+    ("Ethiopia", "ET", "ETH", "231"),
+    ("Finland", "FI", "FIN", "246"),
+    ("France", "FR", "FRA", "250"),
+    ("Germany", "DE", "DEU", "276"),
+    ("Ghana", "GH", "GHA", "288"),
+    ("Greece", "GR", "GRC", "300"),
+    ("Guatemala", "GT", "GTM", "320"),
+    ("Haiti", "HT", "HTI", "332"),
+    ("Honduras", "HN", "HND", "340"),
+    ("Hungary", "HU", "HUN", "348"),
+    ("Iceland", "IS", "ISL", "352"),
+    ("India", "IN", "IND", "356"),
+    ("Indonesia", "ID", "IDN", "360"),
+    ("Iran", "IR", "IRN", "364"),
+    ("Iraq", "IQ", "IRQ", "368"),
+    ("Ireland", "IE", "IRL", "372"),
+    ("Israel", "IL", "ISR", "376"),
+    ("Italy", "IT", "ITA", "380"),
+    ("Jamaica", "JM", "JAM", "388"),
+    ("Japan", "JP", "JPN", "392"),
+    ("Jordan", "JO", "JOR", "400"),
+    ("Kazakhstan", "KZ", "KAZ", "398"),
+    ("Kenya", "KE", "KEN", "404"),
+    ("Kuwait", "KW", "KWT", "414"),
+    ("Latvia", "LV", "LVA", "428"),
+    ("Lebanon", "LB", "LBN", "422"),
+    ("Libya", "LY", "LBY", "434"),
+    ("Lithuania", "LT", "LTU", "440"),
+    ("Luxembourg", "LU", "LUX", "442"),
+    ("Malaysia", "MY", "MYS", "458"),
+    ("Mexico", "MX", "MEX", "484"),
+    ("Morocco", "MA", "MAR", "504"),
+    ("Myanmar", "MM", "MMR", "104"),
+    ("Nepal", "NP", "NPL", "524"),
+    ("Netherlands", "NL", "NLD", "528"),
+    ("New Zealand", "NZ", "NZL", "554"),
+    ("Nicaragua", "NI", "NIC", "558"),
+    ("Nigeria", "NG", "NGA", "566"),
+    ("North Korea", "KP", "PRK", "408"),
+    ("Norway", "NO", "NOR", "578"),
+    ("Pakistan", "PK", "PAK", "586"),
+    ("Panama", "PA", "PAN", "591"),
+    ("Paraguay", "PY", "PRY", "600"),
+    ("Peru", "PE", "PER", "604"),
+    ("Philippines", "PH", "PHL", "608"),
+    ("Poland", "PL", "POL", "616"),
+    ("Portugal", "PT", "PRT", "620"),
+    ("Qatar", "QA", "QAT", "634"),
+    ("Romania", "RO", "ROU", "642"),
+    ("Russia", "RU", "RUS", "643"),
+    ("Saudi Arabia", "SA", "SAU", "682"),
+    ("Senegal", "SN", "SEN", "686"),
+    ("Serbia", "RS", "SRB", "688"),
+    ("Singapore", "SG", "SGP", "702"),
+    ("Slovakia", "SK", "SVK", "703"),
+    ("Slovenia", "SI", "SVN", "705"),
+    ("Somalia", "SO", "SOM", "706"),
+    ("South Africa", "ZA", "ZAF", "710"),
+    ("South Korea", "KR", "KOR", "410"),
+    ("Spain", "ES", "ESP", "724"),
+    ("Sri Lanka", "LK", "LKA", "144"),
+    ("Sudan", "SD", "SDN", "729"),
+    ("Sweden", "SE", "SWE", "752"),
+    ("Switzerland", "CH", "CHE", "756"),
+    ("Syria", "SY", "SYR", "760"),
+    ("Taiwan", "TW", "TWN", "158"),
+    ("Tanzania", "TZ", "TZA", "834"),
+    ("Thailand", "TH", "THA", "764"),
+    ("Tunisia", "TN", "TUN", "788"),
+    ("Turkey", "TR", "TUR", "792"),
+    ("Uganda", "UG", "UGA", "800"),
+    ("Ukraine", "UA", "UKR", "804"),
+    ("United Arab Emirates", "AE", "ARE", "784"),
+    ("United Kingdom", "GB", "GBR", "826"),
+    ("United States", "US", "USA", "840"),
+    ("Uruguay", "UY", "URY", "858"),
+    ("Uzbekistan", "UZ", "UZB", "860"),
+    ("Venezuela", "VE", "VEN", "862"),
+    ("Vietnam", "VN", "VNM", "704"),
+    ("Yemen", "YE", "YEM", "887"),
+    ("Zambia", "ZM", "ZMB", "894"),
+    ("Zimbabwe", "ZW", "ZWE", "716"),
+];
@@ -0,0 +1,49 @@
+//! Currency data (ISO 4217).
+
+/// Currencies as (ISO 4217 code, English name, symbol).
+pub const CURRENCIES: &[(&str, &str, &str)] = &[
+    ("AED", "UAE Dirham", "د.إ"),
+    ("ARS", "Argentine Peso", "$"),
+    ("AUD", "Australian Dollar", "A$"),
+    ("BGN", "Bulgarian Lev", "лв"),
+    ("BRL", "Brazilian Real", "R$"),
+    ("CAD", "Canadian Dollar", "C$"),
+    ("CHF", "Swiss Franc", "CHF"),
+    ("CLP", "Chilean Peso", "$"),
+    ("CNY", "Chinese Yuan", "¥"),
+    ("COP", "Colombian Peso", "$"),
+    ("CZK", "Czech Koruna", "Kč"),
+    ("DKK", "Danish Krone", "kr"),
+    ("EGP", "Egyptian Pound", "E£"),
+    ("EUR", "Euro", "€"),
+    ("GBP", "Pound Sterling", "£"),
+    ("HKD", "Hong Kong Dollar", "HK$"),
+    ("HUF", "Hungarian Forint", "Ft"),
+    ("IDR", "Indonesian Rupiah", "Rp"),
+    ("ILS", "Israeli New Shekel", "₪"),
+    ("INR", "Indian Rupee", "₹"),
+    ("ISK", "Icelandic Króna", "kr"),
+    ("JPY", "Japanese Yen", "¥"),
+    ("KES", "Kenyan Shilling", "KSh"),
+    ("KRW", "South Korean Won", "₩"),
+    ("MXN", "Mexican Peso", "MX$"),
+    ("MYR", "Malaysian Ringgit", "RM"),
+    ("NGN", "Nigerian Naira", "₦"),
+    ("NOK", "Norwegian Krone", "kr"),
+    ("NZD", "New Zealand Dollar", "NZ$"),
+    ("PHP", "Philippine Peso", "₱"),
+    ("PKR", "Pakistani Rupee", "₨"),
+    ("PLN", "Polish Złoty", "zł"),
+    ("RON", "Romanian Leu", "lei"),
+    ("RUB", "Russian Ruble", "₽"),
+    ("SAR", "Saudi Riyal", "﷼"),
+    ("SEK", "Swedish Krona", "kr"),
+    ("SGD", "Singapore Dollar", "S$"),
+    ("THB", "Thai Baht", "฿"),
+    ("TRY", "Turkish Lira", "₺"),
+    ("TWD", "New Taiwan Dollar", "NT$"),
+    ("UAH", "Ukrainian Hryvnia", "₴"),
+    ("USD", "US Dollar", "$"),
+    ("VND", "Vietnamese Dong", "₫"),
+    ("ZAR", "South African Rand", "R"),
+];
@@ -208,6 +208,9 @@ pub const FIRST_NAMES: &[&str] = &[
     "Lori",
 ];
 
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 100;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,94 @@
+//! Industry names, departments and industry classification codes.
+
+/// Common industry names, used for company profiles and `industry()`.
+pub const INDUSTRIES: &[&str] = &[
+    "Accounting",
+    "Advertising",
+    "Aerospace",
+    "Agriculture",
+    "Automotive",
+    "Banking",
+    "Biotechnology",
+    "Chemicals",
+    "Construction",
+    "Consulting",
+    "Consumer Electronics",
+    "Cybersecurity",
+    "Defense",
+    "E-commerce",
+    "Education",
+    "Energy",
+    "Entertainment",
+    "Environmental Services",
+    "Fashion",
+    "Financial Services",
+    "Food and Beverage",
+    "Healthcare",
+    "Hospitality",
+    "Insurance",
+    "Investment Management",
+    "Legal Services",
+    "Logistics",
+    "Manufacturing",
+    "Media",
+    "Mining",
+    "Pharmaceuticals",
+    "Real Estate",
+    "Renewable Energy",
+    "Retail",
+    "Semiconductors",
+    "Software",
+    "Telecommunications",
+    "Transportation",
+    "Travel",
+    "Utilities",
+];
+
+/// Departments found in a typical organization chart.
+pub const DEPARTMENTS: &[&str] = &[
+    "Accounting",
+    "Administration",
+    "Business Development",
+    "Compliance",
+    "Customer Service",
+    "Customer Success",
+    "Data Science",
+    "Design",
+    "Engineering",
+    "Facilities",
+    "Finance",
+    "Human Resources",
+    "Information Technology",
+    "Internal Audit",
+    "Legal",
+    "Logistics",
+    "Marketing",
+    "Operations",
+    "Procurement",
+    "Product Management",
+    "Public Relations",
+    "Quality Assurance",
+    "Research and Development",
+    "Risk Management",
+    "Sales",
+    "Security",
+    "Strategy",
+    "Supply Chain",
+    "Training",
+];
+
+/// Two-digit NAICS 2022 sector codes.
+pub const NAICS_SECTORS: &[&str] = &[
+    "11", "21", "22", "23", "31", "32", "33", "42", "44", "45", "48", "49", "51", "52", "53", "54",
+    "55", "56", "61", "62", "71", "72", "81", "92",
+];
+
+/// Two-digit SIC major group codes in use.
+pub const SIC_MAJOR_GROUPS: &[&str] = &[
+    "01", "02", "07", "08", "09", "10", "12", "13", "14", "15", "16", "17", "20", "21", "22", "23",
+    "24", "25", "26", "27", "28", "29", "30", "31", "32", "33", "34", "35", "36", "37", "38", "39",
+    "40", "41", "42", "44", "45", "46", "47", "48", "49", "50", "51", "52", "53", "54", "55", "56",
+    "57", "58", "59", "60", "61", "62", "63", "64", "65", "67", "70", "72", "73", "75", "76", "78",
+    "79", "80", "81", "82", "83", "84", "86", "87", "88", "89", "91", "92", "93", "94", "95", "96",
+    "97", "99",
+];
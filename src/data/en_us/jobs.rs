@@ -0,0 +1,73 @@
+//! Job title components.
+
+/// Job areas with the department they report into and the roles found in
+/// them, so composed titles read naturally ("Senior Data Scientist",
+/// "Director of Engineering").
+pub const JOB_AREAS: &[(&str, &str, &[&str])] = &[
+    (
+        "Software",
+        "Engineering",
+        &["Engineer", "Developer", "Architect"],
+    ),
+    ("Data", "Data", &["Scientist", "Analyst", "Engineer"]),
+    ("DevOps", "Infrastructure", &["Engineer"]),
+    ("Network", "Infrastructure", &["Engineer", "Administrator"]),
+    ("Security", "Security", &["Engineer", "Analyst"]),
+    ("QA", "Quality Assurance", &["Engineer", "Analyst"]),
+    ("Product", "Product", &["Designer", "Analyst", "Owner"]),
+    ("UX", "Design", &["Designer", "Researcher", "Writer"]),
+    (
+        "Marketing",
+        "Marketing",
+        &["Specialist", "Analyst", "Coordinator", "Strategist"],
+    ),
+    ("Content", "Marketing", &["Writer", "Strategist", "Editor"]),
+    (
+        "Sales",
+        "Sales",
+        &["Representative", "Executive", "Engineer"],
+    ),
+    ("Financial", "Finance", &["Analyst", "Planner"]),
+    ("Tax", "Finance", &["Accountant", "Specialist"]),
+    (
+        "HR",
+        "Human Resources",
+        &["Generalist", "Specialist", "Coordinator"],
+    ),
+    (
+        "Recruiting",
+        "Human Resources",
+        &["Coordinator", "Specialist"],
+    ),
+    (
+        "Customer Success",
+        "Customer Success",
+        &["Manager", "Specialist"],
+    ),
+    ("Support", "Customer Support", &["Engineer", "Specialist"]),
+    (
+        "Operations",
+        "Operations",
+        &["Analyst", "Coordinator", "Specialist"],
+    ),
+    ("Supply Chain", "Operations", &["Analyst", "Planner"]),
+    ("Legal", "Legal", &["Counsel", "Analyst"]),
+    ("Compliance", "Legal", &["Analyst", "Officer"]),
+    ("Research", "Research", &["Scientist", "Associate"]),
+];
+
+/// C-suite titles used for executive-level jobs.
+pub const CHIEF_TITLES: &[&str] = &[
+    "Chief Executive Officer",
+    "Chief Operating Officer",
+    "Chief Financial Officer",
+    "Chief Technology Officer",
+    "Chief Information Officer",
+    "Chief Information Security Officer",
+    "Chief Marketing Officer",
+    "Chief Revenue Officer",
+    "Chief Product Officer",
+    "Chief Data Officer",
+    "Chief People Officer",
+    "Chief Legal Officer",
+];
@@ -0,0 +1,61 @@
+//! Allergy and medication names.
+
+/// Common allergies, covering food, drug and environmental allergens.
+pub const ALLERGIES: &[&str] = &[
+    "Peanuts",
+    "Tree nuts",
+    "Milk",
+    "Eggs",
+    "Wheat",
+    "Soy",
+    "Fish",
+    "Shellfish",
+    "Sesame",
+    "Penicillin",
+    "Amoxicillin",
+    "Sulfonamides",
+    "Aspirin",
+    "Ibuprofen",
+    "Codeine",
+    "Latex",
+    "Pollen",
+    "Dust mites",
+    "Mold",
+    "Cat dander",
+    "Dog dander",
+    "Bee stings",
+];
+
+/// Commonly prescribed medications (generic names).
+pub const MEDICATIONS: &[&str] = &[
+    "Atorvastatin",
+    "Levothyroxine",
+    "Metformin",
+    "Lisinopril",
+    "Amlodipine",
+    "Metoprolol",
+    "Albuterol",
+    "Omeprazole",
+    "Losartan",
+    "Simvastatin",
+    "Gabapentin",
+    "Hydrochlorothiazide",
+    "Sertraline",
+    "Rosuvastatin",
+    "Montelukast",
+    "Escitalopram",
+    "Pantoprazole",
+    "Furosemide",
+    "Bupropion",
+    "Fluoxetine",
+    "Insulin glargine",
+    "Prednisone",
+    "Tamsulosin",
+    "Citalopram",
+    "Warfarin",
+    "Clopidogrel",
+    "Meloxicam",
+    "Trazodone",
+    "Cetirizine",
+    "Amoxicillin",
+];
@@ -2,42 +2,81 @@
 //!
 //! Contains name lists and other data for en_US locale.
 
+mod airlines;
+mod airports;
 mod banks;
+mod calendar;
+mod catch_phrases;
 mod cities;
 mod color_names;
 mod companies;
 mod countries;
+mod country_bounds;
+mod country_codes;
+mod currencies;
 mod first_names;
+mod industries;
+mod jobs;
+mod languages;
 mod last_names;
 mod lorem;
+mod medical;
+mod name_affixes;
+mod products;
 mod states;
 mod streets;
 mod tlds;
+mod vehicles;
 
+pub use airlines::AIRLINES;
+pub use airports::AIRPORTS;
 pub use banks::BANK_NAMES;
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
+pub use catch_phrases::{
+    BUZZWORD_ADJECTIVES, BUZZWORD_NOUNS, BUZZWORD_VERBS, SLOGAN_COMPARATIVES, SLOGAN_QUALITIES,
+    SLOGAN_SUBJECTS, SLOGAN_VERBS, STARTUP_AUDIENCES, STARTUP_PRODUCTS, STARTUP_QUALIFIERS,
+};
 pub use cities::CITIES;
 pub use color_names::COLOR_NAMES;
 pub use companies::{
-    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_PREFIXES, COMPANY_SUFFIXES, JOB_TITLES,
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_SUFFIXES, JOB_TITLES,
 };
 pub use countries::COUNTRIES;
-pub use first_names::FIRST_NAMES;
+pub use country_bounds::COUNTRY_BOUNDS;
+pub use country_codes::COUNTRY_CODES;
+pub use currencies::CURRENCIES;
+pub use first_names::{FIRST_NAMES, MALE_FIRST_NAME_COUNT};
+pub use industries::{DEPARTMENTS, INDUSTRIES, NAICS_SECTORS, SIC_MAJOR_GROUPS};
+pub use jobs::{CHIEF_TITLES, JOB_AREAS};
+pub use languages::LANGUAGES;
 pub use last_names::LAST_NAMES;
 pub use lorem::LOREM_WORDS;
-pub use states::{STATES, STATE_ABBRS};
+pub use medical::{ALLERGIES, MEDICATIONS};
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
+pub use products::{PRODUCT_ADJECTIVES, PRODUCT_MATERIALS, PRODUCT_NOUNS};
+pub use states::{STATES, STATE_ABBRS, STATE_AREA_CODES, STATE_COUNTIES, STATE_ZIP_PREFIXES};
 pub use streets::{STREET_NAMES, STREET_SUFFIXES};
 pub use tlds::{FREE_EMAIL_DOMAINS, SAFE_EMAIL_DOMAINS, TLDS};
+pub use vehicles::{VehicleMake, VEHICLE_MAKES};
 
-use super::formats::{AddressFormat, PhoneFormat, PostalCodeFormat};
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
 
 /// Phone format patterns for US.
 const US_PHONE_PATTERNS: &[&str] = &["(###) ###-####", "###-###-####", "+1 (###) ###-####"];
 
+/// Fictional phone patterns for US: 555-0100 through 555-0199 in any area code.
+const US_FICTIONAL_PATTERNS: &[&str] = &["(###) 555-01##", "###-555-01##", "+1 (###) 555-01##"];
+
 /// Postal code patterns for US (ZIP codes).
 const US_POSTAL_PATTERNS: &[&str] = &["#####", "#####-####"];
 
 /// US phone format specification.
-const US_PHONE_FORMAT: PhoneFormat = PhoneFormat::new(US_PHONE_PATTERNS, "+1");
+const US_PHONE_FORMAT: PhoneFormat = PhoneFormat::new(US_PHONE_PATTERNS, "+1")
+    .with_fictional(US_FICTIONAL_PATTERNS, US_FICTIONAL_PATTERNS);
 
 /// US postal code format specification.
 const US_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(US_POSTAL_PATTERNS);
@@ -46,6 +85,27 @@ const US_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(US_POSTAL_PATTE
 const US_ADDRESS_FORMAT: AddressFormat =
     AddressFormat::new("{street}, {city}, {region_abbr} {postal}", true);
 
+/// US units follow the street (e.g., "12 Main Street Apt 4B").
+pub(crate) const US_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[
+        UnitFormat::lettered("Apt {n}", 1, 999),
+        UnitFormat::new("Suite {n}", 100, 999),
+        UnitFormat::new("Unit {n}", 1, 99),
+    ],
+    "{street} {unit}",
+    "PO Box {n}",
+);
+
+/// US date format (month first, 12-hour clock).
+const US_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{mm}/{dd}/{yyyy}",
+    "{weekday}, {month} {d}, {yyyy}",
+    "{h}:{MM}:{SS} {ampm}",
+);
+
+/// US number format.
+const US_NUMBER_FORMAT: NumberFormat = NumberFormat::new(".", ",", "{symbol}{amount}");
+
 /// English (US) locale data provider.
 pub struct EnUsData;
 
@@ -64,9 +124,11 @@ crate::impl_locale_data! {
     countries: COUNTRIES,
     postal_format: US_POSTAL_FORMAT,
     address_format: US_ADDRESS_FORMAT,
+    secondary_address_format: US_SECONDARY_ADDRESS_FORMAT,
     phone_format: US_PHONE_FORMAT,
     company_prefixes: COMPANY_PREFIXES,
     company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
     job_titles: JOB_TITLES,
     catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
     catch_phrase_nouns: CATCH_PHRASE_NOUNS,
@@ -76,6 +138,15 @@ crate::impl_locale_data! {
     safe_email_domains: SAFE_EMAIL_DOMAINS,
     color_names: COLOR_NAMES,
     bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: US_DATE_FORMAT,
+    number_format: US_NUMBER_FORMAT,
+    region_area_codes: STATE_AREA_CODES,
+    region_zip_prefixes: STATE_ZIP_PREFIXES,
 }
 
 #[cfg(test)]
@@ -109,6 +180,8 @@ mod tests {
         assert!(data.free_email_domains().is_some());
         assert!(data.safe_email_domains().is_some());
         assert!(data.color_names().is_some());
+        assert!(data.name_prefixes().is_some());
+        assert!(data.name_suffixes().is_some());
     }
 
     #[test]
@@ -0,0 +1,7 @@
+//! American name prefixes and suffixes.
+
+/// American name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &["Mr.", "Mrs.", "Ms.", "Miss", "Dr.", "Prof."];
+
+/// American name suffixes (generational and post-nominal).
+pub const NAME_SUFFIXES: &[&str] = &["Jr.", "Sr.", "II", "III", "IV", "MD", "PhD", "DDS"];
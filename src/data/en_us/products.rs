@@ -0,0 +1,47 @@
+//! Product name components.
+
+/// Adjectives that open a product name.
+pub const PRODUCT_ADJECTIVES: &[&str] = &[
+    "Awesome",
+    "Compact",
+    "Deluxe",
+    "Durable",
+    "Elegant",
+    "Ergonomic",
+    "Fantastic",
+    "Gorgeous",
+    "Handcrafted",
+    "Handmade",
+    "Heavy Duty",
+    "Incredible",
+    "Intelligent",
+    "Lightweight",
+    "Luxurious",
+    "Modern",
+    "Practical",
+    "Premium",
+    "Refined",
+    "Rustic",
+    "Sleek",
+    "Small",
+    "Smart",
+    "Sturdy",
+    "Synergistic",
+    "Tasty",
+    "Unbranded",
+    "Vintage",
+];
+
+/// Materials placed between the adjective and the noun.
+pub const PRODUCT_MATERIALS: &[&str] = &[
+    "Aluminum", "Bamboo", "Bronze", "Ceramic", "Concrete", "Copper", "Cotton", "Fresh", "Frozen",
+    "Glass", "Granite", "Iron", "Leather", "Linen", "Marble", "Metal", "Oak", "Plastic", "Rubber",
+    "Silk", "Soft", "Steel", "Walnut", "Wooden", "Wool",
+];
+
+/// Nouns that end a product name.
+pub const PRODUCT_NOUNS: &[&str] = &[
+    "Bag", "Ball", "Bench", "Bike", "Bottle", "Bowl", "Car", "Chair", "Cheese", "Chicken", "Clock",
+    "Coat", "Computer", "Gloves", "Hat", "Keyboard", "Lamp", "Mouse", "Pants", "Pizza", "Salad",
+    "Sausages", "Shirt", "Shoes", "Soap", "Table", "Towels", "Tuna", "Wallet", "Watch",
+];
@@ -61,3 +61,489 @@ pub const STATE_ABBRS: &[&str] = &[
     "NC", "ND", "OH", "OK", "OR", "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VT", "VA", "WA", "WV",
     "WI", "WY",
 ];
+
+/// Telephone area codes for each state (in the same order as `STATES`).
+pub const STATE_AREA_CODES: &[&[&str]] = &[
+    &["205", "251", "256", "334"],                             // AL
+    &["907"],                                                  // AK
+    &["480", "520", "602", "623", "928"],                      // AZ
+    &["479", "501", "870"],                                    // AR
+    &["213", "310", "415", "510", "619", "714", "818", "916"], // CA
+    &["303", "719", "720", "970"],                             // CO
+    &["203", "860"],                                           // CT
+    &["302"],                                                  // DE
+    &["305", "321", "407", "561", "727", "813", "904", "954"], // FL
+    &["404", "478", "678", "706", "770", "912"],               // GA
+    &["808"],                                                  // HI
+    &["208"],                                                  // ID
+    &["217", "309", "312", "630", "773", "847"],               // IL
+    &["219", "317", "574", "765", "812"],                      // IN
+    &["319", "515", "563", "712"],                             // IA
+    &["316", "620", "785", "913"],                             // KS
+    &["270", "502", "606", "859"],                             // KY
+    &["225", "318", "337", "504", "985"],                      // LA
+    &["207"],                                                  // ME
+    &["240", "301", "410", "443"],                             // MD
+    &["413", "508", "617", "781", "978"],                      // MA
+    &["248", "313", "517", "616", "734", "810"],               // MI
+    &["218", "507", "612", "651", "952"],                      // MN
+    &["228", "601", "662"],                                    // MS
+    &["314", "417", "573", "636", "816"],                      // MO
+    &["406"],                                                  // MT
+    &["308", "402"],                                           // NE
+    &["702", "775"],                                           // NV
+    &["603"],                                                  // NH
+    &["201", "609", "732", "856", "908", "973"],               // NJ
+    &["505", "575"],                                           // NM
+    &["212", "315", "516", "518", "585", "716", "718", "914"], // NY
+    &["252", "336", "704", "828", "910", "919"],               // NC
+    &["701"],                                                  // ND
+    &["216", "330", "419", "513", "614", "937"],               // OH
+    &["405", "580", "918"],                                    // OK
+    &["503", "541", "971"],                                    // OR
+    &["215", "412", "570", "610", "717", "814"],               // PA
+    &["401"],                                                  // RI
+    &["803", "843", "864"],                                    // SC
+    &["605"],                                                  // SD
+    &["423", "615", "731", "865", "901", "931"],               // TN
+    &["214", "281", "512", "713", "817", "915", "956", "972"], // TX
+    &["385", "435", "801"],                                    // UT
+    &["802"],                                                  // VT
+    &["276", "434", "540", "703", "757", "804"],               // VA
+    &["206", "253", "360", "425", "509"],                      // WA
+    &["304", "681"],                                           // WV
+    &["262", "414", "608", "715", "920"],                      // WI
+    &["307"],                                                  // WY
+];
+
+/// Inclusive range of three-digit ZIP code prefixes for each state (in the
+/// same order as `STATES`).
+pub const STATE_ZIP_PREFIXES: &[(u16, u16)] = &[
+    (350, 369), // AL
+    (995, 999), // AK
+    (850, 865), // AZ
+    (716, 729), // AR
+    (900, 961), // CA
+    (800, 816), // CO
+    (60, 69),   // CT
+    (197, 199), // DE
+    (320, 349), // FL
+    (300, 319), // GA
+    (967, 968), // HI
+    (832, 838), // ID
+    (600, 629), // IL
+    (460, 479), // IN
+    (500, 528), // IA
+    (660, 679), // KS
+    (400, 427), // KY
+    (700, 714), // LA
+    (39, 49),   // ME
+    (206, 219), // MD
+    (10, 27),   // MA
+    (480, 499), // MI
+    (550, 567), // MN
+    (386, 397), // MS
+    (630, 658), // MO
+    (590, 599), // MT
+    (680, 693), // NE
+    (889, 898), // NV
+    (30, 38),   // NH
+    (70, 89),   // NJ
+    (870, 884), // NM
+    (100, 149), // NY
+    (270, 289), // NC
+    (580, 588), // ND
+    (430, 459), // OH
+    (730, 749), // OK
+    (970, 979), // OR
+    (150, 196), // PA
+    (28, 29),   // RI
+    (290, 299), // SC
+    (570, 577), // SD
+    (370, 385), // TN
+    (750, 799), // TX
+    (840, 847), // UT
+    (50, 59),   // VT
+    (220, 246), // VA
+    (980, 994), // WA
+    (247, 268), // WV
+    (530, 549), // WI
+    (820, 831), // WY
+];
+
+/// A sample of counties (parishes in Louisiana, boroughs in Alaska) for each
+/// state, in the same order as `STATES`.
+pub const STATE_COUNTIES: &[&[&str]] = &[
+    &[
+        "Jefferson County",
+        "Mobile County",
+        "Madison County",
+        "Montgomery County",
+        "Baldwin County",
+    ],
+    &[
+        "Anchorage Municipality",
+        "Fairbanks North Star Borough",
+        "Matanuska-Susitna Borough",
+        "Kenai Peninsula Borough",
+        "Juneau City and Borough",
+    ],
+    &[
+        "Maricopa County",
+        "Pima County",
+        "Pinal County",
+        "Yavapai County",
+        "Coconino County",
+    ],
+    &[
+        "Pulaski County",
+        "Benton County",
+        "Washington County",
+        "Sebastian County",
+        "Faulkner County",
+    ],
+    &[
+        "Los Angeles County",
+        "San Diego County",
+        "Orange County",
+        "Riverside County",
+        "Santa Clara County",
+        "Alameda County",
+        "Sacramento County",
+        "Fresno County",
+    ],
+    &[
+        "Denver County",
+        "El Paso County",
+        "Arapahoe County",
+        "Jefferson County",
+        "Boulder County",
+        "Larimer County",
+    ],
+    &[
+        "Fairfield County",
+        "Hartford County",
+        "New Haven County",
+        "New London County",
+        "Litchfield County",
+    ],
+    &["New Castle County", "Kent County", "Sussex County"],
+    &[
+        "Miami-Dade County",
+        "Broward County",
+        "Palm Beach County",
+        "Hillsborough County",
+        "Orange County",
+        "Duval County",
+        "Pinellas County",
+    ],
+    &[
+        "Fulton County",
+        "Gwinnett County",
+        "Cobb County",
+        "DeKalb County",
+        "Chatham County",
+        "Clayton County",
+    ],
+    &[
+        "Honolulu County",
+        "Hawaii County",
+        "Maui County",
+        "Kauai County",
+    ],
+    &[
+        "Ada County",
+        "Canyon County",
+        "Kootenai County",
+        "Bonneville County",
+        "Twin Falls County",
+    ],
+    &[
+        "Cook County",
+        "DuPage County",
+        "Lake County",
+        "Will County",
+        "Kane County",
+        "Sangamon County",
+    ],
+    &[
+        "Marion County",
+        "Lake County",
+        "Allen County",
+        "Hamilton County",
+        "St. Joseph County",
+        "Monroe County",
+    ],
+    &[
+        "Polk County",
+        "Linn County",
+        "Scott County",
+        "Johnson County",
+        "Black Hawk County",
+    ],
+    &[
+        "Johnson County",
+        "Sedgwick County",
+        "Shawnee County",
+        "Wyandotte County",
+        "Douglas County",
+    ],
+    &[
+        "Jefferson County",
+        "Fayette County",
+        "Kenton County",
+        "Boone County",
+        "Warren County",
+    ],
+    &[
+        "East Baton Rouge Parish",
+        "Jefferson Parish",
+        "Orleans Parish",
+        "St. Tammany Parish",
+        "Caddo Parish",
+        "Lafayette Parish",
+    ],
+    &[
+        "Cumberland County",
+        "York County",
+        "Penobscot County",
+        "Kennebec County",
+        "Androscoggin County",
+    ],
+    &[
+        "Montgomery County",
+        "Prince George's County",
+        "Baltimore County",
+        "Anne Arundel County",
+        "Howard County",
+        "Frederick County",
+    ],
+    &[
+        "Middlesex County",
+        "Worcester County",
+        "Suffolk County",
+        "Essex County",
+        "Norfolk County",
+        "Hampden County",
+    ],
+    &[
+        "Wayne County",
+        "Oakland County",
+        "Macomb County",
+        "Kent County",
+        "Genesee County",
+        "Washtenaw County",
+    ],
+    &[
+        "Hennepin County",
+        "Ramsey County",
+        "Dakota County",
+        "Anoka County",
+        "Washington County",
+        "St. Louis County",
+    ],
+    &[
+        "Hinds County",
+        "Harrison County",
+        "DeSoto County",
+        "Rankin County",
+        "Jackson County",
+    ],
+    &[
+        "St. Louis County",
+        "Jackson County",
+        "St. Charles County",
+        "Greene County",
+        "Clay County",
+        "Boone County",
+    ],
+    &[
+        "Yellowstone County",
+        "Missoula County",
+        "Gallatin County",
+        "Flathead County",
+        "Cascade County",
+    ],
+    &[
+        "Douglas County",
+        "Lancaster County",
+        "Sarpy County",
+        "Hall County",
+        "Buffalo County",
+    ],
+    &[
+        "Clark County",
+        "Washoe County",
+        "Carson City",
+        "Lyon County",
+        "Elko County",
+    ],
+    &[
+        "Hillsborough County",
+        "Rockingham County",
+        "Merrimack County",
+        "Strafford County",
+        "Grafton County",
+    ],
+    &[
+        "Bergen County",
+        "Middlesex County",
+        "Essex County",
+        "Hudson County",
+        "Monmouth County",
+        "Ocean County",
+    ],
+    &[
+        "Bernalillo County",
+        "Doña Ana County",
+        "Santa Fe County",
+        "Sandoval County",
+        "San Juan County",
+    ],
+    &[
+        "Kings County",
+        "Queens County",
+        "New York County",
+        "Suffolk County",
+        "Bronx County",
+        "Nassau County",
+        "Westchester County",
+        "Erie County",
+    ],
+    &[
+        "Mecklenburg County",
+        "Wake County",
+        "Guilford County",
+        "Forsyth County",
+        "Durham County",
+        "Cumberland County",
+    ],
+    &[
+        "Cass County",
+        "Burleigh County",
+        "Grand Forks County",
+        "Ward County",
+        "Williams County",
+    ],
+    &[
+        "Franklin County",
+        "Cuyahoga County",
+        "Hamilton County",
+        "Summit County",
+        "Montgomery County",
+        "Lucas County",
+    ],
+    &[
+        "Oklahoma County",
+        "Tulsa County",
+        "Cleveland County",
+        "Canadian County",
+        "Comanche County",
+    ],
+    &[
+        "Multnomah County",
+        "Washington County",
+        "Clackamas County",
+        "Lane County",
+        "Marion County",
+    ],
+    &[
+        "Philadelphia County",
+        "Allegheny County",
+        "Montgomery County",
+        "Bucks County",
+        "Delaware County",
+        "Lancaster County",
+    ],
+    &[
+        "Providence County",
+        "Kent County",
+        "Washington County",
+        "Newport County",
+        "Bristol County",
+    ],
+    &[
+        "Greenville County",
+        "Richland County",
+        "Charleston County",
+        "Horry County",
+        "Spartanburg County",
+    ],
+    &[
+        "Minnehaha County",
+        "Pennington County",
+        "Lincoln County",
+        "Brown County",
+        "Brookings County",
+    ],
+    &[
+        "Shelby County",
+        "Davidson County",
+        "Knox County",
+        "Hamilton County",
+        "Rutherford County",
+        "Williamson County",
+    ],
+    &[
+        "Harris County",
+        "Dallas County",
+        "Tarrant County",
+        "Bexar County",
+        "Travis County",
+        "Collin County",
+        "El Paso County",
+        "Denton County",
+    ],
+    &[
+        "Salt Lake County",
+        "Utah County",
+        "Davis County",
+        "Weber County",
+        "Washington County",
+    ],
+    &[
+        "Chittenden County",
+        "Rutland County",
+        "Washington County",
+        "Windsor County",
+        "Franklin County",
+    ],
+    &[
+        "Fairfax County",
+        "Prince William County",
+        "Loudoun County",
+        "Chesterfield County",
+        "Henrico County",
+        "Arlington County",
+    ],
+    &[
+        "King County",
+        "Pierce County",
+        "Snohomish County",
+        "Spokane County",
+        "Clark County",
+        "Thurston County",
+    ],
+    &[
+        "Kanawha County",
+        "Berkeley County",
+        "Monongalia County",
+        "Cabell County",
+        "Wood County",
+    ],
+    &[
+        "Milwaukee County",
+        "Dane County",
+        "Waukesha County",
+        "Brown County",
+        "Racine County",
+        "Outagamie County",
+    ],
+    &[
+        "Laramie County",
+        "Natrona County",
+        "Campbell County",
+        "Sweetwater County",
+        "Fremont County",
+    ],
+];
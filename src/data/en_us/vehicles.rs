@@ -0,0 +1,207 @@
+//! Vehicle makes and models.
+
+/// A (make, World Manufacturer Identifiers, (model, first model year)
+/// pairs) entry of the vehicle data.
+pub type VehicleMake = (
+    &'static str,
+    &'static [&'static str],
+    &'static [(&'static str, u16)],
+);
+
+/// Common vehicle makes. Every WMI is one of `providers::vehicle::VIN_WMIS`.
+pub const VEHICLE_MAKES: &[VehicleMake] = &[
+    (
+        "Ford",
+        &["1FA", "1FT", "3FA"],
+        &[
+            ("F-150", 1975),
+            ("Mustang", 1964),
+            ("Explorer", 1991),
+            ("Escape", 2001),
+            ("Focus", 2000),
+            ("Fusion", 2006),
+            ("Edge", 2007),
+            ("Bronco", 2021),
+        ],
+    ),
+    (
+        "Chevrolet",
+        &["1G1", "1GC"],
+        &[
+            ("Silverado", 1999),
+            ("Malibu", 1997),
+            ("Equinox", 2005),
+            ("Tahoe", 1995),
+            ("Impala", 1958),
+            ("Colorado", 2004),
+            ("Traverse", 2009),
+        ],
+    ),
+    (
+        "Honda",
+        &["1HG", "2HG", "JHM"],
+        &[
+            ("Civic", 1973),
+            ("Accord", 1976),
+            ("CR-V", 1997),
+            ("Pilot", 2003),
+            ("Odyssey", 1995),
+            ("Fit", 2007),
+            ("HR-V", 2016),
+        ],
+    ),
+    (
+        "Nissan",
+        &["1N4", "JN1"],
+        &[
+            ("Altima", 1993),
+            ("Sentra", 1982),
+            ("Rogue", 2008),
+            ("Maxima", 1982),
+            ("Pathfinder", 1987),
+            ("Leaf", 2011),
+            ("Frontier", 1998),
+        ],
+    ),
+    (
+        "Toyota",
+        &["2T1", "4T1", "JTD"],
+        &[
+            ("Camry", 1983),
+            ("Corolla", 1968),
+            ("RAV4", 1996),
+            ("Prius", 2001),
+            ("Highlander", 2001),
+            ("Tacoma", 1995),
+            ("Tundra", 2000),
+            ("Sienna", 1998),
+        ],
+    ),
+    (
+        "Tesla",
+        &["5YJ"],
+        &[
+            ("Model S", 2012),
+            ("Model X", 2016),
+            ("Model 3", 2017),
+            ("Model Y", 2020),
+        ],
+    ),
+    (
+        "Hyundai",
+        &["KMH"],
+        &[
+            ("Elantra", 1992),
+            ("Sonata", 1989),
+            ("Tucson", 2005),
+            ("Santa Fe", 2001),
+            ("Kona", 2018),
+            ("Palisade", 2020),
+        ],
+    ),
+    (
+        "Kia",
+        &["KNA"],
+        &[
+            ("Optima", 2001),
+            ("Sorento", 2003),
+            ("Sportage", 1995),
+            ("Soul", 2010),
+            ("Forte", 2010),
+            ("Telluride", 2020),
+        ],
+    ),
+    (
+        "Jaguar",
+        &["SAJ"],
+        &[
+            ("XJ", 1968),
+            ("XF", 2009),
+            ("XE", 2017),
+            ("F-Type", 2014),
+            ("F-Pace", 2017),
+        ],
+    ),
+    (
+        "Land Rover",
+        &["SAL"],
+        &[
+            ("Range Rover", 1970),
+            ("Discovery", 1994),
+            ("Range Rover Sport", 2006),
+            ("Range Rover Evoque", 2012),
+            ("Defender", 2020),
+        ],
+    ),
+    (
+        "Renault",
+        &["VF1"],
+        &[
+            ("Clio", 1990),
+            ("Megane", 1995),
+            ("Captur", 2013),
+            ("Kadjar", 2015),
+            ("Zoe", 2013),
+        ],
+    ),
+    (
+        "Audi",
+        &["WAU"],
+        &[
+            ("A3", 1996),
+            ("A4", 1995),
+            ("A6", 1995),
+            ("Q5", 2009),
+            ("Q7", 2007),
+            ("e-tron", 2019),
+        ],
+    ),
+    (
+        "BMW",
+        &["WBA"],
+        &[
+            ("3 Series", 1975),
+            ("5 Series", 1972),
+            ("X3", 2004),
+            ("X5", 2000),
+            ("i3", 2014),
+        ],
+    ),
+    (
+        "Mercedes-Benz",
+        &["WDD"],
+        &[
+            ("C-Class", 1994),
+            ("E-Class", 1993),
+            ("S-Class", 1972),
+            ("GLC", 2016),
+            ("GLE", 2016),
+        ],
+    ),
+    (
+        "Volkswagen",
+        &["WVW", "3VW"],
+        &[
+            ("Golf", 1974),
+            ("Jetta", 1980),
+            ("Passat", 1973),
+            ("Tiguan", 2009),
+            ("Atlas", 2018),
+        ],
+    ),
+    (
+        "Volvo",
+        &["YV1"],
+        &[("S60", 2001), ("XC60", 2009), ("XC90", 2003), ("V60", 2011)],
+    ),
+    (
+        "Fiat",
+        &["ZFA"],
+        &[
+            ("500", 2008),
+            ("Panda", 1980),
+            ("Punto", 1993),
+            ("Tipo", 2016),
+        ],
+    ),
+];
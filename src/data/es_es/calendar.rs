@@ -0,0 +1,28 @@
+//! Spanish month and weekday names.
+
+/// Spanish month names.
+pub const MONTH_NAMES: &[&str] = &[
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+/// Spanish weekday names, starting with Monday.
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "lunes",
+    "martes",
+    "miércoles",
+    "jueves",
+    "viernes",
+    "sábado",
+    "domingo",
+];
@@ -32,4 +32,5 @@ crate::define_company_data! {
         "tecnologías", "innovaciones", "colaboraciones", "calidad", "asesoramiento",
         "experiencia", "competencias", "rendimiento", "excelencia",
     ],
+    legal_forms: ["S.A.", "S.L.", "S.L.U.", "S.Coop."],
 }
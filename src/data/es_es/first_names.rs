@@ -102,10 +102,12 @@ pub const FIRST_NAMES: &[&str] = &[
     "Verónica",
     "Marina",
     "Sonia",
-    "Lucía",
     "Clara",
 ];
 
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 50;
+
 /// ASCII-safe romanized Spanish first names for email generation.
 pub const ROMANIZED_FIRST_NAMES: &[&str] = &[
     // Male names (ASCII only)
@@ -3,38 +3,59 @@
 //! Contains name lists and other data for es_ES locale.
 
 mod banks;
+mod calendar;
 mod cities;
 mod color_names;
 mod companies;
 mod first_names;
 mod last_names;
+mod name_affixes;
 mod provinces;
 mod streets;
 
 pub use banks::BANK_NAMES;
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
 pub use cities::CITIES;
 pub use color_names::COLOR_NAMES;
 pub use companies::{
-    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_PREFIXES, COMPANY_SUFFIXES, JOB_TITLES,
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_SUFFIXES, JOB_TITLES,
 };
-pub use first_names::{FIRST_NAMES, ROMANIZED_FIRST_NAMES};
+pub use first_names::{FIRST_NAMES, MALE_FIRST_NAME_COUNT, ROMANIZED_FIRST_NAMES};
 pub use last_names::LAST_NAMES;
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
 pub use provinces::{PROVINCES, PROVINCE_ABBRS};
 pub use streets::{STREET_NAMES, STREET_SUFFIXES};
 
 // Shared data
 use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
 
-use super::formats::{AddressFormat, PhoneFormat, PostalCodeFormat};
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
 
 /// Phone format patterns for Spain.
 const ES_PHONE_PATTERNS: &[&str] = &["+34 ### ### ###", "### ### ###", "+34 ## ### ## ##"];
 
+/// Mobile phone patterns for Spain.
+const ES_MOBILE_PATTERNS: &[&str] = &["6## ### ###", "7## ### ###", "+34 6## ### ###"];
+
+/// Landline phone patterns for Spain.
+const ES_LANDLINE_PATTERNS: &[&str] = &[
+    "91# ### ###",
+    "93# ### ###",
+    "95# ### ###",
+    "96# ### ###",
+    "+34 91# ### ###",
+];
+
 /// Postal code patterns for Spain (5 digits).
 const ES_POSTAL_PATTERNS: &[&str] = &["#####"];
 
 /// Spanish phone format specification.
-const ES_PHONE_FORMAT: PhoneFormat = PhoneFormat::new(ES_PHONE_PATTERNS, "+34");
+const ES_PHONE_FORMAT: PhoneFormat =
+    PhoneFormat::new(ES_PHONE_PATTERNS, "+34").with_kinds(ES_MOBILE_PATTERNS, ES_LANDLINE_PATTERNS);
 
 /// Spanish postal code format specification.
 const ES_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(ES_POSTAL_PATTERNS);
@@ -45,6 +66,26 @@ const ES_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(ES_POSTAL_PATTE
 const ES_ADDRESS_FORMAT: AddressFormat =
     AddressFormat::with_prefix_type("{street}\n{postal} {city}", false);
 
+/// Spanish units follow the street (e.g., "Calle Mayor 15, Piso 3").
+const ES_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[
+        UnitFormat::new("Piso {n}", 1, 12),
+        UnitFormat::new("Puerta {n}", 1, 8),
+    ],
+    "{street}, {unit}",
+    "Apartado de Correos {n}",
+);
+
+/// Spanish date format.
+const ES_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{dd}/{mm}/{yyyy}",
+    "{weekday}, {d} de {month} de {yyyy}",
+    "{H}:{MM}:{SS}",
+);
+
+/// Spanish number format.
+const ES_NUMBER_FORMAT: NumberFormat = NumberFormat::new(",", ".", "{amount} {symbol}");
+
 /// Spanish locale data provider.
 pub struct EsESData;
 
@@ -63,9 +104,11 @@ crate::impl_locale_data! {
     countries: COUNTRIES,
     postal_format: ES_POSTAL_FORMAT,
     address_format: ES_ADDRESS_FORMAT,
+    secondary_address_format: ES_SECONDARY_ADDRESS_FORMAT,
     phone_format: ES_PHONE_FORMAT,
     company_prefixes: COMPANY_PREFIXES,
     company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
     job_titles: JOB_TITLES,
     catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
     catch_phrase_nouns: CATCH_PHRASE_NOUNS,
@@ -75,6 +118,13 @@ crate::impl_locale_data! {
     safe_email_domains: SAFE_EMAIL_DOMAINS,
     color_names: COLOR_NAMES,
     bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: ES_DATE_FORMAT,
+    number_format: ES_NUMBER_FORMAT,
     romanized_first_names: ROMANIZED_FIRST_NAMES,
 }
 
@@ -0,0 +1,7 @@
+//! Spanish name prefixes and suffixes.
+
+/// Spanish name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &["Sr.", "Sra.", "Srta.", "D.", "Dña.", "Dr.", "Dra."];
+
+/// Spanish name suffixes (generational and post-nominal).
+pub const NAME_SUFFIXES: &[&str] = &["hijo"];
@@ -1,7 +1,7 @@
 //! Format specifications for locale-specific data generation.
 //!
 //! These structs define patterns and formats used for generating
-//! phone numbers, postal codes, and addresses in different locales.
+//! phone numbers, postal codes, addresses, and dates in different locales.
 
 /// Phone number format specification.
 ///
@@ -20,6 +20,17 @@ pub struct PhoneFormat {
     pub patterns: &'static [&'static str],
     /// Country code (e.g., "+1" for US, "+49" for Germany).
     pub country_code: &'static str,
+    /// Trunk prefix dialled before national numbers and dropped after the
+    /// country code (e.g., "0" in Germany, "8" in Russia, "" in the US).
+    pub trunk_prefix: &'static str,
+    /// Patterns restricted to mobile ranges; empty means `patterns`.
+    pub mobile_patterns: &'static [&'static str],
+    /// Patterns restricted to geographic landline ranges; empty means `patterns`.
+    pub landline_patterns: &'static [&'static str],
+    /// Mobile patterns inside ranges reserved for fiction (e.g., UK 07700 900xxx).
+    pub fictional_mobile_patterns: &'static [&'static str],
+    /// Landline patterns inside ranges reserved for fiction (e.g., US 555-01xx).
+    pub fictional_landline_patterns: &'static [&'static str],
 }
 
 /// Postal code format specification.
@@ -77,12 +88,155 @@ pub struct AddressFormat {
     pub street_type_prefix: bool,
 }
 
+/// A kind of secondary address unit and its number range.
+#[derive(Debug, Clone, Copy)]
+pub struct UnitFormat {
+    /// Template with `{n}` for the unit number (e.g., "Apt {n}", "{n}号室").
+    pub template: &'static str,
+    /// Lowest unit number.
+    pub min: u32,
+    /// Highest unit number.
+    pub max: u32,
+    /// Whether numbers below 30 may carry a letter A-F (e.g., "Apt 4B").
+    pub letters: bool,
+}
+
+/// Secondary unit and PO Box wording.
+///
+/// # Examples
+///
+/// - US: `{street} {unit}` with "Apt 4B" → "12 Main Street Apt 4B", "PO Box 4821"
+/// - UK: `{unit}, {street}` with "Flat 3" → "Flat 3, 12 High Street"
+/// - Korea: `{street}, {unit}` with "101호", "사서함 4821호"
+#[derive(Debug, Clone, Copy)]
+pub struct SecondaryAddressFormat {
+    /// Unit kinds; one is chosen per unit.
+    pub units: &'static [UnitFormat],
+    /// Template joining a street address and a unit, with `{street}` and `{unit}`.
+    pub street_with_unit: &'static str,
+    /// PO Box template with `{n}` for the box number (e.g., "PO Box {n}").
+    pub po_box: &'static str,
+}
+
+/// Date and time format specification.
+///
+/// Templates use the following placeholders:
+/// - `{yyyy}` - Four-digit year
+/// - `{mm}` / `{m}` - Month number, zero-padded / unpadded
+/// - `{dd}` / `{d}` - Day of month, zero-padded / unpadded
+/// - `{month}` - Month name from the locale's month names
+/// - `{weekday}` - Weekday name from the locale's weekday names
+/// - `{HH}` / `{H}` - Hour (24-hour clock), zero-padded / unpadded
+/// - `{h}` - Hour (12-hour clock), unpadded
+/// - `{MM}` / `{SS}` - Minute / second, zero-padded
+/// - `{ampm}` - "AM" or "PM"
+///
+/// # Examples
+///
+/// - US: `{mm}/{dd}/{yyyy}` → "12/31/2024"
+/// - Germany: `{dd}.{mm}.{yyyy}` → "31.12.2024"
+/// - Japan: `{yyyy}年{m}月{d}日` → "2024年12月31日"
+#[derive(Debug, Clone, Copy)]
+pub struct DateFormat {
+    /// Short numeric date template.
+    pub date: &'static str,
+    /// Long date template using month and weekday names.
+    pub long_date: &'static str,
+    /// Time-of-day template.
+    pub time: &'static str,
+}
+
+/// Number and currency format specification.
+///
+/// The currency template supports `{amount}` (the grouped number) and
+/// `{symbol}` (the currency symbol).
+///
+/// # Examples
+///
+/// - US: `.` decimal, `,` group, `{symbol}{amount}` → "$1,234.56"
+/// - Germany: `,` decimal, `.` group, `{amount} {symbol}` → "1.234,56 €"
+#[derive(Debug, Clone, Copy)]
+pub struct NumberFormat {
+    /// Decimal separator.
+    pub decimal: &'static str,
+    /// Thousands grouping separator.
+    pub group: &'static str,
+    /// Currency amount template.
+    pub currency: &'static str,
+}
+
+/// Word and sentence layout for lorem text.
+///
+/// # Examples
+///
+/// - English: `" "` separator, `,` and `.` → "Lorem ipsum, dolor sit."
+/// - Japanese: no separator, `、` and `。` → "今日会社、新しい仕事。"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextFormat {
+    /// Separator between words and between sentences.
+    pub separator: &'static str,
+    /// Clause mark that may follow a word.
+    pub comma: &'static str,
+    /// Mark ending each sentence.
+    pub full_stop: &'static str,
+}
+
 impl PhoneFormat {
-    /// Create a new phone format.
+    /// Create a new phone format without a trunk prefix.
     pub const fn new(patterns: &'static [&'static str], country_code: &'static str) -> Self {
         Self {
             patterns,
             country_code,
+            trunk_prefix: "",
+            mobile_patterns: &[],
+            landline_patterns: &[],
+            fictional_mobile_patterns: &[],
+            fictional_landline_patterns: &[],
+        }
+    }
+
+    /// Create a new phone format whose national patterns start with a trunk prefix.
+    pub const fn with_trunk_prefix(
+        patterns: &'static [&'static str],
+        country_code: &'static str,
+        trunk_prefix: &'static str,
+    ) -> Self {
+        Self {
+            patterns,
+            country_code,
+            trunk_prefix,
+            mobile_patterns: &[],
+            landline_patterns: &[],
+            fictional_mobile_patterns: &[],
+            fictional_landline_patterns: &[],
+        }
+    }
+
+    /// Add separate mobile and landline patterns, for locales where the two
+    /// use distinct number ranges.
+    pub const fn with_kinds(
+        self,
+        mobile_patterns: &'static [&'static str],
+        landline_patterns: &'static [&'static str],
+    ) -> Self {
+        Self {
+            mobile_patterns,
+            landline_patterns,
+            ..self
+        }
+    }
+
+    /// Add patterns confined to number ranges the regulator reserves for
+    /// drama and documentation, which are never assigned to subscribers.
+    pub const fn with_fictional(
+        self,
+        fictional_mobile_patterns: &'static [&'static str],
+        fictional_landline_patterns: &'static [&'static str],
+    ) -> Self {
+        Self {
+            fictional_mobile_patterns,
+            fictional_landline_patterns,
+            ..self
         }
     }
 }
@@ -94,6 +248,83 @@ impl PostalCodeFormat {
     }
 }
 
+impl DateFormat {
+    /// Create a new date format.
+    pub const fn new(date: &'static str, long_date: &'static str, time: &'static str) -> Self {
+        Self {
+            date,
+            long_date,
+            time,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Create a new number format.
+    pub const fn new(decimal: &'static str, group: &'static str, currency: &'static str) -> Self {
+        Self {
+            decimal,
+            group,
+            currency,
+        }
+    }
+}
+
+impl UnitFormat {
+    /// Create a unit format numbered from `min` to `max`.
+    pub const fn new(template: &'static str, min: u32, max: u32) -> Self {
+        Self {
+            template,
+            min,
+            max,
+            letters: false,
+        }
+    }
+
+    /// Create a unit format whose low numbers may carry a letter (e.g., "Apt 4B").
+    pub const fn lettered(template: &'static str, min: u32, max: u32) -> Self {
+        Self {
+            template,
+            min,
+            max,
+            letters: true,
+        }
+    }
+}
+
+impl SecondaryAddressFormat {
+    /// Create a new secondary address format.
+    pub const fn new(
+        units: &'static [UnitFormat],
+        street_with_unit: &'static str,
+        po_box: &'static str,
+    ) -> Self {
+        Self {
+            units,
+            street_with_unit,
+            po_box,
+        }
+    }
+}
+
+impl TextFormat {
+    /// Space-separated words with Latin punctuation.
+    pub const LATIN: Self = Self::new(" ", ",", ".");
+
+    /// Create a new text format.
+    pub const fn new(
+        separator: &'static str,
+        comma: &'static str,
+        full_stop: &'static str,
+    ) -> Self {
+        Self {
+            separator,
+            comma,
+            full_stop,
+        }
+    }
+}
+
 impl AddressFormat {
     /// Create a new address format with default settings.
     /// Defaults: space separator, street type as suffix.
@@ -143,6 +374,34 @@ mod tests {
         let format = PhoneFormat::new(&["(###) ###-####", "###-###-####"], "+1");
         assert_eq!(format.patterns.len(), 2);
         assert_eq!(format.country_code, "+1");
+        assert_eq!(format.trunk_prefix, "");
+    }
+
+    #[test]
+    fn test_phone_format_with_trunk_prefix() {
+        let format =
+            PhoneFormat::with_trunk_prefix(&["+49 ### #######", "0### #######"], "+49", "0");
+        assert_eq!(format.country_code, "+49");
+        assert_eq!(format.trunk_prefix, "0");
+        assert!(format.mobile_patterns.is_empty());
+    }
+
+    #[test]
+    fn test_phone_format_with_kinds() {
+        let format = PhoneFormat::with_trunk_prefix(&["0#### ######"], "+44", "0")
+            .with_kinds(&["07### ######"], &["01### ######"]);
+        assert_eq!(format.mobile_patterns, &["07### ######"]);
+        assert_eq!(format.landline_patterns, &["01### ######"]);
+        assert_eq!(format.patterns, &["0#### ######"]);
+        assert!(format.fictional_mobile_patterns.is_empty());
+    }
+
+    #[test]
+    fn test_phone_format_with_fictional() {
+        let format = PhoneFormat::new(&["(###) ###-####"], "+1")
+            .with_fictional(&["(###) 555-01##"], &["(###) 555-01##"]);
+        assert_eq!(format.fictional_mobile_patterns, &["(###) 555-01##"]);
+        assert_eq!(format.fictional_landline_patterns, &["(###) 555-01##"]);
     }
 
     #[test]
@@ -151,6 +410,14 @@ mod tests {
         assert_eq!(format.patterns.len(), 2);
     }
 
+    #[test]
+    fn test_number_format_creation() {
+        let format = NumberFormat::new(",", ".", "{amount} {symbol}");
+        assert_eq!(format.decimal, ",");
+        assert_eq!(format.group, ".");
+        assert!(format.currency.contains("{amount}"));
+    }
+
     #[test]
     fn test_address_format_creation() {
         let format = AddressFormat::new("{street}, {city}, {region_abbr} {postal}", true);
@@ -165,4 +432,12 @@ mod tests {
         assert!(!format.number_before_street);
         assert_eq!(format.street_name_separator, "");
     }
+
+    #[test]
+    fn test_date_format_creation() {
+        let format = DateFormat::new("{dd}.{mm}.{yyyy}", "{d}. {month} {yyyy}", "{HH}:{MM}:{SS}");
+        assert!(format.date.contains("{yyyy}"));
+        assert!(format.long_date.contains("{month}"));
+        assert_eq!(format.time, "{HH}:{MM}:{SS}");
+    }
 }
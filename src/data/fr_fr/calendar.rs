@@ -0,0 +1,22 @@
+//! French month and weekday names.
+
+/// French month names.
+pub const MONTH_NAMES: &[&str] = &[
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+/// French weekday names, starting with Monday.
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+];
@@ -30,4 +30,5 @@ crate::define_company_data! {
         "stratégies", "technologies", "innovations", "partenariats",
         "qualité", "conseil", "expertise", "expérience", "compétences", "performances",
     ],
+    legal_forms: ["SA", "SARL", "SAS", "SASU", "EURL", "SNC"],
 }
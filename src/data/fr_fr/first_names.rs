@@ -106,6 +106,9 @@ pub const FIRST_NAMES: &[&str] = &[
     "Mathilde",
 ];
 
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 50;
+
 /// ASCII-safe romanized French first names for email generation.
 pub const ROMANIZED_FIRST_NAMES: &[&str] = &[
     // Male names (ASCII only)
@@ -3,38 +3,79 @@
 //! Contains name lists and other data for fr_FR locale.
 
 mod banks;
+mod calendar;
 mod cities;
 mod color_names;
 mod companies;
 mod first_names;
 mod last_names;
+mod name_affixes;
 mod regions;
 mod streets;
 
 pub use banks::BANK_NAMES;
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
 pub use cities::CITIES;
 pub use color_names::COLOR_NAMES;
 pub use companies::{
-    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_PREFIXES, COMPANY_SUFFIXES, JOB_TITLES,
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_SUFFIXES, JOB_TITLES,
 };
-pub use first_names::{FIRST_NAMES, ROMANIZED_FIRST_NAMES};
+pub use first_names::{FIRST_NAMES, MALE_FIRST_NAME_COUNT, ROMANIZED_FIRST_NAMES};
 pub use last_names::LAST_NAMES;
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
 pub use regions::{REGIONS, REGION_ABBRS};
 pub use streets::{STREET_NAMES, STREET_SUFFIXES};
 
 // Shared data
 use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
 
-use super::formats::{AddressFormat, PhoneFormat, PostalCodeFormat};
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
 
 /// Phone format patterns for France.
 const FR_PHONE_PATTERNS: &[&str] = &["+33 # ## ## ## ##", "0# ## ## ## ##", "+33 ### ### ###"];
 
+/// Mobile phone patterns for France.
+const FR_MOBILE_PATTERNS: &[&str] = &[
+    "06 ## ## ## ##",
+    "07 ## ## ## ##",
+    "+33 6 ## ## ## ##",
+    "+33 7 ## ## ## ##",
+];
+
+/// Landline phone patterns for France.
+const FR_LANDLINE_PATTERNS: &[&str] = &[
+    "01 ## ## ## ##",
+    "02 ## ## ## ##",
+    "03 ## ## ## ##",
+    "04 ## ## ## ##",
+    "05 ## ## ## ##",
+    "+33 1 ## ## ## ##",
+];
+
+/// Fictional mobile patterns for France (ARCEP range reserved for fiction).
+const FR_FICTIONAL_MOBILE_PATTERNS: &[&str] = &["06 39 98 ## ##", "+33 6 39 98 ## ##"];
+
+/// Fictional landline patterns for France (ARCEP ranges reserved for fiction).
+const FR_FICTIONAL_LANDLINE_PATTERNS: &[&str] = &[
+    "01 99 00 ## ##",
+    "02 61 91 ## ##",
+    "03 53 01 ## ##",
+    "04 65 71 ## ##",
+    "05 36 49 ## ##",
+    "+33 1 99 00 ## ##",
+];
+
 /// Postal code patterns for France (5 digits).
 const FR_POSTAL_PATTERNS: &[&str] = &["#####"];
 
 /// French phone format specification.
-const FR_PHONE_FORMAT: PhoneFormat = PhoneFormat::new(FR_PHONE_PATTERNS, "+33");
+const FR_PHONE_FORMAT: PhoneFormat = PhoneFormat::with_trunk_prefix(FR_PHONE_PATTERNS, "+33", "0")
+    .with_kinds(FR_MOBILE_PATTERNS, FR_LANDLINE_PATTERNS)
+    .with_fictional(FR_FICTIONAL_MOBILE_PATTERNS, FR_FICTIONAL_LANDLINE_PATTERNS);
 
 /// French postal code format specification.
 const FR_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(FR_POSTAL_PATTERNS);
@@ -45,6 +86,26 @@ const FR_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(FR_POSTAL_PATTE
 const FR_ADDRESS_FORMAT: AddressFormat =
     AddressFormat::with_prefix_type("{street}\n{postal} {city}", true);
 
+/// French units follow the street (e.g., "15 rue de la Paix, Appt 12").
+const FR_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[
+        UnitFormat::new("Appt {n}", 1, 99),
+        UnitFormat::new("Bât. {n}", 1, 9),
+    ],
+    "{street}, {unit}",
+    "BP {n}",
+);
+
+/// French date format.
+const FR_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{dd}/{mm}/{yyyy}",
+    "{weekday} {d} {month} {yyyy}",
+    "{HH}:{MM}:{SS}",
+);
+
+/// French number format.
+const FR_NUMBER_FORMAT: NumberFormat = NumberFormat::new(",", " ", "{amount} {symbol}");
+
 /// French locale data provider.
 pub struct FrFRData;
 
@@ -63,9 +124,11 @@ crate::impl_locale_data! {
     countries: COUNTRIES,
     postal_format: FR_POSTAL_FORMAT,
     address_format: FR_ADDRESS_FORMAT,
+    secondary_address_format: FR_SECONDARY_ADDRESS_FORMAT,
     phone_format: FR_PHONE_FORMAT,
     company_prefixes: COMPANY_PREFIXES,
     company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
     job_titles: JOB_TITLES,
     catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
     catch_phrase_nouns: CATCH_PHRASE_NOUNS,
@@ -75,6 +138,13 @@ crate::impl_locale_data! {
     safe_email_domains: SAFE_EMAIL_DOMAINS,
     color_names: COLOR_NAMES,
     bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: FR_DATE_FORMAT,
+    number_format: FR_NUMBER_FORMAT,
     romanized_first_names: ROMANIZED_FIRST_NAMES,
 }
 
@@ -109,6 +179,8 @@ mod tests {
         assert!(data.free_email_domains().is_some());
         assert!(data.safe_email_domains().is_some());
         assert!(data.color_names().is_some());
+        assert!(data.name_prefixes().is_some());
+        assert!(data.name_suffixes().is_some());
         assert!(data.romanized_first_names().is_some());
     }
 }
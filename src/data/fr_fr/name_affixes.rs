@@ -0,0 +1,7 @@
+//! French name prefixes and suffixes.
+
+/// French name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &["M.", "Mme", "Mlle", "Dr", "Pr", "Me"];
+
+/// French name suffixes (generational and post-nominal).
+pub const NAME_SUFFIXES: &[&str] = &["fils", "père"];
@@ -0,0 +1,28 @@
+//! Italian month and weekday names.
+
+/// Italian month names.
+pub const MONTH_NAMES: &[&str] = &[
+    "gennaio",
+    "febbraio",
+    "marzo",
+    "aprile",
+    "maggio",
+    "giugno",
+    "luglio",
+    "agosto",
+    "settembre",
+    "ottobre",
+    "novembre",
+    "dicembre",
+];
+
+/// Italian weekday names, starting with Monday.
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "lunedì",
+    "martedì",
+    "mercoledì",
+    "giovedì",
+    "venerdì",
+    "sabato",
+    "domenica",
+];
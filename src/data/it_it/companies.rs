@@ -31,4 +31,5 @@ crate::define_company_data! {
         "tecnologie", "innovazioni", "partnership", "qualità", "consulenza",
         "esperienza", "competenze", "performance", "eccellenza",
     ],
+    legal_forms: ["S.p.A.", "S.r.l.", "S.a.s.", "S.n.c."],
 }
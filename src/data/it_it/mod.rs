@@ -3,38 +3,59 @@
 //! Contains name lists and other data for it_IT locale.
 
 mod banks;
+mod calendar;
 mod cities;
 mod color_names;
 mod companies;
 mod first_names;
 mod last_names;
+mod name_affixes;
 mod regions;
 mod streets;
 
 pub use banks::BANK_NAMES;
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
 pub use cities::CITIES;
 pub use color_names::COLOR_NAMES;
 pub use companies::{
-    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_PREFIXES, COMPANY_SUFFIXES, JOB_TITLES,
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_SUFFIXES, JOB_TITLES,
 };
-pub use first_names::{FIRST_NAMES, ROMANIZED_FIRST_NAMES};
+pub use first_names::{FIRST_NAMES, MALE_FIRST_NAME_COUNT, ROMANIZED_FIRST_NAMES};
 pub use last_names::LAST_NAMES;
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
 pub use regions::{REGIONS, REGION_ABBRS};
 pub use streets::{STREET_NAMES, STREET_SUFFIXES};
 
 // Shared data
 use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
 
-use super::formats::{AddressFormat, PhoneFormat, PostalCodeFormat};
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
 
 /// Phone format patterns for Italy.
 const IT_PHONE_PATTERNS: &[&str] = &["+39 ## #### ####", "0## #### ####", "+39 ### #######"];
 
+/// Mobile phone patterns for Italy.
+const IT_MOBILE_PATTERNS: &[&str] = &["3## ### ####", "+39 3## ### ####"];
+
+/// Landline phone patterns for Italy.
+const IT_LANDLINE_PATTERNS: &[&str] = &[
+    "06 #### ####",
+    "02 #### ####",
+    "011 ### ####",
+    "+39 06 #### ####",
+    "+39 02 #### ####",
+];
+
 /// Postal code patterns for Italy (5 digits).
 const IT_POSTAL_PATTERNS: &[&str] = &["#####"];
 
 /// Italian phone format specification.
-const IT_PHONE_FORMAT: PhoneFormat = PhoneFormat::new(IT_PHONE_PATTERNS, "+39");
+const IT_PHONE_FORMAT: PhoneFormat =
+    PhoneFormat::new(IT_PHONE_PATTERNS, "+39").with_kinds(IT_MOBILE_PATTERNS, IT_LANDLINE_PATTERNS);
 
 /// Italian postal code format specification.
 const IT_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(IT_POSTAL_PATTERNS);
@@ -45,6 +66,26 @@ const IT_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(IT_POSTAL_PATTE
 const IT_ADDRESS_FORMAT: AddressFormat =
     AddressFormat::with_prefix_type("{street}\n{postal} {city}", false);
 
+/// Italian units follow the street (e.g., "Via Roma 15, Interno 4").
+const IT_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[
+        UnitFormat::new("Interno {n}", 1, 40),
+        UnitFormat::new("Scala {n}", 1, 9),
+    ],
+    "{street}, {unit}",
+    "Casella Postale {n}",
+);
+
+/// Italian date format.
+const IT_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{dd}/{mm}/{yyyy}",
+    "{weekday} {d} {month} {yyyy}",
+    "{HH}:{MM}:{SS}",
+);
+
+/// Italian number format.
+const IT_NUMBER_FORMAT: NumberFormat = NumberFormat::new(",", ".", "{amount} {symbol}");
+
 /// Italian locale data provider.
 pub struct ItITData;
 
@@ -63,9 +104,11 @@ crate::impl_locale_data! {
     countries: COUNTRIES,
     postal_format: IT_POSTAL_FORMAT,
     address_format: IT_ADDRESS_FORMAT,
+    secondary_address_format: IT_SECONDARY_ADDRESS_FORMAT,
     phone_format: IT_PHONE_FORMAT,
     company_prefixes: COMPANY_PREFIXES,
     company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
     job_titles: JOB_TITLES,
     catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
     catch_phrase_nouns: CATCH_PHRASE_NOUNS,
@@ -75,6 +118,13 @@ crate::impl_locale_data! {
     safe_email_domains: SAFE_EMAIL_DOMAINS,
     color_names: COLOR_NAMES,
     bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: IT_DATE_FORMAT,
+    number_format: IT_NUMBER_FORMAT,
     romanized_first_names: ROMANIZED_FIRST_NAMES,
 }
 
@@ -0,0 +1,9 @@
+//! Italian name prefixes and suffixes.
+
+/// Italian name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &[
+    "Sig.", "Sig.ra", "Dott.", "Dott.ssa", "Prof.", "Prof.ssa", "Ing.", "Avv.",
+];
+
+/// Italian name suffixes (generational and post-nominal).
+pub const NAME_SUFFIXES: &[&str] = &["Jr.", "Sr."];
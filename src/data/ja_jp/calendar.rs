@@ -0,0 +1,17 @@
+//! Japanese month and weekday names.
+
+/// Japanese month names.
+pub const MONTH_NAMES: &[&str] = &[
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+/// Japanese weekday names, starting with Monday.
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "月曜日",
+    "火曜日",
+    "水曜日",
+    "木曜日",
+    "金曜日",
+    "土曜日",
+    "日曜日",
+];
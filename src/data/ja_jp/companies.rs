@@ -31,4 +31,34 @@ crate::define_company_data! {
         "テクノロジー", "イノベーション", "パートナーシップ", "品質",
         "コンサルティング", "専門知識", "経験", "能力", "パフォーマンス",
     ],
+    legal_forms: ["株式会社", "合同会社", "有限会社"],
 }
+
+/// Romanized company prefixes (in the same order as `COMPANY_PREFIXES`).
+pub const COMPANY_PREFIXES_ROMANIZED: &[&str] = &[
+    "Sato",
+    "Suzuki",
+    "Tanaka",
+    "Yamamoto",
+    "Nihon",
+    "Tokyo",
+    "Osaka",
+    "Kyoto",
+    "Yokohama",
+    "Nagoya",
+    "Mitsubishi",
+    "Sumitomo",
+    "Mitsui",
+    "Fuji",
+    "Hitachi",
+    "Toshiba",
+    "Panasonic",
+    "Sony",
+    "Toyota",
+    "Honda",
+    "Global",
+    "World",
+    "Universal",
+    "Inter",
+    "Tech",
+];
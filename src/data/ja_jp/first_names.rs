@@ -106,6 +106,9 @@ pub const FIRST_NAMES: &[&str] = &[
     "敏子",
 ];
 
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 50;
+
 /// Romanized versions of Japanese first names (for email generation).
 pub const FIRST_NAMES_ROMANIZED: &[&str] = &[
     // Male names
@@ -7,38 +7,67 @@
 //! Japanese names traditionally have family name first (e.g., 田中 太郎 = Tanaka Taro).
 
 mod banks;
+mod calendar;
 mod cities;
 mod color_names;
 mod companies;
 mod first_names;
 mod last_names;
+mod name_affixes;
 mod prefectures;
 mod streets;
+mod text;
 
 pub use banks::BANK_NAMES;
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
 pub use cities::CITIES;
 pub use color_names::COLOR_NAMES;
 pub use companies::{
-    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_PREFIXES, COMPANY_SUFFIXES, JOB_TITLES,
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_PREFIXES_ROMANIZED, COMPANY_SUFFIXES, JOB_TITLES,
 };
-pub use first_names::{FIRST_NAMES, FIRST_NAMES_ROMANIZED};
+pub use first_names::{FIRST_NAMES, FIRST_NAMES_ROMANIZED, MALE_FIRST_NAME_COUNT};
 pub use last_names::{LAST_NAMES, LAST_NAMES_ROMANIZED};
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
 pub use prefectures::{PREFECTURES, PREFECTURE_ABBRS};
 pub use streets::{STREET_NAMES, STREET_SUFFIXES};
+pub use text::TEXT_WORDS;
 
 // Shared data
-use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
+use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, SAFE_EMAIL_DOMAINS, TLDS};
 
-use super::formats::{AddressFormat, PhoneFormat, PostalCodeFormat};
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    TextFormat, UnitFormat,
+};
 
 /// Phone format patterns for Japan.
 const JP_PHONE_PATTERNS: &[&str] = &["+81 ##-####-####", "0##-####-####", "+81 #-####-####"];
 
+/// Mobile phone patterns for Japan.
+const JP_MOBILE_PATTERNS: &[&str] = &[
+    "090-####-####",
+    "080-####-####",
+    "070-####-####",
+    "+81 90-####-####",
+    "+81 80-####-####",
+];
+
+/// Landline phone patterns for Japan.
+const JP_LANDLINE_PATTERNS: &[&str] = &[
+    "03-####-####",
+    "06-####-####",
+    "045-###-####",
+    "052-###-####",
+    "+81 3-####-####",
+];
+
 /// Postal code patterns for Japan (###-####).
 const JP_POSTAL_PATTERNS: &[&str] = &["###-####"];
 
 /// Japanese phone format specification.
-const JP_PHONE_FORMAT: PhoneFormat = PhoneFormat::new(JP_PHONE_PATTERNS, "+81");
+const JP_PHONE_FORMAT: PhoneFormat = PhoneFormat::with_trunk_prefix(JP_PHONE_PATTERNS, "+81", "0")
+    .with_kinds(JP_MOBILE_PATTERNS, JP_LANDLINE_PATTERNS);
 
 /// Japanese postal code format specification.
 const JP_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(JP_POSTAL_PATTERNS);
@@ -49,6 +78,26 @@ const JP_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(JP_POSTAL_PATTE
 const JP_ADDRESS_FORMAT: AddressFormat =
     AddressFormat::with_separator("〒{postal} {region}{city}{street}", false, "");
 
+/// Japanese room numbers follow the street (e.g., "中央通り12 305号室").
+const JP_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[UnitFormat::new("{n}号室", 101, 1205)],
+    "{street} {unit}",
+    "私書箱{n}号",
+);
+
+/// Japanese date format (year-month-day with kanji markers).
+const JP_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{yyyy}年{m}月{d}日",
+    "{yyyy}年{month}{d}日{weekday}",
+    "{H}:{MM}:{SS}",
+);
+
+/// Japanese number format.
+const JP_NUMBER_FORMAT: NumberFormat = NumberFormat::new(".", ",", "{symbol}{amount}");
+
+/// Japanese text format: no spaces between words or sentences, "、" and "。".
+const JP_TEXT_FORMAT: TextFormat = TextFormat::new("", "、", "。");
+
 /// Japanese locale data provider.
 pub struct JaJPData;
 
@@ -67,20 +116,31 @@ crate::impl_locale_data! {
     countries: COUNTRIES,
     postal_format: JP_POSTAL_FORMAT,
     address_format: JP_ADDRESS_FORMAT,
+    secondary_address_format: JP_SECONDARY_ADDRESS_FORMAT,
     phone_format: JP_PHONE_FORMAT,
     company_prefixes: COMPANY_PREFIXES,
     company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
     job_titles: JOB_TITLES,
     catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
     catch_phrase_nouns: CATCH_PHRASE_NOUNS,
-    text_words: LOREM_WORDS,
+    text_words: TEXT_WORDS,
     tlds: TLDS,
     free_email_domains: FREE_EMAIL_DOMAINS,
     safe_email_domains: SAFE_EMAIL_DOMAINS,
     color_names: COLOR_NAMES,
     bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: JP_DATE_FORMAT,
+    number_format: JP_NUMBER_FORMAT,
     romanized_first_names: FIRST_NAMES_ROMANIZED,
     romanized_last_names: LAST_NAMES_ROMANIZED,
+    romanized_company_prefixes: COMPANY_PREFIXES_ROMANIZED,
+    text_format: JP_TEXT_FORMAT,
 }
 
 #[cfg(test)]
@@ -114,8 +174,11 @@ mod tests {
         assert!(data.free_email_domains().is_some());
         assert!(data.safe_email_domains().is_some());
         assert!(data.color_names().is_some());
+        assert!(data.name_prefixes().is_some());
+        assert!(data.name_suffixes().is_some());
         assert!(data.romanized_first_names().is_some());
         assert!(data.romanized_last_names().is_some());
+        assert_eq!(data.text_format().full_stop, "。");
     }
 
     #[test]
@@ -0,0 +1,7 @@
+//! Japanese name prefixes and suffixes.
+
+/// Japanese name prefixes (none; honorifics follow the name).
+pub const NAME_PREFIXES: &[&str] = &[];
+
+/// Japanese honorific suffixes attached after the name.
+pub const NAME_SUFFIXES: &[&str] = &["様", "さん", "先生", "殿"];
@@ -0,0 +1,124 @@
+//! Japanese text word data.
+
+/// Common Japanese words for lorem-ipsum-style text.
+///
+/// Sentences join these without spaces and end with "。", so each entry is
+/// a whole word (noun, adjective, verb or adverb) rather than a syllable.
+pub const TEXT_WORDS: &[&str] = &[
+    "日本",
+    "東京",
+    "会社",
+    "時間",
+    "今日",
+    "明日",
+    "世界",
+    "仕事",
+    "学校",
+    "電車",
+    "天気",
+    "季節",
+    "情報",
+    "技術",
+    "社会",
+    "文化",
+    "経済",
+    "自然",
+    "未来",
+    "家族",
+    "友達",
+    "言葉",
+    "生活",
+    "問題",
+    "計画",
+    "結果",
+    "意見",
+    "経験",
+    "関係",
+    "環境",
+    "地域",
+    "場所",
+    "音楽",
+    "映画",
+    "写真",
+    "料理",
+    "旅行",
+    "研究",
+    "開発",
+    "設計",
+    "品質",
+    "価値",
+    "方法",
+    "目的",
+    "理由",
+    "準備",
+    "確認",
+    "連絡",
+    "説明",
+    "報告",
+    "会議",
+    "資料",
+    "予定",
+    "新しい",
+    "大きい",
+    "小さい",
+    "美しい",
+    "楽しい",
+    "早い",
+    "遅い",
+    "高い",
+    "安い",
+    "静かな",
+    "便利な",
+    "大切な",
+    "簡単な",
+    "特別な",
+    "自由な",
+    "元気な",
+    "見る",
+    "聞く",
+    "話す",
+    "書く",
+    "読む",
+    "作る",
+    "使う",
+    "考える",
+    "始める",
+    "続ける",
+    "変わる",
+    "集まる",
+    "進む",
+    "伝える",
+    "選ぶ",
+    "届ける",
+    "そして",
+    "しかし",
+    "また",
+    "さらに",
+    "とても",
+    "少し",
+    "いつも",
+    "すぐに",
+    "ゆっくり",
+    "みんな",
+    "ここ",
+    "そこ",
+    "春",
+    "夏",
+    "秋",
+    "冬",
+    "空",
+    "海",
+    "山",
+    "川",
+    "花",
+    "風",
+    "光",
+    "道",
+    "街",
+    "心",
+    "力",
+    "声",
+    "夢",
+    "朝",
+    "夜",
+];
@@ -0,0 +1,17 @@
+//! Korean month and weekday names.
+
+/// Korean month names.
+pub const MONTH_NAMES: &[&str] = &[
+    "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+];
+
+/// Korean weekday names, starting with Monday.
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "월요일",
+    "화요일",
+    "수요일",
+    "목요일",
+    "금요일",
+    "토요일",
+    "일요일",
+];
@@ -0,0 +1,45 @@
+//! Korean cities.
+
+/// Major Korean cities.
+pub const CITIES: &[&str] = &[
+    "서울",
+    "부산",
+    "인천",
+    "대구",
+    "대전",
+    "광주",
+    "울산",
+    "수원",
+    "창원",
+    "고양",
+    "용인",
+    "성남",
+    "청주",
+    "부천",
+    "화성",
+    "남양주",
+    "전주",
+    "천안",
+    "안산",
+    "안양",
+    "김해",
+    "평택",
+    "포항",
+    "제주",
+    "시흥",
+    "파주",
+    "의정부",
+    "김포",
+    "광명",
+    "원주",
+    "구미",
+    "춘천",
+    "진주",
+    "경주",
+    "목포",
+    "여수",
+    "순천",
+    "강릉",
+    "군산",
+    "익산",
+];
@@ -0,0 +1,35 @@
+//! Korean color names.
+
+/// Color names in Korean.
+pub const COLOR_NAMES: &[&str] = &[
+    "빨간색",
+    "파란색",
+    "초록색",
+    "노란색",
+    "주황색",
+    "보라색",
+    "분홍색",
+    "갈색",
+    "검은색",
+    "흰색",
+    "회색",
+    "베이지색",
+    "청록색",
+    "남색",
+    "하늘색",
+    "연두색",
+    "자주색",
+    "금색",
+    "은색",
+    "살구색",
+    "올리브색",
+    "라벤더색",
+    "민트색",
+    "버건디",
+    "코랄",
+    "아이보리",
+    "카키색",
+    "와인색",
+    "크림색",
+    "은회색",
+];
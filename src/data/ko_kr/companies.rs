@@ -0,0 +1,59 @@
+//! Korean company data.
+
+crate::define_company_data! {
+    prefixes: [
+        "삼성", "현대", "엘지", "한화", "롯데", "대한", "한국", "서울", "부산", "동양",
+        "대우", "신세계", "효성", "금호", "두산", "미래", "글로벌", "코리아", "태평양",
+        "한진", "아시아", "유니버설", "테크", "신한", "동원",
+    ],
+    suffixes: [
+        "주식회사", "유한회사", "그룹", "홀딩스", "코퍼레이션", "산업", "전자",
+        "테크놀로지", "시스템즈", "솔루션즈", "서비스", "엔터프라이즈", "건설", "물산",
+        "인터내셔널",
+    ],
+    job_titles: [
+        "소프트웨어 엔지니어", "프로젝트 매니저", "대표이사", "이사", "영업부장",
+        "마케팅 부장", "재무 분석가", "인사부장", "운영부장", "제품 관리자",
+        "IT 컨설턴트", "시스템 관리자", "품질 관리자", "구매부장", "물류 관리자",
+        "고객지원 담당자", "팀장", "과장", "대리", "사원", "연구원",
+    ],
+    adjectives: [
+        "혁신적인", "역동적인", "지속 가능한", "통합된", "전략적인", "최첨단", "효율적인",
+        "유연한", "전문적인", "고품질", "신뢰할 수 있는", "맞춤형", "글로벌", "선진적인",
+        "고객 중심의",
+    ],
+    nouns: [
+        "솔루션", "서비스", "제품", "시스템", "콘셉트", "전략", "기술", "혁신", "파트너십",
+        "품질", "컨설팅", "전문성", "경험", "역량", "성과",
+    ],
+    legal_forms: ["주식회사", "유한회사"],
+}
+
+/// Romanized company prefixes (in the same order as `COMPANY_PREFIXES`).
+pub const COMPANY_PREFIXES_ROMANIZED: &[&str] = &[
+    "Samsung",
+    "Hyundai",
+    "LG",
+    "Hanwha",
+    "Lotte",
+    "Daehan",
+    "Hankook",
+    "Seoul",
+    "Busan",
+    "Dongyang",
+    "Daewoo",
+    "Shinsegae",
+    "Hyosung",
+    "Kumho",
+    "Doosan",
+    "Mirae",
+    "Global",
+    "Korea",
+    "Taepyeongyang",
+    "Hanjin",
+    "Asia",
+    "Universal",
+    "Tech",
+    "Shinhan",
+    "Dongwon",
+];
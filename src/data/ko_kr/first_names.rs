@@ -0,0 +1,77 @@
+//! Korean given names.
+
+/// Common Korean given names (in Hangul), male names followed by female names.
+pub const FIRST_NAMES: &[&str] = &[
+    "민준", "서준", "도윤", "예준", "시우", "하준", "주원", "지호", "지후", "준우", "준서", "건우",
+    "현우", "우진", "선우", "서진", "민재", "현준", "연우", "유준", "정우", "승우", "승현", "시윤",
+    "준혁", "성민", "영호", "동현", "상철", "재현", "서연", "서윤", "지우", "서현", "민서", "하은",
+    "하윤", "윤서", "지유", "지민", "채원", "수아", "지아", "지윤", "은서", "다은", "예은", "수빈",
+    "소윤", "예린", "미영", "은지", "수진", "혜진", "지영", "유진", "민지", "영희", "정숙", "경희",
+];
+
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 30;
+
+/// Romanized versions of Korean given names (for email generation).
+pub const FIRST_NAMES_ROMANIZED: &[&str] = &[
+    "Minjun",
+    "Seojun",
+    "Doyun",
+    "Yejun",
+    "Siwoo",
+    "Hajun",
+    "Juwon",
+    "Jiho",
+    "Jihu",
+    "Junwoo",
+    "Junseo",
+    "Geonwoo",
+    "Hyunwoo",
+    "Woojin",
+    "Sunwoo",
+    "Seojin",
+    "Minjae",
+    "Hyunjun",
+    "Yeonwoo",
+    "Yujun",
+    "Jungwoo",
+    "Seungwoo",
+    "Seunghyun",
+    "Siyun",
+    "Junhyuk",
+    "Sungmin",
+    "Youngho",
+    "Donghyun",
+    "Sangcheol",
+    "Jaehyun",
+    "Seoyeon",
+    "Seoyun",
+    "Jiwoo",
+    "Seohyun",
+    "Minseo",
+    "Haeun",
+    "Hayun",
+    "Yunseo",
+    "Jiyu",
+    "Jimin",
+    "Chaewon",
+    "Sua",
+    "Jia",
+    "Jiyun",
+    "Eunseo",
+    "Daeun",
+    "Yeeun",
+    "Subin",
+    "Soyun",
+    "Yerin",
+    "Miyoung",
+    "Eunji",
+    "Sujin",
+    "Hyejin",
+    "Jiyoung",
+    "Yujin",
+    "Minji",
+    "Younghee",
+    "Jungsook",
+    "Kyunghee",
+];
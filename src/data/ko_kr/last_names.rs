@@ -0,0 +1,17 @@
+//! Korean surnames.
+
+/// Common Korean surnames (in Hangul).
+pub const LAST_NAMES: &[&str] = &[
+    "김", "이", "박", "최", "정", "강", "조", "윤", "장", "임", "한", "오", "서", "신", "권", "황",
+    "안", "송", "전", "홍", "유", "고", "문", "양", "손", "배", "백", "허", "남", "심", "노", "하",
+    "곽", "성", "차", "주", "우", "구", "민", "류", "나", "진", "지", "엄", "채", "원", "천", "방",
+    "공", "현",
+];
+
+/// Romanized versions of Korean surnames (for email generation).
+pub const LAST_NAMES_ROMANIZED: &[&str] = &[
+    "Kim", "Lee", "Park", "Choi", "Jung", "Kang", "Cho", "Yoon", "Jang", "Lim", "Han", "Oh", "Seo",
+    "Shin", "Kwon", "Hwang", "Ahn", "Song", "Jeon", "Hong", "Yoo", "Ko", "Moon", "Yang", "Son",
+    "Bae", "Baek", "Heo", "Nam", "Shim", "Noh", "Ha", "Kwak", "Sung", "Cha", "Joo", "Woo", "Koo",
+    "Min", "Ryu", "Na", "Jin", "Ji", "Eom", "Chae", "Won", "Chun", "Bang", "Kong", "Hyun",
+];
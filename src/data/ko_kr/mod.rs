@@ -0,0 +1,245 @@
+//! Korean (South Korea) locale data.
+//!
+//! Contains name lists and other data for ko_KR locale.
+//!
+//! Note: Korean names are provided in both Hangul and romanized forms.
+//! For emails, romanized forms are used to ensure ASCII compatibility.
+//! Korean names have the family name first (e.g., 김 민준 = Kim Minjun).
+
+mod banks;
+mod calendar;
+mod cities;
+mod color_names;
+mod companies;
+mod first_names;
+mod last_names;
+mod name_affixes;
+mod provinces;
+mod streets;
+
+pub use banks::BANK_NAMES;
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
+pub use cities::CITIES;
+pub use color_names::COLOR_NAMES;
+pub use companies::{
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_PREFIXES_ROMANIZED, COMPANY_SUFFIXES, JOB_TITLES,
+};
+pub use first_names::{FIRST_NAMES, FIRST_NAMES_ROMANIZED, MALE_FIRST_NAME_COUNT};
+pub use last_names::{LAST_NAMES, LAST_NAMES_ROMANIZED};
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
+pub use provinces::{PROVINCES, PROVINCE_ABBRS};
+pub use streets::{STREET_NAMES, STREET_SUFFIXES};
+
+// Shared data
+use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
+
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
+
+/// Phone format patterns for South Korea (mobile and Seoul/regional landlines).
+const KR_PHONE_PATTERNS: &[&str] = &[
+    "+82 10-####-####",
+    "010-####-####",
+    "+82 2-####-####",
+    "02-####-####",
+    "031-###-####",
+    "032-###-####",
+    "033-###-####",
+    "041-###-####",
+    "042-###-####",
+    "043-###-####",
+    "044-###-####",
+    "051-###-####",
+    "052-###-####",
+    "053-###-####",
+    "054-###-####",
+    "055-###-####",
+    "061-###-####",
+    "062-###-####",
+    "063-###-####",
+    "064-###-####",
+];
+
+/// Mobile phone patterns for South Korea.
+const KR_MOBILE_PATTERNS: &[&str] = &["010-####-####", "+82 10-####-####"];
+
+/// Landline phone patterns for South Korea.
+const KR_LANDLINE_PATTERNS: &[&str] = &[
+    "02-####-####",
+    "031-###-####",
+    "032-###-####",
+    "033-###-####",
+    "041-###-####",
+    "042-###-####",
+    "043-###-####",
+    "044-###-####",
+    "051-###-####",
+    "052-###-####",
+    "053-###-####",
+    "054-###-####",
+    "055-###-####",
+    "061-###-####",
+    "062-###-####",
+    "063-###-####",
+    "064-###-####",
+    "+82 2-####-####",
+];
+
+/// Postal code patterns for South Korea (5-digit zone codes).
+const KR_POSTAL_PATTERNS: &[&str] = &["#####"];
+
+/// Korean phone format specification.
+const KR_PHONE_FORMAT: PhoneFormat = PhoneFormat::with_trunk_prefix(KR_PHONE_PATTERNS, "+82", "0")
+    .with_kinds(KR_MOBILE_PATTERNS, KR_LANDLINE_PATTERNS);
+
+/// Korean postal code format specification.
+const KR_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(KR_POSTAL_PATTERNS);
+
+/// Korean address format specification.
+/// Korean road-name addresses go from largest to smallest: postal, province, city, road.
+/// Road names are compound words without spaces (e.g., "테헤란로 123" not "테헤란 로 123").
+const KR_ADDRESS_FORMAT: AddressFormat =
+    AddressFormat::with_separator("{postal} {region} {city} {street}", false, "");
+
+/// Korean unit numbers follow the road address (e.g., "테헤란로 123, 1001호").
+const KR_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[UnitFormat::new("{n}호", 101, 2505)],
+    "{street}, {unit}",
+    "사서함 {n}호",
+);
+
+/// Korean date format.
+const KR_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{yyyy}. {m}. {d}.",
+    "{yyyy}년 {month} {d}일 {weekday}",
+    "{HH}:{MM}:{SS}",
+);
+
+/// Korean number format.
+const KR_NUMBER_FORMAT: NumberFormat = NumberFormat::new(".", ",", "{symbol}{amount}");
+
+/// Korean locale data provider.
+pub struct KoKRData;
+
+/// Static instance of the Korean locale data.
+pub static KO_KR_DATA: KoKRData = KoKRData;
+
+crate::impl_locale_data! {
+    KoKRData,
+    first_names: FIRST_NAMES,
+    last_names: LAST_NAMES,
+    cities: CITIES,
+    regions: PROVINCES,
+    region_abbrs: PROVINCE_ABBRS,
+    street_names: STREET_NAMES,
+    street_suffixes: STREET_SUFFIXES,
+    countries: COUNTRIES,
+    postal_format: KR_POSTAL_FORMAT,
+    address_format: KR_ADDRESS_FORMAT,
+    secondary_address_format: KR_SECONDARY_ADDRESS_FORMAT,
+    phone_format: KR_PHONE_FORMAT,
+    company_prefixes: COMPANY_PREFIXES,
+    company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
+    job_titles: JOB_TITLES,
+    catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
+    catch_phrase_nouns: CATCH_PHRASE_NOUNS,
+    text_words: LOREM_WORDS,
+    tlds: TLDS,
+    free_email_domains: FREE_EMAIL_DOMAINS,
+    safe_email_domains: SAFE_EMAIL_DOMAINS,
+    color_names: COLOR_NAMES,
+    bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: KR_DATE_FORMAT,
+    number_format: KR_NUMBER_FORMAT,
+    romanized_first_names: FIRST_NAMES_ROMANIZED,
+    romanized_last_names: LAST_NAMES_ROMANIZED,
+    romanized_company_prefixes: COMPANY_PREFIXES_ROMANIZED,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::traits::LocaleData;
+
+    /// Area codes: Seoul (02) and the regional codes (031-064).
+    const KR_AREA_CODES: &[&str] = &[
+        "02", "031", "032", "033", "041", "042", "043", "044", "051", "052", "053", "054", "055",
+        "061", "062", "063", "064",
+    ];
+
+    #[test]
+    fn test_ko_kr_data_implements_locale_data() {
+        let data = &KO_KR_DATA;
+
+        // Test all LocaleData trait methods for full macro coverage
+        assert!(data.first_names().is_some());
+        assert!(data.last_names().is_some());
+        assert!(data.cities().is_some());
+        assert!(data.regions().is_some());
+        assert!(data.region_abbrs().is_some());
+        assert!(data.street_names().is_some());
+        assert!(data.street_suffixes().is_some());
+        assert!(data.countries().is_some());
+        assert!(data.postal_code_format().is_some());
+        assert!(data.address_format().is_some());
+        assert!(data.phone_format().is_some());
+        assert!(data.company_prefixes().is_some());
+        assert!(data.company_suffixes().is_some());
+        assert!(data.job_titles().is_some());
+        assert!(data.catch_phrase_adjectives().is_some());
+        assert!(data.catch_phrase_nouns().is_some());
+        assert!(data.text_words().is_some());
+        assert!(data.tlds().is_some());
+        assert!(data.free_email_domains().is_some());
+        assert!(data.safe_email_domains().is_some());
+        assert!(data.color_names().is_some());
+        assert!(data.name_prefixes().is_some());
+        assert!(data.name_suffixes().is_some());
+        assert!(data.romanized_first_names().is_some());
+        assert!(data.romanized_last_names().is_some());
+    }
+
+    #[test]
+    fn test_provinces_count() {
+        assert_eq!(PROVINCES.len(), 17);
+        assert_eq!(PROVINCES.len(), PROVINCE_ABBRS.len());
+    }
+
+    #[test]
+    fn test_romanized_names_match() {
+        // Verify romanized arrays match size with Hangul arrays
+        assert_eq!(FIRST_NAMES.len(), FIRST_NAMES_ROMANIZED.len());
+        assert_eq!(LAST_NAMES.len(), LAST_NAMES_ROMANIZED.len());
+    }
+
+    #[test]
+    fn test_phone_patterns_use_real_prefixes() {
+        for pattern in KR_PHONE_PATTERNS.iter().chain(KR_LANDLINE_PATTERNS) {
+            let national = pattern
+                .strip_prefix("+82 ")
+                .map(|rest| format!("0{}", rest));
+            let national = national.as_deref().unwrap_or(pattern);
+            let prefix = national.split('-').next().unwrap();
+            assert!(
+                prefix == "010" || KR_AREA_CODES.contains(&prefix),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn test_romanized_names_are_ascii() {
+        assert!(FIRST_NAMES_ROMANIZED.iter().all(|n| n.is_ascii()));
+        assert!(LAST_NAMES_ROMANIZED.iter().all(|n| n.is_ascii()));
+    }
+}
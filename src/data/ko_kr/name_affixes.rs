@@ -0,0 +1,7 @@
+//! Korean name prefixes and suffixes.
+
+/// Korean name prefixes (none; honorifics follow the name).
+pub const NAME_PREFIXES: &[&str] = &[];
+
+/// Korean honorific suffixes attached after the name.
+pub const NAME_SUFFIXES: &[&str] = &["님", "씨", "선생님"];
@@ -0,0 +1,28 @@
+//! Korean provinces and metropolitan cities.
+
+/// The 17 first-level administrative divisions of South Korea.
+pub const PROVINCES: &[&str] = &[
+    "서울특별시",
+    "부산광역시",
+    "대구광역시",
+    "인천광역시",
+    "광주광역시",
+    "대전광역시",
+    "울산광역시",
+    "세종특별자치시",
+    "경기도",
+    "강원특별자치도",
+    "충청북도",
+    "충청남도",
+    "전북특별자치도",
+    "전라남도",
+    "경상북도",
+    "경상남도",
+    "제주특별자치도",
+];
+
+/// Short forms of the Korean provinces (in the same order as `PROVINCES`).
+pub const PROVINCE_ABBRS: &[&str] = &[
+    "서울", "부산", "대구", "인천", "광주", "대전", "울산", "세종", "경기", "강원", "충북", "충남",
+    "전북", "전남", "경북", "경남", "제주",
+];
@@ -0,0 +1,38 @@
+//! Korean road name components.
+
+/// Common Korean road names.
+pub const STREET_NAMES: &[&str] = &[
+    "세종",
+    "테헤란",
+    "을지",
+    "종",
+    "중앙",
+    "충장",
+    "해운대",
+    "올림픽",
+    "한강",
+    "퇴계",
+    "남대문",
+    "삼일",
+    "도산",
+    "논현",
+    "봉은사",
+    "압구정",
+    "청계천",
+    "서초",
+    "반포",
+    "영동",
+    "양재",
+    "동일",
+    "새문안",
+    "사직",
+    "효자",
+    "광평",
+    "학동",
+    "신촌",
+    "성수",
+    "연희",
+];
+
+/// Korean road type suffixes.
+pub const STREET_SUFFIXES: &[&str] = &["로", "길", "대로", "번길"];
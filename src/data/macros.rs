@@ -8,6 +8,7 @@
 /// - `JOB_TITLES`
 /// - `CATCH_PHRASE_ADJECTIVES`
 /// - `CATCH_PHRASE_NOUNS`
+/// - `COMPANY_LEGAL_FORMS`
 ///
 /// # Example
 ///
@@ -18,6 +19,7 @@
 ///     job_titles: ["Engineer", "Manager"],
 ///     adjectives: ["Innovative", "Dynamic"],
 ///     nouns: ["solution", "strategy"],
+///     legal_forms: ["Inc", "LLC"],
 /// }
 /// ```
 #[macro_export]
@@ -27,7 +29,8 @@ macro_rules! define_company_data {
         suffixes: [$($suffix:literal),* $(,)?],
         job_titles: [$($job:literal),* $(,)?],
         adjectives: [$($adj:literal),* $(,)?],
-        nouns: [$($noun:literal),* $(,)?] $(,)?
+        nouns: [$($noun:literal),* $(,)?],
+        legal_forms: [$($form:literal),* $(,)?] $(,)?
     ) => {
         /// Company name prefixes.
         pub const COMPANY_PREFIXES: &[&str] = &[$($prefix),*];
@@ -43,6 +46,9 @@ macro_rules! define_company_data {
 
         /// Catch phrase nouns.
         pub const CATCH_PHRASE_NOUNS: &[&str] = &[$($noun),*];
+
+        /// Registered legal forms (e.g., "LLC", "GmbH").
+        pub const COMPANY_LEGAL_FORMS: &[&str] = &[$($form),*];
     };
 }
 
@@ -50,7 +56,10 @@ macro_rules! define_company_data {
 ///
 /// This macro generates the LocaleData trait implementation with all required methods.
 /// Romanized name parameters are optional - if not provided, the trait's default
-/// implementation (which returns the regular names) will be used.
+/// implementation (which returns the regular names) will be used. The same goes
+/// for `romanized_company_prefixes`; `masculine_last_names`,
+/// `region_area_codes` and `region_zip_prefixes` default to `None`, and
+/// `text_format` to `TextFormat::LATIN`.
 ///
 /// # Example
 ///
@@ -94,9 +103,11 @@ macro_rules! impl_locale_data {
         countries: $countries:expr,
         postal_format: $postal_format:expr,
         address_format: $address_format:expr,
+        secondary_address_format: $secondary_address_format:expr,
         phone_format: $phone_format:expr,
         company_prefixes: $company_prefixes:expr,
         company_suffixes: $company_suffixes:expr,
+        company_legal_forms: $company_legal_forms:expr,
         job_titles: $job_titles:expr,
         catch_phrase_adjectives: $catch_phrase_adjectives:expr,
         catch_phrase_nouns: $catch_phrase_nouns:expr,
@@ -105,9 +116,21 @@ macro_rules! impl_locale_data {
         free_email_domains: $free_email_domains:expr,
         safe_email_domains: $safe_email_domains:expr,
         color_names: $color_names:expr,
-        bank_names: $bank_names:expr
+        bank_names: $bank_names:expr,
+        name_prefixes: $name_prefixes:expr,
+        name_suffixes: $name_suffixes:expr,
+        male_first_names: $male_first_names:expr,
+        month_names: $month_names:expr,
+        weekday_names: $weekday_names:expr,
+        date_format: $date_format:expr,
+        number_format: $number_format:expr
         $(, romanized_first_names: $romanized_first_names:expr)?
         $(, romanized_last_names: $romanized_last_names:expr)?
+        $(, romanized_company_prefixes: $romanized_company_prefixes:expr)?
+        $(, masculine_last_names: $masculine_last_names:expr)?
+        $(, region_area_codes: $region_area_codes:expr)?
+        $(, region_zip_prefixes: $region_zip_prefixes:expr)?
+        $(, text_format: $text_format:expr)?
         $(,)?
     ) => {
         impl $crate::data::traits::LocaleData for $struct_name {
@@ -119,6 +142,18 @@ macro_rules! impl_locale_data {
                 Some($last_names)
             }
 
+            fn name_prefixes(&self) -> Option<&'static [&'static str]> {
+                Some($name_prefixes)
+            }
+
+            fn name_suffixes(&self) -> Option<&'static [&'static str]> {
+                Some($name_suffixes)
+            }
+
+            fn male_first_name_count(&self) -> Option<usize> {
+                Some($male_first_names)
+            }
+
             fn cities(&self) -> Option<&'static [&'static str]> {
                 Some($cities)
             }
@@ -151,6 +186,12 @@ macro_rules! impl_locale_data {
                 Some($address_format)
             }
 
+            fn secondary_address_format(
+                &self,
+            ) -> Option<$crate::data::formats::SecondaryAddressFormat> {
+                Some($secondary_address_format)
+            }
+
             fn phone_format(&self) -> Option<$crate::data::formats::PhoneFormat> {
                 Some($phone_format)
             }
@@ -163,6 +204,10 @@ macro_rules! impl_locale_data {
                 Some($company_suffixes)
             }
 
+            fn company_legal_forms(&self) -> Option<&'static [&'static str]> {
+                Some($company_legal_forms)
+            }
+
             fn job_titles(&self) -> Option<&'static [&'static str]> {
                 Some($job_titles)
             }
@@ -199,6 +244,22 @@ macro_rules! impl_locale_data {
                 Some($bank_names)
             }
 
+            fn month_names(&self) -> Option<&'static [&'static str]> {
+                Some($month_names)
+            }
+
+            fn weekday_names(&self) -> Option<&'static [&'static str]> {
+                Some($weekday_names)
+            }
+
+            fn date_format(&self) -> Option<$crate::data::formats::DateFormat> {
+                Some($date_format)
+            }
+
+            fn number_format(&self) -> Option<$crate::data::formats::NumberFormat> {
+                Some($number_format)
+            }
+
             $(
                 fn romanized_first_names(&self) -> Option<&'static [&'static str]> {
                     Some($romanized_first_names)
@@ -210,6 +271,36 @@ macro_rules! impl_locale_data {
                     Some($romanized_last_names)
                 }
             )?
+
+            $(
+                fn romanized_company_prefixes(&self) -> Option<&'static [&'static str]> {
+                    Some($romanized_company_prefixes)
+                }
+            )?
+
+            $(
+                fn masculine_last_name_count(&self) -> Option<usize> {
+                    Some($masculine_last_names)
+                }
+            )?
+
+            $(
+                fn region_area_codes(&self) -> Option<&'static [&'static [&'static str]]> {
+                    Some($region_area_codes)
+                }
+            )?
+
+            $(
+                fn region_zip_prefixes(&self) -> Option<&'static [(u16, u16)]> {
+                    Some($region_zip_prefixes)
+                }
+            )?
+
+            $(
+                fn text_format(&self) -> $crate::data::formats::TextFormat {
+                    $text_format
+                }
+            )?
         }
     };
 }
@@ -5,17 +5,24 @@
 #[macro_use]
 pub mod macros;
 pub mod formats;
+pub mod overrides;
 pub mod traits;
 
 pub mod de_de;
+pub mod en_ca;
 pub mod en_gb;
 pub mod en_us;
 pub mod es_es;
 pub mod fr_fr;
 pub mod it_it;
 pub mod ja_jp;
+pub mod ko_kr;
+pub mod nl_nl;
+pub mod pl_pl;
+pub mod ru_ru;
 
-pub use formats::{AddressFormat, PhoneFormat, PostalCodeFormat};
+pub use formats::{AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat};
+pub use overrides::{EmptyOverrideError, LocaleOverrides};
 pub use traits::LocaleData;
 
 use crate::locale::Locale;
@@ -42,6 +49,11 @@ pub fn get_locale_data(locale: Locale) -> &'static dyn LocaleData {
         Locale::ItIT => &it_it::IT_IT_DATA,
         Locale::JaJP => &ja_jp::JA_JP_DATA,
         Locale::EnGB => &en_gb::EN_GB_DATA,
+        Locale::KoKR => &ko_kr::KO_KR_DATA,
+        Locale::RuRU => &ru_ru::RU_RU_DATA,
+        Locale::NlNL => &nl_nl::NL_NL_DATA,
+        Locale::PlPL => &pl_pl::PL_PL_DATA,
+        Locale::EnCA => &en_ca::EN_CA_DATA,
     }
 }
 
@@ -68,4 +80,71 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_name_lists_align_for_profiles() {
+        for locale in Locale::ALL {
+            let data = get_locale_data(*locale);
+            let first = data.first_names().unwrap();
+            let last = data.last_names().unwrap();
+            assert_eq!(
+                data.romanized_first_names().map(<[_]>::len),
+                Some(first.len()),
+                "{}",
+                locale
+            );
+            assert_eq!(
+                data.romanized_last_names().map(<[_]>::len),
+                Some(last.len()),
+                "{}",
+                locale
+            );
+            let male = data.male_first_name_count().unwrap();
+            assert!(male > 0 && male < first.len(), "{}", locale);
+            if let Some(masculine) = data.masculine_last_name_count() {
+                assert_eq!(masculine * 2, last.len(), "{}", locale);
+            }
+            if let Some(codes) = data.region_area_codes() {
+                assert_eq!(codes.len(), data.regions().unwrap().len(), "{}", locale);
+                assert!(codes.iter().all(|c| !c.is_empty()), "{}", locale);
+            }
+            if let Some(prefixes) = data.region_zip_prefixes() {
+                assert_eq!(prefixes.len(), data.regions().unwrap().len(), "{}", locale);
+                assert!(
+                    prefixes.iter().all(|(min, max)| min <= max && *max <= 999),
+                    "{}",
+                    locale
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_company_data_for_profiles() {
+        for locale in Locale::ALL {
+            let data = get_locale_data(*locale);
+            assert!(
+                !data.company_legal_forms().unwrap().is_empty(),
+                "{}",
+                locale
+            );
+            let romanized = data.romanized_company_prefixes().unwrap();
+            assert_eq!(
+                romanized.len(),
+                data.company_prefixes().unwrap().len(),
+                "{}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn test_all_locales_have_calendar_data() {
+        for locale in Locale::ALL {
+            let data = get_locale_data(*locale);
+            assert_eq!(data.month_names().map(<[_]>::len), Some(12), "{}", locale);
+            assert_eq!(data.weekday_names().map(<[_]>::len), Some(7), "{}", locale);
+            assert!(data.date_format().is_some(), "{}", locale);
+        }
+    }
 }
@@ -0,0 +1,20 @@
+//! Bank name data for nl_NL locale.
+
+/// Dutch bank names.
+pub const BANK_NAMES: &[&str] = &[
+    "ING",
+    "Rabobank",
+    "ABN AMRO",
+    "de Volksbank",
+    "SNS Bank",
+    "ASN Bank",
+    "RegioBank",
+    "Triodos Bank",
+    "Knab",
+    "bunq",
+    "Van Lanschot",
+    "NIBC",
+    "Achmea Bank",
+    "Aegon Bank",
+    "BNG Bank",
+];
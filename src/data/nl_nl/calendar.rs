@@ -0,0 +1,28 @@
+//! Dutch month and weekday names.
+
+/// Dutch month names.
+pub const MONTH_NAMES: &[&str] = &[
+    "januari",
+    "februari",
+    "maart",
+    "april",
+    "mei",
+    "juni",
+    "juli",
+    "augustus",
+    "september",
+    "oktober",
+    "november",
+    "december",
+];
+
+/// Dutch weekday names, starting with Monday.
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "maandag",
+    "dinsdag",
+    "woensdag",
+    "donderdag",
+    "vrijdag",
+    "zaterdag",
+    "zondag",
+];
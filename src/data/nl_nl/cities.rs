@@ -0,0 +1,35 @@
+//! Dutch cities.
+
+/// Major Dutch cities.
+pub const CITIES: &[&str] = &[
+    "Amsterdam",
+    "Rotterdam",
+    "Den Haag",
+    "Utrecht",
+    "Eindhoven",
+    "Groningen",
+    "Tilburg",
+    "Almere",
+    "Breda",
+    "Nijmegen",
+    "Apeldoorn",
+    "Haarlem",
+    "Arnhem",
+    "Enschede",
+    "Amersfoort",
+    "Zaanstad",
+    "'s-Hertogenbosch",
+    "Zwolle",
+    "Leiden",
+    "Maastricht",
+    "Dordrecht",
+    "Zoetermeer",
+    "Ede",
+    "Delft",
+    "Alkmaar",
+    "Emmen",
+    "Venlo",
+    "Deventer",
+    "Leeuwarden",
+    "Hilversum",
+];
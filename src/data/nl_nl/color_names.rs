@@ -0,0 +1,35 @@
+//! Dutch color names.
+
+/// Color names in Dutch.
+pub const COLOR_NAMES: &[&str] = &[
+    "rood",
+    "blauw",
+    "groen",
+    "geel",
+    "oranje",
+    "paars",
+    "roze",
+    "bruin",
+    "zwart",
+    "wit",
+    "grijs",
+    "beige",
+    "turquoise",
+    "marineblauw",
+    "bordeauxrood",
+    "goud",
+    "zilver",
+    "lila",
+    "olijfgroen",
+    "hemelsblauw",
+    "zalmroze",
+    "koraalrood",
+    "mintgroen",
+    "crème",
+    "lavendel",
+    "oker",
+    "donkergroen",
+    "lichtblauw",
+    "magenta",
+    "cyaan",
+];
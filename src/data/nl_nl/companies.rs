@@ -0,0 +1,105 @@
+//! Dutch company data.
+
+crate::define_company_data! {
+    prefixes: [
+        "Van Dijk",
+        "De Vries",
+        "Jansen",
+        "Bakker",
+        "Holland",
+        "Nederland",
+        "Noord",
+        "Zuid",
+        "Delta",
+        "Oranje",
+        "Amstel",
+        "Rijn",
+        "Maas",
+        "Polder",
+        "Tulp",
+        "Molen",
+        "Dijk",
+        "Haven",
+        "Euro",
+        "Global",
+        "Tech",
+        "Data",
+        "Groen",
+        "Blauw",
+        "Zeeland",
+    ],
+    suffixes: [
+        "B.V.",
+        "N.V.",
+        "Groep",
+        "Holding",
+        "& Zonen",
+        "Partners",
+        "Consultancy",
+        "Techniek",
+        "Bouw",
+        "Diensten",
+        "Logistiek",
+        "Solutions",
+        "Advies",
+        "Installatie",
+        "Media",
+    ],
+    job_titles: [
+        "Softwareontwikkelaar",
+        "Projectmanager",
+        "Directeur",
+        "Accountmanager",
+        "Financieel analist",
+        "HR-adviseur",
+        "Marketingmanager",
+        "Verkoopmedewerker",
+        "Systeembeheerder",
+        "Klantenservicemedewerker",
+        "Teamleider",
+        "Consultant",
+        "Boekhouder",
+        "Jurist",
+        "Logistiek medewerker",
+        "Productmanager",
+        "Data-analist",
+        "Office manager",
+        "Inkoper",
+        "Monteur",
+    ],
+    adjectives: [
+        "Innovatieve",
+        "Duurzame",
+        "Dynamische",
+        "Flexibele",
+        "Betrouwbare",
+        "Efficiënte",
+        "Strategische",
+        "Geïntegreerde",
+        "Klantgerichte",
+        "Moderne",
+        "Slimme",
+        "Professionele",
+        "Toekomstgerichte",
+        "Hoogwaardige",
+        "Creatieve",
+    ],
+    nouns: [
+        "oplossingen",
+        "diensten",
+        "producten",
+        "systemen",
+        "strategieën",
+        "technologie",
+        "innovatie",
+        "samenwerking",
+        "kwaliteit",
+        "expertise",
+        "advies",
+        "ervaring",
+        "platforms",
+        "concepten",
+        "processen",
+    ],
+    legal_forms: ["B.V.", "N.V.", "V.O.F.", "C.V."],
+}
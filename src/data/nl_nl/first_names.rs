@@ -0,0 +1,58 @@
+//! Dutch first names.
+
+/// Common Dutch first names, male names followed by female names.
+pub const FIRST_NAMES: &[&str] = &[
+    "Daan",
+    "Sem",
+    "Lucas",
+    "Levi",
+    "Finn",
+    "Milan",
+    "Noah",
+    "Bram",
+    "Luuk",
+    "Thijs",
+    "Jesse",
+    "Sven",
+    "Ruben",
+    "Lars",
+    "Tim",
+    "Jan",
+    "Pieter",
+    "Kees",
+    "Willem",
+    "Hendrik",
+    "Johannes",
+    "Gerrit",
+    "Dirk",
+    "Bas",
+    "Joost",
+    "Emma",
+    "Julia",
+    "Sophie",
+    "Tess",
+    "Nina",
+    "Mila",
+    "Sara",
+    "Anna",
+    "Eva",
+    "Lotte",
+    "Fleur",
+    "Sanne",
+    "Lisa",
+    "Femke",
+    "Noor",
+    "Iris",
+    "Anouk",
+    "Maria",
+    "Johanna",
+    "Cornelia",
+    "Wilhelmina",
+    "Marieke",
+    "Ingrid",
+    "Annelies",
+    "Esther",
+];
+
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 25;
@@ -0,0 +1,163 @@
+//! Dutch (Netherlands) locale data.
+//!
+//! Contains name lists and other data for nl_NL locale.
+
+mod banks;
+mod calendar;
+mod cities;
+mod color_names;
+mod companies;
+mod first_names;
+mod last_names;
+mod name_affixes;
+mod provincies;
+mod streets;
+
+pub use banks::BANK_NAMES;
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
+pub use cities::CITIES;
+pub use color_names::COLOR_NAMES;
+pub use companies::{
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_SUFFIXES, JOB_TITLES,
+};
+pub use first_names::{FIRST_NAMES, MALE_FIRST_NAME_COUNT};
+pub use last_names::LAST_NAMES;
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
+pub use provincies::{PROVINCIES, PROVINCIE_ABBRS};
+pub use streets::{STREET_NAMES, STREET_SUFFIXES};
+
+// Shared data
+use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
+
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
+
+/// Phone format patterns for the Netherlands (mobile and landline).
+const NL_PHONE_PATTERNS: &[&str] = &[
+    "+31 6 ########",
+    "06-########",
+    "+31 20 ### ####",
+    "0## ### ####",
+];
+
+/// Mobile phone patterns for the Netherlands.
+const NL_MOBILE_PATTERNS: &[&str] = &["06-########", "+31 6 ########"];
+
+/// Landline phone patterns for the Netherlands.
+const NL_LANDLINE_PATTERNS: &[&str] = &[
+    "020 ### ####",
+    "010 ### ####",
+    "070 ### ####",
+    "+31 20 ### ####",
+];
+
+/// Postal code patterns for the Netherlands (4 digits + 2 letters, e.g., "1234 AB").
+const NL_POSTAL_PATTERNS: &[&str] = &["#### AA"];
+
+/// Dutch phone format specification.
+const NL_PHONE_FORMAT: PhoneFormat = PhoneFormat::with_trunk_prefix(NL_PHONE_PATTERNS, "+31", "0")
+    .with_kinds(NL_MOBILE_PATTERNS, NL_LANDLINE_PATTERNS);
+
+/// Dutch postal code format specification.
+const NL_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(NL_POSTAL_PATTERNS);
+
+/// Dutch address format specification.
+/// In the Netherlands, the house number comes after the street name, and street
+/// names are compound words without spaces (e.g., "Kerkstraat 12").
+const NL_ADDRESS_FORMAT: AddressFormat =
+    AddressFormat::with_separator("{street}\n{postal} {city}", false, "");
+
+/// Dutch units are house number additions: a floor or "hs" for the ground
+/// floor (e.g., "Kerkstraat 12-2", "Kerkstraat 12-hs").
+const NL_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[UnitFormat::new("{n}", 1, 4), UnitFormat::new("hs", 0, 0)],
+    "{street}-{unit}",
+    "Postbus {n}",
+);
+
+/// Dutch date format.
+const NL_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{d}-{m}-{yyyy}",
+    "{weekday} {d} {month} {yyyy}",
+    "{HH}:{MM}:{SS}",
+);
+
+/// Dutch number format.
+const NL_NUMBER_FORMAT: NumberFormat = NumberFormat::new(",", ".", "{symbol} {amount}");
+
+/// Dutch locale data provider.
+pub struct NlNLData;
+
+/// Static instance of the Dutch locale data.
+pub static NL_NL_DATA: NlNLData = NlNLData;
+
+crate::impl_locale_data! {
+    NlNLData,
+    first_names: FIRST_NAMES,
+    last_names: LAST_NAMES,
+    cities: CITIES,
+    regions: PROVINCIES,
+    region_abbrs: PROVINCIE_ABBRS,
+    street_names: STREET_NAMES,
+    street_suffixes: STREET_SUFFIXES,
+    countries: COUNTRIES,
+    postal_format: NL_POSTAL_FORMAT,
+    address_format: NL_ADDRESS_FORMAT,
+    secondary_address_format: NL_SECONDARY_ADDRESS_FORMAT,
+    phone_format: NL_PHONE_FORMAT,
+    company_prefixes: COMPANY_PREFIXES,
+    company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
+    job_titles: JOB_TITLES,
+    catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
+    catch_phrase_nouns: CATCH_PHRASE_NOUNS,
+    text_words: LOREM_WORDS,
+    tlds: TLDS,
+    free_email_domains: FREE_EMAIL_DOMAINS,
+    safe_email_domains: SAFE_EMAIL_DOMAINS,
+    color_names: COLOR_NAMES,
+    bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: NL_DATE_FORMAT,
+    number_format: NL_NUMBER_FORMAT,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::traits::LocaleData;
+
+    #[test]
+    fn test_nl_nl_data_implements_locale_data() {
+        let data = &NL_NL_DATA;
+        assert!(data.first_names().is_some());
+        assert!(data.last_names().is_some());
+        assert!(data.cities().is_some());
+        assert!(data.regions().is_some());
+    }
+
+    #[test]
+    fn test_nl_phone_format() {
+        let format = NL_PHONE_FORMAT;
+        assert_eq!(format.country_code, "+31");
+    }
+
+    #[test]
+    fn test_provincies_count() {
+        assert_eq!(PROVINCIES.len(), 12);
+        assert_eq!(PROVINCIE_ABBRS.len(), 12);
+    }
+
+    #[test]
+    fn test_first_names_are_ascii() {
+        // First names feed email generation directly (no romanized set)
+        assert!(FIRST_NAMES.iter().all(|n| n.is_ascii()));
+    }
+}
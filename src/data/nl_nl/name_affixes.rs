@@ -0,0 +1,7 @@
+//! Dutch name prefixes and suffixes.
+
+/// Dutch name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &["Dhr.", "Mevr.", "Dr.", "Prof.", "Mr.", "Ir."];
+
+/// Dutch name suffixes (generational and post-nominal).
+pub const NAME_SUFFIXES: &[&str] = &["MSc", "BSc", "MBA"];
@@ -0,0 +1,22 @@
+//! Dutch provinces.
+
+/// The 12 Dutch provinces.
+pub const PROVINCIES: &[&str] = &[
+    "Drenthe",
+    "Flevoland",
+    "Friesland",
+    "Gelderland",
+    "Groningen",
+    "Limburg",
+    "Noord-Brabant",
+    "Noord-Holland",
+    "Overijssel",
+    "Utrecht",
+    "Zeeland",
+    "Zuid-Holland",
+];
+
+/// Dutch province abbreviations (in the same order as `PROVINCIES`).
+pub const PROVINCIE_ABBRS: &[&str] = &[
+    "DR", "FL", "FR", "GE", "GR", "LI", "NB", "NH", "OV", "UT", "ZE", "ZH",
+];
@@ -0,0 +1,40 @@
+//! Dutch street names and suffixes.
+
+/// Common Dutch street name stems.
+pub const STREET_NAMES: &[&str] = &[
+    "Kerk",
+    "Molen",
+    "School",
+    "Dorps",
+    "Stations",
+    "Hoofd",
+    "Markt",
+    "Wilhelmina",
+    "Juliana",
+    "Beatrix",
+    "Oranje",
+    "Prinsen",
+    "Heeren",
+    "Keizers",
+    "Linden",
+    "Eiken",
+    "Beuken",
+    "Berken",
+    "Rozen",
+    "Tulpen",
+    "Zuider",
+    "Noorder",
+    "Ooster",
+    "Wester",
+    "Park",
+    "Haven",
+    "Brink",
+    "Kastanje",
+    "Vondel",
+    "Rembrandt",
+];
+
+/// Dutch street type suffixes (joined to the stem without a space).
+pub const STREET_SUFFIXES: &[&str] = &[
+    "straat", "weg", "laan", "plein", "gracht", "dijk", "singel", "kade", "steeg", "pad",
+];
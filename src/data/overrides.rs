@@ -0,0 +1,196 @@
+//! User-supplied overrides for locale data.
+//!
+//! Overrides replace individual data lists (first names, cities, ...) for a
+//! single `Faker` instance. Everything that is not overridden, including the
+//! locale's name order and address/phone/postal formats, still comes from the
+//! built-in static data.
+
+use crate::rng::ForgeryRng;
+
+/// Error when an override list is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmptyOverrideError {
+    /// Name of the field whose override list was empty.
+    pub field: &'static str,
+}
+
+impl std::fmt::Display for EmptyOverrideError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "override list for '{}' cannot be empty", self.field)
+    }
+}
+
+impl std::error::Error for EmptyOverrideError {}
+
+/// Per-instance replacements for locale data lists.
+///
+/// A field set to `None` falls through to the locale's built-in data.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocaleOverrides {
+    /// Replacement first names.
+    pub first_names: Option<Vec<String>>,
+    /// Replacement last names.
+    pub last_names: Option<Vec<String>>,
+    /// Replacement city names.
+    pub cities: Option<Vec<String>>,
+    /// Replacement street names (street types still come from the locale).
+    pub street_names: Option<Vec<String>>,
+}
+
+impl LocaleOverrides {
+    /// Overrides with every field unset.
+    pub const EMPTY: Self = Self {
+        first_names: None,
+        last_names: None,
+        cities: None,
+        street_names: None,
+    };
+
+    /// Returns `true` if no field is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.first_names.is_none()
+            && self.last_names.is_none()
+            && self.cities.is_none()
+            && self.street_names.is_none()
+    }
+
+    /// Layer `other` on top of these overrides.
+    ///
+    /// Fields set in `other` replace the current value; unset fields keep
+    /// whatever was overridden before.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EmptyOverrideError` if any list in `other` is empty. Nothing is
+    /// applied in that case.
+    pub fn merge(&mut self, other: LocaleOverrides) -> Result<(), EmptyOverrideError> {
+        other.validate()?;
+        if other.first_names.is_some() {
+            self.first_names = other.first_names;
+        }
+        if other.last_names.is_some() {
+            self.last_names = other.last_names;
+        }
+        if other.cities.is_some() {
+            self.cities = other.cities;
+        }
+        if other.street_names.is_some() {
+            self.street_names = other.street_names;
+        }
+        Ok(())
+    }
+
+    /// Check that no override list is empty.
+    fn validate(&self) -> Result<(), EmptyOverrideError> {
+        let fields = [
+            ("first_names", &self.first_names),
+            ("last_names", &self.last_names),
+            ("cities", &self.cities),
+            ("street_names", &self.street_names),
+        ];
+        for (field, values) in fields {
+            if values.as_ref().is_some_and(|v| v.is_empty()) {
+                return Err(EmptyOverrideError { field });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Pick a value from the override list if set, otherwise from the locale's list.
+///
+/// Falls back to `fallback` when neither list has values. Consumes randomness
+/// exactly like choosing from the locale list directly, so output without
+/// overrides is unchanged.
+#[inline]
+pub(crate) fn choose_with_override<'a>(
+    rng: &mut ForgeryRng,
+    custom: Option<&'a [String]>,
+    base: &'static [&'static str],
+    fallback: &'static str,
+) -> &'a str {
+    if let Some(values) = custom.filter(|v| !v.is_empty()) {
+        rng.choose(values).as_str()
+    } else if base.is_empty() {
+        fallback
+    } else {
+        rng.choose::<&str>(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_overrides() {
+        assert!(LocaleOverrides::EMPTY.is_empty());
+        assert!(LocaleOverrides::default().is_empty());
+    }
+
+    #[test]
+    fn test_merge_layers_fields() {
+        let mut overrides = LocaleOverrides::default();
+        overrides
+            .merge(LocaleOverrides {
+                first_names: Some(vec!["Ada".to_string()]),
+                ..Default::default()
+            })
+            .unwrap();
+        overrides
+            .merge(LocaleOverrides {
+                cities: Some(vec!["Gotham".to_string()]),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(overrides.first_names, Some(vec!["Ada".to_string()]));
+        assert_eq!(overrides.cities, Some(vec!["Gotham".to_string()]));
+        assert!(overrides.last_names.is_none());
+    }
+
+    #[test]
+    fn test_merge_rejects_empty_list() {
+        let mut overrides = LocaleOverrides::default();
+        let err = overrides
+            .merge(LocaleOverrides {
+                first_names: Some(vec!["Ada".to_string()]),
+                last_names: Some(vec![]),
+                ..Default::default()
+            })
+            .unwrap_err();
+        assert_eq!(err.field, "last_names");
+        // Nothing applied on error
+        assert!(overrides.is_empty());
+    }
+
+    #[test]
+    fn test_choose_with_override() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let custom = vec!["Custom".to_string()];
+
+        assert_eq!(
+            choose_with_override(&mut rng, Some(&custom), &["Base"], "x"),
+            "Custom"
+        );
+        assert_eq!(choose_with_override(&mut rng, None, &["Base"], "x"), "Base");
+        assert_eq!(choose_with_override(&mut rng, None, &[], "x"), "x");
+    }
+
+    #[test]
+    fn test_choose_without_override_matches_plain_choose() {
+        let base: &[&str] = &["a", "b", "c", "d", "e"];
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+
+        for _ in 0..50 {
+            assert_eq!(
+                choose_with_override(&mut rng1, None, base, "x"),
+                *rng2.choose(base)
+            );
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Bank name data for pl_PL locale.
+
+/// Polish bank names.
+pub const BANK_NAMES: &[&str] = &[
+    "PKO Bank Polski",
+    "Bank Pekao",
+    "Santander Bank Polska",
+    "mBank",
+    "ING Bank Śląski",
+    "Bank Millennium",
+    "Alior Bank",
+    "BNP Paribas Bank Polska",
+    "Citi Handlowy",
+    "Credit Agricole Bank Polska",
+    "Bank Pocztowy",
+    "VeloBank",
+    "Nest Bank",
+    "Bank Ochrony Środowiska",
+    "Toyota Bank",
+];
@@ -0,0 +1,28 @@
+//! Polish month and weekday names.
+
+/// Polish month names in the genitive case, as used in dates.
+pub const MONTH_NAMES: &[&str] = &[
+    "stycznia",
+    "lutego",
+    "marca",
+    "kwietnia",
+    "maja",
+    "czerwca",
+    "lipca",
+    "sierpnia",
+    "września",
+    "października",
+    "listopada",
+    "grudnia",
+];
+
+/// Polish weekday names, starting with Monday.
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "poniedziałek",
+    "wtorek",
+    "środa",
+    "czwartek",
+    "piątek",
+    "sobota",
+    "niedziela",
+];
@@ -0,0 +1,45 @@
+//! Polish cities.
+
+/// Major Polish cities.
+pub const CITIES: &[&str] = &[
+    "Warszawa",
+    "Kraków",
+    "Wrocław",
+    "Łódź",
+    "Poznań",
+    "Gdańsk",
+    "Szczecin",
+    "Bydgoszcz",
+    "Lublin",
+    "Białystok",
+    "Katowice",
+    "Gdynia",
+    "Częstochowa",
+    "Radom",
+    "Rzeszów",
+    "Toruń",
+    "Sosnowiec",
+    "Kielce",
+    "Gliwice",
+    "Olsztyn",
+    "Zabrze",
+    "Bielsko-Biała",
+    "Bytom",
+    "Zielona Góra",
+    "Rybnik",
+    "Ruda Śląska",
+    "Opole",
+    "Tychy",
+    "Gorzów Wielkopolski",
+    "Elbląg",
+    "Płock",
+    "Wałbrzych",
+    "Włocławek",
+    "Tarnów",
+    "Chorzów",
+    "Koszalin",
+    "Kalisz",
+    "Legnica",
+    "Grudziądz",
+    "Słupsk",
+];
@@ -0,0 +1,35 @@
+//! Polish color names.
+
+/// Color names in Polish.
+pub const COLOR_NAMES: &[&str] = &[
+    "czerwony",
+    "niebieski",
+    "zielony",
+    "żółty",
+    "pomarańczowy",
+    "fioletowy",
+    "różowy",
+    "brązowy",
+    "czarny",
+    "biały",
+    "szary",
+    "beżowy",
+    "turkusowy",
+    "granatowy",
+    "bordowy",
+    "złoty",
+    "srebrny",
+    "liliowy",
+    "oliwkowy",
+    "błękitny",
+    "łososiowy",
+    "koralowy",
+    "miętowy",
+    "kremowy",
+    "lawendowy",
+    "purpurowy",
+    "grafitowy",
+    "seledynowy",
+    "karmazynowy",
+    "amarantowy",
+];
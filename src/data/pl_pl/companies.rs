@@ -0,0 +1,105 @@
+//! Polish company data.
+
+crate::define_company_data! {
+    prefixes: [
+        "Nowak",
+        "Kowalski",
+        "Polska",
+        "Wisła",
+        "Mazowsze",
+        "Bałtyk",
+        "Tatra",
+        "Orzeł",
+        "Sokół",
+        "Piast",
+        "Warta",
+        "Odra",
+        "Karpaty",
+        "Euro",
+        "Inter",
+        "Global",
+        "Tech",
+        "Data",
+        "Mega",
+        "Poltex",
+        "Polmex",
+        "Budimex",
+        "Energo",
+        "Agro",
+        "Trans",
+    ],
+    suffixes: [
+        "Sp. z o.o.",
+        "S.A.",
+        "Sp.j.",
+        "Sp.k.",
+        "Grupa",
+        "Holding",
+        "i Wspólnicy",
+        "Technologie",
+        "Budownictwo",
+        "Usługi",
+        "Logistyka",
+        "Consulting",
+        "Systemy",
+        "Serwis",
+        "Handel",
+    ],
+    job_titles: [
+        "Programista",
+        "Kierownik projektu",
+        "Prezes zarządu",
+        "Dyrektor finansowy",
+        "Księgowy",
+        "Główny księgowy",
+        "Przedstawiciel handlowy",
+        "Specjalista ds. marketingu",
+        "Analityk",
+        "Specjalista ds. HR",
+        "Administrator systemów",
+        "Inżynier",
+        "Prawnik",
+        "Logistyk",
+        "Specjalista ds. zakupów",
+        "Kierownik działu",
+        "Tester oprogramowania",
+        "Grafik",
+        "Ekonomista",
+        "Asystent biura",
+    ],
+    adjectives: [
+        "Innowacyjne",
+        "Niezawodne",
+        "Efektywne",
+        "Nowoczesne",
+        "Zaawansowane",
+        "Elastyczne",
+        "Kompleksowe",
+        "Profesjonalne",
+        "Wysokiej jakości",
+        "Indywidualne",
+        "Strategiczne",
+        "Dynamiczne",
+        "Zrównoważone",
+        "Zintegrowane",
+        "Sprawdzone",
+    ],
+    nouns: [
+        "rozwiązania",
+        "usługi",
+        "produkty",
+        "systemy",
+        "technologie",
+        "strategie",
+        "innowacje",
+        "partnerstwa",
+        "doradztwo",
+        "serwisy",
+        "projekty",
+        "platformy",
+        "koncepcje",
+        "możliwości",
+        "procesy",
+    ],
+    legal_forms: ["Sp. z o.o.", "S.A.", "Sp.j.", "Sp.k."],
+}
@@ -0,0 +1,112 @@
+//! Polish first names.
+
+/// Common Polish first names (includes diacritics), male names followed by female names.
+pub const FIRST_NAMES: &[&str] = &[
+    "Jan",
+    "Piotr",
+    "Krzysztof",
+    "Andrzej",
+    "Tomasz",
+    "Paweł",
+    "Marcin",
+    "Michał",
+    "Marek",
+    "Grzegorz",
+    "Józef",
+    "Łukasz",
+    "Adam",
+    "Zbigniew",
+    "Jerzy",
+    "Tadeusz",
+    "Mateusz",
+    "Dariusz",
+    "Mariusz",
+    "Wojciech",
+    "Jakub",
+    "Kacper",
+    "Szymon",
+    "Antoni",
+    "Filip",
+    "Anna",
+    "Maria",
+    "Katarzyna",
+    "Małgorzata",
+    "Agnieszka",
+    "Barbara",
+    "Ewa",
+    "Krystyna",
+    "Elżbieta",
+    "Zofia",
+    "Joanna",
+    "Magdalena",
+    "Monika",
+    "Aleksandra",
+    "Natalia",
+    "Julia",
+    "Zuzanna",
+    "Maja",
+    "Hanna",
+    "Lena",
+    "Alicja",
+    "Oliwia",
+    "Wiktoria",
+    "Dorota",
+    "Beata",
+];
+
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 25;
+
+/// ASCII-safe romanized Polish first names for email generation.
+pub const ROMANIZED_FIRST_NAMES: &[&str] = &[
+    "Jan",
+    "Piotr",
+    "Krzysztof",
+    "Andrzej",
+    "Tomasz",
+    "Pawel",
+    "Marcin",
+    "Michal",
+    "Marek",
+    "Grzegorz",
+    "Jozef",
+    "Lukasz",
+    "Adam",
+    "Zbigniew",
+    "Jerzy",
+    "Tadeusz",
+    "Mateusz",
+    "Dariusz",
+    "Mariusz",
+    "Wojciech",
+    "Jakub",
+    "Kacper",
+    "Szymon",
+    "Antoni",
+    "Filip",
+    "Anna",
+    "Maria",
+    "Katarzyna",
+    "Malgorzata",
+    "Agnieszka",
+    "Barbara",
+    "Ewa",
+    "Krystyna",
+    "Elzbieta",
+    "Zofia",
+    "Joanna",
+    "Magdalena",
+    "Monika",
+    "Aleksandra",
+    "Natalia",
+    "Julia",
+    "Zuzanna",
+    "Maja",
+    "Hanna",
+    "Lena",
+    "Alicja",
+    "Oliwia",
+    "Wiktoria",
+    "Dorota",
+    "Beata",
+];
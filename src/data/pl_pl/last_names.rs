@@ -0,0 +1,46 @@
+//! Polish surnames.
+
+/// Common Polish surnames.
+pub const LAST_NAMES: &[&str] = &[
+    "Nowak",
+    "Kowalski",
+    "Kowalska",
+    "Wiśniewski",
+    "Wiśniewska",
+    "Wójcik",
+    "Kowalczyk",
+    "Kamiński",
+    "Kamińska",
+    "Lewandowski",
+    "Lewandowska",
+    "Zieliński",
+    "Zielińska",
+    "Szymański",
+    "Szymańska",
+    "Woźniak",
+    "Dąbrowski",
+    "Dąbrowska",
+    "Kozłowski",
+    "Kozłowska",
+    "Jankowski",
+    "Jankowska",
+    "Mazur",
+    "Wojciechowski",
+    "Kwiatkowski",
+    "Krawczyk",
+    "Kaczmarek",
+    "Piotrowski",
+    "Grabowski",
+    "Zając",
+    "Pawłowski",
+    "Michalski",
+    "Król",
+    "Wieczorek",
+    "Jabłoński",
+    "Wróbel",
+    "Nowakowski",
+    "Majewski",
+    "Olszewski",
+    "Stępień",
+    "Malinowski",
+];
@@ -0,0 +1,169 @@
+//! Polish (Poland) locale data.
+//!
+//! Contains name lists and other data for pl_PL locale.
+
+mod banks;
+mod calendar;
+mod cities;
+mod color_names;
+mod companies;
+mod first_names;
+mod last_names;
+mod name_affixes;
+mod streets;
+mod voivodeships;
+
+pub use banks::BANK_NAMES;
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
+pub use cities::CITIES;
+pub use color_names::COLOR_NAMES;
+pub use companies::{
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_SUFFIXES, JOB_TITLES,
+};
+pub use first_names::{FIRST_NAMES, MALE_FIRST_NAME_COUNT, ROMANIZED_FIRST_NAMES};
+pub use last_names::LAST_NAMES;
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
+pub use streets::{STREET_NAMES, STREET_SUFFIXES};
+pub use voivodeships::{VOIVODESHIPS, VOIVODESHIP_ABBRS};
+
+// Shared data
+use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
+
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
+
+/// Phone format patterns for Poland (mobile and landline).
+const PL_PHONE_PATTERNS: &[&str] = &["+48 ### ### ###", "### ### ###", "+48 ## ### ## ##"];
+
+/// Mobile phone patterns for Poland.
+const PL_MOBILE_PATTERNS: &[&str] = &[
+    "5## ### ###",
+    "6## ### ###",
+    "7## ### ###",
+    "+48 5## ### ###",
+    "+48 6## ### ###",
+];
+
+/// Landline phone patterns for Poland.
+const PL_LANDLINE_PATTERNS: &[&str] = &[
+    "22 ### ## ##",
+    "12 ### ## ##",
+    "61 ### ## ##",
+    "71 ### ## ##",
+    "+48 22 ### ## ##",
+];
+
+/// Postal code patterns for Poland (e.g., "00-950").
+const PL_POSTAL_PATTERNS: &[&str] = &["##-###"];
+
+/// Polish phone format specification.
+const PL_PHONE_FORMAT: PhoneFormat =
+    PhoneFormat::new(PL_PHONE_PATTERNS, "+48").with_kinds(PL_MOBILE_PATTERNS, PL_LANDLINE_PATTERNS);
+
+/// Polish postal code format specification.
+const PL_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(PL_POSTAL_PATTERNS);
+
+/// Polish address format specification.
+/// In Poland, the street type comes first and the house number follows the
+/// street name (e.g., "ul. Długa 5").
+const PL_ADDRESS_FORMAT: AddressFormat =
+    AddressFormat::with_prefix_type("{street}\n{postal} {city}", false);
+
+/// Polish flat numbers follow the building number (e.g., "ul. Długa 5 m. 12").
+const PL_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[
+        UnitFormat::new("m. {n}", 1, 120),
+        UnitFormat::new("lok. {n}", 1, 20),
+    ],
+    "{street} {unit}",
+    "skr. poczt. {n}",
+);
+
+/// Polish date format.
+const PL_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{dd}.{mm}.{yyyy}",
+    "{weekday}, {d} {month} {yyyy}",
+    "{HH}:{MM}:{SS}",
+);
+
+/// Polish number format.
+const PL_NUMBER_FORMAT: NumberFormat = NumberFormat::new(",", " ", "{amount} {symbol}");
+
+/// Polish locale data provider.
+pub struct PlPLData;
+
+/// Static instance of the Polish locale data.
+pub static PL_PL_DATA: PlPLData = PlPLData;
+
+crate::impl_locale_data! {
+    PlPLData,
+    first_names: FIRST_NAMES,
+    last_names: LAST_NAMES,
+    cities: CITIES,
+    regions: VOIVODESHIPS,
+    region_abbrs: VOIVODESHIP_ABBRS,
+    street_names: STREET_NAMES,
+    street_suffixes: STREET_SUFFIXES,
+    countries: COUNTRIES,
+    postal_format: PL_POSTAL_FORMAT,
+    address_format: PL_ADDRESS_FORMAT,
+    secondary_address_format: PL_SECONDARY_ADDRESS_FORMAT,
+    phone_format: PL_PHONE_FORMAT,
+    company_prefixes: COMPANY_PREFIXES,
+    company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
+    job_titles: JOB_TITLES,
+    catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
+    catch_phrase_nouns: CATCH_PHRASE_NOUNS,
+    text_words: LOREM_WORDS,
+    tlds: TLDS,
+    free_email_domains: FREE_EMAIL_DOMAINS,
+    safe_email_domains: SAFE_EMAIL_DOMAINS,
+    color_names: COLOR_NAMES,
+    bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: PL_DATE_FORMAT,
+    number_format: PL_NUMBER_FORMAT,
+    romanized_first_names: ROMANIZED_FIRST_NAMES,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::traits::LocaleData;
+
+    #[test]
+    fn test_pl_pl_data_implements_locale_data() {
+        let data = &PL_PL_DATA;
+        assert!(data.first_names().is_some());
+        assert!(data.last_names().is_some());
+        assert!(data.cities().is_some());
+        assert!(data.regions().is_some());
+        assert!(data.romanized_first_names().is_some());
+    }
+
+    #[test]
+    fn test_pl_phone_format() {
+        let format = PL_PHONE_FORMAT;
+        assert_eq!(format.country_code, "+48");
+    }
+
+    #[test]
+    fn test_voivodeships_count() {
+        assert_eq!(VOIVODESHIPS.len(), 16);
+        assert_eq!(VOIVODESHIP_ABBRS.len(), 16);
+    }
+
+    #[test]
+    fn test_romanized_first_names_are_ascii() {
+        assert_eq!(FIRST_NAMES.len(), ROMANIZED_FIRST_NAMES.len());
+        assert!(ROMANIZED_FIRST_NAMES.iter().all(|n| n.is_ascii()));
+    }
+}
@@ -0,0 +1,7 @@
+//! Polish name prefixes and suffixes.
+
+/// Polish name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &["Pan", "Pani", "Dr", "Prof.", "Mgr", "Inż."];
+
+/// Polish name suffixes (generational and post-nominal).
+pub const NAME_SUFFIXES: &[&str] = &["MBA", "PhD"];
@@ -0,0 +1,38 @@
+//! Polish street names and types.
+
+/// Common Polish street names.
+pub const STREET_NAMES: &[&str] = &[
+    "Marszałkowska",
+    "Piłsudskiego",
+    "Mickiewicza",
+    "Słowackiego",
+    "Kościuszki",
+    "Sienkiewicza",
+    "Jana Pawła II",
+    "Długa",
+    "Krótka",
+    "Polna",
+    "Leśna",
+    "Ogrodowa",
+    "Kwiatowa",
+    "Lipowa",
+    "Szkolna",
+    "Kolejowa",
+    "Słoneczna",
+    "Warszawska",
+    "Krakowska",
+    "Zielona",
+    "Parkowa",
+    "Łąkowa",
+    "Brzozowa",
+    "Piękna",
+    "Nowa",
+    "Wojska Polskiego",
+    "Kopernika",
+    "Chopina",
+    "Reymonta",
+    "Żeromskiego",
+];
+
+/// Polish street type abbreviations (used as prefixes).
+pub const STREET_SUFFIXES: &[&str] = &["ul.", "al.", "pl.", "os."];
@@ -0,0 +1,26 @@
+//! Polish voivodeships.
+
+/// The 16 Polish voivodeships (województwa).
+pub const VOIVODESHIPS: &[&str] = &[
+    "dolnośląskie",
+    "kujawsko-pomorskie",
+    "lubelskie",
+    "lubuskie",
+    "łódzkie",
+    "małopolskie",
+    "mazowieckie",
+    "opolskie",
+    "podkarpackie",
+    "podlaskie",
+    "pomorskie",
+    "śląskie",
+    "świętokrzyskie",
+    "warmińsko-mazurskie",
+    "wielkopolskie",
+    "zachodniopomorskie",
+];
+
+/// ISO 3166-2 codes for the voivodeships (in the same order as `VOIVODESHIPS`).
+pub const VOIVODESHIP_ABBRS: &[&str] = &[
+    "DS", "KP", "LU", "LB", "LD", "MA", "MZ", "OP", "PK", "PD", "PM", "SL", "SK", "WN", "WP", "ZP",
+];
@@ -0,0 +1,25 @@
+//! Bank name data for ru_RU locale.
+
+/// Russian bank names.
+pub const BANK_NAMES: &[&str] = &[
+    "Сбербанк",
+    "ВТБ",
+    "Газпромбанк",
+    "Альфа-Банк",
+    "Россельхозбанк",
+    "Т-Банк",
+    "Совкомбанк",
+    "Райффайзенбанк",
+    "Промсвязьбанк",
+    "Банк Открытие",
+    "Росбанк",
+    "МКБ",
+    "Почта Банк",
+    "Банк Уралсиб",
+    "Ак Барс Банк",
+    "Юникредит Банк",
+    "Банк Санкт-Петербург",
+    "Ситибанк",
+    "МТС Банк",
+    "Хоум Банк",
+];
@@ -0,0 +1,28 @@
+//! Russian month and weekday names.
+
+/// Russian month names in the genitive case, as used in dates.
+pub const MONTH_NAMES: &[&str] = &[
+    "января",
+    "февраля",
+    "марта",
+    "апреля",
+    "мая",
+    "июня",
+    "июля",
+    "августа",
+    "сентября",
+    "октября",
+    "ноября",
+    "декабря",
+];
+
+/// Russian weekday names, starting with Monday.
+pub const WEEKDAY_NAMES: &[&str] = &[
+    "понедельник",
+    "вторник",
+    "среда",
+    "четверг",
+    "пятница",
+    "суббота",
+    "воскресенье",
+];
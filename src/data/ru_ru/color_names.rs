@@ -0,0 +1,35 @@
+//! Russian color names.
+
+/// Color names in Russian.
+pub const COLOR_NAMES: &[&str] = &[
+    "красный",
+    "синий",
+    "зелёный",
+    "жёлтый",
+    "оранжевый",
+    "фиолетовый",
+    "розовый",
+    "коричневый",
+    "чёрный",
+    "белый",
+    "серый",
+    "бежевый",
+    "бирюзовый",
+    "голубой",
+    "тёмно-синий",
+    "бордовый",
+    "золотой",
+    "серебряный",
+    "лиловый",
+    "салатовый",
+    "оливковый",
+    "малиновый",
+    "сиреневый",
+    "кремовый",
+    "изумрудный",
+    "лазурный",
+    "песочный",
+    "вишнёвый",
+    "персиковый",
+    "коралловый",
+];
@@ -0,0 +1,134 @@
+//! Russian company data.
+
+crate::define_company_data! {
+    prefixes: [
+        "Сибирь",
+        "Урал",
+        "Волга",
+        "Север",
+        "Восток",
+        "Россия",
+        "Москва",
+        "Нева",
+        "Альфа",
+        "Гарант",
+        "Прогресс",
+        "Технология",
+        "Стройинвест",
+        "Энерго",
+        "Транс",
+        "Мега",
+        "Интер",
+        "Балтика",
+        "Дон",
+        "Кубань",
+        "Алтай",
+        "Байкал",
+        "Полюс",
+        "Вектор",
+        "Спектр",
+    ],
+    suffixes: [
+        "Групп",
+        "Холдинг",
+        "Инвест",
+        "Трейд",
+        "Строй",
+        "Сервис",
+        "Консалтинг",
+        "Логистик",
+        "Технологии",
+        "Системс",
+        "Телеком",
+        "Капитал",
+        "Партнёр",
+        "Индустрия",
+        "Энерго",
+    ],
+    job_titles: [
+        "Программист",
+        "Менеджер проектов",
+        "Генеральный директор",
+        "Финансовый директор",
+        "Бухгалтер",
+        "Главный бухгалтер",
+        "Менеджер по продажам",
+        "Маркетолог",
+        "Аналитик",
+        "HR-менеджер",
+        "Системный администратор",
+        "Инженер",
+        "Юрист",
+        "Логист",
+        "Специалист по закупкам",
+        "Руководитель отдела",
+        "Тестировщик",
+        "Дизайнер",
+        "Экономист",
+        "Офис-менеджер",
+    ],
+    adjectives: [
+        "Инновационные",
+        "Надёжные",
+        "Эффективные",
+        "Современные",
+        "Передовые",
+        "Гибкие",
+        "Комплексные",
+        "Профессиональные",
+        "Качественные",
+        "Индивидуальные",
+        "Стратегические",
+        "Динамичные",
+        "Устойчивые",
+        "Интегрированные",
+        "Клиентоориентированные",
+    ],
+    nouns: [
+        "решения",
+        "услуги",
+        "продукты",
+        "системы",
+        "технологии",
+        "стратегии",
+        "инновации",
+        "партнёрства",
+        "консультации",
+        "сервисы",
+        "проекты",
+        "платформы",
+        "разработки",
+        "концепции",
+        "возможности",
+    ],
+    legal_forms: ["ООО", "АО", "ПАО"],
+}
+
+/// Romanized company prefixes (in the same order as `COMPANY_PREFIXES`).
+pub const COMPANY_PREFIXES_ROMANIZED: &[&str] = &[
+    "Sibir",
+    "Ural",
+    "Volga",
+    "Sever",
+    "Vostok",
+    "Rossiya",
+    "Moskva",
+    "Neva",
+    "Alfa",
+    "Garant",
+    "Progress",
+    "Tekhnologiya",
+    "Stroyinvest",
+    "Energo",
+    "Trans",
+    "Mega",
+    "Inter",
+    "Baltika",
+    "Don",
+    "Kuban",
+    "Altay",
+    "Baykal",
+    "Polyus",
+    "Vektor",
+    "Spektr",
+];
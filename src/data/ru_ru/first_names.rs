@@ -0,0 +1,112 @@
+//! Russian first names.
+
+/// Common Russian first names (in Cyrillic), male names followed by female names.
+pub const FIRST_NAMES: &[&str] = &[
+    "Александр",
+    "Дмитрий",
+    "Максим",
+    "Сергей",
+    "Андрей",
+    "Алексей",
+    "Артём",
+    "Илья",
+    "Кирилл",
+    "Михаил",
+    "Никита",
+    "Матвей",
+    "Роман",
+    "Егор",
+    "Арсений",
+    "Иван",
+    "Денис",
+    "Евгений",
+    "Даниил",
+    "Тимофей",
+    "Владимир",
+    "Павел",
+    "Николай",
+    "Олег",
+    "Виктор",
+    "Анастасия",
+    "Мария",
+    "Анна",
+    "Виктория",
+    "Екатерина",
+    "Наталья",
+    "Марина",
+    "Полина",
+    "Елена",
+    "Дарья",
+    "Алина",
+    "Ирина",
+    "Ксения",
+    "Ольга",
+    "Татьяна",
+    "Юлия",
+    "Светлана",
+    "Софья",
+    "Алиса",
+    "Вероника",
+    "Елизавета",
+    "Валерия",
+    "Александра",
+    "Ульяна",
+    "Варвара",
+];
+
+/// Number of leading `FIRST_NAMES` entries that are male names.
+pub const MALE_FIRST_NAME_COUNT: usize = 25;
+
+/// Transliterated versions of Russian first names (for email generation).
+pub const FIRST_NAMES_ROMANIZED: &[&str] = &[
+    "Aleksandr",
+    "Dmitry",
+    "Maksim",
+    "Sergey",
+    "Andrey",
+    "Aleksey",
+    "Artem",
+    "Ilya",
+    "Kirill",
+    "Mikhail",
+    "Nikita",
+    "Matvey",
+    "Roman",
+    "Egor",
+    "Arseny",
+    "Ivan",
+    "Denis",
+    "Evgeny",
+    "Daniil",
+    "Timofey",
+    "Vladimir",
+    "Pavel",
+    "Nikolay",
+    "Oleg",
+    "Viktor",
+    "Anastasia",
+    "Maria",
+    "Anna",
+    "Viktoria",
+    "Ekaterina",
+    "Natalya",
+    "Marina",
+    "Polina",
+    "Elena",
+    "Darya",
+    "Alina",
+    "Irina",
+    "Ksenia",
+    "Olga",
+    "Tatyana",
+    "Yulia",
+    "Svetlana",
+    "Sofya",
+    "Alisa",
+    "Veronika",
+    "Elizaveta",
+    "Valeria",
+    "Aleksandra",
+    "Ulyana",
+    "Varvara",
+];
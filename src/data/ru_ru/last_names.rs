@@ -0,0 +1,112 @@
+//! Russian surnames.
+
+/// Common Russian surnames (in Cyrillic), masculine forms followed by feminine forms.
+pub const LAST_NAMES: &[&str] = &[
+    "Иванов",
+    "Смирнов",
+    "Кузнецов",
+    "Попов",
+    "Васильев",
+    "Петров",
+    "Соколов",
+    "Михайлов",
+    "Новиков",
+    "Фёдоров",
+    "Морозов",
+    "Волков",
+    "Алексеев",
+    "Лебедев",
+    "Семёнов",
+    "Егоров",
+    "Павлов",
+    "Козлов",
+    "Степанов",
+    "Николаев",
+    "Орлов",
+    "Андреев",
+    "Макаров",
+    "Никитин",
+    "Захаров",
+    "Иванова",
+    "Смирнова",
+    "Кузнецова",
+    "Попова",
+    "Васильева",
+    "Петрова",
+    "Соколова",
+    "Михайлова",
+    "Новикова",
+    "Фёдорова",
+    "Морозова",
+    "Волкова",
+    "Алексеева",
+    "Лебедева",
+    "Семёнова",
+    "Егорова",
+    "Павлова",
+    "Козлова",
+    "Степанова",
+    "Николаева",
+    "Орлова",
+    "Андреева",
+    "Макарова",
+    "Никитина",
+    "Захарова",
+];
+
+/// Number of leading `LAST_NAMES` entries in masculine form.
+pub const MASCULINE_LAST_NAME_COUNT: usize = 25;
+
+/// Transliterated versions of Russian surnames (for email generation).
+pub const LAST_NAMES_ROMANIZED: &[&str] = &[
+    "Ivanov",
+    "Smirnov",
+    "Kuznetsov",
+    "Popov",
+    "Vasilyev",
+    "Petrov",
+    "Sokolov",
+    "Mikhaylov",
+    "Novikov",
+    "Fedorov",
+    "Morozov",
+    "Volkov",
+    "Alekseev",
+    "Lebedev",
+    "Semenov",
+    "Egorov",
+    "Pavlov",
+    "Kozlov",
+    "Stepanov",
+    "Nikolaev",
+    "Orlov",
+    "Andreev",
+    "Makarov",
+    "Nikitin",
+    "Zakharov",
+    "Ivanova",
+    "Smirnova",
+    "Kuznetsova",
+    "Popova",
+    "Vasilyeva",
+    "Petrova",
+    "Sokolova",
+    "Mikhaylova",
+    "Novikova",
+    "Fedorova",
+    "Morozova",
+    "Volkova",
+    "Alekseeva",
+    "Lebedeva",
+    "Semenova",
+    "Egorova",
+    "Pavlova",
+    "Kozlova",
+    "Stepanova",
+    "Nikolaeva",
+    "Orlova",
+    "Andreeva",
+    "Makarova",
+    "Nikitina",
+    "Zakharova",
+];
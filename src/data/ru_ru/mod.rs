@@ -0,0 +1,180 @@
+//! Russian (Russia) locale data.
+//!
+//! Contains name lists and other data for ru_RU locale.
+//!
+//! Note: Russian names are provided in both Cyrillic and transliterated forms.
+//! For emails, transliterated forms are used to ensure ASCII compatibility.
+
+mod banks;
+mod calendar;
+mod cities;
+mod color_names;
+mod companies;
+mod first_names;
+mod last_names;
+mod name_affixes;
+mod oblasts;
+mod streets;
+
+pub use banks::BANK_NAMES;
+pub use calendar::{MONTH_NAMES, WEEKDAY_NAMES};
+pub use cities::CITIES;
+pub use color_names::COLOR_NAMES;
+pub use companies::{
+    CATCH_PHRASE_ADJECTIVES, CATCH_PHRASE_NOUNS, COMPANY_LEGAL_FORMS, COMPANY_PREFIXES,
+    COMPANY_PREFIXES_ROMANIZED, COMPANY_SUFFIXES, JOB_TITLES,
+};
+pub use first_names::{FIRST_NAMES, FIRST_NAMES_ROMANIZED, MALE_FIRST_NAME_COUNT};
+pub use last_names::{LAST_NAMES, LAST_NAMES_ROMANIZED, MASCULINE_LAST_NAME_COUNT};
+pub use name_affixes::{NAME_PREFIXES, NAME_SUFFIXES};
+pub use oblasts::{OBLASTS, OBLAST_ABBRS};
+pub use streets::{STREET_NAMES, STREET_SUFFIXES};
+
+// Shared data
+use super::en_us::{COUNTRIES, FREE_EMAIL_DOMAINS, LOREM_WORDS, SAFE_EMAIL_DOMAINS, TLDS};
+
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    UnitFormat,
+};
+
+/// Phone format patterns for Russia (mobile and Moscow landlines).
+const RU_PHONE_PATTERNS: &[&str] = &[
+    "+7 9## ###-##-##",
+    "8 9## ###-##-##",
+    "+7 (495) ###-##-##",
+    "+7 (###) ###-##-##",
+];
+
+/// Mobile phone patterns for Russia.
+const RU_MOBILE_PATTERNS: &[&str] = &["+7 9## ###-##-##", "8 9## ###-##-##"];
+
+/// Landline phone patterns for Russia.
+const RU_LANDLINE_PATTERNS: &[&str] = &[
+    "+7 (495) ###-##-##",
+    "+7 (812) ###-##-##",
+    "8 (495) ###-##-##",
+    "8 (343) ###-##-##",
+];
+
+/// Postal code patterns for Russia (6 digits).
+const RU_POSTAL_PATTERNS: &[&str] = &["######"];
+
+/// Russian phone format specification.
+const RU_PHONE_FORMAT: PhoneFormat = PhoneFormat::with_trunk_prefix(RU_PHONE_PATTERNS, "+7", "8")
+    .with_kinds(RU_MOBILE_PATTERNS, RU_LANDLINE_PATTERNS);
+
+/// Russian postal code format specification.
+const RU_POSTAL_FORMAT: PostalCodeFormat = PostalCodeFormat::new(RU_POSTAL_PATTERNS);
+
+/// Russian address format specification.
+/// Russian Post order is street, city, region, postal code, with the street type
+/// abbreviation before the name: "ул. Ленина 12".
+const RU_ADDRESS_FORMAT: AddressFormat =
+    AddressFormat::with_prefix_type("{street}, {city}, {region}, {postal}", false);
+
+/// Russian flat numbers follow the building (e.g., "ул. Ленина 12, кв. 45").
+const RU_SECONDARY_ADDRESS_FORMAT: SecondaryAddressFormat = SecondaryAddressFormat::new(
+    &[
+        UnitFormat::new("кв. {n}", 1, 300),
+        UnitFormat::new("офис {n}", 1, 999),
+    ],
+    "{street}, {unit}",
+    "а/я {n}",
+);
+
+/// Russian date format.
+const RU_DATE_FORMAT: DateFormat = DateFormat::new(
+    "{dd}.{mm}.{yyyy}",
+    "{weekday}, {d} {month} {yyyy} г.",
+    "{HH}:{MM}:{SS}",
+);
+
+/// Russian number format.
+const RU_NUMBER_FORMAT: NumberFormat = NumberFormat::new(",", " ", "{amount} {symbol}");
+
+/// Russian locale data provider.
+pub struct RuRUData;
+
+/// Static instance of the Russian locale data.
+pub static RU_RU_DATA: RuRUData = RuRUData;
+
+crate::impl_locale_data! {
+    RuRUData,
+    first_names: FIRST_NAMES,
+    last_names: LAST_NAMES,
+    cities: CITIES,
+    regions: OBLASTS,
+    region_abbrs: OBLAST_ABBRS,
+    street_names: STREET_NAMES,
+    street_suffixes: STREET_SUFFIXES,
+    countries: COUNTRIES,
+    postal_format: RU_POSTAL_FORMAT,
+    address_format: RU_ADDRESS_FORMAT,
+    secondary_address_format: RU_SECONDARY_ADDRESS_FORMAT,
+    phone_format: RU_PHONE_FORMAT,
+    company_prefixes: COMPANY_PREFIXES,
+    company_suffixes: COMPANY_SUFFIXES,
+    company_legal_forms: COMPANY_LEGAL_FORMS,
+    job_titles: JOB_TITLES,
+    catch_phrase_adjectives: CATCH_PHRASE_ADJECTIVES,
+    catch_phrase_nouns: CATCH_PHRASE_NOUNS,
+    text_words: LOREM_WORDS,
+    tlds: TLDS,
+    free_email_domains: FREE_EMAIL_DOMAINS,
+    safe_email_domains: SAFE_EMAIL_DOMAINS,
+    color_names: COLOR_NAMES,
+    bank_names: BANK_NAMES,
+    name_prefixes: NAME_PREFIXES,
+    name_suffixes: NAME_SUFFIXES,
+    male_first_names: MALE_FIRST_NAME_COUNT,
+    month_names: MONTH_NAMES,
+    weekday_names: WEEKDAY_NAMES,
+    date_format: RU_DATE_FORMAT,
+    number_format: RU_NUMBER_FORMAT,
+    romanized_first_names: FIRST_NAMES_ROMANIZED,
+    romanized_last_names: LAST_NAMES_ROMANIZED,
+    romanized_company_prefixes: COMPANY_PREFIXES_ROMANIZED,
+    masculine_last_names: MASCULINE_LAST_NAME_COUNT,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::traits::LocaleData;
+
+    #[test]
+    fn test_ru_ru_data_implements_locale_data() {
+        let data = &RU_RU_DATA;
+        assert!(data.first_names().is_some());
+        assert!(data.last_names().is_some());
+        assert!(data.cities().is_some());
+        assert!(data.regions().is_some());
+        assert!(data.romanized_first_names().is_some());
+        assert!(data.romanized_last_names().is_some());
+    }
+
+    #[test]
+    fn test_ru_phone_format() {
+        let format = RU_PHONE_FORMAT;
+        assert_eq!(format.country_code, "+7");
+    }
+
+    #[test]
+    fn test_oblasts_match_abbrs() {
+        assert_eq!(OBLASTS.len(), OBLAST_ABBRS.len());
+    }
+
+    #[test]
+    fn test_romanized_names_match() {
+        // Verify transliterated arrays match size with Cyrillic arrays
+        assert_eq!(FIRST_NAMES.len(), FIRST_NAMES_ROMANIZED.len());
+        assert_eq!(LAST_NAMES.len(), LAST_NAMES_ROMANIZED.len());
+    }
+
+    #[test]
+    fn test_romanized_names_are_ascii() {
+        assert!(FIRST_NAMES_ROMANIZED.iter().all(|n| n.is_ascii()));
+        assert!(LAST_NAMES_ROMANIZED.iter().all(|n| n.is_ascii()));
+    }
+}
@@ -0,0 +1,7 @@
+//! Russian name prefixes and suffixes.
+
+/// Russian name prefixes (titles and forms of address).
+pub const NAME_PREFIXES: &[&str] = &["г-н", "г-жа", "д-р", "проф."];
+
+/// Russian name suffixes (none in common use).
+pub const NAME_SUFFIXES: &[&str] = &[];
@@ -0,0 +1,42 @@
+//! Russian federal subjects (oblasts, krais, republics and federal cities).
+
+/// Major Russian federal subjects.
+pub const OBLASTS: &[&str] = &[
+    "Москва",
+    "Санкт-Петербург",
+    "Московская область",
+    "Ленинградская область",
+    "Новосибирская область",
+    "Свердловская область",
+    "Нижегородская область",
+    "Самарская область",
+    "Ростовская область",
+    "Краснодарский край",
+    "Республика Татарстан",
+    "Республика Башкортостан",
+    "Челябинская область",
+    "Омская область",
+    "Красноярский край",
+    "Пермский край",
+    "Воронежская область",
+    "Волгоградская область",
+    "Саратовская область",
+    "Тюменская область",
+    "Иркутская область",
+    "Хабаровский край",
+    "Приморский край",
+    "Калининградская область",
+    "Ярославская область",
+    "Тверская область",
+    "Тульская область",
+    "Кемеровская область",
+    "Алтайский край",
+    "Ставропольский край",
+];
+
+/// ISO 3166-2 codes for the federal subjects (in the same order as `OBLASTS`).
+pub const OBLAST_ABBRS: &[&str] = &[
+    "MOW", "SPE", "MOS", "LEN", "NVS", "SVE", "NIZ", "SAM", "ROS", "KDA", "TA", "BA", "CHE", "OMS",
+    "KYA", "PER", "VOR", "VGG", "SAR", "TYU", "IRK", "KHA", "PRI", "KGD", "YAR", "TVE", "TUL",
+    "KEM", "ALT", "STA",
+];
@@ -0,0 +1,38 @@
+//! Russian street names and types.
+
+/// Common Russian street names.
+pub const STREET_NAMES: &[&str] = &[
+    "Ленина",
+    "Гагарина",
+    "Пушкина",
+    "Мира",
+    "Советская",
+    "Молодёжная",
+    "Центральная",
+    "Школьная",
+    "Садовая",
+    "Лесная",
+    "Набережная",
+    "Октябрьская",
+    "Комсомольская",
+    "Первомайская",
+    "Кирова",
+    "Чехова",
+    "Лермонтова",
+    "Горького",
+    "Толстого",
+    "Победы",
+    "Строителей",
+    "Новая",
+    "Заречная",
+    "Полевая",
+    "Зелёная",
+    "Солнечная",
+    "Мичурина",
+    "Суворова",
+    "Куйбышева",
+    "Маяковского",
+];
+
+/// Russian street type abbreviations (used as prefixes).
+pub const STREET_SUFFIXES: &[&str] = &["ул.", "пр-т", "пер.", "б-р", "ш."];
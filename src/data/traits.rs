@@ -3,7 +3,10 @@
 //! The `LocaleData` trait defines the interface that all locale modules
 //! must implement to provide locale-specific data for generation.
 
-use super::formats::{AddressFormat, PhoneFormat, PostalCodeFormat};
+use super::formats::{
+    AddressFormat, DateFormat, NumberFormat, PhoneFormat, PostalCodeFormat, SecondaryAddressFormat,
+    TextFormat,
+};
 
 /// Trait for locale-specific data access.
 ///
@@ -25,6 +28,24 @@ pub trait LocaleData: Send + Sync {
     /// Last names/surnames for the locale.
     fn last_names(&self) -> Option<&'static [&'static str]>;
 
+    /// Name prefixes (e.g., "Mr.", "Dr.", "Frau"). May be empty for locales
+    /// that don't use them.
+    fn name_prefixes(&self) -> Option<&'static [&'static str]>;
+
+    /// Name suffixes (e.g., "Jr.", "PhD", or honorifics like "様").
+    fn name_suffixes(&self) -> Option<&'static [&'static str]>;
+
+    /// Number of leading `first_names()` entries that are traditionally male;
+    /// the remaining entries are female.
+    fn male_first_name_count(&self) -> Option<usize>;
+
+    /// For locales whose surnames inflect by gender (e.g., ru_RU), the number
+    /// of leading `last_names()` entries in masculine form; the remaining
+    /// entries are the feminine forms. `None` when surnames are shared.
+    fn masculine_last_name_count(&self) -> Option<usize> {
+        None
+    }
+
     // === Address Components ===
 
     /// Cities for the locale.
@@ -51,11 +72,27 @@ pub trait LocaleData: Send + Sync {
     /// Address format specification.
     fn address_format(&self) -> Option<AddressFormat>;
 
+    /// Secondary unit and PO Box wording.
+    fn secondary_address_format(&self) -> Option<SecondaryAddressFormat>;
+
     // === Phone ===
 
     /// Phone number format specification.
     fn phone_format(&self) -> Option<PhoneFormat>;
 
+    /// Telephone area codes for each region, in the same order as `regions()`.
+    /// Only locales whose phone patterns start with a three-digit area code
+    /// (en_US, en_CA) provide them.
+    fn region_area_codes(&self) -> Option<&'static [&'static [&'static str]]> {
+        None
+    }
+
+    /// Inclusive range of three-digit postal code prefixes for each region,
+    /// in the same order as `regions()`. Only en_US provides them.
+    fn region_zip_prefixes(&self) -> Option<&'static [(u16, u16)]> {
+        None
+    }
+
     // === Company ===
 
     /// Company name prefixes (e.g., last names used in company names).
@@ -64,6 +101,9 @@ pub trait LocaleData: Send + Sync {
     /// Company name suffixes (e.g., "Inc.", "GmbH", "Ltd.").
     fn company_suffixes(&self) -> Option<&'static [&'static str]>;
 
+    /// Registered legal forms (e.g., "LLC", "GmbH", "S.A.").
+    fn company_legal_forms(&self) -> Option<&'static [&'static str]>;
+
     /// Job titles for the locale.
     fn job_titles(&self) -> Option<&'static [&'static str]>;
 
@@ -78,6 +118,13 @@ pub trait LocaleData: Send + Sync {
     /// Words for generating lorem-ipsum-style text.
     fn text_words(&self) -> Option<&'static [&'static str]>;
 
+    /// How text words are joined and punctuated. Locales written without
+    /// spaces between words (e.g., ja_JP) override it; defaults to
+    /// `TextFormat::LATIN`.
+    fn text_format(&self) -> TextFormat {
+        TextFormat::LATIN
+    }
+
     // === Internet ===
 
     /// Top-level domains.
@@ -99,6 +146,23 @@ pub trait LocaleData: Send + Sync {
     /// Bank names for the locale.
     fn bank_names(&self) -> Option<&'static [&'static str]>;
 
+    // === Dates ===
+
+    /// Month names, January first. Locales that inflect month names in dates
+    /// (e.g., ru_RU, pl_PL) use the form that follows a day number.
+    fn month_names(&self) -> Option<&'static [&'static str]>;
+
+    /// Weekday names, Monday first.
+    fn weekday_names(&self) -> Option<&'static [&'static str]>;
+
+    /// Date and time format specification.
+    fn date_format(&self) -> Option<DateFormat>;
+
+    // === Numbers ===
+
+    /// Number and currency format specification.
+    fn number_format(&self) -> Option<NumberFormat>;
+
     // === Romanization (for non-Latin scripts) ===
 
     /// Romanized first names for email generation.
@@ -114,4 +178,10 @@ pub trait LocaleData: Send + Sync {
     fn romanized_last_names(&self) -> Option<&'static [&'static str]> {
         self.last_names()
     }
+
+    /// Romanized company prefixes for domain generation, in the same order
+    /// as `company_prefixes()`. Defaults to returning `company_prefixes()`.
+    fn romanized_company_prefixes(&self) -> Option<&'static [&'static str]> {
+        self.company_prefixes()
+    }
 }
@@ -3,8 +3,38 @@
 //! This module provides a unified error enum that wraps all error types
 //! used throughout the crate, enabling consistent error handling.
 
-use crate::providers::datetime::DateRangeError;
+use crate::providers::address::{AddressOptionsError, CountryFormatError, UsStateError};
+use crate::providers::auth::{
+    ApiKeyAlphabetError, JwtAlgorithmError, PasswordHashAlgorithmError, TokenFormatError,
+    TotpSecretLengthError,
+};
+use crate::providers::cloud::ArnServiceError;
+use crate::providers::cloud::CloudProviderError;
+use crate::providers::colors::{CssColorFormatError, PaletteSchemeError};
+use crate::providers::commerce::{InvoiceNumberError, PriceTierError};
+use crate::providers::company::{CatchPhraseStyleError, JobLevelError};
+use crate::providers::datetime::{DateRangeError, DateStyleError, TimeFormatError};
+use crate::providers::demographics::DemographicWeightsError;
+use crate::providers::drivers_license::LicenseRegionError;
+use crate::providers::duration::{DurationFormatError, DurationRangeError};
+use crate::providers::filesystem::{FileCategoryError, MimeCategoryError, PathStyleError};
+use crate::providers::finance::{CardNetworkError, CurrencyError, VatCountryError};
+use crate::providers::geo::{BoundingBoxError, GeoCountryError, GpsTrackError};
+use crate::providers::insurance::InsuranceNumberError;
+use crate::providers::internet::{EmailDomainError, UsernameStyleError};
+use crate::providers::logistics::CarrierError;
+use crate::providers::logs::SyslogFormatError;
+use crate::providers::names::NameFormatError;
+use crate::providers::network::{
+    CidrPrefixError, Ipv4RangeError, Ipv6KindError, MacKindError, MacSeparatorError, PortKindError,
+    StatusClassError, UrlOptionsError,
+};
 use crate::providers::numbers::{FloatRangeError, RangeError};
+use crate::providers::phone::{FictionalPhoneError, PhoneKindError, PhoneNumberFormatError};
+use crate::providers::software::K8sResourceError;
+use crate::providers::text::TextShapeError;
+use crate::providers::timeseries::SensorError;
+use crate::providers::timeseries::TimeSeriesError;
 use crate::{BatchSizeError, LocaleError};
 use std::fmt;
 
@@ -47,6 +77,110 @@ pub enum ForgeryError {
     DateRange(DateRangeError),
     /// Unique value generation exhausted.
     UniqueExhausted(UniqueExhaustedError),
+    /// Invalid full name format template.
+    NameFormat(NameFormatError),
+    /// Unknown date output format.
+    DateStyle(DateStyleError),
+    /// Unsupported VAT country code.
+    VatCountry(VatCountryError),
+    /// Unknown credit card network.
+    CardNetwork(CardNetworkError),
+    /// Unknown ISO 4217 currency code.
+    Currency(CurrencyError),
+    /// Unsupported driver's license region.
+    LicenseRegion(LicenseRegionError),
+    /// Invalid coordinate bounding box.
+    BoundingBox(BoundingBoxError),
+    /// Country without a coordinate bounding box.
+    GeoCountry(GeoCountryError),
+    /// Unknown country output format.
+    CountryFormat(CountryFormatError),
+    /// Unsupported file category.
+    FileCategory(FileCategoryError),
+    /// Unknown path style.
+    PathStyle(PathStyleError),
+    /// Unsupported MIME category.
+    MimeCategory(MimeCategoryError),
+    /// Unknown port kind.
+    PortKind(PortKindError),
+    /// Invalid CIDR prefix length range.
+    CidrPrefix(CidrPrefixError),
+    /// Unknown HTTP status class.
+    StatusClass(StatusClassError),
+    /// Invalid URL options.
+    UrlOptions(UrlOptionsError),
+    /// Invalid email domain.
+    EmailDomain(EmailDomainError),
+    /// Unknown MAC address kind.
+    MacKind(MacKindError),
+    /// Unknown MAC address separator.
+    MacSeparator(MacSeparatorError),
+    /// Unknown password hash algorithm.
+    PasswordHashAlgorithm(PasswordHashAlgorithmError),
+    /// Unknown JWT algorithm.
+    JwtAlgorithm(JwtAlgorithmError),
+    /// Unknown API key alphabet.
+    ApiKeyAlphabet(ApiKeyAlphabetError),
+    /// Unknown phone number format.
+    PhoneNumberFormat(PhoneNumberFormatError),
+    /// Unknown phone kind.
+    PhoneKind(PhoneKindError),
+    /// No fictional phone range for the locale.
+    FictionalPhone(FictionalPhoneError),
+    /// Unknown job level.
+    JobLevel(JobLevelError),
+    /// Invalid time format.
+    TimeFormat(TimeFormatError),
+    /// Invalid time series request.
+    TimeSeries(TimeSeriesError),
+    /// Unknown CSS color format.
+    CssColorFormat(CssColorFormatError),
+    /// Unknown color palette scheme.
+    PaletteScheme(PaletteSchemeError),
+    /// Unknown duration output format.
+    DurationFormat(DurationFormatError),
+    /// Invalid duration range (min > max).
+    DurationRange(DurationRangeError),
+    /// Unknown category or zero total in demographic weights.
+    DemographicWeights(DemographicWeightsError),
+    /// Unknown parcel carrier.
+    Carrier(CarrierError),
+    /// Invalid invoice number format.
+    InvoiceNumber(InvoiceNumberError),
+    /// Unknown retail price tier.
+    PriceTier(PriceTierError),
+    /// Unknown Kubernetes resource kind.
+    K8sResource(K8sResourceError),
+    /// Unknown ARN service name.
+    ArnService(ArnServiceError),
+    /// Unknown cloud provider name.
+    CloudProvider(CloudProviderError),
+    /// Unknown syslog format name.
+    SyslogFormat(SyslogFormatError),
+    /// Sensor reading error.
+    Sensor(SensorError),
+    /// GPS track error.
+    GpsTrack(GpsTrackError),
+    /// Invalid insurance policy or claim number format.
+    InsuranceNumber(InsuranceNumberError),
+    /// Unknown opaque token format.
+    TokenFormat(TokenFormatError),
+    /// Unsupported TOTP secret length.
+    TotpSecretLength(TotpSecretLengthError),
+    /// Unknown IPv6 address kind.
+    Ipv6Kind(Ipv6KindError),
+    /// Unknown IPv4 network or range name.
+    Ipv4Range(Ipv4RangeError),
+    /// Invalid address unit or PO Box rates.
+    AddressOptions(AddressOptionsError),
+    /// Unknown US state name or abbreviation.
+    UsState(UsStateError),
+    /// Unknown username style name.
+    UsernameStyle(UsernameStyleError),
+    /// Invalid sentence or paragraph shape.
+    TextShape(TextShapeError),
+    /// Unknown catch phrase style name.
+    CatchPhraseStyle(CatchPhraseStyleError),
 }
 
 impl fmt::Display for ForgeryError {
@@ -58,6 +192,58 @@ impl fmt::Display for ForgeryError {
             ForgeryError::FloatRange(e) => write!(f, "{}", e),
             ForgeryError::DateRange(e) => write!(f, "{}", e),
             ForgeryError::UniqueExhausted(e) => write!(f, "{}", e),
+            ForgeryError::NameFormat(e) => write!(f, "{}", e),
+            ForgeryError::DateStyle(e) => write!(f, "{}", e),
+            ForgeryError::VatCountry(e) => write!(f, "{}", e),
+            ForgeryError::CardNetwork(e) => write!(f, "{}", e),
+            ForgeryError::Currency(e) => write!(f, "{}", e),
+            ForgeryError::LicenseRegion(e) => write!(f, "{}", e),
+            ForgeryError::BoundingBox(e) => write!(f, "{}", e),
+            ForgeryError::GeoCountry(e) => write!(f, "{}", e),
+            ForgeryError::CountryFormat(e) => write!(f, "{}", e),
+            ForgeryError::FileCategory(e) => write!(f, "{}", e),
+            ForgeryError::PathStyle(e) => write!(f, "{}", e),
+            ForgeryError::MimeCategory(e) => write!(f, "{}", e),
+            ForgeryError::PortKind(e) => write!(f, "{}", e),
+            ForgeryError::CidrPrefix(e) => write!(f, "{}", e),
+            ForgeryError::StatusClass(e) => write!(f, "{}", e),
+            ForgeryError::UrlOptions(e) => write!(f, "{}", e),
+            ForgeryError::EmailDomain(e) => write!(f, "{}", e),
+            ForgeryError::MacKind(e) => write!(f, "{}", e),
+            ForgeryError::MacSeparator(e) => write!(f, "{}", e),
+            ForgeryError::PasswordHashAlgorithm(e) => write!(f, "{}", e),
+            ForgeryError::JwtAlgorithm(e) => write!(f, "{}", e),
+            ForgeryError::ApiKeyAlphabet(e) => write!(f, "{}", e),
+            ForgeryError::PhoneNumberFormat(e) => write!(f, "{}", e),
+            ForgeryError::PhoneKind(e) => write!(f, "{}", e),
+            ForgeryError::FictionalPhone(e) => write!(f, "{}", e),
+            ForgeryError::JobLevel(e) => write!(f, "{}", e),
+            ForgeryError::TimeFormat(e) => write!(f, "{}", e),
+            ForgeryError::TimeSeries(e) => write!(f, "{}", e),
+            ForgeryError::CssColorFormat(e) => write!(f, "{}", e),
+            ForgeryError::PaletteScheme(e) => write!(f, "{}", e),
+            ForgeryError::DurationFormat(e) => write!(f, "{}", e),
+            ForgeryError::DurationRange(e) => write!(f, "{}", e),
+            ForgeryError::DemographicWeights(e) => write!(f, "{}", e),
+            ForgeryError::Carrier(e) => write!(f, "{}", e),
+            ForgeryError::InvoiceNumber(e) => write!(f, "{}", e),
+            ForgeryError::PriceTier(e) => write!(f, "{}", e),
+            ForgeryError::K8sResource(e) => write!(f, "{}", e),
+            ForgeryError::ArnService(e) => write!(f, "{}", e),
+            ForgeryError::CloudProvider(e) => write!(f, "{}", e),
+            ForgeryError::SyslogFormat(e) => write!(f, "{}", e),
+            ForgeryError::Sensor(e) => write!(f, "{}", e),
+            ForgeryError::GpsTrack(e) => write!(f, "{}", e),
+            ForgeryError::InsuranceNumber(e) => write!(f, "{}", e),
+            ForgeryError::TokenFormat(e) => write!(f, "{}", e),
+            ForgeryError::TotpSecretLength(e) => write!(f, "{}", e),
+            ForgeryError::Ipv6Kind(e) => write!(f, "{}", e),
+            ForgeryError::Ipv4Range(e) => write!(f, "{}", e),
+            ForgeryError::AddressOptions(e) => write!(f, "{}", e),
+            ForgeryError::UsState(e) => write!(f, "{}", e),
+            ForgeryError::UsernameStyle(e) => write!(f, "{}", e),
+            ForgeryError::TextShape(e) => write!(f, "{}", e),
+            ForgeryError::CatchPhraseStyle(e) => write!(f, "{}", e),
         }
     }
 }
@@ -71,6 +257,58 @@ impl std::error::Error for ForgeryError {
             ForgeryError::FloatRange(e) => Some(e),
             ForgeryError::DateRange(e) => Some(e),
             ForgeryError::UniqueExhausted(e) => Some(e),
+            ForgeryError::NameFormat(e) => Some(e),
+            ForgeryError::DateStyle(e) => Some(e),
+            ForgeryError::VatCountry(e) => Some(e),
+            ForgeryError::CardNetwork(e) => Some(e),
+            ForgeryError::Currency(e) => Some(e),
+            ForgeryError::LicenseRegion(e) => Some(e),
+            ForgeryError::BoundingBox(e) => Some(e),
+            ForgeryError::GeoCountry(e) => Some(e),
+            ForgeryError::CountryFormat(e) => Some(e),
+            ForgeryError::FileCategory(e) => Some(e),
+            ForgeryError::PathStyle(e) => Some(e),
+            ForgeryError::MimeCategory(e) => Some(e),
+            ForgeryError::PortKind(e) => Some(e),
+            ForgeryError::CidrPrefix(e) => Some(e),
+            ForgeryError::StatusClass(e) => Some(e),
+            ForgeryError::UrlOptions(e) => Some(e),
+            ForgeryError::EmailDomain(e) => Some(e),
+            ForgeryError::MacKind(e) => Some(e),
+            ForgeryError::MacSeparator(e) => Some(e),
+            ForgeryError::PasswordHashAlgorithm(e) => Some(e),
+            ForgeryError::JwtAlgorithm(e) => Some(e),
+            ForgeryError::ApiKeyAlphabet(e) => Some(e),
+            ForgeryError::PhoneNumberFormat(e) => Some(e),
+            ForgeryError::PhoneKind(e) => Some(e),
+            ForgeryError::FictionalPhone(e) => Some(e),
+            ForgeryError::JobLevel(e) => Some(e),
+            ForgeryError::TimeFormat(e) => Some(e),
+            ForgeryError::TimeSeries(e) => Some(e),
+            ForgeryError::CssColorFormat(e) => Some(e),
+            ForgeryError::PaletteScheme(e) => Some(e),
+            ForgeryError::DurationFormat(e) => Some(e),
+            ForgeryError::DurationRange(e) => Some(e),
+            ForgeryError::DemographicWeights(e) => Some(e),
+            ForgeryError::Carrier(e) => Some(e),
+            ForgeryError::InvoiceNumber(e) => Some(e),
+            ForgeryError::PriceTier(e) => Some(e),
+            ForgeryError::K8sResource(e) => Some(e),
+            ForgeryError::ArnService(e) => Some(e),
+            ForgeryError::CloudProvider(e) => Some(e),
+            ForgeryError::SyslogFormat(e) => Some(e),
+            ForgeryError::Sensor(e) => Some(e),
+            ForgeryError::GpsTrack(e) => Some(e),
+            ForgeryError::InsuranceNumber(e) => Some(e),
+            ForgeryError::TokenFormat(e) => Some(e),
+            ForgeryError::TotpSecretLength(e) => Some(e),
+            ForgeryError::Ipv6Kind(e) => Some(e),
+            ForgeryError::Ipv4Range(e) => Some(e),
+            ForgeryError::AddressOptions(e) => Some(e),
+            ForgeryError::UsState(e) => Some(e),
+            ForgeryError::UsernameStyle(e) => Some(e),
+            ForgeryError::TextShape(e) => Some(e),
+            ForgeryError::CatchPhraseStyle(e) => Some(e),
         }
     }
 }
@@ -113,6 +351,318 @@ impl From<UniqueExhaustedError> for ForgeryError {
     }
 }
 
+impl From<NameFormatError> for ForgeryError {
+    fn from(err: NameFormatError) -> Self {
+        ForgeryError::NameFormat(err)
+    }
+}
+
+impl From<DateStyleError> for ForgeryError {
+    fn from(err: DateStyleError) -> Self {
+        ForgeryError::DateStyle(err)
+    }
+}
+
+impl From<VatCountryError> for ForgeryError {
+    fn from(err: VatCountryError) -> Self {
+        ForgeryError::VatCountry(err)
+    }
+}
+
+impl From<CardNetworkError> for ForgeryError {
+    fn from(err: CardNetworkError) -> Self {
+        ForgeryError::CardNetwork(err)
+    }
+}
+
+impl From<CurrencyError> for ForgeryError {
+    fn from(err: CurrencyError) -> Self {
+        ForgeryError::Currency(err)
+    }
+}
+
+impl From<LicenseRegionError> for ForgeryError {
+    fn from(err: LicenseRegionError) -> Self {
+        ForgeryError::LicenseRegion(err)
+    }
+}
+
+impl From<BoundingBoxError> for ForgeryError {
+    fn from(err: BoundingBoxError) -> Self {
+        ForgeryError::BoundingBox(err)
+    }
+}
+
+impl From<GeoCountryError> for ForgeryError {
+    fn from(err: GeoCountryError) -> Self {
+        ForgeryError::GeoCountry(err)
+    }
+}
+
+impl From<CountryFormatError> for ForgeryError {
+    fn from(err: CountryFormatError) -> Self {
+        ForgeryError::CountryFormat(err)
+    }
+}
+
+impl From<FileCategoryError> for ForgeryError {
+    fn from(err: FileCategoryError) -> Self {
+        ForgeryError::FileCategory(err)
+    }
+}
+
+impl From<PathStyleError> for ForgeryError {
+    fn from(err: PathStyleError) -> Self {
+        ForgeryError::PathStyle(err)
+    }
+}
+
+impl From<MimeCategoryError> for ForgeryError {
+    fn from(err: MimeCategoryError) -> Self {
+        ForgeryError::MimeCategory(err)
+    }
+}
+
+impl From<PortKindError> for ForgeryError {
+    fn from(err: PortKindError) -> Self {
+        ForgeryError::PortKind(err)
+    }
+}
+
+impl From<CidrPrefixError> for ForgeryError {
+    fn from(err: CidrPrefixError) -> Self {
+        ForgeryError::CidrPrefix(err)
+    }
+}
+
+impl From<StatusClassError> for ForgeryError {
+    fn from(err: StatusClassError) -> Self {
+        ForgeryError::StatusClass(err)
+    }
+}
+
+impl From<UrlOptionsError> for ForgeryError {
+    fn from(err: UrlOptionsError) -> Self {
+        ForgeryError::UrlOptions(err)
+    }
+}
+
+impl From<EmailDomainError> for ForgeryError {
+    fn from(err: EmailDomainError) -> Self {
+        ForgeryError::EmailDomain(err)
+    }
+}
+
+impl From<MacKindError> for ForgeryError {
+    fn from(err: MacKindError) -> Self {
+        ForgeryError::MacKind(err)
+    }
+}
+
+impl From<MacSeparatorError> for ForgeryError {
+    fn from(err: MacSeparatorError) -> Self {
+        ForgeryError::MacSeparator(err)
+    }
+}
+
+impl From<PasswordHashAlgorithmError> for ForgeryError {
+    fn from(err: PasswordHashAlgorithmError) -> Self {
+        ForgeryError::PasswordHashAlgorithm(err)
+    }
+}
+
+impl From<JwtAlgorithmError> for ForgeryError {
+    fn from(err: JwtAlgorithmError) -> Self {
+        ForgeryError::JwtAlgorithm(err)
+    }
+}
+
+impl From<ApiKeyAlphabetError> for ForgeryError {
+    fn from(err: ApiKeyAlphabetError) -> Self {
+        ForgeryError::ApiKeyAlphabet(err)
+    }
+}
+
+impl From<PhoneNumberFormatError> for ForgeryError {
+    fn from(err: PhoneNumberFormatError) -> Self {
+        ForgeryError::PhoneNumberFormat(err)
+    }
+}
+
+impl From<PhoneKindError> for ForgeryError {
+    fn from(err: PhoneKindError) -> Self {
+        ForgeryError::PhoneKind(err)
+    }
+}
+
+impl From<FictionalPhoneError> for ForgeryError {
+    fn from(err: FictionalPhoneError) -> Self {
+        ForgeryError::FictionalPhone(err)
+    }
+}
+
+impl From<JobLevelError> for ForgeryError {
+    fn from(err: JobLevelError) -> Self {
+        ForgeryError::JobLevel(err)
+    }
+}
+
+impl From<TimeFormatError> for ForgeryError {
+    fn from(err: TimeFormatError) -> Self {
+        ForgeryError::TimeFormat(err)
+    }
+}
+
+impl From<TimeSeriesError> for ForgeryError {
+    fn from(err: TimeSeriesError) -> Self {
+        ForgeryError::TimeSeries(err)
+    }
+}
+
+impl From<CssColorFormatError> for ForgeryError {
+    fn from(err: CssColorFormatError) -> Self {
+        ForgeryError::CssColorFormat(err)
+    }
+}
+
+impl From<PaletteSchemeError> for ForgeryError {
+    fn from(err: PaletteSchemeError) -> Self {
+        ForgeryError::PaletteScheme(err)
+    }
+}
+
+impl From<DurationFormatError> for ForgeryError {
+    fn from(err: DurationFormatError) -> Self {
+        ForgeryError::DurationFormat(err)
+    }
+}
+
+impl From<DurationRangeError> for ForgeryError {
+    fn from(err: DurationRangeError) -> Self {
+        ForgeryError::DurationRange(err)
+    }
+}
+
+impl From<DemographicWeightsError> for ForgeryError {
+    fn from(err: DemographicWeightsError) -> Self {
+        ForgeryError::DemographicWeights(err)
+    }
+}
+
+impl From<CarrierError> for ForgeryError {
+    fn from(err: CarrierError) -> Self {
+        ForgeryError::Carrier(err)
+    }
+}
+
+impl From<InvoiceNumberError> for ForgeryError {
+    fn from(err: InvoiceNumberError) -> Self {
+        ForgeryError::InvoiceNumber(err)
+    }
+}
+
+impl From<PriceTierError> for ForgeryError {
+    fn from(err: PriceTierError) -> Self {
+        ForgeryError::PriceTier(err)
+    }
+}
+
+impl From<K8sResourceError> for ForgeryError {
+    fn from(err: K8sResourceError) -> Self {
+        ForgeryError::K8sResource(err)
+    }
+}
+
+impl From<ArnServiceError> for ForgeryError {
+    fn from(err: ArnServiceError) -> Self {
+        ForgeryError::ArnService(err)
+    }
+}
+
+impl From<CloudProviderError> for ForgeryError {
+    fn from(err: CloudProviderError) -> Self {
+        ForgeryError::CloudProvider(err)
+    }
+}
+
+impl From<SyslogFormatError> for ForgeryError {
+    fn from(err: SyslogFormatError) -> Self {
+        ForgeryError::SyslogFormat(err)
+    }
+}
+
+impl From<SensorError> for ForgeryError {
+    fn from(err: SensorError) -> Self {
+        ForgeryError::Sensor(err)
+    }
+}
+
+impl From<GpsTrackError> for ForgeryError {
+    fn from(err: GpsTrackError) -> Self {
+        ForgeryError::GpsTrack(err)
+    }
+}
+
+impl From<InsuranceNumberError> for ForgeryError {
+    fn from(err: InsuranceNumberError) -> Self {
+        ForgeryError::InsuranceNumber(err)
+    }
+}
+
+impl From<TokenFormatError> for ForgeryError {
+    fn from(err: TokenFormatError) -> Self {
+        ForgeryError::TokenFormat(err)
+    }
+}
+
+impl From<TotpSecretLengthError> for ForgeryError {
+    fn from(err: TotpSecretLengthError) -> Self {
+        ForgeryError::TotpSecretLength(err)
+    }
+}
+
+impl From<Ipv6KindError> for ForgeryError {
+    fn from(err: Ipv6KindError) -> Self {
+        ForgeryError::Ipv6Kind(err)
+    }
+}
+
+impl From<Ipv4RangeError> for ForgeryError {
+    fn from(err: Ipv4RangeError) -> Self {
+        ForgeryError::Ipv4Range(err)
+    }
+}
+
+impl From<AddressOptionsError> for ForgeryError {
+    fn from(err: AddressOptionsError) -> Self {
+        ForgeryError::AddressOptions(err)
+    }
+}
+
+impl From<UsStateError> for ForgeryError {
+    fn from(err: UsStateError) -> Self {
+        ForgeryError::UsState(err)
+    }
+}
+
+impl From<UsernameStyleError> for ForgeryError {
+    fn from(err: UsernameStyleError) -> Self {
+        ForgeryError::UsernameStyle(err)
+    }
+}
+
+impl From<TextShapeError> for ForgeryError {
+    fn from(err: TextShapeError) -> Self {
+        ForgeryError::TextShape(err)
+    }
+}
+
+impl From<CatchPhraseStyleError> for ForgeryError {
+    fn from(err: CatchPhraseStyleError) -> Self {
+        ForgeryError::CatchPhraseStyle(err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,6 +709,514 @@ mod tests {
         assert!(matches!(forgery_err, ForgeryError::DateRange(_)));
     }
 
+    #[test]
+    fn test_forgery_error_from_name_format() {
+        let err = NameFormatError {
+            placeholder: "nickname".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::NameFormat(_)));
+        assert!(forgery_err.to_string().contains("{nickname}"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_date_style() {
+        let err = DateStyleError {
+            format: "rfc".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::DateStyle(_)));
+        assert!(forgery_err.to_string().contains("'rfc'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_vat_country() {
+        let err = VatCountryError {
+            country: "US".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::VatCountry(_)));
+        assert!(forgery_err.to_string().contains("'US'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_card_network() {
+        let err = CardNetworkError {
+            network: "diners".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::CardNetwork(_)));
+        assert!(forgery_err.to_string().contains("'diners'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_currency() {
+        let err = CurrencyError {
+            currency: "XYZ".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::Currency(_)));
+        assert!(forgery_err.to_string().contains("'XYZ'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_license_region() {
+        let err = LicenseRegionError {
+            region: "ZZ".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::LicenseRegion(_)));
+        assert!(forgery_err.to_string().contains("'ZZ'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_bounding_box() {
+        let err = BoundingBoxError {
+            min_lat: 10.0,
+            max_lat: 5.0,
+            min_lon: 0.0,
+            max_lon: 1.0,
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::BoundingBox(_)));
+        assert!(forgery_err.to_string().contains("bounding box"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_geo_country() {
+        let err = GeoCountryError {
+            country: "ZZ".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::GeoCountry(_)));
+        assert!(forgery_err.to_string().contains("'ZZ'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_country_format() {
+        let err = CountryFormatError {
+            format: "iso".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::CountryFormat(_)));
+        assert!(forgery_err.to_string().contains("'iso'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_file_category() {
+        let err = FileCategoryError {
+            category: "spreadsheet".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::FileCategory(_)));
+        assert!(forgery_err.to_string().contains("'spreadsheet'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_path_style() {
+        let err = PathStyleError {
+            os: "macos".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::PathStyle(_)));
+        assert!(forgery_err.to_string().contains("'macos'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_mime_category() {
+        let err = MimeCategoryError {
+            category: "font".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::MimeCategory(_)));
+        assert!(forgery_err.to_string().contains("'font'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_port_kind() {
+        let err = PortKindError {
+            kind: "private".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::PortKind(_)));
+        assert!(forgery_err.to_string().contains("'private'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_cidr_prefix() {
+        let err = CidrPrefixError { min: 24, max: 16 };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::CidrPrefix(_)));
+        assert!(forgery_err.to_string().contains("(24, 16)"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_status_class() {
+        let err = StatusClassError {
+            class: "6xx".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::StatusClass(_)));
+        assert!(forgery_err.to_string().contains("'6xx'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_url_options() {
+        let err = UrlOptionsError::InvalidScheme("1http".to_string());
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::UrlOptions(_)));
+        assert!(forgery_err.to_string().contains("'1http'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_email_domain() {
+        let err = EmailDomainError {
+            domain: "corp..example".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::EmailDomain(_)));
+        assert!(forgery_err.to_string().contains("'corp..example'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_mac_kind() {
+        let err = MacKindError {
+            kind: "oui".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::MacKind(_)));
+        assert!(forgery_err.to_string().contains("'oui'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_mac_separator() {
+        let err = MacSeparatorError {
+            separator: "space".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::MacSeparator(_)));
+        assert!(forgery_err.to_string().contains("'space'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_password_hash_algorithm() {
+        let err = PasswordHashAlgorithmError {
+            algorithm: "md5crypt".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(
+            forgery_err,
+            ForgeryError::PasswordHashAlgorithm(_)
+        ));
+        assert!(forgery_err.to_string().contains("'md5crypt'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_jwt_algorithm() {
+        let err = JwtAlgorithmError {
+            algorithm: "none".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::JwtAlgorithm(_)));
+        assert!(forgery_err.to_string().contains("'none'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_api_key_alphabet() {
+        let err = ApiKeyAlphabetError {
+            alphabet: "base58".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::ApiKeyAlphabet(_)));
+        assert!(forgery_err.to_string().contains("'base58'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_phone_number_format() {
+        let err = PhoneNumberFormatError {
+            format: "rfc3966".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::PhoneNumberFormat(_)));
+        assert!(forgery_err.to_string().contains("'rfc3966'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_phone_kind() {
+        let err = PhoneKindError {
+            kind: "voip".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::PhoneKind(_)));
+        assert!(forgery_err.to_string().contains("'voip'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_fictional_phone() {
+        let err = FictionalPhoneError {
+            locale: "es_ES".to_string(),
+            kind: "any".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::FictionalPhone(_)));
+        assert!(forgery_err.to_string().contains("'es_ES'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_job_level() {
+        let err = JobLevelError {
+            level: "ceo".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::JobLevel(_)));
+        assert!(forgery_err.to_string().contains("'ceo'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_time_format() {
+        let err = TimeFormatError {
+            format: "%Y".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::TimeFormat(_)));
+        assert!(forgery_err.to_string().contains("'%Y'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_time_series() {
+        let err = TimeSeriesError::InvalidFrequency("5m".to_string());
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::TimeSeries(_)));
+        assert!(forgery_err.to_string().contains("'5m'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_css_color_format() {
+        let err = CssColorFormatError {
+            format: "hwb".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::CssColorFormat(_)));
+        assert!(forgery_err.to_string().contains("'hwb'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_palette_scheme() {
+        let err = PaletteSchemeError {
+            scheme: "triadic".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::PaletteScheme(_)));
+        assert!(forgery_err.to_string().contains("'triadic'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_duration_format() {
+        let err = DurationFormatError {
+            format: "minutes".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::DurationFormat(_)));
+        assert!(forgery_err.to_string().contains("'minutes'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_duration_range() {
+        let err = DurationRangeError { min: 10, max: 5 };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::DurationRange(_)));
+        assert!(forgery_err.to_string().contains("min (10)"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_demographic_weights() {
+        let err = DemographicWeightsError::ZeroTotal {
+            demographic: crate::providers::demographics::Demographic::Gender,
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::DemographicWeights(_)));
+        assert!(forgery_err.to_string().contains("gender weights"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_carrier() {
+        let err = CarrierError {
+            carrier: "royal_mail".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::Carrier(_)));
+        assert!(forgery_err.to_string().contains("'royal_mail'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_invoice_number() {
+        let err = InvoiceNumberError::Digits(0);
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::InvoiceNumber(_)));
+        assert!(forgery_err.to_string().contains("between 1 and 12"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_price_tier() {
+        let err = PriceTierError {
+            tier: "bargain".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::PriceTier(_)));
+        assert!(forgery_err.to_string().contains("'bargain'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_k8s_resource() {
+        let err = K8sResourceError {
+            kind: "service".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::K8sResource(_)));
+        assert!(forgery_err.to_string().contains("'service'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_arn_service() {
+        let err = ArnServiceError {
+            service: "ec2".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::ArnService(_)));
+        assert!(forgery_err.to_string().contains("'ec2'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_cloud_provider() {
+        let err = CloudProviderError {
+            provider: "oracle".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::CloudProvider(_)));
+        assert!(forgery_err.to_string().contains("'oracle'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_syslog_format() {
+        let err = SyslogFormatError {
+            format: "gelf".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::SyslogFormat(_)));
+        assert!(forgery_err.to_string().contains("'gelf'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_sensor_error() {
+        let err = SensorError::UnknownMetric("rain".to_string());
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::Sensor(_)));
+        assert!(forgery_err.to_string().contains("'rain'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_gps_track_error() {
+        let err = GpsTrackError::InvalidSpeed(-1.0);
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::GpsTrack(_)));
+        assert!(forgery_err.to_string().contains("max_speed_kmh"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_insurance_number_error() {
+        let err = InsuranceNumberError::Digits(0);
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::InsuranceNumber(_)));
+        assert!(forgery_err.to_string().contains("digits"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_token_format_error() {
+        let err = TokenFormatError {
+            format: "jwt".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::TokenFormat(_)));
+        assert!(forgery_err.to_string().contains("'jwt'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_totp_secret_length_error() {
+        let err = TotpSecretLengthError { length: 20 };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::TotpSecretLength(_)));
+        assert!(forgery_err.to_string().contains("length 20"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_ipv6_kind_error() {
+        let err = Ipv6KindError {
+            kind: "multicast".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::Ipv6Kind(_)));
+        assert!(forgery_err.to_string().contains("'multicast'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_ipv4_range_error() {
+        let err = Ipv4RangeError {
+            range: "intranet".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::Ipv4Range(_)));
+        assert!(forgery_err.to_string().contains("'intranet'"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_address_options() {
+        let err = AddressOptionsError::InvalidRate {
+            name: "unit_rate",
+            rate: 2.0,
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::AddressOptions(_)));
+        assert!(forgery_err.to_string().contains("unit_rate"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_us_state() {
+        let err = UsStateError {
+            state: "Ontario".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::UsState(_)));
+        assert!(forgery_err.to_string().contains("Ontario"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_username_style_error() {
+        let err = UsernameStyleError {
+            style: "first-last".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::UsernameStyle(_)));
+        assert!(forgery_err.to_string().contains("first-last"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_text_shape_error() {
+        let err = TextShapeError::CommaRate(2.0);
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::TextShape(_)));
+        assert!(forgery_err.to_string().contains("comma_rate"));
+    }
+
+    #[test]
+    fn test_forgery_error_from_catch_phrase_style_error() {
+        let err = CatchPhraseStyleError {
+            style: "haiku".to_string(),
+        };
+        let forgery_err: ForgeryError = err.into();
+        assert!(matches!(forgery_err, ForgeryError::CatchPhraseStyle(_)));
+        assert!(forgery_err.to_string().contains("haiku"));
+    }
+
     #[test]
     fn test_error_source() {
         let err = RangeError { min: 100, max: 0 };
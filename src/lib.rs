@@ -24,15 +24,18 @@ mod rng;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+use pyo3::types::{PyBytes, PyDict, PyList, PyString, PyTuple};
 use pyo3::IntoPyObjectExt;
 use pyo3_arrow::PyRecordBatch;
 use rng::ForgeryRng;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
 
+use data::overrides::{EmptyOverrideError, LocaleOverrides};
 use error::{ForgeryError, UniqueExhaustedError};
 use locale::{Locale, LocaleError};
 use providers::custom::{is_reserved_name, CustomProvider, CustomProviderError};
+use providers::names::NameFormatError;
 use std::str::FromStr;
 
 /// Maximum batch size to prevent memory exhaustion.
@@ -130,6 +133,23 @@ pub fn validate_locale(locale: &str) -> Result<Locale, LocaleError> {
     Locale::from_str(locale)
 }
 
+/// Parse an optional date `format` name, defaulting to ISO 8601.
+#[inline]
+fn parse_date_style(
+    format: Option<&str>,
+) -> Result<providers::datetime::DateStyle, providers::datetime::DateStyleError> {
+    format.map_or(Ok(providers::datetime::DateStyle::Iso), str::parse)
+}
+
+/// Parse an optional country `format` name, falling back to `default`.
+#[inline]
+fn parse_country_format(
+    format: Option<&str>,
+    default: providers::address::CountryFormat,
+) -> Result<providers::address::CountryFormat, providers::address::CountryFormatError> {
+    format.map_or(Ok(default), str::parse)
+}
+
 /// A fake data generator with its own random state.
 ///
 /// Each instance maintains independent RNG state, allowing for deterministic
@@ -144,11 +164,17 @@ pub fn validate_locale(locale: &str) -> Result<Locale, LocaleError> {
 /// - `it_IT` - Italian (Italy)
 /// - `ja_JP` - Japanese (Japan)
 /// - `en_GB` - English (United Kingdom)
+/// - `ko_KR` - Korean (South Korea)
+/// - `ru_RU` - Russian (Russia)
+/// - `nl_NL` - Dutch (Netherlands)
+/// - `pl_PL` - Polish (Poland)
+/// - `en_CA` - English (Canada)
 #[pyclass]
 pub struct Faker {
     rng: ForgeryRng,
     locale: Locale,
     custom_providers: HashMap<String, CustomProvider>,
+    overrides: Arc<LocaleOverrides>,
 }
 
 // Public Rust API - these methods are callable from Rust code (including benchmarks)
@@ -172,12 +198,18 @@ impl Faker {
     /// - `it_IT` - Italian (Italy)
     /// - `ja_JP` - Japanese (Japan)
     /// - `en_GB` - English (United Kingdom)
+    /// - `ko_KR` - Korean (South Korea)
+    /// - `ru_RU` - Russian (Russia)
+    /// - `nl_NL` - Dutch (Netherlands)
+    /// - `pl_PL` - Polish (Poland)
+    /// - `en_CA` - English (Canada)
     pub fn new(locale: &str) -> Result<Self, LocaleError> {
         let parsed_locale = validate_locale(locale)?;
         Ok(Self {
             rng: ForgeryRng::new(),
             locale: parsed_locale,
             custom_providers: HashMap::new(),
+            overrides: Arc::default(),
         })
     }
 
@@ -187,6 +219,7 @@ impl Faker {
             rng: ForgeryRng::new(),
             locale: Locale::default(),
             custom_providers: HashMap::new(),
+            overrides: Arc::default(),
         }
     }
 
@@ -268,11 +301,15 @@ impl Faker {
     pub fn names(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
         if unique {
-            self.generate_unique(n, providers::names::generate_name)
+            let overrides = Arc::clone(&self.overrides);
+            self.generate_unique(n, |rng, locale| {
+                providers::names::generate_name_with_overrides(rng, locale, &overrides)
+            })
         } else {
-            Ok(providers::names::generate_names(
+            Ok(providers::names::generate_names_with_overrides(
                 &mut self.rng,
                 self.locale,
+                &self.overrides,
                 n,
             ))
         }
@@ -292,11 +329,15 @@ impl Faker {
     pub fn first_names(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
         if unique {
-            self.generate_unique(n, providers::names::generate_first_name)
+            let overrides = Arc::clone(&self.overrides);
+            self.generate_unique(n, |rng, locale| {
+                providers::names::generate_first_name_with_overrides(rng, locale, &overrides)
+            })
         } else {
-            Ok(providers::names::generate_first_names(
+            Ok(providers::names::generate_first_names_with_overrides(
                 &mut self.rng,
                 self.locale,
+                &self.overrides,
                 n,
             ))
         }
@@ -316,11 +357,15 @@ impl Faker {
     pub fn last_names(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
         if unique {
-            self.generate_unique(n, providers::names::generate_last_name)
+            let overrides = Arc::clone(&self.overrides);
+            self.generate_unique(n, |rng, locale| {
+                providers::names::generate_last_name_with_overrides(rng, locale, &overrides)
+            })
         } else {
-            Ok(providers::names::generate_last_names(
+            Ok(providers::names::generate_last_names_with_overrides(
                 &mut self.rng,
                 self.locale,
+                &self.overrides,
                 n,
             ))
         }
@@ -328,36 +373,47 @@ impl Faker {
 
     /// Generate a single random full name.
     pub fn name(&mut self) -> String {
-        providers::names::generate_name(&mut self.rng, self.locale)
+        providers::names::generate_name_with_overrides(&mut self.rng, self.locale, &self.overrides)
     }
 
     /// Generate a single random first name.
     pub fn first_name(&mut self) -> String {
-        providers::names::generate_first_name(&mut self.rng, self.locale)
+        providers::names::generate_first_name_with_overrides(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+        )
     }
 
     /// Generate a single random last name.
     pub fn last_name(&mut self) -> String {
-        providers::names::generate_last_name(&mut self.rng, self.locale)
+        providers::names::generate_last_name_with_overrides(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+        )
     }
 
-    /// Generate a batch of random email addresses.
+    /// Generate a batch of random romanized full names.
+    ///
+    /// Uses the locale's romanized names where available, otherwise the
+    /// regular names.
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of emails to generate
+    /// * `n` - Number of names to generate
     /// * `unique` - If true, ensure all generated values are unique
     ///
     /// # Errors
     ///
     /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
     /// if unique generation cannot produce enough unique values.
-    pub fn emails(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    pub fn names_romanized(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
         if unique {
-            self.generate_unique(n, providers::internet::generate_email)
+            self.generate_unique(n, providers::names::generate_name_romanized)
         } else {
-            Ok(providers::internet::generate_emails(
+            Ok(providers::names::generate_names_romanized(
                 &mut self.rng,
                 self.locale,
                 n,
@@ -365,228 +421,515 @@ impl Faker {
         }
     }
 
-    /// Generate a single random email address.
-    pub fn email(&mut self) -> String {
-        providers::internet::generate_email(&mut self.rng, self.locale)
+    /// Generate a single random romanized full name.
+    pub fn name_romanized(&mut self) -> String {
+        providers::names::generate_name_romanized(&mut self.rng, self.locale)
     }
 
-    /// Generate a batch of random integers within a range.
+    /// Generate a batch of random romanized first names.
+    ///
+    /// Uses the locale's romanized names where available, otherwise the
+    /// regular names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of names to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
     /// # Errors
     ///
-    /// Returns an error if `min > max` or `n` exceeds the maximum batch size.
-    pub fn integers(
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn first_names_romanized(
         &mut self,
         n: usize,
-        min: i64,
-        max: i64,
-    ) -> Result<Vec<i64>, Box<dyn std::error::Error>> {
+        unique: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::numbers::generate_integers(
-            &mut self.rng,
-            n,
-            min,
-            max,
-        )?)
+        if unique {
+            self.generate_unique(n, providers::names::generate_first_name_romanized)
+        } else {
+            Ok(providers::names::generate_first_names_romanized(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a single random integer within a range.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if `min > max`.
-    pub fn integer(&mut self, min: i64, max: i64) -> Result<i64, providers::numbers::RangeError> {
-        providers::numbers::generate_integer(&mut self.rng, min, max)
+    /// Generate a single random romanized first name.
+    pub fn first_name_romanized(&mut self) -> String {
+        providers::names::generate_first_name_romanized(&mut self.rng, self.locale)
     }
 
-    /// Generate a batch of random UUIDs (version 4).
+    /// Generate a batch of random romanized last names.
     ///
-    /// # Errors
+    /// Uses the locale's romanized names where available, otherwise the
+    /// regular names.
     ///
-    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
-    pub fn uuids(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
-        validate_batch_size(n)?;
-        Ok(providers::identifiers::generate_uuids(&mut self.rng, n))
-    }
-
-    /// Generate a single random UUID (version 4).
-    pub fn uuid(&mut self) -> String {
-        providers::identifiers::generate_uuid(&mut self.rng)
-    }
-
-    // === Float Generation ===
-
-    /// Generate a batch of random floats within a range.
+    /// # Arguments
+    ///
+    /// * `n` - Number of names to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
     /// # Errors
     ///
-    /// Returns an error if `min > max` or `n` exceeds the maximum batch size.
-    pub fn floats(
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn last_names_romanized(
         &mut self,
         n: usize,
-        min: f64,
-        max: f64,
-    ) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+        unique: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::numbers::generate_floats(
-            &mut self.rng,
-            n,
-            min,
-            max,
-        )?)
+        if unique {
+            self.generate_unique(n, providers::names::generate_last_name_romanized)
+        } else {
+            Ok(providers::names::generate_last_names_romanized(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a single random float within a range.
+    /// Generate a single random romanized last name.
+    pub fn last_name_romanized(&mut self) -> String {
+        providers::names::generate_last_name_romanized(&mut self.rng, self.locale)
+    }
+
+    /// Generate a batch of random name prefixes (e.g., "Mr.", "Dr.", "Frau").
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of prefixes to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
     /// # Errors
     ///
-    /// Returns an error if `min > max`.
-    pub fn float(
-        &mut self,
-        min: f64,
-        max: f64,
-    ) -> Result<f64, providers::numbers::FloatRangeError> {
-        providers::numbers::generate_float(&mut self.rng, min, max)
-    }
-
-    // === Hash Generation ===
-
-    /// Generate a batch of random MD5 hashes.
-    pub fn md5s(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn prefixes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::identifiers::generate_md5s(&mut self.rng, n))
+        if unique {
+            self.generate_unique(n, providers::names::generate_prefix)
+        } else {
+            Ok(providers::names::generate_prefixes(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a single random MD5 hash.
-    pub fn md5(&mut self) -> String {
-        providers::identifiers::generate_md5(&mut self.rng)
+    /// Generate a single random name prefix.
+    pub fn prefix(&mut self) -> String {
+        providers::names::generate_prefix(&mut self.rng, self.locale)
     }
 
-    /// Generate a batch of random SHA256 hashes.
-    pub fn sha256s(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+    /// Generate a batch of random name suffixes (e.g., "Jr.", "PhD").
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of suffixes to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn suffixes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::identifiers::generate_sha256s(&mut self.rng, n))
+        if unique {
+            self.generate_unique(n, providers::names::generate_suffix)
+        } else {
+            Ok(providers::names::generate_suffixes(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a single random SHA256 hash.
-    pub fn sha256(&mut self) -> String {
-        providers::identifiers::generate_sha256(&mut self.rng)
+    /// Generate a single random name suffix.
+    pub fn suffix(&mut self) -> String {
+        providers::names::generate_suffix(&mut self.rng, self.locale)
     }
 
-    // === Color Generation ===
-
-    /// Generate a batch of random color names.
+    /// Generate a batch of full names with a prefix (e.g., "Dr. Jane Smith").
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of colors to generate
+    /// * `n` - Number of names to generate
     /// * `unique` - If true, ensure all generated values are unique
     ///
     /// # Errors
     ///
     /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
     /// if unique generation cannot produce enough unique values.
-    pub fn colors(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    pub fn names_with_prefix(
+        &mut self,
+        n: usize,
+        unique: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
         if unique {
-            self.generate_unique(n, providers::colors::generate_color)
+            let overrides = Arc::clone(&self.overrides);
+            self.generate_unique(n, |rng, locale| {
+                providers::names::generate_name_with_prefix(rng, locale, &overrides)
+            })
         } else {
-            Ok(providers::colors::generate_colors(
+            Ok(providers::names::generate_names_with_prefix(
                 &mut self.rng,
                 self.locale,
+                &self.overrides,
                 n,
             ))
         }
     }
 
-    /// Generate a single random color name.
-    pub fn color(&mut self) -> String {
-        providers::colors::generate_color(&mut self.rng, self.locale)
+    /// Generate a single full name with a prefix.
+    pub fn name_with_prefix(&mut self) -> String {
+        providers::names::generate_name_with_prefix(&mut self.rng, self.locale, &self.overrides)
     }
 
-    /// Generate a batch of random hex colors.
-    pub fn hex_colors(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+    /// Generate a batch of full names from a format template.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of names to generate
+    /// * `format` - Template using `{prefix}`, `{first}`, `{middle}`,
+    ///   `{middle_initial}`, `{last}` and `{suffix}`; `None` uses the locale's order
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, if the
+    /// template is invalid, or if unique generation cannot produce enough
+    /// unique values.
+    pub fn full_names(
+        &mut self,
+        n: usize,
+        format: Option<&str>,
+        unique: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::colors::generate_hex_colors(&mut self.rng, n))
+        if unique {
+            // Validate once up front so the generator closure can't fail
+            if let Some(format) = format {
+                providers::names::validate_name_format(format)?;
+            }
+            let overrides = Arc::clone(&self.overrides);
+            self.generate_unique(n, |rng, locale| {
+                providers::names::generate_full_name(rng, locale, &overrides, format)
+                    .unwrap_or_default()
+            })
+        } else {
+            Ok(providers::names::generate_full_names(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+                format,
+                n,
+            )?)
+        }
     }
 
-    /// Generate a single random hex color.
-    pub fn hex_color(&mut self) -> String {
-        providers::colors::generate_hex_color(&mut self.rng)
+    /// Generate a single full name from a format template.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NameFormatError` if the template is invalid.
+    pub fn full_name(&mut self, format: Option<&str>) -> Result<String, NameFormatError> {
+        providers::names::generate_full_name(&mut self.rng, self.locale, &self.overrides, format)
     }
 
-    /// Generate a batch of random RGB color tuples.
-    pub fn rgb_colors(&mut self, n: usize) -> Result<Vec<(u8, u8, u8)>, BatchSizeError> {
+    /// Generate a batch of random email addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of emails to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    /// * `domain` - If given, every address uses this domain
+    /// * `style` - Local part layout (see `UsernameStyle`); "first###" if `None`
+    /// * `leet` - Replace letters in the local part with look-alike digits
+    /// * `mixed_case` - Capitalize the name parts of the local part
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, the
+    /// domain or style is invalid, or unique generation cannot produce enough
+    /// unique values.
+    pub fn emails(
+        &mut self,
+        n: usize,
+        unique: bool,
+        domain: Option<&str>,
+        style: Option<&str>,
+        leet: bool,
+        mixed_case: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::colors::generate_rgb_colors(&mut self.rng, n))
+        if let Some(options) = providers::internet::email_username_options(style, leet, mixed_case)?
+        {
+            if !unique {
+                return Ok(providers::internet::generate_styled_emails(
+                    &mut self.rng,
+                    self.locale,
+                    &self.overrides,
+                    n,
+                    domain,
+                    &options,
+                )?);
+            }
+            if let Some(domain) = domain {
+                providers::internet::validate_email_domain(domain)?;
+            }
+            let overrides = Arc::clone(&self.overrides);
+            return self.generate_unique(n, |rng, locale| {
+                providers::internet::styled_email(rng, locale, &overrides, domain, &options)
+            });
+        }
+        match (domain, unique) {
+            (Some(domain), true) => {
+                providers::internet::validate_email_domain(domain)?;
+                let overrides = Arc::clone(&self.overrides);
+                self.generate_unique(n, |rng, locale| {
+                    providers::internet::email_at(rng, locale, &overrides, domain)
+                })
+            }
+            (Some(domain), false) => Ok(providers::internet::generate_emails_with_domain(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+                n,
+                domain,
+            )?),
+            (None, true) => {
+                let overrides = Arc::clone(&self.overrides);
+                self.generate_unique(n, |rng, locale| {
+                    providers::internet::generate_email_with_overrides(rng, locale, &overrides)
+                })
+            }
+            (None, false) => Ok(providers::internet::generate_emails_with_overrides(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+                n,
+            )),
+        }
     }
 
-    /// Generate a single random RGB color tuple.
-    pub fn rgb_color(&mut self) -> (u8, u8, u8) {
-        providers::colors::generate_rgb_color(&mut self.rng)
+    /// Generate a single random email address, optionally on a fixed domain
+    /// and with a styled local part.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if the domain is not a valid hostname or the
+    /// style is unknown.
+    pub fn email(
+        &mut self,
+        domain: Option<&str>,
+        style: Option<&str>,
+        leet: bool,
+        mixed_case: bool,
+    ) -> Result<String, ForgeryError> {
+        if let Some(options) = providers::internet::email_username_options(style, leet, mixed_case)?
+        {
+            return Ok(providers::internet::generate_styled_emails(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+                1,
+                domain,
+                &options,
+            )?
+            .remove(0));
+        }
+        match domain {
+            Some(domain) => Ok(providers::internet::generate_email_with_domain(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+                domain,
+            )?),
+            None => Ok(providers::internet::generate_email_with_overrides(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+            )),
+        }
     }
 
-    // === DateTime Generation ===
-
-    /// Generate a batch of random dates within a range.
-    pub fn dates(
+    /// Generate a batch of usernames.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of usernames to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    /// * `style` - "first###", "first.last", "flast", "first_l##", or "any"
+    ///   for a random style per value
+    /// * `leet` - Replace a, e, i, o, s and t with 4, 3, 1, 0, 5 and 7
+    /// * `mixed_case` - Capitalize each name part instead of all-lowercase
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, the
+    /// style is unknown, or unique generation cannot produce enough unique values.
+    pub fn usernames(
         &mut self,
         n: usize,
-        start: &str,
-        end: &str,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-        validate_batch_size(n)?;
-        Ok(providers::datetime::generate_dates(
+        unique: bool,
+        style: &str,
+        leet: bool,
+        mixed_case: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let options = providers::internet::UsernameOptions {
+            style: style.parse()?,
+            leet,
+            mixed_case,
+        };
+        if unique {
+            let overrides = Arc::clone(&self.overrides);
+            self.generate_unique(n, |rng, locale| {
+                providers::internet::username(rng, locale, &overrides, &options)
+            })
+        } else {
+            Ok(providers::internet::generate_usernames(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+                n,
+                &options,
+            ))
+        }
+    }
+
+    /// Generate a single username (e.g., "james.smith" or "jsmith").
+    ///
+    /// # Errors
+    ///
+    /// Returns `UsernameStyleError` if the style is unknown.
+    pub fn username(
+        &mut self,
+        style: &str,
+        leet: bool,
+        mixed_case: bool,
+    ) -> Result<String, providers::internet::UsernameStyleError> {
+        let options = providers::internet::UsernameOptions {
+            style: style.parse()?,
+            leet,
+            mixed_case,
+        };
+        Ok(providers::internet::generate_username(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            &options,
+        ))
+    }
+
+    /// Generate a batch of corporate email addresses (`first.last@domain`).
+    ///
+    /// The whole batch shares one domain: `domain` if given, otherwise one
+    /// slugged from `company` ("Acme Holdings LLC" gives `acmeholdings.com`),
+    /// otherwise one slugged from a generated company name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// domain is invalid.
+    pub fn company_emails(
+        &mut self,
+        n: usize,
+        domain: Option<&str>,
+        company: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::internet::generate_company_emails(
             &mut self.rng,
+            self.locale,
             n,
-            start,
-            end,
+            domain,
+            company,
         )?)
     }
 
-    /// Generate a single random date within a range.
-    pub fn date(
+    /// Generate a single corporate email address.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EmailDomainError` if the domain is not a valid hostname.
+    pub fn company_email(
         &mut self,
-        start: &str,
-        end: &str,
-    ) -> Result<String, providers::datetime::DateRangeError> {
-        providers::datetime::generate_date(&mut self.rng, start, end)
+        domain: Option<&str>,
+        company: Option<&str>,
+    ) -> Result<String, providers::internet::EmailDomainError> {
+        providers::internet::generate_company_email(&mut self.rng, self.locale, domain, company)
     }
 
-    /// Generate a batch of random dates of birth.
-    pub fn dates_of_birth(
+    /// Generate a batch of random integers within a range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `min > max` or `n` exceeds the maximum batch size.
+    pub fn integers(
         &mut self,
         n: usize,
-        min_age: u32,
-        max_age: u32,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        min: i64,
+        max: i64,
+    ) -> Result<Vec<i64>, Box<dyn std::error::Error>> {
         validate_batch_size(n)?;
-        Ok(providers::datetime::generate_dates_of_birth(
+        Ok(providers::numbers::generate_integers(
             &mut self.rng,
             n,
-            min_age,
-            max_age,
+            min,
+            max,
         )?)
     }
 
-    /// Generate a single random date of birth.
-    pub fn date_of_birth(
-        &mut self,
-        min_age: u32,
-        max_age: u32,
-    ) -> Result<String, providers::datetime::DateRangeError> {
-        providers::datetime::generate_date_of_birth(&mut self.rng, min_age, max_age)
+    /// Generate a single random integer within a range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `min > max`.
+    pub fn integer(&mut self, min: i64, max: i64) -> Result<i64, providers::numbers::RangeError> {
+        providers::numbers::generate_integer(&mut self.rng, min, max)
     }
 
-    /// Generate a batch of random datetimes within a range.
-    pub fn datetimes(
+    /// Generate a batch of random UUIDs (version 4).
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn uuids(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::identifiers::generate_uuids(&mut self.rng, n))
+    }
+
+    /// Generate a single random UUID (version 4).
+    pub fn uuid(&mut self) -> String {
+        providers::identifiers::generate_uuid(&mut self.rng)
+    }
+
+    /// Generate a batch of time-ordered UUIDs (version 7).
+    ///
+    /// Timestamps fall between the start of `start` and the end of `end`
+    /// (YYYY-MM-DD), and the batch is sorted by timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `DateRangeError` for an invalid range.
+    pub fn uuid7s(
         &mut self,
         n: usize,
         start: &str,
         end: &str,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::datetime::generate_datetimes(
+        Ok(providers::identifiers::generate_uuid7s(
             &mut self.rng,
             n,
             start,
@@ -594,160 +937,158 @@ impl Faker {
         )?)
     }
 
-    /// Generate a single random datetime within a range.
-    pub fn datetime(
+    /// Generate a single UUID (version 7) with a timestamp in the range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DateRangeError` for an invalid range.
+    pub fn uuid7(
         &mut self,
         start: &str,
         end: &str,
     ) -> Result<String, providers::datetime::DateRangeError> {
-        providers::datetime::generate_datetime(&mut self.rng, start, end)
+        providers::identifiers::generate_uuid7(&mut self.rng, start, end)
     }
 
-    // === Text Generation ===
+    // === Float Generation ===
 
-    /// Generate a batch of random sentences.
-    pub fn sentences(
+    /// Generate a batch of random floats within a range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `min > max` or `n` exceeds the maximum batch size.
+    pub fn floats(
         &mut self,
         n: usize,
-        word_count: usize,
-    ) -> Result<Vec<String>, BatchSizeError> {
+        min: f64,
+        max: f64,
+    ) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
         validate_batch_size(n)?;
-        Ok(providers::text::generate_sentences(
+        Ok(providers::numbers::generate_floats(
             &mut self.rng,
-            self.locale,
             n,
-            word_count,
-        ))
+            min,
+            max,
+        )?)
     }
 
-    /// Generate a single random sentence.
-    pub fn sentence(&mut self, word_count: usize) -> String {
-        providers::text::generate_sentence(&mut self.rng, self.locale, word_count)
+    /// Generate a single random float within a range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `min > max`.
+    pub fn float(
+        &mut self,
+        min: f64,
+        max: f64,
+    ) -> Result<f64, providers::numbers::FloatRangeError> {
+        providers::numbers::generate_float(&mut self.rng, min, max)
     }
 
-    /// Generate a batch of random paragraphs.
-    pub fn paragraphs(
-        &mut self,
-        n: usize,
-        sentence_count: usize,
-    ) -> Result<Vec<String>, BatchSizeError> {
+    // === Hash Generation ===
+
+    /// Generate a batch of random MD5 hashes.
+    pub fn md5s(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
         validate_batch_size(n)?;
-        Ok(providers::text::generate_paragraphs(
-            &mut self.rng,
-            self.locale,
-            n,
-            sentence_count,
-        ))
+        Ok(providers::identifiers::generate_md5s(&mut self.rng, n))
     }
 
-    /// Generate a single random paragraph.
-    pub fn paragraph(&mut self, sentence_count: usize) -> String {
-        providers::text::generate_paragraph(&mut self.rng, self.locale, sentence_count)
+    /// Generate a single random MD5 hash.
+    pub fn md5(&mut self) -> String {
+        providers::identifiers::generate_md5(&mut self.rng)
     }
 
-    /// Generate a batch of random text blocks.
-    pub fn texts(
-        &mut self,
-        n: usize,
-        min_chars: usize,
-        max_chars: usize,
-    ) -> Result<Vec<String>, BatchSizeError> {
+    /// Generate a batch of random SHA1 hashes.
+    pub fn sha1s(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
         validate_batch_size(n)?;
-        Ok(providers::text::generate_texts(
-            &mut self.rng,
-            self.locale,
-            n,
-            min_chars,
-            max_chars,
-        ))
+        Ok(providers::identifiers::generate_sha1s(&mut self.rng, n))
     }
 
-    /// Generate a single random text block.
-    pub fn text(&mut self, min_chars: usize, max_chars: usize) -> String {
-        providers::text::generate_text(&mut self.rng, self.locale, min_chars, max_chars)
+    /// Generate a single random SHA1 hash.
+    pub fn sha1(&mut self) -> String {
+        providers::identifiers::generate_sha1(&mut self.rng)
     }
 
-    // === Address Generation ===
+    /// Generate a batch of random SHA256 hashes.
+    pub fn sha256s(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::identifiers::generate_sha256s(&mut self.rng, n))
+    }
 
-    /// Generate a batch of random street addresses.
-    ///
-    /// # Arguments
-    ///
-    /// * `n` - Number of addresses to generate
-    /// * `unique` - If true, ensure all generated values are unique
-    ///
-    /// # Errors
-    ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn street_addresses(
-        &mut self,
-        n: usize,
-        unique: bool,
-    ) -> Result<Vec<String>, ForgeryError> {
+    /// Generate a single random SHA256 hash.
+    pub fn sha256(&mut self) -> String {
+        providers::identifiers::generate_sha256(&mut self.rng)
+    }
+
+    /// Generate a batch of random SHA512 hashes.
+    pub fn sha512s(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
         validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::address::generate_street_address)
-        } else {
-            Ok(providers::address::generate_street_addresses(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
+        Ok(providers::identifiers::generate_sha512s(&mut self.rng, n))
     }
 
-    /// Generate a single random street address.
-    pub fn street_address(&mut self) -> String {
-        providers::address::generate_street_address(&mut self.rng, self.locale)
+    /// Generate a single random SHA512 hash.
+    pub fn sha512(&mut self) -> String {
+        providers::identifiers::generate_sha512(&mut self.rng)
     }
 
-    /// Generate a batch of random cities.
+    // === Binary Generation ===
+
+    /// Generate a batch of random byte strings.
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of cities to generate
-    /// * `unique` - If true, ensure all generated values are unique
+    /// * `n` - Number of values to generate
+    /// * `length` - Number of bytes in each value
+    pub fn bytes(&mut self, n: usize, length: usize) -> Result<Vec<Vec<u8>>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::binary::generate_byte_strings(
+            &mut self.rng,
+            n,
+            length,
+        ))
+    }
+
+    /// Generate a batch of base64 strings of random bytes.
     ///
-    /// # Errors
+    /// # Arguments
     ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn cities(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    /// * `n` - Number of values to generate
+    /// * `decoded_length` - Number of bytes each string decodes to
+    /// * `url_safe` - Use unpadded base64url instead of padded standard base64
+    pub fn base64(
+        &mut self,
+        n: usize,
+        decoded_length: usize,
+        url_safe: bool,
+    ) -> Result<Vec<String>, BatchSizeError> {
         validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::address::generate_city)
-        } else {
-            Ok(providers::address::generate_cities(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
+        Ok(providers::binary::generate_base64_strings(
+            &mut self.rng,
+            n,
+            decoded_length,
+            url_safe,
+        ))
     }
 
-    /// Generate a single random city.
-    pub fn city(&mut self) -> String {
-        providers::address::generate_city(&mut self.rng, self.locale)
-    }
+    // === Color Generation ===
 
-    /// Generate a batch of random states.
+    /// Generate a batch of random color names.
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of states to generate
+    /// * `n` - Number of colors to generate
     /// * `unique` - If true, ensure all generated values are unique
     ///
     /// # Errors
     ///
     /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
     /// if unique generation cannot produce enough unique values.
-    pub fn states(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    pub fn colors(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
         if unique {
-            self.generate_unique(n, providers::address::generate_state)
+            self.generate_unique(n, providers::colors::generate_color)
         } else {
-            Ok(providers::address::generate_states(
+            Ok(providers::colors::generate_colors(
                 &mut self.rng,
                 self.locale,
                 n,
@@ -755,2126 +1096,9836 @@ impl Faker {
         }
     }
 
-    /// Generate a single random state.
-    pub fn state(&mut self) -> String {
-        providers::address::generate_state(&mut self.rng, self.locale)
+    /// Generate a single random color name.
+    pub fn color(&mut self) -> String {
+        providers::colors::generate_color(&mut self.rng, self.locale)
     }
 
-    /// Generate a batch of random countries.
-    ///
-    /// # Arguments
-    ///
-    /// * `n` - Number of countries to generate
-    /// * `unique` - If true, ensure all generated values are unique
-    ///
-    /// # Errors
-    ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn countries(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    /// Generate a batch of random hex colors.
+    pub fn hex_colors(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
         validate_batch_size(n)?;
-        if unique {
-            // countries generator doesn't take locale, need a wrapper
-            self.generate_unique(n, |rng, _locale| providers::address::generate_country(rng))
-        } else {
-            Ok(providers::address::generate_countries(&mut self.rng, n))
-        }
+        Ok(providers::colors::generate_hex_colors(&mut self.rng, n))
     }
 
-    /// Generate a single random country.
-    pub fn country(&mut self) -> String {
-        providers::address::generate_country(&mut self.rng)
+    /// Generate a single random hex color.
+    pub fn hex_color(&mut self) -> String {
+        providers::colors::generate_hex_color(&mut self.rng)
     }
 
-    /// Generate a batch of random zip codes.
-    ///
-    /// # Arguments
-    ///
-    /// * `n` - Number of zip codes to generate
-    /// * `unique` - If true, ensure all generated values are unique
-    ///
-    /// # Errors
-    ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn zip_codes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    /// Generate a batch of random RGB color tuples.
+    pub fn rgb_colors(&mut self, n: usize) -> Result<Vec<(u8, u8, u8)>, BatchSizeError> {
         validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::address::generate_zip_code)
-        } else {
-            Ok(providers::address::generate_zip_codes(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
+        Ok(providers::colors::generate_rgb_colors(&mut self.rng, n))
     }
 
-    /// Generate a single random zip code.
-    pub fn zip_code(&mut self) -> String {
-        providers::address::generate_zip_code(&mut self.rng, self.locale)
+    /// Generate a single random RGB color tuple.
+    pub fn rgb_color(&mut self) -> (u8, u8, u8) {
+        providers::colors::generate_rgb_color(&mut self.rng)
     }
 
-    /// Generate a batch of random full addresses.
+    /// Generate a batch of random HSL color tuples.
     ///
-    /// # Arguments
-    ///
-    /// * `n` - Number of addresses to generate
-    /// * `unique` - If true, ensure all generated values are unique
-    ///
-    /// # Errors
-    ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn addresses(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    /// Hue is in degrees (0-359); saturation and lightness are percentages.
+    pub fn hsl_colors(&mut self, n: usize) -> Result<Vec<(u16, u8, u8)>, BatchSizeError> {
         validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::address::generate_address)
-        } else {
-            Ok(providers::address::generate_addresses(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
+        Ok(providers::colors::generate_hsl_colors(&mut self.rng, n))
     }
 
-    /// Generate a single random full address.
-    pub fn address(&mut self) -> String {
-        providers::address::generate_address(&mut self.rng, self.locale)
+    /// Generate a single random HSL color tuple.
+    pub fn hsl_color(&mut self) -> (u16, u8, u8) {
+        providers::colors::generate_hsl_color(&mut self.rng)
     }
 
-    // === Phone Generation ===
-
-    /// Generate a batch of random phone numbers.
-    ///
-    /// # Arguments
-    ///
-    /// * `n` - Number of phone numbers to generate
-    /// * `unique` - If true, ensure all generated values are unique
-    ///
-    /// # Errors
-    ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn phone_numbers(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    /// Generate a batch of random CMYK color tuples (percentages, 0-100).
+    pub fn cmyk_colors(&mut self, n: usize) -> Result<Vec<(u8, u8, u8, u8)>, BatchSizeError> {
         validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::phone::generate_phone_number)
-        } else {
-            Ok(providers::phone::generate_phone_numbers(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
+        Ok(providers::colors::generate_cmyk_colors(&mut self.rng, n))
     }
 
-    /// Generate a single random phone number.
-    pub fn phone_number(&mut self) -> String {
-        providers::phone::generate_phone_number(&mut self.rng, self.locale)
+    /// Generate a single random CMYK color tuple.
+    pub fn cmyk_color(&mut self) -> (u8, u8, u8, u8) {
+        providers::colors::generate_cmyk_color(&mut self.rng)
     }
 
-    // === Company Generation ===
-
-    /// Generate a batch of random company names.
-    ///
-    /// # Arguments
+    /// Generate a batch of CSS color strings.
     ///
-    /// * `n` - Number of companies to generate
-    /// * `unique` - If true, ensure all generated values are unique
+    /// `format` pins the syntax (`"hex"`, `"rgb"`, `"rgba"`, `"hsl"` or
+    /// `"named"`); `None` mixes all of them.
     ///
     /// # Errors
     ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn companies(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// format is unknown.
+    pub fn css_colors(
+        &mut self,
+        n: usize,
+        format: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::company::generate_company)
-        } else {
-            Ok(providers::company::generate_companies(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
-    }
-
-    /// Generate a single random company name.
-    pub fn company(&mut self) -> String {
-        providers::company::generate_company(&mut self.rng, self.locale)
+        let format = format.map(str::parse).transpose()?;
+        Ok(providers::colors::generate_css_colors(
+            &mut self.rng,
+            n,
+            format,
+        ))
     }
 
-    /// Generate a batch of random job titles.
-    ///
-    /// # Arguments
-    ///
-    /// * `n` - Number of job titles to generate
-    /// * `unique` - If true, ensure all generated values are unique
+    /// Generate a single CSS color string.
     ///
     /// # Errors
     ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn jobs(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
-        validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::company::generate_job)
-        } else {
-            Ok(providers::company::generate_jobs(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
-    }
-
-    /// Generate a single random job title.
-    pub fn job(&mut self) -> String {
-        providers::company::generate_job(&mut self.rng, self.locale)
+    /// Returns `CssColorFormatError` for an unknown format.
+    pub fn css_color(
+        &mut self,
+        format: Option<&str>,
+    ) -> Result<String, providers::colors::CssColorFormatError> {
+        let format = format.map(str::parse).transpose()?;
+        Ok(providers::colors::generate_css_color(&mut self.rng, format))
     }
 
-    /// Generate a batch of random catch phrases.
-    ///
-    /// # Arguments
+    /// Generate a batch of color palettes, each a list of `size` hex colors.
     ///
-    /// * `n` - Number of catch phrases to generate
-    /// * `unique` - If true, ensure all generated values are unique
+    /// `scheme` is `"analogous"`, `"complementary"` or `"random"`.
     ///
     /// # Errors
     ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn catch_phrases(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// scheme is unknown.
+    pub fn color_palettes(
+        &mut self,
+        n: usize,
+        size: usize,
+        scheme: &str,
+    ) -> Result<Vec<Vec<String>>, ForgeryError> {
         validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::company::generate_catch_phrase)
-        } else {
-            Ok(providers::company::generate_catch_phrases(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
+        let scheme = scheme.parse()?;
+        Ok(providers::colors::generate_color_palettes(
+            &mut self.rng,
+            n,
+            size,
+            scheme,
+        ))
     }
 
-    /// Generate a single random catch phrase.
-    pub fn catch_phrase(&mut self) -> String {
-        providers::company::generate_catch_phrase(&mut self.rng, self.locale)
+    /// Generate a single palette of `size` hex colors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaletteSchemeError` for an unknown scheme.
+    pub fn color_palette(
+        &mut self,
+        size: usize,
+        scheme: &str,
+    ) -> Result<Vec<String>, providers::colors::PaletteSchemeError> {
+        Ok(providers::colors::generate_color_palette(
+            &mut self.rng,
+            size,
+            scheme.parse()?,
+        ))
     }
 
-    // === Network Generation ===
+    // === DateTime Generation ===
 
-    /// Generate a batch of random URLs.
-    pub fn urls(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+    /// Generate a batch of random dates within a range.
+    ///
+    /// `format` selects the output style: `"iso"` (default, `YYYY-MM-DD`),
+    /// `"locale"` (e.g., `31.12.2024` for de_DE) or `"locale_long"` (with month
+    /// and weekday names).
+    pub fn dates(
+        &mut self,
+        n: usize,
+        start: &str,
+        end: &str,
+        format: Option<&str>,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
         validate_batch_size(n)?;
-        Ok(providers::network::generate_urls(&mut self.rng, n))
+        let style = parse_date_style(format)?;
+        Ok(providers::datetime::generate_dates_with_style(
+            &mut self.rng,
+            self.locale,
+            n,
+            start,
+            end,
+            style,
+        )?)
     }
 
-    /// Generate a single random URL.
-    pub fn url(&mut self) -> String {
-        providers::network::generate_url(&mut self.rng)
+    /// Generate a single random date within a range.
+    ///
+    /// See [`Faker::dates`] for the accepted `format` values.
+    pub fn date(
+        &mut self,
+        start: &str,
+        end: &str,
+        format: Option<&str>,
+    ) -> Result<String, ForgeryError> {
+        let style = parse_date_style(format)?;
+        Ok(providers::datetime::generate_date_with_style(
+            &mut self.rng,
+            self.locale,
+            start,
+            end,
+            style,
+        )?)
     }
 
-    /// Generate a batch of random domain names.
-    pub fn domain_names(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+    /// Generate a batch of random business dates (Monday to Friday) within a
+    /// range, skipping any dates in `holidays`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, a date
+    /// cannot be parsed, start > end, or the range holds no business days.
+    pub fn business_dates(
+        &mut self,
+        n: usize,
+        start: &str,
+        end: &str,
+        holidays: &[String],
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::network::generate_domain_names(&mut self.rng, n))
+        Ok(providers::datetime::generate_business_dates(
+            &mut self.rng,
+            n,
+            start,
+            end,
+            holidays,
+        )?)
     }
 
-    /// Generate a single random domain name.
-    pub fn domain_name(&mut self) -> String {
-        providers::network::generate_domain_name(&mut self.rng)
+    /// Generate a single random business date within a range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DateRangeError` if a date cannot be parsed, start > end, or
+    /// the range holds no business days.
+    pub fn business_date(
+        &mut self,
+        start: &str,
+        end: &str,
+        holidays: &[String],
+    ) -> Result<String, providers::datetime::DateRangeError> {
+        providers::datetime::generate_business_date(&mut self.rng, start, end, holidays)
     }
 
-    /// Generate a batch of random IPv4 addresses.
-    pub fn ipv4s(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+    /// Generate a batch of random dates of birth.
+    pub fn dates_of_birth(
+        &mut self,
+        n: usize,
+        min_age: u32,
+        max_age: u32,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
         validate_batch_size(n)?;
-        Ok(providers::network::generate_ipv4s(&mut self.rng, n))
+        Ok(providers::datetime::generate_dates_of_birth(
+            &mut self.rng,
+            n,
+            min_age,
+            max_age,
+        )?)
     }
 
-    /// Generate a single random IPv4 address.
-    pub fn ipv4(&mut self) -> String {
-        providers::network::generate_ipv4(&mut self.rng)
+    /// Generate a single random date of birth.
+    pub fn date_of_birth(
+        &mut self,
+        min_age: u32,
+        max_age: u32,
+    ) -> Result<String, providers::datetime::DateRangeError> {
+        providers::datetime::generate_date_of_birth(&mut self.rng, min_age, max_age)
     }
 
-    /// Generate a batch of random IPv6 addresses.
-    pub fn ipv6s(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+    /// Generate a batch of `(date_of_birth, age)` pairs.
+    ///
+    /// Each age is computed from its birth date on the 2024-01-01 reference
+    /// date, so the two columns always agree.
+    pub fn dates_of_birth_with_age(
+        &mut self,
+        n: usize,
+        min_age: u32,
+        max_age: u32,
+    ) -> Result<Vec<(String, u32)>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::network::generate_ipv6s(&mut self.rng, n))
-    }
-
-    /// Generate a single random IPv6 address.
-    pub fn ipv6(&mut self) -> String {
-        providers::network::generate_ipv6(&mut self.rng)
-    }
-
-    /// Generate a batch of random MAC addresses.
-    pub fn mac_addresses(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
-        validate_batch_size(n)?;
-        Ok(providers::network::generate_mac_addresses(&mut self.rng, n))
+        Ok(providers::datetime::generate_dates_of_birth_with_age(
+            &mut self.rng,
+            n,
+            min_age,
+            max_age,
+        )?)
     }
 
-    /// Generate a single random MAC address.
-    pub fn mac_address(&mut self) -> String {
-        providers::network::generate_mac_address(&mut self.rng)
+    /// Generate a single `(date_of_birth, age)` pair.
+    pub fn date_of_birth_with_age(
+        &mut self,
+        min_age: u32,
+        max_age: u32,
+    ) -> Result<(String, u32), providers::datetime::DateRangeError> {
+        providers::datetime::generate_date_of_birth_with_age(&mut self.rng, min_age, max_age)
     }
 
-    // === Email Variants ===
-
-    /// Generate a batch of random safe email addresses (example.com/org/net).
-    ///
-    /// # Arguments
-    ///
-    /// * `n` - Number of emails to generate
-    /// * `unique` - If true, ensure all generated values are unique
-    ///
-    /// # Errors
+    /// Generate a batch of random datetimes within a range.
     ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn safe_emails(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+    /// `format` selects the output style: `"iso"` (default,
+    /// `YYYY-MM-DDTHH:MM:SS`), `"locale"` or `"locale_long"`. Locale styles
+    /// append the locale's time format (12-hour for en_US).
+    pub fn datetimes(
+        &mut self,
+        n: usize,
+        start: &str,
+        end: &str,
+        format: Option<&str>,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
         validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::internet::generate_safe_email)
-        } else {
-            Ok(providers::internet::generate_safe_emails(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
-    }
-
-    /// Generate a single random safe email address.
-    pub fn safe_email(&mut self) -> String {
-        providers::internet::generate_safe_email(&mut self.rng, self.locale)
+        let style = parse_date_style(format)?;
+        Ok(providers::datetime::generate_datetimes_with_style(
+            &mut self.rng,
+            self.locale,
+            n,
+            start,
+            end,
+            style,
+        )?)
     }
 
-    /// Generate a batch of random free email addresses (gmail.com, etc.).
-    ///
-    /// # Arguments
-    ///
-    /// * `n` - Number of emails to generate
-    /// * `unique` - If true, ensure all generated values are unique
-    ///
-    /// # Errors
+    /// Generate a single random datetime within a range.
     ///
-    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
-    /// if unique generation cannot produce enough unique values.
-    pub fn free_emails(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
-        validate_batch_size(n)?;
-        if unique {
-            self.generate_unique(n, providers::internet::generate_free_email)
-        } else {
-            Ok(providers::internet::generate_free_emails(
-                &mut self.rng,
-                self.locale,
-                n,
-            ))
-        }
-    }
-
-    /// Generate a single random free email address.
-    pub fn free_email(&mut self) -> String {
-        providers::internet::generate_free_email(&mut self.rng, self.locale)
-    }
-
-    // === Finance Generation ===
-
-    /// Generate a batch of random credit card numbers with valid Luhn checksums.
-    pub fn credit_cards(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
-        validate_batch_size(n)?;
-        Ok(providers::finance::generate_credit_cards(&mut self.rng, n))
-    }
-
-    /// Generate a single random credit card number with valid Luhn checksum.
-    pub fn credit_card(&mut self) -> String {
-        providers::finance::generate_credit_card(&mut self.rng)
-    }
-
-    /// Generate a batch of random IBANs with valid checksums.
-    pub fn ibans(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
-        validate_batch_size(n)?;
-        Ok(providers::finance::generate_ibans(&mut self.rng, n))
-    }
-
-    /// Generate a single random IBAN with valid checksum.
-    pub fn iban(&mut self) -> String {
-        providers::finance::generate_iban(&mut self.rng)
-    }
-
-    /// Generate a batch of random BIC/SWIFT codes.
-    pub fn bics(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
-        validate_batch_size(n)?;
-        Ok(providers::finance::generate_bics(&mut self.rng, n))
-    }
-
-    /// Generate a single random BIC/SWIFT code.
-    pub fn bic(&mut self) -> String {
-        providers::finance::generate_bic(&mut self.rng)
-    }
-
-    /// Generate a batch of random bank account numbers.
-    pub fn bank_accounts(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
-        validate_batch_size(n)?;
-        Ok(providers::finance::generate_bank_accounts(&mut self.rng, n))
-    }
-
-    /// Generate a single random bank account number.
-    pub fn bank_account(&mut self) -> String {
-        providers::finance::generate_bank_account(&mut self.rng)
+    /// See [`Faker::datetimes`] for the accepted `format` values.
+    pub fn datetime(
+        &mut self,
+        start: &str,
+        end: &str,
+        format: Option<&str>,
+    ) -> Result<String, ForgeryError> {
+        let style = parse_date_style(format)?;
+        Ok(providers::datetime::generate_datetime_with_style(
+            &mut self.rng,
+            self.locale,
+            start,
+            end,
+            style,
+        )?)
     }
 
-    /// Generate a batch of random bank names.
+    /// Generate a batch of random times of day.
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of bank names to generate
+    /// * `n` - Number of times to generate
+    /// * `format` - strftime format, e.g. `"%H:%M:%S"` or `"%I:%M %p"`
     ///
     /// # Errors
     ///
-    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
-    pub fn bank_names(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// format has invalid or date specifiers.
+    pub fn times(&mut self, n: usize, format: &str) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::finance::generate_bank_names(
+        Ok(providers::datetime::generate_times(
             &mut self.rng,
-            self.locale,
             n,
-        ))
+            format,
+        )?)
     }
 
-    /// Generate a single random bank name.
-    pub fn bank_name(&mut self) -> String {
-        providers::finance::generate_bank_name(&mut self.rng, self.locale)
+    /// Generate a single random time of day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TimeFormatError` if the format has invalid or date specifiers.
+    pub fn time(&mut self, format: &str) -> Result<String, providers::datetime::TimeFormatError> {
+        providers::datetime::generate_time(&mut self.rng, format)
     }
 
-    /// Generate a batch of UK sort codes.
-    ///
-    /// # Arguments
+    /// Generate a batch of datetimes from the last `days` days.
     ///
-    /// * `n` - Number of sort codes to generate
+    /// Windows are anchored to the current UTC time, so a seed reproduces
+    /// offsets rather than absolute values across runs.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Sort codes in XX-XX-XX format (e.g., "12-34-56")
-    pub fn sort_codes(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
-        validate_batch_size(n)?;
-        Ok(providers::finance::generate_sort_codes(&mut self.rng, n))
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// window reaches past the representable calendar.
+    pub fn recent_datetimes(&mut self, n: usize, days: u32) -> Result<Vec<String>, ForgeryError> {
+        self.relative_datetimes(n, providers::datetime::RelativeRange::Recent(days))
     }
 
-    /// Generate a single UK sort code.
+    /// Generate a single datetime from the last `days` days.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A sort code in XX-XX-XX format (e.g., "12-34-56")
-    pub fn sort_code(&mut self) -> String {
-        providers::finance::generate_sort_code(&mut self.rng)
+    /// Returns `DateRangeError` if the window reaches past the representable
+    /// calendar.
+    pub fn recent(&mut self, days: u32) -> Result<String, providers::datetime::DateRangeError> {
+        self.relative_datetime(providers::datetime::RelativeRange::Recent(days))
     }
 
-    /// Generate a batch of UK bank account numbers.
+    /// Generate a batch of datetimes from the last `years` years.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `n` - Number of account numbers to generate
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// window reaches past the representable calendar.
+    pub fn past_datetimes(&mut self, n: usize, years: u32) -> Result<Vec<String>, ForgeryError> {
+        self.relative_datetimes(n, providers::datetime::RelativeRange::Past(years))
+    }
+
+    /// Generate a single datetime from the last `years` years.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Account numbers as exactly 8 digits (UK standard format)
-    pub fn uk_account_numbers(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
-        validate_batch_size(n)?;
-        Ok(providers::finance::generate_uk_account_numbers(
-            &mut self.rng,
-            n,
-        ))
+    /// Returns `DateRangeError` if the window reaches past the representable
+    /// calendar.
+    pub fn past(&mut self, years: u32) -> Result<String, providers::datetime::DateRangeError> {
+        self.relative_datetime(providers::datetime::RelativeRange::Past(years))
     }
 
-    /// Generate a single UK bank account number.
+    /// Generate a batch of datetimes from now through the next `years` years.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// An account number as exactly 8 digits (UK standard format)
-    pub fn uk_account_number(&mut self) -> String {
-        providers::finance::generate_uk_account_number(&mut self.rng)
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// window reaches past the representable calendar.
+    pub fn future_datetimes(&mut self, n: usize, years: u32) -> Result<Vec<String>, ForgeryError> {
+        self.relative_datetimes(n, providers::datetime::RelativeRange::Future(years))
     }
 
-    /// Generate a batch of financial transactions.
-    ///
-    /// # Arguments
+    /// Generate a single datetime from now through the next `years` years.
     ///
-    /// * `n` - Number of transactions to generate
-    /// * `starting_balance` - The opening balance before the first transaction
-    /// * `start_date` - Start date in YYYY-MM-DD format
-    /// * `end_date` - End date in YYYY-MM-DD format
+    /// # Errors
     ///
-    /// # Returns
+    /// Returns `DateRangeError` if the window reaches past the representable
+    /// calendar.
+    pub fn future(&mut self, years: u32) -> Result<String, providers::datetime::DateRangeError> {
+        self.relative_datetime(providers::datetime::RelativeRange::Future(years))
+    }
+
+    /// Generate a regularly spaced time series as an Arrow RecordBatch with
+    /// `timestamp` and `value` columns.
     ///
-    /// A list of transactions, each containing reference, date, amount,
-    /// transaction_type, description, and running balance. Transactions
-    /// are sorted chronologically.
+    /// Timestamps start at midnight on `start` and advance by `options.freq`;
+    /// all `n` points must fall on or before `end`.
     ///
     /// # Errors
     ///
-    /// Returns an error if batch size exceeds the limit or if the date range is invalid.
-    pub fn transactions(
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, the
+    /// options or dates are invalid, or the series runs past `end`.
+    pub fn time_series(
         &mut self,
         n: usize,
-        starting_balance: f64,
-        start_date: &str,
-        end_date: &str,
-    ) -> Result<Vec<providers::finance::Transaction>, error::ForgeryError> {
+        start: &str,
+        end: &str,
+        options: &providers::timeseries::TimeSeriesOptions,
+    ) -> Result<arrow_array::RecordBatch, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::finance::generate_transactions(
+        Ok(providers::timeseries::generate_time_series(
             &mut self.rng,
             n,
-            starting_balance,
-            start_date,
-            end_date,
+            start,
+            end,
+            options,
         )?)
     }
 
-    /// Generate a batch of transaction amounts.
-    ///
-    /// # Arguments
-    ///
-    /// * `n` - Number of amounts to generate
-    /// * `min` - Minimum amount (inclusive)
-    /// * `max` - Maximum amount (inclusive)
-    ///
-    /// # Returns
+    /// Generate IoT sensor readings (`device_id`, `timestamp`, `metric`,
+    /// `value`, `unit`) from `sensors` devices between the start of `start`
+    /// and the end of `end`, ordered by timestamp.
     ///
-    /// Transaction amounts rounded to 2 decimal places
+    /// `metrics` restricts the metrics reported; empty means all of them.
     ///
     /// # Errors
     ///
-    /// Returns an error if batch size exceeds maximum or if min > max.
-    pub fn transaction_amounts(
+    /// Returns `ForgeryError` if `n` or `sensors` exceeds the maximum batch
+    /// size, a metric is unknown, `sensors` is zero or the dates are invalid.
+    pub fn sensor_readings(
         &mut self,
         n: usize,
-        min: f64,
-        max: f64,
-    ) -> Result<Vec<f64>, error::ForgeryError> {
+        start: &str,
+        end: &str,
+        sensors: usize,
+        metrics: &[String],
+    ) -> Result<Vec<providers::timeseries::SensorReading>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::finance::generate_transaction_amounts(
+        validate_batch_size(sensors)?;
+        Ok(providers::timeseries::generate_sensor_readings(
             &mut self.rng,
             n,
-            min,
-            max,
+            start,
+            end,
+            sensors,
+            metrics,
         )?)
     }
 
-    /// Generate a single transaction amount.
+    /// Generate IoT sensor readings as an Arrow RecordBatch with `device_id`,
+    /// `timestamp`, `metric`, `value` and `unit` columns.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `min` - Minimum amount (inclusive)
-    /// * `max` - Maximum amount (inclusive)
+    /// Returns `ForgeryError` under the same conditions as `sensor_readings`.
+    pub fn sensor_readings_arrow(
+        &mut self,
+        n: usize,
+        start: &str,
+        end: &str,
+        sensors: usize,
+        metrics: &[String],
+    ) -> Result<arrow_array::RecordBatch, ForgeryError> {
+        let readings = self.sensor_readings(n, start, end, sensors, metrics)?;
+        Ok(providers::timeseries::sensor_readings_to_record_batch(
+            &readings,
+        ))
+    }
+
+    /// Generate a batch of ISO 8601 weeks (`2024-W37`) within a date range.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A transaction amount rounded to 2 decimal places
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, a date
+    /// cannot be parsed, or start > end.
+    pub fn iso_weeks(
+        &mut self,
+        n: usize,
+        start: &str,
+        end: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        self.periods(n, start, end, providers::datetime::ReportingPeriod::IsoWeek)
+    }
+
+    /// Generate a single ISO 8601 week within a date range.
     ///
     /// # Errors
     ///
-    /// Returns an error if min > max or if values are not finite.
-    pub fn transaction_amount(
+    /// Returns `DateRangeError` if a date cannot be parsed or start > end.
+    pub fn iso_week(
         &mut self,
-        min: f64,
-        max: f64,
-    ) -> Result<f64, providers::numbers::FloatRangeError> {
-        providers::finance::generate_transaction_amount(&mut self.rng, min, max)
+        start: &str,
+        end: &str,
+    ) -> Result<String, providers::datetime::DateRangeError> {
+        self.period(start, end, providers::datetime::ReportingPeriod::IsoWeek)
     }
 
-    // === Password Generation ===
+    /// Generate a batch of calendar quarters (`2024-Q3`) within a date range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, a date
+    /// cannot be parsed, or start > end.
+    pub fn quarters(
+        &mut self,
+        n: usize,
+        start: &str,
+        end: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        self.periods(n, start, end, providers::datetime::ReportingPeriod::Quarter)
+    }
 
-    /// Generate a batch of random passwords.
+    /// Generate a single calendar quarter within a date range.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `n` - Number of passwords to generate
-    /// * `length` - Length of each password
-    /// * `uppercase` - Include uppercase letters
-    /// * `lowercase` - Include lowercase letters
-    /// * `digits` - Include digits
-    /// * `symbols` - Include symbols
+    /// Returns `DateRangeError` if a date cannot be parsed or start > end.
+    pub fn quarter(
+        &mut self,
+        start: &str,
+        end: &str,
+    ) -> Result<String, providers::datetime::DateRangeError> {
+        self.period(start, end, providers::datetime::ReportingPeriod::Quarter)
+    }
+
+    /// Generate a batch of calendar months (`2024-09`) within a date range.
     ///
     /// # Errors
     ///
-    /// Returns an error if no character sets are enabled or batch size exceeds maximum.
-    pub fn passwords(
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, a date
+    /// cannot be parsed, or start > end.
+    pub fn months(
         &mut self,
         n: usize,
-        length: usize,
-        uppercase: bool,
-        lowercase: bool,
-        digits: bool,
-        symbols: bool,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        start: &str,
+        end: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        self.periods(n, start, end, providers::datetime::ReportingPeriod::Month)
+    }
+
+    /// Generate a single calendar month within a date range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DateRangeError` if a date cannot be parsed or start > end.
+    pub fn month(
+        &mut self,
+        start: &str,
+        end: &str,
+    ) -> Result<String, providers::datetime::DateRangeError> {
+        self.period(start, end, providers::datetime::ReportingPeriod::Month)
+    }
+
+    /// Generate a batch of durations in whole seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// min > max.
+    pub fn durations(&mut self, n: usize, min: u64, max: u64) -> Result<Vec<u64>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::password::generate_passwords(
+        Ok(providers::duration::generate_durations(
             &mut self.rng,
             n,
-            length,
-            uppercase,
-            lowercase,
-            digits,
-            symbols,
+            min,
+            max,
         )?)
     }
 
-    /// Generate a single random password.
-    ///
-    /// # Arguments
-    ///
-    /// * `length` - Length of the password
-    /// * `uppercase` - Include uppercase letters
-    /// * `lowercase` - Include lowercase letters
-    /// * `digits` - Include digits
-    /// * `symbols` - Include symbols
+    /// Generate a single duration in whole seconds.
     ///
     /// # Errors
     ///
-    /// Returns an error if no character sets are enabled.
-    pub fn password(
+    /// Returns `DurationRangeError` if min > max.
+    pub fn duration(
         &mut self,
-        length: usize,
-        uppercase: bool,
-        lowercase: bool,
-        digits: bool,
-        symbols: bool,
-    ) -> Result<String, providers::password::PasswordError> {
-        providers::password::generate_password(
-            &mut self.rng,
-            length,
-            uppercase,
-            lowercase,
-            digits,
-            symbols,
-        )
+        min: u64,
+        max: u64,
+    ) -> Result<u64, providers::duration::DurationRangeError> {
+        providers::duration::generate_duration(&mut self.rng, min, max)
     }
 
-    // === Records Generation ===
-
-    /// Generate records based on a schema.
+    /// Generate a batch of formatted durations.
     ///
-    /// Returns a vector of BTreeMaps, where each BTreeMap represents a record
-    /// with field names as keys and generated values.
+    /// `format` is "iso8601" (`PT2H15M`), "human" (`2h 15m`) or "seconds".
     ///
     /// # Errors
     ///
-    /// Returns an error if the batch size exceeds the maximum or the schema is invalid.
-    pub fn records(
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, the
+    /// format is unknown, or min > max.
+    pub fn formatted_durations(
         &mut self,
         n: usize,
-        schema: &BTreeMap<String, providers::records::FieldSpec>,
-    ) -> Result<Vec<BTreeMap<String, providers::records::Value>>, Box<dyn std::error::Error>> {
+        min: u64,
+        max: u64,
+        format: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::records::generate_records_with_custom(
+        let format = format.parse()?;
+        Ok(providers::duration::generate_formatted_durations(
             &mut self.rng,
-            self.locale,
             n,
-            schema,
-            &self.custom_providers,
+            min,
+            max,
+            format,
         )?)
     }
 
-    /// Generate records as tuples based on a schema.
-    ///
-    /// Returns a vector of vectors, where each inner vector contains values
-    /// in the same order as the provided field order.
+    /// Generate a single formatted duration.
     ///
     /// # Errors
     ///
-    /// Returns an error if the batch size exceeds the maximum or the schema is invalid.
-    pub fn records_tuples(
+    /// Returns `ForgeryError` if the format is unknown or min > max.
+    pub fn formatted_duration(
+        &mut self,
+        min: u64,
+        max: u64,
+        format: &str,
+    ) -> Result<String, ForgeryError> {
+        let format = format.parse()?;
+        Ok(providers::duration::generate_formatted_duration(
+            &mut self.rng,
+            min,
+            max,
+            format,
+        )?)
+    }
+
+    fn periods(
         &mut self,
         n: usize,
-        schema: &BTreeMap<String, providers::records::FieldSpec>,
-        field_order: &[String],
-    ) -> Result<Vec<Vec<providers::records::Value>>, Box<dyn std::error::Error>> {
+        start: &str,
+        end: &str,
+        period: providers::datetime::ReportingPeriod,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::records::generate_records_tuples_with_custom(
+        Ok(providers::datetime::generate_periods(
             &mut self.rng,
-            self.locale,
             n,
-            schema,
-            field_order,
-            &self.custom_providers,
+            start,
+            end,
+            period,
         )?)
     }
 
-    /// Generate records as an Arrow RecordBatch based on a schema.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the batch size exceeds the maximum or the schema is invalid.
-    pub fn records_arrow(
+    fn period(
+        &mut self,
+        start: &str,
+        end: &str,
+        period: providers::datetime::ReportingPeriod,
+    ) -> Result<String, providers::datetime::DateRangeError> {
+        providers::datetime::generate_period(&mut self.rng, start, end, period)
+    }
+
+    fn relative_datetimes(
         &mut self,
         n: usize,
-        schema: &BTreeMap<String, providers::records::FieldSpec>,
-    ) -> Result<arrow_array::RecordBatch, Box<dyn std::error::Error>> {
+        range: providers::datetime::RelativeRange,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        Ok(providers::records::generate_records_arrow_with_custom(
+        Ok(providers::datetime::generate_relative_datetimes(
             &mut self.rng,
-            self.locale,
             n,
-            schema,
-            &self.custom_providers,
+            range,
+            providers::datetime::utc_now(),
         )?)
     }
 
-    // === Custom Providers ===
+    fn relative_datetime(
+        &mut self,
+        range: providers::datetime::RelativeRange,
+    ) -> Result<String, providers::datetime::DateRangeError> {
+        providers::datetime::generate_relative_datetime(
+            &mut self.rng,
+            range,
+            providers::datetime::utc_now(),
+        )
+    }
 
-    /// Register a custom provider with uniform random selection.
-    ///
-    /// Each option has equal probability of being selected.
-    ///
-    /// # Arguments
+    // === Text Generation ===
+
+    /// Generate a batch of word lists.
     ///
-    /// * `name` - The provider name (must not conflict with built-in types)
-    /// * `options` - List of string options to choose from
+    /// Each list holds `count` raw lorem words from the locale, without
+    /// capitalization or punctuation.
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The name conflicts with a built-in type
-    /// - The options list is empty
-    pub fn add_provider(
-        &mut self,
-        name: &str,
-        options: Vec<String>,
-    ) -> Result<(), CustomProviderError> {
-        if is_reserved_name(name) {
-            return Err(CustomProviderError::NameCollision(name.to_string()));
-        }
-        let provider = CustomProvider::uniform(options)?;
-        self.custom_providers.insert(name.to_string(), provider);
-        Ok(())
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn words(&mut self, n: usize, count: usize) -> Result<Vec<Vec<String>>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::text::generate_words(
+            &mut self.rng,
+            self.locale,
+            n,
+            count,
+        ))
     }
 
-    /// Register a custom provider with weighted random selection.
-    ///
-    /// Options are selected based on their relative weights.
+    /// Generate a single raw lorem word.
+    pub fn word(&mut self) -> String {
+        providers::text::generate_word(&mut self.rng, self.locale)
+    }
+
+    /// Generate a batch of random sentences.
     ///
     /// # Arguments
     ///
-    /// * `name` - The provider name (must not conflict with built-in types)
-    /// * `pairs` - List of (value, weight) tuples
+    /// * `n` - Number of sentences to generate
+    /// * `word_count` - Inclusive range of words per sentence, drawn per sentence
+    /// * `comma_rate` - Probability of a comma after each word but the last
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The name conflicts with a built-in type
-    /// - The options list is empty
-    /// - All weights are zero
-    pub fn add_weighted_provider(
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// range or rate is invalid.
+    pub fn sentences(
         &mut self,
-        name: &str,
-        pairs: Vec<(String, u64)>,
-    ) -> Result<(), CustomProviderError> {
-        if is_reserved_name(name) {
-            return Err(CustomProviderError::NameCollision(name.to_string()));
-        }
-        let provider = CustomProvider::weighted(pairs)?;
-        self.custom_providers.insert(name.to_string(), provider);
-        Ok(())
+        n: usize,
+        word_count: (usize, usize),
+        comma_rate: f64,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let shape = providers::text::TextShape {
+            word_count,
+            comma_rate,
+            ..providers::text::TextShape::default()
+        };
+        Ok(providers::text::generate_shaped_sentences(
+            &mut self.rng,
+            self.locale,
+            n,
+            &shape,
+        )?)
     }
 
-    /// Remove a custom provider.
-    ///
-    /// # Arguments
-    ///
-    /// * `name` - The provider name to remove
+    /// Generate a single random sentence.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// `true` if the provider was removed, `false` if it didn't exist.
-    pub fn remove_provider(&mut self, name: &str) -> bool {
-        self.custom_providers.remove(name).is_some()
+    /// Returns `TextShapeError` if the range or rate is invalid.
+    pub fn sentence(
+        &mut self,
+        word_count: (usize, usize),
+        comma_rate: f64,
+    ) -> Result<String, providers::text::TextShapeError> {
+        let shape = providers::text::TextShape {
+            word_count,
+            comma_rate,
+            ..providers::text::TextShape::default()
+        };
+        providers::text::generate_shaped_sentence(&mut self.rng, self.locale, &shape)
     }
 
-    /// Check if a custom provider exists.
+    /// Generate a batch of random paragraphs.
     ///
     /// # Arguments
     ///
-    /// * `name` - The provider name to check
+    /// * `n` - Number of paragraphs to generate
+    /// * `sentence_count` - Inclusive range of sentences per paragraph
+    /// * `word_count` - Inclusive range of words per sentence
+    /// * `comma_rate` - Probability of a comma after each word but the last
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// `true` if the provider exists, `false` otherwise.
-    pub fn has_provider(&self, name: &str) -> bool {
-        self.custom_providers.contains_key(name)
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or a
+    /// range or the rate is invalid.
+    pub fn paragraphs(
+        &mut self,
+        n: usize,
+        sentence_count: (usize, usize),
+        word_count: (usize, usize),
+        comma_rate: f64,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let shape = providers::text::TextShape {
+            word_count,
+            sentence_count,
+            comma_rate,
+        };
+        Ok(providers::text::generate_shaped_paragraphs(
+            &mut self.rng,
+            self.locale,
+            n,
+            &shape,
+        )?)
     }
 
-    /// List all registered custom provider names.
+    /// Generate a single random paragraph.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A sorted vector of provider names (for deterministic output).
-    pub fn list_providers(&self) -> Vec<String> {
-        let mut names: Vec<String> = self.custom_providers.keys().cloned().collect();
-        names.sort();
-        names
+    /// Returns `TextShapeError` if a range or the rate is invalid.
+    pub fn paragraph(
+        &mut self,
+        sentence_count: (usize, usize),
+        word_count: (usize, usize),
+        comma_rate: f64,
+    ) -> Result<String, providers::text::TextShapeError> {
+        let shape = providers::text::TextShape {
+            word_count,
+            sentence_count,
+            comma_rate,
+        };
+        providers::text::generate_shaped_paragraph(&mut self.rng, self.locale, &shape)
     }
 
-    /// Get a set of all custom provider names.
+    /// Generate a batch of random text blocks.
+    pub fn texts(
+        &mut self,
+        n: usize,
+        min_chars: usize,
+        max_chars: usize,
+    ) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::text::generate_texts(
+            &mut self.rng,
+            self.locale,
+            n,
+            min_chars,
+            max_chars,
+        ))
+    }
+
+    /// Generate a single random text block.
+    pub fn text(&mut self, min_chars: usize, max_chars: usize) -> String {
+        providers::text::generate_text(&mut self.rng, self.locale, min_chars, max_chars)
+    }
+
+    /// Generate a batch of Markdown documents.
     ///
-    /// Used internally for schema validation.
-    pub fn custom_provider_names(&self) -> HashSet<String> {
-        self.custom_providers.keys().cloned().collect()
+    /// # Arguments
+    ///
+    /// * `n` - Number of documents to generate
+    /// * `paragraphs` - Number of paragraphs in each document
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn markdowns(
+        &mut self,
+        n: usize,
+        paragraphs: usize,
+    ) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::markup::generate_markdowns(
+            &mut self.rng,
+            self.locale,
+            n,
+            paragraphs,
+        ))
     }
 
-    /// Generate a single value from a custom provider.
+    /// Generate a single Markdown document with headings, lists, links and
+    /// code blocks.
+    pub fn markdown(&mut self, paragraphs: usize) -> String {
+        providers::markup::generate_markdown(&mut self.rng, self.locale, paragraphs)
+    }
+
+    /// Generate a batch of HTML fragments.
     ///
     /// # Arguments
     ///
-    /// * `name` - The custom provider name
+    /// * `n` - Number of fragments to generate
+    /// * `elements` - Number of top-level elements in each fragment
     ///
     /// # Errors
     ///
-    /// Returns an error if the provider doesn't exist.
-    pub fn generate(&mut self, name: &str) -> Result<String, CustomProviderError> {
-        let provider = self
-            .custom_providers
-            .get(name)
-            .ok_or_else(|| CustomProviderError::NotFound(name.to_string()))?;
-        Ok(provider.generate(&mut self.rng))
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn htmls(&mut self, n: usize, elements: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::markup::generate_htmls(
+            &mut self.rng,
+            self.locale,
+            n,
+            elements,
+        ))
     }
 
-    /// Generate a batch of values from a custom provider.
+    /// Generate a single HTML fragment with headings, lists, links and code
+    /// blocks.
+    pub fn html(&mut self, elements: usize) -> String {
+        providers::markup::generate_html(&mut self.rng, self.locale, elements)
+    }
+
+    // === Address Generation ===
+
+    /// Generate a batch of random street addresses.
     ///
     /// # Arguments
     ///
-    /// * `name` - The custom provider name
-    /// * `n` - Number of values to generate
+    /// * `n` - Number of addresses to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The provider doesn't exist
-    /// - `n` exceeds the maximum batch size
-    pub fn generate_batch(
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn street_addresses(
         &mut self,
-        name: &str,
         n: usize,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        unique: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
         validate_batch_size(n)?;
-        let provider = self
-            .custom_providers
-            .get(name)
-            .ok_or_else(|| CustomProviderError::NotFound(name.to_string()))?;
-        Ok(provider.generate_batch(&mut self.rng, n))
+        if unique {
+            let overrides = Arc::clone(&self.overrides);
+            self.generate_unique(n, |rng, locale| {
+                providers::address::generate_street_address_with_overrides(rng, locale, &overrides)
+            })
+        } else {
+            Ok(
+                providers::address::generate_street_addresses_with_overrides(
+                    &mut self.rng,
+                    self.locale,
+                    &self.overrides,
+                    n,
+                ),
+            )
+        }
     }
-}
 
-// Python API - these methods are exposed to Python via PyO3
-#[pymethods]
-impl Faker {
-    /// Create a new Faker instance with the specified locale.
+    /// Generate a single random street address.
+    pub fn street_address(&mut self) -> String {
+        providers::address::generate_street_address_with_overrides(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+        )
+    }
+
+    /// Generate a batch of random cities.
     ///
     /// # Arguments
     ///
-    /// * `locale` - The locale for generated data (default: "en_US")
+    /// * `n` - Number of cities to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
     /// # Errors
     ///
-    /// Returns `ValueError` if the locale is not supported.
-    #[new]
-    #[pyo3(signature = (locale = "en_US"))]
-    fn py_new(locale: &str) -> PyResult<Self> {
-        Self::new(locale).map_err(|e| PyValueError::new_err(e.to_string()))
-    }
-
-    /// Seed the random number generator for deterministic output.
-    #[pyo3(name = "seed")]
-    fn py_seed(&mut self, value: u64) {
-        self.seed(value);
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn cities(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            let overrides = Arc::clone(&self.overrides);
+            self.generate_unique(n, |rng, locale| {
+                providers::address::generate_city_with_overrides(rng, locale, &overrides)
+            })
+        } else {
+            Ok(providers::address::generate_cities_with_overrides(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+                n,
+            ))
+        }
     }
 
-    /// Generate a batch of random full names.
-    #[pyo3(name = "names", signature = (n, unique=false))]
-    fn py_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.names(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single random city.
+    pub fn city(&mut self) -> String {
+        providers::address::generate_city_with_overrides(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+        )
     }
 
-    /// Generate a batch of random first names.
-    #[pyo3(name = "first_names", signature = (n, unique=false))]
-    fn py_first_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.first_names(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of random states.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of states to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn states(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, providers::address::generate_state)
+        } else {
+            Ok(providers::address::generate_states(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a batch of random last names.
-    #[pyo3(name = "last_names", signature = (n, unique=false))]
-    fn py_last_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.last_names(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single random state.
+    pub fn state(&mut self) -> String {
+        providers::address::generate_state(&mut self.rng, self.locale)
     }
 
-    /// Generate a single random full name.
-    #[pyo3(name = "name")]
-    fn py_name(&mut self) -> String {
-        self.name()
+    /// Generate a batch of random state/region abbreviations.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn state_abbrs(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, providers::address::generate_state_abbr)
+        } else {
+            Ok(providers::address::generate_state_abbrs(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a single random first name.
-    #[pyo3(name = "first_name")]
-    fn py_first_name(&mut self) -> String {
-        self.first_name()
+    /// Generate a single random state/region abbreviation.
+    pub fn state_abbr(&mut self) -> String {
+        providers::address::generate_state_abbr(&mut self.rng, self.locale)
     }
 
-    /// Generate a single random last name.
-    #[pyo3(name = "last_name")]
-    fn py_last_name(&mut self) -> String {
-        self.last_name()
+    /// Generate a batch of street names without their type.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn street_names(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            let overrides = Arc::clone(&self.overrides);
+            self.generate_unique(n, |rng, locale| {
+                providers::address::generate_street_name_with_overrides(rng, locale, &overrides)
+            })
+        } else {
+            Ok(providers::address::generate_street_names_with_overrides(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+                n,
+            ))
+        }
     }
 
-    /// Generate a batch of random email addresses.
-    #[pyo3(name = "emails", signature = (n, unique=false))]
-    fn py_emails(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.emails(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single street name without its type (e.g., "Main").
+    pub fn street_name(&mut self) -> String {
+        providers::address::generate_street_name_with_overrides(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+        )
     }
 
-    /// Generate a single random email address.
-    #[pyo3(name = "email")]
-    fn py_email(&mut self) -> String {
-        self.email()
+    /// Generate a batch of street types (e.g., "Street", "Avenue").
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn street_suffixes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, providers::address::generate_street_suffix)
+        } else {
+            Ok(providers::address::generate_street_suffixes(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a batch of random integers within a range.
-    #[pyo3(name = "integers", signature = (n, min = 0, max = 100))]
-    fn py_integers(&mut self, n: usize, min: i64, max: i64) -> PyResult<Vec<i64>> {
-        self.integers(n, min, max)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single street type.
+    pub fn street_suffix(&mut self) -> String {
+        providers::address::generate_street_suffix(&mut self.rng, self.locale)
     }
 
-    /// Generate a single random integer within a range.
-    #[pyo3(name = "integer", signature = (min = 0, max = 100))]
-    fn py_integer(&mut self, min: i64, max: i64) -> PyResult<i64> {
-        self.integer(min, max)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of building numbers (1-9999).
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn building_numbers(
+        &mut self,
+        n: usize,
+        unique: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _| {
+                providers::address::generate_building_number(rng)
+            })
+        } else {
+            Ok(providers::address::generate_building_numbers(
+                &mut self.rng,
+                n,
+            ))
+        }
     }
 
-    /// Generate a batch of random UUIDs (version 4).
-    #[pyo3(name = "uuids")]
-    fn py_uuids(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.uuids(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single building number.
+    pub fn building_number(&mut self) -> String {
+        providers::address::generate_building_number(&mut self.rng)
     }
 
-    /// Generate a single random UUID (version 4).
-    #[pyo3(name = "uuid")]
-    fn py_uuid(&mut self) -> String {
-        self.uuid()
+    /// Generate a batch of random countries.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of countries to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    /// * `format` - `"name"` (default), `"alpha2"`, `"alpha3"` or `"numeric"`
+    ///   for ISO 3166-1 codes
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, if the
+    /// format is unknown, or if unique generation cannot produce enough
+    /// unique values.
+    pub fn countries(
+        &mut self,
+        n: usize,
+        unique: bool,
+        format: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let format = parse_country_format(format, providers::address::CountryFormat::Name)?;
+        if unique {
+            // countries generator doesn't take locale, need a wrapper
+            self.generate_unique(n, |rng, _locale| {
+                providers::address::generate_country_with_format(rng, format)
+            })
+        } else {
+            Ok(providers::address::generate_countries_with_format(
+                &mut self.rng,
+                n,
+                format,
+            ))
+        }
     }
 
-    // === Float Generation ===
-
-    /// Generate a batch of random floats within a range.
-    #[pyo3(name = "floats", signature = (n, min = 0.0, max = 1.0))]
-    fn py_floats(&mut self, n: usize, min: f64, max: f64) -> PyResult<Vec<f64>> {
-        self.floats(n, min, max)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single random country.
+    ///
+    /// See [`Faker::countries`] for the accepted `format` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CountryFormatError` if the format is unknown.
+    pub fn country(
+        &mut self,
+        format: Option<&str>,
+    ) -> Result<String, providers::address::CountryFormatError> {
+        let format = parse_country_format(format, providers::address::CountryFormat::Name)?;
+        Ok(providers::address::generate_country_with_format(
+            &mut self.rng,
+            format,
+        ))
     }
 
-    /// Generate a single random float within a range.
-    #[pyo3(name = "float", signature = (min = 0.0, max = 1.0))]
-    fn py_float(&mut self, min: f64, max: f64) -> PyResult<f64> {
-        self.float(min, max)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of ISO 3166-1 country codes.
+    ///
+    /// Same as [`Faker::countries`] with `format` defaulting to `"alpha2"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, if the
+    /// format is unknown, or if unique generation cannot produce enough
+    /// unique values.
+    pub fn country_codes(
+        &mut self,
+        n: usize,
+        unique: bool,
+        format: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        self.countries(n, unique, Some(format.unwrap_or("alpha2")))
     }
 
-    // === Hash Generation ===
-
-    /// Generate a batch of random MD5 hashes.
-    #[pyo3(name = "md5s")]
-    fn py_md5s(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.md5s(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single ISO 3166-1 country code (alpha-2 by default).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CountryFormatError` if the format is unknown.
+    pub fn country_code(
+        &mut self,
+        format: Option<&str>,
+    ) -> Result<String, providers::address::CountryFormatError> {
+        self.country(Some(format.unwrap_or("alpha2")))
     }
 
-    /// Generate a single random MD5 hash.
-    #[pyo3(name = "md5")]
-    fn py_md5(&mut self) -> String {
-        self.md5()
+    /// Generate a batch of random zip codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of zip codes to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn zip_codes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, providers::address::generate_zip_code)
+        } else {
+            Ok(providers::address::generate_zip_codes(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a batch of random SHA256 hashes.
-    #[pyo3(name = "sha256s")]
-    fn py_sha256s(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.sha256s(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single random zip code.
+    pub fn zip_code(&mut self) -> String {
+        providers::address::generate_zip_code(&mut self.rng, self.locale)
     }
 
-    /// Generate a single random SHA256 hash.
-    #[pyo3(name = "sha256")]
-    fn py_sha256(&mut self) -> String {
-        self.sha256()
+    /// Generate a batch of random full addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of addresses to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    /// * `options` - Rates of secondary units and PO Boxes;
+    ///   `AddressOptions::default()` gives plain street addresses
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, the
+    /// options are invalid, or unique generation cannot produce enough
+    /// unique values.
+    pub fn addresses(
+        &mut self,
+        n: usize,
+        unique: bool,
+        options: &providers::address::AddressOptions,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        options.validate()?;
+        if unique {
+            let overrides = Arc::clone(&self.overrides);
+            let options = *options;
+            self.generate_unique(n, |rng, locale| {
+                providers::address::address_with_options(rng, locale, &overrides, &options)
+            })
+        } else {
+            Ok(providers::address::generate_addresses_with_options(
+                &mut self.rng,
+                self.locale,
+                &self.overrides,
+                n,
+                options,
+            )?)
+        }
     }
 
-    // === Color Generation ===
+    /// Generate a single random full address with units and PO Boxes mixed in per `options`.
+    pub fn address(
+        &mut self,
+        options: &providers::address::AddressOptions,
+    ) -> Result<String, providers::address::AddressOptionsError> {
+        providers::address::generate_address_with_options(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            options,
+        )
+    }
 
-    /// Generate a batch of random color names.
-    #[pyo3(name = "colors", signature = (n, unique=false))]
-    fn py_colors(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.colors(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of US counties.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of counties to generate
+    /// * `state` - State name or abbreviation; a random state per value if `None`
+    /// * `with_state` - Append the state abbreviation (e.g., "Cook County, IL")
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// `state` is not a US state.
+    pub fn counties(
+        &mut self,
+        n: usize,
+        state: Option<&str>,
+        with_state: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::address::generate_counties(
+            &mut self.rng,
+            n,
+            state,
+            with_state,
+        )?)
     }
 
-    /// Generate a single random color name.
-    #[pyo3(name = "color")]
-    fn py_color(&mut self) -> String {
-        self.color()
+    /// Generate a single US county.
+    pub fn county(
+        &mut self,
+        state: Option<&str>,
+        with_state: bool,
+    ) -> Result<String, providers::address::UsStateError> {
+        providers::address::generate_county(&mut self.rng, state, with_state)
     }
 
-    /// Generate a batch of random hex colors.
-    #[pyo3(name = "hex_colors")]
-    fn py_hex_colors(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.hex_colors(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of addresses split into components.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of addresses to generate
+    /// * `unit_rate` - Probability that the `unit` component is filled in
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// `unit_rate` is outside 0.0-1.0.
+    pub fn address_parts_batch(
+        &mut self,
+        n: usize,
+        unit_rate: f64,
+    ) -> Result<Vec<providers::address::AddressParts>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::address::generate_address_parts_batch(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+            unit_rate,
+        )?)
     }
 
-    /// Generate a single random hex color.
-    #[pyo3(name = "hex_color")]
-    fn py_hex_color(&mut self) -> String {
-        self.hex_color()
+    /// Generate a single address split into components.
+    pub fn address_parts(
+        &mut self,
+        unit_rate: f64,
+    ) -> Result<providers::address::AddressParts, providers::address::AddressOptionsError> {
+        providers::address::generate_address_parts(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            unit_rate,
+        )
     }
 
-    /// Generate a batch of random RGB color tuples.
-    #[pyo3(name = "rgb_colors")]
-    fn py_rgb_colors(&mut self, n: usize) -> PyResult<Vec<(u8, u8, u8)>> {
-        self.rgb_colors(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of secondary address units in the locale's wording
+    /// (e.g., "Apt 4B", "Suite 210" for en_US).
+    pub fn secondary_units(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::address::generate_secondary_units(
+            &mut self.rng,
+            self.locale,
+            n,
+        ))
     }
 
-    /// Generate a single random RGB color tuple.
-    #[pyo3(name = "rgb_color")]
-    fn py_rgb_color(&mut self) -> (u8, u8, u8) {
-        self.rgb_color()
+    /// Generate a single secondary address unit.
+    pub fn secondary_unit(&mut self) -> String {
+        providers::address::generate_secondary_unit(&mut self.rng, self.locale)
     }
 
-    // === DateTime Generation ===
+    /// Generate a batch of PO Box lines in the locale's wording
+    /// (e.g., "PO Box 4821" for en_US, "Postfach 4821" for de_DE).
+    pub fn po_boxes(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::address::generate_po_boxes(
+            &mut self.rng,
+            self.locale,
+            n,
+        ))
+    }
 
-    /// Generate a batch of random dates within a range.
-    #[pyo3(name = "dates", signature = (n, start = "2000-01-01", end = "2030-12-31"))]
-    fn py_dates(&mut self, n: usize, start: &str, end: &str) -> PyResult<Vec<String>> {
-        self.dates(n, start, end)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single PO Box line.
+    pub fn po_box(&mut self) -> String {
+        providers::address::generate_po_box(&mut self.rng, self.locale)
     }
 
-    /// Generate a single random date within a range.
-    #[pyo3(name = "date", signature = (start = "2000-01-01", end = "2030-12-31"))]
-    fn py_date(&mut self, start: &str, end: &str) -> PyResult<String> {
-        self.date(start, end)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    // === Geographic Generation ===
+
+    /// Generate a batch of latitudes in decimal degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of latitudes to generate
+    /// * `precision` - Number of decimal places to round to
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn latitudes(&mut self, n: usize, precision: u32) -> Result<Vec<f64>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::geo::generate_latitudes(
+            &mut self.rng,
+            n,
+            precision,
+        ))
     }
 
-    /// Generate a batch of random dates of birth.
-    #[pyo3(name = "dates_of_birth", signature = (n, min_age = 18, max_age = 80))]
-    fn py_dates_of_birth(&mut self, n: usize, min_age: u32, max_age: u32) -> PyResult<Vec<String>> {
-        self.dates_of_birth(n, min_age, max_age)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single latitude in decimal degrees.
+    pub fn latitude(&mut self, precision: u32) -> f64 {
+        providers::geo::generate_latitude(&mut self.rng, precision)
     }
 
-    /// Generate a single random date of birth.
-    #[pyo3(name = "date_of_birth", signature = (min_age = 18, max_age = 80))]
-    fn py_date_of_birth(&mut self, min_age: u32, max_age: u32) -> PyResult<String> {
-        self.date_of_birth(min_age, max_age)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of longitudes in decimal degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of longitudes to generate
+    /// * `precision` - Number of decimal places to round to
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn longitudes(&mut self, n: usize, precision: u32) -> Result<Vec<f64>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::geo::generate_longitudes(
+            &mut self.rng,
+            n,
+            precision,
+        ))
     }
 
-    /// Generate a batch of random datetimes within a range.
-    #[pyo3(name = "datetimes", signature = (n, start = "2000-01-01", end = "2030-12-31"))]
-    fn py_datetimes(&mut self, n: usize, start: &str, end: &str) -> PyResult<Vec<String>> {
-        self.datetimes(n, start, end)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single longitude in decimal degrees.
+    pub fn longitude(&mut self, precision: u32) -> f64 {
+        providers::geo::generate_longitude(&mut self.rng, precision)
     }
 
-    /// Generate a single random datetime within a range.
-    #[pyo3(name = "datetime", signature = (start = "2000-01-01", end = "2030-12-31"))]
-    fn py_datetime(&mut self, start: &str, end: &str) -> PyResult<String> {
-        self.datetime(start, end)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of (latitude, longitude) pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of pairs to generate
+    /// * `precision` - Number of decimal places to round to
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn coordinates(
+        &mut self,
+        n: usize,
+        precision: u32,
+    ) -> Result<Vec<(f64, f64)>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::geo::generate_coordinates(
+            &mut self.rng,
+            n,
+            precision,
+        ))
     }
 
-    // === Text Generation ===
-
-    /// Generate a batch of random sentences.
-    #[pyo3(name = "sentences", signature = (n, word_count = 10))]
-    fn py_sentences(&mut self, n: usize, word_count: usize) -> PyResult<Vec<String>> {
-        self.sentences(n, word_count)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single (latitude, longitude) pair.
+    pub fn coordinate(&mut self, precision: u32) -> (f64, f64) {
+        providers::geo::generate_coordinate(&mut self.rng, precision)
     }
 
-    /// Generate a single random sentence.
-    #[pyo3(name = "sentence", signature = (word_count = 10))]
-    fn py_sentence(&mut self, word_count: usize) -> String {
-        self.sentence(word_count)
+    /// Generate a batch of (latitude, longitude) pairs inside a bounding box.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of pairs to generate
+    /// * `min_lat`, `max_lat` - Latitude bounds in `[-90, 90]`
+    /// * `min_lon`, `max_lon` - Longitude bounds in `[-180, 180]`
+    /// * `precision` - Number of decimal places to round to
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `BoundingBoxError` if the bounds are out of range or inverted.
+    pub fn coordinates_in_bbox(
+        &mut self,
+        n: usize,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+        precision: u32,
+    ) -> Result<Vec<(f64, f64)>, ForgeryError> {
+        validate_batch_size(n)?;
+        let bbox = providers::geo::BoundingBox::new(min_lat, max_lat, min_lon, max_lon)?;
+        Ok(providers::geo::generate_coordinates_in_bbox(
+            &mut self.rng,
+            n,
+            &bbox,
+            precision,
+        ))
     }
 
-    /// Generate a batch of random paragraphs.
-    #[pyo3(name = "paragraphs", signature = (n, sentence_count = 5))]
-    fn py_paragraphs(&mut self, n: usize, sentence_count: usize) -> PyResult<Vec<String>> {
-        self.paragraphs(n, sentence_count)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single (latitude, longitude) pair inside a bounding box.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoundingBoxError` if the bounds are out of range or inverted.
+    pub fn coordinate_in_bbox(
+        &mut self,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+        precision: u32,
+    ) -> Result<(f64, f64), providers::geo::BoundingBoxError> {
+        let bbox = providers::geo::BoundingBox::new(min_lat, max_lat, min_lon, max_lon)?;
+        Ok(providers::geo::generate_coordinate_in_bbox(
+            &mut self.rng,
+            &bbox,
+            precision,
+        ))
     }
 
-    /// Generate a single random paragraph.
-    #[pyo3(name = "paragraph", signature = (sentence_count = 5))]
-    fn py_paragraph(&mut self, sentence_count: usize) -> String {
-        self.paragraph(sentence_count)
+    /// Generate a batch of (latitude, longitude) pairs within a country.
+    ///
+    /// Points are drawn from a rough bounding box around the country.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of pairs to generate
+    /// * `country` - ISO 3166-1 alpha-2 country code (e.g. "DE")
+    /// * `precision` - Number of decimal places to round to
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `GeoCountryError` if the country is not supported.
+    pub fn coordinates_in_country(
+        &mut self,
+        n: usize,
+        country: &str,
+        precision: u32,
+    ) -> Result<Vec<(f64, f64)>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::geo::generate_coordinates_in_country(
+            &mut self.rng,
+            n,
+            country,
+            precision,
+        )?)
     }
 
-    /// Generate a batch of random text blocks.
-    #[pyo3(name = "texts", signature = (n, min_chars = 50, max_chars = 200))]
-    fn py_texts(&mut self, n: usize, min_chars: usize, max_chars: usize) -> PyResult<Vec<String>> {
-        self.texts(n, min_chars, max_chars)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single (latitude, longitude) pair within a country.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeoCountryError` if the country is not supported.
+    pub fn coordinate_in_country(
+        &mut self,
+        country: &str,
+        precision: u32,
+    ) -> Result<(f64, f64), providers::geo::GeoCountryError> {
+        providers::geo::generate_coordinate_in_country(&mut self.rng, country, precision)
     }
 
-    /// Generate a single random text block.
-    #[pyo3(name = "text", signature = (min_chars = 50, max_chars = 200))]
-    fn py_text(&mut self, min_chars: usize, max_chars: usize) -> String {
-        self.text(min_chars, max_chars)
+    /// Generate a GPS track: `points` consecutive fixes with timestamps,
+    /// speed and heading, forming a route rather than independent points.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - Number of fixes
+    /// * `start_coord` - (latitude, longitude) of the first fix; a random
+    ///   point inside a random country if `None`
+    /// * `max_speed_kmh` - Speed limit between fixes
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `points` exceeds the maximum batch size, or
+    /// `GpsTrackError` if the start coordinate or speed limit is invalid.
+    pub fn gps_track(
+        &mut self,
+        points: usize,
+        start_coord: Option<(f64, f64)>,
+        max_speed_kmh: f64,
+    ) -> Result<Vec<providers::geo::GpsPoint>, ForgeryError> {
+        validate_batch_size(points)?;
+        Ok(providers::geo::generate_gps_track(
+            &mut self.rng,
+            points,
+            start_coord,
+            max_speed_kmh,
+        )?)
     }
 
-    // === Address Generation ===
+    // === Phone Generation ===
 
-    /// Generate a batch of random street addresses.
-    #[pyo3(name = "street_addresses", signature = (n, unique=false))]
-    fn py_street_addresses(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.street_addresses(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of random phone numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of phone numbers to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    /// * `format` - "formatted" (locale patterns) or "e164"
+    /// * `kind` - "any", "mobile" or "landline"
+    /// * `safe` - If true, only use ranges reserved for fiction
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, the
+    /// format or kind is unknown, `safe` is set for a locale without a
+    /// fictional range, or unique generation cannot produce enough unique
+    /// values.
+    pub fn phone_numbers(
+        &mut self,
+        n: usize,
+        unique: bool,
+        format: &str,
+        kind: &str,
+        safe: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let options = providers::phone::PhoneOptions {
+            format: format.parse()?,
+            kind: kind.parse()?,
+            safe,
+        };
+        if unique {
+            providers::phone::validate_phone_options(self.locale, &options)?;
+            self.generate_unique(n, |rng, locale| {
+                providers::phone::phone_with_options(rng, locale, &options)
+            })
+        } else {
+            Ok(providers::phone::generate_phone_numbers_with_options(
+                &mut self.rng,
+                self.locale,
+                n,
+                &options,
+            )?)
+        }
     }
 
-    /// Generate a single random street address.
-    #[pyo3(name = "street_address")]
-    fn py_street_address(&mut self) -> String {
-        self.street_address()
+    /// Generate a single random phone number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` for an unknown `format` or `kind`, or if `safe`
+    /// is set for a locale without a fictional range.
+    pub fn phone_number(
+        &mut self,
+        format: &str,
+        kind: &str,
+        safe: bool,
+    ) -> Result<String, ForgeryError> {
+        let options = providers::phone::PhoneOptions {
+            format: format.parse()?,
+            kind: kind.parse()?,
+            safe,
+        };
+        Ok(providers::phone::generate_phone_number_with_options(
+            &mut self.rng,
+            self.locale,
+            &options,
+        )?)
     }
 
-    /// Generate a batch of random cities.
-    #[pyo3(name = "cities", signature = (n, unique=false))]
-    fn py_cities(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.cities(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
-    }
+    // === Profile Generation ===
 
-    /// Generate a single random city.
-    #[pyo3(name = "city")]
-    fn py_city(&mut self) -> String {
-        self.city()
+    /// Generate a batch of person profiles.
+    ///
+    /// Each profile's fields agree with each other: gender matches the first
+    /// name, age matches the date of birth, the email is built from the name
+    /// and, for en_US/en_CA, the phone area code matches the state.
+    pub fn profiles(
+        &mut self,
+        n: usize,
+        min_age: u32,
+        max_age: u32,
+    ) -> Result<Vec<providers::profile::Profile>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::profile::generate_profiles(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+            min_age,
+            max_age,
+        )?)
     }
 
-    /// Generate a batch of random states.
-    #[pyo3(name = "states", signature = (n, unique=false))]
-    fn py_states(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.states(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single person profile.
+    pub fn profile(
+        &mut self,
+        min_age: u32,
+        max_age: u32,
+    ) -> Result<providers::profile::Profile, providers::datetime::DateRangeError> {
+        providers::profile::generate_profile(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            min_age,
+            max_age,
+        )
     }
 
-    /// Generate a single random state.
-    #[pyo3(name = "state")]
-    fn py_state(&mut self) -> String {
-        self.state()
+    // === Demographic Generation ===
+
+    /// Generate a batch of ages in whole years.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// min > max.
+    pub fn ages(&mut self, n: usize, min: u32, max: u32) -> Result<Vec<u32>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::demographics::generate_ages(
+            &mut self.rng,
+            n,
+            min,
+            max,
+        )?)
     }
 
-    /// Generate a batch of random countries.
-    #[pyo3(name = "countries", signature = (n, unique=false))]
-    fn py_countries(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.countries(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single age in whole years.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RangeError` if min > max.
+    pub fn age(&mut self, min: u32, max: u32) -> Result<u32, providers::numbers::RangeError> {
+        providers::demographics::generate_age(&mut self.rng, min, max)
     }
 
-    /// Generate a single random country.
-    #[pyo3(name = "country")]
-    fn py_country(&mut self) -> String {
-        self.country()
+    /// Generate a batch of genders.
+    ///
+    /// `weights` maps categories to relative weights; categories left out are
+    /// never drawn. An empty slice uses the default weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// weights name an unknown category or are all zero.
+    pub fn genders(
+        &mut self,
+        n: usize,
+        weights: &[(String, u32)],
+    ) -> Result<Vec<String>, ForgeryError> {
+        self.demographics(n, providers::demographics::Demographic::Gender, weights)
     }
 
-    /// Generate a batch of random zip codes.
-    #[pyo3(name = "zip_codes", signature = (n, unique=false))]
-    fn py_zip_codes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.zip_codes(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single gender.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DemographicWeightsError` if the weights name an unknown
+    /// category or are all zero.
+    pub fn gender(
+        &mut self,
+        weights: &[(String, u32)],
+    ) -> Result<String, providers::demographics::DemographicWeightsError> {
+        self.demographic(providers::demographics::Demographic::Gender, weights)
     }
 
-    /// Generate a single random zip code.
-    #[pyo3(name = "zip_code")]
-    fn py_zip_code(&mut self) -> String {
-        self.zip_code()
+    /// Generate a batch of pronoun sets.
+    ///
+    /// `weights` maps categories to relative weights; categories left out are
+    /// never drawn. An empty slice uses the default weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// weights name an unknown category or are all zero.
+    pub fn pronouns(
+        &mut self,
+        n: usize,
+        weights: &[(String, u32)],
+    ) -> Result<Vec<String>, ForgeryError> {
+        self.demographics(n, providers::demographics::Demographic::Pronouns, weights)
     }
 
-    /// Generate a batch of random full addresses.
-    #[pyo3(name = "addresses", signature = (n, unique=false))]
-    fn py_addresses(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.addresses(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single pronoun set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DemographicWeightsError` if the weights name an unknown
+    /// category or are all zero.
+    pub fn pronoun(
+        &mut self,
+        weights: &[(String, u32)],
+    ) -> Result<String, providers::demographics::DemographicWeightsError> {
+        self.demographic(providers::demographics::Demographic::Pronouns, weights)
     }
 
-    /// Generate a single random full address.
-    #[pyo3(name = "address")]
-    fn py_address(&mut self) -> String {
-        self.address()
+    /// Generate a batch of marital statuses.
+    ///
+    /// `weights` maps categories to relative weights; categories left out are
+    /// never drawn. An empty slice uses the default weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// weights name an unknown category or are all zero.
+    pub fn marital_statuses(
+        &mut self,
+        n: usize,
+        weights: &[(String, u32)],
+    ) -> Result<Vec<String>, ForgeryError> {
+        self.demographics(
+            n,
+            providers::demographics::Demographic::MaritalStatus,
+            weights,
+        )
     }
 
-    // === Phone Generation ===
+    /// Generate a single marital status.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DemographicWeightsError` if the weights name an unknown
+    /// category or are all zero.
+    pub fn marital_status(
+        &mut self,
+        weights: &[(String, u32)],
+    ) -> Result<String, providers::demographics::DemographicWeightsError> {
+        self.demographic(providers::demographics::Demographic::MaritalStatus, weights)
+    }
 
-    /// Generate a batch of random phone numbers.
-    #[pyo3(name = "phone_numbers", signature = (n, unique=false))]
-    fn py_phone_numbers(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.phone_numbers(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    fn demographics(
+        &mut self,
+        n: usize,
+        demographic: providers::demographics::Demographic,
+        weights: &[(String, u32)],
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let weights = providers::demographics::DemographicWeights::new(demographic, weights)?;
+        Ok(providers::demographics::generate_demographics(
+            &mut self.rng,
+            n,
+            &weights,
+        ))
     }
 
-    /// Generate a single random phone number.
-    #[pyo3(name = "phone_number")]
-    fn py_phone_number(&mut self) -> String {
-        self.phone_number()
+    fn demographic(
+        &mut self,
+        demographic: providers::demographics::Demographic,
+        weights: &[(String, u32)],
+    ) -> Result<String, providers::demographics::DemographicWeightsError> {
+        let weights = providers::demographics::DemographicWeights::new(demographic, weights)?;
+        Ok(providers::demographics::generate_demographic(
+            &mut self.rng,
+            &weights,
+        ))
     }
 
-    // === Company Generation ===
+    // === National ID Generation ===
 
-    /// Generate a batch of random company names.
-    #[pyo3(name = "companies", signature = (n, unique=false))]
-    fn py_companies(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.companies(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
-    }
-
-    /// Generate a single random company name.
-    #[pyo3(name = "company")]
-    fn py_company(&mut self) -> String {
-        self.company()
-    }
-
-    /// Generate a batch of random job titles.
-    #[pyo3(name = "jobs", signature = (n, unique=false))]
-    fn py_jobs(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.jobs(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of national ID numbers for the current locale.
+    ///
+    /// The identifier depends on the locale: SSN (en_US), NINO (en_GB),
+    /// SIN (en_CA), Steuer-ID (de_DE), INSEE/NIR (fr_FR), DNI/NIE (es_ES),
+    /// codice fiscale (it_IT), BSN (nl_NL), PESEL (pl_PL), SNILS (ru_RU),
+    /// My Number (ja_JP) or resident registration number (ko_KR). Check
+    /// digits are valid; the numbers themselves are random.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of IDs to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn national_ids(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, providers::national_id::generate_national_id)
+        } else {
+            Ok(providers::national_id::generate_national_ids(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a single random job title.
-    #[pyo3(name = "job")]
-    fn py_job(&mut self) -> String {
-        self.job()
+    /// Generate a single national ID number for the current locale.
+    pub fn national_id(&mut self) -> String {
+        providers::national_id::generate_national_id(&mut self.rng, self.locale)
     }
 
-    /// Generate a batch of random catch phrases.
-    #[pyo3(name = "catch_phrases", signature = (n, unique=false))]
-    fn py_catch_phrases(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.catch_phrases(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of driver's license numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of license numbers to generate
+    /// * `region` - US state or Canadian province code (e.g. "CA" for
+    ///   California); defaults to a random region for en_US and en_CA, or
+    ///   the national format for other locales
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `LicenseRegionError` if the region is not supported.
+    pub fn drivers_licenses(
+        &mut self,
+        n: usize,
+        region: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::drivers_license::generate_drivers_licenses(
+            &mut self.rng,
+            self.locale,
+            n,
+            region,
+        )?)
     }
 
-    /// Generate a single random catch phrase.
-    #[pyo3(name = "catch_phrase")]
-    fn py_catch_phrase(&mut self) -> String {
-        self.catch_phrase()
+    /// Generate a single driver's license number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LicenseRegionError` if the region is not supported.
+    pub fn drivers_license(
+        &mut self,
+        region: Option<&str>,
+    ) -> Result<String, providers::drivers_license::LicenseRegionError> {
+        providers::drivers_license::generate_drivers_license(&mut self.rng, self.locale, region)
     }
 
-    // === Network Generation ===
+    // === Company Generation ===
 
-    /// Generate a batch of random URLs.
-    #[pyo3(name = "urls")]
-    fn py_urls(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.urls(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of random company names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of companies to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn companies(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, providers::company::generate_company)
+        } else {
+            Ok(providers::company::generate_companies(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a single random URL.
-    #[pyo3(name = "url")]
-    fn py_url(&mut self) -> String {
-        self.url()
+    /// Generate a single random company name.
+    pub fn company(&mut self) -> String {
+        providers::company::generate_company(&mut self.rng, self.locale)
     }
 
-    /// Generate a batch of random domain names.
-    #[pyo3(name = "domain_names")]
-    fn py_domain_names(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.domain_names(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of random job titles.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of job titles to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    /// * `level` - Seniority level (e.g. "senior"); `None` picks from the
+    ///   locale's job titles, a level composes an English title
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, if
+    /// the level is unknown, or if unique generation cannot produce enough
+    /// unique values.
+    pub fn jobs(
+        &mut self,
+        n: usize,
+        unique: bool,
+        level: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let level: Option<providers::company::JobLevel> = level.map(str::parse).transpose()?;
+        match (level, unique) {
+            (Some(level), true) => self.generate_unique(n, |rng, _locale| {
+                providers::company::generate_leveled_job(rng, level)
+            }),
+            (Some(level), false) => Ok(providers::company::generate_leveled_jobs(
+                &mut self.rng,
+                n,
+                level,
+            )),
+            (None, true) => self.generate_unique(n, providers::company::generate_job),
+            (None, false) => Ok(providers::company::generate_jobs(
+                &mut self.rng,
+                self.locale,
+                n,
+            )),
+        }
     }
 
-    /// Generate a single random domain name.
-    #[pyo3(name = "domain_name")]
-    fn py_domain_name(&mut self) -> String {
-        self.domain_name()
+    /// Generate a single random job title.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JobLevelError` for an unknown level.
+    pub fn job(
+        &mut self,
+        level: Option<&str>,
+    ) -> Result<String, providers::company::JobLevelError> {
+        Ok(match level {
+            Some(level) => providers::company::generate_leveled_job(&mut self.rng, level.parse()?),
+            None => providers::company::generate_job(&mut self.rng, self.locale),
+        })
     }
 
-    /// Generate a batch of random IPv4 addresses.
-    #[pyo3(name = "ipv4s")]
-    fn py_ipv4s(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.ipv4s(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of job levels, weighted like an organization chart.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn job_levels(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::company::generate_job_level(rng)
+            })
+        } else {
+            Ok(providers::company::generate_job_levels(&mut self.rng, n))
+        }
     }
 
-    /// Generate a single random IPv4 address.
-    #[pyo3(name = "ipv4")]
-    fn py_ipv4(&mut self) -> String {
-        self.ipv4()
+    /// Generate a single job level (e.g. "senior").
+    pub fn job_level(&mut self) -> String {
+        providers::company::generate_job_level(&mut self.rng)
     }
 
-    /// Generate a batch of random IPv6 addresses.
-    #[pyo3(name = "ipv6s")]
-    fn py_ipv6s(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.ipv6s(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of job areas.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn job_areas(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::company::generate_job_area(rng))
+        } else {
+            Ok(providers::company::generate_job_areas(&mut self.rng, n))
+        }
     }
 
-    /// Generate a single random IPv6 address.
-    #[pyo3(name = "ipv6")]
-    fn py_ipv6(&mut self) -> String {
-        self.ipv6()
+    /// Generate a single job area (e.g. "Data").
+    pub fn job_area(&mut self) -> String {
+        providers::company::generate_job_area(&mut self.rng)
     }
 
-    /// Generate a batch of random MAC addresses.
-    #[pyo3(name = "mac_addresses")]
-    fn py_mac_addresses(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.mac_addresses(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of job roles.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn job_roles(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::company::generate_job_role(rng))
+        } else {
+            Ok(providers::company::generate_job_roles(&mut self.rng, n))
+        }
     }
 
-    /// Generate a single random MAC address.
-    #[pyo3(name = "mac_address")]
-    fn py_mac_address(&mut self) -> String {
-        self.mac_address()
+    /// Generate a single job role (e.g. "Scientist").
+    pub fn job_role(&mut self) -> String {
+        providers::company::generate_job_role(&mut self.rng)
     }
 
-    // === Email Variants ===
-
-    /// Generate a batch of random safe email addresses (example.com/org/net).
-    #[pyo3(name = "safe_emails", signature = (n, unique=false))]
-    fn py_safe_emails(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.safe_emails(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of random catch phrases.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of catch phrases to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    /// * `style` - "basic", "buzzword", "startup" or "slogan"
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, the
+    /// style is unknown, or unique generation cannot produce enough unique values.
+    pub fn catch_phrases(
+        &mut self,
+        n: usize,
+        unique: bool,
+        style: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let style: providers::company::CatchPhraseStyle = style.parse()?;
+        if unique {
+            self.generate_unique(n, |rng, locale| {
+                providers::company::generate_catch_phrase_with_style(rng, locale, style)
+            })
+        } else {
+            Ok(providers::company::generate_catch_phrases_with_style(
+                &mut self.rng,
+                self.locale,
+                n,
+                style,
+            ))
+        }
     }
 
-    /// Generate a single random safe email address.
-    #[pyo3(name = "safe_email")]
-    fn py_safe_email(&mut self) -> String {
-        self.safe_email()
+    /// Generate a single random catch phrase in the given style.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CatchPhraseStyleError` if the style is unknown.
+    pub fn catch_phrase(
+        &mut self,
+        style: &str,
+    ) -> Result<String, providers::company::CatchPhraseStyleError> {
+        Ok(providers::company::generate_catch_phrase_with_style(
+            &mut self.rng,
+            self.locale,
+            style.parse()?,
+        ))
     }
 
-    /// Generate a batch of random free email addresses (gmail.com, etc.).
-    #[pyo3(name = "free_emails", signature = (n, unique=false))]
-    fn py_free_emails(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
-        self.free_emails(n, unique)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of department names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn departments(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::company::generate_department(rng)
+            })
+        } else {
+            Ok(providers::company::generate_departments(&mut self.rng, n))
+        }
     }
 
-    /// Generate a single random free email address.
-    #[pyo3(name = "free_email")]
-    fn py_free_email(&mut self) -> String {
-        self.free_email()
+    /// Generate a single department name.
+    pub fn department(&mut self) -> String {
+        providers::company::generate_department(&mut self.rng)
     }
 
-    // === Finance Generation ===
-
-    /// Generate a batch of random credit card numbers with valid Luhn checksums.
-    #[pyo3(name = "credit_cards")]
-    fn py_credit_cards(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.credit_cards(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of industry names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn industries(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::company::generate_industry(rng))
+        } else {
+            Ok(providers::company::generate_industries(&mut self.rng, n))
+        }
     }
 
-    /// Generate a single random credit card number with valid Luhn checksum.
-    #[pyo3(name = "credit_card")]
-    fn py_credit_card(&mut self) -> String {
-        self.credit_card()
+    /// Generate a single industry name.
+    pub fn industry(&mut self) -> String {
+        providers::company::generate_industry(&mut self.rng)
     }
 
-    /// Generate a batch of random IBANs with valid checksums.
-    #[pyo3(name = "ibans")]
-    fn py_ibans(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.ibans(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
-    }
-
-    /// Generate a single random IBAN with valid checksum.
-    #[pyo3(name = "iban")]
-    fn py_iban(&mut self) -> String {
-        self.iban()
+    /// Generate a batch of six-digit NAICS codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn naics_codes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::company::generate_naics_code(rng)
+            })
+        } else {
+            Ok(providers::company::generate_naics_codes(&mut self.rng, n))
+        }
     }
 
-    /// Generate a batch of random BIC/SWIFT codes.
-    #[pyo3(name = "bics")]
-    fn py_bics(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.bics(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single six-digit NAICS code.
+    pub fn naics_code(&mut self) -> String {
+        providers::company::generate_naics_code(&mut self.rng)
     }
 
-    /// Generate a single random BIC/SWIFT code.
-    #[pyo3(name = "bic")]
-    fn py_bic(&mut self) -> String {
-        self.bic()
+    /// Generate a batch of four-digit SIC codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn sic_codes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::company::generate_sic_code(rng))
+        } else {
+            Ok(providers::company::generate_sic_codes(&mut self.rng, n))
+        }
     }
 
-    /// Generate a batch of random bank account numbers.
-    #[pyo3(name = "bank_accounts")]
-    fn py_bank_accounts(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.bank_accounts(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single four-digit SIC code.
+    pub fn sic_code(&mut self) -> String {
+        providers::company::generate_sic_code(&mut self.rng)
     }
 
-    /// Generate a single random bank account number.
-    #[pyo3(name = "bank_account")]
-    fn py_bank_account(&mut self) -> String {
-        self.bank_account()
+    /// Generate a batch of company profiles.
+    ///
+    /// Each profile's domain is derived from its name, and its tax ID and
+    /// headquarters address follow the current locale.
+    pub fn company_profiles(
+        &mut self,
+        n: usize,
+    ) -> Result<Vec<providers::company::CompanyProfile>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::company::generate_company_profiles(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+        ))
     }
 
-    /// Generate a batch of random bank names.
-    #[pyo3(name = "bank_names")]
-    fn py_bank_names(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.bank_names(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single company profile.
+    pub fn company_profile(&mut self) -> providers::company::CompanyProfile {
+        providers::company::generate_company_profile(&mut self.rng, self.locale, &self.overrides)
     }
 
-    /// Generate a single random bank name.
-    #[pyo3(name = "bank_name")]
-    fn py_bank_name(&mut self) -> String {
-        self.bank_name()
+    /// Generate a batch of US Employer Identification Numbers.
+    ///
+    /// EINs use the `XX-XXXXXXX` format with an IRS-assigned prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of EINs to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn eins(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::tax_id::generate_ein(rng))
+        } else {
+            Ok(providers::tax_id::generate_eins(&mut self.rng, n))
+        }
     }
 
-    /// Generate a batch of UK sort codes.
-    #[pyo3(name = "sort_codes")]
-    fn py_sort_codes(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.sort_codes(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single US Employer Identification Number.
+    pub fn ein(&mut self) -> String {
+        providers::tax_id::generate_ein(&mut self.rng)
     }
 
-    /// Generate a single UK sort code (format: XX-XX-XX).
-    #[pyo3(name = "sort_code")]
-    fn py_sort_code(&mut self) -> String {
-        self.sort_code()
+    /// Generate a batch of company tax IDs for the current locale.
+    ///
+    /// The identifier depends on the locale: EIN (en_US), Business Number
+    /// (en_CA), INN (ru_RU), Corporate Number (ja_JP), Business Registration
+    /// Number (ko_KR), or the VAT number for European locales.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of tax IDs to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn company_tax_ids(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, providers::tax_id::generate_company_tax_id)
+        } else {
+            Ok(providers::tax_id::generate_company_tax_ids(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
     }
 
-    /// Generate a batch of UK bank account numbers (8 digits).
-    #[pyo3(name = "uk_account_numbers")]
-    fn py_uk_account_numbers(&mut self, n: usize) -> PyResult<Vec<String>> {
-        self.uk_account_numbers(n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single company tax ID for the current locale.
+    pub fn company_tax_id(&mut self) -> String {
+        providers::tax_id::generate_company_tax_id(&mut self.rng, self.locale)
     }
 
-    /// Generate a single UK bank account number (8 digits).
-    #[pyo3(name = "uk_account_number")]
-    fn py_uk_account_number(&mut self) -> String {
-        self.uk_account_number()
-    }
+    // === Network Generation ===
 
-    /// Generate a batch of financial transactions.
-    ///
-    /// Args:
-    ///     n: Number of transactions to generate
-    ///     starting_balance: Opening balance before first transaction
-    ///     start_date: Start date in YYYY-MM-DD format
-    ///     end_date: End date in YYYY-MM-DD format
+    /// Generate a batch of random URLs.
     ///
-    /// Returns:
-    ///     List of transaction dicts with keys: reference, date, amount,
-    ///     transaction_type, description, balance
-    #[pyo3(name = "transactions")]
-    fn py_transactions(
+    /// `options` controls the scheme, path depth, query parameters, fragment
+    /// and file extension; `UrlOptions::default()` gives `https://domain/path`.
+    pub fn urls(
         &mut self,
-        py: Python<'_>,
         n: usize,
-        starting_balance: f64,
-        start_date: &str,
-        end_date: &str,
-    ) -> PyResult<Vec<Py<PyAny>>> {
-        let txns = self
-            .transactions(n, starting_balance, start_date, end_date)
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
-
-        txns.into_iter()
-            .map(|t| {
-                let dict = PyDict::new(py);
-                dict.set_item("reference", &t.reference)?;
-                dict.set_item("date", &t.date)?;
-                dict.set_item("amount", t.amount)?;
-                dict.set_item("transaction_type", &t.transaction_type)?;
-                dict.set_item("description", &t.description)?;
-                dict.set_item("balance", t.balance)?;
-                dict.into_py_any(py)
-            })
-            .collect()
+        options: &providers::network::UrlOptions,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::network::generate_urls_with_options(
+            &mut self.rng,
+            n,
+            options,
+        )?)
     }
 
-    /// Generate a batch of transaction amounts.
-    #[pyo3(name = "transaction_amounts")]
-    fn py_transaction_amounts(&mut self, n: usize, min: f64, max: f64) -> PyResult<Vec<f64>> {
-        self.transaction_amounts(n, min, max)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a single random URL shaped by `options`.
+    pub fn url(
+        &mut self,
+        options: &providers::network::UrlOptions,
+    ) -> Result<String, providers::network::UrlOptionsError> {
+        providers::network::generate_url_with_options(&mut self.rng, options)
     }
 
-    /// Generate a single transaction amount.
-    #[pyo3(name = "transaction_amount")]
-    fn py_transaction_amount(&mut self, min: f64, max: f64) -> PyResult<f64> {
-        self.transaction_amount(min, max)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Generate a batch of random domain names.
+    pub fn domain_names(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::network::generate_domain_names(&mut self.rng, n))
     }
 
-    // === Password Generation ===
+    /// Generate a single random domain name.
+    pub fn domain_name(&mut self) -> String {
+        providers::network::generate_domain_name(&mut self.rng)
+    }
 
-    /// Generate a batch of random passwords.
+    /// Generate a batch of random IPv4 addresses.
     ///
-    /// Args:
-    ///     n: Number of passwords to generate
-    ///     length: Length of each password (default: 12)
-    ///     uppercase: Include uppercase letters (default: True)
-    ///     lowercase: Include lowercase letters (default: True)
-    ///     digits: Include digits (default: True)
-    ///     symbols: Include symbols (default: True)
+    /// With `private`, every address is in an RFC 1918 network
+    /// (10.0.0.0/8, 172.16.0.0/12 or 192.168.0.0/16).
     ///
-    /// Returns:
-    ///     List of random passwords
+    /// # Arguments
     ///
-    /// Raises:
-    ///     ValueError: If no character sets are enabled or batch size exceeds limit
-    #[pyo3(name = "passwords", signature = (n, length=12, uppercase=true, lowercase=true, digits=true, symbols=true))]
-    fn py_passwords(
+    /// * `n` - Number of addresses to generate
+    /// * `private` - Limit addresses to RFC 1918 networks
+    /// * `network` - A CIDR block such as "10.0.0.0/8" or a range name such
+    ///   as "loopback" or "documentation"; takes precedence over `private`
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `Ipv4RangeError` for an unparseable `network`.
+    pub fn ipv4s(
         &mut self,
         n: usize,
-        length: usize,
-        uppercase: bool,
-        lowercase: bool,
-        digits: bool,
-        symbols: bool,
-    ) -> PyResult<Vec<String>> {
-        self.passwords(n, length, uppercase, lowercase, digits, symbols)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+        private: bool,
+        network: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        match network {
+            Some(network) => {
+                let range = network.parse()?;
+                Ok(providers::network::generate_ipv4s_in(
+                    &mut self.rng,
+                    n,
+                    &range,
+                ))
+            }
+            None if private => Ok(providers::network::generate_private_ipv4s(&mut self.rng, n)),
+            None => Ok(providers::network::generate_ipv4s(&mut self.rng, n)),
+        }
     }
 
-    /// Generate a single random password.
-    ///
-    /// Args:
-    ///     length: Length of the password (default: 12)
-    ///     uppercase: Include uppercase letters (default: True)
-    ///     lowercase: Include lowercase letters (default: True)
-    ///     digits: Include digits (default: True)
-    ///     symbols: Include symbols (default: True)
+    /// Generate a single random IPv4 address, optionally RFC 1918 private or
+    /// in a given network.
     ///
-    /// Returns:
-    ///     A random password
+    /// # Errors
     ///
-    /// Raises:
-    ///     ValueError: If no character sets are enabled
-    #[pyo3(name = "password", signature = (length=12, uppercase=true, lowercase=true, digits=true, symbols=true))]
-    fn py_password(
+    /// Returns `Ipv4RangeError` for an unparseable `network`.
+    pub fn ipv4(
         &mut self,
-        length: usize,
-        uppercase: bool,
-        lowercase: bool,
-        digits: bool,
-        symbols: bool,
-    ) -> PyResult<String> {
-        self.password(length, uppercase, lowercase, digits, symbols)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+        private: bool,
+        network: Option<&str>,
+    ) -> Result<String, providers::network::Ipv4RangeError> {
+        match network {
+            Some(network) => {
+                let range = network.parse()?;
+                Ok(providers::network::generate_ipv4_in(&mut self.rng, &range))
+            }
+            None if private => Ok(providers::network::generate_private_ipv4(&mut self.rng)),
+            None => Ok(providers::network::generate_ipv4(&mut self.rng)),
+        }
     }
 
-    // === Custom Providers ===
+    /// Generate a batch of publicly routable IPv4 addresses.
+    ///
+    /// No address falls in a private, loopback, link-local, documentation,
+    /// multicast or other special-purpose network.
+    pub fn ipv4_publics(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::network::generate_public_ipv4s(&mut self.rng, n))
+    }
 
-    /// Register a custom provider with uniform random selection.
+    /// Generate a single publicly routable IPv4 address.
+    pub fn ipv4_public(&mut self) -> String {
+        providers::network::generate_public_ipv4(&mut self.rng)
+    }
+
+    /// Generate a batch of IPv4 CIDR blocks.
     ///
-    /// Args:
-    ///     name: The provider name (must not conflict with built-in types)
-    ///     options: List of string options to choose from
+    /// # Arguments
     ///
-    /// Raises:
-    ///     ValueError: If name conflicts with built-in type or options is empty
+    /// * `n` - Number of blocks to generate
+    /// * `min_prefix` - Shortest prefix length (inclusive)
+    /// * `max_prefix` - Longest prefix length (inclusive, at most 32)
     ///
-    /// Example:
-    ///     >>> fake = Faker()
-    ///     >>> fake.add_provider("department", ["Engineering", "Sales", "HR"])
-    ///     >>> fake.generate("department")
-    ///     'Sales'
-    #[pyo3(name = "add_provider")]
-    fn py_add_provider(&mut self, name: &str, options: Vec<String>) -> PyResult<()> {
-        self.add_provider(name, options)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `CidrPrefixError` if the prefix range is invalid.
+    pub fn cidrs(
+        &mut self,
+        n: usize,
+        min_prefix: u8,
+        max_prefix: u8,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::network::generate_cidrs(
+            &mut self.rng,
+            n,
+            min_prefix,
+            max_prefix,
+        )?)
     }
 
-    /// Register a custom provider with weighted random selection.
-    ///
-    /// Args:
-    ///     name: The provider name
-    ///     weighted_options: List of (value, weight) tuples. Higher weights = more likely.
+    /// Generate a single IPv4 CIDR block.
     ///
-    /// Raises:
-    ///     ValueError: If name conflicts, options empty, or weights invalid
+    /// # Errors
     ///
-    /// Example:
-    ///     >>> fake = Faker()
-    ///     >>> fake.add_weighted_provider("status", [("active", 80), ("inactive", 20)])
-    ///     >>> fake.generate("status")  # ~80% chance of "active"
-    ///     'active'
-    #[pyo3(name = "add_weighted_provider")]
-    fn py_add_weighted_provider(
+    /// Returns `CidrPrefixError` if the prefix range is invalid.
+    pub fn cidr(
         &mut self,
-        name: &str,
-        weighted_options: Vec<(String, u64)>,
-    ) -> PyResult<()> {
-        self.add_weighted_provider(name, weighted_options)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+        min_prefix: u8,
+        max_prefix: u8,
+    ) -> Result<String, providers::network::CidrPrefixError> {
+        providers::network::generate_cidr(&mut self.rng, min_prefix, max_prefix)
     }
 
-    /// Remove a custom provider.
+    /// Generate a batch of random IPv6 addresses.
     ///
-    /// Args:
-    ///     name: The provider name to remove
+    /// # Arguments
     ///
-    /// Returns:
-    ///     True if provider was removed, False if it didn't exist
-    #[pyo3(name = "remove_provider")]
-    fn py_remove_provider(&mut self, name: &str) -> bool {
-        self.remove_provider(name)
+    /// * `n` - Number of addresses to generate
+    /// * `compressed` - Use RFC 5952 form (`fe80::1`) instead of all eight 4-digit groups
+    /// * `uppercase` - Write hex digits in uppercase
+    /// * `kind` - "random", "global" (2000::/3), "link_local" (fe80::/64) or "ula" (fd00::/8)
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `Ipv6KindError` for an unknown kind.
+    pub fn ipv6s(
+        &mut self,
+        n: usize,
+        compressed: bool,
+        uppercase: bool,
+        kind: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let kind = kind.parse()?;
+        Ok(providers::network::generate_formatted_ipv6s(
+            &mut self.rng,
+            n,
+            kind,
+            compressed,
+            uppercase,
+        ))
     }
 
-    /// Check if a custom provider exists.
+    /// Generate a single random IPv6 address.
     ///
-    /// Args:
-    ///     name: The provider name to check
+    /// # Errors
     ///
-    /// Returns:
-    ///     True if provider exists, False otherwise
-    #[pyo3(name = "has_provider")]
-    fn py_has_provider(&self, name: &str) -> bool {
-        self.has_provider(name)
+    /// Returns `Ipv6KindError` for an unknown kind.
+    pub fn ipv6(
+        &mut self,
+        compressed: bool,
+        uppercase: bool,
+        kind: &str,
+    ) -> Result<String, providers::network::Ipv6KindError> {
+        let kind = kind.parse()?;
+        Ok(providers::network::generate_formatted_ipv6(
+            &mut self.rng,
+            kind,
+            compressed,
+            uppercase,
+        ))
     }
 
-    /// List all registered custom provider names.
+    /// Generate a batch of random MAC addresses.
     ///
-    /// Returns:
-    ///     List of registered custom provider names
-    #[pyo3(name = "list_providers")]
-    fn py_list_providers(&self) -> Vec<String> {
-        self.list_providers()
+    /// # Arguments
+    ///
+    /// * `n` - Number of addresses to generate
+    /// * `separator` - "colon", "dash", "dot" (Cisco `aabb.ccdd.eeff`) or "bare"
+    /// * `uppercase` - Write hex digits in uppercase
+    /// * `kind` - "random", "vendor" (real OUI prefix) or "local" (locally administered)
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `MacSeparatorError` / `MacKindError` for an unknown option.
+    pub fn mac_addresses(
+        &mut self,
+        n: usize,
+        separator: &str,
+        uppercase: bool,
+        kind: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let separator = separator.parse()?;
+        let kind = kind.parse()?;
+        Ok(providers::network::generate_formatted_mac_addresses(
+            &mut self.rng,
+            n,
+            kind,
+            separator,
+            uppercase,
+        ))
     }
 
-    /// Generate a single value from a custom provider.
-    ///
-    /// Args:
-    ///     name: The custom provider name
+    /// Generate a single random MAC address.
     ///
-    /// Returns:
-    ///     A randomly selected string from the provider's options
+    /// # Errors
     ///
-    /// Raises:
-    ///     ValueError: If provider doesn't exist
-    #[pyo3(name = "generate")]
-    fn py_generate(&mut self, name: &str) -> PyResult<String> {
-        self.generate(name)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// Returns `MacSeparatorError` / `MacKindError` for an unknown option.
+    pub fn mac_address(
+        &mut self,
+        separator: &str,
+        uppercase: bool,
+        kind: &str,
+    ) -> Result<String, ForgeryError> {
+        let separator = separator.parse()?;
+        let kind = kind.parse()?;
+        Ok(providers::network::generate_formatted_mac_address(
+            &mut self.rng,
+            kind,
+            separator,
+            uppercase,
+        ))
     }
 
-    /// Generate a batch of values from a custom provider.
+    /// Generate a batch of port numbers.
     ///
-    /// Args:
-    ///     name: The custom provider name
-    ///     n: Number of values to generate
+    /// # Arguments
     ///
-    /// Returns:
-    ///     A list of randomly selected strings
+    /// * `n` - Number of ports to generate
+    /// * `kind` - "well_known" (1-1023), "registered" (1024-49151),
+    ///   "ephemeral" (49152-65535) or "any" (1-65535)
     ///
-    /// Raises:
-    ///     ValueError: If provider doesn't exist or n exceeds batch limit
-    #[pyo3(name = "generate_batch")]
-    fn py_generate_batch(&mut self, name: &str, n: usize) -> PyResult<Vec<String>> {
-        self.generate_batch(name, n)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `PortKindError` for an unknown `kind`.
+    pub fn ports(&mut self, n: usize, kind: &str) -> Result<Vec<u16>, ForgeryError> {
+        validate_batch_size(n)?;
+        let kind = kind.parse()?;
+        Ok(providers::network::generate_ports(&mut self.rng, n, kind))
     }
 
-    // === Records Generation ===
-
-    /// Generate records based on a schema.
+    /// Generate a single port number.
     ///
-    /// The schema is a dictionary mapping field names to type specifications:
-    /// - Simple types: "name", "email", "uuid", "int", "float", etc.
-    /// - Custom providers: Any registered custom provider name
-    /// - Integer range: ("int", min, max)
-    /// - Float range: ("float", min, max)
-    /// - Text with limits: ("text", min_chars, max_chars)
-    /// - Date range: ("date", start, end)
-    /// - Choice: ("choice", ["option1", "option2", ...])
-    #[pyo3(name = "records")]
-    fn py_records(&mut self, n: usize, schema: &Bound<'_, PyDict>) -> PyResult<Vec<Py<PyAny>>> {
-        let py = schema.py();
-        let custom_names = self.custom_provider_names();
-        let rust_schema = parse_py_schema_with_custom(schema, &custom_names)?;
-        validate_batch_size(n).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    /// # Errors
+    ///
+    /// Returns `PortKindError` for an unknown `kind`.
+    pub fn port(&mut self, kind: &str) -> Result<u16, providers::network::PortKindError> {
+        let kind = kind.parse()?;
+        Ok(providers::network::generate_port(&mut self.rng, kind))
+    }
 
-        let records = providers::records::generate_records_with_custom(
-            &mut self.rng,
-            self.locale,
-            n,
-            &rust_schema,
-            &self.custom_providers,
-        )
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    /// Generate a batch of HTTP methods, weighted toward GET.
+    pub fn http_methods(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::network::generate_http_methods(&mut self.rng, n))
+    }
 
-        records
-            .into_iter()
-            .map(|record| {
-                let dict = PyDict::new(py);
-                for (key, value) in record {
-                    dict.set_item(key, value_to_pyobject(py, value)?)?;
-                }
-                dict.into_py_any(py)
-            })
-            .collect()
+    /// Generate a single HTTP method.
+    pub fn http_method(&mut self) -> String {
+        providers::network::generate_http_method(&mut self.rng)
     }
 
-    /// Generate records as tuples based on a schema.
+    /// Generate a batch of HTTP status codes.
     ///
-    /// Returns a list of tuples with values in alphabetical order of the schema keys.
-    /// This is faster than records() since it avoids creating dictionaries.
-    #[pyo3(name = "records_tuples")]
-    fn py_records_tuples(
+    /// # Arguments
+    ///
+    /// * `n` - Number of status codes to generate
+    /// * `class` - "1xx" through "5xx"; weighted toward 2xx (200 most of all)
+    ///   if `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `StatusClassError` for an unknown class.
+    pub fn http_status_codes(
         &mut self,
         n: usize,
-        schema: &Bound<'_, PyDict>,
-    ) -> PyResult<Vec<Py<PyAny>>> {
-        let py = schema.py();
-        let custom_names = self.custom_provider_names();
-        let rust_schema = parse_py_schema_with_custom(schema, &custom_names)?;
-        validate_batch_size(n).map_err(|e| PyValueError::new_err(e.to_string()))?;
-
-        // Get field order from BTreeMap (sorted alphabetically)
-        let field_order: Vec<String> = rust_schema.keys().cloned().collect();
-
-        let records = providers::records::generate_records_tuples_with_custom(
+        class: Option<&str>,
+    ) -> Result<Vec<u16>, ForgeryError> {
+        validate_batch_size(n)?;
+        let class = class.map(str::parse).transpose()?;
+        Ok(providers::network::generate_http_status_codes(
             &mut self.rng,
-            self.locale,
             n,
-            &rust_schema,
-            &field_order,
-            &self.custom_providers,
-        )
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
-
-        records
-            .into_iter()
-            .map(|record| {
-                let values: Vec<Py<PyAny>> = record
-                    .into_iter()
-                    .map(|v| value_to_pyobject(py, v))
-                    .collect::<PyResult<_>>()?;
-                PyTuple::new(py, values)?.into_py_any(py)
-            })
-            .collect()
+            class,
+        ))
     }
 
-    /// Generate records as a PyArrow RecordBatch.
+    /// Generate a single HTTP status code.
     ///
-    /// This is the high-performance path for generating structured data,
-    /// suitable for use with PyArrow, Polars, and other Arrow-compatible tools.
+    /// # Errors
     ///
-    /// The data is generated in columnar format and returned as a PyArrow RecordBatch,
-    /// which can be used directly with pandas, polars, or other data processing tools.
+    /// Returns `StatusClassError` for an unknown class.
+    pub fn http_status_code(
+        &mut self,
+        class: Option<&str>,
+    ) -> Result<u16, providers::network::StatusClassError> {
+        let class = class.map(str::parse).transpose()?;
+        Ok(providers::network::generate_http_status_code(
+            &mut self.rng,
+            class,
+        ))
+    }
+
+    // === Email Variants ===
+
+    /// Generate a batch of random safe email addresses (example.com/org/net).
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of records to generate
-    /// * `schema` - Schema dictionary mapping field names to type specifications
+    /// * `n` - Number of emails to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A PyArrow RecordBatch with the generated data.
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn safe_emails(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, providers::internet::generate_safe_email)
+        } else {
+            Ok(providers::internet::generate_safe_emails(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
+    }
+
+    /// Generate a single random safe email address.
+    pub fn safe_email(&mut self) -> String {
+        providers::internet::generate_safe_email(&mut self.rng, self.locale)
+    }
+
+    /// Generate a batch of random free email addresses (gmail.com, etc.).
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```python
-    /// import pyarrow as pa
-    /// from forgery import Faker
+    /// * `n` - Number of emails to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
-    /// fake = Faker()
-    /// batch = fake.records_arrow(1000, {
-    ///     "id": "uuid",
-    ///     "name": "name",
-    ///     "age": ("int", 18, 65),
-    ///     "salary": ("float", 30000.0, 150000.0),
-    /// })
-    /// # batch is a pyarrow.RecordBatch
-    /// df = batch.to_pandas()  # Convert to pandas DataFrame
-    /// ```
-    #[pyo3(name = "records_arrow")]
-    fn py_records_arrow(
-        &mut self,
-        py: Python<'_>,
-        n: usize,
-        schema: &Bound<'_, PyDict>,
-    ) -> PyResult<Py<PyAny>> {
-        let custom_names = self.custom_provider_names();
-        let rust_schema = parse_py_schema_with_custom(schema, &custom_names)?;
-        validate_batch_size(n).map_err(|e| PyValueError::new_err(e.to_string()))?;
-
-        let record_batch = providers::records::generate_records_arrow_with_custom(
-            &mut self.rng,
-            self.locale,
-            n,
-            &rust_schema,
-            &self.custom_providers,
-        )
-        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn free_emails(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, providers::internet::generate_free_email)
+        } else {
+            Ok(providers::internet::generate_free_emails(
+                &mut self.rng,
+                self.locale,
+                n,
+            ))
+        }
+    }
 
-        // Convert to PyArrow RecordBatch via pyo3-arrow
-        let py_batch = PyRecordBatch::new(record_batch);
-        py_batch.into_pyarrow(py).map(|bound| bound.unbind())
+    /// Generate a single random free email address.
+    pub fn free_email(&mut self) -> String {
+        providers::internet::generate_free_email(&mut self.rng, self.locale)
     }
 
-    // ============================================================================
-    // Async Methods
-    // ============================================================================
+    // === Finance Generation ===
 
-    /// Generate records asynchronously for non-blocking batch generation.
-    ///
-    /// This method generates records in chunks, yielding control between chunks
-    /// to allow other async tasks to run. Ideal for generating millions of records
-    /// without blocking the event loop.
+    /// Generate a batch of random credit card numbers with valid Luhn checksums.
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of records to generate
-    /// * `schema` - Schema dictionary mapping field names to type specifications
-    /// * `chunk_size` - Optional number of records per chunk (default: 10,000)
+    /// * `n` - Number of card numbers to generate
+    /// * `network` - Restrict to one network: "visa", "mastercard", "amex"
+    ///   or "discover" (default: any)
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A coroutine that resolves to a list of dictionaries.
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `CardNetworkError` if the network name is unknown.
+    pub fn credit_cards(
+        &mut self,
+        n: usize,
+        network: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let network = network.map(str::parse).transpose()?;
+        Ok(providers::finance::generate_credit_cards_for_network(
+            &mut self.rng,
+            n,
+            network,
+        ))
+    }
+
+    /// Generate a single random credit card number with valid Luhn checksum.
     ///
-    /// # Note on RNG State
+    /// # Errors
     ///
-    /// The async methods use a snapshot of the RNG state at call time. The main
-    /// Faker instance's RNG is not advanced. For different results on each call,
-    /// create separate Faker instances or re-seed between calls.
+    /// Returns `CardNetworkError` if the network name is unknown.
+    pub fn credit_card(
+        &mut self,
+        network: Option<&str>,
+    ) -> Result<String, providers::finance::CardNetworkError> {
+        let network = network.map(str::parse).transpose()?;
+        Ok(providers::finance::generate_credit_card_for_network(
+            &mut self.rng,
+            network,
+        ))
+    }
+
+    /// Generate a batch of random IBANs with valid checksums.
+    pub fn ibans(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_ibans(&mut self.rng, n))
+    }
+
+    /// Generate a single random IBAN with valid checksum.
+    pub fn iban(&mut self) -> String {
+        providers::finance::generate_iban(&mut self.rng)
+    }
+
+    /// Generate a batch of random BIC/SWIFT codes.
+    pub fn bics(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_bics(&mut self.rng, n))
+    }
+
+    /// Generate a single random BIC/SWIFT code.
+    pub fn bic(&mut self) -> String {
+        providers::finance::generate_bic(&mut self.rng)
+    }
+
+    /// Generate a batch of ABA routing numbers with valid checksums.
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```python
-    /// import asyncio
-    /// from forgery import Faker
+    /// * `n` - Number of routing numbers to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
-    /// async def main():
-    ///     fake = Faker()
-    ///     records = await fake.records_async(1_000_000, {
-    ///         "name": "name",
-    ///         "email": "email",
-    ///     })
-    ///     print(f"Generated {len(records)} records")
+    /// # Errors
     ///
-    /// asyncio.run(main())
-    /// ```
-    #[pyo3(name = "records_async", signature = (n, schema, chunk_size = None))]
-    fn py_records_async<'py>(
-        &self,
-        py: Python<'py>,
-        n: usize,
-        schema: &Bound<'py, PyDict>,
-        chunk_size: Option<usize>,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        use pyo3_async_runtimes::tokio::future_into_py;
-
-        let mut state = self.prepare_async_state(n, schema, chunk_size)?;
-
-        future_into_py(py, async move {
-            let records = providers::async_records::generate_records_async(
-                &mut state.rng,
-                state.locale,
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn routing_numbers(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::finance::generate_routing_number(rng)
+            })
+        } else {
+            Ok(providers::finance::generate_routing_numbers(
+                &mut self.rng,
                 n,
-                &state.schema,
-                state.chunk_size,
-                &state.custom_providers,
-            )
-            .await
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            ))
+        }
+    }
 
-            // Convert to Python objects
-            Python::attach(|py| {
-                let py_records: PyResult<Vec<Py<PyAny>>> = records
-                    .into_iter()
-                    .map(|record| {
-                        let dict = PyDict::new(py);
-                        for (key, value) in record {
-                            dict.set_item(key, value_to_pyobject(py, value)?)?;
-                        }
-                        dict.into_py_any(py)
-                    })
-                    .collect();
-                py_records
-            })
-        })
+    /// Generate a single ABA routing number with a valid checksum.
+    pub fn routing_number(&mut self) -> String {
+        providers::finance::generate_routing_number(&mut self.rng)
     }
 
-    /// Generate records as tuples asynchronously for non-blocking batch generation.
-    ///
-    /// Similar to records_async() but returns tuples instead of dictionaries,
-    /// which is faster for large datasets.
+    /// Generate a batch of ISO 4217 currency codes.
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of records to generate
-    /// * `schema` - Schema dictionary mapping field names to type specifications
-    /// * `chunk_size` - Optional number of records per chunk (default: 10,000)
+    /// * `n` - Number of codes to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A coroutine that resolves to a list of tuples (values in alphabetical field order).
-    #[pyo3(name = "records_tuples_async", signature = (n, schema, chunk_size = None))]
-    fn py_records_tuples_async<'py>(
-        &self,
-        py: Python<'py>,
-        n: usize,
-        schema: &Bound<'py, PyDict>,
-        chunk_size: Option<usize>,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        use pyo3_async_runtimes::tokio::future_into_py;
-
-        let mut state = self.prepare_async_state(n, schema, chunk_size)?;
-        let field_order: Vec<String> = state.schema.keys().cloned().collect();
-
-        future_into_py(py, async move {
-            let records = providers::async_records::generate_records_tuples_async(
-                &mut state.rng,
-                state.locale,
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn currency_codes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::finance::generate_currency_code(rng)
+            })
+        } else {
+            Ok(providers::finance::generate_currency_codes(
+                &mut self.rng,
                 n,
-                &state.schema,
-                &field_order,
-                state.chunk_size,
-                &state.custom_providers,
-            )
-            .await
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            ))
+        }
+    }
 
-            // Convert to Python objects
-            Python::attach(|py| {
-                let py_records: PyResult<Vec<Py<PyAny>>> = records
-                    .into_iter()
-                    .map(|record| {
-                        let values: Vec<Py<PyAny>> = record
-                            .into_iter()
-                            .map(|v| value_to_pyobject(py, v))
-                            .collect::<PyResult<_>>()?;
-                        PyTuple::new(py, values)?.into_py_any(py)
-                    })
-                    .collect();
-                py_records
-            })
-        })
+    /// Generate a single ISO 4217 currency code.
+    pub fn currency_code(&mut self) -> String {
+        providers::finance::generate_currency_code(&mut self.rng)
     }
 
-    /// Generate records as an Arrow RecordBatch asynchronously.
-    ///
-    /// This is the high-performance async path for generating structured data.
-    /// Generates data in chunks and concatenates them into a single RecordBatch.
+    /// Generate a batch of currency names.
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of records to generate
-    /// * `schema` - Schema dictionary mapping field names to type specifications
-    /// * `chunk_size` - Optional number of records per chunk (default: 10,000)
+    /// * `n` - Number of names to generate
+    /// * `unique` - If true, ensure all generated values are unique
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A coroutine that resolves to a PyArrow RecordBatch.
-    #[pyo3(name = "records_arrow_async", signature = (n, schema, chunk_size = None))]
-    fn py_records_arrow_async<'py>(
-        &self,
-        py: Python<'py>,
-        n: usize,
-        schema: &Bound<'py, PyDict>,
-        chunk_size: Option<usize>,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        use pyo3_async_runtimes::tokio::future_into_py;
-
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn currency_names(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::finance::generate_currency_name(rng)
+            })
+        } else {
+            Ok(providers::finance::generate_currency_names(
+                &mut self.rng,
+                n,
+            ))
+        }
+    }
+
+    /// Generate a single currency name.
+    pub fn currency_name(&mut self) -> String {
+        providers::finance::generate_currency_name(&mut self.rng)
+    }
+
+    /// Generate a batch of currency symbols.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn currency_symbols(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_currency_symbols(
+            &mut self.rng,
+            n,
+        ))
+    }
+
+    /// Generate a single currency symbol.
+    pub fn currency_symbol(&mut self) -> String {
+        providers::finance::generate_currency_symbol(&mut self.rng)
+    }
+
+    /// Generate a batch of stock ticker symbols.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of tickers to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn tickers(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::finance::generate_ticker(rng))
+        } else {
+            Ok(providers::finance::generate_tickers(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single stock ticker symbol.
+    pub fn ticker(&mut self) -> String {
+        providers::finance::generate_ticker(&mut self.rng)
+    }
+
+    /// Generate a batch of stock exchange names (NYSE, NASDAQ, LSE).
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn stock_exchanges(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_stock_exchanges(
+            &mut self.rng,
+            n,
+        ))
+    }
+
+    /// Generate a single stock exchange name.
+    pub fn stock_exchange(&mut self) -> String {
+        providers::finance::generate_stock_exchange(&mut self.rng)
+    }
+
+    /// Generate a batch of stock listings.
+    ///
+    /// Each listing pairs a company name for the current locale with an
+    /// exchange and a ticker derived from the company name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn stocks(
+        &mut self,
+        n: usize,
+    ) -> Result<Vec<providers::finance::StockListing>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_stock_listings(
+            &mut self.rng,
+            self.locale,
+            n,
+        ))
+    }
+
+    /// Generate a batch of random bank account numbers.
+    pub fn bank_accounts(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_bank_accounts(&mut self.rng, n))
+    }
+
+    /// Generate a single random bank account number.
+    pub fn bank_account(&mut self) -> String {
+        providers::finance::generate_bank_account(&mut self.rng)
+    }
+
+    /// Generate a batch of random bank names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of bank names to generate
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn bank_names(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_bank_names(
+            &mut self.rng,
+            self.locale,
+            n,
+        ))
+    }
+
+    /// Generate a single random bank name.
+    pub fn bank_name(&mut self) -> String {
+        providers::finance::generate_bank_name(&mut self.rng, self.locale)
+    }
+
+    /// Generate a batch of EU VAT numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of VAT numbers to generate
+    /// * `country` - Two-letter country code (e.g. "DE"); defaults to the
+    ///   locale's country, or a random supported country
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `VatCountryError` if the country is not supported.
+    pub fn vat_numbers(
+        &mut self,
+        n: usize,
+        country: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_vat_numbers(
+            &mut self.rng,
+            self.locale,
+            n,
+            country,
+        )?)
+    }
+
+    /// Generate a single EU VAT number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VatCountryError` if the country is not supported.
+    pub fn vat_number(
+        &mut self,
+        country: Option<&str>,
+    ) -> Result<String, providers::finance::VatCountryError> {
+        providers::finance::generate_vat_number(&mut self.rng, self.locale, country)
+    }
+
+    /// Generate a batch of UK sort codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of sort codes to generate
+    ///
+    /// # Returns
+    ///
+    /// Sort codes in XX-XX-XX format (e.g., "12-34-56")
+    pub fn sort_codes(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_sort_codes(&mut self.rng, n))
+    }
+
+    /// Generate a single UK sort code.
+    ///
+    /// # Returns
+    ///
+    /// A sort code in XX-XX-XX format (e.g., "12-34-56")
+    pub fn sort_code(&mut self) -> String {
+        providers::finance::generate_sort_code(&mut self.rng)
+    }
+
+    /// Generate a batch of UK bank account numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of account numbers to generate
+    ///
+    /// # Returns
+    ///
+    /// Account numbers as exactly 8 digits (UK standard format)
+    pub fn uk_account_numbers(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_uk_account_numbers(
+            &mut self.rng,
+            n,
+        ))
+    }
+
+    /// Generate a single UK bank account number.
+    ///
+    /// # Returns
+    ///
+    /// An account number as exactly 8 digits (UK standard format)
+    pub fn uk_account_number(&mut self) -> String {
+        providers::finance::generate_uk_account_number(&mut self.rng)
+    }
+
+    /// Generate a batch of financial transactions.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of transactions to generate
+    /// * `starting_balance` - The opening balance before the first transaction
+    /// * `start_date` - Start date in YYYY-MM-DD format
+    /// * `end_date` - End date in YYYY-MM-DD format
+    ///
+    /// # Returns
+    ///
+    /// A list of transactions, each containing reference, date, amount,
+    /// transaction_type, description, and running balance. Transactions
+    /// are sorted chronologically.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if batch size exceeds the limit or if the date range is invalid.
+    pub fn transactions(
+        &mut self,
+        n: usize,
+        starting_balance: f64,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<providers::finance::Transaction>, error::ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_transactions(
+            &mut self.rng,
+            n,
+            starting_balance,
+            start_date,
+            end_date,
+        )?)
+    }
+
+    /// Generate a batch of transaction amounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of amounts to generate
+    /// * `min` - Minimum amount (inclusive)
+    /// * `max` - Maximum amount (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Transaction amounts rounded to 2 decimal places
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if batch size exceeds maximum or if min > max.
+    pub fn transaction_amounts(
+        &mut self,
+        n: usize,
+        min: f64,
+        max: f64,
+    ) -> Result<Vec<f64>, error::ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::finance::generate_transaction_amounts(
+            &mut self.rng,
+            n,
+            min,
+            max,
+        )?)
+    }
+
+    /// Generate a batch of prices rounded to the currency's minor unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of prices to generate
+    /// * `min` - Minimum amount (inclusive)
+    /// * `max` - Maximum amount (inclusive)
+    /// * `currency` - ISO 4217 code (e.g. "USD"); JPY, KRW and other
+    ///   currencies without minor units round to whole numbers
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size,
+    /// `CurrencyError` for an unknown currency, or `FloatRangeError` if the
+    /// range is invalid.
+    pub fn prices(
+        &mut self,
+        n: usize,
+        min: f64,
+        max: f64,
+        currency: &str,
+    ) -> Result<Vec<f64>, ForgeryError> {
+        validate_batch_size(n)?;
+        let currency = currency.parse()?;
+        Ok(providers::finance::generate_prices(
+            &mut self.rng,
+            n,
+            min,
+            max,
+            currency,
+        )?)
+    }
+
+    /// Generate a single price rounded to the currency's minor unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurrencyError` for an unknown currency, or `FloatRangeError`
+    /// if the range is invalid.
+    pub fn price(&mut self, min: f64, max: f64, currency: &str) -> Result<f64, ForgeryError> {
+        let currency = currency.parse()?;
+        Ok(providers::finance::generate_price(
+            &mut self.rng,
+            min,
+            max,
+            currency,
+        )?)
+    }
+
+    /// Generate a batch of prices formatted for the locale and currency.
+    ///
+    /// Uses the locale's decimal and grouping separators and symbol
+    /// placement, e.g. "$1,234.56" (en_US) or "1.234,56 €" (de_DE).
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size,
+    /// `CurrencyError` for an unknown currency, or `FloatRangeError` if the
+    /// range is invalid.
+    pub fn formatted_prices(
+        &mut self,
+        n: usize,
+        min: f64,
+        max: f64,
+        currency: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let currency = currency.parse()?;
+        Ok(providers::finance::generate_formatted_prices(
+            &mut self.rng,
+            self.locale,
+            n,
+            min,
+            max,
+            currency,
+        )?)
+    }
+
+    /// Generate a single price formatted for the locale and currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurrencyError` for an unknown currency, or `FloatRangeError`
+    /// if the range is invalid.
+    pub fn formatted_price(
+        &mut self,
+        min: f64,
+        max: f64,
+        currency: &str,
+    ) -> Result<String, ForgeryError> {
+        let currency = currency.parse()?;
+        Ok(providers::finance::generate_formatted_price(
+            &mut self.rng,
+            self.locale,
+            min,
+            max,
+            currency,
+        )?)
+    }
+
+    /// Generate a single transaction amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - Minimum amount (inclusive)
+    /// * `max` - Maximum amount (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// A transaction amount rounded to 2 decimal places
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if min > max or if values are not finite.
+    pub fn transaction_amount(
+        &mut self,
+        min: f64,
+        max: f64,
+    ) -> Result<f64, providers::numbers::FloatRangeError> {
+        providers::finance::generate_transaction_amount(&mut self.rng, min, max)
+    }
+
+    // === Commerce Generation ===
+
+    /// Generate a batch of invoice or order numbers (e.g., "INV-2024-000123").
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of invoice numbers to generate
+    /// * `prefix` - Leading segment such as "INV" or "ORD"; omitted if empty
+    /// * `digits` - Width of the zero-padded sequence number (1-12)
+    /// * `date` - "year", "year_month" or "date" component, or `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, the
+    /// date component is unknown, or `digits` is out of range.
+    pub fn invoice_numbers(
+        &mut self,
+        n: usize,
+        prefix: &str,
+        digits: u32,
+        date: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let date = date.map(str::parse).transpose()?;
+        Ok(providers::commerce::generate_invoice_numbers(
+            &mut self.rng,
+            n,
+            prefix,
+            digits,
+            date,
+        )?)
+    }
+
+    /// Generate a single invoice or order number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvoiceNumberError` if the date component is unknown or
+    /// `digits` is out of range.
+    pub fn invoice_number(
+        &mut self,
+        prefix: &str,
+        digits: u32,
+        date: Option<&str>,
+    ) -> Result<String, providers::commerce::InvoiceNumberError> {
+        let date = date.map(str::parse).transpose()?;
+        providers::commerce::generate_invoice_number(&mut self.rng, prefix, digits, date)
+    }
+
+    /// Generate a batch of insurance policy numbers (e.g., "POL-2023-00482137K").
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of policy numbers to generate
+    /// * `prefix` - Leading segment of uppercase letters and digits; omitted if empty
+    /// * `digits` - Width of the zero-padded sequence number (1-12)
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, or the
+    /// prefix or `digits` is invalid.
+    pub fn policy_numbers(
+        &mut self,
+        n: usize,
+        prefix: &str,
+        digits: u32,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::insurance::generate_insurance_numbers(
+            &mut self.rng,
+            n,
+            prefix,
+            digits,
+        )?)
+    }
+
+    /// Generate a single insurance policy number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsuranceNumberError` if the prefix or `digits` is invalid.
+    pub fn policy_number(
+        &mut self,
+        prefix: &str,
+        digits: u32,
+    ) -> Result<String, providers::insurance::InsuranceNumberError> {
+        providers::insurance::generate_insurance_number(&mut self.rng, prefix, digits)
+    }
+
+    /// Generate a batch of insurance claim numbers (e.g., "CLM-2024-00031857X").
+    ///
+    /// Claim numbers share the policy number format with a different default
+    /// prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size, or the
+    /// prefix or `digits` is invalid.
+    pub fn claim_numbers(
+        &mut self,
+        n: usize,
+        prefix: &str,
+        digits: u32,
+    ) -> Result<Vec<String>, ForgeryError> {
+        self.policy_numbers(n, prefix, digits)
+    }
+
+    /// Generate a single insurance claim number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsuranceNumberError` if the prefix or `digits` is invalid.
+    pub fn claim_number(
+        &mut self,
+        prefix: &str,
+        digits: u32,
+    ) -> Result<String, providers::insurance::InsuranceNumberError> {
+        self.policy_number(prefix, digits)
+    }
+
+    /// Generate a batch of product SKUs (e.g., "TSH-RED-M-0042").
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of SKUs to generate
+    /// * `pattern` - Pattern where `#` is a digit and `?` an uppercase letter,
+    ///   or `None` for category-color-size-number SKUs
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size.
+    pub fn skus(&mut self, n: usize, pattern: Option<&str>) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::commerce::generate_skus(
+            &mut self.rng,
+            n,
+            pattern,
+        ))
+    }
+
+    /// Generate a single product SKU.
+    pub fn sku(&mut self, pattern: Option<&str>) -> String {
+        providers::commerce::generate_sku(&mut self.rng, pattern)
+    }
+
+    /// Generate a batch of retail prices ending in .99, .95, .00 or .49.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of prices to generate
+    /// * `tier` - "budget", "standard", "premium" or "luxury"
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// tier is unknown.
+    pub fn retail_prices(&mut self, n: usize, tier: &str) -> Result<Vec<f64>, ForgeryError> {
+        validate_batch_size(n)?;
+        let tier = tier.parse()?;
+        Ok(providers::commerce::generate_retail_prices(
+            &mut self.rng,
+            n,
+            tier,
+        ))
+    }
+
+    /// Generate a single retail price (e.g., 49.99).
+    ///
+    /// # Errors
+    ///
+    /// Returns `PriceTierError` if the tier is unknown.
+    pub fn retail_price(&mut self, tier: &str) -> Result<f64, providers::commerce::PriceTierError> {
+        let tier = tier.parse()?;
+        Ok(providers::commerce::generate_retail_price(
+            &mut self.rng,
+            tier,
+        ))
+    }
+
+    /// Generate a batch of product names (e.g., "Ergonomic Granite Chair").
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of names to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn product_names(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::commerce::generate_product_name(rng)
+            })
+        } else {
+            Ok(providers::commerce::generate_product_names(
+                &mut self.rng,
+                n,
+            ))
+        }
+    }
+
+    /// Generate a single product name.
+    pub fn product_name(&mut self) -> String {
+        providers::commerce::generate_product_name(&mut self.rng)
+    }
+
+    // === Product Code Generation ===
+
+    /// Generate a batch of hyphenated ISBN-13s with valid check digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of ISBNs to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn isbn13s(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::codes::generate_isbn13(rng))
+        } else {
+            Ok(providers::codes::generate_isbn13s(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single hyphenated ISBN-13.
+    pub fn isbn13(&mut self) -> String {
+        providers::codes::generate_isbn13(&mut self.rng)
+    }
+
+    /// Generate a batch of hyphenated ISBN-10s with valid check digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of ISBNs to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn isbn10s(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::codes::generate_isbn10(rng))
+        } else {
+            Ok(providers::codes::generate_isbn10s(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single hyphenated ISBN-10.
+    pub fn isbn10(&mut self) -> String {
+        providers::codes::generate_isbn10(&mut self.rng)
+    }
+
+    /// Generate a batch of 15-digit IMEIs with valid Luhn check digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of IMEIs to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn imeis(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::codes::generate_imei(rng))
+        } else {
+            Ok(providers::codes::generate_imeis(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single 15-digit IMEI.
+    pub fn imei(&mut self) -> String {
+        providers::codes::generate_imei(&mut self.rng)
+    }
+
+    /// Generate a batch of DOIs with real registrant prefixes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of DOIs to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn dois(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::codes::generate_doi(rng))
+        } else {
+            Ok(providers::codes::generate_dois(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single DOI (e.g., "10.1038/s41586-020-2012-7").
+    pub fn doi(&mut self) -> String {
+        providers::codes::generate_doi(&mut self.rng)
+    }
+
+    /// Generate a batch of ORCID iDs with valid MOD 11-2 check characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of iDs to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn orcids(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::codes::generate_orcid(rng))
+        } else {
+            Ok(providers::codes::generate_orcids(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single ORCID iD (e.g., "0000-0002-1825-0097").
+    pub fn orcid(&mut self) -> String {
+        providers::codes::generate_orcid(&mut self.rng)
+    }
+
+    // === Vehicle Generation ===
+
+    /// Generate a batch of 17-character VINs with valid check digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of VINs to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn vins(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::vehicle::generate_vin(rng))
+        } else {
+            Ok(providers::vehicle::generate_vins(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single 17-character VIN.
+    pub fn vin(&mut self) -> String {
+        providers::vehicle::generate_vin(&mut self.rng)
+    }
+
+    /// Generate a batch of vehicle makes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn vehicle_makes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::vehicle::generate_vehicle_make(rng)
+            })
+        } else {
+            Ok(providers::vehicle::generate_vehicle_makes(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single vehicle make (e.g. "Toyota").
+    pub fn vehicle_make(&mut self) -> String {
+        providers::vehicle::generate_vehicle_make(&mut self.rng)
+    }
+
+    /// Generate a batch of vehicle models.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn vehicle_models(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::vehicle::generate_vehicle_model(rng)
+            })
+        } else {
+            Ok(providers::vehicle::generate_vehicle_models(
+                &mut self.rng,
+                n,
+            ))
+        }
+    }
+
+    /// Generate a single vehicle model (e.g. "Camry").
+    pub fn vehicle_model(&mut self) -> String {
+        providers::vehicle::generate_vehicle_model(&mut self.rng)
+    }
+
+    /// Generate a batch of vehicles.
+    ///
+    /// Each vehicle's VIN starts with a WMI of its make and encodes its
+    /// model year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn vehicles(
+        &mut self,
+        n: usize,
+    ) -> Result<Vec<providers::vehicle::Vehicle>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::vehicle::generate_vehicles(&mut self.rng, n))
+    }
+
+    /// Generate a single vehicle.
+    pub fn vehicle(&mut self) -> providers::vehicle::Vehicle {
+        providers::vehicle::generate_vehicle(&mut self.rng)
+    }
+
+    // === Medical Generation ===
+
+    /// Generate a batch of blood types, weighted by prevalence.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn blood_types(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::medical::generate_blood_type(rng)
+            })
+        } else {
+            Ok(providers::medical::generate_blood_types(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single blood type (e.g. "O+").
+    pub fn blood_type(&mut self) -> String {
+        providers::medical::generate_blood_type(&mut self.rng)
+    }
+
+    /// Generate a batch of allergies.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn allergies(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::medical::generate_allergy(rng))
+        } else {
+            Ok(providers::medical::generate_allergies(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single allergy (e.g. "Penicillin").
+    pub fn allergy(&mut self) -> String {
+        providers::medical::generate_allergy(&mut self.rng)
+    }
+
+    /// Generate a batch of medication generic names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn medications(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::medical::generate_medication(rng)
+            })
+        } else {
+            Ok(providers::medical::generate_medications(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single medication generic name.
+    pub fn medication(&mut self) -> String {
+        providers::medical::generate_medication(&mut self.rng)
+    }
+
+    /// Generate a batch of ICD-10 diagnosis codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn icd10_codes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::medical::generate_icd10_code(rng)
+            })
+        } else {
+            Ok(providers::medical::generate_icd10_codes(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single ICD-10 diagnosis code (e.g. "E11.65").
+    pub fn icd10_code(&mut self) -> String {
+        providers::medical::generate_icd10_code(&mut self.rng)
+    }
+
+    /// Generate a batch of US National Provider Identifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn npis(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| providers::medical::generate_npi(rng))
+        } else {
+            Ok(providers::medical::generate_npis(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single US National Provider Identifier (e.g. "1234567893").
+    pub fn npi(&mut self) -> String {
+        providers::medical::generate_npi(&mut self.rng)
+    }
+
+    /// Generate a batch of adult heights in centimetres.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn heights(&mut self, n: usize) -> Result<Vec<f64>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::medical::generate_heights(&mut self.rng, n))
+    }
+
+    /// Generate a single adult height in centimetres.
+    pub fn height(&mut self) -> f64 {
+        providers::medical::generate_height(&mut self.rng)
+    }
+
+    /// Generate a batch of adult weights in kilograms.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn weights(&mut self, n: usize) -> Result<Vec<f64>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::medical::generate_weights(&mut self.rng, n))
+    }
+
+    /// Generate a single adult weight in kilograms.
+    pub fn weight(&mut self) -> f64 {
+        providers::medical::generate_weight(&mut self.rng)
+    }
+
+    // === Travel Generation ===
+
+    /// Generate a batch of three-letter IATA airport codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn airport_codes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::travel::generate_airport_code(rng)
+            })
+        } else {
+            Ok(providers::travel::generate_airport_codes(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single three-letter IATA airport code.
+    pub fn airport_code(&mut self) -> String {
+        providers::travel::generate_airport_code(&mut self.rng)
+    }
+
+    /// Generate a batch of four-letter ICAO airport codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn airport_icao_codes(
+        &mut self,
+        n: usize,
+        unique: bool,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::travel::generate_airport_icao_code(rng)
+            })
+        } else {
+            Ok(providers::travel::generate_airport_icao_codes(
+                &mut self.rng,
+                n,
+            ))
+        }
+    }
+
+    /// Generate a single four-letter ICAO airport code.
+    pub fn airport_icao_code(&mut self) -> String {
+        providers::travel::generate_airport_icao_code(&mut self.rng)
+    }
+
+    /// Generate a batch of airport names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn airport_names(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::travel::generate_airport_name(rng)
+            })
+        } else {
+            Ok(providers::travel::generate_airport_names(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single airport name.
+    pub fn airport_name(&mut self) -> String {
+        providers::travel::generate_airport_name(&mut self.rng)
+    }
+
+    /// Generate a batch of cities served by an airport.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn airport_cities(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::travel::generate_airport_cities(&mut self.rng, n))
+    }
+
+    /// Generate a single airport city.
+    pub fn airport_city(&mut self) -> String {
+        providers::travel::generate_airport_city(&mut self.rng)
+    }
+
+    /// Generate a batch of flight numbers (IATA carrier code and 1-4 digits).
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn flight_numbers(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::travel::generate_flight_number(rng)
+            })
+        } else {
+            Ok(providers::travel::generate_flight_numbers(&mut self.rng, n))
+        }
+    }
+
+    /// Generate a single flight number (e.g., "BA283").
+    pub fn flight_number(&mut self) -> String {
+        providers::travel::generate_flight_number(&mut self.rng)
+    }
+
+    /// Generate a batch of airports.
+    ///
+    /// Each airport's codes, name, city and country belong together.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn airports(
+        &mut self,
+        n: usize,
+    ) -> Result<Vec<providers::travel::Airport>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::travel::generate_airports(&mut self.rng, n))
+    }
+
+    // === Logistics Generation ===
+
+    /// Generate a batch of shipment tracking numbers with valid check digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of tracking numbers to generate
+    /// * `carrier` - Restrict to one carrier format: "ups", "fedex", "usps"
+    ///   or "dhl" (default: any)
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `CarrierError` if the carrier name is unknown.
+    pub fn tracking_numbers(
+        &mut self,
+        n: usize,
+        carrier: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let carrier = carrier.map(str::parse).transpose()?;
+        Ok(providers::logistics::generate_tracking_numbers(
+            &mut self.rng,
+            n,
+            carrier,
+        ))
+    }
+
+    /// Generate a single shipment tracking number with a valid check digit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CarrierError` if the carrier name is unknown.
+    pub fn tracking_number(
+        &mut self,
+        carrier: Option<&str>,
+    ) -> Result<String, providers::logistics::CarrierError> {
+        let carrier = carrier.map(str::parse).transpose()?;
+        Ok(providers::logistics::generate_tracking_number(
+            &mut self.rng,
+            carrier,
+        ))
+    }
+
+    // === Language Generation ===
+
+    /// Generate a batch of ISO 639-1 language codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn language_codes(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::language::generate_language_code(rng)
+            })
+        } else {
+            Ok(providers::language::generate_language_codes(
+                &mut self.rng,
+                n,
+            ))
+        }
+    }
+
+    /// Generate a single ISO 639-1 language code.
+    pub fn language_code(&mut self) -> String {
+        providers::language::generate_language_code(&mut self.rng)
+    }
+
+    /// Generate a batch of language names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of values to generate
+    /// * `unique` - If true, ensure all generated values are unique
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or
+    /// if unique generation cannot produce enough unique values.
+    pub fn language_names(&mut self, n: usize, unique: bool) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        if unique {
+            self.generate_unique(n, |rng, _locale| {
+                providers::language::generate_language_name(rng)
+            })
+        } else {
+            Ok(providers::language::generate_language_names(
+                &mut self.rng,
+                n,
+            ))
+        }
+    }
+
+    /// Generate a single language name.
+    pub fn language_name(&mut self) -> String {
+        providers::language::generate_language_name(&mut self.rng)
+    }
+
+    // === File System Generation ===
+
+    /// Generate a batch of file names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of file names to generate
+    /// * `category` - Extension category ("archive", "audio", "code",
+    ///   "document", "image", "text" or "video"); any category if `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `FileCategoryError` if the category is not supported.
+    pub fn file_names(
+        &mut self,
+        n: usize,
+        category: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::filesystem::generate_file_names(
+            &mut self.rng,
+            n,
+            category,
+        )?)
+    }
+
+    /// Generate a single file name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FileCategoryError` if the category is not supported.
+    pub fn file_name(
+        &mut self,
+        category: Option<&str>,
+    ) -> Result<String, providers::filesystem::FileCategoryError> {
+        providers::filesystem::generate_file_name(&mut self.rng, category)
+    }
+
+    /// Generate a batch of file extensions (without the leading dot).
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `FileCategoryError` if the category is not supported.
+    pub fn file_extensions(
+        &mut self,
+        n: usize,
+        category: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::filesystem::generate_file_extensions(
+            &mut self.rng,
+            n,
+            category,
+        )?)
+    }
+
+    /// Generate a single file extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FileCategoryError` if the category is not supported.
+    pub fn file_extension(
+        &mut self,
+        category: Option<&str>,
+    ) -> Result<String, providers::filesystem::FileCategoryError> {
+        providers::filesystem::generate_file_extension(&mut self.rng, category)
+    }
+
+    /// Generate a batch of absolute file paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of paths to generate
+    /// * `depth` - Number of directories before the file name
+    /// * `os` - Path style, "unix" or "windows"
+    /// * `category` - Extension category of the file; any category if `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size,
+    /// `PathStyleError` for an unknown `os`, or `FileCategoryError` if the
+    /// category is not supported.
+    pub fn file_paths(
+        &mut self,
+        n: usize,
+        depth: usize,
+        os: &str,
+        category: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let style = os.parse()?;
+        Ok(providers::filesystem::generate_file_paths(
+            &mut self.rng,
+            n,
+            depth,
+            style,
+            category,
+        )?)
+    }
+
+    /// Generate a single absolute file path.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PathStyleError` for an unknown `os`, or `FileCategoryError`
+    /// if the category is not supported.
+    pub fn file_path(
+        &mut self,
+        depth: usize,
+        os: &str,
+        category: Option<&str>,
+    ) -> Result<String, ForgeryError> {
+        let style = os.parse()?;
+        Ok(providers::filesystem::generate_file_path(
+            &mut self.rng,
+            depth,
+            style,
+            category,
+        )?)
+    }
+
+    /// Generate a batch of MIME types.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of MIME types to generate
+    /// * `category` - Top-level type ("application", "audio", "image",
+    ///   "text" or "video"); weighted toward application and image if `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `MimeCategoryError` if the category is not supported.
+    pub fn mime_types(
+        &mut self,
+        n: usize,
+        category: Option<&str>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::filesystem::generate_mime_types(
+            &mut self.rng,
+            n,
+            category,
+        )?)
+    }
+
+    /// Generate a single MIME type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MimeCategoryError` if the category is not supported.
+    pub fn mime_type(
+        &mut self,
+        category: Option<&str>,
+    ) -> Result<String, providers::filesystem::MimeCategoryError> {
+        providers::filesystem::generate_mime_type(&mut self.rng, category)
+    }
+
+    // === Software Generation ===
+
+    /// Generate a batch of semantic versions.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of versions to generate
+    /// * `prerelease` - If true, append a pre-release tag (e.g. "-rc.2")
+    /// * `build` - If true, append build metadata (e.g. "+build.1187")
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn semvers(
+        &mut self,
+        n: usize,
+        prerelease: bool,
+        build: bool,
+    ) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::software::generate_semvers(
+            &mut self.rng,
+            n,
+            prerelease,
+            build,
+        ))
+    }
+
+    /// Generate a single semantic version.
+    pub fn semver(&mut self, prerelease: bool, build: bool) -> String {
+        providers::software::generate_semver(&mut self.rng, prerelease, build)
+    }
+
+    /// Generate a batch of git commit SHAs.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of SHAs to generate
+    /// * `short` - If true, return the 7-character abbreviated form
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn git_shas(&mut self, n: usize, short: bool) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::software::generate_git_shas(
+            &mut self.rng,
+            n,
+            short,
+        ))
+    }
+
+    /// Generate a single git commit SHA.
+    pub fn git_sha(&mut self, short: bool) -> String {
+        providers::software::generate_git_sha(&mut self.rng, short)
+    }
+
+    /// Generate a batch of conventional commit messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn commit_messages(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::software::generate_commit_messages(
+            &mut self.rng,
+            n,
+        ))
+    }
+
+    /// Generate a single conventional commit message (e.g., "fix(parser): handle empty input").
+    pub fn commit_message(&mut self) -> String {
+        providers::software::generate_commit_message(&mut self.rng)
+    }
+
+    /// Generate a batch of container image references.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of references to generate
+    /// * `registry` - If true, prefix a registry host (e.g. "ghcr.io")
+    /// * `tag` - If true, append a version tag (e.g. ":1.4.2")
+    /// * `digest` - If true, append an "@sha256:" content digest
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn docker_images(
+        &mut self,
+        n: usize,
+        registry: bool,
+        tag: bool,
+        digest: bool,
+    ) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::software::generate_docker_images(
+            &mut self.rng,
+            n,
+            registry,
+            tag,
+            digest,
+        ))
+    }
+
+    /// Generate a single container image reference (e.g., "ghcr.io/payments/api:1.4.2").
+    pub fn docker_image(&mut self, registry: bool, tag: bool, digest: bool) -> String {
+        providers::software::generate_docker_image(&mut self.rng, registry, tag, digest)
+    }
+
+    /// Generate a batch of DNS-1123 compliant Kubernetes resource names.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of names to generate
+    /// * `kind` - "namespace", "deployment", "replicaset" or "pod"
+    ///
+    /// # Errors
+    ///
+    /// Returns `ForgeryError` if `n` exceeds the maximum batch size or the
+    /// kind is unknown.
+    pub fn k8s_names(&mut self, n: usize, kind: &str) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let kind = kind.parse()?;
+        Ok(providers::software::generate_k8s_names(
+            &mut self.rng,
+            n,
+            kind,
+        ))
+    }
+
+    /// Generate a single Kubernetes resource name (e.g., "payments-api-7d9f8b6c4-x2lqn").
+    ///
+    /// # Errors
+    ///
+    /// Returns `K8sResourceError` if the kind is unknown.
+    pub fn k8s_name(
+        &mut self,
+        kind: &str,
+    ) -> Result<String, providers::software::K8sResourceError> {
+        let kind = kind.parse()?;
+        Ok(providers::software::generate_k8s_name(&mut self.rng, kind))
+    }
+
+    // === Cloud Generation ===
+
+    /// Generate a batch of AWS ARNs.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of ARNs to generate
+    /// * `service` - Restrict to one service format: "s3", "iam" or "lambda"
+    ///   (default: any)
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `ArnServiceError` if the service name is unknown.
+    pub fn arns(&mut self, n: usize, service: Option<&str>) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let service = service.map(str::parse).transpose()?;
+        Ok(providers::cloud::generate_arns(&mut self.rng, n, service))
+    }
+
+    /// Generate a single AWS ARN (e.g., "arn:aws:iam::482915730164:role/PaymentsApiRole").
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArnServiceError` if the service name is unknown.
+    pub fn arn(
+        &mut self,
+        service: Option<&str>,
+    ) -> Result<String, providers::cloud::ArnServiceError> {
+        let service = service.map(str::parse).transpose()?;
+        Ok(providers::cloud::generate_arn(&mut self.rng, service))
+    }
+
+    /// Generate a batch of S3 URIs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn s3_uris(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::cloud::generate_s3_uris(&mut self.rng, n))
+    }
+
+    /// Generate a single S3 URI (e.g., "s3://payments-api-prod-logs/2024/03/15/5f1c9a2e.json").
+    pub fn s3_uri(&mut self) -> String {
+        providers::cloud::generate_s3_uri(&mut self.rng)
+    }
+
+    /// Generate a batch of cloud region identifiers, weighted by popularity.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of regions to generate
+    /// * `provider` - "aws", "gcp" or "azure"
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `CloudProviderError` if the provider name is unknown.
+    pub fn cloud_regions(&mut self, n: usize, provider: &str) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let provider = provider.parse()?;
+        Ok(providers::cloud::generate_cloud_regions(
+            &mut self.rng,
+            n,
+            provider,
+        ))
+    }
+
+    /// Generate a single cloud region identifier (e.g., "us-east-1", "westeurope").
+    ///
+    /// # Errors
+    ///
+    /// Returns `CloudProviderError` if the provider name is unknown.
+    pub fn cloud_region(
+        &mut self,
+        provider: &str,
+    ) -> Result<String, providers::cloud::CloudProviderError> {
+        let provider = provider.parse()?;
+        Ok(providers::cloud::generate_cloud_region(
+            &mut self.rng,
+            provider,
+        ))
+    }
+
+    // === Log Generation ===
+
+    /// Generate a batch of access log lines in the combined log format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn access_logs(&mut self, n: usize) -> Result<Vec<String>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::logs::generate_access_logs(&mut self.rng, n))
+    }
+
+    /// Generate a single access log line in the combined log format.
+    pub fn access_log(&mut self) -> String {
+        providers::logs::generate_access_log(&mut self.rng)
+    }
+
+    /// Generate a batch of structured access log entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn access_log_entries(
+        &mut self,
+        n: usize,
+    ) -> Result<Vec<providers::logs::AccessLogEntry>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::logs::generate_access_log_entries(
+            &mut self.rng,
+            n,
+        ))
+    }
+
+    /// Generate a single structured access log entry.
+    pub fn access_log_entry(&mut self) -> providers::logs::AccessLogEntry {
+        providers::logs::generate_access_log_entry(&mut self.rng)
+    }
+
+    /// Generate a batch of syslog lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of lines to generate
+    /// * `format` - "rfc5424" or "rfc3164"
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `SyslogFormatError` if the format name is unknown.
+    pub fn syslogs(&mut self, n: usize, format: &str) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let format = format.parse()?;
+        Ok(providers::logs::generate_syslogs(&mut self.rng, n, format))
+    }
+
+    /// Generate a single syslog line.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SyslogFormatError` if the format name is unknown.
+    pub fn syslog(&mut self, format: &str) -> Result<String, providers::logs::SyslogFormatError> {
+        let format = format.parse()?;
+        Ok(providers::logs::generate_syslog(&mut self.rng, format))
+    }
+
+    /// Generate a batch of structured syslog entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size.
+    pub fn syslog_entries(
+        &mut self,
+        n: usize,
+    ) -> Result<Vec<providers::logs::SyslogEntry>, BatchSizeError> {
+        validate_batch_size(n)?;
+        Ok(providers::logs::generate_syslog_entries(&mut self.rng, n))
+    }
+
+    /// Generate a single structured syslog entry.
+    pub fn syslog_entry(&mut self) -> providers::logs::SyslogEntry {
+        providers::logs::generate_syslog_entry(&mut self.rng)
+    }
+
+    // === Password Generation ===
+
+    /// Generate a batch of random passwords.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of passwords to generate
+    /// * `length` - Length of each password
+    /// * `uppercase` - Include uppercase letters
+    /// * `lowercase` - Include lowercase letters
+    /// * `digits` - Include digits
+    /// * `symbols` - Include symbols
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no character sets are enabled or batch size exceeds maximum.
+    pub fn passwords(
+        &mut self,
+        n: usize,
+        length: usize,
+        uppercase: bool,
+        lowercase: bool,
+        digits: bool,
+        symbols: bool,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        validate_batch_size(n)?;
+        Ok(providers::password::generate_passwords(
+            &mut self.rng,
+            n,
+            length,
+            uppercase,
+            lowercase,
+            digits,
+            symbols,
+        )?)
+    }
+
+    /// Generate a single random password.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - Length of the password
+    /// * `uppercase` - Include uppercase letters
+    /// * `lowercase` - Include lowercase letters
+    /// * `digits` - Include digits
+    /// * `symbols` - Include symbols
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no character sets are enabled.
+    pub fn password(
+        &mut self,
+        length: usize,
+        uppercase: bool,
+        lowercase: bool,
+        digits: bool,
+        symbols: bool,
+    ) -> Result<String, providers::password::PasswordError> {
+        providers::password::generate_password(
+            &mut self.rng,
+            length,
+            uppercase,
+            lowercase,
+            digits,
+            symbols,
+        )
+    }
+
+    /// Generate a batch of password hash strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of hashes to generate
+    /// * `algorithm` - "bcrypt", "argon2id" or "pbkdf2_sha256"
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `PasswordHashAlgorithmError` for an unknown `algorithm`.
+    pub fn password_hashes(
+        &mut self,
+        n: usize,
+        algorithm: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let algorithm = algorithm.parse()?;
+        Ok(providers::auth::generate_password_hashes(
+            &mut self.rng,
+            n,
+            algorithm,
+        ))
+    }
+
+    /// Generate a single password hash string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PasswordHashAlgorithmError` for an unknown `algorithm`.
+    pub fn password_hash(
+        &mut self,
+        algorithm: &str,
+    ) -> Result<String, providers::auth::PasswordHashAlgorithmError> {
+        let algorithm = algorithm.parse()?;
+        Ok(providers::auth::generate_password_hash(
+            &mut self.rng,
+            algorithm,
+        ))
+    }
+
+    /// Generate a batch of JSON Web Tokens with random signatures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `DateRangeError` for an invalid issued-at range.
+    pub fn jwts(
+        &mut self,
+        n: usize,
+        options: &providers::auth::JwtOptions<'_>,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::auth::generate_jwts(&mut self.rng, n, options)?)
+    }
+
+    /// Generate a single JSON Web Token with a random signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DateRangeError` for an invalid issued-at range.
+    pub fn jwt(
+        &mut self,
+        options: &providers::auth::JwtOptions<'_>,
+    ) -> Result<String, providers::datetime::DateRangeError> {
+        providers::auth::generate_jwt(&mut self.rng, options)
+    }
+
+    /// Generate a batch of prefixed API keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of keys to generate
+    /// * `prefix` - Text before the random part (e.g., "sk_live_")
+    /// * `length` - Number of random characters after the prefix
+    /// * `alphabet` - "base62", "hex", "base32" or "base64url"
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `ApiKeyAlphabetError` for an unknown `alphabet`.
+    pub fn api_keys(
+        &mut self,
+        n: usize,
+        prefix: &str,
+        length: usize,
+        alphabet: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let alphabet = alphabet.parse()?;
+        Ok(providers::auth::generate_api_keys(
+            &mut self.rng,
+            n,
+            prefix,
+            length,
+            alphabet,
+        ))
+    }
+
+    /// Generate a single prefixed API key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiKeyAlphabetError` for an unknown `alphabet`.
+    pub fn api_key(
+        &mut self,
+        prefix: &str,
+        length: usize,
+        alphabet: &str,
+    ) -> Result<String, providers::auth::ApiKeyAlphabetError> {
+        let alphabet = alphabet.parse()?;
+        Ok(providers::auth::generate_api_key(
+            &mut self.rng,
+            prefix,
+            length,
+            alphabet,
+        ))
+    }
+
+    /// Generate a batch of session IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of tokens to generate
+    /// * `format` - "hex", "base64url" or "prefixed"
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `TokenFormatError` for an unknown `format`.
+    pub fn session_ids(&mut self, n: usize, format: &str) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let format = format.parse()?;
+        Ok(providers::auth::generate_tokens(
+            &mut self.rng,
+            n,
+            providers::auth::TokenKind::Session,
+            format,
+        ))
+    }
+
+    /// Generate a single session ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenFormatError` for an unknown `format`.
+    pub fn session_id(
+        &mut self,
+        format: &str,
+    ) -> Result<String, providers::auth::TokenFormatError> {
+        let format = format.parse()?;
+        Ok(providers::auth::generate_token(
+            &mut self.rng,
+            providers::auth::TokenKind::Session,
+            format,
+        ))
+    }
+
+    /// Generate a batch of OAuth access tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of tokens to generate
+    /// * `format` - "hex", "base64url" or "prefixed"
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `TokenFormatError` for an unknown `format`.
+    pub fn oauth_access_tokens(
+        &mut self,
+        n: usize,
+        format: &str,
+    ) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let format = format.parse()?;
+        Ok(providers::auth::generate_tokens(
+            &mut self.rng,
+            n,
+            providers::auth::TokenKind::AccessToken,
+            format,
+        ))
+    }
+
+    /// Generate a single OAuth access token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenFormatError` for an unknown `format`.
+    pub fn oauth_access_token(
+        &mut self,
+        format: &str,
+    ) -> Result<String, providers::auth::TokenFormatError> {
+        let format = format.parse()?;
+        Ok(providers::auth::generate_token(
+            &mut self.rng,
+            providers::auth::TokenKind::AccessToken,
+            format,
+        ))
+    }
+
+    /// Generate a batch of OAuth refresh tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of tokens to generate
+    /// * `format` - "hex", "base64url" or "prefixed"
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum batch size, or
+    /// `TokenFormatError` for an unknown `format`.
+    pub fn refresh_tokens(&mut self, n: usize, format: &str) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        let format = format.parse()?;
+        Ok(providers::auth::generate_tokens(
+            &mut self.rng,
+            n,
+            providers::auth::TokenKind::RefreshToken,
+            format,
+        ))
+    }
+
+    /// Generate a single OAuth refresh token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenFormatError` for an unknown `format`.
+    pub fn refresh_token(
+        &mut self,
+        format: &str,
+    ) -> Result<String, providers::auth::TokenFormatError> {
+        let format = format.parse()?;
+        Ok(providers::auth::generate_token(
+            &mut self.rng,
+            providers::auth::TokenKind::RefreshToken,
+            format,
+        ))
+    }
+
+    /// Generate a batch of base32 TOTP secrets.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of secrets to generate
+    /// * `length` - Secret length in characters: 16 or 32
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum, or
+    /// `TotpSecretLengthError` for an unsupported `length`.
+    pub fn totp_secrets(&mut self, n: usize, length: usize) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::auth::generate_totp_secrets(
+            &mut self.rng,
+            n,
+            length,
+        )?)
+    }
+
+    /// Generate a single base32 TOTP secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TotpSecretLengthError` for an unsupported `length`.
+    pub fn totp_secret(
+        &mut self,
+        length: usize,
+    ) -> Result<String, providers::auth::TotpSecretLengthError> {
+        providers::auth::generate_totp_secret(&mut self.rng, length)
+    }
+
+    /// Generate a batch of `otpauth://totp/` provisioning URIs.
+    ///
+    /// The issuer is a company name and the account an email address from
+    /// the Faker's locale, using any first name overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchSizeError` if `n` exceeds the maximum, or
+    /// `TotpSecretLengthError` for an unsupported `length`.
+    pub fn totp_uris(&mut self, n: usize, length: usize) -> Result<Vec<String>, ForgeryError> {
+        validate_batch_size(n)?;
+        Ok(providers::auth::generate_totp_uris(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+            length,
+        )?)
+    }
+
+    /// Generate a single `otpauth://totp/` provisioning URI.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TotpSecretLengthError` for an unsupported `length`.
+    pub fn totp_uri(
+        &mut self,
+        length: usize,
+    ) -> Result<String, providers::auth::TotpSecretLengthError> {
+        providers::auth::generate_totp_uri(&mut self.rng, self.locale, &self.overrides, length)
+    }
+
+    // === Records Generation ===
+
+    /// Generate records based on a schema.
+    ///
+    /// Returns a vector of BTreeMaps, where each BTreeMap represents a record
+    /// with field names as keys and generated values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch size exceeds the maximum or the schema is invalid.
+    pub fn records(
+        &mut self,
+        n: usize,
+        schema: &BTreeMap<String, providers::records::FieldSpec>,
+    ) -> Result<Vec<BTreeMap<String, providers::records::Value>>, Box<dyn std::error::Error>> {
+        validate_batch_size(n)?;
+        Ok(providers::records::generate_records_with_custom(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+            schema,
+            &self.custom_providers,
+        )?)
+    }
+
+    /// Generate records as tuples based on a schema.
+    ///
+    /// Returns a vector of vectors, where each inner vector contains values
+    /// in the same order as the provided field order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch size exceeds the maximum or the schema is invalid.
+    pub fn records_tuples(
+        &mut self,
+        n: usize,
+        schema: &BTreeMap<String, providers::records::FieldSpec>,
+        field_order: &[String],
+    ) -> Result<Vec<Vec<providers::records::Value>>, Box<dyn std::error::Error>> {
+        validate_batch_size(n)?;
+        Ok(providers::records::generate_records_tuples_with_custom(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+            schema,
+            field_order,
+            &self.custom_providers,
+        )?)
+    }
+
+    /// Generate records as an Arrow RecordBatch based on a schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch size exceeds the maximum or the schema is invalid.
+    pub fn records_arrow(
+        &mut self,
+        n: usize,
+        schema: &BTreeMap<String, providers::records::FieldSpec>,
+    ) -> Result<arrow_array::RecordBatch, Box<dyn std::error::Error>> {
+        validate_batch_size(n)?;
+        Ok(providers::records::generate_records_arrow_with_custom(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+            schema,
+            &self.custom_providers,
+        )?)
+    }
+
+    /// Generate a sample of records and compute per-field statistics.
+    ///
+    /// Useful for sanity-checking a schema before a large generation run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sample size exceeds the maximum or the schema is invalid.
+    pub fn describe(
+        &mut self,
+        n_sample: usize,
+        schema: &BTreeMap<String, providers::records::FieldSpec>,
+    ) -> Result<BTreeMap<String, providers::stats::FieldStats>, Box<dyn std::error::Error>> {
+        validate_batch_size(n_sample)?;
+        Ok(providers::stats::describe_schema_with_custom(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n_sample,
+            schema,
+            &self.custom_providers,
+        )?)
+    }
+
+    // === Custom Providers ===
+
+    /// Register a custom provider with uniform random selection.
+    ///
+    /// Each option has equal probability of being selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The provider name (must not conflict with built-in types)
+    /// * `options` - List of string options to choose from
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The name conflicts with a built-in type
+    /// - The options list is empty
+    pub fn add_provider(
+        &mut self,
+        name: &str,
+        options: Vec<String>,
+    ) -> Result<(), CustomProviderError> {
+        if is_reserved_name(name) {
+            return Err(CustomProviderError::NameCollision(name.to_string()));
+        }
+        let provider = CustomProvider::uniform(options)?;
+        self.custom_providers.insert(name.to_string(), provider);
+        Ok(())
+    }
+
+    /// Register a custom provider with weighted random selection.
+    ///
+    /// Options are selected based on their relative weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The provider name (must not conflict with built-in types)
+    /// * `pairs` - List of (value, weight) tuples
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The name conflicts with a built-in type
+    /// - The options list is empty
+    /// - All weights are zero
+    pub fn add_weighted_provider(
+        &mut self,
+        name: &str,
+        pairs: Vec<(String, u64)>,
+    ) -> Result<(), CustomProviderError> {
+        if is_reserved_name(name) {
+            return Err(CustomProviderError::NameCollision(name.to_string()));
+        }
+        let provider = CustomProvider::weighted(pairs)?;
+        self.custom_providers.insert(name.to_string(), provider);
+        Ok(())
+    }
+
+    /// Remove a custom provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The provider name to remove
+    ///
+    /// # Returns
+    ///
+    /// `true` if the provider was removed, `false` if it didn't exist.
+    pub fn remove_provider(&mut self, name: &str) -> bool {
+        self.custom_providers.remove(name).is_some()
+    }
+
+    /// Check if a custom provider exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The provider name to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the provider exists, `false` otherwise.
+    pub fn has_provider(&self, name: &str) -> bool {
+        self.custom_providers.contains_key(name)
+    }
+
+    /// List all registered custom provider names.
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector of provider names (for deterministic output).
+    pub fn list_providers(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.custom_providers.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Get a set of all custom provider names.
+    ///
+    /// Used internally for schema validation.
+    pub fn custom_provider_names(&self) -> HashSet<String> {
+        self.custom_providers.keys().cloned().collect()
+    }
+
+    /// Generate a single value from a custom provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The custom provider name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider doesn't exist.
+    pub fn generate(&mut self, name: &str) -> Result<String, CustomProviderError> {
+        let provider = self
+            .custom_providers
+            .get(name)
+            .ok_or_else(|| CustomProviderError::NotFound(name.to_string()))?;
+        Ok(provider.generate(&mut self.rng))
+    }
+
+    /// Generate a batch of values from a custom provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The custom provider name
+    /// * `n` - Number of values to generate
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The provider doesn't exist
+    /// - `n` exceeds the maximum batch size
+    pub fn generate_batch(
+        &mut self,
+        name: &str,
+        n: usize,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        validate_batch_size(n)?;
+        let provider = self
+            .custom_providers
+            .get(name)
+            .ok_or_else(|| CustomProviderError::NotFound(name.to_string()))?;
+        Ok(provider.generate_batch(&mut self.rng, n))
+    }
+
+    // === Locale Data Overrides ===
+
+    /// Layer user-supplied data lists on top of the locale's built-in data.
+    ///
+    /// Overridden lists replace the locale's list for this instance only; the
+    /// locale's formats (name order, address template, postal codes, phones)
+    /// are unchanged. Fields left as `None` keep any earlier override.
+    ///
+    /// Overrides apply to the name, address, email and username generators,
+    /// including the matching fields of schema-based `records()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EmptyOverrideError` if any supplied list is empty.
+    pub fn override_locale_data(
+        &mut self,
+        overrides: LocaleOverrides,
+    ) -> Result<(), EmptyOverrideError> {
+        Arc::make_mut(&mut self.overrides).merge(overrides)
+    }
+
+    /// Remove all locale data overrides, restoring the built-in data.
+    pub fn clear_locale_overrides(&mut self) {
+        self.overrides = Arc::default();
+    }
+
+    /// Get the locale data overrides currently in effect.
+    pub fn locale_overrides(&self) -> &LocaleOverrides {
+        &self.overrides
+    }
+}
+
+// Python API - these methods are exposed to Python via PyO3
+#[pymethods]
+impl Faker {
+    /// Create a new Faker instance with the specified locale.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - The locale for generated data (default: "en_US")
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` if the locale is not supported.
+    #[new]
+    #[pyo3(signature = (locale = "en_US"))]
+    fn py_new(locale: &str) -> PyResult<Self> {
+        Self::new(locale).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Seed the random number generator for deterministic output.
+    #[pyo3(name = "seed")]
+    fn py_seed(&mut self, value: u64) {
+        self.seed(value);
+    }
+
+    /// Generate a batch of random full names.
+    #[pyo3(name = "names", signature = (n, unique=false))]
+    fn py_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.names(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random first names.
+    #[pyo3(name = "first_names", signature = (n, unique=false))]
+    fn py_first_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.first_names(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random last names.
+    #[pyo3(name = "last_names", signature = (n, unique=false))]
+    fn py_last_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.last_names(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random full name.
+    #[pyo3(name = "name")]
+    fn py_name(&mut self) -> String {
+        self.name()
+    }
+
+    /// Generate a single random first name.
+    #[pyo3(name = "first_name")]
+    fn py_first_name(&mut self) -> String {
+        self.first_name()
+    }
+
+    /// Generate a single random last name.
+    #[pyo3(name = "last_name")]
+    fn py_last_name(&mut self) -> String {
+        self.last_name()
+    }
+
+    /// Generate a batch of random romanized full names.
+    #[pyo3(name = "names_romanized", signature = (n, unique=false))]
+    fn py_names_romanized(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.names_romanized(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random romanized full name.
+    #[pyo3(name = "name_romanized")]
+    fn py_name_romanized(&mut self) -> String {
+        self.name_romanized()
+    }
+
+    /// Generate a batch of random romanized first names.
+    #[pyo3(name = "first_names_romanized", signature = (n, unique=false))]
+    fn py_first_names_romanized(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.first_names_romanized(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random romanized first name.
+    #[pyo3(name = "first_name_romanized")]
+    fn py_first_name_romanized(&mut self) -> String {
+        self.first_name_romanized()
+    }
+
+    /// Generate a batch of random romanized last names.
+    #[pyo3(name = "last_names_romanized", signature = (n, unique=false))]
+    fn py_last_names_romanized(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.last_names_romanized(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random romanized last name.
+    #[pyo3(name = "last_name_romanized")]
+    fn py_last_name_romanized(&mut self) -> String {
+        self.last_name_romanized()
+    }
+
+    /// Generate a batch of random name prefixes.
+    #[pyo3(name = "prefixes", signature = (n, unique=false))]
+    fn py_prefixes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.prefixes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random name prefix.
+    #[pyo3(name = "prefix")]
+    fn py_prefix(&mut self) -> String {
+        self.prefix()
+    }
+
+    /// Generate a batch of random name suffixes.
+    #[pyo3(name = "suffixes", signature = (n, unique=false))]
+    fn py_suffixes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.suffixes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random name suffix.
+    #[pyo3(name = "suffix")]
+    fn py_suffix(&mut self) -> String {
+        self.suffix()
+    }
+
+    /// Generate a batch of full names with a prefix.
+    #[pyo3(name = "names_with_prefix", signature = (n, unique=false))]
+    fn py_names_with_prefix(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.names_with_prefix(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single full name with a prefix.
+    #[pyo3(name = "name_with_prefix")]
+    fn py_name_with_prefix(&mut self) -> String {
+        self.name_with_prefix()
+    }
+
+    /// Generate a batch of full names from a format template.
+    #[pyo3(name = "full_names", signature = (n, full_name_format=None, unique=false))]
+    fn py_full_names(
+        &mut self,
+        n: usize,
+        full_name_format: Option<&str>,
+        unique: bool,
+    ) -> PyResult<Vec<String>> {
+        self.full_names(n, full_name_format, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single full name from a format template.
+    #[pyo3(name = "full_name", signature = (full_name_format=None))]
+    fn py_full_name(&mut self, full_name_format: Option<&str>) -> PyResult<String> {
+        self.full_name(full_name_format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random email addresses.
+    #[pyo3(
+        name = "emails",
+        signature = (n, unique=false, domain=None, style=None, leet=false, mixed_case=false)
+    )]
+    fn py_emails(
+        &mut self,
+        n: usize,
+        unique: bool,
+        domain: Option<&str>,
+        style: Option<&str>,
+        leet: bool,
+        mixed_case: bool,
+    ) -> PyResult<Vec<String>> {
+        self.emails(n, unique, domain, style, leet, mixed_case)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random email address.
+    #[pyo3(name = "email", signature = (domain=None, style=None, leet=false, mixed_case=false))]
+    fn py_email(
+        &mut self,
+        domain: Option<&str>,
+        style: Option<&str>,
+        leet: bool,
+        mixed_case: bool,
+    ) -> PyResult<String> {
+        self.email(domain, style, leet, mixed_case)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of usernames.
+    #[pyo3(
+        name = "usernames",
+        signature = (n, unique=false, style="any", leet=false, mixed_case=false)
+    )]
+    fn py_usernames(
+        &mut self,
+        n: usize,
+        unique: bool,
+        style: &str,
+        leet: bool,
+        mixed_case: bool,
+    ) -> PyResult<Vec<String>> {
+        self.usernames(n, unique, style, leet, mixed_case)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single username.
+    #[pyo3(name = "username", signature = (style="any", leet=false, mixed_case=false))]
+    fn py_username(&mut self, style: &str, leet: bool, mixed_case: bool) -> PyResult<String> {
+        self.username(style, leet, mixed_case)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of corporate email addresses.
+    #[pyo3(name = "company_emails", signature = (n, domain=None, company=None))]
+    fn py_company_emails(
+        &mut self,
+        n: usize,
+        domain: Option<&str>,
+        company: Option<&str>,
+    ) -> PyResult<Vec<String>> {
+        self.company_emails(n, domain, company)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single corporate email address.
+    #[pyo3(name = "company_email", signature = (domain=None, company=None))]
+    fn py_company_email(
+        &mut self,
+        domain: Option<&str>,
+        company: Option<&str>,
+    ) -> PyResult<String> {
+        self.company_email(domain, company)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random integers within a range.
+    #[pyo3(name = "integers", signature = (n, min = 0, max = 100))]
+    fn py_integers(&mut self, n: usize, min: i64, max: i64) -> PyResult<Vec<i64>> {
+        self.integers(n, min, max)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random integer within a range.
+    #[pyo3(name = "integer", signature = (min = 0, max = 100))]
+    fn py_integer(&mut self, min: i64, max: i64) -> PyResult<i64> {
+        self.integer(min, max)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random UUIDs (version 4).
+    #[pyo3(name = "uuids")]
+    fn py_uuids(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.uuids(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random UUID (version 4).
+    #[pyo3(name = "uuid")]
+    fn py_uuid(&mut self) -> String {
+        self.uuid()
+    }
+
+    /// Generate a batch of time-ordered UUIDs (version 7).
+    #[pyo3(name = "uuid7s", signature = (n, start="2020-01-01", end="2030-12-31"))]
+    fn py_uuid7s(&mut self, n: usize, start: &str, end: &str) -> PyResult<Vec<String>> {
+        self.uuid7s(n, start, end)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single UUID (version 7).
+    #[pyo3(name = "uuid7", signature = (start="2020-01-01", end="2030-12-31"))]
+    fn py_uuid7(&mut self, start: &str, end: &str) -> PyResult<String> {
+        self.uuid7(start, end)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Float Generation ===
+
+    /// Generate a batch of random floats within a range.
+    #[pyo3(name = "floats", signature = (n, min = 0.0, max = 1.0))]
+    fn py_floats(&mut self, n: usize, min: f64, max: f64) -> PyResult<Vec<f64>> {
+        self.floats(n, min, max)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random float within a range.
+    #[pyo3(name = "float", signature = (min = 0.0, max = 1.0))]
+    fn py_float(&mut self, min: f64, max: f64) -> PyResult<f64> {
+        self.float(min, max)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Hash Generation ===
+
+    /// Generate a batch of random MD5 hashes.
+    #[pyo3(name = "md5s")]
+    fn py_md5s(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.md5s(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random MD5 hash.
+    #[pyo3(name = "md5")]
+    fn py_md5(&mut self) -> String {
+        self.md5()
+    }
+
+    /// Generate a batch of random SHA1 hashes.
+    #[pyo3(name = "sha1s")]
+    fn py_sha1s(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.sha1s(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random SHA1 hash.
+    #[pyo3(name = "sha1")]
+    fn py_sha1(&mut self) -> String {
+        self.sha1()
+    }
+
+    /// Generate a batch of random SHA256 hashes.
+    #[pyo3(name = "sha256s")]
+    fn py_sha256s(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.sha256s(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random SHA256 hash.
+    #[pyo3(name = "sha256")]
+    fn py_sha256(&mut self) -> String {
+        self.sha256()
+    }
+
+    /// Generate a batch of random SHA512 hashes.
+    #[pyo3(name = "sha512s")]
+    fn py_sha512s(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.sha512s(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random SHA512 hash.
+    #[pyo3(name = "sha512")]
+    fn py_sha512(&mut self) -> String {
+        self.sha512()
+    }
+
+    // === Binary Generation ===
+
+    /// Generate a batch of random byte strings as Python bytes objects.
+    #[pyo3(name = "bytes", signature = (n, length = 16))]
+    fn py_bytes(&mut self, py: Python<'_>, n: usize, length: usize) -> PyResult<Vec<Py<PyBytes>>> {
+        let values = self
+            .bytes(n, length)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(values
+            .iter()
+            .map(|value| PyBytes::new(py, value).unbind())
+            .collect())
+    }
+
+    /// Generate a batch of base64 strings of random bytes.
+    #[pyo3(name = "base64", signature = (n, decoded_length = 32, url_safe = false))]
+    fn py_base64(
+        &mut self,
+        n: usize,
+        decoded_length: usize,
+        url_safe: bool,
+    ) -> PyResult<Vec<String>> {
+        self.base64(n, decoded_length, url_safe)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Color Generation ===
+
+    /// Generate a batch of random color names.
+    #[pyo3(name = "colors", signature = (n, unique=false))]
+    fn py_colors(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.colors(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random color name.
+    #[pyo3(name = "color")]
+    fn py_color(&mut self) -> String {
+        self.color()
+    }
+
+    /// Generate a batch of random hex colors.
+    #[pyo3(name = "hex_colors")]
+    fn py_hex_colors(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.hex_colors(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random hex color.
+    #[pyo3(name = "hex_color")]
+    fn py_hex_color(&mut self) -> String {
+        self.hex_color()
+    }
+
+    /// Generate a batch of random RGB color tuples.
+    #[pyo3(name = "rgb_colors")]
+    fn py_rgb_colors(&mut self, n: usize) -> PyResult<Vec<(u8, u8, u8)>> {
+        self.rgb_colors(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random RGB color tuple.
+    #[pyo3(name = "rgb_color")]
+    fn py_rgb_color(&mut self) -> (u8, u8, u8) {
+        self.rgb_color()
+    }
+
+    /// Generate a batch of random HSL color tuples.
+    #[pyo3(name = "hsl_colors")]
+    fn py_hsl_colors(&mut self, n: usize) -> PyResult<Vec<(u16, u8, u8)>> {
+        self.hsl_colors(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random HSL color tuple.
+    #[pyo3(name = "hsl_color")]
+    fn py_hsl_color(&mut self) -> (u16, u8, u8) {
+        self.hsl_color()
+    }
+
+    /// Generate a batch of random CMYK color tuples.
+    #[pyo3(name = "cmyk_colors")]
+    fn py_cmyk_colors(&mut self, n: usize) -> PyResult<Vec<(u8, u8, u8, u8)>> {
+        self.cmyk_colors(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random CMYK color tuple.
+    #[pyo3(name = "cmyk_color")]
+    fn py_cmyk_color(&mut self) -> (u8, u8, u8, u8) {
+        self.cmyk_color()
+    }
+
+    /// Generate a batch of CSS color strings.
+    #[pyo3(name = "css_colors", signature = (n, format=None))]
+    fn py_css_colors(&mut self, n: usize, format: Option<&str>) -> PyResult<Vec<String>> {
+        self.css_colors(n, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single CSS color string.
+    #[pyo3(name = "css_color", signature = (format=None))]
+    fn py_css_color(&mut self, format: Option<&str>) -> PyResult<String> {
+        self.css_color(format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of color palettes.
+    #[pyo3(name = "color_palettes", signature = (n, size=5, scheme="analogous"))]
+    fn py_color_palettes(
+        &mut self,
+        n: usize,
+        size: usize,
+        scheme: &str,
+    ) -> PyResult<Vec<Vec<String>>> {
+        self.color_palettes(n, size, scheme)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single color palette.
+    #[pyo3(name = "color_palette", signature = (size=5, scheme="analogous"))]
+    fn py_color_palette(&mut self, size: usize, scheme: &str) -> PyResult<Vec<String>> {
+        self.color_palette(size, scheme)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === DateTime Generation ===
+
+    /// Generate a batch of random dates within a range.
+    #[pyo3(name = "dates", signature = (n, start = "2000-01-01", end = "2030-12-31", format = None))]
+    fn py_dates(
+        &mut self,
+        n: usize,
+        start: &str,
+        end: &str,
+        format: Option<&str>,
+    ) -> PyResult<Vec<String>> {
+        self.dates(n, start, end, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random date within a range.
+    #[pyo3(name = "date", signature = (start = "2000-01-01", end = "2030-12-31", format = None))]
+    fn py_date(&mut self, start: &str, end: &str, format: Option<&str>) -> PyResult<String> {
+        self.date(start, end, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random business dates within a range.
+    #[pyo3(
+        name = "business_dates",
+        signature = (n, start = "2000-01-01", end = "2030-12-31", holidays = None)
+    )]
+    fn py_business_dates(
+        &mut self,
+        n: usize,
+        start: &str,
+        end: &str,
+        holidays: Option<Vec<String>>,
+    ) -> PyResult<Vec<String>> {
+        self.business_dates(n, start, end, holidays.as_deref().unwrap_or_default())
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random business date within a range.
+    #[pyo3(
+        name = "business_date",
+        signature = (start = "2000-01-01", end = "2030-12-31", holidays = None)
+    )]
+    fn py_business_date(
+        &mut self,
+        start: &str,
+        end: &str,
+        holidays: Option<Vec<String>>,
+    ) -> PyResult<String> {
+        self.business_date(start, end, holidays.as_deref().unwrap_or_default())
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of ISO 8601 weeks within a date range.
+    #[pyo3(name = "iso_weeks", signature = (n, start = "2000-01-01", end = "2030-12-31"))]
+    fn py_iso_weeks(&mut self, n: usize, start: &str, end: &str) -> PyResult<Vec<String>> {
+        self.iso_weeks(n, start, end)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single ISO 8601 week within a date range.
+    #[pyo3(name = "iso_week", signature = (start = "2000-01-01", end = "2030-12-31"))]
+    fn py_iso_week(&mut self, start: &str, end: &str) -> PyResult<String> {
+        self.iso_week(start, end)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of calendar quarters within a date range.
+    #[pyo3(name = "quarters", signature = (n, start = "2000-01-01", end = "2030-12-31"))]
+    fn py_quarters(&mut self, n: usize, start: &str, end: &str) -> PyResult<Vec<String>> {
+        self.quarters(n, start, end)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single calendar quarter within a date range.
+    #[pyo3(name = "quarter", signature = (start = "2000-01-01", end = "2030-12-31"))]
+    fn py_quarter(&mut self, start: &str, end: &str) -> PyResult<String> {
+        self.quarter(start, end)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of calendar months within a date range.
+    #[pyo3(name = "months", signature = (n, start = "2000-01-01", end = "2030-12-31"))]
+    fn py_months(&mut self, n: usize, start: &str, end: &str) -> PyResult<Vec<String>> {
+        self.months(n, start, end)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single calendar month within a date range.
+    #[pyo3(name = "month", signature = (start = "2000-01-01", end = "2030-12-31"))]
+    fn py_month(&mut self, start: &str, end: &str) -> PyResult<String> {
+        self.month(start, end)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of durations, ISO 8601 strings by default.
+    #[pyo3(name = "durations", signature = (n, min=0, max=86_400, format="iso8601"))]
+    fn py_durations(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        min: u64,
+        max: u64,
+        format: &str,
+    ) -> PyResult<Py<PyAny>> {
+        if format.eq_ignore_ascii_case("seconds") {
+            self.durations(n, min, max)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+                .into_py_any(py)
+        } else {
+            self.formatted_durations(n, min, max, format)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+                .into_py_any(py)
+        }
+    }
+
+    /// Generate a single duration, an ISO 8601 string by default.
+    #[pyo3(name = "duration", signature = (min=0, max=86_400, format="iso8601"))]
+    fn py_duration(
+        &mut self,
+        py: Python<'_>,
+        min: u64,
+        max: u64,
+        format: &str,
+    ) -> PyResult<Py<PyAny>> {
+        if format.eq_ignore_ascii_case("seconds") {
+            self.duration(min, max)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+                .into_py_any(py)
+        } else {
+            self.formatted_duration(min, max, format)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+                .into_py_any(py)
+        }
+    }
+
+    /// Generate a batch of random dates of birth.
+    #[pyo3(name = "dates_of_birth", signature = (n, min_age = 18, max_age = 80))]
+    fn py_dates_of_birth(&mut self, n: usize, min_age: u32, max_age: u32) -> PyResult<Vec<String>> {
+        self.dates_of_birth(n, min_age, max_age)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random date of birth.
+    #[pyo3(name = "date_of_birth", signature = (min_age = 18, max_age = 80))]
+    fn py_date_of_birth(&mut self, min_age: u32, max_age: u32) -> PyResult<String> {
+        self.date_of_birth(min_age, max_age)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of (date_of_birth, age) pairs.
+    #[pyo3(name = "dates_of_birth_with_age", signature = (n, min_age = 18, max_age = 80))]
+    fn py_dates_of_birth_with_age(
+        &mut self,
+        n: usize,
+        min_age: u32,
+        max_age: u32,
+    ) -> PyResult<Vec<(String, u32)>> {
+        self.dates_of_birth_with_age(n, min_age, max_age)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single (date_of_birth, age) pair.
+    #[pyo3(name = "date_of_birth_with_age", signature = (min_age = 18, max_age = 80))]
+    fn py_date_of_birth_with_age(&mut self, min_age: u32, max_age: u32) -> PyResult<(String, u32)> {
+        self.date_of_birth_with_age(min_age, max_age)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random datetimes within a range.
+    #[pyo3(name = "datetimes", signature = (n, start = "2000-01-01", end = "2030-12-31", format = None))]
+    fn py_datetimes(
+        &mut self,
+        n: usize,
+        start: &str,
+        end: &str,
+        format: Option<&str>,
+    ) -> PyResult<Vec<String>> {
+        self.datetimes(n, start, end, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random datetime within a range.
+    #[pyo3(name = "datetime", signature = (start = "2000-01-01", end = "2030-12-31", format = None))]
+    fn py_datetime(&mut self, start: &str, end: &str, format: Option<&str>) -> PyResult<String> {
+        self.datetime(start, end, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random times of day.
+    #[pyo3(name = "times", signature = (n, format = "%H:%M:%S"))]
+    fn py_times(&mut self, n: usize, format: &str) -> PyResult<Vec<String>> {
+        self.times(n, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random time of day.
+    #[pyo3(name = "time", signature = (format = "%H:%M:%S"))]
+    fn py_time(&mut self, format: &str) -> PyResult<String> {
+        self.time(format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of datetimes from the last `days` days.
+    #[pyo3(name = "recent_datetimes", signature = (n, days = 7))]
+    fn py_recent_datetimes(&mut self, n: usize, days: u32) -> PyResult<Vec<String>> {
+        self.recent_datetimes(n, days)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single datetime from the last `days` days.
+    #[pyo3(name = "recent", signature = (days = 7))]
+    fn py_recent(&mut self, days: u32) -> PyResult<String> {
+        self.recent(days)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of datetimes from the last `years` years.
+    #[pyo3(name = "past_datetimes", signature = (n, years = 5))]
+    fn py_past_datetimes(&mut self, n: usize, years: u32) -> PyResult<Vec<String>> {
+        self.past_datetimes(n, years)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single datetime from the last `years` years.
+    #[pyo3(name = "past", signature = (years = 5))]
+    fn py_past(&mut self, years: u32) -> PyResult<String> {
+        self.past(years)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of datetimes from now through the next `years` years.
+    #[pyo3(name = "future_datetimes", signature = (n, years = 1))]
+    fn py_future_datetimes(&mut self, n: usize, years: u32) -> PyResult<Vec<String>> {
+        self.future_datetimes(n, years)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single datetime from now through the next `years` years.
+    #[pyo3(name = "future", signature = (years = 1))]
+    fn py_future(&mut self, years: u32) -> PyResult<String> {
+        self.future(years)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a time series as a PyArrow RecordBatch.
+    #[pyo3(
+        name = "time_series",
+        signature = (
+            n,
+            start,
+            end,
+            freq = "1h",
+            value = "random_walk",
+            jitter = 1.0,
+            drift = 0.0,
+            base = 100.0,
+            amplitude = 10.0,
+            period = "1d"
+        )
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn py_time_series(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        start: &str,
+        end: &str,
+        freq: &str,
+        value: &str,
+        jitter: f64,
+        drift: f64,
+        base: f64,
+        amplitude: f64,
+        period: &str,
+    ) -> PyResult<Py<PyAny>> {
+        let model = value
+            .parse()
+            .map_err(|e: providers::timeseries::TimeSeriesError| {
+                PyValueError::new_err(e.to_string())
+            })?;
+        let options = providers::timeseries::TimeSeriesOptions {
+            freq,
+            model,
+            base,
+            drift,
+            jitter,
+            amplitude,
+            period,
+        };
+        let batch = self
+            .time_series(n, start, end, &options)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        PyRecordBatch::new(batch)
+            .into_pyarrow(py)
+            .map(|bound| bound.unbind())
+    }
+
+    /// Generate IoT sensor readings as dicts.
+    #[pyo3(
+        name = "sensor_readings",
+        signature = (n, start, end, sensors = providers::timeseries::DEFAULT_SENSORS, metrics = None)
+    )]
+    fn py_sensor_readings(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        start: &str,
+        end: &str,
+        sensors: usize,
+        metrics: Option<Vec<String>>,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let readings = self
+            .sensor_readings(
+                n,
+                start,
+                end,
+                sensors,
+                metrics.as_deref().unwrap_or_default(),
+            )
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        readings
+            .into_iter()
+            .map(|r| sensor_reading_to_pydict(py, r)?.into_py_any(py))
+            .collect()
+    }
+
+    /// Generate IoT sensor readings as a PyArrow RecordBatch.
+    #[pyo3(
+        name = "sensor_readings_arrow",
+        signature = (n, start, end, sensors = providers::timeseries::DEFAULT_SENSORS, metrics = None)
+    )]
+    fn py_sensor_readings_arrow(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        start: &str,
+        end: &str,
+        sensors: usize,
+        metrics: Option<Vec<String>>,
+    ) -> PyResult<Py<PyAny>> {
+        let batch = self
+            .sensor_readings_arrow(
+                n,
+                start,
+                end,
+                sensors,
+                metrics.as_deref().unwrap_or_default(),
+            )
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        PyRecordBatch::new(batch)
+            .into_pyarrow(py)
+            .map(|bound| bound.unbind())
+    }
+
+    // === Text Generation ===
+
+    /// Generate a batch of word lists.
+    #[pyo3(name = "words", signature = (n, count = 3))]
+    fn py_words(&mut self, n: usize, count: usize) -> PyResult<Vec<Vec<String>>> {
+        self.words(n, count)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single raw lorem word.
+    #[pyo3(name = "word")]
+    fn py_word(&mut self) -> String {
+        self.word()
+    }
+
+    /// Generate a batch of random sentences.
+    #[pyo3(name = "sentences", signature = (n, word_count = None, comma_rate = 0.0))]
+    fn py_sentences(
+        &mut self,
+        n: usize,
+        word_count: Option<&Bound<'_, PyAny>>,
+        comma_rate: f64,
+    ) -> PyResult<Vec<String>> {
+        let word_count = extract_count_range(word_count, "word_count", (10, 10))?;
+        self.sentences(n, word_count, comma_rate)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random sentence.
+    #[pyo3(name = "sentence", signature = (word_count = None, comma_rate = 0.0))]
+    fn py_sentence(
+        &mut self,
+        word_count: Option<&Bound<'_, PyAny>>,
+        comma_rate: f64,
+    ) -> PyResult<String> {
+        let word_count = extract_count_range(word_count, "word_count", (10, 10))?;
+        self.sentence(word_count, comma_rate)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random paragraphs.
+    #[pyo3(
+        name = "paragraphs",
+        signature = (n, sentence_count = None, word_count = None, comma_rate = 0.0)
+    )]
+    fn py_paragraphs(
+        &mut self,
+        n: usize,
+        sentence_count: Option<&Bound<'_, PyAny>>,
+        word_count: Option<&Bound<'_, PyAny>>,
+        comma_rate: f64,
+    ) -> PyResult<Vec<String>> {
+        let sentence_count = extract_count_range(sentence_count, "sentence_count", (5, 5))?;
+        let word_count = extract_count_range(word_count, "word_count", (5, 15))?;
+        self.paragraphs(n, sentence_count, word_count, comma_rate)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random paragraph.
+    #[pyo3(
+        name = "paragraph",
+        signature = (sentence_count = None, word_count = None, comma_rate = 0.0)
+    )]
+    fn py_paragraph(
+        &mut self,
+        sentence_count: Option<&Bound<'_, PyAny>>,
+        word_count: Option<&Bound<'_, PyAny>>,
+        comma_rate: f64,
+    ) -> PyResult<String> {
+        let sentence_count = extract_count_range(sentence_count, "sentence_count", (5, 5))?;
+        let word_count = extract_count_range(word_count, "word_count", (5, 15))?;
+        self.paragraph(sentence_count, word_count, comma_rate)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random text blocks.
+    #[pyo3(name = "texts", signature = (n, min_chars = 50, max_chars = 200))]
+    fn py_texts(&mut self, n: usize, min_chars: usize, max_chars: usize) -> PyResult<Vec<String>> {
+        self.texts(n, min_chars, max_chars)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random text block.
+    #[pyo3(name = "text", signature = (min_chars = 50, max_chars = 200))]
+    fn py_text(&mut self, min_chars: usize, max_chars: usize) -> String {
+        self.text(min_chars, max_chars)
+    }
+
+    /// Generate a batch of Markdown documents.
+    #[pyo3(name = "markdowns", signature = (n, paragraphs = 3))]
+    fn py_markdowns(&mut self, n: usize, paragraphs: usize) -> PyResult<Vec<String>> {
+        self.markdowns(n, paragraphs)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single Markdown document.
+    #[pyo3(name = "markdown", signature = (paragraphs = 3))]
+    fn py_markdown(&mut self, paragraphs: usize) -> String {
+        self.markdown(paragraphs)
+    }
+
+    /// Generate a batch of HTML fragments.
+    #[pyo3(name = "htmls", signature = (n, elements = 5))]
+    fn py_htmls(&mut self, n: usize, elements: usize) -> PyResult<Vec<String>> {
+        self.htmls(n, elements)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single HTML fragment.
+    #[pyo3(name = "html", signature = (elements = 5))]
+    fn py_html(&mut self, elements: usize) -> String {
+        self.html(elements)
+    }
+
+    // === Address Generation ===
+
+    /// Generate a batch of random street addresses.
+    #[pyo3(name = "street_addresses", signature = (n, unique=false))]
+    fn py_street_addresses(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.street_addresses(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random street address.
+    #[pyo3(name = "street_address")]
+    fn py_street_address(&mut self) -> String {
+        self.street_address()
+    }
+
+    /// Generate a batch of random cities.
+    #[pyo3(name = "cities", signature = (n, unique=false))]
+    fn py_cities(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.cities(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random city.
+    #[pyo3(name = "city")]
+    fn py_city(&mut self) -> String {
+        self.city()
+    }
+
+    /// Generate a batch of random states.
+    #[pyo3(name = "states", signature = (n, unique=false))]
+    fn py_states(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.states(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random state.
+    #[pyo3(name = "state")]
+    fn py_state(&mut self) -> String {
+        self.state()
+    }
+
+    /// Generate a batch of random state/region abbreviations.
+    #[pyo3(name = "state_abbrs", signature = (n, unique=false))]
+    fn py_state_abbrs(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.state_abbrs(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random state/region abbreviation.
+    #[pyo3(name = "state_abbr")]
+    fn py_state_abbr(&mut self) -> String {
+        self.state_abbr()
+    }
+
+    /// Generate a batch of street names.
+    #[pyo3(name = "street_names", signature = (n, unique=false))]
+    fn py_street_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.street_names(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single street name.
+    #[pyo3(name = "street_name")]
+    fn py_street_name(&mut self) -> String {
+        self.street_name()
+    }
+
+    /// Generate a batch of street types.
+    #[pyo3(name = "street_suffixes", signature = (n, unique=false))]
+    fn py_street_suffixes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.street_suffixes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single street type.
+    #[pyo3(name = "street_suffix")]
+    fn py_street_suffix(&mut self) -> String {
+        self.street_suffix()
+    }
+
+    /// Generate a batch of building numbers.
+    #[pyo3(name = "building_numbers", signature = (n, unique=false))]
+    fn py_building_numbers(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.building_numbers(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single building number.
+    #[pyo3(name = "building_number")]
+    fn py_building_number(&mut self) -> String {
+        self.building_number()
+    }
+
+    /// Generate a batch of random countries.
+    #[pyo3(name = "countries", signature = (n, unique=false, format=None))]
+    fn py_countries(
+        &mut self,
+        n: usize,
+        unique: bool,
+        format: Option<&str>,
+    ) -> PyResult<Vec<String>> {
+        self.countries(n, unique, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random country.
+    #[pyo3(name = "country", signature = (format=None))]
+    fn py_country(&mut self, format: Option<&str>) -> PyResult<String> {
+        self.country(format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of ISO 3166-1 country codes.
+    #[pyo3(name = "country_codes", signature = (n, unique=false, format=None))]
+    fn py_country_codes(
+        &mut self,
+        n: usize,
+        unique: bool,
+        format: Option<&str>,
+    ) -> PyResult<Vec<String>> {
+        self.country_codes(n, unique, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single ISO 3166-1 country code.
+    #[pyo3(name = "country_code", signature = (format=None))]
+    fn py_country_code(&mut self, format: Option<&str>) -> PyResult<String> {
+        self.country_code(format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random zip codes.
+    #[pyo3(name = "zip_codes", signature = (n, unique=false))]
+    fn py_zip_codes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.zip_codes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random zip code.
+    #[pyo3(name = "zip_code")]
+    fn py_zip_code(&mut self) -> String {
+        self.zip_code()
+    }
+
+    /// Generate a batch of random full addresses.
+    #[pyo3(name = "addresses", signature = (n, unique=false, unit_rate=0.0, po_box_rate=0.0))]
+    fn py_addresses(
+        &mut self,
+        n: usize,
+        unique: bool,
+        unit_rate: f64,
+        po_box_rate: f64,
+    ) -> PyResult<Vec<String>> {
+        let options = providers::address::AddressOptions {
+            unit_rate,
+            po_box_rate,
+        };
+        self.addresses(n, unique, &options)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random full address.
+    #[pyo3(name = "address", signature = (unit_rate=0.0, po_box_rate=0.0))]
+    fn py_address(&mut self, unit_rate: f64, po_box_rate: f64) -> PyResult<String> {
+        let options = providers::address::AddressOptions {
+            unit_rate,
+            po_box_rate,
+        };
+        self.address(&options)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of US counties.
+    #[pyo3(name = "counties", signature = (n, state=None, with_state=false))]
+    fn py_counties(
+        &mut self,
+        n: usize,
+        state: Option<&str>,
+        with_state: bool,
+    ) -> PyResult<Vec<String>> {
+        self.counties(n, state, with_state)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single US county.
+    #[pyo3(name = "county", signature = (state=None, with_state=false))]
+    fn py_county(&mut self, state: Option<&str>, with_state: bool) -> PyResult<String> {
+        self.county(state, with_state)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of addresses as dictionaries of components.
+    #[pyo3(name = "address_parts_batch", signature = (n, unit_rate=0.0))]
+    fn py_address_parts_batch(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        unit_rate: f64,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let parts = self
+            .address_parts_batch(n, unit_rate)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        parts
+            .into_iter()
+            .map(|p| address_parts_to_pydict(py, p)?.into_py_any(py))
+            .collect()
+    }
+
+    /// Generate a single address as a dictionary of components.
+    #[pyo3(name = "address_parts", signature = (unit_rate=0.0))]
+    fn py_address_parts(&mut self, py: Python<'_>, unit_rate: f64) -> PyResult<Py<PyAny>> {
+        let parts = self
+            .address_parts(unit_rate)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        address_parts_to_pydict(py, parts)?.into_py_any(py)
+    }
+
+    /// Generate a batch of secondary address units.
+    #[pyo3(name = "secondary_units")]
+    fn py_secondary_units(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.secondary_units(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single secondary address unit.
+    #[pyo3(name = "secondary_unit")]
+    fn py_secondary_unit(&mut self) -> String {
+        self.secondary_unit()
+    }
+
+    /// Generate a batch of PO Box lines.
+    #[pyo3(name = "po_boxes")]
+    fn py_po_boxes(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.po_boxes(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single PO Box line.
+    #[pyo3(name = "po_box")]
+    fn py_po_box(&mut self) -> String {
+        self.po_box()
+    }
+
+    // === Geographic Generation ===
+
+    /// Generate a batch of latitudes in decimal degrees.
+    #[pyo3(name = "latitudes", signature = (n, precision=6))]
+    fn py_latitudes(&mut self, n: usize, precision: u32) -> PyResult<Vec<f64>> {
+        self.latitudes(n, precision)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single latitude in decimal degrees.
+    #[pyo3(name = "latitude", signature = (precision=6))]
+    fn py_latitude(&mut self, precision: u32) -> f64 {
+        self.latitude(precision)
+    }
+
+    /// Generate a batch of longitudes in decimal degrees.
+    #[pyo3(name = "longitudes", signature = (n, precision=6))]
+    fn py_longitudes(&mut self, n: usize, precision: u32) -> PyResult<Vec<f64>> {
+        self.longitudes(n, precision)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single longitude in decimal degrees.
+    #[pyo3(name = "longitude", signature = (precision=6))]
+    fn py_longitude(&mut self, precision: u32) -> f64 {
+        self.longitude(precision)
+    }
+
+    /// Generate a batch of (latitude, longitude) pairs.
+    #[pyo3(name = "coordinates", signature = (n, precision=6))]
+    fn py_coordinates(&mut self, n: usize, precision: u32) -> PyResult<Vec<(f64, f64)>> {
+        self.coordinates(n, precision)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single (latitude, longitude) pair.
+    #[pyo3(name = "coordinate", signature = (precision=6))]
+    fn py_coordinate(&mut self, precision: u32) -> (f64, f64) {
+        self.coordinate(precision)
+    }
+
+    /// Generate a batch of (latitude, longitude) pairs inside a bounding box.
+    #[pyo3(
+        name = "coordinates_in_bbox",
+        signature = (n, min_lat, max_lat, min_lon, max_lon, precision=6)
+    )]
+    fn py_coordinates_in_bbox(
+        &mut self,
+        n: usize,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+        precision: u32,
+    ) -> PyResult<Vec<(f64, f64)>> {
+        self.coordinates_in_bbox(n, min_lat, max_lat, min_lon, max_lon, precision)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single (latitude, longitude) pair inside a bounding box.
+    #[pyo3(
+        name = "coordinate_in_bbox",
+        signature = (min_lat, max_lat, min_lon, max_lon, precision=6)
+    )]
+    fn py_coordinate_in_bbox(
+        &mut self,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+        precision: u32,
+    ) -> PyResult<(f64, f64)> {
+        self.coordinate_in_bbox(min_lat, max_lat, min_lon, max_lon, precision)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of (latitude, longitude) pairs within a country.
+    #[pyo3(name = "coordinates_in_country", signature = (n, country, precision=6))]
+    fn py_coordinates_in_country(
+        &mut self,
+        n: usize,
+        country: &str,
+        precision: u32,
+    ) -> PyResult<Vec<(f64, f64)>> {
+        self.coordinates_in_country(n, country, precision)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single (latitude, longitude) pair within a country.
+    #[pyo3(name = "coordinate_in_country", signature = (country, precision=6))]
+    fn py_coordinate_in_country(&mut self, country: &str, precision: u32) -> PyResult<(f64, f64)> {
+        self.coordinate_in_country(country, precision)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a GPS track as a list of dicts.
+    #[pyo3(
+        name = "gps_track",
+        signature = (points, start_coord=None, max_speed_kmh=providers::geo::DEFAULT_MAX_SPEED_KMH)
+    )]
+    fn py_gps_track(
+        &mut self,
+        py: Python<'_>,
+        points: usize,
+        start_coord: Option<(f64, f64)>,
+        max_speed_kmh: f64,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let track = self
+            .gps_track(points, start_coord, max_speed_kmh)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        track
+            .into_iter()
+            .map(|p| gps_point_to_pydict(py, p)?.into_py_any(py))
+            .collect()
+    }
+
+    // === Phone Generation ===
+
+    /// Generate a batch of random phone numbers.
+    #[pyo3(
+        name = "phone_numbers",
+        signature = (n, unique=false, format="formatted", kind="any", safe=false)
+    )]
+    fn py_phone_numbers(
+        &mut self,
+        n: usize,
+        unique: bool,
+        format: &str,
+        kind: &str,
+        safe: bool,
+    ) -> PyResult<Vec<String>> {
+        self.phone_numbers(n, unique, format, kind, safe)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random phone number.
+    #[pyo3(name = "phone_number", signature = (format="formatted", kind="any", safe=false))]
+    fn py_phone_number(&mut self, format: &str, kind: &str, safe: bool) -> PyResult<String> {
+        self.phone_number(format, kind, safe)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Profile Generation ===
+
+    /// Generate a batch of person profiles.
+    ///
+    /// Returns:
+    ///     List of dicts with keys: name, first_name, last_name, gender,
+    ///     date_of_birth, age, email, phone, street_address, city, state,
+    ///     postal_code, address
+    #[pyo3(name = "profiles", signature = (n, min_age = 18, max_age = 80))]
+    fn py_profiles(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        min_age: u32,
+        max_age: u32,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let profiles = self
+            .profiles(n, min_age, max_age)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        profiles
+            .into_iter()
+            .map(|p| profile_to_pydict(py, p)?.into_py_any(py))
+            .collect()
+    }
+
+    /// Generate a single person profile as a dict.
+    #[pyo3(name = "profile", signature = (min_age = 18, max_age = 80))]
+    fn py_profile(&mut self, py: Python<'_>, min_age: u32, max_age: u32) -> PyResult<Py<PyAny>> {
+        let profile = self
+            .profile(min_age, max_age)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        profile_to_pydict(py, profile)?.into_py_any(py)
+    }
+
+    // === Demographic Generation ===
+
+    /// Generate a batch of ages in whole years.
+    #[pyo3(name = "ages", signature = (n, min = 18, max = 80))]
+    fn py_ages(&mut self, n: usize, min: u32, max: u32) -> PyResult<Vec<u32>> {
+        self.ages(n, min, max)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single age in whole years.
+    #[pyo3(name = "age", signature = (min = 18, max = 80))]
+    fn py_age(&mut self, min: u32, max: u32) -> PyResult<u32> {
+        self.age(min, max)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of genders, optionally with custom weights.
+    #[pyo3(name = "genders", signature = (n, weights=None))]
+    fn py_genders(
+        &mut self,
+        n: usize,
+        weights: Option<HashMap<String, u32>>,
+    ) -> PyResult<Vec<String>> {
+        self.genders(n, &weight_pairs(weights))
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single gender, optionally with custom weights.
+    #[pyo3(name = "gender", signature = (weights=None))]
+    fn py_gender(&mut self, weights: Option<HashMap<String, u32>>) -> PyResult<String> {
+        self.gender(&weight_pairs(weights))
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of pronoun sets, optionally with custom weights.
+    #[pyo3(name = "pronouns", signature = (n, weights=None))]
+    fn py_pronouns(
+        &mut self,
+        n: usize,
+        weights: Option<HashMap<String, u32>>,
+    ) -> PyResult<Vec<String>> {
+        self.pronouns(n, &weight_pairs(weights))
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single pronoun set, optionally with custom weights.
+    #[pyo3(name = "pronoun", signature = (weights=None))]
+    fn py_pronoun(&mut self, weights: Option<HashMap<String, u32>>) -> PyResult<String> {
+        self.pronoun(&weight_pairs(weights))
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of marital statuses, optionally with custom weights.
+    #[pyo3(name = "marital_statuses", signature = (n, weights=None))]
+    fn py_marital_statuses(
+        &mut self,
+        n: usize,
+        weights: Option<HashMap<String, u32>>,
+    ) -> PyResult<Vec<String>> {
+        self.marital_statuses(n, &weight_pairs(weights))
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single marital status, optionally with custom weights.
+    #[pyo3(name = "marital_status", signature = (weights=None))]
+    fn py_marital_status(&mut self, weights: Option<HashMap<String, u32>>) -> PyResult<String> {
+        self.marital_status(&weight_pairs(weights))
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === National ID Generation ===
+
+    /// Generate a batch of national ID numbers for the current locale.
+    #[pyo3(name = "national_ids", signature = (n, unique=false))]
+    fn py_national_ids(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.national_ids(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single national ID number for the current locale.
+    #[pyo3(name = "national_id")]
+    fn py_national_id(&mut self) -> String {
+        self.national_id()
+    }
+
+    /// Generate a batch of driver's license numbers.
+    #[pyo3(name = "drivers_licenses", signature = (n, region=None))]
+    fn py_drivers_licenses(&mut self, n: usize, region: Option<&str>) -> PyResult<Vec<String>> {
+        self.drivers_licenses(n, region)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single driver's license number.
+    #[pyo3(name = "drivers_license", signature = (region=None))]
+    fn py_drivers_license(&mut self, region: Option<&str>) -> PyResult<String> {
+        self.drivers_license(region)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Company Generation ===
+
+    /// Generate a batch of random company names.
+    #[pyo3(name = "companies", signature = (n, unique=false))]
+    fn py_companies(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.companies(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random company name.
+    #[pyo3(name = "company")]
+    fn py_company(&mut self) -> String {
+        self.company()
+    }
+
+    /// Generate a batch of random job titles.
+    #[pyo3(name = "jobs", signature = (n, unique=false, level=None))]
+    fn py_jobs(&mut self, n: usize, unique: bool, level: Option<&str>) -> PyResult<Vec<String>> {
+        self.jobs(n, unique, level)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random job title.
+    #[pyo3(name = "job", signature = (level=None))]
+    fn py_job(&mut self, level: Option<&str>) -> PyResult<String> {
+        self.job(level)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of job levels, weighted like an organization chart.
+    #[pyo3(name = "job_levels", signature = (n, unique=false))]
+    fn py_job_levels(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.job_levels(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single job level (e.g. "senior").
+    #[pyo3(name = "job_level")]
+    fn py_job_level(&mut self) -> String {
+        self.job_level()
+    }
+
+    /// Generate a batch of job areas.
+    #[pyo3(name = "job_areas", signature = (n, unique=false))]
+    fn py_job_areas(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.job_areas(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single job area (e.g. "Data").
+    #[pyo3(name = "job_area")]
+    fn py_job_area(&mut self) -> String {
+        self.job_area()
+    }
+
+    /// Generate a batch of job roles.
+    #[pyo3(name = "job_roles", signature = (n, unique=false))]
+    fn py_job_roles(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.job_roles(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single job role (e.g. "Scientist").
+    #[pyo3(name = "job_role")]
+    fn py_job_role(&mut self) -> String {
+        self.job_role()
+    }
+
+    /// Generate a batch of random catch phrases.
+    #[pyo3(name = "catch_phrases", signature = (n, unique=false, style="basic"))]
+    fn py_catch_phrases(&mut self, n: usize, unique: bool, style: &str) -> PyResult<Vec<String>> {
+        self.catch_phrases(n, unique, style)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random catch phrase.
+    #[pyo3(name = "catch_phrase", signature = (style="basic"))]
+    fn py_catch_phrase(&mut self, style: &str) -> PyResult<String> {
+        self.catch_phrase(style)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of department names.
+    #[pyo3(name = "departments", signature = (n, unique=false))]
+    fn py_departments(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.departments(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single department name.
+    #[pyo3(name = "department")]
+    fn py_department(&mut self) -> String {
+        self.department()
+    }
+
+    /// Generate a batch of industry names.
+    #[pyo3(name = "industries", signature = (n, unique=false))]
+    fn py_industries(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.industries(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single industry name.
+    #[pyo3(name = "industry")]
+    fn py_industry(&mut self) -> String {
+        self.industry()
+    }
+
+    /// Generate a batch of six-digit NAICS codes.
+    #[pyo3(name = "naics_codes", signature = (n, unique=false))]
+    fn py_naics_codes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.naics_codes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single six-digit NAICS code.
+    #[pyo3(name = "naics_code")]
+    fn py_naics_code(&mut self) -> String {
+        self.naics_code()
+    }
+
+    /// Generate a batch of four-digit SIC codes.
+    #[pyo3(name = "sic_codes", signature = (n, unique=false))]
+    fn py_sic_codes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.sic_codes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single four-digit SIC code.
+    #[pyo3(name = "sic_code")]
+    fn py_sic_code(&mut self) -> String {
+        self.sic_code()
+    }
+
+    /// Generate a batch of company profiles.
+    ///
+    /// Returns:
+    ///     List of dicts with keys: name, legal_form, industry, tax_id,
+    ///     domain, address, catch_phrase
+    #[pyo3(name = "company_profiles")]
+    fn py_company_profiles(&mut self, py: Python<'_>, n: usize) -> PyResult<Vec<Py<PyAny>>> {
+        let profiles = self
+            .company_profiles(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        profiles
+            .into_iter()
+            .map(|p| company_profile_to_pydict(py, p)?.into_py_any(py))
+            .collect()
+    }
+
+    /// Generate a single company profile as a dict.
+    #[pyo3(name = "company_profile")]
+    fn py_company_profile(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let profile = self.company_profile();
+        company_profile_to_pydict(py, profile)?.into_py_any(py)
+    }
+
+    /// Generate a batch of US Employer Identification Numbers.
+    #[pyo3(name = "eins", signature = (n, unique=false))]
+    fn py_eins(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.eins(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single US Employer Identification Number.
+    #[pyo3(name = "ein")]
+    fn py_ein(&mut self) -> String {
+        self.ein()
+    }
+
+    /// Generate a batch of company tax IDs for the current locale.
+    #[pyo3(name = "company_tax_ids", signature = (n, unique=false))]
+    fn py_company_tax_ids(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.company_tax_ids(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single company tax ID for the current locale.
+    #[pyo3(name = "company_tax_id")]
+    fn py_company_tax_id(&mut self) -> String {
+        self.company_tax_id()
+    }
+
+    // === Network Generation ===
+
+    /// Generate a batch of random URLs.
+    #[pyo3(
+        name = "urls",
+        signature = (n, scheme="https", path_depth=None, query_params=0, fragment=false, extension=None)
+    )]
+    fn py_urls(
+        &mut self,
+        n: usize,
+        scheme: &str,
+        path_depth: Option<usize>,
+        query_params: usize,
+        fragment: bool,
+        extension: Option<&str>,
+    ) -> PyResult<Vec<String>> {
+        let options = providers::network::UrlOptions {
+            scheme,
+            path_depth,
+            query_params,
+            fragment,
+            extension,
+        };
+        self.urls(n, &options)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random URL.
+    #[pyo3(
+        name = "url",
+        signature = (scheme="https", path_depth=None, query_params=0, fragment=false, extension=None)
+    )]
+    fn py_url(
+        &mut self,
+        scheme: &str,
+        path_depth: Option<usize>,
+        query_params: usize,
+        fragment: bool,
+        extension: Option<&str>,
+    ) -> PyResult<String> {
+        let options = providers::network::UrlOptions {
+            scheme,
+            path_depth,
+            query_params,
+            fragment,
+            extension,
+        };
+        self.url(&options)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random domain names.
+    #[pyo3(name = "domain_names")]
+    fn py_domain_names(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.domain_names(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random domain name.
+    #[pyo3(name = "domain_name")]
+    fn py_domain_name(&mut self) -> String {
+        self.domain_name()
+    }
+
+    /// Generate a batch of random IPv4 addresses.
+    #[pyo3(name = "ipv4s", signature = (n, private=false, network=None))]
+    fn py_ipv4s(
+        &mut self,
+        n: usize,
+        private: bool,
+        network: Option<&str>,
+    ) -> PyResult<Vec<String>> {
+        self.ipv4s(n, private, network)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random IPv4 address.
+    #[pyo3(name = "ipv4", signature = (private=false, network=None))]
+    fn py_ipv4(&mut self, private: bool, network: Option<&str>) -> PyResult<String> {
+        self.ipv4(private, network)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of publicly routable IPv4 addresses.
+    #[pyo3(name = "ipv4_publics")]
+    fn py_ipv4_publics(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.ipv4_publics(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single publicly routable IPv4 address.
+    #[pyo3(name = "ipv4_public")]
+    fn py_ipv4_public(&mut self) -> String {
+        self.ipv4_public()
+    }
+
+    /// Generate a batch of IPv4 CIDR blocks.
+    #[pyo3(name = "cidrs", signature = (n, prefix_range=(8, 30)))]
+    fn py_cidrs(&mut self, n: usize, prefix_range: (u8, u8)) -> PyResult<Vec<String>> {
+        self.cidrs(n, prefix_range.0, prefix_range.1)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single IPv4 CIDR block.
+    #[pyo3(name = "cidr", signature = (prefix_range=(8, 30)))]
+    fn py_cidr(&mut self, prefix_range: (u8, u8)) -> PyResult<String> {
+        self.cidr(prefix_range.0, prefix_range.1)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random IPv6 addresses.
+    #[pyo3(
+        name = "ipv6s",
+        signature = (n, compressed=false, uppercase=false, kind="random")
+    )]
+    fn py_ipv6s(
+        &mut self,
+        n: usize,
+        compressed: bool,
+        uppercase: bool,
+        kind: &str,
+    ) -> PyResult<Vec<String>> {
+        self.ipv6s(n, compressed, uppercase, kind)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random IPv6 address.
+    #[pyo3(name = "ipv6", signature = (compressed=false, uppercase=false, kind="random"))]
+    fn py_ipv6(&mut self, compressed: bool, uppercase: bool, kind: &str) -> PyResult<String> {
+        self.ipv6(compressed, uppercase, kind)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random MAC addresses.
+    #[pyo3(
+        name = "mac_addresses",
+        signature = (n, separator="colon", uppercase=false, kind="random")
+    )]
+    fn py_mac_addresses(
+        &mut self,
+        n: usize,
+        separator: &str,
+        uppercase: bool,
+        kind: &str,
+    ) -> PyResult<Vec<String>> {
+        self.mac_addresses(n, separator, uppercase, kind)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random MAC address.
+    #[pyo3(name = "mac_address", signature = (separator="colon", uppercase=false, kind="random"))]
+    fn py_mac_address(&mut self, separator: &str, uppercase: bool, kind: &str) -> PyResult<String> {
+        self.mac_address(separator, uppercase, kind)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of port numbers.
+    #[pyo3(name = "ports", signature = (n, kind="any"))]
+    fn py_ports(&mut self, n: usize, kind: &str) -> PyResult<Vec<u16>> {
+        self.ports(n, kind)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single port number.
+    #[pyo3(name = "port", signature = (kind="any"))]
+    fn py_port(&mut self, kind: &str) -> PyResult<u16> {
+        self.port(kind)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of HTTP methods.
+    #[pyo3(name = "http_methods")]
+    fn py_http_methods(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.http_methods(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single HTTP method.
+    #[pyo3(name = "http_method")]
+    fn py_http_method(&mut self) -> String {
+        self.http_method()
+    }
+
+    /// Generate a batch of HTTP status codes.
+    #[pyo3(name = "http_status_codes", signature = (n, class_=None))]
+    fn py_http_status_codes(&mut self, n: usize, class_: Option<&str>) -> PyResult<Vec<u16>> {
+        self.http_status_codes(n, class_)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single HTTP status code.
+    #[pyo3(name = "http_status_code", signature = (class_=None))]
+    fn py_http_status_code(&mut self, class_: Option<&str>) -> PyResult<u16> {
+        self.http_status_code(class_)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Email Variants ===
+
+    /// Generate a batch of random safe email addresses (example.com/org/net).
+    #[pyo3(name = "safe_emails", signature = (n, unique=false))]
+    fn py_safe_emails(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.safe_emails(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random safe email address.
+    #[pyo3(name = "safe_email")]
+    fn py_safe_email(&mut self) -> String {
+        self.safe_email()
+    }
+
+    /// Generate a batch of random free email addresses (gmail.com, etc.).
+    #[pyo3(name = "free_emails", signature = (n, unique=false))]
+    fn py_free_emails(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.free_emails(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random free email address.
+    #[pyo3(name = "free_email")]
+    fn py_free_email(&mut self) -> String {
+        self.free_email()
+    }
+
+    // === Finance Generation ===
+
+    /// Generate a batch of random credit card numbers with valid Luhn checksums.
+    #[pyo3(name = "credit_cards", signature = (n, network=None))]
+    fn py_credit_cards(&mut self, n: usize, network: Option<&str>) -> PyResult<Vec<String>> {
+        self.credit_cards(n, network)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random credit card number with valid Luhn checksum.
+    #[pyo3(name = "credit_card", signature = (network=None))]
+    fn py_credit_card(&mut self, network: Option<&str>) -> PyResult<String> {
+        self.credit_card(network)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of random IBANs with valid checksums.
+    #[pyo3(name = "ibans")]
+    fn py_ibans(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.ibans(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random IBAN with valid checksum.
+    #[pyo3(name = "iban")]
+    fn py_iban(&mut self) -> String {
+        self.iban()
+    }
+
+    /// Generate a batch of random BIC/SWIFT codes.
+    #[pyo3(name = "bics")]
+    fn py_bics(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.bics(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random BIC/SWIFT code.
+    #[pyo3(name = "bic")]
+    fn py_bic(&mut self) -> String {
+        self.bic()
+    }
+
+    /// Generate a batch of ABA routing numbers with valid checksums.
+    #[pyo3(name = "routing_numbers", signature = (n, unique=false))]
+    fn py_routing_numbers(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.routing_numbers(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single ABA routing number with a valid checksum.
+    #[pyo3(name = "routing_number")]
+    fn py_routing_number(&mut self) -> String {
+        self.routing_number()
+    }
+
+    /// Generate a batch of ISO 4217 currency codes.
+    #[pyo3(name = "currency_codes", signature = (n, unique=false))]
+    fn py_currency_codes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.currency_codes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single ISO 4217 currency code.
+    #[pyo3(name = "currency_code")]
+    fn py_currency_code(&mut self) -> String {
+        self.currency_code()
+    }
+
+    /// Generate a batch of currency names.
+    #[pyo3(name = "currency_names", signature = (n, unique=false))]
+    fn py_currency_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.currency_names(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single currency name.
+    #[pyo3(name = "currency_name")]
+    fn py_currency_name(&mut self) -> String {
+        self.currency_name()
+    }
+
+    /// Generate a batch of currency symbols.
+    #[pyo3(name = "currency_symbols")]
+    fn py_currency_symbols(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.currency_symbols(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single currency symbol.
+    #[pyo3(name = "currency_symbol")]
+    fn py_currency_symbol(&mut self) -> String {
+        self.currency_symbol()
+    }
+
+    /// Generate a batch of stock ticker symbols.
+    #[pyo3(name = "tickers", signature = (n, unique=false))]
+    fn py_tickers(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.tickers(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single stock ticker symbol.
+    #[pyo3(name = "ticker")]
+    fn py_ticker(&mut self) -> String {
+        self.ticker()
+    }
+
+    /// Generate a batch of stock exchange names.
+    #[pyo3(name = "stock_exchanges")]
+    fn py_stock_exchanges(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.stock_exchanges(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single stock exchange name.
+    #[pyo3(name = "stock_exchange")]
+    fn py_stock_exchange(&mut self) -> String {
+        self.stock_exchange()
+    }
+
+    /// Generate a batch of stock listings.
+    ///
+    /// Returns:
+    ///     List of dicts with keys: ticker, company, exchange
+    #[pyo3(name = "stocks")]
+    fn py_stocks(&mut self, py: Python<'_>, n: usize) -> PyResult<Vec<Py<PyAny>>> {
+        let listings = self
+            .stocks(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        listings
+            .into_iter()
+            .map(|s| {
+                let dict = PyDict::new(py);
+                dict.set_item("ticker", &s.ticker)?;
+                dict.set_item("company", &s.company)?;
+                dict.set_item("exchange", &s.exchange)?;
+                dict.into_py_any(py)
+            })
+            .collect()
+    }
+
+    /// Generate a batch of random bank account numbers.
+    #[pyo3(name = "bank_accounts")]
+    fn py_bank_accounts(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.bank_accounts(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random bank account number.
+    #[pyo3(name = "bank_account")]
+    fn py_bank_account(&mut self) -> String {
+        self.bank_account()
+    }
+
+    /// Generate a batch of random bank names.
+    #[pyo3(name = "bank_names")]
+    fn py_bank_names(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.bank_names(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random bank name.
+    #[pyo3(name = "bank_name")]
+    fn py_bank_name(&mut self) -> String {
+        self.bank_name()
+    }
+
+    /// Generate a batch of EU VAT numbers.
+    #[pyo3(name = "vat_numbers", signature = (n, country=None))]
+    fn py_vat_numbers(&mut self, n: usize, country: Option<&str>) -> PyResult<Vec<String>> {
+        self.vat_numbers(n, country)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single EU VAT number.
+    #[pyo3(name = "vat_number", signature = (country=None))]
+    fn py_vat_number(&mut self, country: Option<&str>) -> PyResult<String> {
+        self.vat_number(country)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of UK sort codes.
+    #[pyo3(name = "sort_codes")]
+    fn py_sort_codes(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.sort_codes(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single UK sort code (format: XX-XX-XX).
+    #[pyo3(name = "sort_code")]
+    fn py_sort_code(&mut self) -> String {
+        self.sort_code()
+    }
+
+    /// Generate a batch of UK bank account numbers (8 digits).
+    #[pyo3(name = "uk_account_numbers")]
+    fn py_uk_account_numbers(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.uk_account_numbers(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single UK bank account number (8 digits).
+    #[pyo3(name = "uk_account_number")]
+    fn py_uk_account_number(&mut self) -> String {
+        self.uk_account_number()
+    }
+
+    /// Generate a batch of financial transactions.
+    ///
+    /// Args:
+    ///     n: Number of transactions to generate
+    ///     starting_balance: Opening balance before first transaction
+    ///     start_date: Start date in YYYY-MM-DD format
+    ///     end_date: End date in YYYY-MM-DD format
+    ///
+    /// Returns:
+    ///     List of transaction dicts with keys: reference, date, amount,
+    ///     transaction_type, description, balance
+    #[pyo3(name = "transactions")]
+    fn py_transactions(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        starting_balance: f64,
+        start_date: &str,
+        end_date: &str,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let txns = self
+            .transactions(n, starting_balance, start_date, end_date)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        txns.into_iter()
+            .map(|t| {
+                let dict = PyDict::new(py);
+                dict.set_item("reference", &t.reference)?;
+                dict.set_item("date", &t.date)?;
+                dict.set_item("amount", t.amount)?;
+                dict.set_item("transaction_type", &t.transaction_type)?;
+                dict.set_item("description", &t.description)?;
+                dict.set_item("balance", t.balance)?;
+                dict.into_py_any(py)
+            })
+            .collect()
+    }
+
+    /// Generate a batch of transaction amounts.
+    #[pyo3(name = "transaction_amounts")]
+    fn py_transaction_amounts(&mut self, n: usize, min: f64, max: f64) -> PyResult<Vec<f64>> {
+        self.transaction_amounts(n, min, max)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single transaction amount.
+    #[pyo3(name = "transaction_amount")]
+    fn py_transaction_amount(&mut self, min: f64, max: f64) -> PyResult<f64> {
+        self.transaction_amount(min, max)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of prices, formatted strings by default.
+    #[pyo3(name = "prices", signature = (n, min, max, currency="USD", formatted=true))]
+    fn py_prices(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        min: f64,
+        max: f64,
+        currency: &str,
+        formatted: bool,
+    ) -> PyResult<Py<PyAny>> {
+        if formatted {
+            self.formatted_prices(n, min, max, currency)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+                .into_py_any(py)
+        } else {
+            self.prices(n, min, max, currency)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+                .into_py_any(py)
+        }
+    }
+
+    /// Generate a single price, a formatted string by default.
+    #[pyo3(name = "price", signature = (min, max, currency="USD", formatted=true))]
+    fn py_price(
+        &mut self,
+        py: Python<'_>,
+        min: f64,
+        max: f64,
+        currency: &str,
+        formatted: bool,
+    ) -> PyResult<Py<PyAny>> {
+        if formatted {
+            self.formatted_price(min, max, currency)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+                .into_py_any(py)
+        } else {
+            self.price(min, max, currency)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+                .into_py_any(py)
+        }
+    }
+
+    // === Commerce Generation ===
+
+    /// Generate a batch of invoice or order numbers.
+    #[pyo3(
+        name = "invoice_numbers",
+        signature = (n, prefix = "INV", digits = 6, date = Some("year"))
+    )]
+    fn py_invoice_numbers(
+        &mut self,
+        n: usize,
+        prefix: &str,
+        digits: u32,
+        date: Option<&str>,
+    ) -> PyResult<Vec<String>> {
+        self.invoice_numbers(n, prefix, digits, date)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single invoice or order number.
+    #[pyo3(
+        name = "invoice_number",
+        signature = (prefix = "INV", digits = 6, date = Some("year"))
+    )]
+    fn py_invoice_number(
+        &mut self,
+        prefix: &str,
+        digits: u32,
+        date: Option<&str>,
+    ) -> PyResult<String> {
+        self.invoice_number(prefix, digits, date)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of insurance policy numbers.
+    #[pyo3(name = "policy_numbers", signature = (n, prefix = "POL", digits = 8))]
+    fn py_policy_numbers(&mut self, n: usize, prefix: &str, digits: u32) -> PyResult<Vec<String>> {
+        self.policy_numbers(n, prefix, digits)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single insurance policy number.
+    #[pyo3(name = "policy_number", signature = (prefix = "POL", digits = 8))]
+    fn py_policy_number(&mut self, prefix: &str, digits: u32) -> PyResult<String> {
+        self.policy_number(prefix, digits)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of insurance claim numbers.
+    #[pyo3(name = "claim_numbers", signature = (n, prefix = "CLM", digits = 8))]
+    fn py_claim_numbers(&mut self, n: usize, prefix: &str, digits: u32) -> PyResult<Vec<String>> {
+        self.claim_numbers(n, prefix, digits)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single insurance claim number.
+    #[pyo3(name = "claim_number", signature = (prefix = "CLM", digits = 8))]
+    fn py_claim_number(&mut self, prefix: &str, digits: u32) -> PyResult<String> {
+        self.claim_number(prefix, digits)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of product SKUs.
+    #[pyo3(name = "skus", signature = (n, pattern=None))]
+    fn py_skus(&mut self, n: usize, pattern: Option<&str>) -> PyResult<Vec<String>> {
+        self.skus(n, pattern)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single product SKU.
+    #[pyo3(name = "sku", signature = (pattern=None))]
+    fn py_sku(&mut self, pattern: Option<&str>) -> String {
+        self.sku(pattern)
+    }
+
+    /// Generate a batch of retail prices, as floats or `decimal.Decimal`s.
+    #[pyo3(name = "retail_prices", signature = (n, tier="standard", decimal=false))]
+    fn py_retail_prices(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        tier: &str,
+        decimal: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let prices = self
+            .retail_prices(n, tier)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        if decimal {
+            prices
+                .into_iter()
+                .map(|price| to_py_decimal(py, &format!("{:.2}", price)))
+                .collect::<PyResult<Vec<_>>>()?
+                .into_py_any(py)
+        } else {
+            prices.into_py_any(py)
+        }
+    }
+
+    /// Generate a single retail price, as a float or `decimal.Decimal`.
+    #[pyo3(name = "retail_price", signature = (tier="standard", decimal=false))]
+    fn py_retail_price(
+        &mut self,
+        py: Python<'_>,
+        tier: &str,
+        decimal: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let price = self
+            .retail_price(tier)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        if decimal {
+            to_py_decimal(py, &format!("{:.2}", price))
+        } else {
+            price.into_py_any(py)
+        }
+    }
+
+    /// Generate a batch of product names.
+    #[pyo3(name = "product_names", signature = (n, unique=false))]
+    fn py_product_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.product_names(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single product name (e.g., "Ergonomic Granite Chair").
+    #[pyo3(name = "product_name")]
+    fn py_product_name(&mut self) -> String {
+        self.product_name()
+    }
+
+    // === Product Code Generation ===
+
+    /// Generate a batch of hyphenated ISBN-13s with valid check digits.
+    #[pyo3(name = "isbn13s", signature = (n, unique=false))]
+    fn py_isbn13s(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.isbn13s(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single hyphenated ISBN-13.
+    #[pyo3(name = "isbn13")]
+    fn py_isbn13(&mut self) -> String {
+        self.isbn13()
+    }
+
+    /// Generate a batch of hyphenated ISBN-10s with valid check digits.
+    #[pyo3(name = "isbn10s", signature = (n, unique=false))]
+    fn py_isbn10s(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.isbn10s(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single hyphenated ISBN-10.
+    #[pyo3(name = "isbn10")]
+    fn py_isbn10(&mut self) -> String {
+        self.isbn10()
+    }
+
+    /// Generate a batch of 15-digit IMEIs with valid Luhn check digits.
+    #[pyo3(name = "imeis", signature = (n, unique=false))]
+    fn py_imeis(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.imeis(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single 15-digit IMEI.
+    #[pyo3(name = "imei")]
+    fn py_imei(&mut self) -> String {
+        self.imei()
+    }
+
+    /// Generate a batch of DOIs with real registrant prefixes.
+    #[pyo3(name = "dois", signature = (n, unique=false))]
+    fn py_dois(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.dois(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single DOI.
+    #[pyo3(name = "doi")]
+    fn py_doi(&mut self) -> String {
+        self.doi()
+    }
+
+    /// Generate a batch of ORCID iDs with valid MOD 11-2 check characters.
+    #[pyo3(name = "orcids", signature = (n, unique=false))]
+    fn py_orcids(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.orcids(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single ORCID iD.
+    #[pyo3(name = "orcid")]
+    fn py_orcid(&mut self) -> String {
+        self.orcid()
+    }
+
+    // === Vehicle Generation ===
+
+    /// Generate a batch of 17-character VINs with valid check digits.
+    #[pyo3(name = "vins", signature = (n, unique=false))]
+    fn py_vins(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.vins(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single 17-character VIN.
+    #[pyo3(name = "vin")]
+    fn py_vin(&mut self) -> String {
+        self.vin()
+    }
+
+    /// Generate a batch of vehicle makes.
+    #[pyo3(name = "vehicle_makes", signature = (n, unique=false))]
+    fn py_vehicle_makes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.vehicle_makes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single vehicle make (e.g. "Toyota").
+    #[pyo3(name = "vehicle_make")]
+    fn py_vehicle_make(&mut self) -> String {
+        self.vehicle_make()
+    }
+
+    /// Generate a batch of vehicle models.
+    #[pyo3(name = "vehicle_models", signature = (n, unique=false))]
+    fn py_vehicle_models(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.vehicle_models(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single vehicle model (e.g. "Camry").
+    #[pyo3(name = "vehicle_model")]
+    fn py_vehicle_model(&mut self) -> String {
+        self.vehicle_model()
+    }
+
+    /// Generate a batch of vehicles.
+    ///
+    /// Returns:
+    ///     List of dicts with keys: make, model, year, vin
+    #[pyo3(name = "vehicles")]
+    fn py_vehicles(&mut self, py: Python<'_>, n: usize) -> PyResult<Vec<Py<PyAny>>> {
+        let vehicles = self
+            .vehicles(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        vehicles
+            .into_iter()
+            .map(|v| vehicle_to_pydict(py, v)?.into_py_any(py))
+            .collect()
+    }
+
+    /// Generate a single vehicle as a dict.
+    #[pyo3(name = "vehicle")]
+    fn py_vehicle(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let vehicle = self.vehicle();
+        vehicle_to_pydict(py, vehicle)?.into_py_any(py)
+    }
+
+    // === Medical Generation ===
+
+    /// Generate a batch of blood types, weighted by prevalence.
+    #[pyo3(name = "blood_types", signature = (n, unique=false))]
+    fn py_blood_types(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.blood_types(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single blood type (e.g. "O+").
+    #[pyo3(name = "blood_type")]
+    fn py_blood_type(&mut self) -> String {
+        self.blood_type()
+    }
+
+    /// Generate a batch of allergies.
+    #[pyo3(name = "allergies", signature = (n, unique=false))]
+    fn py_allergies(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.allergies(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single allergy (e.g. "Penicillin").
+    #[pyo3(name = "allergy")]
+    fn py_allergy(&mut self) -> String {
+        self.allergy()
+    }
+
+    /// Generate a batch of medication generic names.
+    #[pyo3(name = "medications", signature = (n, unique=false))]
+    fn py_medications(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.medications(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single medication generic name.
+    #[pyo3(name = "medication")]
+    fn py_medication(&mut self) -> String {
+        self.medication()
+    }
+
+    /// Generate a batch of ICD-10 diagnosis codes.
+    #[pyo3(name = "icd10_codes", signature = (n, unique=false))]
+    fn py_icd10_codes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.icd10_codes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single ICD-10 diagnosis code.
+    #[pyo3(name = "icd10_code")]
+    fn py_icd10_code(&mut self) -> String {
+        self.icd10_code()
+    }
+
+    /// Generate a batch of US National Provider Identifiers.
+    #[pyo3(name = "npis", signature = (n, unique=false))]
+    fn py_npis(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.npis(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single US National Provider Identifier.
+    #[pyo3(name = "npi")]
+    fn py_npi(&mut self) -> String {
+        self.npi()
+    }
+
+    /// Generate a batch of adult heights in centimetres.
+    #[pyo3(name = "heights")]
+    fn py_heights(&mut self, n: usize) -> PyResult<Vec<f64>> {
+        self.heights(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single adult height in centimetres.
+    #[pyo3(name = "height")]
+    fn py_height(&mut self) -> f64 {
+        self.height()
+    }
+
+    /// Generate a batch of adult weights in kilograms.
+    #[pyo3(name = "weights")]
+    fn py_weights(&mut self, n: usize) -> PyResult<Vec<f64>> {
+        self.weights(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single adult weight in kilograms.
+    #[pyo3(name = "weight")]
+    fn py_weight(&mut self) -> f64 {
+        self.weight()
+    }
+
+    // === Travel Generation ===
+
+    /// Generate a batch of three-letter IATA airport codes.
+    #[pyo3(name = "airport_codes", signature = (n, unique=false))]
+    fn py_airport_codes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.airport_codes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single three-letter IATA airport code.
+    #[pyo3(name = "airport_code")]
+    fn py_airport_code(&mut self) -> String {
+        self.airport_code()
+    }
+
+    /// Generate a batch of four-letter ICAO airport codes.
+    #[pyo3(name = "airport_icao_codes", signature = (n, unique=false))]
+    fn py_airport_icao_codes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.airport_icao_codes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single four-letter ICAO airport code.
+    #[pyo3(name = "airport_icao_code")]
+    fn py_airport_icao_code(&mut self) -> String {
+        self.airport_icao_code()
+    }
+
+    /// Generate a batch of airport names.
+    #[pyo3(name = "airport_names", signature = (n, unique=false))]
+    fn py_airport_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.airport_names(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single airport name.
+    #[pyo3(name = "airport_name")]
+    fn py_airport_name(&mut self) -> String {
+        self.airport_name()
+    }
+
+    /// Generate a batch of cities served by an airport.
+    #[pyo3(name = "airport_cities")]
+    fn py_airport_cities(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.airport_cities(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single airport city.
+    #[pyo3(name = "airport_city")]
+    fn py_airport_city(&mut self) -> String {
+        self.airport_city()
+    }
+
+    /// Generate a batch of flight numbers (IATA carrier code and 1-4 digits).
+    #[pyo3(name = "flight_numbers", signature = (n, unique=false))]
+    fn py_flight_numbers(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.flight_numbers(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single flight number (e.g., "BA283").
+    #[pyo3(name = "flight_number")]
+    fn py_flight_number(&mut self) -> String {
+        self.flight_number()
+    }
+
+    /// Generate a batch of airports.
+    ///
+    /// Returns:
+    ///     List of dicts with keys: iata, icao, name, city, country
+    #[pyo3(name = "airports")]
+    fn py_airports(&mut self, py: Python<'_>, n: usize) -> PyResult<Vec<Py<PyAny>>> {
+        let airports = self
+            .airports(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        airports
+            .into_iter()
+            .map(|a| {
+                let dict = PyDict::new(py);
+                dict.set_item("iata", &a.iata)?;
+                dict.set_item("icao", &a.icao)?;
+                dict.set_item("name", &a.name)?;
+                dict.set_item("city", &a.city)?;
+                dict.set_item("country", &a.country)?;
+                dict.into_py_any(py)
+            })
+            .collect()
+    }
+
+    // === Logistics Generation ===
+
+    /// Generate a batch of shipment tracking numbers with valid check digits.
+    #[pyo3(name = "tracking_numbers", signature = (n, carrier=None))]
+    fn py_tracking_numbers(&mut self, n: usize, carrier: Option<&str>) -> PyResult<Vec<String>> {
+        self.tracking_numbers(n, carrier)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single shipment tracking number with a valid check digit.
+    #[pyo3(name = "tracking_number", signature = (carrier=None))]
+    fn py_tracking_number(&mut self, carrier: Option<&str>) -> PyResult<String> {
+        self.tracking_number(carrier)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Language Generation ===
+
+    /// Generate a batch of ISO 639-1 language codes.
+    #[pyo3(name = "language_codes", signature = (n, unique=false))]
+    fn py_language_codes(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.language_codes(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single ISO 639-1 language code.
+    #[pyo3(name = "language_code")]
+    fn py_language_code(&mut self) -> String {
+        self.language_code()
+    }
+
+    /// Generate a batch of language names.
+    #[pyo3(name = "language_names", signature = (n, unique=false))]
+    fn py_language_names(&mut self, n: usize, unique: bool) -> PyResult<Vec<String>> {
+        self.language_names(n, unique)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single language name.
+    #[pyo3(name = "language_name")]
+    fn py_language_name(&mut self) -> String {
+        self.language_name()
+    }
+
+    // === File System Generation ===
+
+    /// Generate a batch of file names.
+    #[pyo3(name = "file_names", signature = (n, category=None))]
+    fn py_file_names(&mut self, n: usize, category: Option<&str>) -> PyResult<Vec<String>> {
+        self.file_names(n, category)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single file name.
+    #[pyo3(name = "file_name", signature = (category=None))]
+    fn py_file_name(&mut self, category: Option<&str>) -> PyResult<String> {
+        self.file_name(category)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of file extensions.
+    #[pyo3(name = "file_extensions", signature = (n, category=None))]
+    fn py_file_extensions(&mut self, n: usize, category: Option<&str>) -> PyResult<Vec<String>> {
+        self.file_extensions(n, category)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single file extension.
+    #[pyo3(name = "file_extension", signature = (category=None))]
+    fn py_file_extension(&mut self, category: Option<&str>) -> PyResult<String> {
+        self.file_extension(category)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of absolute file paths.
+    #[pyo3(name = "file_paths", signature = (n, depth=2, os="unix", category=None))]
+    fn py_file_paths(
+        &mut self,
+        n: usize,
+        depth: usize,
+        os: &str,
+        category: Option<&str>,
+    ) -> PyResult<Vec<String>> {
+        self.file_paths(n, depth, os, category)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single absolute file path.
+    #[pyo3(name = "file_path", signature = (depth=2, os="unix", category=None))]
+    fn py_file_path(&mut self, depth: usize, os: &str, category: Option<&str>) -> PyResult<String> {
+        self.file_path(depth, os, category)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of MIME types.
+    #[pyo3(name = "mime_types", signature = (n, category=None))]
+    fn py_mime_types(&mut self, n: usize, category: Option<&str>) -> PyResult<Vec<String>> {
+        self.mime_types(n, category)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single MIME type.
+    #[pyo3(name = "mime_type", signature = (category=None))]
+    fn py_mime_type(&mut self, category: Option<&str>) -> PyResult<String> {
+        self.mime_type(category)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Software Generation ===
+
+    /// Generate a batch of semantic versions.
+    #[pyo3(name = "semvers", signature = (n, prerelease=false, build=false))]
+    fn py_semvers(&mut self, n: usize, prerelease: bool, build: bool) -> PyResult<Vec<String>> {
+        self.semvers(n, prerelease, build)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single semantic version.
+    #[pyo3(name = "semver", signature = (prerelease=false, build=false))]
+    fn py_semver(&mut self, prerelease: bool, build: bool) -> String {
+        self.semver(prerelease, build)
+    }
+
+    /// Generate a batch of git commit SHAs.
+    #[pyo3(name = "git_shas", signature = (n, short=false))]
+    fn py_git_shas(&mut self, n: usize, short: bool) -> PyResult<Vec<String>> {
+        self.git_shas(n, short)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single git commit SHA.
+    #[pyo3(name = "git_sha", signature = (short=false))]
+    fn py_git_sha(&mut self, short: bool) -> String {
+        self.git_sha(short)
+    }
+
+    /// Generate a batch of conventional commit messages.
+    #[pyo3(name = "commit_messages")]
+    fn py_commit_messages(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.commit_messages(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single conventional commit message.
+    #[pyo3(name = "commit_message")]
+    fn py_commit_message(&mut self) -> String {
+        self.commit_message()
+    }
+
+    /// Generate a batch of container image references.
+    #[pyo3(
+        name = "docker_images",
+        signature = (n, registry=true, tag=true, digest=false)
+    )]
+    fn py_docker_images(
+        &mut self,
+        n: usize,
+        registry: bool,
+        tag: bool,
+        digest: bool,
+    ) -> PyResult<Vec<String>> {
+        self.docker_images(n, registry, tag, digest)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single container image reference.
+    #[pyo3(name = "docker_image", signature = (registry=true, tag=true, digest=false))]
+    fn py_docker_image(&mut self, registry: bool, tag: bool, digest: bool) -> String {
+        self.docker_image(registry, tag, digest)
+    }
+
+    /// Generate a batch of DNS-1123 compliant Kubernetes resource names.
+    #[pyo3(name = "k8s_names", signature = (n, kind="pod"))]
+    fn py_k8s_names(&mut self, n: usize, kind: &str) -> PyResult<Vec<String>> {
+        self.k8s_names(n, kind)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single Kubernetes resource name.
+    #[pyo3(name = "k8s_name", signature = (kind="pod"))]
+    fn py_k8s_name(&mut self, kind: &str) -> PyResult<String> {
+        self.k8s_name(kind)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Cloud Generation ===
+
+    /// Generate a batch of AWS ARNs.
+    #[pyo3(name = "arns", signature = (n, service=None))]
+    fn py_arns(&mut self, n: usize, service: Option<&str>) -> PyResult<Vec<String>> {
+        self.arns(n, service)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single AWS ARN.
+    #[pyo3(name = "arn", signature = (service=None))]
+    fn py_arn(&mut self, service: Option<&str>) -> PyResult<String> {
+        self.arn(service)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of S3 URIs.
+    #[pyo3(name = "s3_uris")]
+    fn py_s3_uris(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.s3_uris(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single S3 URI.
+    #[pyo3(name = "s3_uri")]
+    fn py_s3_uri(&mut self) -> String {
+        self.s3_uri()
+    }
+
+    /// Generate a batch of cloud region identifiers.
+    #[pyo3(name = "cloud_regions", signature = (n, provider="aws"))]
+    fn py_cloud_regions(&mut self, n: usize, provider: &str) -> PyResult<Vec<String>> {
+        self.cloud_regions(n, provider)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single cloud region identifier.
+    #[pyo3(name = "cloud_region", signature = (provider="aws"))]
+    fn py_cloud_region(&mut self, provider: &str) -> PyResult<String> {
+        self.cloud_region(provider)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Log Generation ===
+
+    /// Generate a batch of combined log format access log lines.
+    #[pyo3(name = "access_logs")]
+    fn py_access_logs(&mut self, n: usize) -> PyResult<Vec<String>> {
+        self.access_logs(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single combined log format access log line.
+    #[pyo3(name = "access_log")]
+    fn py_access_log(&mut self) -> String {
+        self.access_log()
+    }
+
+    /// Generate a batch of access log entries as dicts.
+    #[pyo3(name = "access_log_entries")]
+    fn py_access_log_entries(&mut self, py: Python<'_>, n: usize) -> PyResult<Vec<Py<PyAny>>> {
+        let entries = self
+            .access_log_entries(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        entries
+            .into_iter()
+            .map(|e| access_log_entry_to_pydict(py, e)?.into_py_any(py))
+            .collect()
+    }
+
+    /// Generate a single access log entry as a dict.
+    #[pyo3(name = "access_log_entry")]
+    fn py_access_log_entry(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let entry = self.access_log_entry();
+        access_log_entry_to_pydict(py, entry)?.into_py_any(py)
+    }
+
+    /// Generate a batch of syslog lines.
+    #[pyo3(name = "syslogs", signature = (n, format="rfc5424"))]
+    fn py_syslogs(&mut self, n: usize, format: &str) -> PyResult<Vec<String>> {
+        self.syslogs(n, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single syslog line.
+    #[pyo3(name = "syslog", signature = (format="rfc5424"))]
+    fn py_syslog(&mut self, format: &str) -> PyResult<String> {
+        self.syslog(format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of syslog entries as dicts.
+    #[pyo3(name = "syslog_entries")]
+    fn py_syslog_entries(&mut self, py: Python<'_>, n: usize) -> PyResult<Vec<Py<PyAny>>> {
+        let entries = self
+            .syslog_entries(n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        entries
+            .into_iter()
+            .map(|e| syslog_entry_to_pydict(py, e)?.into_py_any(py))
+            .collect()
+    }
+
+    /// Generate a single syslog entry as a dict.
+    #[pyo3(name = "syslog_entry")]
+    fn py_syslog_entry(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let entry = self.syslog_entry();
+        syslog_entry_to_pydict(py, entry)?.into_py_any(py)
+    }
+
+    // === Password Generation ===
+
+    /// Generate a batch of random passwords.
+    ///
+    /// Args:
+    ///     n: Number of passwords to generate
+    ///     length: Length of each password (default: 12)
+    ///     uppercase: Include uppercase letters (default: True)
+    ///     lowercase: Include lowercase letters (default: True)
+    ///     digits: Include digits (default: True)
+    ///     symbols: Include symbols (default: True)
+    ///
+    /// Returns:
+    ///     List of random passwords
+    ///
+    /// Raises:
+    ///     ValueError: If no character sets are enabled or batch size exceeds limit
+    #[pyo3(name = "passwords", signature = (n, length=12, uppercase=true, lowercase=true, digits=true, symbols=true))]
+    fn py_passwords(
+        &mut self,
+        n: usize,
+        length: usize,
+        uppercase: bool,
+        lowercase: bool,
+        digits: bool,
+        symbols: bool,
+    ) -> PyResult<Vec<String>> {
+        self.passwords(n, length, uppercase, lowercase, digits, symbols)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single random password.
+    ///
+    /// Args:
+    ///     length: Length of the password (default: 12)
+    ///     uppercase: Include uppercase letters (default: True)
+    ///     lowercase: Include lowercase letters (default: True)
+    ///     digits: Include digits (default: True)
+    ///     symbols: Include symbols (default: True)
+    ///
+    /// Returns:
+    ///     A random password
+    ///
+    /// Raises:
+    ///     ValueError: If no character sets are enabled
+    #[pyo3(name = "password", signature = (length=12, uppercase=true, lowercase=true, digits=true, symbols=true))]
+    fn py_password(
+        &mut self,
+        length: usize,
+        uppercase: bool,
+        lowercase: bool,
+        digits: bool,
+        symbols: bool,
+    ) -> PyResult<String> {
+        self.password(length, uppercase, lowercase, digits, symbols)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of password hash strings.
+    #[pyo3(name = "password_hashes", signature = (n, algorithm="bcrypt"))]
+    fn py_password_hashes(&mut self, n: usize, algorithm: &str) -> PyResult<Vec<String>> {
+        self.password_hashes(n, algorithm)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single password hash string.
+    #[pyo3(name = "password_hash", signature = (algorithm="bcrypt"))]
+    fn py_password_hash(&mut self, algorithm: &str) -> PyResult<String> {
+        self.password_hash(algorithm)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of JSON Web Tokens.
+    #[pyo3(
+        name = "jwts",
+        signature = (n, subject=None, issuer=None, start="2020-01-01", end="2030-12-31", ttl=3600, algorithm="HS256")
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn py_jwts(
+        &mut self,
+        n: usize,
+        subject: Option<&str>,
+        issuer: Option<&str>,
+        start: &str,
+        end: &str,
+        ttl: u32,
+        algorithm: &str,
+    ) -> PyResult<Vec<String>> {
+        let options = providers::auth::JwtOptions {
+            subject,
+            issuer,
+            start,
+            end,
+            ttl,
+            algorithm: parse_jwt_algorithm(algorithm)?,
+        };
+        self.jwts(n, &options)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single JSON Web Token.
+    #[pyo3(
+        name = "jwt",
+        signature = (subject=None, issuer=None, start="2020-01-01", end="2030-12-31", ttl=3600, algorithm="HS256")
+    )]
+    fn py_jwt(
+        &mut self,
+        subject: Option<&str>,
+        issuer: Option<&str>,
+        start: &str,
+        end: &str,
+        ttl: u32,
+        algorithm: &str,
+    ) -> PyResult<String> {
+        let options = providers::auth::JwtOptions {
+            subject,
+            issuer,
+            start,
+            end,
+            ttl,
+            algorithm: parse_jwt_algorithm(algorithm)?,
+        };
+        self.jwt(&options)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of prefixed API keys.
+    #[pyo3(
+        name = "api_keys",
+        signature = (n, prefix="sk_live_", length=32, alphabet="base62")
+    )]
+    fn py_api_keys(
+        &mut self,
+        n: usize,
+        prefix: &str,
+        length: usize,
+        alphabet: &str,
+    ) -> PyResult<Vec<String>> {
+        self.api_keys(n, prefix, length, alphabet)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single prefixed API key.
+    #[pyo3(name = "api_key", signature = (prefix="sk_live_", length=32, alphabet="base62"))]
+    fn py_api_key(&mut self, prefix: &str, length: usize, alphabet: &str) -> PyResult<String> {
+        self.api_key(prefix, length, alphabet)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of session IDs.
+    #[pyo3(name = "session_ids", signature = (n, format = "hex"))]
+    fn py_session_ids(&mut self, n: usize, format: &str) -> PyResult<Vec<String>> {
+        self.session_ids(n, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single session ID.
+    #[pyo3(name = "session_id", signature = (format = "hex"))]
+    fn py_session_id(&mut self, format: &str) -> PyResult<String> {
+        self.session_id(format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of OAuth access tokens.
+    #[pyo3(name = "oauth_access_tokens", signature = (n, format = "prefixed"))]
+    fn py_oauth_access_tokens(&mut self, n: usize, format: &str) -> PyResult<Vec<String>> {
+        self.oauth_access_tokens(n, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single OAuth access token.
+    #[pyo3(name = "oauth_access_token", signature = (format = "prefixed"))]
+    fn py_oauth_access_token(&mut self, format: &str) -> PyResult<String> {
+        self.oauth_access_token(format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of OAuth refresh tokens.
+    #[pyo3(name = "refresh_tokens", signature = (n, format = "prefixed"))]
+    fn py_refresh_tokens(&mut self, n: usize, format: &str) -> PyResult<Vec<String>> {
+        self.refresh_tokens(n, format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single OAuth refresh token.
+    #[pyo3(name = "refresh_token", signature = (format = "prefixed"))]
+    fn py_refresh_token(&mut self, format: &str) -> PyResult<String> {
+        self.refresh_token(format)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of base32 TOTP secrets.
+    #[pyo3(name = "totp_secrets", signature = (n, length = 32))]
+    fn py_totp_secrets(&mut self, n: usize, length: usize) -> PyResult<Vec<String>> {
+        self.totp_secrets(n, length)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single base32 TOTP secret.
+    #[pyo3(name = "totp_secret", signature = (length = 32))]
+    fn py_totp_secret(&mut self, length: usize) -> PyResult<String> {
+        self.totp_secret(length)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of otpauth:// TOTP provisioning URIs.
+    #[pyo3(name = "totp_uris", signature = (n, length = 32))]
+    fn py_totp_uris(&mut self, n: usize, length: usize) -> PyResult<Vec<String>> {
+        self.totp_uris(n, length)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a single otpauth:// TOTP provisioning URI.
+    #[pyo3(name = "totp_uri", signature = (length = 32))]
+    fn py_totp_uri(&mut self, length: usize) -> PyResult<String> {
+        self.totp_uri(length)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Custom Providers ===
+
+    /// Register a custom provider with uniform random selection.
+    ///
+    /// Args:
+    ///     name: The provider name (must not conflict with built-in types)
+    ///     options: List of string options to choose from
+    ///
+    /// Raises:
+    ///     ValueError: If name conflicts with built-in type or options is empty
+    ///
+    /// Example:
+    ///     >>> fake = Faker()
+    ///     >>> fake.add_provider("department", ["Engineering", "Sales", "HR"])
+    ///     >>> fake.generate("department")
+    ///     'Sales'
+    #[pyo3(name = "add_provider")]
+    fn py_add_provider(&mut self, name: &str, options: Vec<String>) -> PyResult<()> {
+        self.add_provider(name, options)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Register a custom provider with weighted random selection.
+    ///
+    /// Args:
+    ///     name: The provider name
+    ///     weighted_options: List of (value, weight) tuples. Higher weights = more likely.
+    ///
+    /// Raises:
+    ///     ValueError: If name conflicts, options empty, or weights invalid
+    ///
+    /// Example:
+    ///     >>> fake = Faker()
+    ///     >>> fake.add_weighted_provider("status", [("active", 80), ("inactive", 20)])
+    ///     >>> fake.generate("status")  # ~80% chance of "active"
+    ///     'active'
+    #[pyo3(name = "add_weighted_provider")]
+    fn py_add_weighted_provider(
+        &mut self,
+        name: &str,
+        weighted_options: Vec<(String, u64)>,
+    ) -> PyResult<()> {
+        self.add_weighted_provider(name, weighted_options)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Remove a custom provider.
+    ///
+    /// Args:
+    ///     name: The provider name to remove
+    ///
+    /// Returns:
+    ///     True if provider was removed, False if it didn't exist
+    #[pyo3(name = "remove_provider")]
+    fn py_remove_provider(&mut self, name: &str) -> bool {
+        self.remove_provider(name)
+    }
+
+    /// Check if a custom provider exists.
+    ///
+    /// Args:
+    ///     name: The provider name to check
+    ///
+    /// Returns:
+    ///     True if provider exists, False otherwise
+    #[pyo3(name = "has_provider")]
+    fn py_has_provider(&self, name: &str) -> bool {
+        self.has_provider(name)
+    }
+
+    /// List all registered custom provider names.
+    ///
+    /// Returns:
+    ///     List of registered custom provider names
+    #[pyo3(name = "list_providers")]
+    fn py_list_providers(&self) -> Vec<String> {
+        self.list_providers()
+    }
+
+    /// Generate a single value from a custom provider.
+    ///
+    /// Args:
+    ///     name: The custom provider name
+    ///
+    /// Returns:
+    ///     A randomly selected string from the provider's options
+    ///
+    /// Raises:
+    ///     ValueError: If provider doesn't exist
+    #[pyo3(name = "generate")]
+    fn py_generate(&mut self, name: &str) -> PyResult<String> {
+        self.generate(name)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Generate a batch of values from a custom provider.
+    ///
+    /// Args:
+    ///     name: The custom provider name
+    ///     n: Number of values to generate
+    ///
+    /// Returns:
+    ///     A list of randomly selected strings
+    ///
+    /// Raises:
+    ///     ValueError: If provider doesn't exist or n exceeds batch limit
+    #[pyo3(name = "generate_batch")]
+    fn py_generate_batch(&mut self, name: &str, n: usize) -> PyResult<Vec<String>> {
+        self.generate_batch(name, n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    // === Locale Data Overrides ===
+
+    /// Layer custom data lists on top of the locale's built-in data.
+    ///
+    /// Only the lists you pass are replaced; the locale's formats stay in effect.
+    ///
+    /// Args:
+    ///     first_names: Replacement first names
+    ///     last_names: Replacement last names
+    ///     cities: Replacement city names
+    ///     street_names: Replacement street names (street types still come from the locale)
+    ///
+    /// Raises:
+    ///     ValueError: If any supplied list is empty
+    ///
+    /// Example:
+    ///     >>> fake = Faker("de_DE")
+    ///     >>> fake.override_locale_data(first_names=["Anna", "Ben"])
+    ///     >>> fake.name()
+    ///     'Anna Müller'
+    #[pyo3(
+        name = "override_locale_data",
+        signature = (first_names=None, last_names=None, cities=None, street_names=None)
+    )]
+    fn py_override_locale_data(
+        &mut self,
+        first_names: Option<Vec<String>>,
+        last_names: Option<Vec<String>>,
+        cities: Option<Vec<String>>,
+        street_names: Option<Vec<String>>,
+    ) -> PyResult<()> {
+        self.override_locale_data(LocaleOverrides {
+            first_names,
+            last_names,
+            cities,
+            street_names,
+        })
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Remove all locale data overrides, restoring the built-in data.
+    #[pyo3(name = "clear_locale_overrides")]
+    fn py_clear_locale_overrides(&mut self) {
+        self.clear_locale_overrides()
+    }
+
+    // === Records Generation ===
+
+    /// Generate records based on a schema.
+    ///
+    /// The schema is a dictionary mapping field names to type specifications:
+    /// - Simple types: "name", "email", "uuid", "int", "float", etc.
+    /// - Custom providers: Any registered custom provider name
+    /// - Integer range: ("int", min, max)
+    /// - Float range: ("float", min, max)
+    /// - Text with limits: ("text", min_chars, max_chars)
+    /// - Date range: ("date", start, end)
+    /// - Choice: ("choice", ["option1", "option2", ...])
+    #[pyo3(name = "records")]
+    fn py_records(&mut self, n: usize, schema: &Bound<'_, PyDict>) -> PyResult<Vec<Py<PyAny>>> {
+        let py = schema.py();
+        let custom_names = self.custom_provider_names();
+        let rust_schema = parse_py_schema_with_custom(schema, &custom_names)?;
+        validate_batch_size(n).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        let records = providers::records::generate_records_with_custom(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+            &rust_schema,
+            &self.custom_providers,
+        )
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        records
+            .into_iter()
+            .map(|record| {
+                let dict = PyDict::new(py);
+                for (key, value) in record {
+                    dict.set_item(key, value_to_pyobject(py, value)?)?;
+                }
+                dict.into_py_any(py)
+            })
+            .collect()
+    }
+
+    /// Generate records as tuples based on a schema.
+    ///
+    /// Returns a list of tuples with values in alphabetical order of the schema keys.
+    /// This is faster than records() since it avoids creating dictionaries.
+    #[pyo3(name = "records_tuples")]
+    fn py_records_tuples(
+        &mut self,
+        n: usize,
+        schema: &Bound<'_, PyDict>,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let py = schema.py();
+        let custom_names = self.custom_provider_names();
+        let rust_schema = parse_py_schema_with_custom(schema, &custom_names)?;
+        validate_batch_size(n).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        // Get field order from BTreeMap (sorted alphabetically)
+        let field_order: Vec<String> = rust_schema.keys().cloned().collect();
+
+        let records = providers::records::generate_records_tuples_with_custom(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+            &rust_schema,
+            &field_order,
+            &self.custom_providers,
+        )
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        records
+            .into_iter()
+            .map(|record| {
+                let values: Vec<Py<PyAny>> = record
+                    .into_iter()
+                    .map(|v| value_to_pyobject(py, v))
+                    .collect::<PyResult<_>>()?;
+                PyTuple::new(py, values)?.into_py_any(py)
+            })
+            .collect()
+    }
+
+    /// Generate records as a PyArrow RecordBatch.
+    ///
+    /// This is the high-performance path for generating structured data,
+    /// suitable for use with PyArrow, Polars, and other Arrow-compatible tools.
+    ///
+    /// The data is generated in columnar format and returned as a PyArrow RecordBatch,
+    /// which can be used directly with pandas, polars, or other data processing tools.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of records to generate
+    /// * `schema` - Schema dictionary mapping field names to type specifications
+    ///
+    /// # Returns
+    ///
+    /// A PyArrow RecordBatch with the generated data.
+    ///
+    /// # Example
+    ///
+    /// ```python
+    /// import pyarrow as pa
+    /// from forgery import Faker
+    ///
+    /// fake = Faker()
+    /// batch = fake.records_arrow(1000, {
+    ///     "id": "uuid",
+    ///     "name": "name",
+    ///     "age": ("int", 18, 65),
+    ///     "salary": ("float", 30000.0, 150000.0),
+    /// })
+    /// # batch is a pyarrow.RecordBatch
+    /// df = batch.to_pandas()  # Convert to pandas DataFrame
+    /// ```
+    #[pyo3(name = "records_arrow")]
+    fn py_records_arrow(
+        &mut self,
+        py: Python<'_>,
+        n: usize,
+        schema: &Bound<'_, PyDict>,
+    ) -> PyResult<Py<PyAny>> {
+        let custom_names = self.custom_provider_names();
+        let rust_schema = parse_py_schema_with_custom(schema, &custom_names)?;
+        validate_batch_size(n).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        let record_batch = providers::records::generate_records_arrow_with_custom(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n,
+            &rust_schema,
+            &self.custom_providers,
+        )
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        // Convert to PyArrow RecordBatch via pyo3-arrow
+        let py_batch = PyRecordBatch::new(record_batch);
+        py_batch.into_pyarrow(py).map(|bound| bound.unbind())
+    }
+
+    /// Generate a sample of records and return per-field statistics.
+    ///
+    /// Returns a dictionary mapping each field name to a dictionary with
+    /// `count`, `null_count`, `null_rate`, `cardinality`, `min`, `max`,
+    /// `min_length`, `max_length`, and `mean_length`. Length statistics are
+    /// `None` for non-string fields.
+    #[pyo3(name = "describe", signature = (schema, n_sample = 1000))]
+    fn py_describe(&mut self, schema: &Bound<'_, PyDict>, n_sample: usize) -> PyResult<Py<PyAny>> {
+        let py = schema.py();
+        let custom_names = self.custom_provider_names();
+        let rust_schema = parse_py_schema_with_custom(schema, &custom_names)?;
+        validate_batch_size(n_sample).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        let stats = providers::stats::describe_schema_with_custom(
+            &mut self.rng,
+            self.locale,
+            &self.overrides,
+            n_sample,
+            &rust_schema,
+            &self.custom_providers,
+        )
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        let result = PyDict::new(py);
+        for (field, field_stats) in stats {
+            result.set_item(field, field_stats_to_pydict(py, field_stats)?)?;
+        }
+        result.into_py_any(py)
+    }
+
+    // ============================================================================
+    // Async Methods
+    // ============================================================================
+
+    /// Generate records asynchronously for non-blocking batch generation.
+    ///
+    /// This method generates records in chunks, yielding control between chunks
+    /// to allow other async tasks to run. Ideal for generating millions of records
+    /// without blocking the event loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of records to generate
+    /// * `schema` - Schema dictionary mapping field names to type specifications
+    /// * `chunk_size` - Optional number of records per chunk (default: 10,000)
+    ///
+    /// # Returns
+    ///
+    /// A coroutine that resolves to a list of dictionaries.
+    ///
+    /// # Note on RNG State
+    ///
+    /// The async methods use a snapshot of the RNG state at call time. The main
+    /// Faker instance's RNG is not advanced. For different results on each call,
+    /// create separate Faker instances or re-seed between calls.
+    ///
+    /// # Example
+    ///
+    /// ```python
+    /// import asyncio
+    /// from forgery import Faker
+    ///
+    /// async def main():
+    ///     fake = Faker()
+    ///     records = await fake.records_async(1_000_000, {
+    ///         "name": "name",
+    ///         "email": "email",
+    ///     })
+    ///     print(f"Generated {len(records)} records")
+    ///
+    /// asyncio.run(main())
+    /// ```
+    #[pyo3(name = "records_async", signature = (n, schema, chunk_size = None))]
+    fn py_records_async<'py>(
+        &self,
+        py: Python<'py>,
+        n: usize,
+        schema: &Bound<'py, PyDict>,
+        chunk_size: Option<usize>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        use pyo3_async_runtimes::tokio::future_into_py;
+
+        let mut state = self.prepare_async_state(n, schema, chunk_size)?;
+
+        future_into_py(py, async move {
+            let records = providers::async_records::generate_records_async(
+                &mut state.rng,
+                state.locale,
+                &state.overrides,
+                n,
+                &state.schema,
+                state.chunk_size,
+                &state.custom_providers,
+            )
+            .await
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+            // Convert to Python objects
+            Python::attach(|py| {
+                let py_records: PyResult<Vec<Py<PyAny>>> = records
+                    .into_iter()
+                    .map(|record| {
+                        let dict = PyDict::new(py);
+                        for (key, value) in record {
+                            dict.set_item(key, value_to_pyobject(py, value)?)?;
+                        }
+                        dict.into_py_any(py)
+                    })
+                    .collect();
+                py_records
+            })
+        })
+    }
+
+    /// Generate records as tuples asynchronously for non-blocking batch generation.
+    ///
+    /// Similar to records_async() but returns tuples instead of dictionaries,
+    /// which is faster for large datasets.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of records to generate
+    /// * `schema` - Schema dictionary mapping field names to type specifications
+    /// * `chunk_size` - Optional number of records per chunk (default: 10,000)
+    ///
+    /// # Returns
+    ///
+    /// A coroutine that resolves to a list of tuples (values in alphabetical field order).
+    #[pyo3(name = "records_tuples_async", signature = (n, schema, chunk_size = None))]
+    fn py_records_tuples_async<'py>(
+        &self,
+        py: Python<'py>,
+        n: usize,
+        schema: &Bound<'py, PyDict>,
+        chunk_size: Option<usize>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        use pyo3_async_runtimes::tokio::future_into_py;
+
+        let mut state = self.prepare_async_state(n, schema, chunk_size)?;
+        let field_order: Vec<String> = state.schema.keys().cloned().collect();
+
+        future_into_py(py, async move {
+            let records = providers::async_records::generate_records_tuples_async(
+                &mut state.rng,
+                state.locale,
+                &state.overrides,
+                n,
+                &state.schema,
+                &field_order,
+                state.chunk_size,
+                &state.custom_providers,
+            )
+            .await
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+            // Convert to Python objects
+            Python::attach(|py| {
+                let py_records: PyResult<Vec<Py<PyAny>>> = records
+                    .into_iter()
+                    .map(|record| {
+                        let values: Vec<Py<PyAny>> = record
+                            .into_iter()
+                            .map(|v| value_to_pyobject(py, v))
+                            .collect::<PyResult<_>>()?;
+                        PyTuple::new(py, values)?.into_py_any(py)
+                    })
+                    .collect();
+                py_records
+            })
+        })
+    }
+
+    /// Generate records as an Arrow RecordBatch asynchronously.
+    ///
+    /// This is the high-performance async path for generating structured data.
+    /// Generates data in chunks and concatenates them into a single RecordBatch.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of records to generate
+    /// * `schema` - Schema dictionary mapping field names to type specifications
+    /// * `chunk_size` - Optional number of records per chunk (default: 10,000)
+    ///
+    /// # Returns
+    ///
+    /// A coroutine that resolves to a PyArrow RecordBatch.
+    #[pyo3(name = "records_arrow_async", signature = (n, schema, chunk_size = None))]
+    fn py_records_arrow_async<'py>(
+        &self,
+        py: Python<'py>,
+        n: usize,
+        schema: &Bound<'py, PyDict>,
+        chunk_size: Option<usize>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        use pyo3_async_runtimes::tokio::future_into_py;
+
         let mut state = self.prepare_async_state(n, schema, chunk_size)?;
 
-        future_into_py(py, async move {
-            let record_batch = providers::async_records::generate_records_arrow_async(
-                &mut state.rng,
-                state.locale,
-                n,
-                &state.schema,
-                state.chunk_size,
-                &state.custom_providers,
-            )
-            .await
+        future_into_py(py, async move {
+            let record_batch = providers::async_records::generate_records_arrow_async(
+                &mut state.rng,
+                state.locale,
+                &state.overrides,
+                n,
+                &state.schema,
+                state.chunk_size,
+                &state.custom_providers,
+            )
+            .await
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+            // Convert to PyArrow RecordBatch
+            // Preserve original error type (e.g., ImportError if pyarrow missing)
+            Python::attach(|py| {
+                let py_batch = PyRecordBatch::new(record_batch);
+                py_batch.into_pyarrow(py).map(|bound| bound.unbind())
+            })
+        })
+    }
+}
+
+/// Prepared state for async record generation operations.
+///
+/// This struct bundles all the validated and cloned state needed for async operations,
+/// reducing code duplication across the three async methods.
+struct AsyncRecordState {
+    rng: ForgeryRng,
+    locale: Locale,
+    overrides: Arc<LocaleOverrides>,
+    schema: BTreeMap<String, providers::records::FieldSpec>,
+    chunk_size: usize,
+    custom_providers: HashMap<String, CustomProvider>,
+}
+
+impl Faker {
+    /// Prepare state for async record generation.
+    ///
+    /// Validates inputs and clones necessary state for use in async blocks.
+    fn prepare_async_state(
+        &self,
+        n: usize,
+        schema: &Bound<'_, PyDict>,
+        chunk_size: Option<usize>,
+    ) -> PyResult<AsyncRecordState> {
+        validate_batch_size(n).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let custom_names = self.custom_provider_names();
+        let rust_schema = parse_py_schema_with_custom(schema, &custom_names)?;
+
+        Ok(AsyncRecordState {
+            rng: self.rng.clone(),
+            locale: self.locale,
+            overrides: Arc::clone(&self.overrides),
+            schema: rust_schema,
+            chunk_size: chunk_size.unwrap_or(providers::async_records::DEFAULT_CHUNK_SIZE),
+            custom_providers: self.custom_providers.clone(),
+        })
+    }
+}
+
+/// Parse a Python schema dictionary into a Rust BTreeMap, with custom provider support.
+fn parse_py_schema_with_custom(
+    schema: &Bound<'_, PyDict>,
+    custom_provider_names: &HashSet<String>,
+) -> PyResult<BTreeMap<String, providers::records::FieldSpec>> {
+    // Validate schema size to prevent DoS attacks via huge schemas
+    validate_schema_size(schema.len()).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    let mut rust_schema = BTreeMap::new();
+
+    for (key, value) in schema.iter() {
+        let field_name: String = key.extract()?;
+        let field_spec = parse_field_spec_with_custom(&value, custom_provider_names)?;
+        rust_schema.insert(field_name, field_spec);
+    }
+
+    Ok(rust_schema)
+}
+
+/// Parse a Python field specification into a Rust FieldSpec, with custom provider support.
+fn parse_field_spec_with_custom(
+    value: &Bound<'_, PyAny>,
+    custom_provider_names: &HashSet<String>,
+) -> PyResult<providers::records::FieldSpec> {
+    if value.is_instance_of::<PyString>() {
+        return parse_string_field_spec_with_custom(value, custom_provider_names);
+    }
+    if value.is_instance_of::<PyTuple>() {
+        let tuple: Vec<Bound<'_, PyAny>> = value.extract()?;
+        if let Some(first) = tuple.first() {
+            if first
+                .extract::<String>()
+                .is_ok_and(|name| name == "nullable")
+            {
+                return parse_nullable_spec(&tuple, custom_provider_names);
+            }
+        }
+        return parse_tuple_field_spec(value);
+    }
+    Err(PyValueError::new_err(
+        "Field specification must be a string or tuple",
+    ))
+}
+
+/// Parse a simple string type specification, with custom provider support.
+fn parse_string_field_spec_with_custom(
+    value: &Bound<'_, PyAny>,
+    custom_provider_names: &HashSet<String>,
+) -> PyResult<providers::records::FieldSpec> {
+    let type_str: String = value.extract()?;
+    providers::records::parse_simple_type_with_custom(&type_str, custom_provider_names)
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Parse a nullable specification like ("nullable", "email", 0.1).
+///
+/// The wrapped specification may be any string or tuple specification,
+/// including a custom provider name.
+fn parse_nullable_spec(
+    tuple: &[Bound<'_, PyAny>],
+    custom_provider_names: &HashSet<String>,
+) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 3 {
+        return Err(PyValueError::new_err(
+            "Nullable specification must be (\"nullable\", spec, null_rate)",
+        ));
+    }
+    let inner = parse_field_spec_with_custom(&tuple[1], custom_provider_names)?;
+    let null_rate: f64 = tuple[2].extract()?;
+    let spec = providers::records::FieldSpec::Nullable {
+        inner: Box::new(inner),
+        null_rate,
+    };
+    providers::records::validate_spec(&spec).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(spec)
+}
+
+/// Read a count given as an int or an inclusive `(min, max)` tuple, or
+/// `default` if omitted.
+fn extract_count_range(
+    value: Option<&Bound<'_, PyAny>>,
+    name: &str,
+    default: (usize, usize),
+) -> PyResult<(usize, usize)> {
+    let Some(value) = value else {
+        return Ok(default);
+    };
+    if let Ok(count) = value.extract::<usize>() {
+        return Ok((count, count));
+    }
+    value.extract::<(usize, usize)>().map_err(|_| {
+        PyValueError::new_err(format!(
+            "{} must be a non-negative int or a (min, max) tuple of them",
+            name
+        ))
+    })
+}
+
+/// Parse a tuple type specification like ("int", min, max).
+fn parse_tuple_field_spec(value: &Bound<'_, PyAny>) -> PyResult<providers::records::FieldSpec> {
+    let tuple: Vec<Bound<'_, PyAny>> = value.extract()?;
+    if tuple.len() < 2 {
+        return Err(PyValueError::new_err(
+            "Tuple specification must have at least 2 elements",
+        ));
+    }
+
+    let type_name: String = tuple[0].extract()?;
+    match type_name.as_str() {
+        "int" => parse_int_range(&tuple),
+        "float" => parse_float_range(&tuple),
+        "bool" => parse_bool_spec(&tuple),
+        "text" => parse_text_spec(&tuple),
+        "date" => parse_date_range(&tuple),
+        "business_date" => parse_business_date_spec(&tuple),
+        "date_of_birth_with_age" => parse_date_of_birth_with_age_spec(&tuple),
+        "duration" => parse_duration_spec(&tuple),
+        "age" => parse_age_spec(&tuple),
+        "gender" => parse_demographic_spec(&tuple, providers::demographics::Demographic::Gender),
+        "pronouns" => {
+            parse_demographic_spec(&tuple, providers::demographics::Demographic::Pronouns)
+        }
+        "marital_status" => {
+            parse_demographic_spec(&tuple, providers::demographics::Demographic::MaritalStatus)
+        }
+        "choice" => parse_choice_spec(&tuple),
+        "vat_number" => parse_vat_number_spec(&tuple),
+        "drivers_license" => parse_drivers_license_spec(&tuple),
+        "credit_card" => parse_credit_card_spec(&tuple),
+        "tracking_number" => parse_tracking_number_spec(&tuple),
+        "catch_phrase" => parse_catch_phrase_spec(&tuple),
+        "invoice_number" => parse_invoice_number_spec(&tuple),
+        "policy_number" | "claim_number" => parse_insurance_number_spec(&tuple, &type_name),
+        "sku" => parse_sku_spec(&tuple),
+        "docker_image" => parse_docker_image_spec(&tuple),
+        "k8s_name" => parse_k8s_name_spec(&tuple),
+        "arn" => parse_arn_spec(&tuple),
+        "cloud_region" => parse_cloud_region_spec(&tuple),
+        "syslog" => parse_syslog_spec(&tuple),
+        "retail_price" => parse_retail_price_spec(&tuple),
+        "price" => parse_price_spec(&tuple),
+        "country" => parse_country_spec(&tuple),
+        "email" => parse_email_spec(&tuple),
+        "mime_type" => parse_mime_type_spec(&tuple),
+        "bytes" => parse_bytes_spec(&tuple),
+        "base64" => parse_base64_spec(&tuple),
+        "port" => parse_port_spec(&tuple),
+        "ipv4" => parse_ipv4_spec(&tuple),
+        "http_status_code" => parse_http_status_code_spec(&tuple),
+        "job" => parse_job_spec(&tuple),
+        "css_color" => parse_css_color_spec(&tuple),
+        "recent" | "past" | "future" => parse_relative_spec(&tuple, &type_name),
+        "password_hash" => parse_password_hash_spec(&tuple),
+        "api_key" => parse_api_key_spec(&tuple),
+        "session_id" => parse_token_spec(&tuple, providers::auth::TokenKind::Session),
+        "oauth_access_token" => parse_token_spec(&tuple, providers::auth::TokenKind::AccessToken),
+        "refresh_token" => parse_token_spec(&tuple, providers::auth::TokenKind::RefreshToken),
+        "phone" => parse_phone_spec(&tuple),
+        "address_parts" => parse_address_parts_spec(&tuple),
+        "county" => parse_county_spec(&tuple),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown parameterized type: {}",
+            type_name
+        ))),
+    }
+}
+
+/// Parse an integer range specification: ("int", min, max).
+fn parse_int_range(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 3 {
+        return Err(PyValueError::new_err(
+            "int specification must be (\"int\", min, max)",
+        ));
+    }
+    let min: i64 = tuple[1].extract()?;
+    let max: i64 = tuple[2].extract()?;
+    Ok(providers::records::FieldSpec::IntRange { min, max })
+}
+
+/// Parse a float range specification: ("float", min, max).
+fn parse_float_range(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 3 {
+        return Err(PyValueError::new_err(
+            "float specification must be (\"float\", min, max)",
+        ));
+    }
+    let min: f64 = tuple[1].extract()?;
+    let max: f64 = tuple[2].extract()?;
+    Ok(providers::records::FieldSpec::FloatRange { min, max })
+}
+
+/// Parse a boolean specification: ("bool", true_rate).
+fn parse_bool_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "bool specification must be (\"bool\", true_rate)",
+        ));
+    }
+    let true_rate: f64 = tuple[1].extract()?;
+    let spec = providers::records::FieldSpec::Bool { true_rate };
+    providers::records::validate_spec(&spec).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(spec)
+}
+
+/// Parse a text specification: ("text", min_chars, max_chars).
+fn parse_text_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 3 {
+        return Err(PyValueError::new_err(
+            "text specification must be (\"text\", min_chars, max_chars)",
+        ));
+    }
+    let min_chars: usize = tuple[1].extract()?;
+    let max_chars: usize = tuple[2].extract()?;
+    if min_chars > max_chars {
+        return Err(PyValueError::new_err(format!(
+            "Invalid text range: min_chars ({}) > max_chars ({})",
+            min_chars, max_chars
+        )));
+    }
+    Ok(providers::records::FieldSpec::Text {
+        min_chars,
+        max_chars,
+    })
+}
+
+/// Parse a date range specification: ("date", start, end).
+fn parse_date_range(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 3 {
+        return Err(PyValueError::new_err(
+            "date specification must be (\"date\", start, end)",
+        ));
+    }
+    let start: String = tuple[1].extract()?;
+    let end: String = tuple[2].extract()?;
+    Ok(providers::records::FieldSpec::DateRange { start, end })
+}
+
+/// Parse a business date specification: ("business_date", start, end[, holidays]).
+fn parse_business_date_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if !(3..=4).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(
+            "business_date specification must be (\"business_date\", start, end[, holidays])",
+        ));
+    }
+    let start: String = tuple[1].extract()?;
+    let end: String = tuple[2].extract()?;
+    let holidays: Vec<String> = match tuple.get(3) {
+        Some(holidays) => holidays.extract()?,
+        None => Vec::new(),
+    };
+    Ok(providers::records::FieldSpec::BusinessDate {
+        start,
+        end,
+        holidays,
+    })
+}
+
+/// Parse a date of birth with age specification:
+/// ("date_of_birth_with_age", min_age, max_age).
+fn parse_date_of_birth_with_age_spec(
+    tuple: &[Bound<'_, PyAny>],
+) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 3 {
+        return Err(PyValueError::new_err(
+            "date_of_birth_with_age specification must be (\"date_of_birth_with_age\", min_age, max_age)",
+        ));
+    }
+    let min_age: u32 = tuple[1].extract()?;
+    let max_age: u32 = tuple[2].extract()?;
+    Ok(providers::records::FieldSpec::DateOfBirthWithAge { min_age, max_age })
+}
+
+/// Parse a duration specification: ("duration", min, max[, format]).
+fn parse_duration_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if !(3..=4).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(
+            "duration specification must be (\"duration\", min, max[, format])",
+        ));
+    }
+    let min: u64 = tuple[1].extract()?;
+    let max: u64 = tuple[2].extract()?;
+    let format = match tuple.get(3) {
+        Some(name) => name.extract::<String>()?.parse().map_err(
+            |e: providers::duration::DurationFormatError| PyValueError::new_err(e.to_string()),
+        )?,
+        None => providers::duration::DurationFormat::Iso8601,
+    };
+    Ok(providers::records::FieldSpec::Duration { min, max, format })
+}
+
+/// Parse an age specification: ("age", min, max).
+fn parse_age_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 3 {
+        return Err(PyValueError::new_err(
+            "age specification must be (\"age\", min, max)",
+        ));
+    }
+    let min: u32 = tuple[1].extract()?;
+    let max: u32 = tuple[2].extract()?;
+    Ok(providers::records::FieldSpec::IntRange {
+        min: min.into(),
+        max: max.into(),
+    })
+}
+
+/// Parse a demographic specification: (name, {category: weight}).
+fn parse_demographic_spec(
+    tuple: &[Bound<'_, PyAny>],
+    demographic: providers::demographics::Demographic,
+) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(format!(
+            "{0} specification must be (\"{0}\", {{category: weight}})",
+            demographic.name()
+        )));
+    }
+    let weights: HashMap<String, u32> = tuple[1].extract()?;
+    let weights =
+        providers::demographics::DemographicWeights::new(demographic, &weight_pairs(Some(weights)))
             .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::Demographic { weights })
+}
 
-            // Convert to PyArrow RecordBatch
-            // Preserve original error type (e.g., ImportError if pyarrow missing)
-            Python::attach(|py| {
-                let py_batch = PyRecordBatch::new(record_batch);
-                py_batch.into_pyarrow(py).map(|bound| bound.unbind())
-            })
-        })
+/// Parse a choice specification: ("choice", [options]).
+/// Parse a price specification: ("price", min, max[, currency[, formatted]]).
+fn parse_price_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if !(3..=5).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(
+            "price specification must be (\"price\", min, max[, currency[, formatted]])",
+        ));
+    }
+    let min: f64 = tuple[1].extract()?;
+    let max: f64 = tuple[2].extract()?;
+    let currency = match tuple.get(3) {
+        Some(code) => code
+            .extract::<String>()?
+            .parse()
+            .map_err(|e: providers::finance::CurrencyError| PyValueError::new_err(e.to_string()))?,
+        None => providers::finance::Currency::USD,
+    };
+    let formatted = match tuple.get(4) {
+        Some(flag) => flag.extract()?,
+        None => true,
+    };
+    Ok(providers::records::FieldSpec::Price {
+        min,
+        max,
+        currency,
+        formatted,
+    })
+}
+
+/// Parse a credit card specification: ("credit_card", network).
+fn parse_credit_card_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "credit_card specification must be (\"credit_card\", network)",
+        ));
+    }
+    let network: String = tuple[1].extract()?;
+    let network = network
+        .parse()
+        .map_err(|e: providers::finance::CardNetworkError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::CreditCardNetwork { network })
+}
+
+/// Parse a tracking number specification: ("tracking_number", carrier).
+fn parse_tracking_number_spec(
+    tuple: &[Bound<'_, PyAny>],
+) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "tracking_number specification must be (\"tracking_number\", carrier)",
+        ));
+    }
+    let carrier: String = tuple[1].extract()?;
+    let carrier = carrier
+        .parse()
+        .map_err(|e: providers::logistics::CarrierError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::TrackingNumberCarrier { carrier })
+}
+
+/// Parse a catch phrase specification: ("catch_phrase", style).
+fn parse_catch_phrase_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "catch_phrase specification must be (\"catch_phrase\", style)",
+        ));
+    }
+    let style: String = tuple[1].extract()?;
+    let style = style
+        .parse()
+        .map_err(|e: providers::company::CatchPhraseStyleError| {
+            PyValueError::new_err(e.to_string())
+        })?;
+    Ok(providers::records::FieldSpec::CatchPhraseStyled { style })
+}
+
+/// Parse an invoice number specification:
+/// ("invoice_number", prefix[, digits[, date]]).
+fn parse_invoice_number_spec(
+    tuple: &[Bound<'_, PyAny>],
+) -> PyResult<providers::records::FieldSpec> {
+    if !(2..=4).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(
+            "invoice_number specification must be (\"invoice_number\", prefix[, digits[, date]])",
+        ));
+    }
+    let prefix: String = tuple[1].extract()?;
+    let digits = match tuple.get(2) {
+        Some(digits) => digits.extract()?,
+        None => providers::commerce::DEFAULT_INVOICE_DIGITS,
+    };
+    let date = match tuple.get(3) {
+        Some(date) => date
+            .extract::<Option<String>>()?
+            .map(|name| name.parse())
+            .transpose()
+            .map_err(|e: providers::commerce::InvoiceNumberError| {
+                PyValueError::new_err(e.to_string())
+            })?,
+        None => Some(providers::commerce::InvoiceDate::Year),
+    };
+    Ok(providers::records::FieldSpec::InvoiceNumber {
+        prefix,
+        digits,
+        date,
+    })
+}
+
+/// Parse an insurance number specification: (name, prefix[, digits]) where
+/// name is "policy_number" or "claim_number".
+fn parse_insurance_number_spec(
+    tuple: &[Bound<'_, PyAny>],
+    name: &str,
+) -> PyResult<providers::records::FieldSpec> {
+    if !(2..=3).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(format!(
+            "{} specification must be (\"{}\", prefix[, digits])",
+            name, name
+        )));
+    }
+    let prefix: String = tuple[1].extract()?;
+    let digits = match tuple.get(2) {
+        Some(digits) => digits.extract()?,
+        None => providers::insurance::DEFAULT_INSURANCE_DIGITS,
+    };
+    Ok(providers::records::FieldSpec::InsuranceNumber { prefix, digits })
+}
+
+/// Parse a retail price specification: ("retail_price", tier[, decimal]).
+fn parse_retail_price_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if !(2..=3).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(
+            "retail_price specification must be (\"retail_price\", tier[, decimal])",
+        ));
+    }
+    let tier: String = tuple[1].extract()?;
+    let tier = tier
+        .parse()
+        .map_err(|e: providers::commerce::PriceTierError| PyValueError::new_err(e.to_string()))?;
+    let decimal = match tuple.get(2) {
+        Some(flag) => flag.extract()?,
+        None => false,
+    };
+    Ok(providers::records::FieldSpec::RetailPrice { tier, decimal })
+}
+
+/// Parse a container image specification:
+/// ("docker_image", registry[, tag[, digest]]).
+fn parse_docker_image_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if !(2..=4).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(
+            "docker_image specification must be (\"docker_image\", registry[, tag[, digest]])",
+        ));
+    }
+    let registry: bool = tuple[1].extract()?;
+    let tag = match tuple.get(2) {
+        Some(flag) => flag.extract()?,
+        None => true,
+    };
+    let digest = match tuple.get(3) {
+        Some(flag) => flag.extract()?,
+        None => false,
+    };
+    Ok(providers::records::FieldSpec::DockerImage {
+        registry,
+        tag,
+        digest,
+    })
+}
+
+/// Parse a Kubernetes name specification: ("k8s_name", kind).
+fn parse_k8s_name_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "k8s_name specification must be (\"k8s_name\", kind)",
+        ));
+    }
+    let kind: String = tuple[1].extract()?;
+    let kind = kind
+        .parse()
+        .map_err(|e: providers::software::K8sResourceError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::K8sName { kind })
+}
+
+/// Parse an ARN specification: ("arn", service).
+fn parse_arn_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "arn specification must be (\"arn\", service)",
+        ));
+    }
+    let service: String = tuple[1].extract()?;
+    let service = service
+        .parse()
+        .map_err(|e: providers::cloud::ArnServiceError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::ArnService { service })
+}
+
+/// Parse a cloud region specification: ("cloud_region", provider).
+fn parse_cloud_region_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "cloud_region specification must be (\"cloud_region\", provider)",
+        ));
+    }
+    let provider: String = tuple[1].extract()?;
+    let provider = provider
+        .parse()
+        .map_err(|e: providers::cloud::CloudProviderError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::CloudRegion { provider })
+}
+
+/// Parse a syslog specification: ("syslog", format).
+fn parse_syslog_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "syslog specification must be (\"syslog\", format)",
+        ));
+    }
+    let format: String = tuple[1].extract()?;
+    let format = format
+        .parse()
+        .map_err(|e: providers::logs::SyslogFormatError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::Syslog { format })
+}
+
+/// Parse a SKU specification: ("sku", pattern).
+fn parse_sku_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "sku specification must be (\"sku\", pattern)",
+        ));
     }
+    let pattern: String = tuple[1].extract()?;
+    Ok(providers::records::FieldSpec::SkuPattern { pattern })
 }
 
-/// Prepared state for async record generation operations.
-///
-/// This struct bundles all the validated and cloned state needed for async operations,
-/// reducing code duplication across the three async methods.
-struct AsyncRecordState {
-    rng: ForgeryRng,
-    locale: Locale,
-    schema: BTreeMap<String, providers::records::FieldSpec>,
-    chunk_size: usize,
-    custom_providers: HashMap<String, CustomProvider>,
+/// Parse a country specification: ("country", format).
+fn parse_country_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "country specification must be (\"country\", format)",
+        ));
+    }
+    let format: String = tuple[1].extract()?;
+    let format = format
+        .parse()
+        .map_err(|e: providers::address::CountryFormatError| {
+            PyValueError::new_err(e.to_string())
+        })?;
+    Ok(providers::records::FieldSpec::CountryFormatted { format })
 }
 
-impl Faker {
-    /// Prepare state for async record generation.
-    ///
-    /// Validates inputs and clones necessary state for use in async blocks.
-    fn prepare_async_state(
-        &self,
-        n: usize,
-        schema: &Bound<'_, PyDict>,
-        chunk_size: Option<usize>,
-    ) -> PyResult<AsyncRecordState> {
-        validate_batch_size(n).map_err(|e| PyValueError::new_err(e.to_string()))?;
-        let custom_names = self.custom_provider_names();
-        let rust_schema = parse_py_schema_with_custom(schema, &custom_names)?;
+/// Parse a VAT number specification: ("vat_number", country).
+fn parse_vat_number_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "vat_number specification must be (\"vat_number\", country)",
+        ));
+    }
+    let country: String = tuple[1].extract()?;
+    Ok(providers::records::FieldSpec::VatNumber {
+        country: Some(country),
+    })
+}
 
-        Ok(AsyncRecordState {
-            rng: self.rng.clone(),
-            locale: self.locale,
-            schema: rust_schema,
-            chunk_size: chunk_size.unwrap_or(providers::async_records::DEFAULT_CHUNK_SIZE),
-            custom_providers: self.custom_providers.clone(),
-        })
+/// Parse a driver's license specification: ("drivers_license", region).
+fn parse_drivers_license_spec(
+    tuple: &[Bound<'_, PyAny>],
+) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "drivers_license specification must be (\"drivers_license\", region)",
+        ));
     }
+    let region: String = tuple[1].extract()?;
+    Ok(providers::records::FieldSpec::DriversLicense {
+        region: Some(region),
+    })
 }
 
-/// Parse a Python schema dictionary into a Rust BTreeMap, with custom provider support.
-fn parse_py_schema_with_custom(
-    schema: &Bound<'_, PyDict>,
-    custom_provider_names: &HashSet<String>,
-) -> PyResult<BTreeMap<String, providers::records::FieldSpec>> {
-    // Validate schema size to prevent DoS attacks via huge schemas
-    validate_schema_size(schema.len()).map_err(|e| PyValueError::new_err(e.to_string()))?;
+/// Parse an IPv4 network specification: ("ipv4", network).
+fn parse_ipv4_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "ipv4 specification must be (\"ipv4\", network)",
+        ));
+    }
+    let network: String = tuple[1].extract()?;
+    let range = network
+        .parse()
+        .map_err(|e: providers::network::Ipv4RangeError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::Ipv4Network { range })
+}
 
-    let mut rust_schema = BTreeMap::new();
+/// Parse an address parts specification: ("address_parts", unit_rate).
+fn parse_address_parts_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "address_parts specification must be (\"address_parts\", unit_rate)",
+        ));
+    }
+    let unit_rate: f64 = tuple[1].extract()?;
+    providers::address::AddressOptions {
+        unit_rate,
+        po_box_rate: 0.0,
+    }
+    .validate()
+    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::AddressParts { unit_rate })
+}
 
-    for (key, value) in schema.iter() {
-        let field_name: String = key.extract()?;
-        let field_spec = parse_field_spec_with_custom(&value, custom_provider_names)?;
-        rust_schema.insert(field_name, field_spec);
+/// Parse a county specification: ("county", state[, with_state]).
+fn parse_county_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if !(2..=3).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(
+            "county specification must be (\"county\", state[, with_state])",
+        ));
     }
+    let state: Option<String> = tuple[1].extract()?;
+    let with_state = match tuple.get(2) {
+        Some(with_state) => with_state.extract()?,
+        None => false,
+    };
+    Ok(providers::records::FieldSpec::County { state, with_state })
+}
 
-    Ok(rust_schema)
+/// Parse a port specification: ("port", kind).
+fn parse_port_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "port specification must be (\"port\", kind)",
+        ));
+    }
+    let kind: String = tuple[1].extract()?;
+    let kind = kind
+        .parse()
+        .map_err(|e: providers::network::PortKindError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::Port { kind })
 }
 
-/// Parse a Python field specification into a Rust FieldSpec, with custom provider support.
-fn parse_field_spec_with_custom(
-    value: &Bound<'_, PyAny>,
-    custom_provider_names: &HashSet<String>,
+/// Parse an HTTP status code specification: ("http_status_code", class).
+fn parse_http_status_code_spec(
+    tuple: &[Bound<'_, PyAny>],
 ) -> PyResult<providers::records::FieldSpec> {
-    if value.is_instance_of::<PyString>() {
-        return parse_string_field_spec_with_custom(value, custom_provider_names);
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "http_status_code specification must be (\"http_status_code\", class)",
+        ));
     }
-    if value.is_instance_of::<PyTuple>() {
-        return parse_tuple_field_spec(value);
+    let class: String = tuple[1].extract()?;
+    let class = class
+        .parse()
+        .map_err(|e: providers::network::StatusClassError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::HttpStatusCode { class: Some(class) })
+}
+
+/// Parse a job specification: ("job", level).
+fn parse_job_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "job specification must be (\"job\", level)",
+        ));
     }
-    Err(PyValueError::new_err(
-        "Field specification must be a string or tuple",
-    ))
+    let level: String = tuple[1].extract()?;
+    let level = level
+        .parse()
+        .map_err(|e: providers::company::JobLevelError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::Job { level: Some(level) })
 }
 
-/// Parse a simple string type specification, with custom provider support.
-fn parse_string_field_spec_with_custom(
-    value: &Bound<'_, PyAny>,
-    custom_provider_names: &HashSet<String>,
+/// Parse a relative datetime specification: ("recent", days), ("past", years)
+/// or ("future", years).
+fn parse_relative_spec(
+    tuple: &[Bound<'_, PyAny>],
+    kind: &str,
 ) -> PyResult<providers::records::FieldSpec> {
-    let type_str: String = value.extract()?;
-    providers::records::parse_simple_type_with_custom(&type_str, custom_provider_names)
-        .map_err(|e| PyValueError::new_err(e.to_string()))
+    use providers::datetime::RelativeRange;
+
+    let unit = if kind == "recent" { "days" } else { "years" };
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(format!(
+            "{kind} specification must be (\"{kind}\", {unit})"
+        )));
+    }
+    let amount: u32 = tuple[1].extract()?;
+    let range = match kind {
+        "recent" => RelativeRange::Recent(amount),
+        "past" => RelativeRange::Past(amount),
+        _ => RelativeRange::Future(amount),
+    };
+    Ok(providers::records::FieldSpec::RelativeDateTime { range })
 }
 
-/// Parse a tuple type specification like ("int", min, max).
-fn parse_tuple_field_spec(value: &Bound<'_, PyAny>) -> PyResult<providers::records::FieldSpec> {
-    let tuple: Vec<Bound<'_, PyAny>> = value.extract()?;
-    if tuple.len() < 2 {
+/// Parse a CSS color specification: ("css_color", format).
+fn parse_css_color_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
         return Err(PyValueError::new_err(
-            "Tuple specification must have at least 2 elements",
+            "css_color specification must be (\"css_color\", format)",
         ));
     }
-
-    let type_name: String = tuple[0].extract()?;
-    match type_name.as_str() {
-        "int" => parse_int_range(&tuple),
-        "float" => parse_float_range(&tuple),
-        "text" => parse_text_spec(&tuple),
-        "date" => parse_date_range(&tuple),
-        "choice" => parse_choice_spec(&tuple),
-        _ => Err(PyValueError::new_err(format!(
-            "Unknown parameterized type: {}",
-            type_name
-        ))),
-    }
+    let format: String = tuple[1].extract()?;
+    let format = format
+        .parse()
+        .map_err(|e: providers::colors::CssColorFormatError| {
+            PyValueError::new_err(e.to_string())
+        })?;
+    Ok(providers::records::FieldSpec::CssColor {
+        format: Some(format),
+    })
 }
 
-/// Parse an integer range specification: ("int", min, max).
-fn parse_int_range(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
-    if tuple.len() != 3 {
+/// Parse an email specification: ("email", domain).
+fn parse_email_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
         return Err(PyValueError::new_err(
-            "int specification must be (\"int\", min, max)",
+            "email specification must be (\"email\", domain)",
         ));
     }
-    let min: i64 = tuple[1].extract()?;
-    let max: i64 = tuple[2].extract()?;
-    Ok(providers::records::FieldSpec::IntRange { min, max })
+    let domain: String = tuple[1].extract()?;
+    Ok(providers::records::FieldSpec::EmailWithDomain { domain })
 }
 
-/// Parse a float range specification: ("float", min, max).
-fn parse_float_range(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
-    if tuple.len() != 3 {
+/// Parse a JWT algorithm name, raising `ValueError` for unknown names.
+fn parse_jwt_algorithm(algorithm: &str) -> PyResult<providers::auth::JwtAlgorithm> {
+    algorithm
+        .parse()
+        .map_err(|e: providers::auth::JwtAlgorithmError| PyValueError::new_err(e.to_string()))
+}
+
+/// Parse a password hash specification: ("password_hash", algorithm).
+fn parse_password_hash_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
         return Err(PyValueError::new_err(
-            "float specification must be (\"float\", min, max)",
+            "password_hash specification must be (\"password_hash\", algorithm)",
         ));
     }
-    let min: f64 = tuple[1].extract()?;
-    let max: f64 = tuple[2].extract()?;
-    Ok(providers::records::FieldSpec::FloatRange { min, max })
+    let algorithm: String = tuple[1].extract()?;
+    let algorithm =
+        algorithm
+            .parse()
+            .map_err(|e: providers::auth::PasswordHashAlgorithmError| {
+                PyValueError::new_err(e.to_string())
+            })?;
+    Ok(providers::records::FieldSpec::PasswordHash { algorithm })
 }
 
-/// Parse a text specification: ("text", min_chars, max_chars).
-fn parse_text_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
-    if tuple.len() != 3 {
+/// Parse an API key specification: ("api_key", prefix[, length[, alphabet]]).
+fn parse_api_key_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if !(2..=4).contains(&tuple.len()) {
         return Err(PyValueError::new_err(
-            "text specification must be (\"text\", min_chars, max_chars)",
+            "api_key specification must be (\"api_key\", prefix[, length[, alphabet]])",
         ));
     }
-    let min_chars: usize = tuple[1].extract()?;
-    let max_chars: usize = tuple[2].extract()?;
-    if min_chars > max_chars {
+    let prefix: String = tuple[1].extract()?;
+    let length = match tuple.get(2) {
+        Some(length) => length.extract()?,
+        None => providers::auth::DEFAULT_API_KEY_LENGTH,
+    };
+    let alphabet = match tuple.get(3) {
+        Some(name) => name.extract::<String>()?.parse().map_err(
+            |e: providers::auth::ApiKeyAlphabetError| PyValueError::new_err(e.to_string()),
+        )?,
+        None => providers::auth::ApiKeyAlphabet::default(),
+    };
+    Ok(providers::records::FieldSpec::ApiKey {
+        prefix,
+        length,
+        alphabet,
+    })
+}
+
+/// Parse an opaque token specification: (name, format) where name is
+/// "session_id", "oauth_access_token" or "refresh_token".
+fn parse_token_spec(
+    tuple: &[Bound<'_, PyAny>],
+    kind: providers::auth::TokenKind,
+) -> PyResult<providers::records::FieldSpec> {
+    let name: String = tuple[0].extract()?;
+    if tuple.len() != 2 {
         return Err(PyValueError::new_err(format!(
-            "Invalid text range: min_chars ({}) > max_chars ({})",
-            min_chars, max_chars
+            "{} specification must be (\"{}\", format)",
+            name, name
         )));
     }
-    Ok(providers::records::FieldSpec::Text {
-        min_chars,
-        max_chars,
+    let format = tuple[1]
+        .extract::<String>()?
+        .parse()
+        .map_err(|e: providers::auth::TokenFormatError| PyValueError::new_err(e.to_string()))?;
+    Ok(providers::records::FieldSpec::Token { kind, format })
+}
+
+/// Parse a phone specification: ("phone", format[, kind[, safe]]).
+fn parse_phone_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if !(2..=4).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(
+            "phone specification must be (\"phone\", format[, kind[, safe]])",
+        ));
+    }
+    let format: String = tuple[1].extract()?;
+    let format = format
+        .parse()
+        .map_err(|e: providers::phone::PhoneNumberFormatError| {
+            PyValueError::new_err(e.to_string())
+        })?;
+    let kind = match tuple.get(2) {
+        Some(kind) => kind
+            .extract::<String>()?
+            .parse()
+            .map_err(|e: providers::phone::PhoneKindError| PyValueError::new_err(e.to_string()))?,
+        None => providers::phone::PhoneKind::default(),
+    };
+    let safe = match tuple.get(3) {
+        Some(flag) => flag.extract()?,
+        None => false,
+    };
+    Ok(providers::records::FieldSpec::Phone {
+        options: providers::phone::PhoneOptions { format, kind, safe },
     })
 }
 
-/// Parse a date range specification: ("date", start, end).
-fn parse_date_range(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
-    if tuple.len() != 3 {
+/// Parse a MIME type specification: ("mime_type", category).
+fn parse_mime_type_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
         return Err(PyValueError::new_err(
-            "date specification must be (\"date\", start, end)",
+            "mime_type specification must be (\"mime_type\", category)",
         ));
     }
-    let start: String = tuple[1].extract()?;
-    let end: String = tuple[2].extract()?;
-    Ok(providers::records::FieldSpec::DateRange { start, end })
+    let category: String = tuple[1].extract()?;
+    Ok(providers::records::FieldSpec::MimeType {
+        category: Some(category),
+    })
+}
+
+/// Parse a random bytes specification: ("bytes", length).
+fn parse_bytes_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if tuple.len() != 2 {
+        return Err(PyValueError::new_err(
+            "bytes specification must be (\"bytes\", length)",
+        ));
+    }
+    let length: usize = tuple[1].extract()?;
+    Ok(providers::records::FieldSpec::Bytes { length })
+}
+
+/// Parse a base64 specification: ("base64", decoded_length[, url_safe]).
+fn parse_base64_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
+    if !(2..=3).contains(&tuple.len()) {
+        return Err(PyValueError::new_err(
+            "base64 specification must be (\"base64\", decoded_length[, url_safe])",
+        ));
+    }
+    let decoded_length: usize = tuple[1].extract()?;
+    let url_safe = match tuple.get(2) {
+        Some(url_safe) => url_safe.extract()?,
+        None => false,
+    };
+    Ok(providers::records::FieldSpec::Base64 {
+        decoded_length,
+        url_safe,
+    })
 }
 
-/// Parse a choice specification: ("choice", [options]).
 fn parse_choice_spec(tuple: &[Bound<'_, PyAny>]) -> PyResult<providers::records::FieldSpec> {
     if tuple.len() != 2 {
         return Err(PyValueError::new_err(
@@ -2894,10 +10945,197 @@ fn value_to_pyobject(py: Python<'_>, value: providers::records::Value) -> PyResu
         providers::records::Value::String(s) => Ok(s.into_pyobject(py)?.into_any().unbind()),
         providers::records::Value::Int(i) => Ok(i.into_pyobject(py)?.into_any().unbind()),
         providers::records::Value::Float(f) => Ok(f.into_pyobject(py)?.into_any().unbind()),
+        providers::records::Value::Bool(b) => b.into_py_any(py),
         providers::records::Value::Tuple3U8(r, g, b) => {
             Ok(PyTuple::new(py, [r, g, b])?.into_any().unbind())
         }
+        providers::records::Value::Tuple3U16(a, b, c) => {
+            Ok(PyTuple::new(py, [a, b, c])?.into_any().unbind())
+        }
+        providers::records::Value::Tuple4U8(a, b, c, d) => {
+            Ok(PyTuple::new(py, [a, b, c, d])?.into_any().unbind())
+        }
+        providers::records::Value::Tuple2F64(a, b) => {
+            Ok(PyTuple::new(py, [a, b])?.into_any().unbind())
+        }
+        providers::records::Value::TupleStrU32(s, i) => (s, i).into_py_any(py),
+        providers::records::Value::Decimal(..) => to_py_decimal(py, &value.as_string()),
+        providers::records::Value::Bytes(bytes) => Ok(PyBytes::new(py, &bytes).into_any().unbind()),
+        providers::records::Value::Null => Ok(py.None()),
+        providers::records::Value::Struct(fields) => {
+            let dict = PyDict::new(py);
+            for (name, value) in fields {
+                dict.set_item(name, value)?;
+            }
+            dict.into_py_any(py)
+        }
+    }
+}
+
+/// Build a Python `decimal.Decimal` from its string form.
+fn to_py_decimal(py: Python<'_>, text: &str) -> PyResult<Py<PyAny>> {
+    Ok(py
+        .import("decimal")?
+        .getattr("Decimal")?
+        .call1((text,))?
+        .unbind())
+}
+
+/// Convert an optional Python weights dict to (category, weight) pairs.
+fn weight_pairs(weights: Option<HashMap<String, u32>>) -> Vec<(String, u32)> {
+    weights.map(|w| w.into_iter().collect()).unwrap_or_default()
+}
+
+/// Convert a person profile to a Python dictionary.
+fn profile_to_pydict(
+    py: Python<'_>,
+    profile: providers::profile::Profile,
+) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("name", profile.name)?;
+    dict.set_item("first_name", profile.first_name)?;
+    dict.set_item("last_name", profile.last_name)?;
+    dict.set_item("gender", profile.gender)?;
+    dict.set_item("date_of_birth", profile.date_of_birth)?;
+    dict.set_item("age", profile.age)?;
+    dict.set_item("email", profile.email)?;
+    dict.set_item("phone", profile.phone)?;
+    dict.set_item("street_address", profile.street_address)?;
+    dict.set_item("city", profile.city)?;
+    dict.set_item("state", profile.state)?;
+    dict.set_item("postal_code", profile.postal_code)?;
+    dict.set_item("address", profile.address)?;
+    Ok(dict)
+}
+
+/// Convert address parts to a Python dictionary.
+fn address_parts_to_pydict(
+    py: Python<'_>,
+    parts: providers::address::AddressParts,
+) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    for (name, value) in providers::address::ADDRESS_PARTS_FIELDS
+        .iter()
+        .zip(parts.into_values())
+    {
+        dict.set_item(*name, value)?;
+    }
+    Ok(dict)
+}
+
+/// Convert a company profile to a Python dictionary.
+fn company_profile_to_pydict(
+    py: Python<'_>,
+    profile: providers::company::CompanyProfile,
+) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    for (name, value) in providers::company::COMPANY_PROFILE_FIELDS
+        .iter()
+        .zip(profile.into_values())
+    {
+        dict.set_item(*name, value)?;
     }
+    Ok(dict)
+}
+
+/// Convert a vehicle to a Python dictionary.
+fn vehicle_to_pydict(
+    py: Python<'_>,
+    vehicle: providers::vehicle::Vehicle,
+) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("make", vehicle.make)?;
+    dict.set_item("model", vehicle.model)?;
+    dict.set_item("year", vehicle.year)?;
+    dict.set_item("vin", vehicle.vin)?;
+    Ok(dict)
+}
+
+/// Convert an access log entry to a Python dictionary.
+fn access_log_entry_to_pydict(
+    py: Python<'_>,
+    entry: providers::logs::AccessLogEntry,
+) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("ip", entry.ip)?;
+    dict.set_item("timestamp", entry.timestamp)?;
+    dict.set_item("method", entry.method)?;
+    dict.set_item("path", entry.path)?;
+    dict.set_item("protocol", entry.protocol)?;
+    dict.set_item("status", entry.status)?;
+    dict.set_item("size", entry.size)?;
+    dict.set_item("referrer", entry.referrer)?;
+    dict.set_item("user_agent", entry.user_agent)?;
+    Ok(dict)
+}
+
+/// Convert a GPS track fix to a Python dictionary.
+fn gps_point_to_pydict(
+    py: Python<'_>,
+    point: providers::geo::GpsPoint,
+) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("timestamp", point.timestamp)?;
+    dict.set_item("latitude", point.latitude)?;
+    dict.set_item("longitude", point.longitude)?;
+    dict.set_item("speed_kmh", point.speed_kmh)?;
+    dict.set_item("heading", point.heading)?;
+    Ok(dict)
+}
+
+/// Convert a sensor reading to a Python dictionary.
+fn sensor_reading_to_pydict(
+    py: Python<'_>,
+    reading: providers::timeseries::SensorReading,
+) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("timestamp", reading.timestamp_iso())?;
+    dict.set_item("device_id", reading.device_id)?;
+    dict.set_item("metric", reading.metric)?;
+    dict.set_item("value", reading.value)?;
+    dict.set_item("unit", reading.unit)?;
+    Ok(dict)
+}
+
+/// Convert a syslog entry to a Python dictionary.
+fn syslog_entry_to_pydict(
+    py: Python<'_>,
+    entry: providers::logs::SyslogEntry,
+) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("timestamp", entry.timestamp)?;
+    dict.set_item("hostname", entry.hostname)?;
+    dict.set_item("app_name", entry.app_name)?;
+    dict.set_item("pid", entry.pid)?;
+    dict.set_item("facility", entry.facility)?;
+    dict.set_item("severity", entry.severity)?;
+    dict.set_item("message", entry.message)?;
+    dict.set_item("priority", entry.priority)?;
+    Ok(dict)
+}
+
+/// Convert field statistics to a Python dictionary.
+fn field_stats_to_pydict(
+    py: Python<'_>,
+    stats: providers::stats::FieldStats,
+) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("count", stats.count)?;
+    dict.set_item("null_count", stats.null_count)?;
+    dict.set_item("null_rate", stats.null_rate())?;
+    dict.set_item("cardinality", stats.cardinality)?;
+    dict.set_item(
+        "min",
+        stats.min.map(|v| value_to_pyobject(py, v)).transpose()?,
+    )?;
+    dict.set_item(
+        "max",
+        stats.max.map(|v| value_to_pyobject(py, v)).transpose()?,
+    )?;
+    dict.set_item("min_length", stats.min_length)?;
+    dict.set_item("max_length", stats.max_length)?;
+    dict.set_item("mean_length", stats.mean_length)?;
+    Ok(dict)
 }
 
 /// The forgery Python module.
@@ -2955,7 +11193,7 @@ mod tests {
         let names = faker.names(100, false).unwrap();
         assert_eq!(names.len(), 100);
 
-        let emails = faker.emails(50, false).unwrap();
+        let emails = faker.emails(50, false, None, None, false, false).unwrap();
         assert_eq!(emails.len(), 50);
 
         let ints = faker.integers(200, 0, 1000).unwrap();
@@ -2972,6 +11210,16 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_sensor_readings_sensor_limit() {
+        let mut faker = Faker::new_default();
+        let result = faker.sensor_readings(1, "2024-01-01", "2024-01-01", MAX_BATCH_SIZE + 1, &[]);
+        assert!(matches!(result, Err(ForgeryError::BatchSize(_))));
+        assert!(faker
+            .sensor_readings(1, "2024-01-01", "2024-01-01", 0, &[])
+            .is_err());
+    }
+
     #[test]
     fn test_unique_generation() {
         let mut faker = Faker::new_default();
@@ -2999,6 +11247,105 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_override_locale_data() {
+        let mut faker = Faker::new_default();
+        faker.seed(42);
+        faker
+            .override_locale_data(LocaleOverrides {
+                cities: Some(vec!["Gotham".to_string(), "Metropolis".to_string()]),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let cities = faker.cities(2, true).unwrap();
+        assert!(cities.contains(&"Gotham".to_string()));
+        assert!(cities.contains(&"Metropolis".to_string()));
+        let address = faker
+            .address(&providers::address::AddressOptions::default())
+            .unwrap();
+        assert!(address.contains("Gotham") || address.contains("Metropolis"));
+
+        faker.clear_locale_overrides();
+        assert!(faker.locale_overrides().is_empty());
+    }
+
+    #[test]
+    fn test_override_locale_data_in_records_and_emails() {
+        let mut faker = Faker::new_default();
+        faker.seed(42);
+        faker
+            .override_locale_data(LocaleOverrides {
+                first_names: Some(vec!["Zébulon".to_string()]),
+                cities: Some(vec!["Gotham".to_string()]),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let mut schema = BTreeMap::new();
+        schema.insert(
+            "first_name".to_string(),
+            providers::records::FieldSpec::FirstName,
+        );
+        schema.insert("city".to_string(), providers::records::FieldSpec::City);
+        for record in faker.records(20, &schema).unwrap() {
+            assert_eq!(
+                record["first_name"],
+                providers::records::Value::String("Zébulon".to_string())
+            );
+            assert_eq!(
+                record["city"],
+                providers::records::Value::String("Gotham".to_string())
+            );
+        }
+        let batch = faker.records_arrow(20, &schema).unwrap();
+        let column = batch
+            .column_by_name("first_name")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<arrow_array::StringArray>()
+            .unwrap();
+        assert!(column.iter().all(|v| v == Some("Zébulon")));
+
+        let email = faker.email(None, None, false, false).unwrap();
+        assert!(email.starts_with("zebulon"), "{}", email);
+        let email = faker.email(Some("corp.example"), Some("first.last"), false, false);
+        assert!(email.unwrap().starts_with("zebulon."));
+    }
+
+    #[test]
+    fn test_override_locale_data_rejects_empty_list() {
+        let mut faker = Faker::new_default();
+        let result = faker.override_locale_data(LocaleOverrides {
+            first_names: Some(vec![]),
+            ..Default::default()
+        });
+        assert!(result.is_err());
+        assert!(faker.locale_overrides().is_empty());
+    }
+
+    #[test]
+    fn test_dates_locale_format() {
+        let mut faker = Faker::new("de_DE").unwrap();
+        faker.seed(42);
+        let dates = faker
+            .dates(10, "2024-01-01", "2024-12-31", Some("locale"))
+            .unwrap();
+        for date in dates {
+            assert_eq!(date.len(), 10);
+            assert!(date.ends_with(".2024"), "unexpected date {}", date);
+        }
+    }
+
+    #[test]
+    fn test_date_rejects_unknown_format() {
+        let mut faker = Faker::new_default();
+        let err = faker
+            .date("2024-01-01", "2024-12-31", Some("rfc"))
+            .unwrap_err();
+        assert!(matches!(err, ForgeryError::DateStyle(_)));
+    }
+
     #[test]
     fn test_validate_locale() {
         assert!(validate_locale("en_US").is_ok());
@@ -3008,6 +11355,11 @@ mod tests {
         assert!(validate_locale("it_IT").is_ok());
         assert!(validate_locale("ja_JP").is_ok());
         assert!(validate_locale("en_GB").is_ok());
+        assert!(validate_locale("ko_KR").is_ok());
+        assert!(validate_locale("ru_RU").is_ok());
+        assert!(validate_locale("nl_NL").is_ok());
+        assert!(validate_locale("pl_PL").is_ok());
+        assert!(validate_locale("en_CA").is_ok());
         assert!(validate_locale("xx_YY").is_err());
         assert!(validate_locale("").is_err());
     }
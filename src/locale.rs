@@ -27,6 +27,16 @@ pub enum Locale {
     JaJP,
     /// English (United Kingdom)
     EnGB,
+    /// Korean (South Korea)
+    KoKR,
+    /// Russian (Russia)
+    RuRU,
+    /// Dutch (Netherlands)
+    NlNL,
+    /// Polish (Poland)
+    PlPL,
+    /// English (Canada)
+    EnCA,
 }
 
 impl Locale {
@@ -39,6 +49,11 @@ impl Locale {
         Locale::ItIT,
         Locale::JaJP,
         Locale::EnGB,
+        Locale::KoKR,
+        Locale::RuRU,
+        Locale::NlNL,
+        Locale::PlPL,
+        Locale::EnCA,
     ];
 
     /// Get the string representation of the locale.
@@ -61,13 +76,18 @@ impl Locale {
             Locale::ItIT => "it_IT",
             Locale::JaJP => "ja_JP",
             Locale::EnGB => "en_GB",
+            Locale::KoKR => "ko_KR",
+            Locale::RuRU => "ru_RU",
+            Locale::NlNL => "nl_NL",
+            Locale::PlPL => "pl_PL",
+            Locale::EnCA => "en_CA",
         }
     }
 
-    /// Check if this locale uses family name first (e.g., Japanese).
+    /// Check if this locale uses family name first (e.g., Japanese, Korean).
     #[inline]
     pub const fn family_name_first(&self) -> bool {
-        matches!(self, Locale::JaJP)
+        matches!(self, Locale::JaJP | Locale::KoKR)
     }
 }
 
@@ -89,6 +109,11 @@ impl FromStr for Locale {
             "it_IT" => Ok(Locale::ItIT),
             "ja_JP" => Ok(Locale::JaJP),
             "en_GB" => Ok(Locale::EnGB),
+            "ko_KR" => Ok(Locale::KoKR),
+            "ru_RU" => Ok(Locale::RuRU),
+            "nl_NL" => Ok(Locale::NlNL),
+            "pl_PL" => Ok(Locale::PlPL),
+            "en_CA" => Ok(Locale::EnCA),
             _ => Err(LocaleError {
                 requested: s.to_string(),
             }),
@@ -161,6 +186,11 @@ mod tests {
         assert_eq!(Locale::from_str("it_IT").unwrap(), Locale::ItIT);
         assert_eq!(Locale::from_str("ja_JP").unwrap(), Locale::JaJP);
         assert_eq!(Locale::from_str("en_GB").unwrap(), Locale::EnGB);
+        assert_eq!(Locale::from_str("ko_KR").unwrap(), Locale::KoKR);
+        assert_eq!(Locale::from_str("ru_RU").unwrap(), Locale::RuRU);
+        assert_eq!(Locale::from_str("nl_NL").unwrap(), Locale::NlNL);
+        assert_eq!(Locale::from_str("pl_PL").unwrap(), Locale::PlPL);
+        assert_eq!(Locale::from_str("en_CA").unwrap(), Locale::EnCA);
     }
 
     #[test]
@@ -181,6 +211,11 @@ mod tests {
         assert_eq!(Locale::ItIT.as_str(), "it_IT");
         assert_eq!(Locale::JaJP.as_str(), "ja_JP");
         assert_eq!(Locale::EnGB.as_str(), "en_GB");
+        assert_eq!(Locale::KoKR.as_str(), "ko_KR");
+        assert_eq!(Locale::RuRU.as_str(), "ru_RU");
+        assert_eq!(Locale::NlNL.as_str(), "nl_NL");
+        assert_eq!(Locale::PlPL.as_str(), "pl_PL");
+        assert_eq!(Locale::EnCA.as_str(), "en_CA");
     }
 
     #[test]
@@ -199,13 +234,19 @@ mod tests {
         assert!(!Locale::EnUS.family_name_first());
         assert!(!Locale::DeDE.family_name_first());
         assert!(Locale::JaJP.family_name_first());
+        assert!(Locale::KoKR.family_name_first());
     }
 
     #[test]
     fn test_all_locales() {
-        assert_eq!(Locale::ALL.len(), 7);
+        assert_eq!(Locale::ALL.len(), 12);
         assert!(Locale::ALL.contains(&Locale::EnUS));
         assert!(Locale::ALL.contains(&Locale::JaJP));
+        assert!(Locale::ALL.contains(&Locale::KoKR));
+        assert!(Locale::ALL.contains(&Locale::RuRU));
+        assert!(Locale::ALL.contains(&Locale::NlNL));
+        assert!(Locale::ALL.contains(&Locale::PlPL));
+        assert!(Locale::ALL.contains(&Locale::EnCA));
     }
 
     #[test]
@@ -1,19 +1,37 @@
 //! Address generation provider.
 //!
 //! Generates addresses, street names, cities, states, countries, and zip codes.
+//! Countries can be returned as English names or ISO 3166-1 codes.
 
-use crate::data::en_us::COUNTRIES;
+use crate::data::en_us::{
+    COUNTRIES, COUNTRY_CODES, STATES, STATE_ABBRS, STATE_COUNTIES, US_SECONDARY_ADDRESS_FORMAT,
+};
+use crate::data::formats::SecondaryAddressFormat;
 use crate::data::get_locale_data;
+use crate::data::overrides::{choose_with_override, LocaleOverrides};
 use crate::locale::Locale;
 use crate::rng::ForgeryRng;
+use std::str::FromStr;
 
 /// Generate a batch of random street addresses.
 ///
 /// Format: "123 Main Street"
 pub fn generate_street_addresses(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    generate_street_addresses_with_overrides(rng, locale, &LocaleOverrides::EMPTY, n)
+}
+
+/// Generate a batch of random street addresses, drawing from user overrides where set.
+pub fn generate_street_addresses_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<String> {
     let mut addresses = Vec::with_capacity(n);
     for _ in 0..n {
-        addresses.push(generate_street_address(rng, locale));
+        addresses.push(generate_street_address_with_overrides(
+            rng, locale, overrides,
+        ));
     }
     addresses
 }
@@ -21,22 +39,115 @@ pub fn generate_street_addresses(rng: &mut ForgeryRng, locale: Locale, n: usize)
 /// Generate a single random street address.
 #[inline]
 pub fn generate_street_address(rng: &mut ForgeryRng, locale: Locale) -> String {
-    let data = get_locale_data(locale);
-    let street_names = data.street_names().unwrap_or(&[]);
-    let street_suffixes = data.street_suffixes().unwrap_or(&[]);
-    let format = data.address_format();
+    generate_street_address_with_overrides(rng, locale, &LocaleOverrides::EMPTY)
+}
 
-    let number: u32 = rng.gen_range(1, 9999);
-    let name = if street_names.is_empty() {
-        "Main"
-    } else {
-        rng.choose(street_names)
-    };
-    let street_type = if street_suffixes.is_empty() {
+/// Generate a single random street address, drawing from user overrides where set.
+#[inline]
+pub fn generate_street_address_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> String {
+    let (number, name, street_type) = street_parts(rng, locale, overrides);
+    format_street_address(locale, number, &name, street_type)
+}
+
+/// Draw a building number, street name and street type.
+fn street_parts(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> (u32, String, &'static str) {
+    let number = building_number(rng);
+    let name = generate_street_name_with_overrides(rng, locale, overrides);
+    let street_type = street_suffix(rng, locale);
+    (number, name, street_type)
+}
+
+/// Draw a building number.
+#[inline]
+fn building_number(rng: &mut ForgeryRng) -> u32 {
+    rng.gen_range(1, 9999)
+}
+
+/// Draw a street type from the locale's data.
+#[inline]
+fn street_suffix(rng: &mut ForgeryRng, locale: Locale) -> &'static str {
+    let street_suffixes = get_locale_data(locale).street_suffixes().unwrap_or(&[]);
+    if street_suffixes.is_empty() {
         "Street"
     } else {
-        rng.choose(street_suffixes)
-    };
+        rng.choose::<&str>(street_suffixes)
+    }
+}
+
+/// Generate a batch of building numbers.
+pub fn generate_building_numbers(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut numbers = Vec::with_capacity(n);
+    for _ in 0..n {
+        numbers.push(generate_building_number(rng));
+    }
+    numbers
+}
+
+/// Generate a single building number (1-9999), as used in street addresses.
+#[inline]
+pub fn generate_building_number(rng: &mut ForgeryRng) -> String {
+    building_number(rng).to_string()
+}
+
+/// Generate a batch of street names, drawing from user overrides where set.
+pub fn generate_street_names_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_street_name_with_overrides(rng, locale, overrides));
+    }
+    names
+}
+
+/// Generate a single street name without its type (e.g., "Main", "Oak").
+#[inline]
+pub fn generate_street_name(rng: &mut ForgeryRng, locale: Locale) -> String {
+    generate_street_name_with_overrides(rng, locale, &LocaleOverrides::EMPTY)
+}
+
+/// Generate a single street name, drawing from user overrides where set.
+#[inline]
+pub fn generate_street_name_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> String {
+    let street_names = get_locale_data(locale).street_names().unwrap_or(&[]);
+    choose_with_override(rng, overrides.street_names.as_deref(), street_names, "Main").to_string()
+}
+
+/// Generate a batch of street types.
+pub fn generate_street_suffixes(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    let mut suffixes = Vec::with_capacity(n);
+    for _ in 0..n {
+        suffixes.push(generate_street_suffix(rng, locale));
+    }
+    suffixes
+}
+
+/// Generate a single street type (e.g., "Street", "Avenue", "Straße").
+///
+/// Some locales place it before the name ("Calle", "rue").
+#[inline]
+pub fn generate_street_suffix(rng: &mut ForgeryRng, locale: Locale) -> String {
+    street_suffix(rng, locale).to_string()
+}
+
+/// Join street parts in the locale's order.
+fn format_street_address(locale: Locale, number: u32, name: &str, street_type: &str) -> String {
+    let format = get_locale_data(locale).address_format();
 
     // Get format options from address format
     let (number_before_street, separator, type_prefix) = match format {
@@ -66,9 +177,19 @@ pub fn generate_street_address(rng: &mut ForgeryRng, locale: Locale) -> String {
 
 /// Generate a batch of random city names.
 pub fn generate_cities(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    generate_cities_with_overrides(rng, locale, &LocaleOverrides::EMPTY, n)
+}
+
+/// Generate a batch of random city names, drawing from user overrides where set.
+pub fn generate_cities_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<String> {
     let mut cities = Vec::with_capacity(n);
     for _ in 0..n {
-        cities.push(generate_city(rng, locale));
+        cities.push(generate_city_with_overrides(rng, locale, overrides));
     }
     cities
 }
@@ -76,13 +197,24 @@ pub fn generate_cities(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<St
 /// Generate a single random city name.
 #[inline]
 pub fn generate_city(rng: &mut ForgeryRng, locale: Locale) -> String {
+    generate_city_with_overrides(rng, locale, &LocaleOverrides::EMPTY)
+}
+
+/// Generate a single random city name, drawing from user overrides where set.
+#[inline]
+pub fn generate_city_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> String {
     let data = get_locale_data(locale);
-    let cities = data.cities().unwrap_or(&[]);
-    if cities.is_empty() {
-        "City".to_string()
-    } else {
-        rng.choose(cities).to_string()
-    }
+    choose_with_override(
+        rng,
+        overrides.cities.as_deref(),
+        data.cities().unwrap_or(&[]),
+        "City",
+    )
+    .to_string()
 }
 
 /// Generate a batch of random state/region names.
@@ -107,7 +239,6 @@ pub fn generate_state(rng: &mut ForgeryRng, locale: Locale) -> String {
 }
 
 /// Generate a batch of random state/region abbreviations.
-#[allow(dead_code)]
 pub fn generate_state_abbrs(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
     let mut abbrs = Vec::with_capacity(n);
     for _ in 0..n {
@@ -128,6 +259,103 @@ pub fn generate_state_abbr(rng: &mut ForgeryRng, locale: Locale) -> String {
     }
 }
 
+/// Error for a state that is not a US state name or abbreviation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsStateError {
+    /// The unrecognized state.
+    pub state: String,
+}
+
+impl std::fmt::Display for UsStateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown US state '{}'; expected a state name such as 'Texas' or an abbreviation such as 'TX'",
+            self.state
+        )
+    }
+}
+
+impl std::error::Error for UsStateError {}
+
+/// Check that `state` is a US state name or two-letter abbreviation.
+///
+/// # Errors
+///
+/// Returns `UsStateError` if it is neither, ignoring case.
+pub fn validate_us_state(state: &str) -> Result<(), UsStateError> {
+    us_state_index(state).map(|_| ())
+}
+
+/// Index into `STATES` of a state name or two-letter abbreviation, ignoring case.
+fn us_state_index(state: &str) -> Result<usize, UsStateError> {
+    STATES
+        .iter()
+        .zip(STATE_ABBRS)
+        .position(|(name, abbr)| {
+            name.eq_ignore_ascii_case(state) || abbr.eq_ignore_ascii_case(state)
+        })
+        .ok_or_else(|| UsStateError {
+            state: state.to_string(),
+        })
+}
+
+/// Generate a batch of US counties.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of counties to generate
+/// * `state` - State name or abbreviation to draw from; a random state per value if `None`
+/// * `with_state` - Append the state abbreviation (e.g., "Cook County, IL")
+///
+/// # Errors
+///
+/// Returns `UsStateError` if `state` is not a US state.
+pub fn generate_counties(
+    rng: &mut ForgeryRng,
+    n: usize,
+    state: Option<&str>,
+    with_state: bool,
+) -> Result<Vec<String>, UsStateError> {
+    let index = state.map(us_state_index).transpose()?;
+    let mut counties = Vec::with_capacity(n);
+    for _ in 0..n {
+        counties.push(county(rng, index, with_state));
+    }
+    Ok(counties)
+}
+
+/// Generate a single US county (e.g., "Travis County", "Orleans Parish").
+///
+/// Counties are US data whatever the locale. Without `state`, a state is
+/// drawn first and the county is one of its counties, so the state suffix
+/// added by `with_state` always matches.
+///
+/// # Errors
+///
+/// Returns `UsStateError` if `state` is not a US state.
+#[inline]
+pub fn generate_county(
+    rng: &mut ForgeryRng,
+    state: Option<&str>,
+    with_state: bool,
+) -> Result<String, UsStateError> {
+    let index = state.map(us_state_index).transpose()?;
+    Ok(county(rng, index, with_state))
+}
+
+/// County of the state at `index`, or of a random state.
+fn county(rng: &mut ForgeryRng, index: Option<usize>, with_state: bool) -> String {
+    let index = index.unwrap_or_else(|| rng.gen_range(0, STATES.len() - 1));
+    let county = *rng.choose(STATE_COUNTIES[index]);
+    if with_state {
+        format!("{}, {}", county, STATE_ABBRS[index])
+    } else {
+        county.to_string()
+    }
+}
+
 /// Generate a batch of random country names.
 pub fn generate_countries(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
     let mut countries = Vec::with_capacity(n);
@@ -144,6 +372,88 @@ pub fn generate_country(rng: &mut ForgeryRng) -> String {
     rng.choose(COUNTRIES).to_string()
 }
 
+/// Output format for generated countries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CountryFormat {
+    /// English name (`Germany`).
+    #[default]
+    Name,
+    /// ISO 3166-1 alpha-2 code (`DE`).
+    Alpha2,
+    /// ISO 3166-1 alpha-3 code (`DEU`).
+    Alpha3,
+    /// ISO 3166-1 numeric code, zero-padded to three digits (`276`).
+    Numeric,
+}
+
+/// Accepted names for `CountryFormat`, in declaration order.
+pub const COUNTRY_FORMATS: &[&str] = &["name", "alpha2", "alpha3", "numeric"];
+
+/// Error for an unknown country format name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountryFormatError {
+    /// The unrecognized format name.
+    pub format: String,
+}
+
+impl std::fmt::Display for CountryFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown country format '{}'; expected one of: {}",
+            self.format,
+            COUNTRY_FORMATS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for CountryFormatError {}
+
+impl FromStr for CountryFormat {
+    type Err = CountryFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "name" => Ok(CountryFormat::Name),
+            "alpha2" => Ok(CountryFormat::Alpha2),
+            "alpha3" => Ok(CountryFormat::Alpha3),
+            "numeric" => Ok(CountryFormat::Numeric),
+            _ => Err(CountryFormatError {
+                format: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Generate a batch of countries in the given format.
+pub fn generate_countries_with_format(
+    rng: &mut ForgeryRng,
+    n: usize,
+    format: CountryFormat,
+) -> Vec<String> {
+    let mut countries = Vec::with_capacity(n);
+    for _ in 0..n {
+        countries.push(generate_country_with_format(rng, format));
+    }
+    countries
+}
+
+/// Generate a single country in the given format.
+///
+/// Draws from the same country list as `generate_country`, so a given seed
+/// yields the same countries whatever the format.
+#[inline]
+pub fn generate_country_with_format(rng: &mut ForgeryRng, format: CountryFormat) -> String {
+    let &(name, alpha2, alpha3, numeric) = rng.choose(COUNTRY_CODES);
+    match format {
+        CountryFormat::Name => name,
+        CountryFormat::Alpha2 => alpha2,
+        CountryFormat::Alpha3 => alpha3,
+        CountryFormat::Numeric => numeric,
+    }
+    .to_string()
+}
+
 /// Generate a batch of random postal/zip codes.
 pub fn generate_zip_codes(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
     let mut zips = Vec::with_capacity(n);
@@ -168,6 +478,25 @@ pub fn generate_zip_code(rng: &mut ForgeryRng, locale: Locale) -> String {
     format!("{:05}", zip5)
 }
 
+/// Generate a postal code that belongs to the region at `region_index`.
+///
+/// The first three digits come from the region's prefix range for locales
+/// that provide `region_zip_prefixes()`; others get a plain postal code.
+pub(crate) fn generate_zip_code_in_region(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    region_index: usize,
+) -> String {
+    let zip = generate_zip_code(rng, locale);
+    match get_locale_data(locale)
+        .region_zip_prefixes()
+        .and_then(|prefixes| prefixes.get(region_index))
+    {
+        Some(&(min, max)) => format!("{:03}{}", rng.gen_range(min, max), &zip[3..]),
+        None => zip,
+    }
+}
+
 /// Expand a format pattern where # is a digit and A/@ is a letter.
 ///
 /// Placeholders:
@@ -193,9 +522,19 @@ fn expand_pattern(rng: &mut ForgeryRng, pattern: &str) -> String {
 
 /// Generate a batch of random full addresses.
 pub fn generate_addresses(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    generate_addresses_with_overrides(rng, locale, &LocaleOverrides::EMPTY, n)
+}
+
+/// Generate a batch of random full addresses, drawing from user overrides where set.
+pub fn generate_addresses_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<String> {
     let mut addresses = Vec::with_capacity(n);
     for _ in 0..n {
-        addresses.push(generate_address(rng, locale));
+        addresses.push(generate_address_with_overrides(rng, locale, overrides));
     }
     addresses
 }
@@ -206,9 +545,32 @@ pub fn generate_addresses(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec
 /// Supported placeholders: `{street}`, `{city}`, `{region}`, `{region_abbr}`, `{postal}`
 #[inline]
 pub fn generate_address(rng: &mut ForgeryRng, locale: Locale) -> String {
+    generate_address_with_overrides(rng, locale, &LocaleOverrides::EMPTY)
+}
+
+/// Generate a single random full address, drawing from user overrides where set.
+///
+/// Overridden street names and cities are slotted into the locale's template.
+#[inline]
+pub fn generate_address_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> String {
+    let street = generate_street_address_with_overrides(rng, locale, overrides);
+    fill_address_template(rng, locale, overrides, &street)
+}
+
+/// Slot a street line and freshly drawn city, region and postal code into the
+/// locale's address template.
+fn fill_address_template(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    street: &str,
+) -> String {
     let data = get_locale_data(locale);
-    let street = generate_street_address(rng, locale);
-    let city = generate_city(rng, locale);
+    let city = generate_city_with_overrides(rng, locale, overrides);
     let region = generate_state(rng, locale);
     let region_abbr = generate_state_abbr(rng, locale);
     let postal = generate_zip_code(rng, locale);
@@ -221,17 +583,374 @@ pub fn generate_address(rng: &mut ForgeryRng, locale: Locale) -> String {
 
     // Replace placeholders with actual values
     template
-        .replace("{street}", &street)
+        .replace("{street}", street)
         .replace("{city}", &city)
         .replace("{region}", &region)
         .replace("{region_abbr}", &region_abbr)
         .replace("{postal}", &postal)
 }
 
+/// The locale's secondary unit and PO Box wording, or the US wording if it has none.
+fn secondary_address_format(locale: Locale) -> SecondaryAddressFormat {
+    get_locale_data(locale)
+        .secondary_address_format()
+        .unwrap_or(US_SECONDARY_ADDRESS_FORMAT)
+}
+
+/// Generate a batch of secondary address units.
+pub fn generate_secondary_units(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    let mut units = Vec::with_capacity(n);
+    for _ in 0..n {
+        units.push(generate_secondary_unit(rng, locale));
+    }
+    units
+}
+
+/// Generate a single secondary address unit in the locale's wording
+/// (e.g., "Apt 4B" for en_US, "Flat 3" for en_GB, "1001호" for ko_KR).
+///
+/// Each unit kind has its own number range; lettered kinds add a letter A-F
+/// to half of the numbers below 30.
+#[inline]
+pub fn generate_secondary_unit(rng: &mut ForgeryRng, locale: Locale) -> String {
+    let unit = *rng.choose(secondary_address_format(locale).units);
+    let number: u32 = rng.gen_range(unit.min, unit.max);
+    let number = if unit.letters && number < 30 && rng.gen_range(0u8, 1) == 0 {
+        let letter = char::from(b'A' + rng.gen_range(0u8, 5));
+        format!("{}{}", number, letter)
+    } else {
+        number.to_string()
+    };
+    unit.template.replace("{n}", &number)
+}
+
+/// Generate a batch of PO Box lines.
+pub fn generate_po_boxes(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    let mut boxes = Vec::with_capacity(n);
+    for _ in 0..n {
+        boxes.push(generate_po_box(rng, locale));
+    }
+    boxes
+}
+
+/// Generate a single PO Box line in the locale's wording
+/// (e.g., "PO Box 4821" for en_US, "Postfach 4821" for de_DE).
+#[inline]
+pub fn generate_po_box(rng: &mut ForgeryRng, locale: Locale) -> String {
+    let number: u32 = rng.gen_range(1, 99999);
+    secondary_address_format(locale)
+        .po_box
+        .replace("{n}", &number.to_string())
+}
+
+/// Options for mixing secondary units and PO Boxes into full addresses.
+///
+/// The default reproduces `generate_address`. Each address independently gets
+/// a PO Box in place of the street with probability `po_box_rate`, or a
+/// secondary unit with probability `unit_rate`, both in the locale's wording.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AddressOptions {
+    /// Fraction of addresses with a secondary unit, from 0.0 to 1.0.
+    pub unit_rate: f64,
+    /// Fraction of addresses with a PO Box instead of a street, from 0.0 to 1.0.
+    pub po_box_rate: f64,
+}
+
+impl AddressOptions {
+    /// Check that both rates are between 0 and 1 and together at most 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AddressOptionsError` for an out-of-range rate.
+    pub fn validate(&self) -> Result<(), AddressOptionsError> {
+        for (name, rate) in [
+            ("unit_rate", self.unit_rate),
+            ("po_box_rate", self.po_box_rate),
+        ] {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(AddressOptionsError::InvalidRate { name, rate });
+            }
+        }
+        if self.unit_rate + self.po_box_rate > 1.0 {
+            return Err(AddressOptionsError::RateSum {
+                unit_rate: self.unit_rate,
+                po_box_rate: self.po_box_rate,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Errors for invalid address options.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AddressOptionsError {
+    /// A rate is NaN or outside 0.0-1.0.
+    InvalidRate {
+        /// The option name.
+        name: &'static str,
+        /// The rejected rate.
+        rate: f64,
+    },
+    /// The unit and PO Box rates add up to more than 1.0.
+    RateSum {
+        /// The secondary unit rate.
+        unit_rate: f64,
+        /// The PO Box rate.
+        po_box_rate: f64,
+    },
+}
+
+impl std::fmt::Display for AddressOptionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidRate { name, rate } => {
+                write!(f, "{} must be between 0.0 and 1.0, got {}", name, rate)
+            }
+            Self::RateSum {
+                unit_rate,
+                po_box_rate,
+            } => write!(
+                f,
+                "unit_rate + po_box_rate must be at most 1.0, got {} + {}",
+                unit_rate, po_box_rate
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AddressOptionsError {}
+
+/// Generate a batch of full addresses with units and PO Boxes mixed in per `options`.
+///
+/// # Errors
+///
+/// Returns `AddressOptionsError` if the options are invalid.
+pub fn generate_addresses_with_options(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+    options: &AddressOptions,
+) -> Result<Vec<String>, AddressOptionsError> {
+    options.validate()?;
+    let mut addresses = Vec::with_capacity(n);
+    for _ in 0..n {
+        addresses.push(address_with_options(rng, locale, overrides, options));
+    }
+    Ok(addresses)
+}
+
+/// Generate a single full address with a unit or PO Box mixed in per `options`
+/// (e.g., "4821 Oak Avenue Suite 210, Springfield, IL 62704").
+///
+/// # Errors
+///
+/// Returns `AddressOptionsError` if the options are invalid.
+#[inline]
+pub fn generate_address_with_options(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    options: &AddressOptions,
+) -> Result<String, AddressOptionsError> {
+    options.validate()?;
+    Ok(address_with_options(rng, locale, overrides, options))
+}
+
+/// Address for options that are already validated.
+///
+/// Draws nothing extra when both rates are zero, so the default options give
+/// the same sequence as `generate_address_with_overrides`. A rate (or the
+/// combined rate) of 1.0 always applies, since `gen_range` can return 1.0.
+pub(crate) fn address_with_options(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    options: &AddressOptions,
+) -> String {
+    if options.unit_rate == 0.0 && options.po_box_rate == 0.0 {
+        return generate_address_with_overrides(rng, locale, overrides);
+    }
+    let roll: f64 = rng.gen_range(0.0, 1.0);
+    let combined_rate = options.po_box_rate + options.unit_rate;
+    let street = if options.po_box_rate >= 1.0 || roll < options.po_box_rate {
+        generate_po_box(rng, locale)
+    } else if combined_rate >= 1.0 || roll < combined_rate {
+        let street = generate_street_address_with_overrides(rng, locale, overrides);
+        let unit = generate_secondary_unit(rng, locale);
+        secondary_address_format(locale)
+            .street_with_unit
+            .replace("{street}", &street)
+            .replace("{unit}", &unit)
+    } else {
+        generate_street_address_with_overrides(rng, locale, overrides)
+    };
+    fill_address_template(rng, locale, overrides, &street)
+}
+
+/// English name of the country a locale's addresses are in.
+fn locale_country(locale: Locale) -> &'static str {
+    match locale {
+        Locale::EnUS => "United States",
+        Locale::DeDE => "Germany",
+        Locale::FrFR => "France",
+        Locale::EsES => "Spain",
+        Locale::ItIT => "Italy",
+        Locale::JaJP => "Japan",
+        Locale::EnGB => "United Kingdom",
+        Locale::KoKR => "South Korea",
+        Locale::RuRU => "Russia",
+        Locale::NlNL => "Netherlands",
+        Locale::PlPL => "Poland",
+        Locale::EnCA => "Canada",
+    }
+}
+
+/// A generated address split into its components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressParts {
+    /// House or building number
+    pub building_number: String,
+    /// Street name without its type (e.g., "Main")
+    pub street_name: String,
+    /// Street type (e.g., "Street", "Avenue"); placed before the name in some locales
+    pub street_suffix: String,
+    /// Secondary unit (e.g., "Apt 4B"), or empty if the address has none
+    pub unit: String,
+    /// City name
+    pub city: String,
+    /// State or region name
+    pub region: String,
+    /// Abbreviation of `region`
+    pub region_abbr: String,
+    /// Postal or zip code
+    pub postal_code: String,
+    /// English name of the locale's country
+    pub country: String,
+}
+
+/// Address part field names, in the order they appear in records.
+pub const ADDRESS_PARTS_FIELDS: &[&str] = &[
+    "building_number",
+    "street_name",
+    "street_suffix",
+    "unit",
+    "city",
+    "region",
+    "region_abbr",
+    "postal_code",
+    "country",
+];
+
+impl AddressParts {
+    /// Field values in `ADDRESS_PARTS_FIELDS` order.
+    pub fn into_values(self) -> [String; 9] {
+        [
+            self.building_number,
+            self.street_name,
+            self.street_suffix,
+            self.unit,
+            self.city,
+            self.region,
+            self.region_abbr,
+            self.postal_code,
+            self.country,
+        ]
+    }
+}
+
+/// Generate a batch of address parts.
+///
+/// # Errors
+///
+/// Returns `AddressOptionsError` if `unit_rate` is outside 0.0-1.0.
+pub fn generate_address_parts_batch(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+    unit_rate: f64,
+) -> Result<Vec<AddressParts>, AddressOptionsError> {
+    validate_unit_rate(unit_rate)?;
+    let mut parts = Vec::with_capacity(n);
+    for _ in 0..n {
+        parts.push(address_parts(rng, locale, overrides, unit_rate));
+    }
+    Ok(parts)
+}
+
+/// Generate a single address as separate components.
+///
+/// Unlike `generate_address`, the region abbreviation always abbreviates the
+/// region, and the unit is present with probability `unit_rate`.
+///
+/// # Errors
+///
+/// Returns `AddressOptionsError` if `unit_rate` is outside 0.0-1.0.
+#[inline]
+pub fn generate_address_parts(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    unit_rate: f64,
+) -> Result<AddressParts, AddressOptionsError> {
+    validate_unit_rate(unit_rate)?;
+    Ok(address_parts(rng, locale, overrides, unit_rate))
+}
+
+/// Check a unit rate on its own.
+fn validate_unit_rate(unit_rate: f64) -> Result<(), AddressOptionsError> {
+    AddressOptions {
+        unit_rate,
+        po_box_rate: 0.0,
+    }
+    .validate()
+}
+
+/// Address parts for a unit rate that is already validated.
+///
+/// A `unit_rate` of 1.0 always adds a unit, since `gen_range` can return 1.0.
+pub(crate) fn address_parts(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    unit_rate: f64,
+) -> AddressParts {
+    let data = get_locale_data(locale);
+    let (number, street_name, street_suffix) = street_parts(rng, locale, overrides);
+    let unit = if unit_rate >= 1.0 || (unit_rate > 0.0 && rng.gen_range(0.0, 1.0) < unit_rate) {
+        generate_secondary_unit(rng, locale)
+    } else {
+        String::new()
+    };
+    let city = generate_city_with_overrides(rng, locale, overrides);
+
+    let regions = data.regions().unwrap_or(&[]);
+    let region_index = rng.gen_range(0, regions.len().saturating_sub(1));
+    let region = regions.get(region_index).copied().unwrap_or("State");
+    let region_abbr = data
+        .region_abbrs()
+        .and_then(|abbrs| abbrs.get(region_index))
+        .copied()
+        .unwrap_or(region);
+
+    AddressParts {
+        building_number: number.to_string(),
+        street_name,
+        street_suffix: street_suffix.to_string(),
+        unit,
+        city,
+        region: region.to_string(),
+        region_abbr: region_abbr.to_string(),
+        postal_code: generate_zip_code(rng, locale),
+        country: locale_country(locale).to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::data::en_us::{CITIES, STATES};
+    use crate::data::en_us::CITIES;
 
     #[test]
     fn test_generate_street_addresses_count() {
@@ -326,6 +1045,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_street_components() {
+        use crate::data::en_us::{STREET_NAMES, STREET_SUFFIXES};
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for name in generate_street_names_with_overrides(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+        ) {
+            assert!(STREET_NAMES.contains(&name.as_str()), "{}", name);
+        }
+        for suffix in generate_street_suffixes(&mut rng, Locale::EnUS, 100) {
+            assert!(STREET_SUFFIXES.contains(&suffix.as_str()), "{}", suffix);
+        }
+        for number in generate_building_numbers(&mut rng, 100) {
+            assert!((1..=9999).contains(&number.parse::<u32>().unwrap()));
+        }
+        assert!(!generate_street_name(&mut rng, Locale::JaJP).is_empty());
+        assert!(!generate_street_suffix(&mut rng, Locale::FrFR).is_empty());
+    }
+
     #[test]
     fn test_state_abbr_format() {
         let mut rng = ForgeryRng::new();
@@ -366,6 +1109,113 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_county_data_matches_states() {
+        assert_eq!(STATE_COUNTIES.len(), STATES.len());
+        for counties in STATE_COUNTIES {
+            assert!(!counties.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_counties() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let all: Vec<&str> = STATE_COUNTIES
+            .iter()
+            .flat_map(|c| c.iter().copied())
+            .collect();
+        for county in generate_counties(&mut rng, 200, None, false).unwrap() {
+            assert!(all.contains(&county.as_str()), "{}", county);
+        }
+
+        let texas = generate_counties(&mut rng, 50, Some("texas"), false).unwrap();
+        let tx = generate_counties(&mut rng, 50, Some("TX"), false).unwrap();
+        for county in texas.iter().chain(&tx) {
+            assert!(STATE_COUNTIES[42].contains(&county.as_str()), "{}", county);
+        }
+
+        for county in generate_counties(&mut rng, 200, None, true).unwrap() {
+            let (name, abbr) = county.rsplit_once(", ").unwrap();
+            let index = STATE_ABBRS.iter().position(|a| *a == abbr).unwrap();
+            assert!(STATE_COUNTIES[index].contains(&name), "{}", county);
+        }
+
+        let err = generate_county(&mut rng, Some("Ontario"), false).unwrap_err();
+        assert_eq!(err.state, "Ontario");
+        assert!(err.to_string().contains("'Ontario'"));
+    }
+
+    #[test]
+    fn test_country_codes_match_countries() {
+        assert_eq!(COUNTRY_CODES.len(), COUNTRIES.len());
+        for (&(name, alpha2, alpha3, numeric), &country) in COUNTRY_CODES.iter().zip(COUNTRIES) {
+            assert_eq!(name, country);
+            assert!(alpha2.len() == 2 && alpha2.bytes().all(|b| b.is_ascii_uppercase()));
+            assert!(alpha3.len() == 3 && alpha3.bytes().all(|b| b.is_ascii_uppercase()));
+            assert!(numeric.len() == 3 && numeric.bytes().all(|b| b.is_ascii_digit()));
+        }
+    }
+
+    #[test]
+    fn test_country_format_parse() {
+        assert_eq!(
+            "name".parse::<CountryFormat>().unwrap(),
+            CountryFormat::Name
+        );
+        assert_eq!(
+            "alpha2".parse::<CountryFormat>().unwrap(),
+            CountryFormat::Alpha2
+        );
+        assert_eq!(
+            "alpha3".parse::<CountryFormat>().unwrap(),
+            CountryFormat::Alpha3
+        );
+        assert_eq!(
+            "numeric".parse::<CountryFormat>().unwrap(),
+            CountryFormat::Numeric
+        );
+        let err = "iso".parse::<CountryFormat>().unwrap_err();
+        assert_eq!(err.format, "iso");
+        assert!(err.to_string().contains("alpha2"));
+    }
+
+    #[test]
+    fn test_countries_with_format() {
+        let lookup = |format: CountryFormat| {
+            let mut rng = ForgeryRng::new();
+            rng.seed(42);
+            generate_countries_with_format(&mut rng, 50, format)
+        };
+        let names = lookup(CountryFormat::Name);
+        let alpha2 = lookup(CountryFormat::Alpha2);
+        let alpha3 = lookup(CountryFormat::Alpha3);
+        let numeric = lookup(CountryFormat::Numeric);
+
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        assert_eq!(names, generate_countries(&mut rng, 50));
+
+        for i in 0..50 {
+            let entry = COUNTRY_CODES.iter().find(|c| c.0 == names[i]).unwrap();
+            assert_eq!(entry.1, alpha2[i]);
+            assert_eq!(entry.2, alpha3[i]);
+            assert_eq!(entry.3, numeric[i]);
+        }
+    }
+
+    #[test]
+    fn test_known_country_codes() {
+        let find = |name: &str| *COUNTRY_CODES.iter().find(|c| c.0 == name).unwrap();
+        assert_eq!(find("Germany"), ("Germany", "DE", "DEU", "276"));
+        assert_eq!(
+            find("United Kingdom"),
+            ("United Kingdom", "GB", "GBR", "826")
+        );
+        assert_eq!(find("Afghanistan"), ("Afghanistan", "AF", "AFG", "004"));
+    }
+
     #[test]
     fn test_generate_zip_codes_count() {
         let mut rng = ForgeryRng::new();
@@ -476,6 +1326,41 @@ mod tests {
         assert_ne!(a1, a2, "Different seeds should produce different addresses");
     }
 
+    #[test]
+    fn test_cities_with_overrides() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let overrides = LocaleOverrides {
+            cities: Some(vec!["Gotham".to_string(), "Metropolis".to_string()]),
+            ..Default::default()
+        };
+
+        let cities = generate_cities_with_overrides(&mut rng, Locale::EnUS, &overrides, 50);
+        for city in &cities {
+            assert!(city == "Gotham" || city == "Metropolis");
+        }
+    }
+
+    #[test]
+    fn test_address_with_overrides_keeps_locale_format() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let overrides = LocaleOverrides {
+            cities: Some(vec!["Gotham".to_string()]),
+            street_names: Some(vec!["Wayne".to_string()]),
+            ..Default::default()
+        };
+
+        for _ in 0..20 {
+            let address = generate_address_with_overrides(&mut rng, Locale::EnUS, &overrides);
+            // US format: "123 Wayne Street, Gotham, ST 12345"
+            let parts: Vec<&str> = address.split(", ").collect();
+            assert_eq!(parts.len(), 3);
+            assert!(parts[0].contains(" Wayne "));
+            assert_eq!(parts[1], "Gotham");
+        }
+    }
+
     #[test]
     fn test_empty_batches() {
         let mut rng = ForgeryRng::new();
@@ -501,6 +1386,11 @@ mod tests {
             Locale::EsES,
             Locale::ItIT,
             Locale::JaJP,
+            Locale::KoKR,
+            Locale::RuRU,
+            Locale::NlNL,
+            Locale::PlPL,
+            Locale::EnCA,
         ] {
             let addr = generate_address(&mut rng, locale);
             assert!(
@@ -510,6 +1400,266 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_secondary_units_and_po_boxes() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let designators = ["Apt", "Suite", "Unit"];
+        let units = generate_secondary_units(&mut rng, Locale::EnUS, 300);
+        for unit in &units {
+            let (designator, number) = unit.split_once(' ').unwrap();
+            assert!(designators.contains(&designator), "{}", unit);
+            let digits = number.trim_end_matches(|c: char| ('A'..='F').contains(&c));
+            assert!(digits.parse::<u32>().is_ok(), "{}", unit);
+        }
+        for designator in designators {
+            assert!(units.iter().any(|u| u.starts_with(designator)));
+        }
+        assert!(units
+            .iter()
+            .any(|u| u.ends_with(|c: char| c.is_ascii_uppercase())));
+
+        for po_box in generate_po_boxes(&mut rng, Locale::EnUS, 100) {
+            let number = po_box.strip_prefix("PO Box ").unwrap();
+            assert!((1..=99999).contains(&number.parse::<u32>().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_secondary_units_and_po_boxes_follow_locale() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for unit in generate_secondary_units(&mut rng, Locale::KoKR, 50) {
+            let number = unit.strip_suffix('호').unwrap();
+            assert!(
+                (101..=2505).contains(&number.parse::<u32>().unwrap()),
+                "{}",
+                unit
+            );
+        }
+        for unit in generate_secondary_units(&mut rng, Locale::DeDE, 50) {
+            assert!(
+                unit.starts_with("Wohnung ") || unit.starts_with("App. "),
+                "{}",
+                unit
+            );
+        }
+        assert!(generate_po_box(&mut rng, Locale::DeDE).starts_with("Postfach "));
+        assert!(generate_po_box(&mut rng, Locale::JaJP).starts_with("私書箱"));
+
+        let options = AddressOptions {
+            unit_rate: 1.0,
+            ..AddressOptions::default()
+        };
+        let overrides = LocaleOverrides::EMPTY;
+        for address in
+            generate_addresses_with_options(&mut rng, Locale::EnGB, &overrides, 20, &options)
+                .unwrap()
+        {
+            let first_line = address.lines().next().unwrap();
+            assert!(
+                ["Flat ", "Apartment ", "Unit "]
+                    .iter()
+                    .any(|d| first_line.starts_with(d)),
+                "{}",
+                address
+            );
+            assert!(!first_line.contains("Apt"), "{}", address);
+        }
+
+        for locale in Locale::ALL {
+            let format = get_locale_data(*locale).secondary_address_format();
+            assert!(format.is_some_and(|f| !f.units.is_empty()), "{:?}", locale);
+        }
+    }
+
+    #[test]
+    fn test_address_options_default_matches_plain() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+
+        let plain = generate_addresses(&mut rng1, Locale::EnUS, 50);
+        let with_options = generate_addresses_with_options(
+            &mut rng2,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            50,
+            &AddressOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(plain, with_options);
+    }
+
+    #[test]
+    fn test_address_options_rates() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let overrides = LocaleOverrides::EMPTY;
+
+        let all_boxes = AddressOptions {
+            po_box_rate: 1.0,
+            ..AddressOptions::default()
+        };
+        for address in
+            generate_addresses_with_options(&mut rng, Locale::EnUS, &overrides, 50, &all_boxes)
+                .unwrap()
+        {
+            assert!(address.starts_with("PO Box "), "{}", address);
+            assert_eq!(address.split(", ").count(), 3, "{}", address);
+        }
+
+        let mixed = AddressOptions {
+            unit_rate: 0.5,
+            po_box_rate: 0.2,
+        };
+        let addresses =
+            generate_addresses_with_options(&mut rng, Locale::EnUS, &overrides, 1000, &mixed)
+                .unwrap();
+        let has_unit = |a: &&String| {
+            let street = a.split(", ").next().unwrap();
+            ["Apt", "Suite", "Unit"]
+                .iter()
+                .any(|d| street.contains(&format!(" {} ", d)))
+        };
+        let boxes = addresses
+            .iter()
+            .filter(|a| a.starts_with("PO Box "))
+            .count();
+        let units = addresses.iter().filter(has_unit).count();
+        assert!((120..280).contains(&boxes), "{} PO Boxes", boxes);
+        assert!((400..600).contains(&units), "{} units", units);
+    }
+
+    #[test]
+    fn test_address_options_invalid() {
+        let mut rng = ForgeryRng::new();
+        let overrides = LocaleOverrides::EMPTY;
+        for (unit_rate, po_box_rate) in [(-0.1, 0.0), (0.0, 1.5), (f64::NAN, 0.0), (0.6, 0.6)] {
+            let options = AddressOptions {
+                unit_rate,
+                po_box_rate,
+            };
+            assert!(options.validate().is_err());
+            assert!(
+                generate_address_with_options(&mut rng, Locale::EnUS, &overrides, &options)
+                    .is_err()
+            );
+        }
+        let err = AddressOptions {
+            unit_rate: 0.6,
+            po_box_rate: 0.6,
+        }
+        .validate()
+        .unwrap_err();
+        assert!(err.to_string().contains("at most 1.0"));
+    }
+
+    #[test]
+    fn test_address_options_full_rate_always_applies() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let overrides = LocaleOverrides::EMPTY;
+
+        let all_boxes = AddressOptions {
+            po_box_rate: 1.0,
+            ..AddressOptions::default()
+        };
+        for address in
+            generate_addresses_with_options(&mut rng, Locale::EnUS, &overrides, 10_000, &all_boxes)
+                .unwrap()
+        {
+            assert!(address.starts_with("PO Box "), "{}", address);
+        }
+
+        let all_units = AddressOptions {
+            unit_rate: 1.0,
+            ..AddressOptions::default()
+        };
+        for address in
+            generate_addresses_with_options(&mut rng, Locale::EnUS, &overrides, 10_000, &all_units)
+                .unwrap()
+        {
+            let street = address.split(", ").next().unwrap();
+            assert!(
+                ["Apt", "Suite", "Unit"]
+                    .iter()
+                    .any(|d| street.contains(&format!(" {} ", d))),
+                "{}",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn test_address_parts_consistent() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let overrides = LocaleOverrides::EMPTY;
+
+        let parts =
+            generate_address_parts_batch(&mut rng, Locale::EnUS, &overrides, 100, 0.0).unwrap();
+        for p in &parts {
+            let index = STATES.iter().position(|s| *s == p.region).unwrap();
+            assert_eq!(p.region_abbr, STATE_ABBRS[index]);
+            assert!(p.building_number.parse::<u32>().is_ok());
+            assert!(CITIES.contains(&p.city.as_str()));
+            assert!(p.unit.is_empty());
+            assert_eq!(p.country, "United States");
+        }
+
+        let with_units =
+            generate_address_parts_batch(&mut rng, Locale::EnUS, &overrides, 20, 1.0).unwrap();
+        assert!(with_units.iter().all(|p| !p.unit.is_empty()));
+
+        let german = generate_address_parts(&mut rng, Locale::DeDE, &overrides, 0.0).unwrap();
+        assert_eq!(german.country, "Germany");
+        assert_eq!(
+            german.clone().into_values().len(),
+            ADDRESS_PARTS_FIELDS.len()
+        );
+        assert!(generate_address_parts(&mut rng, Locale::EnUS, &overrides, 1.5).is_err());
+    }
+
+    #[test]
+    fn test_address_parts_full_unit_rate_always_has_unit() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let parts = generate_address_parts_batch(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            10_000,
+            1.0,
+        )
+        .unwrap();
+        assert!(parts.iter().all(|p| !p.unit.is_empty()));
+    }
+
+    #[test]
+    fn test_address_parts_street_matches_street_address() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(3);
+        rng2.seed(3);
+
+        for locale in [Locale::EnUS, Locale::DeDE, Locale::EsES] {
+            let street = generate_street_address(&mut rng1, locale);
+            let parts = address_parts(&mut rng2, locale, &LocaleOverrides::EMPTY, 0.0);
+            let number: u32 = parts.building_number.parse().unwrap();
+            assert_eq!(
+                format_street_address(locale, number, &parts.street_name, &parts.street_suffix),
+                street
+            );
+            rng1.seed(3);
+            rng2.seed(3);
+        }
+    }
 }
 
 #[cfg(test)]
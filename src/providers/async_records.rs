@@ -34,6 +34,7 @@
 //! - Use `chunk_size >= n` to avoid chunking
 //! - Use the sync `records_arrow()` method directly
 
+use crate::data::overrides::LocaleOverrides;
 use crate::locale::Locale;
 use crate::providers::custom::CustomProvider;
 use crate::providers::records::{
@@ -71,6 +72,7 @@ fn normalize_chunk_size(chunk_size: usize) -> usize {
 ///
 /// * `rng` - The random number generator (will be mutated)
 /// * `locale` - The locale for locale-aware generation
+/// * `overrides` - User replacements for locale data lists
 /// * `n` - Total number of records to generate
 /// * `schema` - The schema specification
 /// * `chunk_size` - Number of records per chunk
@@ -82,6 +84,7 @@ fn normalize_chunk_size(chunk_size: usize) -> usize {
 pub async fn generate_records_async(
     rng: &mut ForgeryRng,
     locale: Locale,
+    overrides: &LocaleOverrides,
     n: usize,
     schema: &BTreeMap<String, FieldSpec>,
     chunk_size: usize,
@@ -101,7 +104,8 @@ pub async fn generate_records_async(
         for _ in 0..this_chunk {
             let mut record = BTreeMap::new();
             for (field_name, spec) in schema {
-                let value = generate_value_with_custom(rng, locale, spec, custom_providers)?;
+                let value =
+                    generate_value_with_custom(rng, locale, overrides, spec, custom_providers)?;
                 record.insert(field_name.clone(), value);
             }
             records.push(record);
@@ -128,6 +132,7 @@ pub async fn generate_records_async(
 ///
 /// * `rng` - The random number generator (will be mutated)
 /// * `locale` - The locale for locale-aware generation
+/// * `overrides` - User replacements for locale data lists
 /// * `n` - Total number of records to generate
 /// * `schema` - The schema specification
 /// * `field_order` - Order of fields in output tuples
@@ -137,9 +142,11 @@ pub async fn generate_records_async(
 /// # Returns
 ///
 /// A vector of tuples (as Vecs).
+#[allow(clippy::too_many_arguments)]
 pub async fn generate_records_tuples_async(
     rng: &mut ForgeryRng,
     locale: Locale,
+    overrides: &LocaleOverrides,
     n: usize,
     schema: &BTreeMap<String, FieldSpec>,
     field_order: &[String],
@@ -193,7 +200,8 @@ pub async fn generate_records_tuples_async(
                 let spec = schema
                     .get(field_name)
                     .expect("field_name was validated to exist in schema");
-                let value = generate_value_with_custom(rng, locale, spec, custom_providers)?;
+                let value =
+                    generate_value_with_custom(rng, locale, overrides, spec, custom_providers)?;
                 record.push(value);
             }
             records.push(record);
@@ -237,6 +245,7 @@ pub async fn generate_records_tuples_async(
 ///
 /// * `rng` - The random number generator (will be mutated)
 /// * `locale` - The locale for locale-aware generation
+/// * `overrides` - User replacements for locale data lists
 /// * `n` - Total number of records to generate
 /// * `schema` - The schema specification
 /// * `chunk_size` - Number of records per chunk
@@ -248,6 +257,7 @@ pub async fn generate_records_tuples_async(
 pub async fn generate_records_arrow_async(
     rng: &mut ForgeryRng,
     locale: Locale,
+    overrides: &LocaleOverrides,
     n: usize,
     schema: &BTreeMap<String, FieldSpec>,
     chunk_size: usize,
@@ -263,7 +273,14 @@ pub async fn generate_records_arrow_async(
 
     // For small batches, just use the sync version
     if n <= chunk_size {
-        return generate_records_arrow_with_custom(rng, locale, n, schema, custom_providers);
+        return generate_records_arrow_with_custom(
+            rng,
+            locale,
+            overrides,
+            n,
+            schema,
+            custom_providers,
+        );
     }
 
     let mut batches: Vec<RecordBatch> = Vec::new();
@@ -273,8 +290,14 @@ pub async fn generate_records_arrow_async(
         let this_chunk = remaining.min(chunk_size);
 
         // Generate chunk as a RecordBatch
-        let batch =
-            generate_records_arrow_with_custom(rng, locale, this_chunk, schema, custom_providers)?;
+        let batch = generate_records_arrow_with_custom(
+            rng,
+            locale,
+            overrides,
+            this_chunk,
+            schema,
+            custom_providers,
+        )?;
 
         batches.push(batch);
         remaining -= this_chunk;
@@ -314,10 +337,17 @@ mod tests {
         rng.seed(42);
 
         let schema = create_test_schema();
-        let records =
-            generate_records_async(&mut rng, Locale::EnUS, 100, &schema, 10, &HashMap::new())
-                .await
-                .unwrap();
+        let records = generate_records_async(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+            &schema,
+            10,
+            &HashMap::new(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(records.len(), 100);
         for record in &records {
@@ -335,15 +365,29 @@ mod tests {
 
         let schema = create_test_schema();
 
-        let records1 =
-            generate_records_async(&mut rng1, Locale::EnUS, 100, &schema, 10, &HashMap::new())
-                .await
-                .unwrap();
+        let records1 = generate_records_async(
+            &mut rng1,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+            &schema,
+            10,
+            &HashMap::new(),
+        )
+        .await
+        .unwrap();
 
-        let records2 =
-            generate_records_async(&mut rng2, Locale::EnUS, 100, &schema, 10, &HashMap::new())
-                .await
-                .unwrap();
+        let records2 = generate_records_async(
+            &mut rng2,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+            &schema,
+            10,
+            &HashMap::new(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(records1, records2);
     }
@@ -360,15 +404,28 @@ mod tests {
         let schema = create_test_schema();
 
         // Sync version
-        let sync_records =
-            generate_records_with_custom(&mut rng1, Locale::EnUS, 100, &schema, &HashMap::new())
-                .unwrap();
+        let sync_records = generate_records_with_custom(
+            &mut rng1,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+            &schema,
+            &HashMap::new(),
+        )
+        .unwrap();
 
         // Async version
-        let async_records =
-            generate_records_async(&mut rng2, Locale::EnUS, 100, &schema, 10, &HashMap::new())
-                .await
-                .unwrap();
+        let async_records = generate_records_async(
+            &mut rng2,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+            &schema,
+            10,
+            &HashMap::new(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(sync_records, async_records);
     }
@@ -378,10 +435,17 @@ mod tests {
         let mut rng = ForgeryRng::new();
         let schema = create_test_schema();
 
-        let records =
-            generate_records_async(&mut rng, Locale::EnUS, 0, &schema, 10, &HashMap::new())
-                .await
-                .unwrap();
+        let records = generate_records_async(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            0,
+            &schema,
+            10,
+            &HashMap::new(),
+        )
+        .await
+        .unwrap();
 
         assert!(records.is_empty());
     }
@@ -397,6 +461,7 @@ mod tests {
         let records = generate_records_tuples_async(
             &mut rng,
             Locale::EnUS,
+            &LocaleOverrides::EMPTY,
             100,
             &schema,
             &field_order,
@@ -419,10 +484,17 @@ mod tests {
 
         let schema = create_test_schema();
 
-        let batch =
-            generate_records_arrow_async(&mut rng, Locale::EnUS, 100, &schema, 10, &HashMap::new())
-                .await
-                .unwrap();
+        let batch = generate_records_arrow_async(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+            &schema,
+            10,
+            &HashMap::new(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(batch.num_rows(), 100);
         assert_eq!(batch.num_columns(), 2);
@@ -440,6 +512,7 @@ mod tests {
         let batch1 = generate_records_arrow_async(
             &mut rng1,
             Locale::EnUS,
+            &LocaleOverrides::EMPTY,
             100,
             &schema,
             10,
@@ -451,6 +524,7 @@ mod tests {
         let batch2 = generate_records_arrow_async(
             &mut rng2,
             Locale::EnUS,
+            &LocaleOverrides::EMPTY,
             100,
             &schema,
             10,
@@ -473,6 +547,7 @@ mod tests {
         let records = generate_records_async(
             &mut rng,
             Locale::EnUS,
+            &LocaleOverrides::EMPTY,
             100,
             &schema,
             0, // Should use default
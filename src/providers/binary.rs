@@ -0,0 +1,138 @@
+//! Binary data generation provider.
+//!
+//! Generates byte strings of random content for blob columns and for
+//! exercising serialization code paths that must handle arbitrary bytes,
+//! and base64 text of random bytes for token and payload columns.
+//!
+//! | Encoding | Example (12 bytes) |
+//! |----------|--------------------|
+//! | base64 | `q3Lp+0Zb/8Qm1x9a` |
+//! | base64url | `q3Lp-0Zb_8Qm1x9a` |
+
+use crate::providers::auth::{encode_base64, BASE64_STANDARD, BASE64_URL};
+use crate::rng::ForgeryRng;
+
+/// Default number of bytes per value.
+pub const DEFAULT_BYTES_LENGTH: usize = 16;
+
+/// Default number of random bytes behind each base64 string.
+pub const DEFAULT_BASE64_DECODED_LENGTH: usize = 32;
+
+/// Generate a batch of random byte strings, each `length` bytes long.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of values to generate
+/// * `length` - Number of bytes in each value
+pub fn generate_byte_strings(rng: &mut ForgeryRng, n: usize, length: usize) -> Vec<Vec<u8>> {
+    let mut values = Vec::with_capacity(n);
+    for _ in 0..n {
+        values.push(generate_byte_string(rng, length));
+    }
+    values
+}
+
+/// Generate a single byte string of `length` random bytes.
+#[inline]
+pub fn generate_byte_string(rng: &mut ForgeryRng, length: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; length];
+    rng.fill_bytes(&mut bytes);
+    bytes
+}
+
+/// Generate a batch of base64 strings that decode to `decoded_length` random bytes.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of values to generate
+/// * `decoded_length` - Number of bytes each string decodes to
+/// * `url_safe` - Use the unpadded URL-safe alphabet (`-`, `_`) instead of
+///   padded standard base64 (`+`, `/`, `=`)
+pub fn generate_base64_strings(
+    rng: &mut ForgeryRng,
+    n: usize,
+    decoded_length: usize,
+    url_safe: bool,
+) -> Vec<String> {
+    let mut values = Vec::with_capacity(n);
+    for _ in 0..n {
+        values.push(generate_base64_string(rng, decoded_length, url_safe));
+    }
+    values
+}
+
+/// Generate a single base64 string of `decoded_length` random bytes.
+#[inline]
+pub fn generate_base64_string(
+    rng: &mut ForgeryRng,
+    decoded_length: usize,
+    url_safe: bool,
+) -> String {
+    let bytes = generate_byte_string(rng, decoded_length);
+    if url_safe {
+        encode_base64(&bytes, BASE64_URL, false)
+    } else {
+        encode_base64(&bytes, BASE64_STANDARD, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_string_lengths() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let values = generate_byte_strings(&mut rng, 100, 24);
+        assert_eq!(values.len(), 100);
+        assert!(values.iter().all(|v| v.len() == 24));
+        assert!(values.iter().any(|v| v.iter().any(|&b| b >= 0x80)));
+        assert!(generate_byte_string(&mut rng, 0).is_empty());
+    }
+
+    #[test]
+    fn test_base64_lengths() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        // Padded output is always a multiple of four; unpadded drops the `=`s.
+        for (decoded, padded, unpadded) in
+            [(0, 0, 0), (1, 4, 2), (2, 4, 3), (3, 4, 4), (32, 44, 43)]
+        {
+            assert_eq!(
+                generate_base64_string(&mut rng, decoded, false).len(),
+                padded
+            );
+            assert_eq!(
+                generate_base64_string(&mut rng, decoded, true).len(),
+                unpadded
+            );
+        }
+
+        let standard = generate_base64_strings(&mut rng, 200, 31, false);
+        assert!(standard
+            .iter()
+            .all(|s| s.ends_with('=') && !s.contains(['-', '_'])));
+        assert!(standard.iter().any(|s| s.contains(['+', '/'])));
+        let url_safe = generate_base64_strings(&mut rng, 200, 31, true);
+        assert!(url_safe.iter().all(|s| !s.contains(['+', '/', '='])));
+        assert!(url_safe.iter().any(|s| s.contains(['-', '_'])));
+    }
+
+    #[test]
+    fn test_byte_strings_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+
+        assert_eq!(
+            generate_byte_strings(&mut rng1, 20, 32),
+            generate_byte_strings(&mut rng2, 20, 32)
+        );
+    }
+}
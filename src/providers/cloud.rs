@@ -0,0 +1,595 @@
+//! Cloud resource identifier generation provider.
+//!
+//! Generates AWS resource names and object storage URIs with realistic
+//! partition, region and account components, and region identifiers of the
+//! three major cloud providers.
+//!
+//! | Kind | Example |
+//! |------|---------|
+//! | S3 ARN | `arn:aws:s3:::payments-api-prod-logs/2024/03/15/5f1c9a2e.json.gz` |
+//! | IAM ARN | `arn:aws:iam::482915730164:role/PaymentsApiRole` |
+//! | Lambda ARN | `arn:aws:lambda:eu-west-1:482915730164:function:payments-worker:live` |
+//! | S3 URI | `s3://data-ingest-staging-exports/year=2023/month=11/day=02/part-00007.parquet` |
+//!
+//! S3 and IAM ARNs have no region; IAM and Lambda ARNs carry a 12-digit
+//! account ID.
+//!
+//! Regions are weighted by popularity, so `us-east-1`, `us-central1` and
+//! `eastus` come up most often for AWS, GCP and Azure respectively.
+
+use crate::providers::software::{SERVICE_NAMES, SERVICE_TEAMS};
+use crate::rng::ForgeryRng;
+use std::str::FromStr;
+
+/// AWS service whose ARN format to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArnService {
+    /// S3 bucket or object (`arn:aws:s3:::bucket/key`).
+    S3,
+    /// IAM role, user or policy (`arn:aws:iam::account:role/Name`).
+    Iam,
+    /// Lambda function (`arn:aws:lambda:region:account:function:name`).
+    Lambda,
+}
+
+/// Accepted names for `ArnService`, in declaration order.
+pub const ARN_SERVICES: &[&str] = &["s3", "iam", "lambda"];
+
+/// Error for an unknown ARN service name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArnServiceError {
+    /// The unrecognized service name.
+    pub service: String,
+}
+
+impl std::fmt::Display for ArnServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown ARN service '{}'; expected one of: {}",
+            self.service,
+            ARN_SERVICES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ArnServiceError {}
+
+impl FromStr for ArnService {
+    type Err = ArnServiceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "s3" => Ok(ArnService::S3),
+            "iam" => Ok(ArnService::Iam),
+            "lambda" => Ok(ArnService::Lambda),
+            _ => Err(ArnServiceError {
+                service: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Cloud provider whose region identifiers to use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CloudProvider {
+    /// Amazon Web Services (e.g. `us-east-1`).
+    #[default]
+    Aws,
+    /// Google Cloud Platform (e.g. `europe-west3`).
+    Gcp,
+    /// Microsoft Azure (e.g. `westeurope`).
+    Azure,
+}
+
+/// Accepted names for `CloudProvider`, in declaration order.
+pub const CLOUD_PROVIDERS: &[&str] = &["aws", "gcp", "azure"];
+
+/// Error for an unknown cloud provider name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloudProviderError {
+    /// The unrecognized provider name.
+    pub provider: String,
+}
+
+impl std::fmt::Display for CloudProviderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown cloud provider '{}'; expected one of: {}",
+            self.provider,
+            CLOUD_PROVIDERS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for CloudProviderError {}
+
+impl FromStr for CloudProvider {
+    type Err = CloudProviderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "aws" => Ok(CloudProvider::Aws),
+            "gcp" => Ok(CloudProvider::Gcp),
+            "azure" => Ok(CloudProvider::Azure),
+            _ => Err(CloudProviderError {
+                provider: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Regions of the commercial `aws` partition with popularity weights.
+const AWS_REGION_WEIGHTS: &[(&str, u32)] = &[
+    ("us-east-1", 30),
+    ("us-west-2", 14),
+    ("eu-west-1", 10),
+    ("us-east-2", 8),
+    ("eu-central-1", 7),
+    ("ap-southeast-1", 4),
+    ("ap-northeast-1", 4),
+    ("ap-southeast-2", 3),
+    ("eu-west-2", 3),
+    ("ap-south-1", 3),
+    ("ca-central-1", 3),
+    ("us-west-1", 2),
+    ("sa-east-1", 2),
+    ("eu-north-1", 2),
+    ("ap-northeast-2", 2),
+    ("eu-west-3", 1),
+    ("eu-south-1", 1),
+    ("me-south-1", 1),
+    ("af-south-1", 1),
+];
+
+/// Google Cloud regions with popularity weights.
+const GCP_REGION_WEIGHTS: &[(&str, u32)] = &[
+    ("us-central1", 25),
+    ("us-east1", 14),
+    ("europe-west1", 9),
+    ("us-east4", 8),
+    ("us-west1", 8),
+    ("europe-west4", 6),
+    ("europe-west2", 5),
+    ("europe-west3", 5),
+    ("asia-east1", 4),
+    ("asia-northeast1", 4),
+    ("asia-southeast1", 4),
+    ("australia-southeast1", 3),
+    ("us-west2", 2),
+    ("asia-south1", 2),
+    ("northamerica-northeast1", 2),
+    ("southamerica-east1", 2),
+];
+
+/// Azure regions with popularity weights.
+const AZURE_REGION_WEIGHTS: &[(&str, u32)] = &[
+    ("eastus", 22),
+    ("westeurope", 12),
+    ("eastus2", 12),
+    ("westus2", 9),
+    ("northeurope", 8),
+    ("centralus", 7),
+    ("southcentralus", 5),
+    ("uksouth", 5),
+    ("westus", 4),
+    ("southeastasia", 4),
+    ("japaneast", 3),
+    ("australiaeast", 3),
+    ("canadacentral", 3),
+    ("germanywestcentral", 2),
+    ("francecentral", 2),
+    ("centralindia", 2),
+    ("brazilsouth", 2),
+];
+
+impl CloudProvider {
+    /// The provider's regions with their popularity weights.
+    fn region_weights(self) -> &'static [(&'static str, u32)] {
+        match self {
+            CloudProvider::Aws => AWS_REGION_WEIGHTS,
+            CloudProvider::Gcp => GCP_REGION_WEIGHTS,
+            CloudProvider::Azure => AZURE_REGION_WEIGHTS,
+        }
+    }
+}
+
+/// Regions of the `aws-cn` partition.
+const AWS_CHINA_REGIONS: &[&str] = &["cn-north-1", "cn-northwest-1"];
+
+/// Regions of the `aws-us-gov` partition.
+const AWS_GOVCLOUD_REGIONS: &[&str] = &["us-gov-west-1", "us-gov-east-1"];
+
+/// Environment segments of bucket names.
+const BUCKET_ENVIRONMENTS: &[&str] = &["dev", "staging", "prod"];
+
+/// Purpose segments of bucket names.
+const BUCKET_PURPOSES: &[&str] = &[
+    "logs",
+    "backups",
+    "assets",
+    "artifacts",
+    "exports",
+    "uploads",
+    "reports",
+    "data-lake",
+    "tfstate",
+];
+
+/// Object extensions of dated log and event keys.
+const OBJECT_EXTENSIONS: &[&str] = &["json", "json.gz", "csv", "log.gz", "avro"];
+
+/// Name suffixes of IAM roles and policies.
+const IAM_ROLE_SUFFIXES: &[&str] = &["Role", "ExecutionRole", "DeployRole", "ReadOnlyRole"];
+
+/// Lambda aliases appended to qualified function ARNs.
+const LAMBDA_ALIASES: &[&str] = &["live", "prod", "staging"];
+
+/// Random decimal digits as a string.
+fn random_digit_string(rng: &mut ForgeryRng, count: usize) -> String {
+    (0..count)
+        .map(|_| char::from(b'0' + rng.gen_range(0u8, 9)))
+        .collect()
+}
+
+/// Random lowercase hexadecimal digits as a string.
+fn random_hex_string(rng: &mut ForgeryRng, count: usize) -> String {
+    (0..count)
+        .map(|_| char::from(*rng.choose(b"0123456789abcdef")))
+        .collect()
+}
+
+/// Uppercase the first letter of each `-`-separated word and drop the dashes.
+fn pascal_case(words: &[&str]) -> String {
+    words
+        .iter()
+        .flat_map(|word| word.split('-'))
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Draw one region from a weighted table.
+fn weighted_region(rng: &mut ForgeryRng, weights: &[(&'static str, u32)]) -> &'static str {
+    let total: u32 = weights.iter().map(|&(_, w)| w).sum();
+    let mut roll = rng.gen_range(1, total);
+    for &(region, weight) in weights {
+        if roll <= weight {
+            return region;
+        }
+        roll -= weight;
+    }
+    weights[weights.len() - 1].0
+}
+
+/// Pick a partition and one of its regions; about 2% of values fall in
+/// China or GovCloud.
+fn aws_partition_and_region(rng: &mut ForgeryRng) -> (&'static str, &'static str) {
+    match rng.gen_range(1u8, 100) {
+        1 => ("aws-cn", *rng.choose(AWS_CHINA_REGIONS)),
+        2 => ("aws-us-gov", *rng.choose(AWS_GOVCLOUD_REGIONS)),
+        _ => ("aws", weighted_region(rng, AWS_REGION_WEIGHTS)),
+    }
+}
+
+/// Generate an S3 bucket name (e.g., "payments-api-prod-logs").
+///
+/// Names are lowercase letters, digits and hyphens, at most 63 characters.
+fn bucket_name(rng: &mut ForgeryRng) -> String {
+    let team = *rng.choose(SERVICE_TEAMS);
+    let service = *rng.choose(SERVICE_NAMES);
+    let env = *rng.choose(BUCKET_ENVIRONMENTS);
+    let purpose = *rng.choose(BUCKET_PURPOSES);
+    format!("{}-{}-{}-{}", team, service, env, purpose)
+}
+
+/// Generate an S3 object key in one of the common layouts: date-partitioned
+/// log objects, Hive-style data lake partitions or flat uploads.
+fn object_key(rng: &mut ForgeryRng) -> String {
+    let year = rng.gen_range(2019u16, 2025);
+    let month = rng.gen_range(1u8, 12);
+    let day = rng.gen_range(1u8, 28);
+    match rng.gen_range(0u8, 2) {
+        0 => {
+            let ext = *rng.choose(OBJECT_EXTENSIONS);
+            format!(
+                "{:04}/{:02}/{:02}/{}.{}",
+                year,
+                month,
+                day,
+                random_hex_string(rng, 8),
+                ext
+            )
+        }
+        1 => format!(
+            "year={:04}/month={:02}/day={:02}/part-{:05}.parquet",
+            year,
+            month,
+            day,
+            rng.gen_range(0u32, 199)
+        ),
+        _ => format!(
+            "uploads/{}/{}.pdf",
+            random_hex_string(rng, 2),
+            random_hex_string(rng, 16)
+        ),
+    }
+}
+
+/// Generate an S3 ARN for a bucket or, usually, an object in it.
+fn s3_arn(rng: &mut ForgeryRng) -> String {
+    let (partition, _) = aws_partition_and_region(rng);
+    let bucket = bucket_name(rng);
+    if rng.gen_range(0u8, 3) == 0 {
+        format!("arn:{}:s3:::{}", partition, bucket)
+    } else {
+        format!("arn:{}:s3:::{}/{}", partition, bucket, object_key(rng))
+    }
+}
+
+/// Generate an IAM ARN for a role, user or policy.
+fn iam_arn(rng: &mut ForgeryRng) -> String {
+    let (partition, _) = aws_partition_and_region(rng);
+    let account = random_digit_string(rng, 12);
+    let team = *rng.choose(SERVICE_TEAMS);
+    let service = *rng.choose(SERVICE_NAMES);
+    let resource = match rng.gen_range(0u8, 2) {
+        0 => {
+            let suffix = *rng.choose(IAM_ROLE_SUFFIXES);
+            format!("role/{}{}", pascal_case(&[team, service]), suffix)
+        }
+        1 => format!("user/{}-{}", service, random_digit_string(rng, 2)),
+        _ => format!("policy/{}Access", pascal_case(&[team, service])),
+    };
+    format!("arn:{}:iam::{}:{}", partition, account, resource)
+}
+
+/// Generate a Lambda function ARN, sometimes qualified with an alias.
+fn lambda_arn(rng: &mut ForgeryRng) -> String {
+    let (partition, region) = aws_partition_and_region(rng);
+    let account = random_digit_string(rng, 12);
+    let team = *rng.choose(SERVICE_TEAMS);
+    let service = *rng.choose(SERVICE_NAMES);
+    let mut arn = format!(
+        "arn:{}:lambda:{}:{}:function:{}-{}",
+        partition, region, account, team, service
+    );
+    if rng.gen_range(0u8, 2) == 0 {
+        let alias = *rng.choose(LAMBDA_ALIASES);
+        arn.push(':');
+        arn.push_str(alias);
+    }
+    arn
+}
+
+/// Generate a batch of AWS ARNs.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of ARNs to generate
+/// * `service` - Service format to use; a random service per value if `None`
+pub fn generate_arns(rng: &mut ForgeryRng, n: usize, service: Option<ArnService>) -> Vec<String> {
+    let mut arns = Vec::with_capacity(n);
+    for _ in 0..n {
+        arns.push(generate_arn(rng, service));
+    }
+    arns
+}
+
+/// Generate a single AWS ARN (e.g., "arn:aws:iam::482915730164:role/PaymentsApiRole").
+#[inline]
+pub fn generate_arn(rng: &mut ForgeryRng, service: Option<ArnService>) -> String {
+    let service = service
+        .unwrap_or_else(|| *rng.choose(&[ArnService::S3, ArnService::Iam, ArnService::Lambda]));
+    match service {
+        ArnService::S3 => s3_arn(rng),
+        ArnService::Iam => iam_arn(rng),
+        ArnService::Lambda => lambda_arn(rng),
+    }
+}
+
+/// Generate a batch of S3 URIs.
+pub fn generate_s3_uris(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut uris = Vec::with_capacity(n);
+    for _ in 0..n {
+        uris.push(generate_s3_uri(rng));
+    }
+    uris
+}
+
+/// Generate a single S3 URI (e.g., "s3://payments-api-prod-logs/2024/03/15/5f1c9a2e.json").
+#[inline]
+pub fn generate_s3_uri(rng: &mut ForgeryRng) -> String {
+    let bucket = bucket_name(rng);
+    format!("s3://{}/{}", bucket, object_key(rng))
+}
+
+/// Generate a batch of region identifiers, weighted by popularity.
+pub fn generate_cloud_regions(
+    rng: &mut ForgeryRng,
+    n: usize,
+    provider: CloudProvider,
+) -> Vec<String> {
+    let weights = provider.region_weights();
+    let mut regions = Vec::with_capacity(n);
+    for _ in 0..n {
+        regions.push(weighted_region(rng, weights).to_string());
+    }
+    regions
+}
+
+/// Generate a single region identifier (e.g., "us-east-1", "europe-west3", "westeurope").
+#[inline]
+pub fn generate_cloud_region(rng: &mut ForgeryRng, provider: CloudProvider) -> String {
+    weighted_region(rng, provider.region_weights()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> ForgeryRng {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        rng
+    }
+
+    fn is_aws_region(region: &str) -> bool {
+        AWS_REGION_WEIGHTS.iter().any(|&(r, _)| r == region)
+    }
+
+    fn valid_bucket(bucket: &str) -> bool {
+        (3..=63).contains(&bucket.len())
+            && bucket
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+            && !bucket.starts_with('-')
+            && !bucket.ends_with('-')
+    }
+
+    #[test]
+    fn test_s3_arns() {
+        for arn in generate_arns(&mut seeded(), 200, Some(ArnService::S3)) {
+            let parts: Vec<&str> = arn.splitn(6, ':').collect();
+            assert_eq!(parts.len(), 6, "{}", arn);
+            assert_eq!(parts[0], "arn");
+            assert!(["aws", "aws-cn", "aws-us-gov"].contains(&parts[1]));
+            assert_eq!(&parts[2..5], &["s3", "", ""], "{}", arn);
+            let bucket = parts[5].split('/').next().unwrap();
+            assert!(valid_bucket(bucket), "{}", arn);
+        }
+    }
+
+    #[test]
+    fn test_iam_and_lambda_arns() {
+        let mut rng = seeded();
+        for arn in generate_arns(&mut rng, 200, Some(ArnService::Iam)) {
+            let parts: Vec<&str> = arn.split(':').collect();
+            assert_eq!(parts.len(), 6, "{}", arn);
+            assert_eq!(parts[2], "iam");
+            assert_eq!(parts[3], "", "{}", arn);
+            assert_eq!(parts[4].len(), 12);
+            assert!(parts[4].bytes().all(|b| b.is_ascii_digit()));
+            assert!(
+                ["role/", "user/", "policy/"]
+                    .iter()
+                    .any(|p| parts[5].starts_with(p)),
+                "{}",
+                arn
+            );
+        }
+        for arn in generate_arns(&mut rng, 200, Some(ArnService::Lambda)) {
+            let parts: Vec<&str> = arn.split(':').collect();
+            assert!((7..=8).contains(&parts.len()), "{}", arn);
+            assert_eq!(parts[2], "lambda");
+            assert!(
+                is_aws_region(parts[3])
+                    || AWS_CHINA_REGIONS.contains(&parts[3])
+                    || AWS_GOVCLOUD_REGIONS.contains(&parts[3]),
+                "{}",
+                arn
+            );
+            assert_eq!(parts[4].len(), 12);
+            assert_eq!(parts[5], "function");
+        }
+    }
+
+    #[test]
+    fn test_partition_matches_region() {
+        let mut rng = seeded();
+        for _ in 0..2000 {
+            let (partition, region) = aws_partition_and_region(&mut rng);
+            match partition {
+                "aws-cn" => assert!(region.starts_with("cn-")),
+                "aws-us-gov" => assert!(region.starts_with("us-gov-")),
+                _ => assert!(is_aws_region(region)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_s3_uris() {
+        for uri in generate_s3_uris(&mut seeded(), 200) {
+            let rest = uri.strip_prefix("s3://").unwrap();
+            let (bucket, key) = rest.split_once('/').unwrap();
+            assert!(valid_bucket(bucket), "{}", uri);
+            assert!(!key.is_empty() && !key.starts_with('/'), "{}", uri);
+        }
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        assert_eq!(
+            pascal_case(&["payments", "cache-warmer"]),
+            "PaymentsCacheWarmer"
+        );
+    }
+
+    #[test]
+    fn test_cloud_regions() {
+        let mut rng = seeded();
+        for provider in [CloudProvider::Aws, CloudProvider::Gcp, CloudProvider::Azure] {
+            let weights = provider.region_weights();
+            let regions = generate_cloud_regions(&mut rng, 2000, provider);
+            assert!(regions.iter().all(|r| weights.iter().any(|&(w, _)| w == r)));
+            // The most popular region clearly leads the least popular ones
+            let top = regions.iter().filter(|r| *r == weights[0].0).count();
+            let last = weights[weights.len() - 1].0;
+            let bottom = regions.iter().filter(|r| *r == last).count();
+            assert!(top > 5 * bottom, "{:?}: {} vs {}", provider, top, bottom);
+        }
+    }
+
+    #[test]
+    fn test_region_identifier_shapes() {
+        for weights in [AWS_REGION_WEIGHTS, GCP_REGION_WEIGHTS, AZURE_REGION_WEIGHTS] {
+            for &(region, weight) in weights {
+                assert!(weight > 0);
+                assert!(region
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-'));
+            }
+        }
+        // us-east-1, us-central1, eastus
+        assert!(AWS_REGION_WEIGHTS
+            .iter()
+            .all(|&(r, _)| r.matches('-').count() == 2 && r.ends_with(char::is_numeric)));
+        assert!(GCP_REGION_WEIGHTS
+            .iter()
+            .all(|&(r, _)| r.matches('-').count() == 1 && r.ends_with(char::is_numeric)));
+        assert!(AZURE_REGION_WEIGHTS.iter().all(|&(r, _)| !r.contains('-')));
+    }
+
+    #[test]
+    fn test_cloud_provider_from_str() {
+        for name in CLOUD_PROVIDERS {
+            assert!(name.parse::<CloudProvider>().is_ok());
+        }
+        let err = "oracle".parse::<CloudProvider>().unwrap_err();
+        assert!(err.to_string().contains("'oracle'"));
+    }
+
+    #[test]
+    fn test_arn_service_from_str() {
+        for name in ARN_SERVICES {
+            assert!(name.parse::<ArnService>().is_ok());
+        }
+        let err = "ec2".parse::<ArnService>().unwrap_err();
+        assert!(err.to_string().contains("'ec2'"));
+    }
+
+    #[test]
+    fn test_arns_deterministic() {
+        assert_eq!(
+            generate_arns(&mut seeded(), 50, None),
+            generate_arns(&mut seeded(), 50, None)
+        );
+    }
+}
@@ -0,0 +1,523 @@
+//! Product and publication code generation provider.
+//!
+//! Generates standard codes that carry a check digit. ISBNs use the
+//! hyphenated display form: GS1 prefix (ISBN-13 only), registration group,
+//! registrant, publication and check digit.
+//!
+//! | Code | Example |
+//! |------|---------|
+//! | ISBN-13 | `978-0-306-40615-7` |
+//! | ISBN-10 | `0-306-40615-2` |
+//! | IMEI | `356938035643809` |
+//! | DOI | `10.1038/s41586-020-2012-7` |
+//! | ORCID iD | `0000-0002-1825-0097` |
+
+use crate::providers::finance::{luhn_checksum, validate_luhn};
+use crate::rng::ForgeryRng;
+
+/// Registration groups under the 978 prefix (English, French, German,
+/// Japanese, Russian, Chinese, Czech, Polish, Spanish, Italian, Korean,
+/// Dutch, Swedish).
+pub const ISBN_978_GROUPS: &[&str] = &[
+    "0", "1", "2", "3", "4", "5", "7", "80", "83", "84", "88", "89", "90", "91",
+];
+
+/// Registration groups under the 979 prefix (US, France, Korea, Italy).
+pub const ISBN_979_GROUPS: &[&str] = &["8", "10", "11", "12"];
+
+/// Random decimal digits as a string.
+fn random_digit_string(rng: &mut ForgeryRng, count: usize) -> String {
+    (0..count)
+        .map(|_| char::from(b'0' + rng.gen_range(0u8, 9)))
+        .collect()
+}
+
+/// Digits of a code, ignoring hyphens and spaces.
+fn code_digits(code: &str) -> String {
+    code.chars().filter(|c| *c != '-' && *c != ' ').collect()
+}
+
+/// Group, registrant and publication parts making up the nine-digit ISBN body.
+fn isbn_parts(rng: &mut ForgeryRng, group: &str) -> [String; 3] {
+    let registrant_len = rng.gen_range(2, 7 - group.len());
+    let publication_len = 9 - group.len() - registrant_len;
+    [
+        group.to_string(),
+        random_digit_string(rng, registrant_len),
+        random_digit_string(rng, publication_len),
+    ]
+}
+
+/// ISBN-13 check digit over the first twelve digits (weights 1, 3 alternating).
+fn isbn13_check_digit(digits: &str) -> char {
+    let sum: u32 = digits
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| u32::from(b - b'0') * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    char::from(b'0' + ((10 - sum % 10) % 10) as u8)
+}
+
+/// ISBN-10 check character over the first nine digits (weights 10 down to 2).
+fn isbn10_check_digit(digits: &str) -> char {
+    let sum: u32 = digits
+        .bytes()
+        .zip((2..=10).rev())
+        .map(|(b, w)| u32::from(b - b'0') * w)
+        .sum();
+    match (11 - sum % 11) % 11 {
+        10 => 'X',
+        c => char::from(b'0' + c as u8),
+    }
+}
+
+/// Validate an ISBN-13, with or without hyphens.
+pub fn validate_isbn13(isbn: &str) -> bool {
+    let digits = code_digits(isbn);
+    if digits.len() != 13 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    if !digits.starts_with("978") && !digits.starts_with("979") {
+        return false;
+    }
+    digits.ends_with(isbn13_check_digit(&digits[..12]))
+}
+
+/// Validate an ISBN-10, with or without hyphens.
+///
+/// The final character may be `X`, standing for a check value of 10.
+pub fn validate_isbn10(isbn: &str) -> bool {
+    let digits = code_digits(isbn);
+    if digits.len() != 10 || !digits.is_ascii() || !digits[..9].bytes().all(|b| b.is_ascii_digit())
+    {
+        return false;
+    }
+    digits.ends_with(isbn10_check_digit(&digits[..9]))
+}
+
+/// Generate a batch of hyphenated ISBN-13s.
+pub fn generate_isbn13s(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut isbns = Vec::with_capacity(n);
+    for _ in 0..n {
+        isbns.push(generate_isbn13(rng));
+    }
+    isbns
+}
+
+/// Generate a single hyphenated ISBN-13 (e.g., "978-0-306-40615-7").
+///
+/// Most use the 978 prefix; about one in ten use 979.
+#[inline]
+pub fn generate_isbn13(rng: &mut ForgeryRng) -> String {
+    let (prefix, group) = if rng.gen_range(0u8, 9) == 0 {
+        ("979", *rng.choose(ISBN_979_GROUPS))
+    } else {
+        ("978", *rng.choose(ISBN_978_GROUPS))
+    };
+    let [group, registrant, publication] = isbn_parts(rng, group);
+    let body = format!("{}{}{}{}", prefix, group, registrant, publication);
+    let check = isbn13_check_digit(&body);
+    format!(
+        "{}-{}-{}-{}-{}",
+        prefix, group, registrant, publication, check
+    )
+}
+
+/// Generate a batch of hyphenated ISBN-10s.
+pub fn generate_isbn10s(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut isbns = Vec::with_capacity(n);
+    for _ in 0..n {
+        isbns.push(generate_isbn10(rng));
+    }
+    isbns
+}
+
+/// Generate a single hyphenated ISBN-10 (e.g., "0-306-40615-2").
+///
+/// ISBN-10s only exist for the 978 prefix, so groups are drawn from those.
+#[inline]
+pub fn generate_isbn10(rng: &mut ForgeryRng) -> String {
+    let group = *rng.choose(ISBN_978_GROUPS);
+    let [group, registrant, publication] = isbn_parts(rng, group);
+    let body = format!("{}{}{}", group, registrant, publication);
+    let check = isbn10_check_digit(&body);
+    format!("{}-{}-{}-{}", group, registrant, publication, check)
+}
+
+// === IMEI ===
+
+/// Reporting Body Identifiers that open a Type Allocation Code (PTCRB, BABT,
+/// TAF and GSMA, which issue most handset TACs).
+pub const IMEI_REPORTING_BODIES: &[&str] = &["01", "35", "86", "99"];
+
+/// Validate an IMEI: 15 digits passing the Luhn check.
+pub fn validate_imei(imei: &str) -> bool {
+    imei.len() == 15 && imei.bytes().all(|b| b.is_ascii_digit()) && validate_luhn(imei)
+}
+
+/// Generate a batch of IMEIs with valid Luhn check digits.
+pub fn generate_imeis(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut imeis = Vec::with_capacity(n);
+    for _ in 0..n {
+        imeis.push(generate_imei(rng));
+    }
+    imeis
+}
+
+/// Generate a single 15-digit IMEI (e.g., "356938035643809").
+///
+/// The 8-digit TAC starts with a real reporting body identifier, followed by a
+/// 6-digit serial number and the Luhn check digit.
+#[inline]
+pub fn generate_imei(rng: &mut ForgeryRng) -> String {
+    let reporting_body = *rng.choose(IMEI_REPORTING_BODIES);
+    let mut imei = String::with_capacity(15);
+    imei.push_str(reporting_body);
+    imei.push_str(&random_digit_string(rng, 12));
+    imei.push(char::from(b'0' + luhn_checksum(&imei)));
+    imei
+}
+
+// === DOI ===
+
+/// DOI registrant prefixes of major publishers and repositories, each with a
+/// suffix pattern in that registrant's style.
+///
+/// In patterns `#` is a digit, `?` a lowercase letter, and `{yyyy}`, `{yy}`,
+/// `{mm}` and `{dd}` are parts of a publication date.
+pub const DOI_REGISTRANTS: &[(&str, &str)] = &[
+    ("10.1002", "anie.{yyyy}#####"),
+    ("10.1007", "s#####-0{yy}-0####-#"),
+    ("10.1016", "j.cell.{yyyy}.{mm}.0##"),
+    ("10.1021", "acs.jmedchem.#c0####"),
+    ("10.1038", "s41586-0{yy}-####-#"),
+    ("10.1056", "NEJMoa{yyyy}###"),
+    ("10.1073", "pnas.{yyyy}#####"),
+    ("10.1093", "nar/gka?###"),
+    ("10.1101", "{yyyy}.{mm}.{dd}.######"),
+    ("10.1103", "PhysRevLett.1##.######"),
+    ("10.1109", "TPAMI.{yyyy}.#######"),
+    ("10.1126", "science.a??####"),
+    ("10.1145", "#######.#######"),
+    ("10.1177", "0956797{yy}#######"),
+    ("10.1371", "journal.pone.0######"),
+    ("10.3390", "ijms2#######"),
+    ("10.48550", "arXiv.{yy}{mm}.#####"),
+    ("10.5281", "zenodo.#######"),
+];
+
+/// Expand a DOI suffix pattern from `DOI_REGISTRANTS`.
+fn expand_doi_suffix(rng: &mut ForgeryRng, pattern: &str) -> String {
+    let year: u32 = rng.gen_range(2010, 2024);
+    let month: u32 = rng.gen_range(1, 12);
+    let day: u32 = rng.gen_range(1, 28);
+    pattern
+        .replace("{yyyy}", &year.to_string())
+        .replace("{yy}", &format!("{:02}", year % 100))
+        .replace("{mm}", &format!("{:02}", month))
+        .replace("{dd}", &format!("{:02}", day))
+        .chars()
+        .map(|c| match c {
+            '#' => char::from(b'0' + rng.gen_range(0u8, 9)),
+            '?' => char::from(b'a' + rng.gen_range(0u8, 25)),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Generate a batch of DOIs.
+pub fn generate_dois(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut dois = Vec::with_capacity(n);
+    for _ in 0..n {
+        dois.push(generate_doi(rng));
+    }
+    dois
+}
+
+/// Generate a single DOI (e.g., "10.1038/s41586-020-2012-7").
+///
+/// The prefix is a real registrant and the suffix follows its style.
+#[inline]
+pub fn generate_doi(rng: &mut ForgeryRng) -> String {
+    let &(prefix, pattern) = rng.choose(DOI_REGISTRANTS);
+    format!("{}/{}", prefix, expand_doi_suffix(rng, pattern))
+}
+
+// === ORCID ===
+
+/// ISO 7064 MOD 11-2 check character over the first fifteen ORCID digits.
+fn orcid_check_digit(digits: &str) -> char {
+    let total = digits
+        .bytes()
+        .fold(0u32, |total, b| (total + u32::from(b - b'0')) * 2);
+    match (12 - total % 11) % 11 {
+        10 => 'X',
+        c => char::from(b'0' + c as u8),
+    }
+}
+
+/// Validate an ORCID iD, with or without hyphens.
+///
+/// The final character may be `X`, standing for a check value of 10.
+pub fn validate_orcid(orcid: &str) -> bool {
+    let digits = code_digits(orcid);
+    if digits.len() != 16 || !digits.is_ascii() || !digits[..15].bytes().all(|b| b.is_ascii_digit())
+    {
+        return false;
+    }
+    digits.ends_with(orcid_check_digit(&digits[..15]))
+}
+
+/// Generate a batch of ORCID iDs.
+pub fn generate_orcids(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut orcids = Vec::with_capacity(n);
+    for _ in 0..n {
+        orcids.push(generate_orcid(rng));
+    }
+    orcids
+}
+
+/// Generate a single ORCID iD (e.g., "0000-0002-1825-0097").
+///
+/// iDs fall in the issued `0000-0001` to `0000-0003` blocks and end with
+/// an ISO 7064 MOD 11-2 check character, which may be `X`.
+#[inline]
+pub fn generate_orcid(rng: &mut ForgeryRng) -> String {
+    let block: u8 = rng.gen_range(1, 3);
+    let body = format!("0000000{}{}", block, random_digit_string(rng, 7));
+    let check = orcid_check_digit(&body);
+    format!(
+        "{}-{}-{}-{}{}",
+        &body[..4],
+        &body[4..8],
+        &body[8..12],
+        &body[12..],
+        check
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isbn_check_digits_known_values() {
+        assert_eq!(isbn13_check_digit("978030640615"), '7');
+        assert_eq!(isbn10_check_digit("030640615"), '2');
+        // 0-8044-2957-X
+        assert_eq!(isbn10_check_digit("080442957"), 'X');
+    }
+
+    #[test]
+    fn test_validate_isbn13() {
+        assert!(validate_isbn13("978-0-306-40615-7"));
+        assert!(validate_isbn13("9780306406157"));
+        assert!(!validate_isbn13("978-0-306-40615-8"));
+        assert!(!validate_isbn13("977-0-306-40615-7"));
+        assert!(!validate_isbn13("978-0-306-4061"));
+    }
+
+    #[test]
+    fn test_validate_isbn10() {
+        assert!(validate_isbn10("0-306-40615-2"));
+        assert!(validate_isbn10("0-8044-2957-X"));
+        assert!(!validate_isbn10("0-306-40615-3"));
+        assert!(!validate_isbn10("0-306-4061"));
+    }
+
+    #[test]
+    fn test_generate_isbn13s() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let isbns = generate_isbn13s(&mut rng, 500);
+        assert_eq!(isbns.len(), 500);
+        for isbn in &isbns {
+            assert!(validate_isbn13(isbn), "{}", isbn);
+            let parts: Vec<&str> = isbn.split('-').collect();
+            assert_eq!(parts.len(), 5, "{}", isbn);
+            let groups = if parts[0] == "978" {
+                ISBN_978_GROUPS
+            } else {
+                ISBN_979_GROUPS
+            };
+            assert!(groups.contains(&parts[1]), "{}", isbn);
+        }
+        assert!(isbns.iter().any(|i| i.starts_with("979-")));
+    }
+
+    #[test]
+    fn test_generate_isbn10s() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let isbns = generate_isbn10s(&mut rng, 500);
+        assert_eq!(isbns.len(), 500);
+        for isbn in &isbns {
+            assert!(validate_isbn10(isbn), "{}", isbn);
+            assert_eq!(code_digits(isbn).len(), 10, "{}", isbn);
+            assert_eq!(isbn.matches('-').count(), 3, "{}", isbn);
+        }
+        assert!(isbns.iter().any(|i| i.ends_with('X')));
+    }
+
+    #[test]
+    fn test_isbn_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_isbn13s(&mut rng1, 50),
+            generate_isbn13s(&mut rng2, 50)
+        );
+        assert_eq!(
+            generate_isbn10s(&mut rng1, 50),
+            generate_isbn10s(&mut rng2, 50)
+        );
+    }
+
+    #[test]
+    fn test_validate_imei() {
+        assert!(validate_imei("490154203237518"));
+        assert!(!validate_imei("490154203237519"));
+        assert!(!validate_imei("49015420323751"));
+        assert!(!validate_imei("49015420323751a"));
+    }
+
+    #[test]
+    fn test_generate_imeis() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let imeis = generate_imeis(&mut rng, 500);
+        assert_eq!(imeis.len(), 500);
+        for imei in &imeis {
+            assert!(validate_imei(imei), "{}", imei);
+            assert!(IMEI_REPORTING_BODIES.contains(&&imei[..2]), "{}", imei);
+        }
+    }
+
+    #[test]
+    fn test_generate_dois() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let dois = generate_dois(&mut rng, 500);
+        for doi in &dois {
+            let (prefix, suffix) = doi.split_once('/').unwrap();
+            assert!(DOI_REGISTRANTS.iter().any(|(p, _)| *p == prefix), "{}", doi);
+            assert!(!suffix.contains(['#', '?', '{', '}']), "{}", doi);
+            assert!(suffix.is_ascii() && !suffix.contains(' '), "{}", doi);
+        }
+        let prefixes: std::collections::HashSet<_> =
+            dois.iter().map(|d| d.split('/').next().unwrap()).collect();
+        assert_eq!(prefixes.len(), DOI_REGISTRANTS.len());
+    }
+
+    #[test]
+    fn test_doi_suffix_pattern() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let suffix = expand_doi_suffix(&mut rng, "{yyyy}.{mm}.{dd}.##");
+        let parts: Vec<&str> = suffix.split('.').collect();
+        let year: u32 = parts[0].parse().unwrap();
+        assert!((2010..=2024).contains(&year), "{}", suffix);
+        assert!((1..=12).contains(&parts[1].parse::<u32>().unwrap()));
+        assert!((1..=28).contains(&parts[2].parse::<u32>().unwrap()));
+        assert_eq!(parts[3].len(), 2);
+    }
+
+    #[test]
+    fn test_orcid_check_digit_known_values() {
+        assert_eq!(orcid_check_digit("000000021825009"), '7');
+        // Example iD with an X check character from the ORCID documentation
+        assert_eq!(orcid_check_digit("000000021694233"), 'X');
+        assert!(validate_orcid("0000-0002-1825-0097"));
+        assert!(validate_orcid("0000-0002-1694-233X"));
+        assert!(!validate_orcid("0000-0002-1825-0098"));
+        assert!(!validate_orcid("0000-0002-1825-009"));
+    }
+
+    #[test]
+    fn test_generate_orcids() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let orcids = generate_orcids(&mut rng, 500);
+        for orcid in &orcids {
+            assert!(validate_orcid(orcid), "{}", orcid);
+            assert_eq!(orcid.len(), 19, "{}", orcid);
+            assert!(
+                ["0000-0001-", "0000-0002-", "0000-0003-"]
+                    .iter()
+                    .any(|p| orcid.starts_with(p)),
+                "{}",
+                orcid
+            );
+        }
+        assert!(orcids.iter().any(|o| o.ends_with('X')));
+    }
+
+    #[test]
+    fn test_imei_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(generate_imeis(&mut rng1, 50), generate_imeis(&mut rng2, 50));
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_isbn13_valid(seed in any::<u64>(), n in 0usize..100) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let isbns = generate_isbn13s(&mut rng, n);
+            prop_assert_eq!(isbns.len(), n);
+            for isbn in isbns {
+                prop_assert!(validate_isbn13(&isbn), "{}", isbn);
+            }
+        }
+
+        #[test]
+        fn prop_isbn10_valid(seed in any::<u64>(), n in 0usize..100) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let isbns = generate_isbn10s(&mut rng, n);
+            prop_assert_eq!(isbns.len(), n);
+            for isbn in isbns {
+                prop_assert!(validate_isbn10(&isbn), "{}", isbn);
+            }
+        }
+
+        #[test]
+        fn prop_orcid_valid(seed in any::<u64>(), n in 0usize..100) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            for orcid in generate_orcids(&mut rng, n) {
+                prop_assert!(validate_orcid(&orcid), "{}", orcid);
+            }
+        }
+
+        #[test]
+        fn prop_imei_valid(seed in any::<u64>(), n in 0usize..100) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let imeis = generate_imeis(&mut rng, n);
+            prop_assert_eq!(imeis.len(), n);
+            for imei in imeis {
+                prop_assert!(validate_imei(&imei), "{}", imei);
+            }
+        }
+    }
+}
@@ -1,10 +1,12 @@
 //! Color generation provider.
 //!
-//! Generates color names, hex colors, and RGB tuples.
+//! Generates color names, hex colors, RGB, HSL and CMYK tuples, CSS color
+//! strings, and harmonized palettes.
 
 use crate::data::get_locale_data;
 use crate::locale::Locale;
 use crate::rng::ForgeryRng;
+use std::str::FromStr;
 
 /// Generate a batch of random color names.
 pub fn generate_colors(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
@@ -71,6 +73,453 @@ pub fn generate_rgb_color(rng: &mut ForgeryRng) -> (u8, u8, u8) {
     (r, g, b)
 }
 
+/// Generate a batch of random HSL color tuples.
+///
+/// Returns colors as `(h, s, l)` with hue in degrees (0-359) and saturation
+/// and lightness as percentages (0-100).
+pub fn generate_hsl_colors(rng: &mut ForgeryRng, n: usize) -> Vec<(u16, u8, u8)> {
+    let mut colors = Vec::with_capacity(n);
+    for _ in 0..n {
+        colors.push(generate_hsl_color(rng));
+    }
+    colors
+}
+
+/// Generate a single random HSL color tuple.
+///
+/// Returns a color as `(h, s, l)` with hue 0-359 and saturation and
+/// lightness 0-100.
+#[inline]
+pub fn generate_hsl_color(rng: &mut ForgeryRng) -> (u16, u8, u8) {
+    let h: u16 = rng.gen_range(0, 359);
+    let s: u8 = rng.gen_range(0, 100);
+    let l: u8 = rng.gen_range(0, 100);
+    (h, s, l)
+}
+
+/// Generate a batch of random CMYK color tuples.
+///
+/// Returns colors as `(c, m, y, k)` where each component is a percentage
+/// (0-100).
+pub fn generate_cmyk_colors(rng: &mut ForgeryRng, n: usize) -> Vec<(u8, u8, u8, u8)> {
+    let mut colors = Vec::with_capacity(n);
+    for _ in 0..n {
+        colors.push(generate_cmyk_color(rng));
+    }
+    colors
+}
+
+/// Generate a single random CMYK color tuple.
+///
+/// Returns a color as `(c, m, y, k)` where each component is 0-100.
+#[inline]
+pub fn generate_cmyk_color(rng: &mut ForgeryRng) -> (u8, u8, u8, u8) {
+    let c: u8 = rng.gen_range(0, 100);
+    let m: u8 = rng.gen_range(0, 100);
+    let y: u8 = rng.gen_range(0, 100);
+    let k: u8 = rng.gen_range(0, 100);
+    (c, m, y, k)
+}
+
+/// CSS named colors (CSS Color Module Level 4).
+const CSS_NAMED_COLORS: &[&str] = &[
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+/// CSS color syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CssColorFormat {
+    /// `#rrggbb`
+    Hex,
+    /// `rgb(r, g, b)`
+    Rgb,
+    /// `rgba(r, g, b, a)`
+    Rgba,
+    /// `hsl(h, s%, l%)`
+    Hsl,
+    /// A CSS named color such as `rebeccapurple`.
+    Named,
+}
+
+/// Accepted CSS color format names.
+pub const CSS_COLOR_FORMATS: &[&str] = &["hex", "rgb", "rgba", "hsl", "named"];
+
+/// Every CSS color format, for picking one at random.
+const ALL_CSS_COLOR_FORMATS: &[CssColorFormat] = &[
+    CssColorFormat::Hex,
+    CssColorFormat::Rgb,
+    CssColorFormat::Rgba,
+    CssColorFormat::Hsl,
+    CssColorFormat::Named,
+];
+
+/// Error for an unknown CSS color format name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssColorFormatError {
+    /// The unrecognized format name.
+    pub format: String,
+}
+
+impl std::fmt::Display for CssColorFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown CSS color format '{}'; expected one of: {}",
+            self.format,
+            CSS_COLOR_FORMATS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for CssColorFormatError {}
+
+impl FromStr for CssColorFormat {
+    type Err = CssColorFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "hex" => Ok(CssColorFormat::Hex),
+            "rgb" => Ok(CssColorFormat::Rgb),
+            "rgba" => Ok(CssColorFormat::Rgba),
+            "hsl" => Ok(CssColorFormat::Hsl),
+            "named" => Ok(CssColorFormat::Named),
+            _ => Err(CssColorFormatError {
+                format: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Generate a batch of CSS color strings.
+///
+/// With `format` set every value uses that syntax; with `None` each value
+/// picks one of the formats at random.
+pub fn generate_css_colors(
+    rng: &mut ForgeryRng,
+    n: usize,
+    format: Option<CssColorFormat>,
+) -> Vec<String> {
+    let mut colors = Vec::with_capacity(n);
+    for _ in 0..n {
+        colors.push(generate_css_color(rng, format));
+    }
+    colors
+}
+
+/// Generate a single CSS color string, e.g. `rgba(12, 200, 45, 0.35)`.
+pub fn generate_css_color(rng: &mut ForgeryRng, format: Option<CssColorFormat>) -> String {
+    let format = match format {
+        Some(format) => format,
+        None => *rng.choose(ALL_CSS_COLOR_FORMATS),
+    };
+    match format {
+        CssColorFormat::Hex => generate_hex_color(rng),
+        CssColorFormat::Rgb => {
+            let (r, g, b) = generate_rgb_color(rng);
+            format!("rgb({}, {}, {})", r, g, b)
+        }
+        CssColorFormat::Rgba => {
+            let (r, g, b) = generate_rgb_color(rng);
+            let alpha: u8 = rng.gen_range(0, 100);
+            format!("rgba({}, {}, {}, {})", r, g, b, f64::from(alpha) / 100.0)
+        }
+        CssColorFormat::Hsl => {
+            let (h, s, l) = generate_hsl_color(rng);
+            format!("hsl({}, {}%, {}%)", h, s, l)
+        }
+        CssColorFormat::Named => rng.choose(CSS_NAMED_COLORS).to_string(),
+    }
+}
+
+/// Strategy for picking related colors in a palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteScheme {
+    /// Neighbouring hues, 30 degrees apart.
+    Analogous,
+    /// A base hue and its opposite, each in a range of lightness.
+    Complementary,
+    /// Independent random colors.
+    Random,
+}
+
+/// Accepted palette scheme names.
+pub const PALETTE_SCHEMES: &[&str] = &["analogous", "complementary", "random"];
+
+/// Error for an unknown palette scheme name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteSchemeError {
+    /// The unrecognized scheme name.
+    pub scheme: String,
+}
+
+impl std::fmt::Display for PaletteSchemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown palette scheme '{}'; expected one of: {}",
+            self.scheme,
+            PALETTE_SCHEMES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for PaletteSchemeError {}
+
+impl FromStr for PaletteScheme {
+    type Err = PaletteSchemeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "analogous" => Ok(PaletteScheme::Analogous),
+            "complementary" => Ok(PaletteScheme::Complementary),
+            "random" => Ok(PaletteScheme::Random),
+            _ => Err(PaletteSchemeError {
+                scheme: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Hue step between neighbouring analogous colors, in degrees.
+const ANALOGOUS_STEP: u16 = 30;
+
+/// Lightness range (percent) spanned by each half of a complementary palette.
+const COMPLEMENTARY_LIGHTNESS: (u8, u8) = (30, 70);
+
+/// Convert an HSL color (hue in degrees, saturation and lightness in percent)
+/// to `#rrggbb`.
+fn hsl_to_hex(h: u16, s: u8, l: u8) -> String {
+    let s = f64::from(s) / 100.0;
+    let l = f64::from(l) / 100.0;
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let sector = f64::from(h % 360) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = l - chroma / 2.0;
+    let channel = |v: f64| ((v + m) * 255.0).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(r), channel(g), channel(b))
+}
+
+/// Spread `count` lightness values evenly across `(min, max)`.
+fn lightness_step(index: usize, count: usize, (min, max): (u8, u8)) -> u8 {
+    if count <= 1 {
+        return (min + max) / 2;
+    }
+    let span = usize::from(max - min);
+    min + u8::try_from(span * index / (count - 1)).expect("step within lightness span")
+}
+
+/// Generate a batch of color palettes, each a list of `size` hex colors.
+pub fn generate_color_palettes(
+    rng: &mut ForgeryRng,
+    n: usize,
+    size: usize,
+    scheme: PaletteScheme,
+) -> Vec<Vec<String>> {
+    let mut palettes = Vec::with_capacity(n);
+    for _ in 0..n {
+        palettes.push(generate_color_palette(rng, size, scheme));
+    }
+    palettes
+}
+
+/// Pick a base color for a harmonized palette: any hue, with saturation
+/// (45-85%) and lightness (35-65%) kept away from the washed-out extremes.
+fn base_hsl(rng: &mut ForgeryRng) -> (u16, u8, u8) {
+    let h: u16 = rng.gen_range(0, 359);
+    let s: u8 = rng.gen_range(45, 85);
+    let l: u8 = rng.gen_range(35, 65);
+    (h, s, l)
+}
+
+/// Generate a single palette of `size` hex colors.
+///
+/// Analogous palettes step the hue 30 degrees at a time at a fixed
+/// saturation and lightness. Complementary palettes alternate a hue and its
+/// opposite, each shaded from dark to light.
+pub fn generate_color_palette(
+    rng: &mut ForgeryRng,
+    size: usize,
+    scheme: PaletteScheme,
+) -> Vec<String> {
+    match scheme {
+        PaletteScheme::Analogous => {
+            let (hue, saturation, lightness) = base_hsl(rng);
+            (0..size)
+                .map(|i| {
+                    let offset = (i % 12) as u16 * ANALOGOUS_STEP;
+                    hsl_to_hex(hue + offset, saturation, lightness)
+                })
+                .collect()
+        }
+        PaletteScheme::Complementary => {
+            let (hue, saturation, _) = base_hsl(rng);
+            let per_side = size.div_ceil(2);
+            (0..size)
+                .map(|i| {
+                    let shade = lightness_step(i / 2, per_side, COMPLEMENTARY_LIGHTNESS);
+                    hsl_to_hex(hue + (i % 2) as u16 * 180, saturation, shade)
+                })
+                .collect()
+        }
+        PaletteScheme::Random => generate_hex_colors(rng, size),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,6 +711,197 @@ mod tests {
         assert!(has_middle_values, "Should have some middle-range values");
     }
 
+    #[test]
+    fn test_hsl_color_ranges() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let colors = generate_hsl_colors(&mut rng, 1000);
+        assert_eq!(colors.len(), 1000);
+        for (h, s, l) in colors {
+            assert!(h < 360, "hue {} out of range", h);
+            assert!(s <= 100 && l <= 100, "({}, {}, {}) out of range", h, s, l);
+        }
+    }
+
+    #[test]
+    fn test_cmyk_color_ranges() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let colors = generate_cmyk_colors(&mut rng, 1000);
+        assert_eq!(colors.len(), 1000);
+        for (c, m, y, k) in colors {
+            assert!(
+                c <= 100 && m <= 100 && y <= 100 && k <= 100,
+                "({}, {}, {}, {}) out of range",
+                c,
+                m,
+                y,
+                k
+            );
+        }
+    }
+
+    #[test]
+    fn test_hsl_cmyk_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(12345);
+        rng2.seed(12345);
+
+        assert_eq!(
+            generate_hsl_colors(&mut rng1, 100),
+            generate_hsl_colors(&mut rng2, 100)
+        );
+        assert_eq!(
+            generate_cmyk_color(&mut rng1),
+            generate_cmyk_color(&mut rng2)
+        );
+    }
+
+    #[test]
+    fn test_css_color_formats() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for color in generate_css_colors(&mut rng, 50, Some(CssColorFormat::Hex)) {
+            assert!(color.starts_with('#') && color.len() == 7, "{}", color);
+        }
+        for color in generate_css_colors(&mut rng, 50, Some(CssColorFormat::Rgb)) {
+            let inner = color
+                .strip_prefix("rgb(")
+                .unwrap()
+                .strip_suffix(')')
+                .unwrap();
+            assert_eq!(inner.split(", ").count(), 3, "{}", color);
+        }
+        for color in generate_css_colors(&mut rng, 50, Some(CssColorFormat::Rgba)) {
+            let inner = color
+                .strip_prefix("rgba(")
+                .unwrap()
+                .strip_suffix(')')
+                .unwrap();
+            let alpha: f64 = inner.rsplit(", ").next().unwrap().parse().unwrap();
+            assert!((0.0..=1.0).contains(&alpha), "{}", color);
+        }
+        for color in generate_css_colors(&mut rng, 50, Some(CssColorFormat::Hsl)) {
+            assert!(
+                color.starts_with("hsl(") && color.ends_with("%)"),
+                "{}",
+                color
+            );
+        }
+        for color in generate_css_colors(&mut rng, 50, Some(CssColorFormat::Named)) {
+            assert!(CSS_NAMED_COLORS.contains(&color.as_str()), "{}", color);
+        }
+    }
+
+    #[test]
+    fn test_css_color_mixes_formats() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let colors = generate_css_colors(&mut rng, 500, None);
+        assert!(colors.iter().any(|c| c.starts_with('#')));
+        assert!(colors.iter().any(|c| c.starts_with("rgb(")));
+        assert!(colors.iter().any(|c| c.starts_with("rgba(")));
+        assert!(colors.iter().any(|c| c.starts_with("hsl(")));
+        assert!(colors
+            .iter()
+            .any(|c| CSS_NAMED_COLORS.contains(&c.as_str())));
+    }
+
+    #[test]
+    fn test_css_color_format_from_str() {
+        assert_eq!("RGBA".parse::<CssColorFormat>(), Ok(CssColorFormat::Rgba));
+        let err = "hwb".parse::<CssColorFormat>().unwrap_err();
+        assert!(err.to_string().contains("hex, rgb, rgba, hsl, named"));
+    }
+
+    /// Hue in degrees of a `#rrggbb` color.
+    fn hex_hue(hex: &str) -> f64 {
+        let channel = |i: usize| f64::from(u8::from_str_radix(&hex[i..i + 2], 16).unwrap());
+        let (r, g, b) = (channel(1), channel(3), channel(5));
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        let hue = if max == r {
+            60.0 * ((g - b) / delta)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        hue.rem_euclid(360.0)
+    }
+
+    fn hue_distance(a: f64, b: f64) -> f64 {
+        let d = (a - b).rem_euclid(360.0);
+        d.min(360.0 - d)
+    }
+
+    #[test]
+    fn test_hsl_to_hex() {
+        assert_eq!(hsl_to_hex(0, 100, 50), "#ff0000");
+        assert_eq!(hsl_to_hex(120, 100, 50), "#00ff00");
+        assert_eq!(hsl_to_hex(240, 100, 50), "#0000ff");
+        assert_eq!(hsl_to_hex(390, 100, 50), "#ff8000");
+        assert_eq!(hsl_to_hex(0, 0, 100), "#ffffff");
+        assert_eq!(hsl_to_hex(0, 0, 0), "#000000");
+    }
+
+    #[test]
+    fn test_analogous_palette_hues() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for palette in generate_color_palettes(&mut rng, 50, 5, PaletteScheme::Analogous) {
+            assert_eq!(palette.len(), 5);
+            for pair in palette.windows(2) {
+                let step = hue_distance(hex_hue(&pair[0]), hex_hue(&pair[1]));
+                assert!((step - 30.0).abs() < 3.0, "{:?}", palette);
+            }
+        }
+    }
+
+    #[test]
+    fn test_complementary_palette_hues() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for palette in generate_color_palettes(&mut rng, 50, 6, PaletteScheme::Complementary) {
+            assert_eq!(palette.len(), 6);
+            let base = hex_hue(&palette[0]);
+            for (i, color) in palette.iter().enumerate() {
+                let expected = if i % 2 == 0 { 0.0 } else { 180.0 };
+                let distance = hue_distance(base, hex_hue(color));
+                assert!((distance - expected).abs() < 3.0, "{:?}", palette);
+            }
+            assert_ne!(palette[0], palette[2], "shades should differ");
+        }
+    }
+
+    #[test]
+    fn test_palette_sizes_and_schemes() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        assert!(generate_color_palette(&mut rng, 0, PaletteScheme::Analogous).is_empty());
+        assert_eq!(
+            generate_color_palette(&mut rng, 1, PaletteScheme::Complementary).len(),
+            1
+        );
+        let random = generate_color_palette(&mut rng, 8, PaletteScheme::Random);
+        assert!(random.iter().all(|c| c.len() == 7 && c.starts_with('#')));
+
+        assert_eq!(
+            "Complementary".parse::<PaletteScheme>(),
+            Ok(PaletteScheme::Complementary)
+        );
+        let err = "triadic".parse::<PaletteScheme>().unwrap_err();
+        assert!(err.to_string().contains("analogous, complementary, random"));
+    }
+
     #[test]
     fn test_different_seeds_different_colors() {
         let mut rng1 = ForgeryRng::new();
@@ -309,6 +949,11 @@ mod tests {
             Locale::EsES,
             Locale::ItIT,
             Locale::JaJP,
+            Locale::KoKR,
+            Locale::RuRU,
+            Locale::NlNL,
+            Locale::PlPL,
+            Locale::EnCA,
         ] {
             let color = generate_color(&mut rng, locale);
             assert!(
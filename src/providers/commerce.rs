@@ -0,0 +1,532 @@
+//! Commerce data generation provider.
+//!
+//! Generates business document identifiers and catalog codes for order,
+//! billing and retail data.
+//!
+//! | Field | Example |
+//! |-------|---------|
+//! | Invoice number | `INV-2024-000123` |
+//! | SKU | `TSH-RED-M-0042` |
+//! | Product name | `Ergonomic Granite Chair` |
+//! | Retail price | `49.99`, `199.00` |
+
+use crate::data::en_us::{PRODUCT_ADJECTIVES, PRODUCT_MATERIALS, PRODUCT_NOUNS};
+use crate::providers::datetime;
+use crate::rng::ForgeryRng;
+use std::str::FromStr;
+
+/// Default invoice number prefix.
+pub const DEFAULT_INVOICE_PREFIX: &str = "INV";
+
+/// Default number of sequence digits in an invoice number.
+pub const DEFAULT_INVOICE_DIGITS: u32 = 6;
+
+/// Largest supported number of sequence digits.
+pub const MAX_INVOICE_DIGITS: u32 = 12;
+
+/// Date range the date component of invoice numbers is drawn from.
+const INVOICE_DATE_RANGE: (&str, &str) = ("2020-01-01", "2024-12-31");
+
+/// Date component of an invoice number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InvoiceDate {
+    /// Four-digit year, e.g. `2024`.
+    #[default]
+    Year,
+    /// Year and month, e.g. `202409`.
+    YearMonth,
+    /// Full date, e.g. `20240915`.
+    Date,
+}
+
+/// Accepted names for `InvoiceDate`, in declaration order.
+pub const INVOICE_DATES: &[&str] = &["year", "year_month", "date"];
+
+impl FromStr for InvoiceDate {
+    type Err = InvoiceNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "year" => Ok(InvoiceDate::Year),
+            "year_month" => Ok(InvoiceDate::YearMonth),
+            "date" => Ok(InvoiceDate::Date),
+            _ => Err(InvoiceNumberError::UnknownDate(s.to_string())),
+        }
+    }
+}
+
+/// Error for an invalid invoice number format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvoiceNumberError {
+    /// The date component name is not recognized.
+    UnknownDate(String),
+    /// The sequence width is zero or above `MAX_INVOICE_DIGITS`.
+    Digits(u32),
+}
+
+impl std::fmt::Display for InvoiceNumberError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownDate(name) => write!(
+                f,
+                "unknown invoice date component '{}'; expected one of: {}",
+                name,
+                INVOICE_DATES.join(", ")
+            ),
+            Self::Digits(digits) => write!(
+                f,
+                "invoice number digits must be between 1 and {}, got {}",
+                MAX_INVOICE_DIGITS, digits
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvoiceNumberError {}
+
+/// Check that an invoice sequence width is supported.
+///
+/// # Errors
+///
+/// Returns `InvoiceNumberError::Digits` if `digits` is 0 or above
+/// `MAX_INVOICE_DIGITS`.
+#[inline]
+pub fn validate_invoice_digits(digits: u32) -> Result<(), InvoiceNumberError> {
+    if digits == 0 || digits > MAX_INVOICE_DIGITS {
+        return Err(InvoiceNumberError::Digits(digits));
+    }
+    Ok(())
+}
+
+/// Draw the date component of an invoice number.
+fn invoice_date(rng: &mut ForgeryRng, date: InvoiceDate) -> String {
+    let (start, end) = INVOICE_DATE_RANGE;
+    let iso = datetime::generate_date(rng, start, end).expect("invoice date range is valid");
+    match date {
+        InvoiceDate::Year => iso[..4].to_string(),
+        InvoiceDate::YearMonth => format!("{}{}", &iso[..4], &iso[5..7]),
+        InvoiceDate::Date => iso.replace('-', ""),
+    }
+}
+
+/// Build one invoice number from validated parts.
+fn invoice_number(
+    rng: &mut ForgeryRng,
+    prefix: &str,
+    digits: u32,
+    date: Option<InvoiceDate>,
+) -> String {
+    let mut parts = Vec::with_capacity(3);
+    if !prefix.is_empty() {
+        parts.push(prefix.to_string());
+    }
+    if let Some(date) = date {
+        parts.push(invoice_date(rng, date));
+    }
+    let sequence = rng.gen_range(1, 10u64.pow(digits) - 1);
+    parts.push(format!("{:0width$}", sequence, width = digits as usize));
+    parts.join("-")
+}
+
+/// Generate a batch of invoice or order numbers.
+///
+/// Each number joins the prefix, the optional date component and a
+/// zero-padded sequence number with hyphens, e.g. `INV-2024-000123`.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of invoice numbers to generate
+/// * `prefix` - Leading segment such as "INV" or "ORD"; omitted if empty
+/// * `digits` - Width of the zero-padded sequence number
+/// * `date` - Date component drawn from 2020-2024, or `None` for no date
+///
+/// # Errors
+///
+/// Returns `InvoiceNumberError` if `digits` is 0 or above `MAX_INVOICE_DIGITS`.
+pub fn generate_invoice_numbers(
+    rng: &mut ForgeryRng,
+    n: usize,
+    prefix: &str,
+    digits: u32,
+    date: Option<InvoiceDate>,
+) -> Result<Vec<String>, InvoiceNumberError> {
+    validate_invoice_digits(digits)?;
+    let mut numbers = Vec::with_capacity(n);
+    for _ in 0..n {
+        numbers.push(invoice_number(rng, prefix, digits, date));
+    }
+    Ok(numbers)
+}
+
+/// Generate a single invoice or order number (e.g., "INV-2024-000123").
+///
+/// # Errors
+///
+/// Returns `InvoiceNumberError` if `digits` is 0 or above `MAX_INVOICE_DIGITS`.
+#[inline]
+pub fn generate_invoice_number(
+    rng: &mut ForgeryRng,
+    prefix: &str,
+    digits: u32,
+    date: Option<InvoiceDate>,
+) -> Result<String, InvoiceNumberError> {
+    validate_invoice_digits(digits)?;
+    Ok(invoice_number(rng, prefix, digits, date))
+}
+
+/// Product category codes used as the first SKU segment.
+const SKU_CATEGORIES: &[&str] = &[
+    "BAG", "BLT", "BTS", "CAP", "DRS", "GLV", "HAT", "HOD", "JKT", "JNS", "PNT", "SCF", "SCK",
+    "SHO", "SHR", "SKT", "SWT", "TSH", "VST",
+];
+
+/// Color codes used as the second SKU segment.
+const SKU_COLORS: &[&str] = &[
+    "BLK", "BLU", "BRN", "GRN", "GRY", "NVY", "ORG", "PNK", "PRP", "RED", "TAN", "WHT", "YLW",
+];
+
+/// Size codes used as the third SKU segment.
+const SKU_SIZES: &[&str] = &["XS", "S", "M", "L", "XL", "XXL"];
+
+/// Build one SKU from category, color, size and a four-digit item number.
+fn default_sku(rng: &mut ForgeryRng) -> String {
+    let category = *rng.choose(SKU_CATEGORIES);
+    let color = *rng.choose(SKU_COLORS);
+    let size = *rng.choose(SKU_SIZES);
+    let item: u16 = rng.gen_range(1, 9999);
+    format!("{}-{}-{}-{:04}", category, color, size, item)
+}
+
+/// Expand a SKU pattern where `#` is a digit and `?` is an uppercase letter.
+fn sku_from_pattern(rng: &mut ForgeryRng, pattern: &str) -> String {
+    pattern
+        .chars()
+        .map(|c| match c {
+            '#' => char::from(b'0' + rng.gen_range(0u8, 9)),
+            '?' => char::from(b'A' + rng.gen_range(0u8, 25)),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Generate a batch of product SKUs.
+///
+/// Without a pattern each SKU joins a category, color and size code with a
+/// four-digit item number, e.g. `TSH-RED-M-0042`.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of SKUs to generate
+/// * `pattern` - Pattern where `#` is a digit and `?` an uppercase letter;
+///   other characters are copied as-is
+pub fn generate_skus(rng: &mut ForgeryRng, n: usize, pattern: Option<&str>) -> Vec<String> {
+    let mut skus = Vec::with_capacity(n);
+    for _ in 0..n {
+        skus.push(generate_sku(rng, pattern));
+    }
+    skus
+}
+
+/// Generate a single product SKU (e.g., "TSH-RED-M-0042").
+#[inline]
+pub fn generate_sku(rng: &mut ForgeryRng, pattern: Option<&str>) -> String {
+    match pattern {
+        Some(pattern) => sku_from_pattern(rng, pattern),
+        None => default_sku(rng),
+    }
+}
+
+/// Generate a batch of product names.
+pub fn generate_product_names(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_product_name(rng));
+    }
+    names
+}
+
+/// Generate a single product name: adjective, material and noun (e.g., "Ergonomic Granite Chair").
+#[inline]
+pub fn generate_product_name(rng: &mut ForgeryRng) -> String {
+    let adjective = rng.choose(PRODUCT_ADJECTIVES);
+    let material = rng.choose(PRODUCT_MATERIALS);
+    let noun = rng.choose(PRODUCT_NOUNS);
+    format!("{} {} {}", adjective, material, noun)
+}
+
+/// Number of decimal places in a retail price.
+pub const RETAIL_PRICE_SCALE: u32 = 2;
+
+/// Cent endings of retail prices with their weights in percent.
+const RETAIL_PRICE_ENDINGS: &[(u64, u32)] = &[(99, 55), (95, 20), (0, 15), (49, 10)];
+
+/// Price band of a retail price.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PriceTier {
+    /// 1.49 to 19.99.
+    Budget,
+    /// 20.00 to 99.99.
+    #[default]
+    Standard,
+    /// 109.00 to 999.99, whole part ending in 9.
+    Premium,
+    /// 1009.00 to 9999.99, whole part ending in 9.
+    Luxury,
+}
+
+/// Accepted names for `PriceTier`, in declaration order.
+pub const PRICE_TIERS: &[&str] = &["budget", "standard", "premium", "luxury"];
+
+impl PriceTier {
+    /// Inclusive range of the whole-unit part of a price.
+    fn whole_range(self) -> (u64, u64) {
+        match self {
+            PriceTier::Budget => (1, 19),
+            PriceTier::Standard => (20, 99),
+            PriceTier::Premium => (100, 999),
+            PriceTier::Luxury => (1000, 9999),
+        }
+    }
+}
+
+/// Error for an unknown price tier name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceTierError {
+    /// The unrecognized tier name.
+    pub tier: String,
+}
+
+impl std::fmt::Display for PriceTierError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown price tier '{}'; expected one of: {}",
+            self.tier,
+            PRICE_TIERS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for PriceTierError {}
+
+impl FromStr for PriceTier {
+    type Err = PriceTierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "budget" => Ok(PriceTier::Budget),
+            "standard" => Ok(PriceTier::Standard),
+            "premium" => Ok(PriceTier::Premium),
+            "luxury" => Ok(PriceTier::Luxury),
+            _ => Err(PriceTierError {
+                tier: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Draw a cent ending from `RETAIL_PRICE_ENDINGS`.
+fn retail_price_ending(rng: &mut ForgeryRng) -> u64 {
+    let total: u32 = RETAIL_PRICE_ENDINGS.iter().map(|&(_, w)| w).sum();
+    let mut roll = rng.gen_range(1, total);
+    for &(cents, weight) in RETAIL_PRICE_ENDINGS {
+        if roll <= weight {
+            return cents;
+        }
+        roll -= weight;
+    }
+    RETAIL_PRICE_ENDINGS[0].0
+}
+
+/// Generate a single retail price in cents.
+///
+/// Prices end in .99, .95, .00 or .49, and from 100 upwards the whole part
+/// ends in 9 (`149.99`, `1999.00`), as storefront prices usually do.
+pub fn generate_retail_price_cents(rng: &mut ForgeryRng, tier: PriceTier) -> i64 {
+    let (min, max) = tier.whole_range();
+    let mut whole = rng.gen_range(min, max);
+    if whole >= 100 {
+        whole = whole - whole % 10 + 9;
+    }
+    let mut cents = retail_price_ending(rng);
+    // A budget price of 1.00 reads as a placeholder rather than a price
+    if whole == 1 && cents == 0 {
+        cents = 49;
+    }
+    (whole * 100 + cents) as i64
+}
+
+/// Generate a batch of retail prices with psychological endings.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of prices to generate
+/// * `tier` - Price band to draw from
+pub fn generate_retail_prices(rng: &mut ForgeryRng, n: usize, tier: PriceTier) -> Vec<f64> {
+    let mut prices = Vec::with_capacity(n);
+    for _ in 0..n {
+        prices.push(generate_retail_price(rng, tier));
+    }
+    prices
+}
+
+/// Generate a single retail price (e.g., 49.99).
+#[inline]
+pub fn generate_retail_price(rng: &mut ForgeryRng, tier: PriceTier) -> f64 {
+    generate_retail_price_cents(rng, tier) as f64 / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> ForgeryRng {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        rng
+    }
+
+    #[test]
+    fn test_invoice_number_default_format() {
+        let numbers = generate_invoice_numbers(
+            &mut seeded(),
+            200,
+            DEFAULT_INVOICE_PREFIX,
+            DEFAULT_INVOICE_DIGITS,
+            Some(InvoiceDate::Year),
+        )
+        .unwrap();
+        for number in &numbers {
+            let parts: Vec<&str> = number.split('-').collect();
+            assert_eq!(parts.len(), 3, "{}", number);
+            assert_eq!(parts[0], "INV");
+            let year: u32 = parts[1].parse().unwrap();
+            assert!((2020..=2024).contains(&year), "{}", number);
+            assert_eq!(parts[2].len(), 6, "{}", number);
+            assert!(parts[2].bytes().all(|b| b.is_ascii_digit()));
+        }
+    }
+
+    #[test]
+    fn test_invoice_number_options() {
+        let mut rng = seeded();
+        let number = generate_invoice_number(&mut rng, "ORD", 4, None).unwrap();
+        assert!(
+            number.starts_with("ORD-") && number.len() == 8,
+            "{}",
+            number
+        );
+
+        let number = generate_invoice_number(&mut rng, "", 8, Some(InvoiceDate::Date)).unwrap();
+        let (date, sequence) = number.split_once('-').unwrap();
+        assert_eq!(date.len(), 8, "{}", number);
+        assert_eq!(sequence.len(), 8, "{}", number);
+
+        let number =
+            generate_invoice_number(&mut rng, "PO", 3, Some(InvoiceDate::YearMonth)).unwrap();
+        assert_eq!(number.split('-').nth(1).unwrap().len(), 6, "{}", number);
+    }
+
+    #[test]
+    fn test_invoice_number_errors() {
+        let mut rng = seeded();
+        assert_eq!(
+            generate_invoice_number(&mut rng, "INV", 0, None),
+            Err(InvoiceNumberError::Digits(0))
+        );
+        assert!(generate_invoice_numbers(&mut rng, 1, "INV", 13, None).is_err());
+        let err = "quarter".parse::<InvoiceDate>().unwrap_err();
+        assert!(err.to_string().contains("'quarter'"));
+    }
+
+    #[test]
+    fn test_default_skus() {
+        for sku in generate_skus(&mut seeded(), 200, None) {
+            let parts: Vec<&str> = sku.split('-').collect();
+            assert_eq!(parts.len(), 4, "{}", sku);
+            assert!(SKU_CATEGORIES.contains(&parts[0]), "{}", sku);
+            assert!(SKU_COLORS.contains(&parts[1]), "{}", sku);
+            assert!(SKU_SIZES.contains(&parts[2]), "{}", sku);
+            assert_eq!(parts[3].len(), 4, "{}", sku);
+            assert_ne!(parts[3], "0000");
+        }
+    }
+
+    #[test]
+    fn test_pattern_skus() {
+        for sku in generate_skus(&mut seeded(), 100, Some("AB-???-####")) {
+            assert_eq!(sku.len(), 11, "{}", sku);
+            assert!(sku.starts_with("AB-"), "{}", sku);
+            assert!(sku[3..6].bytes().all(|b| b.is_ascii_uppercase()), "{}", sku);
+            assert!(sku[7..].bytes().all(|b| b.is_ascii_digit()), "{}", sku);
+        }
+        assert_eq!(generate_sku(&mut seeded(), Some("FIXED")), "FIXED");
+    }
+
+    #[test]
+    fn test_product_names() {
+        let names = generate_product_names(&mut seeded(), 200);
+        for name in &names {
+            let adjective = PRODUCT_ADJECTIVES
+                .iter()
+                .find(|a| name.starts_with(&format!("{} ", a)))
+                .unwrap_or_else(|| panic!("no adjective in {}", name));
+            let rest = &name[adjective.len() + 1..];
+            let (material, noun) = rest.split_once(' ').unwrap();
+            assert!(PRODUCT_MATERIALS.contains(&material), "{}", name);
+            assert!(PRODUCT_NOUNS.contains(&noun), "{}", name);
+        }
+        let distinct: std::collections::HashSet<_> = names.iter().collect();
+        assert!(distinct.len() > 150);
+    }
+
+    #[test]
+    fn test_retail_prices() {
+        let mut rng = seeded();
+        for (tier, min, max) in [
+            (PriceTier::Budget, 149, 1999),
+            (PriceTier::Standard, 2000, 9999),
+            (PriceTier::Premium, 10900, 99999),
+            (PriceTier::Luxury, 100900, 999999),
+        ] {
+            for _ in 0..500 {
+                let cents = generate_retail_price_cents(&mut rng, tier);
+                assert!((min..=max).contains(&cents), "{:?}: {}", tier, cents);
+                assert!([99, 95, 0, 49].contains(&(cents % 100)), "{}", cents);
+                if cents >= 10000 {
+                    assert_eq!(cents / 100 % 10, 9, "{}", cents);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_retail_price_endings_skewed() {
+        let prices = generate_retail_prices(&mut seeded(), 2000, PriceTier::Standard);
+        let nines = prices
+            .iter()
+            .filter(|p| ((*p * 100.0).round() as i64) % 100 == 99)
+            .count();
+        assert!((900..1300).contains(&nines), "nines: {}", nines);
+    }
+
+    #[test]
+    fn test_price_tier_from_str() {
+        for name in PRICE_TIERS {
+            assert!(name.parse::<PriceTier>().is_ok());
+        }
+        let err = "bargain".parse::<PriceTier>().unwrap_err();
+        assert!(err.to_string().contains("'bargain'"));
+    }
+
+    #[test]
+    fn test_invoice_numbers_deterministic() {
+        let generate = || {
+            generate_invoice_numbers(&mut seeded(), 50, "INV", 6, Some(InvoiceDate::Year)).unwrap()
+        };
+        assert_eq!(generate(), generate());
+    }
+}
@@ -1,9 +1,41 @@
 //! Company-related data generation provider.
 //!
-//! Generates company names, job titles, and catch phrases.
+//! Generates company names, job titles, and catch phrases, as well as
+//! complete company profiles whose fields describe the same business.
+//!
+//! Departments, industries, NAICS/SIC classification codes and leveled job
+//! titles are English (US) data and do not depend on the locale:
+//!
+//! | Field | Example |
+//! |-------|---------|
+//! | Department | `Human Resources` |
+//! | Industry | `Biotechnology` |
+//! | NAICS code | `541512` (6 digits, valid 2022 sector) |
+//! | SIC code | `7372` (4 digits, valid major group) |
+//! | Job with level `senior` | `Senior Data Scientist` |
+//! | Job level / area / role | `senior` / `Data` / `Scientist` |
+//!
+//! Catch phrases default to the locale's adjective + noun; the other styles
+//! draw on English pools:
+//!
+//! | Style | Example |
+//! |-------|---------|
+//! | `basic` | `Innovative solution` |
+//! | `buzzword` | `Leverage end-to-end synergies` |
+//! | `startup` | `AI-powered marketplace for independent restaurants` |
+//! | `slogan` | `Think bigger.` |
 
+use std::str::FromStr;
+
+use crate::data::en_us::{
+    BUZZWORD_ADJECTIVES, BUZZWORD_NOUNS, BUZZWORD_VERBS, CHIEF_TITLES, DEPARTMENTS, INDUSTRIES,
+    JOB_AREAS, NAICS_SECTORS, SIC_MAJOR_GROUPS, SLOGAN_COMPARATIVES, SLOGAN_QUALITIES,
+    SLOGAN_SUBJECTS, SLOGAN_VERBS, STARTUP_AUDIENCES, STARTUP_PRODUCTS, STARTUP_QUALIFIERS,
+};
 use crate::data::get_locale_data;
+use crate::data::overrides::LocaleOverrides;
 use crate::locale::Locale;
+use crate::providers::{address, internet, tax_id};
 use crate::rng::ForgeryRng;
 
 /// Generate a batch of random company names.
@@ -35,6 +67,28 @@ pub fn generate_company(rng: &mut ForgeryRng, locale: Locale) -> String {
     format!("{} {}", prefix, suffix)
 }
 
+/// Generate a company name in Latin script, for deriving web domains.
+///
+/// Uses the locale's romanized prefixes where it has them; suffixes in
+/// other scripts are left for `internet::company_domain` to drop.
+pub(crate) fn generate_romanized_company(rng: &mut ForgeryRng, locale: Locale) -> String {
+    let data = get_locale_data(locale);
+    let prefixes = data.romanized_company_prefixes().unwrap_or(&[]);
+    let suffixes = data.company_suffixes().unwrap_or(&[]);
+
+    let prefix = if prefixes.is_empty() {
+        "Acme"
+    } else {
+        rng.choose(prefixes)
+    };
+    let suffix = if suffixes.is_empty() {
+        "Inc"
+    } else {
+        rng.choose(suffixes)
+    };
+    format!("{} {}", prefix, suffix)
+}
+
 /// Generate a batch of random job titles.
 pub fn generate_jobs(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
     let mut jobs = Vec::with_capacity(n);
@@ -56,6 +110,212 @@ pub fn generate_job(rng: &mut ForgeryRng, locale: Locale) -> String {
     }
 }
 
+/// Job seniority level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobLevel {
+    /// "Software Intern"
+    Intern,
+    /// "Junior Software Engineer"
+    Junior,
+    /// "Software Engineer"
+    Mid,
+    /// "Senior Software Engineer"
+    Senior,
+    /// "Lead Software Engineer"
+    Lead,
+    /// "Principal Software Engineer"
+    Principal,
+    /// "Engineering Manager"
+    Manager,
+    /// "Director of Engineering"
+    Director,
+    /// "VP of Engineering"
+    Vp,
+    /// "Chief Technology Officer"
+    Executive,
+}
+
+/// Accepted names for `JobLevel`, in declaration order.
+pub const JOB_LEVELS: &[&str] = &[
+    "intern",
+    "junior",
+    "mid",
+    "senior",
+    "lead",
+    "principal",
+    "manager",
+    "director",
+    "vp",
+    "executive",
+];
+
+/// Share of each level in a typical organization, out of 100.
+const JOB_LEVEL_WEIGHTS: &[(JobLevel, u8)] = &[
+    (JobLevel::Intern, 4),
+    (JobLevel::Junior, 15),
+    (JobLevel::Mid, 25),
+    (JobLevel::Senior, 20),
+    (JobLevel::Lead, 10),
+    (JobLevel::Principal, 4),
+    (JobLevel::Manager, 12),
+    (JobLevel::Director, 6),
+    (JobLevel::Vp, 3),
+    (JobLevel::Executive, 1),
+];
+
+impl JobLevel {
+    /// The accepted name of the level (e.g., "senior").
+    pub fn name(self) -> &'static str {
+        match self {
+            JobLevel::Intern => "intern",
+            JobLevel::Junior => "junior",
+            JobLevel::Mid => "mid",
+            JobLevel::Senior => "senior",
+            JobLevel::Lead => "lead",
+            JobLevel::Principal => "principal",
+            JobLevel::Manager => "manager",
+            JobLevel::Director => "director",
+            JobLevel::Vp => "vp",
+            JobLevel::Executive => "executive",
+        }
+    }
+}
+
+/// Error for an unknown job level name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobLevelError {
+    /// The unrecognized level name.
+    pub level: String,
+}
+
+impl std::fmt::Display for JobLevelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown job level '{}'; expected one of: {}",
+            self.level,
+            JOB_LEVELS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for JobLevelError {}
+
+impl FromStr for JobLevel {
+    type Err = JobLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "intern" => Ok(JobLevel::Intern),
+            "junior" => Ok(JobLevel::Junior),
+            "mid" => Ok(JobLevel::Mid),
+            "senior" => Ok(JobLevel::Senior),
+            "lead" => Ok(JobLevel::Lead),
+            "principal" => Ok(JobLevel::Principal),
+            "manager" => Ok(JobLevel::Manager),
+            "director" => Ok(JobLevel::Director),
+            "vp" => Ok(JobLevel::Vp),
+            "executive" => Ok(JobLevel::Executive),
+            _ => Err(JobLevelError {
+                level: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Generate a batch of job titles at the given level.
+pub fn generate_leveled_jobs(rng: &mut ForgeryRng, n: usize, level: JobLevel) -> Vec<String> {
+    let mut jobs = Vec::with_capacity(n);
+    for _ in 0..n {
+        jobs.push(generate_leveled_job(rng, level));
+    }
+    jobs
+}
+
+/// Generate a single job title at the given level.
+///
+/// Titles are composed from an area and one of its roles, or the area's
+/// department for management levels.
+#[inline]
+pub fn generate_leveled_job(rng: &mut ForgeryRng, level: JobLevel) -> String {
+    if level == JobLevel::Executive {
+        return rng.choose(CHIEF_TITLES).to_string();
+    }
+    let (area, department, roles) = *rng.choose(JOB_AREAS);
+    match level {
+        JobLevel::Intern => format!("{} Intern", area),
+        JobLevel::Manager => format!("{} Manager", department),
+        JobLevel::Director => format!("Director of {}", department),
+        JobLevel::Vp => format!("VP of {}", department),
+        _ => {
+            let role = *rng.choose(roles);
+            match level {
+                JobLevel::Junior => format!("Junior {} {}", area, role),
+                JobLevel::Senior => format!("Senior {} {}", area, role),
+                JobLevel::Lead => format!("Lead {} {}", area, role),
+                JobLevel::Principal => format!("Principal {} {}", area, role),
+                _ => format!("{} {}", area, role),
+            }
+        }
+    }
+}
+
+/// Generate a batch of job levels.
+pub fn generate_job_levels(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut levels = Vec::with_capacity(n);
+    for _ in 0..n {
+        levels.push(generate_job_level(rng));
+    }
+    levels
+}
+
+/// Generate a single job level name (e.g., "senior").
+///
+/// Levels are weighted like a typical organization chart: mostly mid and
+/// senior individual contributors, with few executives.
+#[inline]
+pub fn generate_job_level(rng: &mut ForgeryRng) -> String {
+    let mut roll = rng.gen_range(1u8, 100);
+    for &(level, weight) in JOB_LEVEL_WEIGHTS {
+        if roll <= weight {
+            return level.name().to_string();
+        }
+        roll -= weight;
+    }
+    JobLevel::Mid.name().to_string()
+}
+
+/// Generate a batch of job areas.
+pub fn generate_job_areas(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut areas = Vec::with_capacity(n);
+    for _ in 0..n {
+        areas.push(generate_job_area(rng));
+    }
+    areas
+}
+
+/// Generate a single job area (e.g., "Data").
+#[inline]
+pub fn generate_job_area(rng: &mut ForgeryRng) -> String {
+    rng.choose(JOB_AREAS).0.to_string()
+}
+
+/// Generate a batch of job roles.
+pub fn generate_job_roles(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut roles = Vec::with_capacity(n);
+    for _ in 0..n {
+        roles.push(generate_job_role(rng));
+    }
+    roles
+}
+
+/// Generate a single job role (e.g., "Scientist").
+#[inline]
+pub fn generate_job_role(rng: &mut ForgeryRng) -> String {
+    let roles = rng.choose(JOB_AREAS).2;
+    rng.choose(roles).to_string()
+}
+
 /// Generate a batch of random catch phrases.
 pub fn generate_catch_phrases(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
     let mut phrases = Vec::with_capacity(n);
@@ -85,11 +345,483 @@ pub fn generate_catch_phrase(rng: &mut ForgeryRng, locale: Locale) -> String {
     format!("{} {}", adj, noun)
 }
 
+/// Catch phrase pattern.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CatchPhraseStyle {
+    /// The locale's adjective + noun ("Innovative solution").
+    #[default]
+    Basic,
+    /// Verb, adjective and plural noun ("Leverage end-to-end synergies").
+    Buzzword,
+    /// Qualifier, product and audience ("AI-powered marketplace for independent restaurants").
+    Startup,
+    /// Short tagline ("Think bigger.", "Payroll made simple.").
+    Slogan,
+}
+
+/// Accepted names for `CatchPhraseStyle`, in declaration order.
+pub const CATCH_PHRASE_STYLES: &[&str] = &["basic", "buzzword", "startup", "slogan"];
+
+/// Error for an unknown catch phrase style name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatchPhraseStyleError {
+    /// The unrecognized style name.
+    pub style: String,
+}
+
+impl std::fmt::Display for CatchPhraseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown catch phrase style '{}'; expected one of: {}",
+            self.style,
+            CATCH_PHRASE_STYLES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for CatchPhraseStyleError {}
+
+impl FromStr for CatchPhraseStyle {
+    type Err = CatchPhraseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "basic" => Ok(CatchPhraseStyle::Basic),
+            "buzzword" => Ok(CatchPhraseStyle::Buzzword),
+            "startup" => Ok(CatchPhraseStyle::Startup),
+            "slogan" => Ok(CatchPhraseStyle::Slogan),
+            _ => Err(CatchPhraseStyleError {
+                style: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Generate a batch of catch phrases in the given style.
+///
+/// Only `CatchPhraseStyle::Basic` depends on the locale; the other styles
+/// are English.
+pub fn generate_catch_phrases_with_style(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    style: CatchPhraseStyle,
+) -> Vec<String> {
+    let mut phrases = Vec::with_capacity(n);
+    for _ in 0..n {
+        phrases.push(generate_catch_phrase_with_style(rng, locale, style));
+    }
+    phrases
+}
+
+/// Generate a single catch phrase in the given style.
+#[inline]
+pub fn generate_catch_phrase_with_style(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    style: CatchPhraseStyle,
+) -> String {
+    match style {
+        CatchPhraseStyle::Basic => generate_catch_phrase(rng, locale),
+        CatchPhraseStyle::Buzzword => format!(
+            "{} {} {}",
+            rng.choose(BUZZWORD_VERBS),
+            rng.choose(BUZZWORD_ADJECTIVES),
+            rng.choose(BUZZWORD_NOUNS)
+        ),
+        CatchPhraseStyle::Startup => format!(
+            "{} {} for {}",
+            rng.choose(STARTUP_QUALIFIERS),
+            rng.choose(STARTUP_PRODUCTS),
+            rng.choose(STARTUP_AUDIENCES)
+        ),
+        CatchPhraseStyle::Slogan => match rng.gen_range(0u8, 3) {
+            0 => format!(
+                "{} {}.",
+                rng.choose(SLOGAN_VERBS),
+                rng.choose(SLOGAN_COMPARATIVES)
+            ),
+            1 => format!("{} made simple.", rng.choose(SLOGAN_SUBJECTS)),
+            2 => format!("{} by design.", rng.choose(SLOGAN_QUALITIES)),
+            _ => format!("Built for {}.", rng.choose(STARTUP_AUDIENCES)),
+        },
+    }
+}
+
+/// Generate a batch of department names.
+pub fn generate_departments(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut departments = Vec::with_capacity(n);
+    for _ in 0..n {
+        departments.push(generate_department(rng));
+    }
+    departments
+}
+
+/// Generate a single department name (e.g., "Human Resources").
+#[inline]
+pub fn generate_department(rng: &mut ForgeryRng) -> String {
+    rng.choose(DEPARTMENTS).to_string()
+}
+
+/// Generate a batch of industry names.
+pub fn generate_industries(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut industries = Vec::with_capacity(n);
+    for _ in 0..n {
+        industries.push(generate_industry(rng));
+    }
+    industries
+}
+
+/// Generate a single industry name (e.g., "Biotechnology").
+#[inline]
+pub fn generate_industry(rng: &mut ForgeryRng) -> String {
+    rng.choose(INDUSTRIES).to_string()
+}
+
+/// Generate a batch of NAICS codes.
+pub fn generate_naics_codes(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut codes = Vec::with_capacity(n);
+    for _ in 0..n {
+        codes.push(generate_naics_code(rng));
+    }
+    codes
+}
+
+/// Generate a single six-digit NAICS code (e.g., "541512").
+///
+/// The first two digits are a real NAICS sector; the subsector digit is
+/// never zero.
+#[inline]
+pub fn generate_naics_code(rng: &mut ForgeryRng) -> String {
+    let sector = *rng.choose(NAICS_SECTORS);
+    format!(
+        "{}{}{:03}",
+        sector,
+        rng.gen_range(1u8, 9),
+        rng.gen_range(0u16, 999)
+    )
+}
+
+/// Generate a batch of SIC codes.
+pub fn generate_sic_codes(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut codes = Vec::with_capacity(n);
+    for _ in 0..n {
+        codes.push(generate_sic_code(rng));
+    }
+    codes
+}
+
+/// Generate a single four-digit SIC code (e.g., "7372").
+///
+/// The first two digits are a SIC major group in use; the industry group
+/// digit is never zero.
+#[inline]
+pub fn generate_sic_code(rng: &mut ForgeryRng) -> String {
+    let group = *rng.choose(SIC_MAJOR_GROUPS);
+    format!(
+        "{}{}{}",
+        group,
+        rng.gen_range(1u8, 9),
+        rng.gen_range(0u8, 9)
+    )
+}
+
+/// A generated company with internally consistent fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompanyProfile {
+    /// Registered company name, including the legal form
+    pub name: String,
+    /// Registered legal form (e.g., "LLC", "GmbH")
+    pub legal_form: String,
+    /// Industry the company operates in
+    pub industry: String,
+    /// Company tax ID for the locale (EIN, VAT number, ...)
+    pub tax_id: String,
+    /// Web domain derived from the company name
+    pub domain: String,
+    /// Headquarters address
+    pub address: String,
+    /// Marketing catch phrase
+    pub catch_phrase: String,
+}
+
+/// Company profile field names, in the order they appear in records.
+pub const COMPANY_PROFILE_FIELDS: &[&str] = &[
+    "name",
+    "legal_form",
+    "industry",
+    "tax_id",
+    "domain",
+    "address",
+    "catch_phrase",
+];
+
+impl CompanyProfile {
+    /// Field values in `COMPANY_PROFILE_FIELDS` order.
+    pub fn into_values(self) -> [String; 7] {
+        [
+            self.name,
+            self.legal_form,
+            self.industry,
+            self.tax_id,
+            self.domain,
+            self.address,
+            self.catch_phrase,
+        ]
+    }
+}
+
+/// Generate a batch of company profiles.
+pub fn generate_company_profiles(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<CompanyProfile> {
+    let mut profiles = Vec::with_capacity(n);
+    for _ in 0..n {
+        profiles.push(generate_company_profile(rng, locale, overrides));
+    }
+    profiles
+}
+
+/// Generate a single company profile.
+///
+/// The name combines a locale prefix, an optional descriptor and a legal
+/// form (ru_RU puts the legal form first: `ООО «Урал Инвест»`). The domain
+/// is built from the romanized name and the locale's country TLD or `.com`.
+pub fn generate_company_profile(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> CompanyProfile {
+    let data = get_locale_data(locale);
+    let prefixes = data.company_prefixes().unwrap_or(&[]);
+    let prefix_index = rng.gen_range(0, prefixes.len().saturating_sub(1));
+    let prefix = prefixes.get(prefix_index).copied().unwrap_or("Acme");
+    let romanized = data
+        .romanized_company_prefixes()
+        .and_then(|names| names.get(prefix_index))
+        .copied()
+        .unwrap_or(prefix);
+
+    let legal_forms = data.company_legal_forms().unwrap_or(&[]);
+    let legal_form = if legal_forms.is_empty() {
+        "Inc"
+    } else {
+        *rng.choose(legal_forms)
+    };
+
+    // Descriptors that are themselves legal forms would repeat the suffix
+    let suffixes = data.company_suffixes().unwrap_or(&[]);
+    let descriptor = if suffixes.is_empty() || rng.gen_range(0u8, 1) == 0 {
+        None
+    } else {
+        Some(*rng.choose(suffixes)).filter(|s| !legal_forms.contains(s))
+    };
+
+    let trading_name = match descriptor {
+        Some(descriptor) => format!("{} {}", prefix, descriptor),
+        None => prefix.to_string(),
+    };
+    let name = match locale {
+        Locale::RuRU => format!("{} «{}»", legal_form, trading_name),
+        _ => format!("{} {}", trading_name, legal_form),
+    };
+
+    let tld = if rng.gen_range(0u8, 1) == 0 {
+        "com"
+    } else {
+        country_tld(locale)
+    };
+    let domain = format!("{}.{}", internet::ascii_slug(romanized, "company"), tld);
+
+    let industry = generate_industry(rng);
+    let tax_id = tax_id::generate_company_tax_id(rng, locale);
+    let address = address::generate_address_with_overrides(rng, locale, overrides);
+    let catch_phrase = generate_catch_phrase(rng, locale);
+
+    CompanyProfile {
+        name,
+        legal_form: legal_form.to_string(),
+        industry,
+        tax_id,
+        domain,
+        address,
+        catch_phrase,
+    }
+}
+
+/// Country-code TLD that companies in the locale register under.
+fn country_tld(locale: Locale) -> &'static str {
+    match locale {
+        Locale::EnUS => "us",
+        Locale::EnGB => "co.uk",
+        Locale::EnCA => "ca",
+        Locale::DeDE => "de",
+        Locale::FrFR => "fr",
+        Locale::EsES => "es",
+        Locale::ItIT => "it",
+        Locale::JaJP => "co.jp",
+        Locale::KoKR => "co.kr",
+        Locale::NlNL => "nl",
+        Locale::PlPL => "pl",
+        Locale::RuRU => "ru",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::data::en_us::JOB_TITLES;
 
+    fn company_profiles(locale: Locale, n: usize) -> Vec<CompanyProfile> {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        generate_company_profiles(&mut rng, locale, &LocaleOverrides::EMPTY, n)
+    }
+
+    #[test]
+    fn test_company_profile_fields_agree() {
+        let data = get_locale_data(Locale::EnUS);
+        for p in company_profiles(Locale::EnUS, 200) {
+            assert!(
+                p.name.ends_with(&format!(" {}", p.legal_form)),
+                "{}",
+                p.name
+            );
+            assert!(data
+                .company_legal_forms()
+                .unwrap()
+                .contains(&p.legal_form.as_str()));
+            let label = p.domain.split('.').next().unwrap();
+            let prefix = p.name.split_whitespace().next().unwrap();
+            assert_eq!(label, prefix.to_lowercase(), "{}", p.domain);
+            assert!(p.domain.ends_with(".com") || p.domain.ends_with(".us"));
+            assert!(INDUSTRIES.contains(&p.industry.as_str()));
+            assert_eq!(p.tax_id.len(), 10, "{}", p.tax_id);
+            assert!(!p.address.is_empty() && !p.catch_phrase.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_company_profile_romanized_domain() {
+        for locale in [Locale::JaJP, Locale::KoKR, Locale::RuRU, Locale::DeDE] {
+            for p in company_profiles(locale, 50) {
+                assert!(p.domain.is_ascii(), "{}", p.domain);
+                assert!(!p.domain.starts_with('.'), "{}", p.domain);
+            }
+        }
+        for p in company_profiles(Locale::RuRU, 20) {
+            assert!(
+                p.name.starts_with(&format!("{} «", p.legal_form)),
+                "{}",
+                p.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_company_profile_deterministic() {
+        assert_eq!(
+            company_profiles(Locale::DeDE, 20),
+            company_profiles(Locale::DeDE, 20)
+        );
+        let fields = company_profiles(Locale::EnGB, 1).remove(0).into_values();
+        assert_eq!(fields.len(), COMPANY_PROFILE_FIELDS.len());
+    }
+
+    #[test]
+    fn test_leveled_jobs() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        for job in generate_leveled_jobs(&mut rng, 50, JobLevel::Senior) {
+            assert!(job.starts_with("Senior "), "{}", job);
+        }
+        for job in generate_leveled_jobs(&mut rng, 50, JobLevel::Director) {
+            assert!(job.starts_with("Director of "), "{}", job);
+        }
+        for job in generate_leveled_jobs(&mut rng, 50, JobLevel::Executive) {
+            assert!(CHIEF_TITLES.contains(&job.as_str()), "{}", job);
+        }
+        for job in generate_leveled_jobs(&mut rng, 50, JobLevel::Mid) {
+            let area = JOB_AREAS.iter().find(|a| job.starts_with(a.0)).unwrap();
+            assert!(area.2.iter().any(|r| job.ends_with(r)), "{}", job);
+        }
+    }
+
+    #[test]
+    fn test_job_level_parse() {
+        for name in JOB_LEVELS {
+            let level: JobLevel = name.parse().unwrap();
+            assert_eq!(level.name(), *name);
+        }
+        assert_eq!("SENIOR".parse::<JobLevel>().unwrap(), JobLevel::Senior);
+        let err = "ceo".parse::<JobLevel>().unwrap_err();
+        assert!(err.to_string().contains("'ceo'"));
+    }
+
+    #[test]
+    fn test_job_level_weights() {
+        assert_eq!(
+            JOB_LEVEL_WEIGHTS
+                .iter()
+                .map(|(_, w)| u32::from(*w))
+                .sum::<u32>(),
+            100
+        );
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let levels = generate_job_levels(&mut rng, 2000);
+        let count = |name: &str| levels.iter().filter(|l| *l == name).count();
+        assert!(levels.iter().all(|l| JOB_LEVELS.contains(&l.as_str())));
+        assert!(count("mid") > count("director"));
+        assert!(count("senior") > count("executive"));
+    }
+
+    #[test]
+    fn test_job_area_and_role() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        for area in generate_job_areas(&mut rng, 50) {
+            assert!(JOB_AREAS.iter().any(|a| a.0 == area));
+        }
+        for role in generate_job_roles(&mut rng, 50) {
+            assert!(JOB_AREAS.iter().any(|a| a.2.contains(&role.as_str())));
+        }
+    }
+
+    #[test]
+    fn test_department_and_industry() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        for department in generate_departments(&mut rng, 100) {
+            assert!(DEPARTMENTS.contains(&department.as_str()));
+        }
+        for industry in generate_industries(&mut rng, 100) {
+            assert!(INDUSTRIES.contains(&industry.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_naics_and_sic_codes() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        for code in generate_naics_codes(&mut rng, 500) {
+            assert_eq!(code.len(), 6, "{}", code);
+            assert!(code.chars().all(|c| c.is_ascii_digit()), "{}", code);
+            assert!(NAICS_SECTORS.contains(&&code[..2]), "{}", code);
+            assert_ne!(&code[2..3], "0", "{}", code);
+        }
+        for code in generate_sic_codes(&mut rng, 500) {
+            assert_eq!(code.len(), 4, "{}", code);
+            assert!(code.chars().all(|c| c.is_ascii_digit()), "{}", code);
+            assert!(SIC_MAJOR_GROUPS.contains(&&code[..2]), "{}", code);
+            assert_ne!(&code[2..3], "0", "{}", code);
+        }
+    }
+
     #[test]
     fn test_generate_companies_count() {
         let mut rng = ForgeryRng::new();
@@ -206,6 +938,64 @@ mod tests {
         assert_eq!(p1, p2);
     }
 
+    #[test]
+    fn test_catch_phrase_styles() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for phrase in generate_catch_phrases_with_style(
+            &mut rng,
+            Locale::EnUS,
+            50,
+            CatchPhraseStyle::Buzzword,
+        ) {
+            let verb = phrase.split(' ').next().unwrap();
+            assert!(BUZZWORD_VERBS.contains(&verb), "{}", phrase);
+            assert!(
+                BUZZWORD_NOUNS.iter().any(|noun| phrase.ends_with(noun)),
+                "{}",
+                phrase
+            );
+        }
+        for phrase in
+            generate_catch_phrases_with_style(&mut rng, Locale::DeDE, 50, CatchPhraseStyle::Startup)
+        {
+            assert!(phrase.contains(" for "), "{}", phrase);
+        }
+        let slogans = generate_catch_phrases_with_style(
+            &mut rng,
+            Locale::EnUS,
+            200,
+            CatchPhraseStyle::Slogan,
+        );
+        assert!(slogans.iter().all(|s| s.ends_with('.')));
+        assert!(slogans.iter().any(|s| s.ends_with(" made simple.")));
+        assert!(slogans.iter().any(|s| s.starts_with("Built for ")));
+    }
+
+    #[test]
+    fn test_basic_catch_phrase_style_matches_default() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(9);
+        rng2.seed(9);
+
+        assert_eq!(
+            generate_catch_phrases(&mut rng1, Locale::FrFR, 20),
+            generate_catch_phrases_with_style(
+                &mut rng2,
+                Locale::FrFR,
+                20,
+                CatchPhraseStyle::default()
+            )
+        );
+        for name in CATCH_PHRASE_STYLES {
+            assert!(name.parse::<CatchPhraseStyle>().is_ok());
+        }
+        let err = "haiku".parse::<CatchPhraseStyle>().unwrap_err();
+        assert!(err.to_string().contains("'haiku'"));
+    }
+
     #[test]
     fn test_empty_batches() {
         let mut rng = ForgeryRng::new();
@@ -242,6 +1032,11 @@ mod tests {
             Locale::EsES,
             Locale::ItIT,
             Locale::JaJP,
+            Locale::KoKR,
+            Locale::RuRU,
+            Locale::NlNL,
+            Locale::PlPL,
+            Locale::EnCA,
         ] {
             let company = generate_company(&mut rng, locale);
             assert!(
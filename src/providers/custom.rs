@@ -183,18 +183,38 @@ pub const RESERVED_PROVIDER_NAMES: &[&str] = &[
     "email",
     "safe_email",
     "free_email",
+    "company_email",
+    "username",
     "url",
     "domain_name",
     // Identifiers
     "uuid",
+    "uuid7",
     "md5",
     "sha256",
+    "sha1",
+    "sha512",
+    // Binary
+    "bytes",
+    "base64",
+    // Authentication
+    "password_hash",
+    "jwt",
+    "api_key",
+    "session_id",
+    "oauth_access_token",
+    "refresh_token",
+    "totp_secret",
+    "totp_uri",
     // Numbers
     "int",
     "float",
+    "bool",
     // Phone
     "phone",
     "phone_number",
+    // National ID
+    "national_id",
     // Address
     "address",
     "street_address",
@@ -202,25 +222,128 @@ pub const RESERVED_PROVIDER_NAMES: &[&str] = &[
     "state",
     "country",
     "zip_code",
+    "country_code",
+    "street_name",
+    "street_suffix",
+    "building_number",
+    "state_abbr",
+    "secondary_unit",
+    "po_box",
+    "address_parts",
+    "county",
     // Company
     "company",
     "job",
+    "job_level",
+    "job_area",
+    "job_role",
     "catch_phrase",
+    "ein",
+    "company_tax_id",
+    "company_profile",
+    "industry",
+    "naics_code",
+    "sic_code",
     // Network
     "ipv4",
     "ipv6",
     "mac_address",
+    "ipv4_private",
+    "ipv4_public",
+    "cidr",
+    "port",
+    "http_method",
+    "http_status_code",
     // Colors
     "color",
     "hex_color",
     "rgb_color",
+    "hsl_color",
+    "cmyk_color",
+    "css_color",
+    "coordinates",
     // Finance
     "credit_card",
     "iban",
+    "vat_number",
+    "drivers_license",
+    "routing_number",
+    "price",
+    "invoice_number",
+    "policy_number",
+    "claim_number",
+    "sku",
+    "product_name",
+    "retail_price",
+    "currency_code",
+    "currency_name",
+    "currency_symbol",
+    "ticker",
+    "stock_exchange",
+    "isbn13",
+    "isbn10",
+    "imei",
+    "doi",
+    "orcid",
+    "vin",
+    "vehicle_make",
+    "vehicle_model",
+    // Demographics
+    "age",
+    "gender",
+    "pronouns",
+    "marital_status",
+    // Medical
+    "blood_type",
+    "height",
+    "weight",
+    "allergy",
+    "medication",
+    "icd10_code",
+    "npi",
+    // Travel
+    "airport_code",
+    "airport_icao_code",
+    "airport_name",
+    "airport_city",
+    "flight_number",
+    // Logistics
+    "tracking_number",
+    // Language
+    "language_code",
+    "language_name",
+    // File system
+    "file_name",
+    "file_extension",
+    "file_path",
+    "mime_type",
+    // Software
+    "semver",
+    "git_sha",
+    "commit_message",
+    "docker_image",
+    "k8s_name",
+    "arn",
+    "s3_uri",
+    "cloud_region",
+    "access_log",
+    "access_log_entry",
+    "syslog",
+    "syslog_entry",
     // DateTime
     "date",
     "datetime",
+    "time",
+    "business_date",
+    "recent",
+    "past",
+    "future",
     "date_of_birth",
+    "date_of_birth_with_age",
+    "iso_week",
+    "quarter",
+    "month",
+    "duration",
     // Text
     "sentence",
     "paragraph",
@@ -1,9 +1,18 @@
 //! Date and time generation provider.
 //!
-//! Generates dates, times, and datetime values.
+//! Generates dates, times, and datetime values. Times of day are formatted
+//! with strftime specifiers (`%H:%M:%S`, `%I:%M %p`). The `recent`, `past`
+//! and `future` windows are anchored to the current UTC time, so a fixed seed
+//! reproduces the offsets but not the absolute values across runs.
 
+use crate::data::get_locale_data;
+use crate::locale::Locale;
 use crate::rng::ForgeryRng;
-use chrono::{Datelike, NaiveDate};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Error type for date range generation.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -28,6 +37,57 @@ impl std::fmt::Display for DateRangeError {
 
 impl std::error::Error for DateRangeError {}
 
+/// Output style for generated dates and datetimes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateStyle {
+    /// ISO 8601 (`2024-12-31`, `2024-12-31T23:59:59`).
+    #[default]
+    Iso,
+    /// The locale's short numeric format (`31.12.2024` for de_DE).
+    Locale,
+    /// The locale's long format with month and weekday names
+    /// (`Dienstag, 31. Dezember 2024` for de_DE).
+    LocaleLong,
+}
+
+/// Accepted names for `DateStyle`, in declaration order.
+pub const DATE_STYLES: &[&str] = &["iso", "locale", "locale_long"];
+
+/// Error for an unknown date format name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateStyleError {
+    /// The unrecognized format name.
+    pub format: String,
+}
+
+impl std::fmt::Display for DateStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown date format '{}'; expected one of: {}",
+            self.format,
+            DATE_STYLES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for DateStyleError {}
+
+impl FromStr for DateStyle {
+    type Err = DateStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "iso" => Ok(DateStyle::Iso),
+            "locale" => Ok(DateStyle::Locale),
+            "locale_long" => Ok(DateStyle::LocaleLong),
+            _ => Err(DateStyleError {
+                format: s.to_string(),
+            }),
+        }
+    }
+}
+
 /// Default start date for date generation.
 #[allow(dead_code)]
 pub const DEFAULT_START_DATE: &str = "1970-01-01";
@@ -78,6 +138,25 @@ fn validate_date_range(start: &str, end: &str) -> Result<ValidatedDateRange, Dat
     })
 }
 
+/// Milliseconds in a day.
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Inclusive Unix millisecond bounds covering every instant from the start
+/// of `start` to the end of `end`.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if dates cannot be parsed or start > end.
+pub(crate) fn unix_millis_range(start: &str, end: &str) -> Result<(i64, i64), DateRangeError> {
+    let range = validate_date_range(start, end)?;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)
+        .expect("epoch is a valid date")
+        .num_days_from_ce();
+    let start_ms = i64::from(range.start_days - epoch) * MILLIS_PER_DAY;
+    let end_ms = i64::from(range.end_days - epoch + 1) * MILLIS_PER_DAY - 1;
+    Ok((start_ms, end_ms))
+}
+
 /// Generate a random date from a validated range.
 #[inline]
 fn random_date_from_range(
@@ -104,8 +183,7 @@ fn calculate_dob_range(min_age: u32, max_age: u32) -> Result<(String, String), D
         });
     }
 
-    let today = NaiveDate::from_ymd_opt(REFERENCE_DATE.0, REFERENCE_DATE.1, REFERENCE_DATE.2)
-        .expect("invalid reference date");
+    let today = reference_date();
     let end_date = today
         .with_year(today.year() - min_age as i32)
         .unwrap_or(today);
@@ -136,13 +214,38 @@ pub fn generate_dates(
     n: usize,
     start: &str,
     end: &str,
+) -> Result<Vec<String>, DateRangeError> {
+    generate_dates_with_style(rng, Locale::EnUS, n, start, end, DateStyle::Iso)
+}
+
+/// Generate a batch of random dates formatted in the given style.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `locale` - The locale whose date format is used for non-ISO styles
+/// * `n` - Number of dates to generate
+/// * `start` - Start date in YYYY-MM-DD format (inclusive)
+/// * `end` - End date in YYYY-MM-DD format (inclusive)
+/// * `style` - Output style
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if dates cannot be parsed or start > end.
+pub fn generate_dates_with_style(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    start: &str,
+    end: &str,
+    style: DateStyle,
 ) -> Result<Vec<String>, DateRangeError> {
     let range = validate_date_range(start, end)?;
 
     let mut dates = Vec::with_capacity(n);
     for _ in 0..n {
         let date = random_date_from_range(rng, &range, start, end)?;
-        dates.push(date.format("%Y-%m-%d").to_string());
+        dates.push(format_date(date, locale, style));
     }
     Ok(dates)
 }
@@ -157,10 +260,173 @@ pub fn generate_date(
     rng: &mut ForgeryRng,
     start: &str,
     end: &str,
+) -> Result<String, DateRangeError> {
+    generate_date_with_style(rng, Locale::EnUS, start, end, DateStyle::Iso)
+}
+
+/// Generate a single random date formatted in the given style.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if dates cannot be parsed or start > end.
+#[inline]
+pub fn generate_date_with_style(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    start: &str,
+    end: &str,
+    style: DateStyle,
 ) -> Result<String, DateRangeError> {
     let range = validate_date_range(start, end)?;
     let date = random_date_from_range(rng, &range, start, end)?;
-    Ok(date.format("%Y-%m-%d").to_string())
+    Ok(format_date(date, locale, style))
+}
+
+/// Number of weekdays before the given days-from-CE value.
+///
+/// Day 1 (0001-01-01) is a Monday, so every run of seven days from there
+/// holds five weekdays followed by a weekend.
+#[inline]
+fn weekday_rank(days: i32) -> i64 {
+    let offset = i64::from(days) - 1;
+    offset.div_euclid(7) * 5 + offset.rem_euclid(7).min(5)
+}
+
+/// Inverse of [`weekday_rank`]: the days-from-CE value of the weekday with
+/// the given rank.
+#[inline]
+fn day_from_weekday_rank(rank: i64) -> i32 {
+    let days = 1 + rank.div_euclid(5) * 7 + rank.rem_euclid(5);
+    i32::try_from(days).expect("rank derived from a valid date")
+}
+
+/// Validated business-day range, with holidays stored as sorted weekday ranks.
+struct BusinessDayRange {
+    first_rank: i64,
+    count: i64,
+    holiday_ranks: Vec<i64>,
+}
+
+/// Validate a range and holiday list for business-day sampling.
+fn validate_business_range(
+    start: &str,
+    end: &str,
+    holidays: &[String],
+) -> Result<BusinessDayRange, DateRangeError> {
+    let range = validate_date_range(start, end)?;
+    let mut holiday_ranks = Vec::with_capacity(holidays.len());
+    for holiday in holidays {
+        let date = parse_date(holiday).map_err(|e| DateRangeError {
+            start: start.to_string(),
+            end: end.to_string(),
+            reason: format!("invalid holiday '{}': {}", holiday, e),
+        })?;
+        let days = date.num_days_from_ce();
+        let is_weekday = date.weekday().number_from_monday() <= 5;
+        if is_weekday && (range.start_days..=range.end_days).contains(&days) {
+            holiday_ranks.push(weekday_rank(days));
+        }
+    }
+    holiday_ranks.sort_unstable();
+    holiday_ranks.dedup();
+
+    let first_rank = weekday_rank(range.start_days);
+    let weekdays = weekday_rank(range.end_days + 1) - first_rank;
+    let count = weekdays - holiday_ranks.len() as i64;
+    if count <= 0 {
+        return Err(DateRangeError {
+            start: start.to_string(),
+            end: end.to_string(),
+            reason: "range contains no business days".to_string(),
+        });
+    }
+    Ok(BusinessDayRange {
+        first_rank,
+        count,
+        holiday_ranks,
+    })
+}
+
+/// Check that a business-day range parses and holds at least one business day.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if a date cannot be parsed, start > end, or the
+/// range holds no business days.
+pub(crate) fn validate_business_dates(
+    start: &str,
+    end: &str,
+    holidays: &[String],
+) -> Result<(), DateRangeError> {
+    validate_business_range(start, end, holidays).map(|_| ())
+}
+
+/// Pick a business day uniformly from a validated range.
+#[inline]
+fn random_business_day(rng: &mut ForgeryRng, range: &BusinessDayRange) -> NaiveDate {
+    let mut rank = range.first_rank + rng.gen_range(0, range.count - 1);
+    for &holiday in &range.holiday_ranks {
+        if holiday <= rank {
+            rank += 1;
+        } else {
+            break;
+        }
+    }
+    NaiveDate::from_num_days_from_ce_opt(day_from_weekday_rank(rank))
+        .expect("rank within validated range")
+}
+
+/// Generate a batch of random business dates (Monday to Friday, excluding
+/// `holidays`) in YYYY-MM-DD format.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of dates to generate
+/// * `start` - Start date in YYYY-MM-DD format (inclusive)
+/// * `end` - End date in YYYY-MM-DD format (inclusive)
+/// * `holidays` - Dates in YYYY-MM-DD format to exclude
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if a date cannot be parsed, start > end, or the
+/// range holds no business days.
+pub fn generate_business_dates(
+    rng: &mut ForgeryRng,
+    n: usize,
+    start: &str,
+    end: &str,
+    holidays: &[String],
+) -> Result<Vec<String>, DateRangeError> {
+    let range = validate_business_range(start, end, holidays)?;
+    let mut dates = Vec::with_capacity(n);
+    for _ in 0..n {
+        dates.push(
+            random_business_day(rng, &range)
+                .format("%Y-%m-%d")
+                .to_string(),
+        );
+    }
+    Ok(dates)
+}
+
+/// Generate a single random business date.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if a date cannot be parsed, start > end, or the
+/// range holds no business days.
+#[inline]
+pub fn generate_business_date(
+    rng: &mut ForgeryRng,
+    start: &str,
+    end: &str,
+    holidays: &[String],
+) -> Result<String, DateRangeError> {
+    let range = validate_business_range(start, end, holidays)?;
+    Ok(random_business_day(rng, &range)
+        .format("%Y-%m-%d")
+        .to_string())
 }
 
 /// Generate a batch of random date-of-birth values.
@@ -212,6 +478,198 @@ pub fn generate_date_of_birth(
     generate_date(rng, &start_str, &end_str)
 }
 
+/// Reference date as a `NaiveDate`.
+#[inline]
+fn reference_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(REFERENCE_DATE.0, REFERENCE_DATE.1, REFERENCE_DATE.2)
+        .expect("invalid reference date")
+}
+
+/// Days-from-CE bounds of the birth dates whose age on the reference date
+/// falls within `min_age..=max_age`.
+fn birth_day_bounds(min_age: u32, max_age: u32) -> Result<(i32, i32), DateRangeError> {
+    let range_error = |reason: &str| DateRangeError {
+        start: format!("min_age={}", min_age),
+        end: format!("max_age={}", max_age),
+        reason: reason.to_string(),
+    };
+    if min_age > max_age {
+        return Err(range_error("min_age must be less than or equal to max_age"));
+    }
+
+    let today = reference_date();
+    let years_before = |years: u32| {
+        years
+            .checked_mul(12)
+            .and_then(|months| today.checked_sub_months(Months::new(months)))
+    };
+    // Born after this date the person has not yet had their (max_age + 1)th birthday
+    let earliest = max_age
+        .checked_add(1)
+        .and_then(years_before)
+        .and_then(|date| date.succ_opt());
+    let (Some(earliest), Some(latest)) = (earliest, years_before(min_age)) else {
+        return Err(range_error("age is outside the supported calendar range"));
+    };
+    Ok((earliest.num_days_from_ce(), latest.num_days_from_ce()))
+}
+
+/// Check that an age range is ordered and within the supported calendar.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if min_age > max_age or the ages reach past the
+/// supported calendar.
+pub fn validate_birth_ages(min_age: u32, max_age: u32) -> Result<(), DateRangeError> {
+    birth_day_bounds(min_age, max_age).map(|_| ())
+}
+
+/// Draw a birth date between validated bounds and compute its age.
+#[inline]
+fn random_birth_date_and_age(
+    rng: &mut ForgeryRng,
+    (earliest, latest): (i32, i32),
+) -> (NaiveDate, u32) {
+    let days = rng.gen_range(earliest, latest);
+    let dob = NaiveDate::from_num_days_from_ce_opt(days).expect("day within validated range");
+    let age = reference_date()
+        .years_since(dob)
+        .expect("birth date precedes reference date");
+    (dob, age)
+}
+
+/// Generate a date of birth together with the age it gives on the reference date.
+///
+/// Unlike `generate_date_of_birth`, the birth date is drawn so that the age
+/// always falls within `min_age..=max_age`.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if min_age > max_age or the ages reach past the
+/// supported calendar.
+pub(crate) fn generate_birth_date_and_age(
+    rng: &mut ForgeryRng,
+    min_age: u32,
+    max_age: u32,
+) -> Result<(NaiveDate, u32), DateRangeError> {
+    let bounds = birth_day_bounds(min_age, max_age)?;
+    Ok(random_birth_date_and_age(rng, bounds))
+}
+
+/// Generate a batch of `(date_of_birth, age)` pairs.
+///
+/// Dates are YYYY-MM-DD strings and each age is computed from its date on the
+/// 2024-01-01 reference date, so an age column always agrees with the birth
+/// date column.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if min_age > max_age or the ages reach past the
+/// supported calendar.
+pub fn generate_dates_of_birth_with_age(
+    rng: &mut ForgeryRng,
+    n: usize,
+    min_age: u32,
+    max_age: u32,
+) -> Result<Vec<(String, u32)>, DateRangeError> {
+    let bounds = birth_day_bounds(min_age, max_age)?;
+    let mut results = Vec::with_capacity(n);
+    for _ in 0..n {
+        let (dob, age) = random_birth_date_and_age(rng, bounds);
+        results.push((dob.format("%Y-%m-%d").to_string(), age));
+    }
+    Ok(results)
+}
+
+/// Generate a single `(date_of_birth, age)` pair.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if min_age > max_age or the ages reach past the
+/// supported calendar.
+#[inline]
+pub fn generate_date_of_birth_with_age(
+    rng: &mut ForgeryRng,
+    min_age: u32,
+    max_age: u32,
+) -> Result<(String, u32), DateRangeError> {
+    let (dob, age) = generate_birth_date_and_age(rng, min_age, max_age)?;
+    Ok((dob.format("%Y-%m-%d").to_string(), age))
+}
+
+/// Calendar period that a random date is reported as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportingPeriod {
+    /// ISO 8601 week (`2024-W37`), numbered by ISO week-based year.
+    IsoWeek,
+    /// Calendar quarter (`2024-Q3`).
+    Quarter,
+    /// Calendar month (`2024-09`).
+    Month,
+}
+
+/// Format a date as the reporting period containing it.
+fn format_period(date: NaiveDate, period: ReportingPeriod) -> String {
+    match period {
+        ReportingPeriod::IsoWeek => {
+            let week = date.iso_week();
+            format!("{:04}-W{:02}", week.year(), week.week())
+        }
+        ReportingPeriod::Quarter => format!("{:04}-Q{}", date.year(), date.month0() / 3 + 1),
+        ReportingPeriod::Month => format!("{:04}-{:02}", date.year(), date.month()),
+    }
+}
+
+/// Generate a batch of reporting periods (ISO weeks, quarters or months).
+///
+/// Each value is the period containing a uniformly drawn date in the range,
+/// so periods cut short by `start` or `end` come up proportionally less often.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of periods to generate
+/// * `start` - Start date in YYYY-MM-DD format (inclusive)
+/// * `end` - End date in YYYY-MM-DD format (inclusive)
+/// * `period` - Which period to report
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if dates cannot be parsed or start > end.
+pub fn generate_periods(
+    rng: &mut ForgeryRng,
+    n: usize,
+    start: &str,
+    end: &str,
+    period: ReportingPeriod,
+) -> Result<Vec<String>, DateRangeError> {
+    let range = validate_date_range(start, end)?;
+
+    let mut periods = Vec::with_capacity(n);
+    for _ in 0..n {
+        let date = random_date_from_range(rng, &range, start, end)?;
+        periods.push(format_period(date, period));
+    }
+    Ok(periods)
+}
+
+/// Generate a single reporting period.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if dates cannot be parsed or start > end.
+#[inline]
+pub fn generate_period(
+    rng: &mut ForgeryRng,
+    start: &str,
+    end: &str,
+    period: ReportingPeriod,
+) -> Result<String, DateRangeError> {
+    let range = validate_date_range(start, end)?;
+    let date = random_date_from_range(rng, &range, start, end)?;
+    Ok(format_period(date, period))
+}
+
 /// Generate a random time component (hour, minute, second).
 #[inline]
 fn random_time(rng: &mut ForgeryRng) -> (u32, u32, u32) {
@@ -222,15 +680,319 @@ fn random_time(rng: &mut ForgeryRng) -> (u32, u32, u32) {
     )
 }
 
-/// Format a date and time as ISO 8601 datetime string.
+/// Default strftime format for times of day.
+pub const DEFAULT_TIME_FORMAT: &str = "%H:%M:%S";
+
+/// Error for a time format that cannot render a time of day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeFormatError {
+    /// The rejected format string.
+    pub format: String,
+}
+
+impl std::fmt::Display for TimeFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid time format '{}'; use strftime time specifiers such as %H:%M:%S",
+            self.format
+        )
+    }
+}
+
+impl std::error::Error for TimeFormatError {}
+
+/// Parse a strftime format, rejecting specifiers a time of day cannot fill
+/// (such as `%Y`).
+fn parse_time_format(format: &str) -> Result<Vec<Item<'_>>, TimeFormatError> {
+    let items: Vec<Item<'_>> = StrftimeItems::new(format).collect();
+    let mut probe = String::new();
+    if items.contains(&Item::Error)
+        || write!(probe, "{}", NaiveTime::MIN.format_with_items(items.iter())).is_err()
+    {
+        return Err(TimeFormatError {
+            format: format.to_string(),
+        });
+    }
+    Ok(items)
+}
+
+/// Render a time of day with pre-validated format items.
+fn format_time((hour, minute, second): (u32, u32, u32), items: &[Item<'_>]) -> String {
+    NaiveTime::from_hms_opt(hour, minute, second)
+        .expect("random_time produces a valid time")
+        .format_with_items(items.iter())
+        .to_string()
+}
+
+/// Generate a batch of random times of day.
+///
+/// # Errors
+///
+/// Returns `TimeFormatError` if the format has invalid or date specifiers.
+pub fn generate_times(
+    rng: &mut ForgeryRng,
+    n: usize,
+    format: &str,
+) -> Result<Vec<String>, TimeFormatError> {
+    let items = parse_time_format(format)?;
+    let mut times = Vec::with_capacity(n);
+    for _ in 0..n {
+        times.push(format_time(random_time(rng), &items));
+    }
+    Ok(times)
+}
+
+/// Generate a single random time of day (e.g., "14:03:27").
+///
+/// # Errors
+///
+/// Returns `TimeFormatError` if the format has invalid or date specifiers.
+#[inline]
+pub fn generate_time(rng: &mut ForgeryRng, format: &str) -> Result<String, TimeFormatError> {
+    let items = parse_time_format(format)?;
+    Ok(format_time(random_time(rng), &items))
+}
+
+/// Generate a random time of day as microseconds since midnight, for Arrow
+/// `Time64` columns.
+#[inline]
+pub(crate) fn generate_time_micros(rng: &mut ForgeryRng) -> i64 {
+    let (hour, minute, second) = random_time(rng);
+    i64::from(hour * 3600 + minute * 60 + second) * 1_000_000
+}
+
+/// Default look-back window for `recent`, in days.
+pub const DEFAULT_RECENT_DAYS: u32 = 7;
+
+/// Default look-back window for `past`, in years.
+pub const DEFAULT_PAST_YEARS: u32 = 5;
+
+/// Default look-ahead window for `future`, in years.
+pub const DEFAULT_FUTURE_YEARS: u32 = 1;
+
+/// A datetime window anchored to the current moment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeRange {
+    /// The last `n` days, up to now.
+    Recent(u32),
+    /// The last `n` years, up to now.
+    Past(u32),
+    /// From now through the next `n` years.
+    Future(u32),
+}
+
+impl RelativeRange {
+    /// Resolve the window into inclusive start and end datetimes around `now`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DateRangeError` if the window reaches past the representable
+    /// calendar.
+    pub fn bounds(
+        self,
+        now: NaiveDateTime,
+    ) -> Result<(NaiveDateTime, NaiveDateTime), DateRangeError> {
+        let (start, end) = match self {
+            RelativeRange::Recent(days) => {
+                (now.checked_sub_days(Days::new(u64::from(days))), Some(now))
+            }
+            RelativeRange::Past(years) => (
+                years
+                    .checked_mul(12)
+                    .and_then(|m| now.checked_sub_months(Months::new(m))),
+                Some(now),
+            ),
+            RelativeRange::Future(years) => (
+                Some(now),
+                years
+                    .checked_mul(12)
+                    .and_then(|m| now.checked_add_months(Months::new(m))),
+            ),
+        };
+        match (start, end) {
+            (Some(start), Some(end)) => Ok((start, end)),
+            _ => Err(DateRangeError {
+                start: self.describe_start(),
+                end: self.describe_end(),
+                reason: "offset is out of the supported calendar range".to_string(),
+            }),
+        }
+    }
+
+    fn describe_start(self) -> String {
+        match self {
+            RelativeRange::Recent(days) => format!("now - {} days", days),
+            RelativeRange::Past(years) => format!("now - {} years", years),
+            RelativeRange::Future(_) => "now".to_string(),
+        }
+    }
+
+    fn describe_end(self) -> String {
+        match self {
+            RelativeRange::Future(years) => format!("now + {} years", years),
+            _ => "now".to_string(),
+        }
+    }
+}
+
+/// The current UTC time, truncated to whole seconds.
+pub(crate) fn utc_now() -> NaiveDateTime {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    DateTime::from_timestamp(i64::try_from(secs).unwrap_or(i64::MAX), 0)
+        .unwrap_or_default()
+        .naive_utc()
+}
+
+/// Pick a second uniformly between two datetimes (inclusive).
+#[inline]
+fn random_datetime_between(
+    rng: &mut ForgeryRng,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+) -> NaiveDateTime {
+    let span = (end - start).num_seconds();
+    start + TimeDelta::seconds(rng.gen_range(0i64, span))
+}
+
+/// Generate a batch of ISO 8601 datetimes within a window around `now`.
+///
+/// Callers normally pass the current UTC time; taking it as an argument
+/// keeps the output reproducible for a fixed seed and anchor.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if the window reaches past the representable
+/// calendar.
+pub fn generate_relative_datetimes(
+    rng: &mut ForgeryRng,
+    n: usize,
+    range: RelativeRange,
+    now: NaiveDateTime,
+) -> Result<Vec<String>, DateRangeError> {
+    let (start, end) = range.bounds(now)?;
+    let mut datetimes = Vec::with_capacity(n);
+    for _ in 0..n {
+        let dt = random_datetime_between(rng, start, end);
+        datetimes.push(dt.format("%Y-%m-%dT%H:%M:%S").to_string());
+    }
+    Ok(datetimes)
+}
+
+/// Generate a single ISO 8601 datetime within a window around `now`.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if the window reaches past the representable
+/// calendar.
 #[inline]
-fn format_datetime(date: NaiveDate, hour: u32, minute: u32, second: u32) -> String {
+pub fn generate_relative_datetime(
+    rng: &mut ForgeryRng,
+    range: RelativeRange,
+    now: NaiveDateTime,
+) -> Result<String, DateRangeError> {
+    let (start, end) = range.bounds(now)?;
+    Ok(random_datetime_between(rng, start, end)
+        .format("%Y-%m-%dT%H:%M:%S")
+        .to_string())
+}
+
+/// Expand a `DateFormat` template for the given date and time.
+///
+/// Unknown placeholders are copied through unchanged.
+fn render_date_template(
+    template: &str,
+    locale: Locale,
+    date: NaiveDate,
+    (hour, minute, second): (u32, u32, u32),
+) -> String {
+    let data = get_locale_data(locale);
+    let month_idx = date.month0() as usize;
+    let weekday_idx = date.weekday().num_days_from_monday() as usize;
+
+    let mut out = String::with_capacity(template.len() + 16);
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('}') else {
+            // Unmatched brace: keep only the unconsumed tail as literal text
+            rest = &rest[open..];
+            break;
+        };
+        let token = &rest[open + 1..open + close];
+        match token {
+            "yyyy" => out.push_str(&format!("{:04}", date.year())),
+            "mm" => out.push_str(&format!("{:02}", date.month())),
+            "m" => out.push_str(&date.month().to_string()),
+            "dd" => out.push_str(&format!("{:02}", date.day())),
+            "d" => out.push_str(&date.day().to_string()),
+            "month" => match data.month_names().and_then(|m| m.get(month_idx)) {
+                Some(name) => out.push_str(name),
+                None => out.push_str(&date.month().to_string()),
+            },
+            "weekday" => {
+                if let Some(name) = data.weekday_names().and_then(|w| w.get(weekday_idx)) {
+                    out.push_str(name);
+                }
+            }
+            "HH" => out.push_str(&format!("{:02}", hour)),
+            "H" => out.push_str(&hour.to_string()),
+            "h" => out.push_str(&(if hour % 12 == 0 { 12 } else { hour % 12 }).to_string()),
+            "MM" => out.push_str(&format!("{:02}", minute)),
+            "SS" => out.push_str(&format!("{:02}", second)),
+            "ampm" => out.push_str(if hour < 12 { "AM" } else { "PM" }),
+            _ => out.push_str(&rest[open..=open + close]),
+        }
+        rest = &rest[open + close + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Format a date in the given style.
+fn format_date(date: NaiveDate, locale: Locale, style: DateStyle) -> String {
+    let format = get_locale_data(locale).date_format();
+    let template = match (style, format) {
+        (DateStyle::Locale, Some(f)) => f.date,
+        (DateStyle::LocaleLong, Some(f)) => f.long_date,
+        _ => return date.format("%Y-%m-%d").to_string(),
+    };
+    render_date_template(template, locale, date, (0, 0, 0))
+}
+
+/// Format a date and time in the given style.
+///
+/// ISO style produces `YYYY-MM-DDTHH:MM:SS`; locale styles join the locale's
+/// date and time templates with a space.
+#[inline]
+fn format_datetime(
+    date: NaiveDate,
+    time: (u32, u32, u32),
+    locale: Locale,
+    style: DateStyle,
+) -> String {
+    let format = get_locale_data(locale).date_format();
+    let date_template = match (style, format) {
+        (DateStyle::Locale, Some(f)) => f.date,
+        (DateStyle::LocaleLong, Some(f)) => f.long_date,
+        _ => {
+            let (hour, minute, second) = time;
+            return format!(
+                "{}T{:02}:{:02}:{:02}",
+                date.format("%Y-%m-%d"),
+                hour,
+                minute,
+                second
+            );
+        }
+    };
+    let time_template = format.map_or("{HH}:{MM}:{SS}", |f| f.time);
     format!(
-        "{}T{:02}:{:02}:{:02}",
-        date.format("%Y-%m-%d"),
-        hour,
-        minute,
-        second
+        "{} {}",
+        render_date_template(date_template, locale, date, time),
+        render_date_template(time_template, locale, date, time)
     )
 }
 
@@ -251,14 +1013,39 @@ pub fn generate_datetimes(
     n: usize,
     start: &str,
     end: &str,
+) -> Result<Vec<String>, DateRangeError> {
+    generate_datetimes_with_style(rng, Locale::EnUS, n, start, end, DateStyle::Iso)
+}
+
+/// Generate a batch of random datetime strings formatted in the given style.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `locale` - The locale whose date and time formats are used for non-ISO styles
+/// * `n` - Number of datetimes to generate
+/// * `start` - Start date in YYYY-MM-DD format (inclusive)
+/// * `end` - End date in YYYY-MM-DD format (inclusive)
+/// * `style` - Output style
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if dates cannot be parsed or start > end.
+pub fn generate_datetimes_with_style(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    start: &str,
+    end: &str,
+    style: DateStyle,
 ) -> Result<Vec<String>, DateRangeError> {
     let range = validate_date_range(start, end)?;
 
     let mut datetimes = Vec::with_capacity(n);
     for _ in 0..n {
         let date = random_date_from_range(rng, &range, start, end)?;
-        let (hour, minute, second) = random_time(rng);
-        datetimes.push(format_datetime(date, hour, minute, second));
+        let time = random_time(rng);
+        datetimes.push(format_datetime(date, time, locale, style));
     }
     Ok(datetimes)
 }
@@ -273,11 +1060,27 @@ pub fn generate_datetime(
     rng: &mut ForgeryRng,
     start: &str,
     end: &str,
+) -> Result<String, DateRangeError> {
+    generate_datetime_with_style(rng, Locale::EnUS, start, end, DateStyle::Iso)
+}
+
+/// Generate a single random datetime string formatted in the given style.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if dates cannot be parsed or start > end.
+#[inline]
+pub fn generate_datetime_with_style(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    start: &str,
+    end: &str,
+    style: DateStyle,
 ) -> Result<String, DateRangeError> {
     let range = validate_date_range(start, end)?;
     let date = random_date_from_range(rng, &range, start, end)?;
-    let (hour, minute, second) = random_time(rng);
-    Ok(format_datetime(date, hour, minute, second))
+    let time = random_time(rng);
+    Ok(format_datetime(date, time, locale, style))
 }
 
 #[cfg(test)]
@@ -285,6 +1088,141 @@ mod tests {
     use super::*;
 
     // Date tests
+    #[test]
+    fn test_birth_date_and_age_match() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let today = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        for _ in 0..500 {
+            let (dob, age) = generate_birth_date_and_age(&mut rng, 18, 20).unwrap();
+            assert!((18..=20).contains(&age), "{} {}", dob, age);
+            assert_eq!(today.years_since(dob), Some(age));
+        }
+
+        let (dob, age) = generate_birth_date_and_age(&mut rng, 0, 0).unwrap();
+        assert_eq!(age, 0);
+        assert!(dob > NaiveDate::from_ymd_opt(2023, 1, 1).unwrap());
+
+        assert!(generate_birth_date_and_age(&mut rng, 30, 20).is_err());
+        assert!(generate_birth_date_and_age(&mut rng, 0, u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_dates_of_birth_with_age() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let today = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+        let pairs = generate_dates_of_birth_with_age(&mut rng, 200, 25, 40).unwrap();
+        assert_eq!(pairs.len(), 200);
+        for (dob, age) in &pairs {
+            let date = NaiveDate::parse_from_str(dob, "%Y-%m-%d").unwrap();
+            assert!((25..=40).contains(age), "{} {}", dob, age);
+            assert_eq!(today.years_since(date), Some(*age));
+        }
+
+        assert!(generate_dates_of_birth_with_age(&mut rng, 0, 40, 25).is_err());
+        assert!(generate_date_of_birth_with_age(&mut rng, 40, 25).is_err());
+    }
+
+    #[test]
+    fn test_format_period() {
+        let date = NaiveDate::from_ymd_opt(2024, 9, 11).unwrap();
+        assert_eq!(format_period(date, ReportingPeriod::IsoWeek), "2024-W37");
+        assert_eq!(format_period(date, ReportingPeriod::Quarter), "2024-Q3");
+        assert_eq!(format_period(date, ReportingPeriod::Month), "2024-09");
+
+        // ISO weeks belong to the week-based year
+        let new_year = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+        assert_eq!(
+            format_period(new_year, ReportingPeriod::IsoWeek),
+            "2020-W53"
+        );
+        let december = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+        assert_eq!(
+            format_period(december, ReportingPeriod::IsoWeek),
+            "2025-W01"
+        );
+    }
+
+    #[test]
+    fn test_generate_periods_in_range() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let quarters = generate_periods(
+            &mut rng,
+            200,
+            "2024-04-01",
+            "2024-09-30",
+            ReportingPeriod::Quarter,
+        )
+        .unwrap();
+        assert!(quarters.iter().all(|q| q == "2024-Q2" || q == "2024-Q3"));
+        assert!(quarters.iter().any(|q| q == "2024-Q2"));
+        assert!(quarters.iter().any(|q| q == "2024-Q3"));
+
+        let months = generate_periods(
+            &mut rng,
+            200,
+            "2023-12-15",
+            "2024-01-15",
+            ReportingPeriod::Month,
+        )
+        .unwrap();
+        assert!(months.iter().all(|m| m == "2023-12" || m == "2024-01"));
+
+        let week = generate_period(
+            &mut rng,
+            "2024-09-09",
+            "2024-09-15",
+            ReportingPeriod::IsoWeek,
+        )
+        .unwrap();
+        assert_eq!(week, "2024-W37");
+
+        assert!(generate_periods(
+            &mut rng,
+            1,
+            "2024-02-01",
+            "2024-01-01",
+            ReportingPeriod::Month
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_generate_times() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let times = generate_times(&mut rng, 200, DEFAULT_TIME_FORMAT).unwrap();
+        for time in &times {
+            assert!(
+                NaiveTime::parse_from_str(time, "%H:%M:%S").is_ok(),
+                "{}",
+                time
+            );
+        }
+        let times = generate_times(&mut rng, 50, "%I:%M %p").unwrap();
+        for time in &times {
+            assert!(time.ends_with(" AM") || time.ends_with(" PM"), "{}", time);
+        }
+        let micros = generate_time_micros(&mut rng);
+        assert!((0..86_400_000_000).contains(&micros));
+        assert_eq!(micros % 1_000_000, 0);
+    }
+
+    #[test]
+    fn test_time_format_errors() {
+        let mut rng = ForgeryRng::new();
+        for format in ["%Y-%m-%d", "%Q", "%H:%"] {
+            let err = generate_time(&mut rng, format).unwrap_err();
+            assert!(err.to_string().contains(format), "{}", err);
+        }
+        assert_eq!(generate_time(&mut rng, "noon").unwrap(), "noon");
+    }
+
     #[test]
     fn test_generate_dates_count() {
         let mut rng = ForgeryRng::new();
@@ -535,6 +1473,265 @@ mod tests {
             "Different seeds should produce different dates"
         );
     }
+
+    // Localized formatting tests
+    fn new_year_eve() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()
+    }
+
+    #[test]
+    fn test_date_style_from_str() {
+        assert_eq!("iso".parse::<DateStyle>().unwrap(), DateStyle::Iso);
+        assert_eq!("locale".parse::<DateStyle>().unwrap(), DateStyle::Locale);
+        assert_eq!(
+            "locale_long".parse::<DateStyle>().unwrap(),
+            DateStyle::LocaleLong
+        );
+        let err = "rfc".parse::<DateStyle>().unwrap_err();
+        assert_eq!(err.format, "rfc");
+        assert!(err.to_string().contains("iso, locale, locale_long"));
+    }
+
+    #[test]
+    fn test_format_date_locale_short() {
+        let date = new_year_eve();
+        let cases = [
+            (Locale::EnUS, "12/31/2024"),
+            (Locale::EnGB, "31/12/2024"),
+            (Locale::DeDE, "31.12.2024"),
+            (Locale::JaJP, "2024年12月31日"),
+            (Locale::KoKR, "2024. 12. 31."),
+            (Locale::NlNL, "31-12-2024"),
+        ];
+        for (locale, expected) in cases {
+            assert_eq!(format_date(date, locale, DateStyle::Locale), expected);
+        }
+    }
+
+    #[test]
+    fn test_format_date_locale_long() {
+        let date = new_year_eve();
+        assert_eq!(
+            format_date(date, Locale::EnUS, DateStyle::LocaleLong),
+            "Tuesday, December 31, 2024"
+        );
+        assert_eq!(
+            format_date(date, Locale::DeDE, DateStyle::LocaleLong),
+            "Dienstag, 31. Dezember 2024"
+        );
+        assert_eq!(
+            format_date(date, Locale::RuRU, DateStyle::LocaleLong),
+            "вторник, 31 декабря 2024 г."
+        );
+        assert_eq!(
+            format_date(date, Locale::JaJP, DateStyle::LocaleLong),
+            "2024年12月31日火曜日"
+        );
+    }
+
+    #[test]
+    fn test_format_date_iso_ignores_locale() {
+        let date = new_year_eve();
+        for locale in Locale::ALL {
+            assert_eq!(format_date(date, *locale, DateStyle::Iso), "2024-12-31");
+        }
+    }
+
+    #[test]
+    fn test_render_date_template_unmatched_brace() {
+        let date = new_year_eve();
+        assert_eq!(
+            render_date_template("{yyyy}-{x", Locale::EnUS, date, (0, 0, 0)),
+            "2024-{x"
+        );
+        assert_eq!(
+            render_date_template("{dd}.{mm} {", Locale::EnUS, date, (0, 0, 0)),
+            "31.12 {"
+        );
+    }
+
+    #[test]
+    fn test_format_datetime_locale() {
+        let date = new_year_eve();
+        assert_eq!(
+            format_datetime(date, (0, 5, 9), Locale::EnUS, DateStyle::Locale),
+            "12/31/2024 12:05:09 AM"
+        );
+        assert_eq!(
+            format_datetime(date, (23, 5, 9), Locale::DeDE, DateStyle::Locale),
+            "31.12.2024 23:05:09"
+        );
+        assert_eq!(
+            format_datetime(date, (23, 5, 9), Locale::FrFR, DateStyle::Iso),
+            "2024-12-31T23:05:09"
+        );
+    }
+
+    #[test]
+    fn test_styled_dates_match_iso_sequence() {
+        // Styling must not change which dates are drawn
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(42);
+        rng2.seed(42);
+
+        let iso = generate_dates(&mut rng1, 20, "2020-01-01", "2024-12-31").unwrap();
+        let de = generate_dates_with_style(
+            &mut rng2,
+            Locale::DeDE,
+            20,
+            "2020-01-01",
+            "2024-12-31",
+            DateStyle::Locale,
+        )
+        .unwrap();
+        for (iso, de) in iso.iter().zip(&de) {
+            let (y, m, d) = (&iso[0..4], &iso[5..7], &iso[8..10]);
+            assert_eq!(de, &format!("{}.{}.{}", d, m, y));
+        }
+    }
+
+    // Business date tests
+    #[test]
+    fn test_weekday_rank_round_trip() {
+        assert_eq!(
+            NaiveDate::from_num_days_from_ce_opt(1).unwrap().weekday(),
+            chrono::Weekday::Mon
+        );
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .num_days_from_ce();
+        for days in start..start + 30 {
+            let date = NaiveDate::from_num_days_from_ce_opt(days).unwrap();
+            if date.weekday().number_from_monday() <= 5 {
+                assert_eq!(day_from_weekday_rank(weekday_rank(days)), days);
+            }
+        }
+    }
+
+    #[test]
+    fn test_business_dates_skip_weekends_and_holidays() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let holidays = vec!["2024-12-25".to_string(), "2024-12-26".to_string()];
+        let dates =
+            generate_business_dates(&mut rng, 500, "2024-12-20", "2024-12-31", &holidays).unwrap();
+        let mut seen = std::collections::BTreeSet::new();
+        for date in &dates {
+            let parsed = parse_date(date).unwrap();
+            assert!(parsed.weekday().number_from_monday() <= 5, "{date}");
+            assert!(!holidays.contains(date), "{date}");
+            seen.insert(date.clone());
+        }
+        // Dec 20, 23, 24, 27, 30, 31
+        assert_eq!(seen.len(), 6);
+    }
+
+    #[test]
+    fn test_business_date_single_day() {
+        let mut rng = ForgeryRng::new();
+        let date = generate_business_date(&mut rng, "2024-01-05", "2024-01-07", &[]).unwrap();
+        assert_eq!(date, "2024-01-05");
+    }
+
+    #[test]
+    fn test_business_dates_no_business_days() {
+        let mut rng = ForgeryRng::new();
+        let err =
+            generate_business_dates(&mut rng, 1, "2024-01-06", "2024-01-07", &[]).unwrap_err();
+        assert!(err.reason.contains("no business days"));
+
+        let holidays = vec!["2024-01-08".to_string()];
+        let err =
+            generate_business_date(&mut rng, "2024-01-06", "2024-01-08", &holidays).unwrap_err();
+        assert!(err.reason.contains("no business days"));
+    }
+
+    #[test]
+    fn test_business_dates_invalid_holiday() {
+        let mut rng = ForgeryRng::new();
+        let holidays = vec!["not-a-date".to_string()];
+        let err =
+            generate_business_date(&mut rng, "2024-01-01", "2024-12-31", &holidays).unwrap_err();
+        assert!(err.reason.contains("invalid holiday 'not-a-date'"));
+    }
+
+    // Relative datetime tests
+    fn fixed_now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, 15)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    fn parse_iso(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_relative_bounds() {
+        let now = fixed_now();
+        let (start, end) = RelativeRange::Recent(7).bounds(now).unwrap();
+        assert_eq!(start, now - TimeDelta::days(7));
+        assert_eq!(end, now);
+
+        let (start, end) = RelativeRange::Past(5).bounds(now).unwrap();
+        assert_eq!(start.date(), NaiveDate::from_ymd_opt(2019, 3, 15).unwrap());
+        assert_eq!(end, now);
+
+        let (start, end) = RelativeRange::Future(1).bounds(now).unwrap();
+        assert_eq!(start, now);
+        assert_eq!(end.date(), NaiveDate::from_ymd_opt(2025, 3, 15).unwrap());
+    }
+
+    #[test]
+    fn test_relative_datetimes_in_window() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let now = fixed_now();
+        for range in [
+            RelativeRange::Recent(7),
+            RelativeRange::Past(5),
+            RelativeRange::Future(1),
+        ] {
+            let (start, end) = range.bounds(now).unwrap();
+            for dt in generate_relative_datetimes(&mut rng, 200, range, now).unwrap() {
+                let parsed = parse_iso(&dt);
+                assert!(parsed >= start && parsed <= end, "{dt} outside {range:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_relative_zero_window_is_now() {
+        let mut rng = ForgeryRng::new();
+        let now = fixed_now();
+        let dt = generate_relative_datetime(&mut rng, RelativeRange::Recent(0), now).unwrap();
+        assert_eq!(dt, "2024-03-15T12:00:00");
+    }
+
+    #[test]
+    fn test_relative_deterministic_for_fixed_anchor() {
+        let now = fixed_now();
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+        assert_eq!(
+            generate_relative_datetimes(&mut rng1, 50, RelativeRange::Past(5), now).unwrap(),
+            generate_relative_datetimes(&mut rng2, 50, RelativeRange::Past(5), now).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_relative_out_of_range() {
+        let mut rng = ForgeryRng::new();
+        let err =
+            generate_relative_datetime(&mut rng, RelativeRange::Future(u32::MAX), fixed_now())
+                .unwrap_err();
+        assert_eq!(err.start, "now");
+        assert_eq!(err.end, format!("now + {} years", u32::MAX));
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,318 @@
+//! Demographic data generation provider.
+//!
+//! Generates the basic categorical attributes of a person profile. Values are
+//! English and do not depend on the locale. Each category set has default
+//! weights that can be replaced per call.
+//!
+//! | Field | Example | Default distribution |
+//! |-------|---------|----------------------|
+//! | Age | `34` | Uniform over 18-80 |
+//! | Gender | `female` | female 49%, male 49%, non-binary 2% |
+//! | Pronouns | `she/her` | she/her 48%, he/him 48%, they/them 4% |
+//! | Marital status | `married` | married 48%, single 34%, divorced 10%, widowed 6%, separated 2% |
+
+use crate::providers::numbers::RangeError;
+use crate::rng::ForgeryRng;
+
+/// Default minimum age in years.
+pub const DEFAULT_MIN_AGE: u32 = 18;
+
+/// Default maximum age in years.
+pub const DEFAULT_MAX_AGE: u32 = 80;
+
+/// Genders with their default weights in percent.
+const GENDER_WEIGHTS: &[(&str, u32)] = &[("female", 49), ("male", 49), ("non-binary", 2)];
+
+/// Pronoun sets with their default weights in percent.
+const PRONOUN_WEIGHTS: &[(&str, u32)] = &[("she/her", 48), ("he/him", 48), ("they/them", 4)];
+
+/// Marital statuses with their default weights in percent.
+const MARITAL_STATUS_WEIGHTS: &[(&str, u32)] = &[
+    ("married", 48),
+    ("single", 34),
+    ("divorced", 10),
+    ("widowed", 6),
+    ("separated", 2),
+];
+
+/// A categorical demographic attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Demographic {
+    /// `female`, `male` or `non-binary`.
+    Gender,
+    /// `she/her`, `he/him` or `they/them`.
+    Pronouns,
+    /// `married`, `single`, `divorced`, `widowed` or `separated`.
+    MaritalStatus,
+}
+
+impl Demographic {
+    /// The demographic's name, as used for schema types.
+    pub fn name(self) -> &'static str {
+        match self {
+            Demographic::Gender => "gender",
+            Demographic::Pronouns => "pronouns",
+            Demographic::MaritalStatus => "marital_status",
+        }
+    }
+
+    /// The demographic's categories with their default weights.
+    fn default_weights(self) -> &'static [(&'static str, u32)] {
+        match self {
+            Demographic::Gender => GENDER_WEIGHTS,
+            Demographic::Pronouns => PRONOUN_WEIGHTS,
+            Demographic::MaritalStatus => MARITAL_STATUS_WEIGHTS,
+        }
+    }
+
+    /// The demographic's category names.
+    pub fn categories(self) -> Vec<&'static str> {
+        self.default_weights().iter().map(|&(c, _)| c).collect()
+    }
+}
+
+/// Error for demographic weights that cannot be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DemographicWeightsError {
+    /// A weight names a category the demographic does not have.
+    UnknownCategory {
+        /// The demographic being weighted.
+        demographic: Demographic,
+        /// The unrecognized category.
+        category: String,
+    },
+    /// Every weight is zero.
+    ZeroTotal {
+        /// The demographic being weighted.
+        demographic: Demographic,
+    },
+}
+
+impl std::fmt::Display for DemographicWeightsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownCategory {
+                demographic,
+                category,
+            } => write!(
+                f,
+                "unknown {} category '{}'; expected one of: {}",
+                demographic.name(),
+                category,
+                demographic.categories().join(", ")
+            ),
+            Self::ZeroTotal { demographic } => write!(
+                f,
+                "invalid {} weights: at least one weight must be positive",
+                demographic.name()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DemographicWeightsError {}
+
+/// Resolved category weights for one demographic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DemographicWeights {
+    /// Categories with a positive weight.
+    categories: Vec<(&'static str, u32)>,
+    /// Sum of all weights.
+    total: u64,
+}
+
+impl DemographicWeights {
+    /// The default weights for a demographic.
+    pub fn default_for(demographic: Demographic) -> Self {
+        Self::from_pairs(demographic.default_weights().to_vec())
+    }
+
+    /// Build weights for a demographic from (category, weight) pairs.
+    ///
+    /// Categories left out get weight zero; an empty list gives the defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DemographicWeightsError` if a category is unknown or every
+    /// weight is zero.
+    pub fn new(
+        demographic: Demographic,
+        weights: &[(String, u32)],
+    ) -> Result<Self, DemographicWeightsError> {
+        if weights.is_empty() {
+            return Ok(Self::default_for(demographic));
+        }
+        let mut pairs: Vec<(&'static str, u32)> = demographic
+            .default_weights()
+            .iter()
+            .map(|&(c, _)| (c, 0))
+            .collect();
+        for (category, weight) in weights {
+            let slot = pairs
+                .iter_mut()
+                .find(|(c, _)| *c == category)
+                .ok_or_else(|| DemographicWeightsError::UnknownCategory {
+                    demographic,
+                    category: category.clone(),
+                })?;
+            slot.1 = *weight;
+        }
+        let resolved = Self::from_pairs(pairs);
+        if resolved.total == 0 {
+            return Err(DemographicWeightsError::ZeroTotal { demographic });
+        }
+        Ok(resolved)
+    }
+
+    fn from_pairs(mut pairs: Vec<(&'static str, u32)>) -> Self {
+        pairs.retain(|&(_, w)| w > 0);
+        let total = pairs.iter().map(|&(_, w)| u64::from(w)).sum();
+        Self {
+            categories: pairs,
+            total,
+        }
+    }
+
+    /// Draw one category.
+    #[inline]
+    fn sample(&self, rng: &mut ForgeryRng) -> &'static str {
+        let mut roll = rng.gen_range(1u64, self.total);
+        for &(category, weight) in &self.categories {
+            if roll <= u64::from(weight) {
+                return category;
+            }
+            roll -= u64::from(weight);
+        }
+        self.categories[self.categories.len() - 1].0
+    }
+}
+
+/// Generate a batch of demographic categories drawn from the given weights.
+pub fn generate_demographics(
+    rng: &mut ForgeryRng,
+    n: usize,
+    weights: &DemographicWeights,
+) -> Vec<String> {
+    let mut results = Vec::with_capacity(n);
+    for _ in 0..n {
+        results.push(weights.sample(rng).to_string());
+    }
+    results
+}
+
+/// Generate a single demographic category drawn from the given weights.
+#[inline]
+pub fn generate_demographic(rng: &mut ForgeryRng, weights: &DemographicWeights) -> String {
+    weights.sample(rng).to_string()
+}
+
+/// Generate a batch of ages in whole years.
+///
+/// # Errors
+///
+/// Returns `RangeError` if min > max.
+pub fn generate_ages(
+    rng: &mut ForgeryRng,
+    n: usize,
+    min: u32,
+    max: u32,
+) -> Result<Vec<u32>, RangeError> {
+    validate_ages(min, max)?;
+    let mut results = Vec::with_capacity(n);
+    for _ in 0..n {
+        results.push(rng.gen_range(min, max));
+    }
+    Ok(results)
+}
+
+/// Generate a single age in whole years.
+///
+/// # Errors
+///
+/// Returns `RangeError` if min > max.
+#[inline]
+pub fn generate_age(rng: &mut ForgeryRng, min: u32, max: u32) -> Result<u32, RangeError> {
+    validate_ages(min, max)?;
+    Ok(rng.gen_range(min, max))
+}
+
+/// Check that an age range is ordered.
+///
+/// # Errors
+///
+/// Returns `RangeError` if min > max.
+#[inline]
+pub fn validate_ages(min: u32, max: u32) -> Result<(), RangeError> {
+    if min > max {
+        return Err(RangeError {
+            min: min.into(),
+            max: max.into(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> ForgeryRng {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        rng
+    }
+
+    #[test]
+    fn test_default_weights() {
+        let weights = DemographicWeights::default_for(Demographic::MaritalStatus);
+        let values = generate_demographics(&mut seeded(), 5000, &weights);
+        let married = values.iter().filter(|v| *v == "married").count();
+        let separated = values.iter().filter(|v| *v == "separated").count();
+        assert!((2100..2700).contains(&married), "married: {}", married);
+        assert!(separated < 250, "separated: {}", separated);
+        assert!(values.iter().all(|v| Demographic::MaritalStatus
+            .categories()
+            .contains(&v.as_str())));
+    }
+
+    #[test]
+    fn test_custom_weights() {
+        let weights =
+            DemographicWeights::new(Demographic::Gender, &[("non-binary".to_string(), 1)]).unwrap();
+        let values = generate_demographics(&mut seeded(), 100, &weights);
+        assert!(values.iter().all(|v| v == "non-binary"));
+
+        let err = DemographicWeights::new(Demographic::Pronouns, &[("xe/xem".to_string(), 1)])
+            .unwrap_err();
+        assert!(err.to_string().contains("'xe/xem'"));
+
+        let err =
+            DemographicWeights::new(Demographic::Gender, &[("male".to_string(), 0)]).unwrap_err();
+        assert_eq!(
+            err,
+            DemographicWeightsError::ZeroTotal {
+                demographic: Demographic::Gender
+            }
+        );
+    }
+
+    #[test]
+    fn test_ages() {
+        let ages = generate_ages(&mut seeded(), 500, 21, 30).unwrap();
+        assert!(ages.iter().all(|a| (21..=30).contains(a)));
+        assert_eq!(generate_age(&mut seeded(), 40, 40), Ok(40));
+        assert_eq!(
+            generate_age(&mut seeded(), 30, 21),
+            Err(RangeError { min: 30, max: 21 })
+        );
+    }
+
+    #[test]
+    fn test_demographics_deterministic() {
+        let weights = DemographicWeights::default_for(Demographic::Pronouns);
+        assert_eq!(
+            generate_demographics(&mut seeded(), 50, &weights),
+            generate_demographics(&mut seeded(), 50, &weights)
+        );
+    }
+}
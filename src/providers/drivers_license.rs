@@ -0,0 +1,426 @@
+//! Driver's license number generation provider.
+//!
+//! Generates license numbers in the format used by each locale's issuing
+//! authority. The US and Canada issue licenses per state or province, so
+//! those locales pick a random region unless one is requested. The numbers
+//! are random and not issued to anyone.
+//!
+//! | Locale | Format | Example |
+//! |--------|--------|---------|
+//! | en_US | Per state (see [`US_LICENSE_STATES`]) | `A1234567` (CA) |
+//! | en_CA | Per province (see [`CA_LICENSE_PROVINCES`]) | `A1234-56789-01234` (ON) |
+//! | en_GB | DVLA driver number | `MORGA657054SM9IJ` |
+//! | de_DE | Führerscheinnummer | `B072RRE2I55` |
+//! | fr_FR | NEPH | `123456789012` |
+//! | es_ES | DNI or NIE | `12345678Z` |
+//! | it_IT | Patente | `AB1234567C` |
+//! | nl_NL | Rijbewijsnummer | `1234567890` |
+//! | pl_PL | Prawo jazdy | `00123/15/1234` |
+//! | ru_RU | Водительское удостоверение | `77 12 345678` |
+//! | ja_JP | 免許証番号 | `123456789012` |
+//! | ko_KR | 운전면허번호 | `11-15-123456-78` |
+
+use crate::locale::Locale;
+use crate::providers::names::generate_last_name;
+use crate::providers::national_id::generate_dni;
+use crate::rng::ForgeryRng;
+
+/// US states with a supported license format.
+pub const US_LICENSE_STATES: &[&str] = &[
+    "AZ", "CA", "CO", "FL", "GA", "IL", "MA", "MI", "NJ", "NY", "OH", "PA", "TX", "WA",
+];
+
+/// Canadian provinces with a supported license format.
+pub const CA_LICENSE_PROVINCES: &[&str] = &["AB", "BC", "ON", "QC"];
+
+/// Error for a driver's license region that is not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseRegionError {
+    /// The unsupported region code.
+    pub region: String,
+}
+
+impl std::fmt::Display for LicenseRegionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unsupported driver's license region '{}'; expected one of: {}, {}",
+            self.region,
+            US_LICENSE_STATES.join(", "),
+            CA_LICENSE_PROVINCES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for LicenseRegionError {}
+
+/// Validate a license region code (case-insensitive) and return its canonical form.
+///
+/// Regions are US state and Canadian province codes.
+///
+/// # Errors
+///
+/// Returns `LicenseRegionError` if the region is not supported.
+pub fn validate_license_region(region: &str) -> Result<&'static str, LicenseRegionError> {
+    US_LICENSE_STATES
+        .iter()
+        .chain(CA_LICENSE_PROVINCES)
+        .find(|r| r.eq_ignore_ascii_case(region))
+        .copied()
+        .ok_or_else(|| LicenseRegionError {
+            region: region.to_string(),
+        })
+}
+
+/// Generate a batch of driver's license numbers.
+///
+/// With `region` set, every number uses that state's or province's format.
+/// Otherwise en_US and en_CA pick a random region for each number, and other
+/// locales use their national format.
+///
+/// # Errors
+///
+/// Returns `LicenseRegionError` if `region` is not supported.
+pub fn generate_drivers_licenses(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    region: Option<&str>,
+) -> Result<Vec<String>, LicenseRegionError> {
+    let region = region.map(validate_license_region).transpose()?;
+    let mut licenses = Vec::with_capacity(n);
+    for _ in 0..n {
+        licenses.push(drivers_license_for(rng, locale, region));
+    }
+    Ok(licenses)
+}
+
+/// Generate a single driver's license number.
+///
+/// See [`generate_drivers_licenses`] for how the format is chosen.
+///
+/// # Errors
+///
+/// Returns `LicenseRegionError` if `region` is not supported.
+#[inline]
+pub fn generate_drivers_license(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    region: Option<&str>,
+) -> Result<String, LicenseRegionError> {
+    let region = region.map(validate_license_region).transpose()?;
+    Ok(drivers_license_for(rng, locale, region))
+}
+
+/// Generate a driver's license number for an already validated region.
+pub(crate) fn drivers_license_for(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    region: Option<&'static str>,
+) -> String {
+    if let Some(region) = region {
+        return regional_license(rng, region);
+    }
+    match locale {
+        Locale::EnUS => {
+            let state = rng.choose(US_LICENSE_STATES);
+            regional_license(rng, state)
+        }
+        Locale::EnCA => {
+            let province = rng.choose(CA_LICENSE_PROVINCES);
+            regional_license(rng, province)
+        }
+        Locale::EnGB => generate_dvla_number(rng),
+        Locale::DeDE => {
+            let mut s = alphanumeric(rng, 9);
+            push_digits(rng, &mut s, 1);
+            s.push_str(&alphanumeric(rng, 1));
+            s
+        }
+        Locale::FrFR | Locale::JaJP => digits(rng, 12),
+        Locale::EsES => generate_dni(rng),
+        Locale::ItIT => {
+            let mut s = letters(rng, 2);
+            push_digits(rng, &mut s, 7);
+            s.push_str(&letters(rng, 1));
+            s
+        }
+        Locale::NlNL => digits(rng, 10),
+        Locale::PlPL => format!(
+            "{}/{:02}/{}",
+            digits(rng, 5),
+            rng.gen_range(0u8, 99),
+            digits(rng, 4)
+        ),
+        Locale::RuRU => format!(
+            "{:02} {:02} {}",
+            rng.gen_range(1u8, 99),
+            rng.gen_range(0u8, 99),
+            digits(rng, 6)
+        ),
+        Locale::KoKR => format!(
+            "{}-{:02}-{}-{}",
+            rng.gen_range(11u8, 28),
+            rng.gen_range(0u8, 99),
+            digits(rng, 6),
+            digits(rng, 2)
+        ),
+    }
+}
+
+/// License number in a US state's or Canadian province's format.
+fn regional_license(rng: &mut ForgeryRng, region: &str) -> String {
+    match region {
+        // Letter + 7 digits
+        "CA" => format!("{}{}", letters(rng, 1), digits(rng, 7)),
+        // Letter + 8 digits
+        "AZ" => format!("{}{}", letters(rng, 1), digits(rng, 8)),
+        // Letter + 11 digits
+        "IL" => format!("{}{}", letters(rng, 1), digits(rng, 11)),
+        // Letter + 12 digits
+        "FL" | "MI" => format!("{}{}", letters(rng, 1), digits(rng, 12)),
+        // Letter + 14 digits
+        "NJ" => format!("{}{}", letters(rng, 1), digits(rng, 14)),
+        // S + 8 digits
+        "MA" => format!("S{}", digits(rng, 8)),
+        // 2 letters + 6 digits
+        "OH" => format!("{}{}", letters(rng, 2), digits(rng, 6)),
+        // WDL + 9 alphanumerics (format since 2018)
+        "WA" => format!("WDL{}", alphanumeric(rng, 9)),
+        // 8 digits
+        "PA" | "TX" => digits(rng, 8),
+        // 9 digits (CO printed as XX-XXX-XXXX)
+        "CO" => format!("{}-{}-{}", digits(rng, 2), digits(rng, 3), digits(rng, 4)),
+        "AB" => format!("{}-{}", digits(rng, 6), digits(rng, 3)),
+        "BC" => digits(rng, 7),
+        "ON" => format!(
+            "{}{}-{}-{}",
+            letters(rng, 1),
+            digits(rng, 4),
+            digits(rng, 5),
+            digits(rng, 5)
+        ),
+        "QC" => format!(
+            "{}{}-{}-{}",
+            letters(rng, 1),
+            digits(rng, 4),
+            digits(rng, 6),
+            digits(rng, 2)
+        ),
+        // GA, NY
+        _ => digits(rng, 9),
+    }
+}
+
+/// UK DVLA driver number (16 characters).
+///
+/// Surname (5, padded with 9), decade digit, birth month (+50 for women),
+/// day, year digit, two initials (padded with 9), a digit, two check letters.
+fn generate_dvla_number(rng: &mut ForgeryRng) -> String {
+    let mut s: String = generate_last_name(rng, Locale::EnGB)
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .take(5)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    while s.len() < 5 {
+        s.push('9');
+    }
+
+    let year: u8 = rng.gen_range(40, 99);
+    let month: u8 = rng.gen_range(1, 12) + if rng.gen_range(0, 1) == 1 { 50 } else { 0 };
+    let day: u8 = rng.gen_range(1, 28);
+    s.push(char::from(b'0' + year / 10));
+    s.push_str(&format!("{:02}{:02}", month, day));
+    s.push(char::from(b'0' + year % 10));
+
+    s.push_str(&letters(rng, 1));
+    if rng.gen_range(0, 1) == 1 {
+        s.push_str(&letters(rng, 1));
+    } else {
+        s.push('9');
+    }
+    s.push('9');
+    s.push_str(&letters(rng, 2));
+    s
+}
+
+/// Random decimal digits as a string.
+fn digits(rng: &mut ForgeryRng, count: usize) -> String {
+    let mut s = String::with_capacity(count);
+    push_digits(rng, &mut s, count);
+    s
+}
+
+/// Append random decimal digits.
+fn push_digits(rng: &mut ForgeryRng, out: &mut String, count: usize) {
+    for _ in 0..count {
+        out.push(char::from(b'0' + rng.gen_range(0u8, 9)));
+    }
+}
+
+/// Random uppercase letters.
+fn letters(rng: &mut ForgeryRng, count: usize) -> String {
+    (0..count)
+        .map(|_| char::from(rng.gen_range(b'A', b'Z')))
+        .collect()
+}
+
+/// Random uppercase letters and digits.
+fn alphanumeric(rng: &mut ForgeryRng, count: usize) -> String {
+    const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    (0..count).map(|_| char::from(*rng.choose(CHARS))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(locale: Locale, region: Option<&str>, n: usize) -> Vec<String> {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        generate_drivers_licenses(&mut rng, locale, n, region).unwrap()
+    }
+
+    fn matches_shape(value: &str, shape: &str) -> bool {
+        // 'A' = letter, '9' = digit, 'X' = letter or digit, anything else literal
+        value.len() == shape.len()
+            && value.bytes().zip(shape.bytes()).all(|(v, s)| match s {
+                b'A' => v.is_ascii_uppercase(),
+                b'9' => v.is_ascii_digit(),
+                b'X' => v.is_ascii_uppercase() || v.is_ascii_digit(),
+                _ => v == s,
+            })
+    }
+
+    #[test]
+    fn test_validate_license_region() {
+        assert_eq!(validate_license_region("ca"), Ok("CA"));
+        assert_eq!(validate_license_region("ON"), Ok("ON"));
+        let err = validate_license_region("ZZ").unwrap_err();
+        assert_eq!(err.region, "ZZ");
+        assert!(err.to_string().contains("expected one of"));
+    }
+
+    #[test]
+    fn test_invalid_region_errors() {
+        let mut rng = ForgeryRng::new();
+        assert!(generate_drivers_licenses(&mut rng, Locale::EnUS, 5, Some("XX")).is_err());
+        assert!(generate_drivers_license(&mut rng, Locale::EnUS, Some("XX")).is_err());
+    }
+
+    #[test]
+    fn test_regional_formats() {
+        for (region, shape) in [
+            ("CA", "A9999999"),
+            ("AZ", "A99999999"),
+            ("FL", "A999999999999"),
+            ("IL", "A99999999999"),
+            ("MA", "S99999999"),
+            ("NJ", "A99999999999999"),
+            ("NY", "999999999"),
+            ("OH", "AA999999"),
+            ("TX", "99999999"),
+            ("WA", "WDLXXXXXXXXX"),
+            ("CO", "99-999-9999"),
+            ("AB", "999999-999"),
+            ("BC", "9999999"),
+            ("ON", "A9999-99999-99999"),
+            ("QC", "A9999-999999-99"),
+        ] {
+            for license in sample(Locale::EnUS, Some(region), 50) {
+                assert!(matches_shape(&license, shape), "{}: {}", region, license);
+            }
+        }
+    }
+
+    #[test]
+    fn test_region_overrides_locale() {
+        for license in sample(Locale::DeDE, Some("CA"), 50) {
+            assert!(matches_shape(&license, "A9999999"), "{}", license);
+        }
+    }
+
+    #[test]
+    fn test_national_formats() {
+        for (locale, shape) in [
+            (Locale::FrFR, "999999999999"),
+            (Locale::JaJP, "999999999999"),
+            (Locale::ItIT, "AA9999999A"),
+            (Locale::NlNL, "9999999999"),
+            (Locale::PlPL, "99999/99/9999"),
+            (Locale::RuRU, "99 99 999999"),
+            (Locale::KoKR, "99-99-999999-99"),
+            (Locale::DeDE, "XXXXXXXXX9X"),
+        ] {
+            for license in sample(locale, None, 50) {
+                assert!(matches_shape(&license, shape), "{}: {}", locale, license);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dvla_number() {
+        for license in sample(Locale::EnGB, None, 200) {
+            assert_eq!(license.len(), 16, "{}", license);
+            assert!(license[..5]
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b == b'9'));
+            assert!(license[5..11].bytes().all(|b| b.is_ascii_digit()));
+            let month: u8 = license[6..8].parse().unwrap();
+            assert!((1..=12).contains(&(month % 50)), "{}", license);
+            assert_eq!(&license[13..14], "9", "{}", license);
+            assert!(license[14..].bytes().all(|b| b.is_ascii_uppercase()));
+        }
+    }
+
+    #[test]
+    fn test_us_and_ca_locales_use_own_regions() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(7);
+        // Ontario is the only format with two hyphens and a leading letter
+        let ca = generate_drivers_licenses(&mut rng, Locale::EnCA, 200, None).unwrap();
+        assert!(ca.iter().any(|l| matches_shape(l, "A9999-99999-99999")));
+        let us = generate_drivers_licenses(&mut rng, Locale::EnUS, 200, None).unwrap();
+        assert!(us.iter().any(|l| l.starts_with("WDL")));
+        assert!(!us.iter().any(|l| matches_shape(l, "A9999-99999-99999")));
+    }
+
+    #[test]
+    fn test_drivers_licenses_count_and_determinism() {
+        for locale in Locale::ALL {
+            assert_eq!(sample(*locale, None, 50).len(), 50);
+            assert_eq!(sample(*locale, None, 20), sample(*locale, None, 20));
+        }
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_drivers_license_count(n in 0usize..200, locale_idx in 0usize..12) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(42);
+            let locale = Locale::ALL[locale_idx];
+            let licenses = generate_drivers_licenses(&mut rng, locale, n, None).unwrap();
+            prop_assert_eq!(licenses.len(), n);
+        }
+
+        #[test]
+        fn prop_drivers_license_seed_determinism(seed in any::<u64>(), locale_idx in 0usize..12) {
+            let locale = Locale::ALL[locale_idx];
+            let mut rng1 = ForgeryRng::new();
+            let mut rng2 = ForgeryRng::new();
+            rng1.seed(seed);
+            rng2.seed(seed);
+            prop_assert_eq!(
+                generate_drivers_licenses(&mut rng1, locale, 10, None).unwrap(),
+                generate_drivers_licenses(&mut rng2, locale, 10, None).unwrap()
+            );
+        }
+    }
+}
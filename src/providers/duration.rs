@@ -0,0 +1,301 @@
+//! Duration generation provider.
+//!
+//! Generates elapsed-time values for SLA and telemetry data, as ISO 8601
+//! durations (`PT2H15M`), short human-readable strings (`2h 15m`) or whole
+//! seconds.
+
+use crate::rng::ForgeryRng;
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Default minimum duration in seconds.
+pub const DEFAULT_MIN_DURATION: u64 = 0;
+
+/// Default maximum duration in seconds (one day).
+pub const DEFAULT_MAX_DURATION: u64 = 86_400;
+
+/// Output format for generated durations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DurationFormat {
+    /// ISO 8601 duration, e.g. `PT2H15M` or `P1DT4H`.
+    #[default]
+    Iso8601,
+    /// Short human-readable form, e.g. `2h 15m` or `1d 4h`.
+    Human,
+    /// Whole seconds as an integer.
+    Seconds,
+}
+
+/// Accepted duration format names.
+pub const DURATION_FORMATS: &[&str] = &["iso8601", "human", "seconds"];
+
+/// Error for an unknown duration format name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationFormatError {
+    /// The unrecognized format name.
+    pub format: String,
+}
+
+impl std::fmt::Display for DurationFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown duration format '{}'; expected one of: {}",
+            self.format,
+            DURATION_FORMATS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for DurationFormatError {}
+
+impl FromStr for DurationFormat {
+    type Err = DurationFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "iso8601" => Ok(DurationFormat::Iso8601),
+            "human" => Ok(DurationFormat::Human),
+            "seconds" => Ok(DurationFormat::Seconds),
+            _ => Err(DurationFormatError {
+                format: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Error for a duration range with min > max.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationRangeError {
+    /// The minimum duration in seconds.
+    pub min: u64,
+    /// The maximum duration in seconds.
+    pub max: u64,
+}
+
+impl std::fmt::Display for DurationRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid duration range: min ({}) must be less than or equal to max ({})",
+            self.min, self.max
+        )
+    }
+}
+
+impl std::error::Error for DurationRangeError {}
+
+/// Check that a duration range is ordered.
+///
+/// # Errors
+///
+/// Returns `DurationRangeError` if min > max.
+#[inline]
+pub fn validate_duration_range(min: u64, max: u64) -> Result<(), DurationRangeError> {
+    if min > max {
+        return Err(DurationRangeError { min, max });
+    }
+    Ok(())
+}
+
+/// Split whole seconds into days, hours, minutes and seconds.
+#[inline]
+fn components(seconds: u64) -> [(u64, char); 4] {
+    [
+        (seconds / 86_400, 'd'),
+        (seconds / 3_600 % 24, 'h'),
+        (seconds / 60 % 60, 'm'),
+        (seconds % 60, 's'),
+    ]
+}
+
+/// Format whole seconds as an ISO 8601 duration, omitting zero components.
+///
+/// Zero is `PT0S`; days are kept separate from hours (`P1DT2H`).
+pub fn format_iso8601(seconds: u64) -> String {
+    if seconds == 0 {
+        return "PT0S".to_string();
+    }
+    let [(days, _), time @ ..] = components(seconds);
+    let mut out = String::from("P");
+    if days > 0 {
+        let _ = write!(out, "{}D", days);
+    }
+    if time.iter().any(|&(value, _)| value > 0) {
+        out.push('T');
+        for (value, unit) in time {
+            if value > 0 {
+                let _ = write!(out, "{}{}", value, unit.to_ascii_uppercase());
+            }
+        }
+    }
+    out
+}
+
+/// Format whole seconds as a short human-readable duration, e.g. `2h 15m`.
+///
+/// Zero components are omitted and zero is `0s`.
+pub fn format_human(seconds: u64) -> String {
+    if seconds == 0 {
+        return "0s".to_string();
+    }
+    let parts: Vec<String> = components(seconds)
+        .into_iter()
+        .filter(|&(value, _)| value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect();
+    parts.join(" ")
+}
+
+/// Format whole seconds in the given duration format.
+#[inline]
+pub fn format_duration(seconds: u64, format: DurationFormat) -> String {
+    match format {
+        DurationFormat::Iso8601 => format_iso8601(seconds),
+        DurationFormat::Human => format_human(seconds),
+        DurationFormat::Seconds => seconds.to_string(),
+    }
+}
+
+/// Generate a batch of durations in whole seconds.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of durations to generate
+/// * `min` - Minimum duration in seconds (inclusive)
+/// * `max` - Maximum duration in seconds (inclusive)
+///
+/// # Errors
+///
+/// Returns `DurationRangeError` if min > max.
+pub fn generate_durations(
+    rng: &mut ForgeryRng,
+    n: usize,
+    min: u64,
+    max: u64,
+) -> Result<Vec<u64>, DurationRangeError> {
+    validate_duration_range(min, max)?;
+    let mut results = Vec::with_capacity(n);
+    for _ in 0..n {
+        results.push(rng.gen_range(min, max));
+    }
+    Ok(results)
+}
+
+/// Generate a single duration in whole seconds.
+///
+/// # Errors
+///
+/// Returns `DurationRangeError` if min > max.
+#[inline]
+pub fn generate_duration(
+    rng: &mut ForgeryRng,
+    min: u64,
+    max: u64,
+) -> Result<u64, DurationRangeError> {
+    validate_duration_range(min, max)?;
+    Ok(rng.gen_range(min, max))
+}
+
+/// Generate a batch of durations formatted as strings.
+///
+/// # Errors
+///
+/// Returns `DurationRangeError` if min > max.
+pub fn generate_formatted_durations(
+    rng: &mut ForgeryRng,
+    n: usize,
+    min: u64,
+    max: u64,
+    format: DurationFormat,
+) -> Result<Vec<String>, DurationRangeError> {
+    Ok(generate_durations(rng, n, min, max)?
+        .into_iter()
+        .map(|seconds| format_duration(seconds, format))
+        .collect())
+}
+
+/// Generate a single duration formatted as a string.
+///
+/// # Errors
+///
+/// Returns `DurationRangeError` if min > max.
+#[inline]
+pub fn generate_formatted_duration(
+    rng: &mut ForgeryRng,
+    min: u64,
+    max: u64,
+    format: DurationFormat,
+) -> Result<String, DurationRangeError> {
+    let seconds = generate_duration(rng, min, max)?;
+    Ok(format_duration(seconds, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_iso8601() {
+        assert_eq!(format_iso8601(0), "PT0S");
+        assert_eq!(format_iso8601(45), "PT45S");
+        assert_eq!(format_iso8601(8_100), "PT2H15M");
+        assert_eq!(format_iso8601(86_400), "P1D");
+        assert_eq!(format_iso8601(86_400 + 4 * 3_600 + 5), "P1DT4H5S");
+    }
+
+    #[test]
+    fn test_format_human() {
+        assert_eq!(format_human(0), "0s");
+        assert_eq!(format_human(8_100), "2h 15m");
+        assert_eq!(format_human(90_061), "1d 1h 1m 1s");
+    }
+
+    #[test]
+    fn test_duration_format_from_str() {
+        assert_eq!("iso8601".parse(), Ok(DurationFormat::Iso8601));
+        assert_eq!("Human".parse(), Ok(DurationFormat::Human));
+        assert_eq!("seconds".parse(), Ok(DurationFormat::Seconds));
+        let err = "minutes".parse::<DurationFormat>().unwrap_err();
+        assert!(err.to_string().contains("'minutes'"));
+    }
+
+    #[test]
+    fn test_durations_in_range() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let seconds = generate_durations(&mut rng, 500, 60, 3_600).unwrap();
+        assert_eq!(seconds.len(), 500);
+        assert!(seconds.iter().all(|s| (60..=3_600).contains(s)));
+
+        let fixed =
+            generate_formatted_duration(&mut rng, 8_100, 8_100, DurationFormat::Human).unwrap();
+        assert_eq!(fixed, "2h 15m");
+    }
+
+    #[test]
+    fn test_duration_invalid_range() {
+        let mut rng = ForgeryRng::new();
+        assert!(generate_formatted_durations(&mut rng, 1, 10, 5, DurationFormat::Iso8601).is_err());
+        assert_eq!(
+            generate_duration(&mut rng, 10, 5),
+            Err(DurationRangeError { min: 10, max: 5 })
+        );
+    }
+
+    #[test]
+    fn test_durations_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+        assert_eq!(
+            generate_formatted_durations(&mut rng1, 50, 0, 100_000, DurationFormat::Iso8601)
+                .unwrap(),
+            generate_formatted_durations(&mut rng2, 50, 0, 100_000, DurationFormat::Iso8601)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,633 @@
+//! File name, extension and path generation provider.
+//!
+//! File names are built from lorem words and an extension drawn from a
+//! category (e.g., `image` gives `.png`, `.jpg`, ...). Paths join a few
+//! directories with Unix (`/home/...`) or Windows (`C:\Users\...`) separators.
+//!
+//! | Category | Extensions |
+//! |----------|------------|
+//! | archive | `zip`, `tar`, `gz`, `7z`, `rar` |
+//! | audio | `mp3`, `wav`, `flac`, `ogg`, `m4a` |
+//! | code | `py`, `rs`, `js`, `ts`, `java`, `go`, `c`, `cpp`, `rb`, `sh` |
+//! | document | `pdf`, `doc`, `docx`, `xls`, `xlsx`, `ppt`, `pptx`, `odt` |
+//! | image | `png`, `jpg`, `jpeg`, `gif`, `bmp`, `svg`, `webp`, `tiff` |
+//! | text | `txt`, `csv`, `json`, `xml`, `html`, `md`, `log`, `yaml` |
+//! | video | `mp4`, `mov`, `avi`, `mkv`, `webm` |
+//!
+//! MIME types are grouped by their top-level type (application, audio, image,
+//! text, video). Without a category, application and image types are the most
+//! common, matching typical upload and storage workloads.
+
+use crate::data::en_us::LOREM_WORDS;
+use crate::rng::ForgeryRng;
+use std::str::FromStr;
+
+/// File categories with their extensions, sorted by category.
+pub const FILE_CATEGORIES: &[(&str, &[&str])] = &[
+    ("archive", &["zip", "tar", "gz", "7z", "rar"]),
+    ("audio", &["mp3", "wav", "flac", "ogg", "m4a"]),
+    (
+        "code",
+        &["py", "rs", "js", "ts", "java", "go", "c", "cpp", "rb", "sh"],
+    ),
+    (
+        "document",
+        &["pdf", "doc", "docx", "xls", "xlsx", "ppt", "pptx", "odt"],
+    ),
+    (
+        "image",
+        &["png", "jpg", "jpeg", "gif", "bmp", "svg", "webp", "tiff"],
+    ),
+    (
+        "text",
+        &["txt", "csv", "json", "xml", "html", "md", "log", "yaml"],
+    ),
+    ("video", &["mp4", "mov", "avi", "mkv", "webm"]),
+];
+
+/// Top-level directories for Unix paths.
+const UNIX_ROOTS: &[&str] = &["home", "opt", "srv", "tmp", "usr", "var"];
+
+/// Top-level directories for Windows paths (under `C:\`).
+const WINDOWS_ROOTS: &[&str] = &["Users", "Program Files", "ProgramData", "Temp", "Windows"];
+
+/// Default number of directories in a generated path.
+pub const DEFAULT_PATH_DEPTH: usize = 2;
+
+/// Error for a file category that is not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCategoryError {
+    /// The unsupported category.
+    pub category: String,
+}
+
+impl std::fmt::Display for FileCategoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let categories: Vec<&str> = FILE_CATEGORIES.iter().map(|c| c.0).collect();
+        write!(
+            f,
+            "unsupported file category '{}'; expected one of: {}",
+            self.category,
+            categories.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for FileCategoryError {}
+
+/// Look up the extensions of a file category (case-insensitive).
+///
+/// # Errors
+///
+/// Returns `FileCategoryError` if the category is not in `FILE_CATEGORIES`.
+pub fn category_extensions(category: &str) -> Result<&'static [&'static str], FileCategoryError> {
+    FILE_CATEGORIES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(category))
+        .map(|&(_, extensions)| extensions)
+        .ok_or_else(|| FileCategoryError {
+            category: category.to_string(),
+        })
+}
+
+/// Path separator style.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PathStyle {
+    /// Forward slashes from `/` (`/home/lorem/ipsum.txt`).
+    #[default]
+    Unix,
+    /// Backslashes from `C:\` (`C:\Users\lorem\ipsum.txt`).
+    Windows,
+}
+
+/// Accepted names for `PathStyle`, in declaration order.
+pub const PATH_STYLES: &[&str] = &["unix", "windows"];
+
+/// Error for an unknown path style name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathStyleError {
+    /// The unrecognized style name.
+    pub os: String,
+}
+
+impl std::fmt::Display for PathStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown path os '{}'; expected one of: {}",
+            self.os,
+            PATH_STYLES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for PathStyleError {}
+
+impl FromStr for PathStyle {
+    type Err = PathStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "unix" => Ok(PathStyle::Unix),
+            "windows" => Ok(PathStyle::Windows),
+            _ => Err(PathStyleError { os: s.to_string() }),
+        }
+    }
+}
+
+/// Pick an extension from the given category, or from any category.
+#[inline]
+pub(crate) fn extension_for(
+    rng: &mut ForgeryRng,
+    extensions: Option<&'static [&'static str]>,
+) -> &'static str {
+    let extensions = match extensions {
+        Some(extensions) => extensions,
+        None => rng.choose(FILE_CATEGORIES).1,
+    };
+    rng.choose::<&str>(extensions)
+}
+
+/// Generate a batch of file extensions (without the leading dot).
+///
+/// # Errors
+///
+/// Returns `FileCategoryError` if the category is not supported.
+pub fn generate_file_extensions(
+    rng: &mut ForgeryRng,
+    n: usize,
+    category: Option<&str>,
+) -> Result<Vec<String>, FileCategoryError> {
+    let extensions = category.map(category_extensions).transpose()?;
+    let mut result = Vec::with_capacity(n);
+    for _ in 0..n {
+        result.push(extension_for(rng, extensions).to_string());
+    }
+    Ok(result)
+}
+
+/// Generate a single file extension (e.g., "png").
+///
+/// # Errors
+///
+/// Returns `FileCategoryError` if the category is not supported.
+pub fn generate_file_extension(
+    rng: &mut ForgeryRng,
+    category: Option<&str>,
+) -> Result<String, FileCategoryError> {
+    let extensions = category.map(category_extensions).transpose()?;
+    Ok(extension_for(rng, extensions).to_string())
+}
+
+/// Build a file name from one or two lorem words and an extension.
+pub(crate) fn file_name_for(
+    rng: &mut ForgeryRng,
+    extensions: Option<&'static [&'static str]>,
+) -> String {
+    let mut name = String::from(*rng.choose(LOREM_WORDS));
+    if rng.gen_range(0, 1) == 1 {
+        let second = *rng.choose(LOREM_WORDS);
+        name.push('_');
+        name.push_str(second);
+    }
+    name.push('.');
+    name.push_str(extension_for(rng, extensions));
+    name
+}
+
+/// Generate a batch of file names.
+///
+/// # Errors
+///
+/// Returns `FileCategoryError` if the category is not supported.
+pub fn generate_file_names(
+    rng: &mut ForgeryRng,
+    n: usize,
+    category: Option<&str>,
+) -> Result<Vec<String>, FileCategoryError> {
+    let extensions = category.map(category_extensions).transpose()?;
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(file_name_for(rng, extensions));
+    }
+    Ok(names)
+}
+
+/// Generate a single file name (e.g., "dolor_sit.png").
+///
+/// # Errors
+///
+/// Returns `FileCategoryError` if the category is not supported.
+pub fn generate_file_name(
+    rng: &mut ForgeryRng,
+    category: Option<&str>,
+) -> Result<String, FileCategoryError> {
+    let extensions = category.map(category_extensions).transpose()?;
+    Ok(file_name_for(rng, extensions))
+}
+
+/// Build a path of `depth` directories ending in a file name.
+pub(crate) fn file_path_for(
+    rng: &mut ForgeryRng,
+    depth: usize,
+    style: PathStyle,
+    extensions: Option<&'static [&'static str]>,
+) -> String {
+    let (mut path, separator, roots) = match style {
+        PathStyle::Unix => (String::from("/"), '/', UNIX_ROOTS),
+        PathStyle::Windows => (String::from("C:\\"), '\\', WINDOWS_ROOTS),
+    };
+    for i in 0..depth {
+        let dir = if i == 0 {
+            *rng.choose(roots)
+        } else {
+            *rng.choose(LOREM_WORDS)
+        };
+        path.push_str(dir);
+        path.push(separator);
+    }
+    path.push_str(&file_name_for(rng, extensions));
+    path
+}
+
+/// Generate a batch of absolute file paths.
+///
+/// # Errors
+///
+/// Returns `FileCategoryError` if the category is not supported.
+pub fn generate_file_paths(
+    rng: &mut ForgeryRng,
+    n: usize,
+    depth: usize,
+    style: PathStyle,
+    category: Option<&str>,
+) -> Result<Vec<String>, FileCategoryError> {
+    let extensions = category.map(category_extensions).transpose()?;
+    let mut paths = Vec::with_capacity(n);
+    for _ in 0..n {
+        paths.push(file_path_for(rng, depth, style, extensions));
+    }
+    Ok(paths)
+}
+
+/// Generate a single absolute file path with `depth` directories.
+///
+/// The first directory is a conventional top-level one (`home`, `var`,
+/// `Users`, ...), the rest are lorem words, e.g. "/home/lorem/dolor_sit.png"
+/// or "C:\Users\lorem\dolor_sit.png".
+///
+/// # Errors
+///
+/// Returns `FileCategoryError` if the category is not supported.
+pub fn generate_file_path(
+    rng: &mut ForgeryRng,
+    depth: usize,
+    style: PathStyle,
+    category: Option<&str>,
+) -> Result<String, FileCategoryError> {
+    let extensions = category.map(category_extensions).transpose()?;
+    Ok(file_path_for(rng, depth, style, extensions))
+}
+
+// === MIME Types ===
+
+/// MIME types by top-level type, sorted by category.
+pub const MIME_TYPES: &[(&str, &[&str])] = &[
+    (
+        "application",
+        &[
+            "application/json",
+            "application/pdf",
+            "application/zip",
+            "application/xml",
+            "application/gzip",
+            "application/octet-stream",
+            "application/javascript",
+            "application/msword",
+            "application/vnd.ms-excel",
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        ],
+    ),
+    (
+        "audio",
+        &[
+            "audio/mpeg",
+            "audio/wav",
+            "audio/ogg",
+            "audio/flac",
+            "audio/aac",
+            "audio/mp4",
+        ],
+    ),
+    (
+        "image",
+        &[
+            "image/jpeg",
+            "image/png",
+            "image/gif",
+            "image/webp",
+            "image/svg+xml",
+            "image/bmp",
+            "image/tiff",
+        ],
+    ),
+    (
+        "text",
+        &[
+            "text/plain",
+            "text/html",
+            "text/csv",
+            "text/css",
+            "text/markdown",
+            "text/xml",
+        ],
+    ),
+    (
+        "video",
+        &[
+            "video/mp4",
+            "video/webm",
+            "video/quicktime",
+            "video/x-msvideo",
+            "video/x-matroska",
+        ],
+    ),
+];
+
+/// Category draws for MIME types without a category: application 4, image 3,
+/// text 2, video 1, audio 1.
+const MIME_CATEGORY_WEIGHTS: &[usize] = &[0, 0, 0, 0, 2, 2, 2, 3, 3, 4, 1];
+
+/// Error for a MIME category that is not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MimeCategoryError {
+    /// The unsupported category.
+    pub category: String,
+}
+
+impl std::fmt::Display for MimeCategoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let categories: Vec<&str> = MIME_TYPES.iter().map(|c| c.0).collect();
+        write!(
+            f,
+            "unsupported MIME category '{}'; expected one of: {}",
+            self.category,
+            categories.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for MimeCategoryError {}
+
+/// Look up the MIME types of a category (case-insensitive).
+///
+/// # Errors
+///
+/// Returns `MimeCategoryError` if the category is not in `MIME_TYPES`.
+pub fn category_mime_types(category: &str) -> Result<&'static [&'static str], MimeCategoryError> {
+    MIME_TYPES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(category))
+        .map(|&(_, types)| types)
+        .ok_or_else(|| MimeCategoryError {
+            category: category.to_string(),
+        })
+}
+
+/// Pick a MIME type from the given category, or from a weighted category.
+#[inline]
+pub(crate) fn mime_type_for(
+    rng: &mut ForgeryRng,
+    types: Option<&'static [&'static str]>,
+) -> &'static str {
+    let types = match types {
+        Some(types) => types,
+        None => MIME_TYPES[*rng.choose(MIME_CATEGORY_WEIGHTS)].1,
+    };
+    rng.choose::<&str>(types)
+}
+
+/// Generate a batch of MIME types.
+///
+/// # Errors
+///
+/// Returns `MimeCategoryError` if the category is not supported.
+pub fn generate_mime_types(
+    rng: &mut ForgeryRng,
+    n: usize,
+    category: Option<&str>,
+) -> Result<Vec<String>, MimeCategoryError> {
+    let types = category.map(category_mime_types).transpose()?;
+    let mut result = Vec::with_capacity(n);
+    for _ in 0..n {
+        result.push(mime_type_for(rng, types).to_string());
+    }
+    Ok(result)
+}
+
+/// Generate a single MIME type (e.g., "image/png").
+///
+/// # Errors
+///
+/// Returns `MimeCategoryError` if the category is not supported.
+pub fn generate_mime_type(
+    rng: &mut ForgeryRng,
+    category: Option<&str>,
+) -> Result<String, MimeCategoryError> {
+    let types = category.map(category_mime_types).transpose()?;
+    Ok(mime_type_for(rng, types).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extension_of(name: &str) -> &str {
+        name.rsplit_once('.').unwrap().1
+    }
+
+    #[test]
+    fn test_category_data() {
+        assert!(FILE_CATEGORIES.windows(2).all(|w| w[0].0 < w[1].0));
+        for (category, extensions) in FILE_CATEGORIES {
+            assert!(!extensions.is_empty(), "{}", category);
+            assert!(extensions.iter().all(|e| e
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())));
+        }
+    }
+
+    #[test]
+    fn test_category_extensions() {
+        assert!(category_extensions("image").unwrap().contains(&"png"));
+        assert!(category_extensions("IMAGE").unwrap().contains(&"png"));
+        let err = category_extensions("spreadsheet").unwrap_err();
+        assert_eq!(err.category, "spreadsheet");
+        assert!(err.to_string().contains("'spreadsheet'"));
+        assert!(err.to_string().contains("image"));
+    }
+
+    #[test]
+    fn test_path_style_parse() {
+        assert_eq!("unix".parse::<PathStyle>().unwrap(), PathStyle::Unix);
+        assert_eq!("Windows".parse::<PathStyle>().unwrap(), PathStyle::Windows);
+        let err = "macos".parse::<PathStyle>().unwrap_err();
+        assert!(err.to_string().contains("'macos'"));
+    }
+
+    #[test]
+    fn test_file_names() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let names = generate_file_names(&mut rng, 200, Some("image")).unwrap();
+        assert_eq!(names.len(), 200);
+        let images = category_extensions("image").unwrap();
+        for name in &names {
+            assert!(images.contains(&extension_of(name)), "{}", name);
+            assert!(!name.contains('/') && !name.contains(' '), "{}", name);
+        }
+        assert!(names.iter().any(|n| n.contains('_')));
+
+        let any = generate_file_names(&mut rng, 500, None).unwrap();
+        for name in &any {
+            let ext = extension_of(name);
+            assert!(
+                FILE_CATEGORIES.iter().any(|c| c.1.contains(&ext)),
+                "{}",
+                name
+            );
+        }
+
+        assert!(generate_file_name(&mut rng, Some("bogus")).is_err());
+    }
+
+    #[test]
+    fn test_file_extensions() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let audio = category_extensions("audio").unwrap();
+        for ext in generate_file_extensions(&mut rng, 100, Some("audio")).unwrap() {
+            assert!(audio.contains(&ext.as_str()));
+        }
+        let ext = generate_file_extension(&mut rng, None).unwrap();
+        assert!(!ext.starts_with('.'));
+    }
+
+    #[test]
+    fn test_unix_paths() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for depth in 0..5 {
+            for path in generate_file_paths(&mut rng, 50, depth, PathStyle::Unix, None).unwrap() {
+                assert!(path.starts_with('/'), "{}", path);
+                assert_eq!(path.matches('/').count(), depth + 1, "{}", path);
+                assert!(!path.contains('\\'));
+                if depth > 0 {
+                    let root = path[1..].split('/').next().unwrap();
+                    assert!(UNIX_ROOTS.contains(&root), "{}", path);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_windows_paths() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let docs = category_extensions("document").unwrap();
+        for path in
+            generate_file_paths(&mut rng, 100, 3, PathStyle::Windows, Some("document")).unwrap()
+        {
+            assert!(path.starts_with("C:\\"), "{}", path);
+            assert_eq!(path.matches('\\').count(), 4, "{}", path);
+            assert!(!path.contains('/'));
+            assert!(docs.contains(&extension_of(&path)), "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_mime_type_data() {
+        assert!(MIME_TYPES.windows(2).all(|w| w[0].0 < w[1].0));
+        for (category, types) in MIME_TYPES {
+            for mime in *types {
+                assert_eq!(mime.split_once('/').unwrap().0, *category, "{}", mime);
+            }
+        }
+        assert!(MIME_CATEGORY_WEIGHTS.iter().all(|&i| i < MIME_TYPES.len()));
+    }
+
+    #[test]
+    fn test_mime_types_by_category() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for (category, _) in MIME_TYPES {
+            for mime in generate_mime_types(&mut rng, 50, Some(category)).unwrap() {
+                assert!(mime.starts_with(&format!("{}/", category)), "{}", mime);
+            }
+        }
+        assert!(generate_mime_type(&mut rng, Some("IMAGE"))
+            .unwrap()
+            .starts_with("image/"));
+
+        let err = generate_mime_type(&mut rng, Some("font")).unwrap_err();
+        assert_eq!(err.category, "font");
+        assert!(err.to_string().contains("application"));
+    }
+
+    #[test]
+    fn test_mime_types_weighted() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let types = generate_mime_types(&mut rng, 5000, None).unwrap();
+        let count = |prefix: &str| types.iter().filter(|t| t.starts_with(prefix)).count();
+        let (application, image, text) = (count("application/"), count("image/"), count("text/"));
+        let (video, audio) = (count("video/"), count("audio/"));
+        assert_eq!(application + image + text + video + audio, 5000);
+        assert!(
+            application > image && image > text && text > video,
+            "{:?}",
+            (application, image, text, video)
+        );
+        assert!(audio > 0);
+    }
+
+    #[test]
+    fn test_filesystem_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_file_paths(&mut rng1, 50, 2, PathStyle::Unix, None).unwrap(),
+            generate_file_paths(&mut rng2, 50, 2, PathStyle::Unix, None).unwrap()
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_file_path_depth(seed in any::<u64>(), n in 0usize..50, depth in 0usize..8) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let paths = generate_file_paths(&mut rng, n, depth, PathStyle::Unix, None).unwrap();
+            prop_assert_eq!(paths.len(), n);
+            for path in paths {
+                prop_assert_eq!(path.matches('/').count(), depth + 1);
+            }
+        }
+    }
+}
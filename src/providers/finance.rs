@@ -1,26 +1,81 @@
 //! Finance-related data generation provider.
 //!
 //! Generates credit card numbers (with valid Luhn checksum), IBANs,
-//! BIC/SWIFT codes, bank account numbers, and bank names.
+//! BIC/SWIFT codes, ABA routing numbers, bank account numbers, bank names,
+//! ISO 4217 currencies, formatted prices, and VAT numbers.
 
+use crate::data::en_us::CURRENCIES;
 use crate::data::get_locale_data;
 use crate::locale::Locale;
 use crate::rng::ForgeryRng;
-
-/// Credit card prefixes (IIN ranges) for major card networks.
-const CARD_PREFIXES: &[(&str, usize)] = &[
-    ("4", 16),    // Visa
-    ("51", 16),   // Mastercard
-    ("52", 16),   // Mastercard
-    ("53", 16),   // Mastercard
-    ("54", 16),   // Mastercard
-    ("55", 16),   // Mastercard
-    ("34", 15),   // American Express
-    ("37", 15),   // American Express
-    ("6011", 16), // Discover
-    ("65", 16),   // Discover
+use std::str::FromStr;
+
+/// Credit card prefixes (IIN ranges) and lengths for major card networks.
+const CARD_PREFIXES: &[(&str, usize, CardNetwork)] = &[
+    ("4", 16, CardNetwork::Visa),
+    ("51", 16, CardNetwork::Mastercard),
+    ("52", 16, CardNetwork::Mastercard),
+    ("53", 16, CardNetwork::Mastercard),
+    ("54", 16, CardNetwork::Mastercard),
+    ("55", 16, CardNetwork::Mastercard),
+    ("34", 15, CardNetwork::Amex),
+    ("37", 15, CardNetwork::Amex),
+    ("6011", 16, CardNetwork::Discover),
+    ("65", 16, CardNetwork::Discover),
 ];
 
+/// Credit card network (IIN family).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardNetwork {
+    /// Visa (prefix 4, 16 digits).
+    Visa,
+    /// Mastercard (prefixes 51-55, 16 digits).
+    Mastercard,
+    /// American Express (prefixes 34 and 37, 15 digits).
+    Amex,
+    /// Discover (prefixes 6011 and 65, 16 digits).
+    Discover,
+}
+
+/// Accepted names for `CardNetwork`, in declaration order.
+pub const CARD_NETWORKS: &[&str] = &["visa", "mastercard", "amex", "discover"];
+
+/// Error for an unknown card network name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardNetworkError {
+    /// The unrecognized network name.
+    pub network: String,
+}
+
+impl std::fmt::Display for CardNetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown card network '{}'; expected one of: {}",
+            self.network,
+            CARD_NETWORKS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for CardNetworkError {}
+
+impl FromStr for CardNetwork {
+    type Err = CardNetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "visa" => Ok(CardNetwork::Visa),
+            "mastercard" => Ok(CardNetwork::Mastercard),
+            "amex" => Ok(CardNetwork::Amex),
+            "discover" => Ok(CardNetwork::Discover),
+            _ => Err(CardNetworkError {
+                network: s.to_string(),
+            }),
+        }
+    }
+}
+
 /// Country codes and BBAN lengths for IBAN generation.
 const IBAN_COUNTRIES: &[(&str, usize)] = &[
     ("DE", 18), // Germany
@@ -35,9 +90,20 @@ const IBAN_COUNTRIES: &[(&str, usize)] = &[
     ("PL", 24), // Poland
 ];
 
+/// ABA routing number prefixes: Federal Reserve districts (01-12), thrift
+/// institutions (21-32), electronic transactions (61-72) and traveler's
+/// checks (80).
+const ROUTING_PREFIXES: &[u8] = &[
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 61, 62,
+    63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 80,
+];
+
+/// ABA routing number checksum weights.
+const ROUTING_WEIGHTS: [u32; 9] = [3, 7, 1, 3, 7, 1, 3, 7, 1];
+
 /// Calculate Luhn checksum digit for a partial number string.
 /// The returned digit should be appended to make a valid Luhn number.
-fn luhn_checksum(number: &str) -> u8 {
+pub(crate) fn luhn_checksum(number: &str) -> u8 {
     let mut sum: u32 = 0;
     // When calculating check digit, the rightmost digit of the partial number
     // will be doubled (because the check digit we append won't be doubled).
@@ -92,17 +158,48 @@ pub fn validate_luhn(number: &str) -> bool {
 
 /// Generate a batch of credit card numbers with valid Luhn checksums.
 pub fn generate_credit_cards(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    generate_credit_cards_for_network(rng, n, None)
+}
+
+/// Generate a single credit card number with a valid Luhn checksum.
+#[inline]
+pub fn generate_credit_card(rng: &mut ForgeryRng) -> String {
+    generate_credit_card_for_network(rng, None)
+}
+
+/// Generate a batch of credit card numbers, optionally from one network.
+///
+/// With `network` set to `None` the prefix is drawn from every network.
+pub fn generate_credit_cards_for_network(
+    rng: &mut ForgeryRng,
+    n: usize,
+    network: Option<CardNetwork>,
+) -> Vec<String> {
     let mut cards = Vec::with_capacity(n);
     for _ in 0..n {
-        cards.push(generate_credit_card(rng));
+        cards.push(generate_credit_card_for_network(rng, network));
     }
     cards
 }
 
-/// Generate a single credit card number with a valid Luhn checksum.
+/// Generate a single credit card number, optionally from one network.
 #[inline]
-pub fn generate_credit_card(rng: &mut ForgeryRng) -> String {
-    let (prefix, total_length) = rng.choose(CARD_PREFIXES);
+pub fn generate_credit_card_for_network(
+    rng: &mut ForgeryRng,
+    network: Option<CardNetwork>,
+) -> String {
+    let (prefix, total_length, _) = match network {
+        None => rng.choose(CARD_PREFIXES),
+        Some(network) => {
+            let count = CARD_PREFIXES.iter().filter(|p| p.2 == network).count();
+            let index = rng.gen_range(0, count - 1);
+            CARD_PREFIXES
+                .iter()
+                .filter(|p| p.2 == network)
+                .nth(index)
+                .expect("index within network prefixes")
+        }
+    };
 
     // Generate random digits (excluding the check digit)
     let random_length = total_length - prefix.len() - 1;
@@ -304,6 +401,54 @@ pub fn generate_bank_account(rng: &mut ForgeryRng) -> String {
     account
 }
 
+/// Validate an ABA routing number checksum.
+///
+/// The number must be exactly nine ASCII digits whose weighted sum
+/// (weights 3, 7, 1 repeating) is a multiple of 10.
+pub fn validate_routing_number(number: &str) -> bool {
+    if number.len() != 9 || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    let sum: u32 = number
+        .bytes()
+        .zip(ROUTING_WEIGHTS)
+        .map(|(b, w)| u32::from(b - b'0') * w)
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Generate a batch of ABA routing numbers with valid checksums.
+pub fn generate_routing_numbers(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut numbers = Vec::with_capacity(n);
+    for _ in 0..n {
+        numbers.push(generate_routing_number(rng));
+    }
+    numbers
+}
+
+/// Generate a single 9-digit ABA routing number with a valid checksum.
+///
+/// The first two digits are an assigned routing prefix and the third a
+/// Federal Reserve office (1-9).
+#[inline]
+pub fn generate_routing_number(rng: &mut ForgeryRng) -> String {
+    let prefix = rng.choose(ROUTING_PREFIXES);
+    let mut number = format!("{:02}", prefix);
+    number.push((b'0' + rng.gen_range(1u8, 9)) as char);
+    for _ in 0..5 {
+        number.push((b'0' + rng.gen_range(0u8, 9)) as char);
+    }
+
+    let sum: u32 = number
+        .bytes()
+        .zip(ROUTING_WEIGHTS)
+        .map(|(b, w)| u32::from(b - b'0') * w)
+        .sum();
+    number.push((b'0' + ((10 - sum % 10) % 10) as u8) as char);
+
+    number
+}
+
 /// Generate a batch of bank names using locale-specific data.
 pub fn generate_bank_names(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
     let data = get_locale_data(locale);
@@ -392,6 +537,648 @@ pub fn generate_uk_account_number(rng: &mut ForgeryRng) -> String {
     account
 }
 
+// === Currencies ===
+
+/// Pick a random (code, name, symbol) currency entry.
+#[inline]
+fn choose_currency(rng: &mut ForgeryRng) -> &'static (&'static str, &'static str, &'static str) {
+    rng.choose(CURRENCIES)
+}
+
+/// Generate a batch of ISO 4217 currency codes.
+pub fn generate_currency_codes(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut codes = Vec::with_capacity(n);
+    for _ in 0..n {
+        codes.push(generate_currency_code(rng));
+    }
+    codes
+}
+
+/// Generate a single ISO 4217 currency code (e.g., "EUR").
+#[inline]
+pub fn generate_currency_code(rng: &mut ForgeryRng) -> String {
+    choose_currency(rng).0.to_string()
+}
+
+/// Generate a batch of currency names.
+pub fn generate_currency_names(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_currency_name(rng));
+    }
+    names
+}
+
+/// Generate a single currency name (e.g., "Euro").
+#[inline]
+pub fn generate_currency_name(rng: &mut ForgeryRng) -> String {
+    choose_currency(rng).1.to_string()
+}
+
+/// Generate a batch of currency symbols.
+pub fn generate_currency_symbols(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut symbols = Vec::with_capacity(n);
+    for _ in 0..n {
+        symbols.push(generate_currency_symbol(rng));
+    }
+    symbols
+}
+
+/// Generate a single currency symbol (e.g., "€").
+#[inline]
+pub fn generate_currency_symbol(rng: &mut ForgeryRng) -> String {
+    choose_currency(rng).2.to_string()
+}
+
+// === Prices ===
+
+/// ISO 4217 currencies without minor units.
+const ZERO_DECIMAL_CURRENCIES: &[&str] = &["CLP", "ISK", "JPY", "KRW", "VND"];
+
+/// A known ISO 4217 currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Currency {
+    /// ISO 4217 code (e.g., "EUR").
+    pub code: &'static str,
+    /// English name.
+    pub name: &'static str,
+    /// Currency symbol.
+    pub symbol: &'static str,
+}
+
+impl Currency {
+    /// US dollar, the default currency for prices.
+    pub const USD: Currency = Currency {
+        code: "USD",
+        name: "US Dollar",
+        symbol: "$",
+    };
+
+    /// Number of minor-unit digits (2 for most currencies, 0 for JPY, KRW, ...).
+    pub fn decimals(&self) -> u32 {
+        if ZERO_DECIMAL_CURRENCIES.contains(&self.code) {
+            0
+        } else {
+            2
+        }
+    }
+}
+
+/// Error for a currency code that is not in the ISO 4217 data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyError {
+    /// The unrecognized currency code.
+    pub currency: String,
+}
+
+impl std::fmt::Display for CurrencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown currency '{}'; expected an ISO 4217 code such as USD, EUR or JPY",
+            self.currency
+        )
+    }
+}
+
+impl std::error::Error for CurrencyError {}
+
+impl FromStr for Currency {
+    type Err = CurrencyError;
+
+    /// Look up a currency by ISO 4217 code (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CURRENCIES
+            .iter()
+            .find(|(code, _, _)| code.eq_ignore_ascii_case(s))
+            .map(|&(code, name, symbol)| Currency { code, name, symbol })
+            .ok_or_else(|| CurrencyError {
+                currency: s.to_string(),
+            })
+    }
+}
+
+/// Check a price range, returning it in the currency's minor units.
+fn price_range_units(
+    min: f64,
+    max: f64,
+    currency: Currency,
+) -> Result<(i64, i64), crate::providers::numbers::FloatRangeError> {
+    use crate::providers::numbers::{FloatRangeError, FloatRangeErrorReason};
+
+    if !min.is_finite() || !max.is_finite() {
+        return Err(FloatRangeError {
+            min,
+            max,
+            reason: FloatRangeErrorReason::NonFiniteValue,
+        });
+    }
+    if min > max {
+        return Err(FloatRangeError {
+            min,
+            max,
+            reason: FloatRangeErrorReason::MinGreaterThanMax,
+        });
+    }
+
+    let scale = 10f64.powi(currency.decimals() as i32);
+    let min_units = (min * scale).ceil() as i64;
+    // A range narrower than one minor unit collapses to its lower bound
+    let max_units = ((max * scale).floor() as i64).max(min_units);
+    Ok((min_units, max_units))
+}
+
+/// Draw an amount in minor units and convert it back to a decimal value.
+#[inline]
+fn price_from_units(rng: &mut ForgeryRng, (min, max): (i64, i64), currency: Currency) -> f64 {
+    let scale = 10f64.powi(currency.decimals() as i32);
+    rng.gen_range(min, max) as f64 / scale
+}
+
+/// Generate a batch of prices rounded to the currency's minor unit.
+///
+/// # Errors
+///
+/// Returns `FloatRangeError` if `min > max` or if either value is NaN or infinity.
+pub fn generate_prices(
+    rng: &mut ForgeryRng,
+    n: usize,
+    min: f64,
+    max: f64,
+    currency: Currency,
+) -> Result<Vec<f64>, crate::providers::numbers::FloatRangeError> {
+    let units = price_range_units(min, max, currency)?;
+    let mut prices = Vec::with_capacity(n);
+    for _ in 0..n {
+        prices.push(price_from_units(rng, units, currency));
+    }
+    Ok(prices)
+}
+
+/// Generate a single price rounded to the currency's minor unit.
+///
+/// # Errors
+///
+/// Returns `FloatRangeError` if `min > max` or if either value is NaN or infinity.
+#[inline]
+pub fn generate_price(
+    rng: &mut ForgeryRng,
+    min: f64,
+    max: f64,
+    currency: Currency,
+) -> Result<f64, crate::providers::numbers::FloatRangeError> {
+    let units = price_range_units(min, max, currency)?;
+    Ok(price_from_units(rng, units, currency))
+}
+
+/// Generate a batch of prices formatted for the locale and currency.
+///
+/// # Errors
+///
+/// Returns `FloatRangeError` if `min > max` or if either value is NaN or infinity.
+pub fn generate_formatted_prices(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    min: f64,
+    max: f64,
+    currency: Currency,
+) -> Result<Vec<String>, crate::providers::numbers::FloatRangeError> {
+    let units = price_range_units(min, max, currency)?;
+    let mut prices = Vec::with_capacity(n);
+    for _ in 0..n {
+        let amount = price_from_units(rng, units, currency);
+        prices.push(format_price(amount, currency, locale));
+    }
+    Ok(prices)
+}
+
+/// Generate a single price formatted for the locale and currency.
+///
+/// # Errors
+///
+/// Returns `FloatRangeError` if `min > max` or if either value is NaN or infinity.
+#[inline]
+pub fn generate_formatted_price(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    min: f64,
+    max: f64,
+    currency: Currency,
+) -> Result<String, crate::providers::numbers::FloatRangeError> {
+    let amount = generate_price(rng, min, max, currency)?;
+    Ok(format_price(amount, currency, locale))
+}
+
+/// Format an amount with the locale's separators and currency placement.
+///
+/// Examples: `$1,234.56` (en_US), `1.234,56 €` (de_DE), `€ 1.234,56`
+/// (nl_NL), `¥1,235` (ja_JP with JPY).
+pub fn format_price(amount: f64, currency: Currency, locale: Locale) -> String {
+    let (decimal, group, template) = match get_locale_data(locale).number_format() {
+        Some(f) => (f.decimal, f.group, f.currency),
+        None => (".", ",", "{symbol}{amount}"),
+    };
+
+    let digits = format!("{:.*}", currency.decimals() as usize, amount.abs());
+    let (int_part, frac_part) = match digits.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (digits.as_str(), None),
+    };
+
+    let mut number = String::with_capacity(digits.len() + int_part.len() / 3 + 1);
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i).is_multiple_of(3) {
+            number.push_str(group);
+        }
+        number.push(c);
+    }
+    if let Some(frac) = frac_part {
+        number.push_str(decimal);
+        number.push_str(frac);
+    }
+
+    let formatted = template
+        .replace("{amount}", &number)
+        .replace("{symbol}", currency.symbol);
+    // Negative zero after rounding is shown without a sign
+    if amount < 0.0 && digits.bytes().any(|b| (b'1'..=b'9').contains(&b)) {
+        format!("-{}", formatted)
+    } else {
+        formatted
+    }
+}
+
+// === Stock Tickers ===
+
+/// Stock exchanges that listings are drawn from.
+pub const STOCK_EXCHANGES: &[&str] = &["NYSE", "NASDAQ", "LSE"];
+
+/// Ticker lengths for unpaired tickers, weighted toward three and four letters.
+const TICKER_LENGTHS: &[usize] = &[1, 2, 3, 3, 3, 4, 4, 4, 4, 5];
+
+/// A company listed on a stock exchange.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StockListing {
+    /// Ticker symbol (1-5 uppercase letters)
+    pub ticker: String,
+    /// Listed company name
+    pub company: String,
+    /// Exchange the company trades on (e.g., "NASDAQ")
+    pub exchange: String,
+}
+
+/// Random uppercase ASCII letters.
+fn random_uppercase(rng: &mut ForgeryRng, len: usize) -> String {
+    (0..len)
+        .map(|_| char::from(rng.gen_range(b'A', b'Z')))
+        .collect()
+}
+
+/// Ticker length range used by an exchange.
+///
+/// NYSE symbols run 1-4 letters, NASDAQ symbols 4-5, and LSE TIDMs 3-4.
+fn ticker_length_range(exchange: &str) -> (usize, usize) {
+    match exchange {
+        "NYSE" => (1, 4),
+        "NASDAQ" => (4, 5),
+        _ => (3, 4),
+    }
+}
+
+/// Generate a batch of stock ticker symbols.
+pub fn generate_tickers(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut tickers = Vec::with_capacity(n);
+    for _ in 0..n {
+        tickers.push(generate_ticker(rng));
+    }
+    tickers
+}
+
+/// Generate a single stock ticker symbol (e.g., "KMBR").
+#[inline]
+pub fn generate_ticker(rng: &mut ForgeryRng) -> String {
+    let len = *rng.choose(TICKER_LENGTHS);
+    random_uppercase(rng, len)
+}
+
+/// Generate a batch of stock exchange names.
+pub fn generate_stock_exchanges(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut exchanges = Vec::with_capacity(n);
+    for _ in 0..n {
+        exchanges.push(generate_stock_exchange(rng));
+    }
+    exchanges
+}
+
+/// Generate a single stock exchange name.
+#[inline]
+pub fn generate_stock_exchange(rng: &mut ForgeryRng) -> String {
+    rng.choose(STOCK_EXCHANGES).to_string()
+}
+
+/// Generate a batch of stock listings for the given locale.
+pub fn generate_stock_listings(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+) -> Vec<StockListing> {
+    let mut listings = Vec::with_capacity(n);
+    for _ in 0..n {
+        listings.push(generate_stock_listing(rng, locale));
+    }
+    listings
+}
+
+/// Generate a single stock listing for the given locale.
+///
+/// The ticker is built from the leading letters of the company name where the
+/// name is written in Latin script, padded with random letters to a length
+/// the exchange uses.
+pub fn generate_stock_listing(rng: &mut ForgeryRng, locale: Locale) -> StockListing {
+    use crate::providers::company::generate_company;
+
+    let company = generate_company(rng, locale);
+    let exchange = *rng.choose(STOCK_EXCHANGES);
+    let (min, max) = ticker_length_range(exchange);
+    let len = rng.gen_range(min, max);
+
+    let mut ticker: String = company
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .take(len)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let missing = len - ticker.len();
+    ticker.push_str(&random_uppercase(rng, missing));
+
+    StockListing {
+        ticker,
+        company,
+        exchange: exchange.to_string(),
+    }
+}
+
+// === VAT Numbers ===
+
+/// Country codes supported by VAT number generation.
+pub const VAT_COUNTRIES: &[&str] = &["AT", "BE", "DE", "ES", "FR", "GB", "IT", "NL", "PL", "PT"];
+
+/// Error for a VAT country code that is not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VatCountryError {
+    /// The unsupported country code.
+    pub country: String,
+}
+
+impl std::fmt::Display for VatCountryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unsupported VAT country '{}'; expected one of: {}",
+            self.country,
+            VAT_COUNTRIES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for VatCountryError {}
+
+/// Validate a VAT country code (case-insensitive) and return its canonical form.
+///
+/// # Errors
+///
+/// Returns `VatCountryError` if the country is not in `VAT_COUNTRIES`.
+pub fn validate_vat_country(country: &str) -> Result<&'static str, VatCountryError> {
+    VAT_COUNTRIES
+        .iter()
+        .find(|c| c.eq_ignore_ascii_case(country))
+        .copied()
+        .ok_or_else(|| VatCountryError {
+            country: country.to_string(),
+        })
+}
+
+/// The VAT country matching a locale, if the locale's country has VAT numbers.
+fn vat_country_for_locale(locale: Locale) -> Option<&'static str> {
+    match locale {
+        Locale::DeDE => Some("DE"),
+        Locale::FrFR => Some("FR"),
+        Locale::EsES => Some("ES"),
+        Locale::ItIT => Some("IT"),
+        Locale::EnGB => Some("GB"),
+        Locale::NlNL => Some("NL"),
+        Locale::PlPL => Some("PL"),
+        _ => None,
+    }
+}
+
+/// Random decimal digits (each 0-9).
+fn random_digits(rng: &mut ForgeryRng, count: usize) -> Vec<u32> {
+    (0..count).map(|_| rng.gen_range(0u32, 9)).collect()
+}
+
+/// Join digits into a string.
+fn digits_to_string(digits: &[u32]) -> String {
+    digits
+        .iter()
+        .map(|&d| char::from_digit(d, 10).expect("digit"))
+        .collect()
+}
+
+/// Generate a batch of VAT numbers.
+///
+/// With `country` set, every number is for that country. Otherwise the
+/// locale's country is used when it has VAT numbers, and a random supported
+/// country is picked for each number when it does not.
+///
+/// # Errors
+///
+/// Returns `VatCountryError` if `country` is not supported.
+pub fn generate_vat_numbers(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    country: Option<&str>,
+) -> Result<Vec<String>, VatCountryError> {
+    let country = country.map(validate_vat_country).transpose()?;
+    let mut numbers = Vec::with_capacity(n);
+    for _ in 0..n {
+        numbers.push(vat_number_for(rng, locale, country));
+    }
+    Ok(numbers)
+}
+
+/// Generate a single VAT number.
+///
+/// See [`generate_vat_numbers`] for how the country is chosen.
+///
+/// # Errors
+///
+/// Returns `VatCountryError` if `country` is not supported.
+#[inline]
+pub fn generate_vat_number(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    country: Option<&str>,
+) -> Result<String, VatCountryError> {
+    let country = country.map(validate_vat_country).transpose()?;
+    Ok(vat_number_for(rng, locale, country))
+}
+
+/// Generate a VAT number for an already validated country.
+pub(crate) fn vat_number_for(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    country: Option<&'static str>,
+) -> String {
+    let country = match country.or_else(|| vat_country_for_locale(locale)) {
+        Some(c) => c,
+        None => rng.choose(VAT_COUNTRIES),
+    };
+    match country {
+        "AT" => generate_at_vat(rng),
+        "BE" => generate_be_vat(rng),
+        "DE" => generate_de_vat(rng),
+        "ES" => generate_es_vat(rng),
+        "FR" => generate_fr_vat(rng),
+        "GB" => generate_gb_vat(rng),
+        "IT" => generate_it_vat(rng),
+        "NL" => generate_nl_vat(rng),
+        "PL" => generate_pl_vat(rng),
+        _ => generate_pt_vat(rng),
+    }
+}
+
+/// Austria: `ATU` + 7 digits + check digit.
+fn generate_at_vat(rng: &mut ForgeryRng) -> String {
+    let digits = random_digits(rng, 7);
+    let sum: u32 = digits
+        .iter()
+        .zip([1, 2, 1, 2, 1, 2, 1])
+        .map(|(d, w)| {
+            let p = d * w;
+            p / 10 + p % 10
+        })
+        .sum();
+    let check = (10 - (sum + 4) % 10) % 10;
+    format!("ATU{}{}", digits_to_string(&digits), check)
+}
+
+/// Belgium: `BE` + 10 digits starting with 0 or 1, mod 97 check.
+fn generate_be_vat(rng: &mut ForgeryRng) -> String {
+    let base: u32 = rng.gen_range(0, 19_999_999);
+    format!("BE{:08}{:02}", base, 97 - base % 97)
+}
+
+/// Germany: `DE` + 8 digits + ISO 7064 MOD 11,10 check digit.
+fn generate_de_vat(rng: &mut ForgeryRng) -> String {
+    let mut digits = vec![rng.gen_range(1u32, 9)];
+    digits.extend(random_digits(rng, 7));
+    digits.push(crate::providers::national_id::mod_11_10_check_digit(
+        &digits,
+    ));
+    format!("DE{}", digits_to_string(&digits))
+}
+
+/// Spain: `ES` + CIF (entity letter, 7 digits, control digit or letter).
+fn generate_es_vat(rng: &mut ForgeryRng) -> String {
+    const ENTITY_LETTERS: &[u8] = b"ABCDEFGHJNPQRSUVW";
+    let letter = *rng.choose(ENTITY_LETTERS);
+    let digits = random_digits(rng, 7);
+    let sum: u32 = digits
+        .iter()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 0 {
+                let p = d * 2;
+                p / 10 + p % 10
+            } else {
+                d
+            }
+        })
+        .sum();
+    let control = (10 - sum % 10) % 10;
+    // Public bodies and non-resident entities use a control letter
+    let control = if b"NPQRSW".contains(&letter) {
+        char::from(b"JABCDEFGHI"[control as usize])
+    } else {
+        char::from_digit(control, 10).expect("digit")
+    };
+    format!(
+        "ES{}{}{}",
+        char::from(letter),
+        digits_to_string(&digits),
+        control
+    )
+}
+
+/// France: `FR` + 2-digit key + SIREN (9 digits, Luhn-valid).
+fn generate_fr_vat(rng: &mut ForgeryRng) -> String {
+    let mut siren = digits_to_string(&random_digits(rng, 8));
+    siren.push(char::from(b'0' + luhn_checksum(&siren)));
+    let value: u64 = siren.parse().expect("SIREN digits");
+    let key = (12 + 3 * (value % 97)) % 97;
+    format!("FR{:02}{}", key, siren)
+}
+
+/// United Kingdom: `GB` + 7 digits + 2-digit mod 97 check.
+fn generate_gb_vat(rng: &mut ForgeryRng) -> String {
+    let digits = random_digits(rng, 7);
+    let sum: u32 = digits.iter().zip((2..=8).rev()).map(|(d, w)| d * w).sum();
+    let check = (97 - sum % 97) % 97;
+    format!("GB{}{:02}", digits_to_string(&digits), check)
+}
+
+/// Italy: `IT` + 7-digit company number + 3-digit office code + Luhn check.
+fn generate_it_vat(rng: &mut ForgeryRng) -> String {
+    let company = digits_to_string(&random_digits(rng, 7));
+    let office: u32 = rng.gen_range(1, 100);
+    let mut number = format!("{}{:03}", company, office);
+    number.push(char::from(b'0' + luhn_checksum(&number)));
+    format!("IT{}", number)
+}
+
+/// Netherlands: `NL` + 9 digits passing the 11-test + `B` + 2-digit suffix.
+fn generate_nl_vat(rng: &mut ForgeryRng) -> String {
+    loop {
+        let digits = random_digits(rng, 8);
+        let sum: u32 = digits.iter().zip((2..=9).rev()).map(|(d, w)| d * w).sum();
+        let check = sum % 11;
+        if check < 10 {
+            let suffix: u8 = rng.gen_range(1, 3);
+            return format!("NL{}{}B{:02}", digits_to_string(&digits), check, suffix);
+        }
+    }
+}
+
+/// Poland: `PL` + NIP (9 digits + weighted mod 11 check).
+fn generate_pl_vat(rng: &mut ForgeryRng) -> String {
+    const WEIGHTS: [u32; 9] = [6, 5, 7, 2, 3, 4, 5, 6, 7];
+    loop {
+        let mut digits = vec![rng.gen_range(1u32, 9)];
+        digits.extend(random_digits(rng, 8));
+        let sum: u32 = digits.iter().zip(WEIGHTS).map(|(d, w)| d * w).sum();
+        let check = sum % 11;
+        if check < 10 {
+            return format!("PL{}{}", digits_to_string(&digits), check);
+        }
+    }
+}
+
+/// Portugal: `PT` + NIF (8 digits + mod 11 check).
+fn generate_pt_vat(rng: &mut ForgeryRng) -> String {
+    // Company NIFs start with 5
+    let mut digits = vec![5];
+    digits.extend(random_digits(rng, 7));
+    let sum: u32 = digits.iter().zip((2..=9).rev()).map(|(d, w)| d * w).sum();
+    let check = match 11 - sum % 11 {
+        10 | 11 => 0,
+        c => c,
+    };
+    format!("PT{}{}", digits_to_string(&digits), check)
+}
+
 // === Financial Transaction Data ===
 
 /// Debit transaction types (money going out).
@@ -682,6 +1469,7 @@ pub fn generate_transaction_amounts(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     // Credit card tests
     #[test]
@@ -754,6 +1542,54 @@ mod tests {
         assert_eq!(c1, c2);
     }
 
+    #[test]
+    fn test_credit_card_network_filter() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let cases: [(CardNetwork, &[&str], usize); 4] = [
+            (CardNetwork::Visa, &["4"], 16),
+            (CardNetwork::Mastercard, &["51", "52", "53", "54", "55"], 16),
+            (CardNetwork::Amex, &["34", "37"], 15),
+            (CardNetwork::Discover, &["6011", "65"], 16),
+        ];
+        for (network, prefixes, length) in cases {
+            let cards = generate_credit_cards_for_network(&mut rng, 200, Some(network));
+            for card in &cards {
+                assert_eq!(card.len(), length, "{}", card);
+                assert!(prefixes.iter().any(|p| card.starts_with(p)), "{}", card);
+                assert!(validate_luhn(card), "{}", card);
+            }
+            // Every prefix of the network is used
+            for prefix in prefixes {
+                assert!(cards.iter().any(|c| c.starts_with(prefix)), "{}", prefix);
+            }
+        }
+    }
+
+    #[test]
+    fn test_credit_card_without_network_matches_default() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+
+        assert_eq!(
+            generate_credit_cards(&mut rng1, 50),
+            generate_credit_cards_for_network(&mut rng2, 50, None)
+        );
+    }
+
+    #[test]
+    fn test_card_network_from_str() {
+        for name in CARD_NETWORKS {
+            assert!(name.parse::<CardNetwork>().is_ok());
+        }
+        let err = "diners".parse::<CardNetwork>().unwrap_err();
+        assert_eq!(err.network, "diners");
+        assert!(err.to_string().contains("visa, mastercard, amex, discover"));
+    }
+
     // IBAN tests
     #[test]
     fn test_generate_ibans_count() {
@@ -845,6 +1681,228 @@ mod tests {
         assert!(!validate_iban("XX00123456789")); // Invalid format
     }
 
+    #[test]
+    fn test_routing_number_known_valid() {
+        assert!(validate_routing_number("011000015")); // Federal Reserve Bank of Boston
+        assert!(validate_routing_number("021000021"));
+    }
+
+    #[test]
+    fn test_routing_number_known_invalid() {
+        assert!(!validate_routing_number("011000016")); // Changed last digit
+        assert!(!validate_routing_number("01100001")); // Too short
+        assert!(!validate_routing_number("01100001a"));
+        assert!(!validate_routing_number("０11000015"));
+    }
+
+    #[test]
+    fn test_routing_numbers_valid() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let numbers = generate_routing_numbers(&mut rng, 500);
+        assert_eq!(numbers.len(), 500);
+        for number in &numbers {
+            assert!(validate_routing_number(number), "{}", number);
+            let prefix: u8 = number[..2].parse().unwrap();
+            assert!(ROUTING_PREFIXES.contains(&prefix), "{}", number);
+            assert_ne!(&number[2..3], "0");
+        }
+    }
+
+    #[test]
+    fn test_routing_number_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(12345);
+        rng2.seed(12345);
+
+        assert_eq!(
+            generate_routing_numbers(&mut rng1, 50),
+            generate_routing_numbers(&mut rng2, 50)
+        );
+    }
+
+    #[test]
+    fn test_currency_data_valid() {
+        let mut codes = HashSet::new();
+        for (code, name, symbol) in CURRENCIES {
+            assert_eq!(code.len(), 3, "{}", code);
+            assert!(code.bytes().all(|b| b.is_ascii_uppercase()), "{}", code);
+            assert!(codes.insert(*code), "duplicate currency {}", code);
+            assert!(!name.is_empty() && !symbol.is_empty(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_currency_generators() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let codes = generate_currency_codes(&mut rng, 100);
+        let names = generate_currency_names(&mut rng, 100);
+        let symbols = generate_currency_symbols(&mut rng, 100);
+        assert_eq!((codes.len(), names.len(), symbols.len()), (100, 100, 100));
+        assert!(codes.iter().all(|c| CURRENCIES.iter().any(|e| e.0 == c)));
+        assert!(names.iter().all(|n| CURRENCIES.iter().any(|e| e.1 == n)));
+        assert!(symbols.iter().all(|s| CURRENCIES.iter().any(|e| e.2 == s)));
+    }
+
+    #[test]
+    fn test_currency_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_currency_codes(&mut rng1, 50),
+            generate_currency_codes(&mut rng2, 50)
+        );
+    }
+
+    #[test]
+    fn test_ticker_format() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let tickers = generate_tickers(&mut rng, 500);
+        assert_eq!(tickers.len(), 500);
+        for ticker in &tickers {
+            assert!((1..=5).contains(&ticker.len()), "{}", ticker);
+            assert!(ticker.bytes().all(|b| b.is_ascii_uppercase()), "{}", ticker);
+        }
+        assert!(tickers.iter().any(|t| t.len() == 1));
+        assert!(tickers.iter().any(|t| t.len() == 5));
+    }
+
+    #[test]
+    fn test_stock_exchanges() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let exchanges = generate_stock_exchanges(&mut rng, 100);
+        assert!(exchanges
+            .iter()
+            .all(|e| STOCK_EXCHANGES.contains(&e.as_str())));
+    }
+
+    #[test]
+    fn test_stock_listing_ticker_matches_exchange() {
+        for locale in Locale::ALL {
+            let mut rng = ForgeryRng::new();
+            rng.seed(42);
+            for listing in generate_stock_listings(&mut rng, *locale, 100) {
+                let (min, max) = ticker_length_range(&listing.exchange);
+                let len = listing.ticker.len();
+                assert!((min..=max).contains(&len), "{:?}", listing);
+                assert!(listing.ticker.bytes().all(|b| b.is_ascii_uppercase()));
+                assert!(!listing.company.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_stock_listing_ticker_from_company_name() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        for listing in generate_stock_listings(&mut rng, Locale::EnUS, 100) {
+            let letters: String = listing
+                .company
+                .chars()
+                .filter(|c| c.is_ascii_alphabetic())
+                .map(|c| c.to_ascii_uppercase())
+                .collect();
+            assert!(letters.starts_with(&listing.ticker), "{:?}", listing);
+        }
+    }
+
+    fn currency(code: &str) -> Currency {
+        code.parse().unwrap()
+    }
+
+    #[test]
+    fn test_currency_from_str() {
+        let eur = currency("eur");
+        assert_eq!((eur.code, eur.symbol, eur.decimals()), ("EUR", "€", 2));
+        assert_eq!(currency("JPY").decimals(), 0);
+        assert_eq!(currency("USD"), Currency::USD);
+        let err = "XYZ".parse::<Currency>().unwrap_err();
+        assert_eq!(err.currency, "XYZ");
+    }
+
+    #[test]
+    fn test_format_price_locales() {
+        let usd = currency("USD");
+        let eur = currency("EUR");
+        assert_eq!(format_price(1234.56, usd, Locale::EnUS), "$1,234.56");
+        assert_eq!(format_price(1234.56, eur, Locale::DeDE), "1.234,56 €");
+        assert_eq!(format_price(1234.56, eur, Locale::NlNL), "€ 1.234,56");
+        assert_eq!(format_price(1234567.5, eur, Locale::FrFR), "1 234 567,50 €");
+        assert_eq!(
+            format_price(1234.0, currency("JPY"), Locale::JaJP),
+            "¥1,234"
+        );
+        assert_eq!(format_price(0.5, usd, Locale::EnUS), "$0.50");
+        assert_eq!(format_price(999.0, usd, Locale::EnUS), "$999.00");
+        assert_eq!(format_price(-12.3, usd, Locale::EnUS), "-$12.30");
+        assert_eq!(format_price(-0.001, usd, Locale::EnUS), "$0.00");
+    }
+
+    #[test]
+    fn test_prices_in_range_and_rounded() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let prices = generate_prices(&mut rng, 500, 1.0, 100.0, currency("USD")).unwrap();
+        assert_eq!(prices.len(), 500);
+        for p in &prices {
+            assert!((1.0..=100.0).contains(p), "{}", p);
+            assert!(((p * 100.0).round() - p * 100.0).abs() < 1e-6, "{}", p);
+        }
+
+        let yen = generate_prices(&mut rng, 100, 100.0, 5000.0, currency("JPY")).unwrap();
+        assert!(yen.iter().all(|p| p.fract() == 0.0));
+    }
+
+    #[test]
+    fn test_prices_narrow_range() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let usd = currency("USD");
+
+        assert_eq!(generate_price(&mut rng, 9.99, 9.99, usd).unwrap(), 9.99);
+        // Narrower than one cent collapses to the rounded-up lower bound
+        assert_eq!(generate_price(&mut rng, 1.001, 1.002, usd).unwrap(), 1.01);
+    }
+
+    #[test]
+    fn test_prices_invalid_range() {
+        let mut rng = ForgeryRng::new();
+        let usd = currency("USD");
+        assert!(generate_prices(&mut rng, 10, 10.0, 1.0, usd).is_err());
+        assert!(generate_price(&mut rng, f64::NAN, 1.0, usd).is_err());
+        assert!(
+            generate_formatted_prices(&mut rng, Locale::EnUS, 1, 0.0, f64::INFINITY, usd).is_err()
+        );
+    }
+
+    #[test]
+    fn test_formatted_prices_match_plain_prices() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+        let eur = currency("EUR");
+
+        let plain = generate_prices(&mut rng1, 20, 0.0, 5000.0, eur).unwrap();
+        let formatted =
+            generate_formatted_prices(&mut rng2, Locale::DeDE, 20, 0.0, 5000.0, eur).unwrap();
+        for (p, f) in plain.iter().zip(&formatted) {
+            assert_eq!(format_price(*p, eur, Locale::DeDE), *f);
+        }
+    }
+
     #[test]
     fn test_iban_non_ascii_does_not_panic() {
         // Non-ASCII characters should return false, not panic
@@ -1344,6 +2402,11 @@ mod tests {
             Locale::EsES,
             Locale::ItIT,
             Locale::JaJP,
+            Locale::KoKR,
+            Locale::RuRU,
+            Locale::NlNL,
+            Locale::PlPL,
+            Locale::EnCA,
         ];
 
         for locale in locales {
@@ -1359,6 +2422,126 @@ mod tests {
             }
         }
     }
+
+    // VAT number tests
+
+    /// Independent checksum validation for generated VAT numbers.
+    fn vat_is_valid(vat: &str) -> bool {
+        let (country, body) = vat.split_at(2);
+        let digits: Vec<u32> = body.chars().filter_map(|c| c.to_digit(10)).collect();
+        let weighted =
+            |ds: &[u32], ws: &[u32]| -> u32 { ds.iter().zip(ws).map(|(d, w)| d * w).sum() };
+        match country {
+            "AT" => {
+                let sum: u32 = digits[..7]
+                    .iter()
+                    .zip([1, 2, 1, 2, 1, 2, 1])
+                    .map(|(d, w)| (d * w) / 10 + (d * w) % 10)
+                    .sum();
+                body.starts_with('U') && (10 - (sum + 4) % 10) % 10 == digits[7]
+            }
+            "BE" => {
+                let base: u64 = body[..8].parse().unwrap();
+                let check: u64 = body[8..].parse().unwrap();
+                97 - base % 97 == check
+            }
+            "DE" => crate::providers::national_id::mod_11_10_check_digit(&digits[..8]) == digits[8],
+            "ES" => {
+                let sum: u32 = digits[..7]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &d)| {
+                        if i % 2 == 0 {
+                            (d * 2) / 10 + (d * 2) % 10
+                        } else {
+                            d
+                        }
+                    })
+                    .sum();
+                let control = (10 - sum % 10) % 10;
+                let last = body.chars().last().unwrap();
+                last.to_digit(10) == Some(control)
+                    || last == char::from(b"JABCDEFGHI"[control as usize])
+            }
+            "FR" => {
+                let key: u64 = body[..2].parse().unwrap();
+                let siren: u64 = body[2..].parse().unwrap();
+                validate_luhn(&body[2..]) && key == (12 + 3 * (siren % 97)) % 97
+            }
+            "GB" => (weighted(&digits[..7], &[8, 7, 6, 5, 4, 3, 2]) + digits[7] * 10 + digits[8])
+                .is_multiple_of(97),
+            "IT" => validate_luhn(body),
+            "NL" => weighted(&digits[..8], &[9, 8, 7, 6, 5, 4, 3, 2]) % 11 == digits[8],
+            "PL" => weighted(&digits[..9], &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11 == digits[9],
+            "PT" => {
+                let check = 11 - weighted(&digits[..8], &[9, 8, 7, 6, 5, 4, 3, 2]) % 11;
+                (if check >= 10 { 0 } else { check }) == digits[8]
+            }
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_vat_validator_known_numbers() {
+        for vat in [
+            "ATU13585627",
+            "BE0411905847",
+            "DE136695976",
+            "ESA58818501",
+            "FR40303265045",
+            "GB980780684",
+            "IT00743110157",
+            "NL004495445B01",
+            "PL5260250274",
+            "PT501964843",
+        ] {
+            assert!(vat_is_valid(vat), "{}", vat);
+        }
+        assert!(!vat_is_valid("DE136695977"));
+    }
+
+    #[test]
+    fn test_vat_numbers_valid_for_each_country() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        for country in VAT_COUNTRIES {
+            let numbers = generate_vat_numbers(&mut rng, Locale::EnUS, 100, Some(country)).unwrap();
+            for vat in numbers {
+                assert!(vat.starts_with(country), "{}", vat);
+                assert!(vat_is_valid(&vat), "{}", vat);
+            }
+        }
+    }
+
+    #[test]
+    fn test_vat_country_case_insensitive() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let vat = generate_vat_number(&mut rng, Locale::EnUS, Some("de")).unwrap();
+        assert!(vat.starts_with("DE"));
+    }
+
+    #[test]
+    fn test_vat_unknown_country() {
+        let mut rng = ForgeryRng::new();
+        let err = generate_vat_numbers(&mut rng, Locale::EnUS, 5, Some("US")).unwrap_err();
+        assert_eq!(err.country, "US");
+        assert!(err.to_string().contains("AT, BE, DE"));
+    }
+
+    #[test]
+    fn test_vat_defaults_to_locale_country() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let numbers = generate_vat_numbers(&mut rng, Locale::ItIT, 20, None).unwrap();
+        assert!(numbers.iter().all(|v| v.starts_with("IT")));
+
+        // Locales without VAT numbers pick from all supported countries
+        let numbers = generate_vat_numbers(&mut rng, Locale::JaJP, 50, None).unwrap();
+        assert!(numbers.iter().all(|v| vat_is_valid(v)));
+        let prefixes: HashSet<&str> = numbers.iter().map(|v| &v[..2]).collect();
+        assert!(prefixes.len() > 1);
+    }
 }
 
 #[cfg(test)]
@@ -1440,5 +2623,29 @@ mod proptest_tests {
 
             prop_assert_eq!(i1, i2);
         }
+
+        #[test]
+        fn prop_routing_number_valid_checksum(seed_val in any::<u64>(), n in 1usize..100) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed_val);
+
+            for number in generate_routing_numbers(&mut rng, n) {
+                prop_assert!(validate_routing_number(&number), "{}", number);
+            }
+        }
+
+        #[test]
+        fn prop_stock_listing_seed_determinism(seed_val in any::<u64>(), n in 1usize..50) {
+            let mut rng1 = ForgeryRng::new();
+            let mut rng2 = ForgeryRng::new();
+
+            rng1.seed(seed_val);
+            rng2.seed(seed_val);
+
+            prop_assert_eq!(
+                generate_stock_listings(&mut rng1, Locale::EnUS, n),
+                generate_stock_listings(&mut rng2, Locale::EnUS, n)
+            );
+        }
     }
 }
@@ -0,0 +1,688 @@
+//! Geographic coordinate generation provider.
+//!
+//! Generates latitudes in `[-90, 90]` and longitudes in `[-180, 180]` as
+//! decimal degrees, rounded to a configurable number of decimal places.
+//!
+//! Points can also be constrained to a bounding box, or to the rough bounding
+//! box of a country (ISO 3166-1 alpha-2 code). Country boxes are rectangles
+//! around the mainland, so points near borders or coasts may fall just
+//! outside the country itself.
+//!
+//! GPS tracks are routes rather than independent points: each fix moves on
+//! from the previous one at a speed up to a limit, with a heading that turns
+//! gradually, one fix every `GPS_FIX_INTERVAL_SECS` seconds.
+
+use crate::data::en_us::COUNTRY_BOUNDS;
+use crate::providers::numbers::standard_normal;
+use crate::rng::ForgeryRng;
+use chrono::DateTime;
+
+/// Default number of decimal places (about 0.1 m of precision).
+pub const DEFAULT_COORDINATE_PRECISION: u32 = 6;
+
+/// Round a value to `precision` decimal places.
+///
+/// Precision beyond 15 places is below f64 resolution and leaves the value as is.
+#[inline]
+fn round_to(value: f64, precision: u32) -> f64 {
+    if precision >= 15 {
+        return value;
+    }
+    let factor = 10f64.powi(precision as i32);
+    (value * factor).round() / factor
+}
+
+/// Generate a batch of latitudes.
+pub fn generate_latitudes(rng: &mut ForgeryRng, n: usize, precision: u32) -> Vec<f64> {
+    let mut latitudes = Vec::with_capacity(n);
+    for _ in 0..n {
+        latitudes.push(generate_latitude(rng, precision));
+    }
+    latitudes
+}
+
+/// Generate a single latitude in `[-90, 90]`.
+#[inline]
+pub fn generate_latitude(rng: &mut ForgeryRng, precision: u32) -> f64 {
+    round_to(rng.gen_range(-90.0, 90.0), precision)
+}
+
+/// Generate a batch of longitudes.
+pub fn generate_longitudes(rng: &mut ForgeryRng, n: usize, precision: u32) -> Vec<f64> {
+    let mut longitudes = Vec::with_capacity(n);
+    for _ in 0..n {
+        longitudes.push(generate_longitude(rng, precision));
+    }
+    longitudes
+}
+
+/// Generate a single longitude in `[-180, 180]`.
+#[inline]
+pub fn generate_longitude(rng: &mut ForgeryRng, precision: u32) -> f64 {
+    round_to(rng.gen_range(-180.0, 180.0), precision)
+}
+
+/// Generate a batch of (latitude, longitude) pairs.
+pub fn generate_coordinates(rng: &mut ForgeryRng, n: usize, precision: u32) -> Vec<(f64, f64)> {
+    let mut coordinates = Vec::with_capacity(n);
+    for _ in 0..n {
+        coordinates.push(generate_coordinate(rng, precision));
+    }
+    coordinates
+}
+
+/// Generate a single (latitude, longitude) pair.
+#[inline]
+pub fn generate_coordinate(rng: &mut ForgeryRng, precision: u32) -> (f64, f64) {
+    let lat = generate_latitude(rng, precision);
+    let lon = generate_longitude(rng, precision);
+    (lat, lon)
+}
+
+// === Bounding Boxes ===
+
+/// Error for a bounding box with out-of-range or inverted bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundingBoxError {
+    /// The minimum latitude given.
+    pub min_lat: f64,
+    /// The maximum latitude given.
+    pub max_lat: f64,
+    /// The minimum longitude given.
+    pub min_lon: f64,
+    /// The maximum longitude given.
+    pub max_lon: f64,
+}
+
+impl std::fmt::Display for BoundingBoxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid bounding box (lat {} to {}, lon {} to {}): latitudes must be in \
+             [-90, 90], longitudes in [-180, 180], and each min <= max",
+            self.min_lat, self.max_lat, self.min_lon, self.max_lon
+        )
+    }
+}
+
+impl std::error::Error for BoundingBoxError {}
+
+/// Error for a country code without a bounding box.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoCountryError {
+    /// The unsupported country code.
+    pub country: String,
+}
+
+impl std::fmt::Display for GeoCountryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let codes: Vec<&str> = COUNTRY_BOUNDS.iter().map(|c| c.0).collect();
+        write!(
+            f,
+            "unsupported country '{}' for coordinates; expected one of: {}",
+            self.country,
+            codes.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for GeoCountryError {}
+
+/// A latitude/longitude rectangle in decimal degrees.
+///
+/// Boxes crossing the antimeridian are not supported; `min_lon` must not
+/// exceed `max_lon`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    /// Southern edge.
+    pub min_lat: f64,
+    /// Northern edge.
+    pub max_lat: f64,
+    /// Western edge.
+    pub min_lon: f64,
+    /// Eastern edge.
+    pub max_lon: f64,
+}
+
+impl BoundingBox {
+    /// Create a bounding box, validating its bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoundingBoxError` if any bound is non-finite or out of range,
+    /// or if a minimum exceeds its maximum.
+    pub fn new(
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+    ) -> Result<Self, BoundingBoxError> {
+        let lat_ok = (-90.0..=90.0).contains(&min_lat)
+            && (-90.0..=90.0).contains(&max_lat)
+            && min_lat <= max_lat;
+        let lon_ok = (-180.0..=180.0).contains(&min_lon)
+            && (-180.0..=180.0).contains(&max_lon)
+            && min_lon <= max_lon;
+        if !lat_ok || !lon_ok {
+            return Err(BoundingBoxError {
+                min_lat,
+                max_lat,
+                min_lon,
+                max_lon,
+            });
+        }
+        Ok(Self {
+            min_lat,
+            max_lat,
+            min_lon,
+            max_lon,
+        })
+    }
+
+    /// Whether a point lies inside the box (edges included).
+    pub fn contains(&self, lat: f64, lon: f64) -> bool {
+        (self.min_lat..=self.max_lat).contains(&lat) && (self.min_lon..=self.max_lon).contains(&lon)
+    }
+}
+
+/// Look up the rough bounding box of a country (case-insensitive).
+///
+/// # Errors
+///
+/// Returns `GeoCountryError` if the country has no bounding box.
+pub fn country_bounding_box(country: &str) -> Result<BoundingBox, GeoCountryError> {
+    COUNTRY_BOUNDS
+        .iter()
+        .find(|c| c.0.eq_ignore_ascii_case(country))
+        .map(|&(_, min_lat, max_lat, min_lon, max_lon)| BoundingBox {
+            min_lat,
+            max_lat,
+            min_lon,
+            max_lon,
+        })
+        .ok_or_else(|| GeoCountryError {
+            country: country.to_string(),
+        })
+}
+
+/// Generate a batch of (latitude, longitude) pairs inside a bounding box.
+pub fn generate_coordinates_in_bbox(
+    rng: &mut ForgeryRng,
+    n: usize,
+    bbox: &BoundingBox,
+    precision: u32,
+) -> Vec<(f64, f64)> {
+    let mut coordinates = Vec::with_capacity(n);
+    for _ in 0..n {
+        coordinates.push(generate_coordinate_in_bbox(rng, bbox, precision));
+    }
+    coordinates
+}
+
+/// Generate a single (latitude, longitude) pair inside a bounding box.
+///
+/// Rounding can push a value past an edge, so results are clamped back
+/// into the box.
+#[inline]
+pub fn generate_coordinate_in_bbox(
+    rng: &mut ForgeryRng,
+    bbox: &BoundingBox,
+    precision: u32,
+) -> (f64, f64) {
+    let lat = round_to(rng.gen_range(bbox.min_lat, bbox.max_lat), precision)
+        .clamp(bbox.min_lat, bbox.max_lat);
+    let lon = round_to(rng.gen_range(bbox.min_lon, bbox.max_lon), precision)
+        .clamp(bbox.min_lon, bbox.max_lon);
+    (lat, lon)
+}
+
+/// Generate a batch of (latitude, longitude) pairs within a country's
+/// bounding box.
+///
+/// # Errors
+///
+/// Returns `GeoCountryError` if the country has no bounding box.
+pub fn generate_coordinates_in_country(
+    rng: &mut ForgeryRng,
+    n: usize,
+    country: &str,
+    precision: u32,
+) -> Result<Vec<(f64, f64)>, GeoCountryError> {
+    let bbox = country_bounding_box(country)?;
+    Ok(generate_coordinates_in_bbox(rng, n, &bbox, precision))
+}
+
+/// Generate a single (latitude, longitude) pair within a country's bounding box.
+///
+/// # Errors
+///
+/// Returns `GeoCountryError` if the country has no bounding box.
+pub fn generate_coordinate_in_country(
+    rng: &mut ForgeryRng,
+    country: &str,
+    precision: u32,
+) -> Result<(f64, f64), GeoCountryError> {
+    let bbox = country_bounding_box(country)?;
+    Ok(generate_coordinate_in_bbox(rng, &bbox, precision))
+}
+
+// === GPS Tracks ===
+
+/// Default speed limit of a GPS track in km/h.
+pub const DEFAULT_MAX_SPEED_KMH: f64 = 50.0;
+
+/// Seconds between consecutive GPS fixes.
+pub const GPS_FIX_INTERVAL_SECS: i64 = 10;
+
+/// Start of the track start-time window (2024-01-01T00:00:00Z).
+const TRACK_WINDOW_START: i64 = 1_704_067_200;
+
+/// Length of the track start-time window in seconds (the 366 days of 2024).
+const TRACK_WINDOW_SECS: i64 = 366 * 86_400;
+
+/// Mean Earth radius in kilometres.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Standard deviation of the heading change between fixes, in degrees.
+const HEADING_JITTER_DEG: f64 = 12.0;
+
+/// Standard deviation of the speed change between fixes, as a fraction of
+/// the speed limit.
+const SPEED_JITTER: f64 = 0.1;
+
+/// Error for GPS track options that cannot be used.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GpsTrackError {
+    /// The start coordinate is out of range.
+    InvalidStart {
+        /// The latitude given.
+        lat: f64,
+        /// The longitude given.
+        lon: f64,
+    },
+    /// The speed limit is negative or not finite.
+    InvalidSpeed(f64),
+}
+
+impl std::fmt::Display for GpsTrackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidStart { lat, lon } => write!(
+                f,
+                "invalid start coordinate ({}, {}): latitude must be in [-90, 90] and \
+                 longitude in [-180, 180]",
+                lat, lon
+            ),
+            Self::InvalidSpeed(speed) => write!(
+                f,
+                "invalid max_speed_kmh {}: must be a finite number >= 0",
+                speed
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GpsTrackError {}
+
+/// One fix of a GPS track.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpsPoint {
+    /// UTC time of the fix (e.g., "2024-03-14T09:26:53")
+    pub timestamp: String,
+    /// Latitude in decimal degrees (6 places)
+    pub latitude: f64,
+    /// Longitude in decimal degrees (6 places)
+    pub longitude: f64,
+    /// Speed over the following interval in km/h (1 place)
+    pub speed_kmh: f64,
+    /// Heading over the following interval in degrees clockwise from north (1 place)
+    pub heading: f64,
+}
+
+/// Move `distance_km` from a point along a great circle at `heading_deg`.
+fn destination(lat: f64, lon: f64, heading_deg: f64, distance_km: f64) -> (f64, f64) {
+    let (lat1, lon1) = (lat.to_radians(), lon.to_radians());
+    let bearing = heading_deg.to_radians();
+    let angle = distance_km / EARTH_RADIUS_KM;
+    let lat2 = (lat1.sin() * angle.cos() + lat1.cos() * angle.sin() * bearing.cos()).asin();
+    let lon2 = lon1
+        + (bearing.sin() * angle.sin() * lat1.cos()).atan2(angle.cos() - lat1.sin() * lat2.sin());
+    let lon2 = (lon2.to_degrees() + 540.0).rem_euclid(360.0) - 180.0;
+    (lat2.to_degrees(), lon2)
+}
+
+/// Great-circle distance between two points in kilometres.
+pub fn haversine_km(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (lat1, lat2) = (a.0.to_radians(), b.0.to_radians());
+    let dlat = lat2 - lat1;
+    let dlon = (b.1 - a.1).to_radians();
+    let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().asin()
+}
+
+/// Check GPS track options.
+///
+/// # Errors
+///
+/// Returns `GpsTrackError` if the start coordinate is out of range or the
+/// speed limit is negative or not finite.
+pub fn validate_gps_track(
+    start: Option<(f64, f64)>,
+    max_speed_kmh: f64,
+) -> Result<(), GpsTrackError> {
+    if let Some((lat, lon)) = start {
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+            return Err(GpsTrackError::InvalidStart { lat, lon });
+        }
+    }
+    if !max_speed_kmh.is_finite() || max_speed_kmh < 0.0 {
+        return Err(GpsTrackError::InvalidSpeed(max_speed_kmh));
+    }
+    Ok(())
+}
+
+/// Generate a GPS track of `points` consecutive fixes.
+///
+/// The track begins at `start`, or at a random point inside a random
+/// country's bounding box, at a random time in 2024. Each fix is
+/// `GPS_FIX_INTERVAL_SECS` after the previous one and no further from it
+/// than `max_speed_kmh` allows.
+///
+/// # Errors
+///
+/// Returns `GpsTrackError` if the start coordinate is out of range or the
+/// speed limit is negative or not finite.
+pub fn generate_gps_track(
+    rng: &mut ForgeryRng,
+    points: usize,
+    start: Option<(f64, f64)>,
+    max_speed_kmh: f64,
+) -> Result<Vec<GpsPoint>, GpsTrackError> {
+    validate_gps_track(start, max_speed_kmh)?;
+    let (mut lat, mut lon) = match start {
+        Some(coordinate) => coordinate,
+        None => {
+            let &(_, min_lat, max_lat, min_lon, max_lon) = rng.choose(COUNTRY_BOUNDS);
+            let bbox = BoundingBox {
+                min_lat,
+                max_lat,
+                min_lon,
+                max_lon,
+            };
+            generate_coordinate_in_bbox(rng, &bbox, DEFAULT_COORDINATE_PRECISION)
+        }
+    };
+    let start_secs = TRACK_WINDOW_START + rng.gen_range(0, TRACK_WINDOW_SECS - 1);
+    let mut heading = rng.gen_range(0.0, 360.0);
+    let mut speed = rng.gen_range(0.0, max_speed_kmh);
+
+    let mut track = Vec::with_capacity(points);
+    for i in 0..points {
+        let secs = start_secs + i as i64 * GPS_FIX_INTERVAL_SECS;
+        let timestamp = DateTime::from_timestamp(secs, 0)
+            .expect("timestamp within 2024")
+            .format("%Y-%m-%dT%H:%M:%S")
+            .to_string();
+        track.push(GpsPoint {
+            timestamp,
+            latitude: round_to(lat, DEFAULT_COORDINATE_PRECISION),
+            longitude: round_to(lon, DEFAULT_COORDINATE_PRECISION),
+            speed_kmh: round_to(speed, 1),
+            heading: round_to(heading, 1) % 360.0,
+        });
+        let distance_km = speed * GPS_FIX_INTERVAL_SECS as f64 / 3600.0;
+        (lat, lon) = destination(lat, lon, heading, distance_km);
+        heading = (heading + HEADING_JITTER_DEG * standard_normal(rng)).rem_euclid(360.0);
+        speed =
+            (speed + SPEED_JITTER * max_speed_kmh * standard_normal(rng)).clamp(0.0, max_speed_kmh);
+    }
+    Ok(track)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal_places(value: f64) -> usize {
+        let s = value.to_string();
+        s.split_once('.').map_or(0, |(_, frac)| frac.len())
+    }
+
+    #[test]
+    fn test_coordinates_in_range() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let coordinates = generate_coordinates(&mut rng, 1000, DEFAULT_COORDINATE_PRECISION);
+        assert_eq!(coordinates.len(), 1000);
+        for (lat, lon) in coordinates {
+            assert!((-90.0..=90.0).contains(&lat), "{}", lat);
+            assert!((-180.0..=180.0).contains(&lon), "{}", lon);
+        }
+    }
+
+    #[test]
+    fn test_latitudes_and_longitudes() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let lats = generate_latitudes(&mut rng, 500, 4);
+        let lons = generate_longitudes(&mut rng, 500, 4);
+        assert!(lats.iter().all(|v| (-90.0..=90.0).contains(v)));
+        assert!(lons.iter().all(|v| (-180.0..=180.0).contains(v)));
+        assert!(lons.iter().any(|v| v.abs() > 90.0));
+    }
+
+    #[test]
+    fn test_precision() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for precision in [0, 2, 4, 6] {
+            for (lat, lon) in generate_coordinates(&mut rng, 200, precision) {
+                assert!(decimal_places(lat) <= precision as usize, "{}", lat);
+                assert!(decimal_places(lon) <= precision as usize, "{}", lon);
+            }
+        }
+        for lat in generate_latitudes(&mut rng, 50, 0) {
+            assert_eq!(lat, lat.trunc());
+        }
+    }
+
+    #[test]
+    fn test_high_precision_is_unrounded() {
+        assert_eq!(
+            round_to(1.234_567_890_123_456_7, 20),
+            1.234_567_890_123_456_7
+        );
+        assert_eq!(round_to(1.25, 1), 1.3);
+    }
+
+    #[test]
+    fn test_bounding_box_validation() {
+        assert!(BoundingBox::new(40.0, 41.0, -74.5, -73.5).is_ok());
+        assert!(BoundingBox::new(-90.0, 90.0, -180.0, 180.0).is_ok());
+        assert!(BoundingBox::new(1.0, 1.0, 2.0, 2.0).is_ok());
+        assert!(BoundingBox::new(41.0, 40.0, 0.0, 1.0).is_err());
+        assert!(BoundingBox::new(0.0, 1.0, 10.0, -10.0).is_err());
+        assert!(BoundingBox::new(-91.0, 0.0, 0.0, 1.0).is_err());
+        assert!(BoundingBox::new(0.0, 1.0, 0.0, 181.0).is_err());
+        assert!(BoundingBox::new(f64::NAN, 1.0, 0.0, 1.0).is_err());
+
+        let err = BoundingBox::new(41.0, 40.0, 0.0, 1.0).unwrap_err();
+        assert!(err.to_string().contains("lat 41 to 40"));
+    }
+
+    #[test]
+    fn test_coordinates_in_bbox() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let bbox = BoundingBox::new(40.5, 40.9, -74.3, -73.7).unwrap();
+        for precision in [0, 1, 3, 6] {
+            for (lat, lon) in generate_coordinates_in_bbox(&mut rng, 300, &bbox, precision) {
+                assert!(bbox.contains(lat, lon), "({}, {})", lat, lon);
+            }
+        }
+
+        let point = BoundingBox::new(10.0, 10.0, 20.0, 20.0).unwrap();
+        assert_eq!(
+            generate_coordinate_in_bbox(&mut rng, &point, 6),
+            (10.0, 20.0)
+        );
+    }
+
+    #[test]
+    fn test_coordinates_in_country() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let de = country_bounding_box("DE").unwrap();
+        let points = generate_coordinates_in_country(&mut rng, 500, "de", 6).unwrap();
+        assert_eq!(points.len(), 500);
+        for (lat, lon) in points {
+            assert!(de.contains(lat, lon), "({}, {})", lat, lon);
+            assert!((47.0..=55.5).contains(&lat));
+            assert!((5.5..=15.5).contains(&lon));
+        }
+
+        let (lat, lon) = generate_coordinate_in_country(&mut rng, "AU", 6).unwrap();
+        assert!(lat < 0.0 && lon > 100.0);
+    }
+
+    #[test]
+    fn test_country_bounds_data() {
+        for &(code, min_lat, max_lat, min_lon, max_lon) in COUNTRY_BOUNDS {
+            assert_eq!(code.len(), 2);
+            assert!(
+                BoundingBox::new(min_lat, max_lat, min_lon, max_lon).is_ok(),
+                "{}",
+                code
+            );
+        }
+        assert!(COUNTRY_BOUNDS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_unknown_country() {
+        let mut rng = ForgeryRng::new();
+        let err = generate_coordinates_in_country(&mut rng, 5, "ZZ", 6).unwrap_err();
+        assert_eq!(err.country, "ZZ");
+        assert!(err.to_string().contains("'ZZ'"));
+        assert!(err.to_string().contains("DE"));
+    }
+
+    #[test]
+    fn test_coordinates_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_coordinates(&mut rng1, 50, 6),
+            generate_coordinates(&mut rng2, 50, 6)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_coordinates_in_range(seed in any::<u64>(), n in 0usize..200, precision in 0u32..10) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let coordinates = generate_coordinates(&mut rng, n, precision);
+            prop_assert_eq!(coordinates.len(), n);
+            for (lat, lon) in coordinates {
+                prop_assert!((-90.0..=90.0).contains(&lat));
+                prop_assert!((-180.0..=180.0).contains(&lon));
+            }
+        }
+
+        #[test]
+        fn prop_coordinates_in_bbox(
+            seed in any::<u64>(),
+            lat in -90.0f64..=90.0,
+            lat_span in 0.0f64..=10.0,
+            lon in -180.0f64..=180.0,
+            lon_span in 0.0f64..=10.0,
+            precision in 0u32..10,
+        ) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let bbox = BoundingBox::new(lat, (lat + lat_span).min(90.0), lon, (lon + lon_span).min(180.0))
+                .unwrap();
+            for (lat, lon) in generate_coordinates_in_bbox(&mut rng, 50, &bbox, precision) {
+                prop_assert!(bbox.contains(lat, lon));
+            }
+        }
+    }
+
+    #[test]
+    fn test_gps_track_is_a_route() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let track = generate_gps_track(&mut rng, 500, Some((51.5074, -0.1278)), 60.0).unwrap();
+        assert_eq!(track.len(), 500);
+        assert_eq!((track[0].latitude, track[0].longitude), (51.5074, -0.1278));
+        // Rounding to 6 places can add ~0.15 m per fix.
+        let limit_km = 60.0 * GPS_FIX_INTERVAL_SECS as f64 / 3600.0 + 0.001;
+        for pair in track.windows(2) {
+            let step = haversine_km(
+                (pair[0].latitude, pair[0].longitude),
+                (pair[1].latitude, pair[1].longitude),
+            );
+            assert!(step <= limit_km, "step {} km", step);
+            assert!(pair[0].timestamp < pair[1].timestamp);
+        }
+        for point in &track {
+            assert!((0.0..=60.0).contains(&point.speed_kmh));
+            assert!((0.0..360.0).contains(&point.heading));
+        }
+        let end = track.last().unwrap();
+        assert!(haversine_km((51.5074, -0.1278), (end.latitude, end.longitude)) > 0.1);
+    }
+
+    #[test]
+    fn test_gps_track_random_start_and_errors() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let track = generate_gps_track(&mut rng, 10, None, DEFAULT_MAX_SPEED_KMH).unwrap();
+        assert!(COUNTRY_BOUNDS
+            .iter()
+            .any(|&(_, min_lat, max_lat, min_lon, max_lon)| {
+                (min_lat..=max_lat).contains(&track[0].latitude)
+                    && (min_lon..=max_lon).contains(&track[0].longitude)
+            }));
+        let still = generate_gps_track(&mut rng, 5, Some((0.0, 0.0)), 0.0).unwrap();
+        assert!(still
+            .iter()
+            .all(|p| p.latitude == 0.0 && p.speed_kmh == 0.0));
+
+        assert_eq!(
+            generate_gps_track(&mut rng, 1, Some((91.0, 0.0)), 50.0),
+            Err(GpsTrackError::InvalidStart {
+                lat: 91.0,
+                lon: 0.0
+            })
+        );
+        assert_eq!(
+            generate_gps_track(&mut rng, 1, None, -1.0),
+            Err(GpsTrackError::InvalidSpeed(-1.0))
+        );
+    }
+
+    #[test]
+    fn test_destination_wraps_longitude() {
+        let (lat, lon) = destination(0.0, 179.99, 90.0, 5.0);
+        assert!(lat.abs() < 1e-9);
+        assert!(lon < -179.9, "{}", lon);
+        let back = haversine_km((0.0, 179.99), (lat, lon));
+        assert!((back - 5.0).abs() < 1e-6);
+    }
+}
@@ -1,14 +1,17 @@
 //! Identifier generation provider.
 //!
-//! Generates UUIDs, and hash-like hex strings.
+//! Generates UUIDs (version 4 and the time-ordered version 7), and hash-like
+//! hex strings.
 //!
-//! # Note on MD5/SHA256
+//! # Note on MD5/SHA1/SHA256/SHA512
 //!
-//! The `generate_md5` and `generate_sha256` functions produce random hex strings
-//! that match the format of MD5 (32 chars) and SHA256 (64 chars) hashes.
+//! The `generate_md5`, `generate_sha1`, `generate_sha256` and `generate_sha512`
+//! functions produce random hex strings that match the format of MD5 (32 chars),
+//! SHA1 (40 chars), SHA256 (64 chars) and SHA512 (128 chars) hashes.
 //! They are NOT cryptographic hashes of any input data - they are simply
 //! random hex strings useful for generating fake data.
 
+use crate::providers::datetime::{unix_millis_range, DateRangeError};
 use crate::rng::ForgeryRng;
 
 /// Lookup table for fast hex encoding.
@@ -71,6 +74,93 @@ pub fn generate_uuid(rng: &mut ForgeryRng) -> String {
     format_uuid(&bytes)
 }
 
+/// Default start of the UUIDv7 timestamp range.
+pub const DEFAULT_UUID7_START: &str = "2020-01-01";
+
+/// Default end of the UUIDv7 timestamp range.
+pub const DEFAULT_UUID7_END: &str = "2030-12-31";
+
+/// Largest Unix millisecond timestamp that fits the 48-bit UUIDv7 field.
+const UUID7_MAX_MILLIS: i64 = (1 << 48) - 1;
+
+/// Timestamp bounds for UUIDv7, which cannot encode instants before 1970.
+fn uuid7_millis_range(start: &str, end: &str) -> Result<(i64, i64), DateRangeError> {
+    let (start_ms, end_ms) = unix_millis_range(start, end)?;
+    if start_ms < 0 || end_ms > UUID7_MAX_MILLIS {
+        return Err(DateRangeError {
+            start: start.to_string(),
+            end: end.to_string(),
+            reason: "UUIDv7 timestamps must be on or after 1970-01-01 and fit in 48 bits"
+                .to_string(),
+        });
+    }
+    Ok((start_ms, end_ms))
+}
+
+/// UUIDv7 (RFC 9562) bytes: a big-endian 48-bit Unix millisecond timestamp
+/// followed by the version, 12 random bits, the variant and 62 random bits.
+fn uuid7_bytes(rng: &mut ForgeryRng, millis: i64) -> [u8; 16] {
+    let mut bytes = [0u8; 16];
+    rng.fill_bytes(&mut bytes[6..]);
+    bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x70; // Version 7
+    bytes[8] = (bytes[8] & 0x3f) | 0x80; // Variant RFC 4122
+    bytes
+}
+
+/// Generate a batch of UUIDv7 strings with timestamps between `start` and `end`.
+///
+/// The batch is in ascending timestamp order, like keys issued one after
+/// another, so it sorts the same as text and by time.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of UUIDs to generate
+/// * `start` - Start date in YYYY-MM-DD format (inclusive)
+/// * `end` - End date in YYYY-MM-DD format (inclusive)
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if dates cannot be parsed, start > end, or the
+/// range falls outside what a 48-bit timestamp can hold.
+pub fn generate_uuid7s(
+    rng: &mut ForgeryRng,
+    n: usize,
+    start: &str,
+    end: &str,
+) -> Result<Vec<String>, DateRangeError> {
+    let (start_ms, end_ms) = uuid7_millis_range(start, end)?;
+    let mut timestamps: Vec<i64> = (0..n).map(|_| rng.gen_range(start_ms, end_ms)).collect();
+    timestamps.sort_unstable();
+
+    let mut uuids: Vec<String> = timestamps
+        .into_iter()
+        .map(|millis| format_uuid(&uuid7_bytes(rng, millis)))
+        .collect();
+    // Equal timestamps are ordered by their random bits
+    uuids.sort_unstable();
+    Ok(uuids)
+}
+
+/// Generate a single UUIDv7 string with a timestamp between `start` and `end`
+/// (e.g., "018f3c2a-7b4e-7d21-9c3f-5a8e2b1d4f60").
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if dates cannot be parsed, start > end, or the
+/// range falls outside what a 48-bit timestamp can hold.
+#[inline]
+pub fn generate_uuid7(
+    rng: &mut ForgeryRng,
+    start: &str,
+    end: &str,
+) -> Result<String, DateRangeError> {
+    let (start_ms, end_ms) = uuid7_millis_range(start, end)?;
+    let millis = rng.gen_range(start_ms, end_ms);
+    Ok(format_uuid(&uuid7_bytes(rng, millis)))
+}
+
 /// Format 16 bytes as a UUID string using lookup table for performance.
 fn format_uuid(bytes: &[u8; 16]) -> String {
     // Pre-allocate exact size: 32 hex chars + 4 dashes = 36
@@ -144,6 +234,32 @@ pub fn generate_md5(rng: &mut ForgeryRng) -> String {
     format_hex(&bytes)
 }
 
+/// Generate a batch of SHA1-like hash strings (40 lowercase hex characters).
+///
+/// Note: These are pseudo-random hashes generated from our seeded RNG,
+/// not actual SHA1 hashes of any input data.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of hashes to generate
+pub fn generate_sha1s(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut hashes = Vec::with_capacity(n);
+    for _ in 0..n {
+        hashes.push(generate_sha1(rng));
+    }
+    hashes
+}
+
+/// Generate a single SHA1-like hash string (40 lowercase hex characters),
+/// the length of a git commit ID.
+#[inline]
+pub fn generate_sha1(rng: &mut ForgeryRng) -> String {
+    let mut bytes = [0u8; 20];
+    rng.fill_bytes(&mut bytes);
+    format_hex(&bytes)
+}
+
 /// Generate a batch of SHA256-like hash strings (64 lowercase hex characters).
 ///
 /// Note: These are pseudo-random hashes generated from our seeded RNG,
@@ -171,6 +287,31 @@ pub fn generate_sha256(rng: &mut ForgeryRng) -> String {
     format_hex(&bytes)
 }
 
+/// Generate a batch of SHA512-like hash strings (128 lowercase hex characters).
+///
+/// Note: These are pseudo-random hashes generated from our seeded RNG,
+/// not actual SHA512 hashes of any input data.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of hashes to generate
+pub fn generate_sha512s(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut hashes = Vec::with_capacity(n);
+    for _ in 0..n {
+        hashes.push(generate_sha512(rng));
+    }
+    hashes
+}
+
+/// Generate a single SHA512-like hash string (128 lowercase hex characters).
+#[inline]
+pub fn generate_sha512(rng: &mut ForgeryRng) -> String {
+    let mut bytes = [0u8; 64];
+    rng.fill_bytes(&mut bytes);
+    format_hex(&bytes)
+}
+
 /// Format bytes as a lowercase hex string using a lookup table for performance.
 fn format_hex(bytes: &[u8]) -> String {
     let mut result = String::with_capacity(bytes.len() * 2);
@@ -547,6 +688,48 @@ mod tests {
         );
     }
 
+    // SHA1 / SHA512 tests
+    #[test]
+    fn test_sha1_and_sha512_format() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let cases = [
+            (generate_sha1s(&mut rng, 100), 40),
+            (generate_sha512s(&mut rng, 100), 128),
+        ];
+        for (hashes, len) in cases {
+            assert_eq!(hashes.len(), 100);
+            for hash in &hashes {
+                assert_eq!(hash.len(), len, "{}", hash);
+                assert!(
+                    hash.bytes()
+                        .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b)),
+                    "{}",
+                    hash
+                );
+            }
+            let unique: std::collections::HashSet<_> = hashes.iter().collect();
+            assert_eq!(unique.len(), hashes.len());
+        }
+        assert_eq!(generate_sha1(&mut rng).len(), 40);
+        assert_eq!(generate_sha512(&mut rng).len(), 128);
+    }
+
+    #[test]
+    fn test_sha1_and_sha512_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(12345);
+        rng2.seed(12345);
+
+        assert_eq!(generate_sha1s(&mut rng1, 50), generate_sha1s(&mut rng2, 50));
+        assert_eq!(
+            generate_sha512s(&mut rng1, 50),
+            generate_sha512s(&mut rng2, 50)
+        );
+    }
+
     #[test]
     fn test_format_hex() {
         let bytes = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
@@ -567,6 +750,72 @@ mod tests {
         let hex = format_hex(&bytes);
         assert_eq!(hex, "ffffffff");
     }
+
+    #[test]
+    fn test_uuid7_layout() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let uuids = generate_uuid7s(&mut rng, 200, "2024-01-01", "2024-12-31").unwrap();
+        assert_eq!(uuids.len(), 200);
+        for uuid in &uuids {
+            assert_eq!(uuid.len(), 36);
+            let chars: Vec<char> = uuid.chars().collect();
+            assert_eq!(chars[14], '7', "UUID version should be 7: {}", uuid);
+            assert!(matches!(chars[19], '8' | '9' | 'a' | 'b'), "{}", uuid);
+
+            let millis = i64::from_str_radix(&uuid.replace('-', "")[..12], 16).unwrap();
+            // 2024-01-01T00:00:00Z and 2025-01-01T00:00:00Z
+            assert!(
+                (1_704_067_200_000..1_735_689_600_000).contains(&millis),
+                "{}",
+                uuid
+            );
+        }
+    }
+
+    #[test]
+    fn test_uuid7_batch_is_time_ordered() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let uuids = generate_uuid7s(&mut rng, 500, DEFAULT_UUID7_START, DEFAULT_UUID7_END).unwrap();
+        assert!(uuids.windows(2).all(|w| w[0] <= w[1]));
+        let unique: std::collections::HashSet<_> = uuids.iter().collect();
+        assert_eq!(unique.len(), uuids.len());
+
+        // A single day still spreads over the whole day
+        let day = generate_uuid7s(&mut rng, 100, "2024-06-01", "2024-06-01").unwrap();
+        assert!(day.windows(2).all(|w| w[0] <= w[1]));
+        assert_ne!(day.first().unwrap()[..8], day.last().unwrap()[..8]);
+    }
+
+    #[test]
+    fn test_uuid7_invalid_range() {
+        let mut rng = ForgeryRng::new();
+
+        assert!(generate_uuid7s(&mut rng, 10, "2024-12-31", "2024-01-01").is_err());
+        assert!(generate_uuid7(&mut rng, "2024-13-01", "2024-12-31").is_err());
+        let err = generate_uuid7(&mut rng, "1969-12-31", "2024-01-01").unwrap_err();
+        assert!(err.reason.contains("1970-01-01"), "{}", err);
+        assert_eq!(
+            generate_uuid7(&mut rng, "1970-01-01", "1970-01-01").unwrap()[..5],
+            *"00000"
+        );
+    }
+
+    #[test]
+    fn test_uuid7_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_uuid7s(&mut rng1, 50, DEFAULT_UUID7_START, DEFAULT_UUID7_END).unwrap(),
+            generate_uuid7s(&mut rng2, 50, DEFAULT_UUID7_START, DEFAULT_UUID7_END).unwrap()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -798,5 +1047,37 @@ mod proptest_tests {
 
             prop_assert_eq!(hashes1, hashes2);
         }
+
+        /// Property: SHA1 and SHA512 hashes have correct length and lowercase hex
+        #[test]
+        fn prop_sha1_sha512_format(seed_val in any::<u64>(), n in 0usize..100) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed_val);
+
+            let sha1s = generate_sha1s(&mut rng, n);
+            let sha512s = generate_sha512s(&mut rng, n);
+            prop_assert_eq!(sha1s.len(), n);
+            prop_assert_eq!(sha512s.len(), n);
+            for hash in sha1s.iter().chain(&sha512s) {
+                prop_assert!(hash.len() == 40 || hash.len() == 128);
+                let lower_hex = |b: u8| b.is_ascii_digit() || (b'a'..=b'f').contains(&b);
+                prop_assert!(hash.bytes().all(lower_hex));
+            }
+        }
+
+        /// Property: UUIDv7 batches are sorted and carry version 7
+        #[test]
+        fn prop_uuid7_sorted(seed in any::<u64>(), n in 0usize..200) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+
+            let uuids =
+                generate_uuid7s(&mut rng, n, DEFAULT_UUID7_START, DEFAULT_UUID7_END).unwrap();
+            prop_assert_eq!(uuids.len(), n);
+            prop_assert!(uuids.windows(2).all(|w| w[0] <= w[1]));
+            for uuid in uuids {
+                prop_assert_eq!(&uuid[14..15], "7");
+            }
+        }
     }
 }
@@ -0,0 +1,249 @@
+//! Insurance policy and claim number generation provider.
+//!
+//! Numbers join a prefix, a year and a zero-padded sequence number with
+//! hyphens and end in a check character, e.g. `POL-2023-00482137K`. The check
+//! character is the Luhn mod 36 character (`0`-`9`, `A`-`Z`) over every
+//! letter and digit before it, so typos in any segment are detected.
+//!
+//! | Kind | Default prefix | Example |
+//! |------|----------------|---------|
+//! | Policy | `POL` | `POL-2023-00482137K` |
+//! | Claim | `CLM` | `CLM-2024-00031857X` |
+
+use crate::rng::ForgeryRng;
+
+/// Default policy number prefix.
+pub const DEFAULT_POLICY_PREFIX: &str = "POL";
+
+/// Default claim number prefix.
+pub const DEFAULT_CLAIM_PREFIX: &str = "CLM";
+
+/// Default number of sequence digits.
+pub const DEFAULT_INSURANCE_DIGITS: u32 = 8;
+
+/// Largest supported number of sequence digits.
+pub const MAX_INSURANCE_DIGITS: u32 = 12;
+
+/// Longest supported prefix.
+pub const MAX_INSURANCE_PREFIX_LEN: usize = 10;
+
+/// Years the year segment is drawn from (inclusive).
+const INSURANCE_YEARS: (u32, u32) = (2020, 2024);
+
+/// Characters of the check alphabet, indexed by code point.
+const CHECK_CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Error for an invalid policy or claim number format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsuranceNumberError {
+    /// The prefix has characters other than A-Z and 0-9, or is too long.
+    Prefix(String),
+    /// The sequence width is zero or above `MAX_INSURANCE_DIGITS`.
+    Digits(u32),
+}
+
+impl std::fmt::Display for InsuranceNumberError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Prefix(prefix) => write!(
+                f,
+                "invalid insurance number prefix '{}': use up to {} uppercase letters and digits",
+                prefix, MAX_INSURANCE_PREFIX_LEN
+            ),
+            Self::Digits(digits) => write!(
+                f,
+                "insurance number digits must be between 1 and {}, got {}",
+                MAX_INSURANCE_DIGITS, digits
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InsuranceNumberError {}
+
+/// Check that a prefix and sequence width are supported.
+///
+/// # Errors
+///
+/// Returns `InsuranceNumberError` if the prefix is not up to
+/// `MAX_INSURANCE_PREFIX_LEN` uppercase ASCII letters and digits, or `digits`
+/// is 0 or above `MAX_INSURANCE_DIGITS`.
+pub fn validate_insurance_format(prefix: &str, digits: u32) -> Result<(), InsuranceNumberError> {
+    if prefix.len() > MAX_INSURANCE_PREFIX_LEN
+        || !prefix
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    {
+        return Err(InsuranceNumberError::Prefix(prefix.to_string()));
+    }
+    if digits == 0 || digits > MAX_INSURANCE_DIGITS {
+        return Err(InsuranceNumberError::Digits(digits));
+    }
+    Ok(())
+}
+
+/// Luhn mod 36 code point of a character, or `None` for separators.
+#[inline]
+fn code_point(b: u8) -> Option<u32> {
+    CHECK_CHARS.iter().position(|&c| c == b).map(|i| i as u32)
+}
+
+/// Luhn mod 36 sum over the code points from the right, doubling every
+/// second one starting with the rightmost if `double_first`.
+fn luhn36_sum(body: &str, double_first: bool) -> u32 {
+    let n = CHECK_CHARS.len() as u32;
+    let mut double = double_first;
+    let mut sum = 0;
+    for value in body.bytes().rev().filter_map(code_point) {
+        let addend = if double { value * 2 } else { value };
+        sum += addend / n + addend % n;
+        double = !double;
+    }
+    sum
+}
+
+/// Luhn mod 36 check character for the letters and digits of `body`.
+fn check_character(body: &str) -> char {
+    let n = CHECK_CHARS.len() as u32;
+    let check = (n - luhn36_sum(body, true) % n) % n;
+    char::from(CHECK_CHARS[check as usize])
+}
+
+/// Validate a policy or claim number's check character.
+///
+/// Hyphens are ignored; every other character must be A-Z or 0-9.
+pub fn validate_insurance_number(number: &str) -> bool {
+    let n = CHECK_CHARS.len() as u32;
+    number.len() > 1
+        && number.bytes().all(|b| b == b'-' || code_point(b).is_some())
+        && luhn36_sum(number, false).is_multiple_of(n)
+}
+
+/// Build one number from validated parts.
+fn insurance_number(rng: &mut ForgeryRng, prefix: &str, digits: u32) -> String {
+    let year = rng.gen_range(INSURANCE_YEARS.0, INSURANCE_YEARS.1);
+    let sequence = rng.gen_range(1, 10u64.pow(digits) - 1);
+    let mut number = if prefix.is_empty() {
+        format!("{}-{:0width$}", year, sequence, width = digits as usize)
+    } else {
+        format!(
+            "{}-{}-{:0width$}",
+            prefix,
+            year,
+            sequence,
+            width = digits as usize
+        )
+    };
+    let check = check_character(&number);
+    number.push(check);
+    number
+}
+
+/// Generate a batch of policy or claim numbers.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of values to generate
+/// * `prefix` - Leading segment such as "POL" or "CLM"; omitted if empty
+/// * `digits` - Width of the zero-padded sequence number
+///
+/// # Errors
+///
+/// Returns `InsuranceNumberError` if the prefix or width is invalid.
+pub fn generate_insurance_numbers(
+    rng: &mut ForgeryRng,
+    n: usize,
+    prefix: &str,
+    digits: u32,
+) -> Result<Vec<String>, InsuranceNumberError> {
+    validate_insurance_format(prefix, digits)?;
+    let mut numbers = Vec::with_capacity(n);
+    for _ in 0..n {
+        numbers.push(insurance_number(rng, prefix, digits));
+    }
+    Ok(numbers)
+}
+
+/// Generate a single policy or claim number (e.g., "POL-2023-00482137K").
+///
+/// # Errors
+///
+/// Returns `InsuranceNumberError` if the prefix or width is invalid.
+#[inline]
+pub fn generate_insurance_number(
+    rng: &mut ForgeryRng,
+    prefix: &str,
+    digits: u32,
+) -> Result<String, InsuranceNumberError> {
+    validate_insurance_format(prefix, digits)?;
+    Ok(insurance_number(rng, prefix, digits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> ForgeryRng {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        rng
+    }
+
+    #[test]
+    fn test_check_character() {
+        // From the right: A (10) doubled is 20, plus 1 is 21, so the check is 36 - 21 = 15.
+        assert_eq!(check_character("1A"), 'F');
+        assert!(validate_insurance_number("1AF"));
+        assert!(!validate_insurance_number("1AG"));
+    }
+
+    #[test]
+    fn test_insurance_numbers() {
+        let numbers =
+            generate_insurance_numbers(&mut seeded(), 500, DEFAULT_POLICY_PREFIX, 8).unwrap();
+        for number in &numbers {
+            let parts: Vec<&str> = number.split('-').collect();
+            assert_eq!(parts.len(), 3, "{}", number);
+            assert_eq!(parts[0], "POL");
+            let year: u32 = parts[1].parse().unwrap();
+            assert!((2020..=2024).contains(&year), "{}", number);
+            assert_eq!(parts[2].len(), 9, "{}", number);
+            assert!(parts[2][..8].bytes().all(|b| b.is_ascii_digit()));
+            assert!(validate_insurance_number(number), "{}", number);
+        }
+        assert!(numbers
+            .iter()
+            .any(|n| n.as_bytes()[17].is_ascii_uppercase()));
+    }
+
+    #[test]
+    fn test_check_character_detects_typos() {
+        let number = generate_insurance_number(&mut seeded(), "CLM", 6).unwrap();
+        let mut typo = number.clone().into_bytes();
+        typo[5] = if typo[5] == b'9' { b'8' } else { typo[5] + 1 };
+        assert!(!validate_insurance_number(
+            &String::from_utf8(typo).unwrap()
+        ));
+
+        let bare = generate_insurance_number(&mut seeded(), "", 4).unwrap();
+        assert_eq!(bare.len(), 10, "{}", bare);
+        assert!(validate_insurance_number(&bare));
+    }
+
+    #[test]
+    fn test_invalid_format() {
+        assert_eq!(
+            generate_insurance_number(&mut seeded(), "pol", 8),
+            Err(InsuranceNumberError::Prefix("pol".to_string()))
+        );
+        assert_eq!(
+            generate_insurance_numbers(&mut seeded(), 1, "POL", 0),
+            Err(InsuranceNumberError::Digits(0))
+        );
+        assert_eq!(
+            generate_insurance_numbers(&mut seeded(), 1, "POL", 13),
+            Err(InsuranceNumberError::Digits(13))
+        );
+    }
+}
@@ -4,8 +4,11 @@
 
 use crate::data::en_us::{FREE_EMAIL_DOMAINS, SAFE_EMAIL_DOMAINS};
 use crate::data::get_locale_data;
+use crate::data::overrides::{choose_with_override, LocaleOverrides};
 use crate::locale::Locale;
+use crate::providers::company;
 use crate::rng::ForgeryRng;
+use std::str::FromStr;
 
 /// Common email domains for generation.
 const EMAIL_DOMAINS: &[&str] = &[
@@ -21,9 +24,19 @@ const EMAIL_DOMAINS: &[&str] = &[
 
 /// Generate a batch of email addresses.
 pub fn generate_emails(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    generate_emails_with_overrides(rng, locale, &LocaleOverrides::EMPTY, n)
+}
+
+/// Generate a batch of email addresses, drawing names from user overrides where set.
+pub fn generate_emails_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<String> {
     let mut emails = Vec::with_capacity(n);
     for _ in 0..n {
-        emails.push(generate_email(rng, locale));
+        emails.push(generate_email_with_overrides(rng, locale, overrides));
     }
     emails
 }
@@ -33,17 +46,537 @@ pub fn generate_emails(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<St
 /// Uses romanized first names for non-Latin locales.
 #[inline]
 pub fn generate_email(rng: &mut ForgeryRng, locale: Locale) -> String {
+    generate_email_with_overrides(rng, locale, &LocaleOverrides::EMPTY)
+}
+
+/// Generate a single email address, drawing the name from user overrides where set.
+#[inline]
+pub fn generate_email_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> String {
+    let name = email_name(rng, locale, overrides);
+    let num: u16 = rng.gen_range(1, 999);
+    let domain = rng.choose(EMAIL_DOMAINS);
+    format!("{}{:03}@{}", name, num, domain)
+}
+
+/// Lowercase first name for an email local part.
+///
+/// Locale names are romanized (important for non-Latin scripts like
+/// Japanese); override names are folded to ASCII so the address stays valid.
+fn email_name(rng: &mut ForgeryRng, locale: Locale, overrides: &LocaleOverrides) -> String {
+    if let Some(names) = overrides.first_names.as_deref().filter(|v| !v.is_empty()) {
+        return ascii_slug(rng.choose(names).as_str(), "user");
+    }
     let data = get_locale_data(locale);
-    // Use romanized names for email (important for non-Latin scripts like Japanese)
     let names = data.romanized_first_names().unwrap_or(&[]);
-    let name = if names.is_empty() {
-        "user"
+    if names.is_empty() {
+        "user".to_string()
     } else {
-        rng.choose(names)
+        rng.choose(names).to_lowercase()
+    }
+}
+
+/// Error for an email domain that is not a valid hostname.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailDomainError {
+    /// The rejected domain.
+    pub domain: String,
+}
+
+impl std::fmt::Display for EmailDomainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid email domain '{}'; expected dot-separated labels of letters, digits and hyphens",
+            self.domain
+        )
+    }
+}
+
+impl std::error::Error for EmailDomainError {}
+
+/// Validate an email domain as an RFC 1123 hostname.
+///
+/// Each label is 1-63 ASCII letters, digits or hyphens and does not start or
+/// end with a hyphen; the whole domain is at most 253 characters.
+pub fn validate_email_domain(domain: &str) -> Result<(), EmailDomainError> {
+    let valid_label = |label: &str| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-')
     };
+    if domain.len() <= 253 && domain.split('.').all(valid_label) {
+        Ok(())
+    } else {
+        Err(EmailDomainError {
+            domain: domain.to_string(),
+        })
+    }
+}
+
+/// Generate a batch of email addresses on a fixed domain.
+///
+/// # Errors
+///
+/// Returns `EmailDomainError` if the domain is not a valid hostname.
+pub fn generate_emails_with_domain(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+    domain: &str,
+) -> Result<Vec<String>, EmailDomainError> {
+    validate_email_domain(domain)?;
+    let mut emails = Vec::with_capacity(n);
+    for _ in 0..n {
+        emails.push(email_at(rng, locale, overrides, domain));
+    }
+    Ok(emails)
+}
+
+/// Generate a single email address on a fixed domain (e.g., "james042@corp.example").
+///
+/// # Errors
+///
+/// Returns `EmailDomainError` if the domain is not a valid hostname.
+#[inline]
+pub fn generate_email_with_domain(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    domain: &str,
+) -> Result<String, EmailDomainError> {
+    validate_email_domain(domain)?;
+    Ok(email_at(rng, locale, overrides, domain))
+}
+
+/// Email address in the `generate_email` style on an already validated domain.
+pub(crate) fn email_at(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    domain: &str,
+) -> String {
+    let name = email_name(rng, locale, overrides);
     let num: u16 = rng.gen_range(1, 999);
-    let domain = rng.choose(EMAIL_DOMAINS);
-    format!("{}{:03}@{}", name.to_lowercase(), num, domain)
+    format!("{}{:03}@{}", name, num, domain.to_ascii_lowercase())
+}
+
+/// Shape of a generated username or email local part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UsernameStyle {
+    /// One of the styles below, chosen per value.
+    #[default]
+    Any,
+    /// First name and a 3-digit number ("james042"), as used by `email()`.
+    FirstNumber,
+    /// First and last name joined by a dot ("james.smith").
+    FirstLast,
+    /// First initial and last name ("jsmith").
+    FLast,
+    /// First name, last initial and a 2-digit number ("james_s42").
+    FirstLNumber,
+}
+
+/// Accepted names for `UsernameStyle`, in declaration order.
+pub const USERNAME_STYLES: &[&str] = &["any", "first###", "first.last", "flast", "first_l##"];
+
+/// Error for an unknown username style name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsernameStyleError {
+    /// The unrecognized style name.
+    pub style: String,
+}
+
+impl std::fmt::Display for UsernameStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown username style '{}'; expected one of: {}",
+            self.style,
+            USERNAME_STYLES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UsernameStyleError {}
+
+impl FromStr for UsernameStyle {
+    type Err = UsernameStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "any" => Ok(UsernameStyle::Any),
+            "first###" => Ok(UsernameStyle::FirstNumber),
+            "first.last" => Ok(UsernameStyle::FirstLast),
+            "flast" => Ok(UsernameStyle::FLast),
+            "first_l##" => Ok(UsernameStyle::FirstLNumber),
+            _ => Err(UsernameStyleError {
+                style: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Style, leetspeak and case of generated usernames.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UsernameOptions {
+    /// Layout of the name parts and digits.
+    pub style: UsernameStyle,
+    /// Replace a, e, i, o, s and t with 4, 3, 1, 0, 5 and 7.
+    pub leet: bool,
+    /// Capitalize each name part ("James.Smith") instead of all-lowercase.
+    pub mixed_case: bool,
+}
+
+/// Username options for email local parts, or `None` for the plain
+/// `email()` style when no option is set.
+///
+/// Without a `style`, emails keep the "first###" layout.
+///
+/// # Errors
+///
+/// Returns `UsernameStyleError` if `style` is not a known style name.
+pub fn email_username_options(
+    style: Option<&str>,
+    leet: bool,
+    mixed_case: bool,
+) -> Result<Option<UsernameOptions>, UsernameStyleError> {
+    if style.is_none() && !leet && !mixed_case {
+        return Ok(None);
+    }
+    let style = match style {
+        Some(style) => style.parse()?,
+        None => UsernameStyle::FirstNumber,
+    };
+    Ok(Some(UsernameOptions {
+        style,
+        leet,
+        mixed_case,
+    }))
+}
+
+/// Leetspeak digit for a letter, if it has one.
+#[inline]
+fn leet_char(c: char) -> char {
+    match c.to_ascii_lowercase() {
+        'a' => '4',
+        'e' => '3',
+        'i' => '1',
+        'o' => '0',
+        's' => '5',
+        't' => '7',
+        _ => c,
+    }
+}
+
+/// Uppercase the first letter of a lowercase ASCII slug.
+fn capitalized(slug: &str) -> String {
+    let mut chars = slug.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Build one username from romanized locale names or user overrides.
+pub(crate) fn username(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    options: &UsernameOptions,
+) -> String {
+    let data = get_locale_data(locale);
+    let mut part = |custom: Option<&[String]>, names: Option<&'static [&'static str]>, fallback| {
+        let name = choose_with_override(rng, custom, names.unwrap_or(&[]), fallback);
+        ascii_slug(name, fallback)
+    };
+    let mut first = part(
+        overrides.first_names.as_deref(),
+        data.romanized_first_names(),
+        "user",
+    );
+    let mut last = part(
+        overrides.last_names.as_deref(),
+        data.romanized_last_names(),
+        "name",
+    );
+    if options.mixed_case {
+        first = capitalized(&first);
+        last = capitalized(&last);
+    }
+    let style = match options.style {
+        UsernameStyle::Any => *rng.choose(&[
+            UsernameStyle::FirstNumber,
+            UsernameStyle::FirstLast,
+            UsernameStyle::FLast,
+            UsernameStyle::FirstLNumber,
+        ]),
+        style => style,
+    };
+    let username = match style {
+        UsernameStyle::FirstNumber => format!("{}{:03}", first, rng.gen_range(1u16, 999)),
+        UsernameStyle::FirstLast => format!("{}.{}", first, last),
+        UsernameStyle::FLast => format!("{}{}", &first[..1], last),
+        UsernameStyle::FirstLNumber | UsernameStyle::Any => {
+            format!("{}_{}{:02}", first, &last[..1], rng.gen_range(0u8, 99))
+        }
+    };
+    if options.leet {
+        username.chars().map(leet_char).collect()
+    } else {
+        username
+    }
+}
+
+/// Generate a batch of usernames.
+///
+/// Names are romanized and folded to ASCII, so every locale gives usernames
+/// of letters, digits, dots and underscores.
+pub fn generate_usernames(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+    options: &UsernameOptions,
+) -> Vec<String> {
+    let mut usernames = Vec::with_capacity(n);
+    for _ in 0..n {
+        usernames.push(username(rng, locale, overrides, options));
+    }
+    usernames
+}
+
+/// Generate a single username (e.g., "james.smith", "jsmith" or "james_s42").
+#[inline]
+pub fn generate_username(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    options: &UsernameOptions,
+) -> String {
+    username(rng, locale, overrides, options)
+}
+
+/// Email address with a styled local part on `domain`, or on a random
+/// free-mail domain if `None`. The domain must already be validated.
+pub(crate) fn styled_email(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    domain: Option<&str>,
+    options: &UsernameOptions,
+) -> String {
+    let local = username(rng, locale, overrides, options);
+    match domain {
+        Some(domain) => format!("{}@{}", local, domain.to_ascii_lowercase()),
+        None => format!("{}@{}", local, rng.choose(EMAIL_DOMAINS)),
+    }
+}
+
+/// Generate a batch of email addresses whose local parts follow `options`.
+///
+/// # Errors
+///
+/// Returns `EmailDomainError` if the domain is not a valid hostname.
+pub fn generate_styled_emails(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+    domain: Option<&str>,
+    options: &UsernameOptions,
+) -> Result<Vec<String>, EmailDomainError> {
+    if let Some(domain) = domain {
+        validate_email_domain(domain)?;
+    }
+    let mut emails = Vec::with_capacity(n);
+    for _ in 0..n {
+        emails.push(styled_email(rng, locale, overrides, domain, options));
+    }
+    Ok(emails)
+}
+
+/// Words dropped from the end of a company name when deriving its domain,
+/// as lowercase ASCII slugs of the legal forms they abbreviate.
+const LEGAL_FORM_WORDS: &[&str] = &[
+    "inc",
+    "incorporated",
+    "llc",
+    "llp",
+    "lp",
+    "ltd",
+    "limited",
+    "corp",
+    "corporation",
+    "co",
+    "company",
+    "plc",
+    "ltee",
+    "gmbh",
+    "ag",
+    "kg",
+    "ohg",
+    "ug",
+    "se",
+    "sa",
+    "sl",
+    "slu",
+    "scoop",
+    "sarl",
+    "sas",
+    "sasu",
+    "eurl",
+    "snc",
+    "spa",
+    "srl",
+    "bv",
+    "nv",
+    "vof",
+    "cv",
+    "sp",
+    "z",
+    "oo",
+    "spj",
+    "spk",
+];
+
+/// Web domain derived from a company name ("Acme Holdings LLC" becomes "acmeholdings.com").
+///
+/// Trailing legal forms are dropped and the remaining words are folded to
+/// lowercase ASCII letters and digits; a name with no Latin letters left
+/// gives "company.com".
+pub fn company_domain(company: &str) -> String {
+    let mut words: Vec<String> = company
+        .split_whitespace()
+        .map(|word| ascii_slug(word, ""))
+        .filter(|word| !word.is_empty())
+        .collect();
+    while words.len() > 1
+        && words
+            .last()
+            .is_some_and(|word| LEGAL_FORM_WORDS.contains(&word.as_str()))
+    {
+        words.pop();
+    }
+    let slug = words.concat();
+    if slug.is_empty() {
+        "company.com".to_string()
+    } else {
+        format!("{}.com", slug)
+    }
+}
+
+/// Generate a batch of corporate email addresses (`first.last@domain`).
+///
+/// All addresses share one domain so the batch looks like one organization:
+/// `domain` if given, otherwise one derived from `company`, otherwise one
+/// derived from a generated company name (e.g., "acmeholdings.com").
+///
+/// # Errors
+///
+/// Returns `EmailDomainError` if the domain is not a valid hostname.
+pub fn generate_company_emails(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    domain: Option<&str>,
+    company: Option<&str>,
+) -> Result<Vec<String>, EmailDomainError> {
+    let domain = match (domain, company) {
+        (Some(domain), _) => {
+            validate_email_domain(domain)?;
+            domain.to_ascii_lowercase()
+        }
+        (None, Some(company)) => company_domain(company),
+        (None, None) => company_domain(&company::generate_romanized_company(rng, locale)),
+    };
+    let mut emails = Vec::with_capacity(n);
+    for _ in 0..n {
+        emails.push(company_email_at(rng, locale, &domain));
+    }
+    Ok(emails)
+}
+
+/// Generate a single corporate email address (e.g., "jane.smith@acmeholdings.com").
+///
+/// Without `domain`, the domain is derived from `company` or from a
+/// generated company name.
+///
+/// # Errors
+///
+/// Returns `EmailDomainError` if the domain is not a valid hostname.
+#[inline]
+pub fn generate_company_email(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    domain: Option<&str>,
+    company: Option<&str>,
+) -> Result<String, EmailDomainError> {
+    Ok(generate_company_emails(rng, locale, 1, domain, company)?.remove(0))
+}
+
+/// `first.last@domain` with the names reduced to lowercase ASCII letters and digits.
+fn company_email_at(rng: &mut ForgeryRng, locale: Locale, domain: &str) -> String {
+    let data = get_locale_data(locale);
+    let mut part = |names: Option<&'static [&'static str]>, fallback: &str| {
+        let name = match names {
+            Some(names) if !names.is_empty() => *rng.choose(names),
+            _ => fallback,
+        };
+        let slug: String = name
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        if slug.is_empty() {
+            fallback.to_string()
+        } else {
+            slug
+        }
+    };
+    let first = part(data.romanized_first_names(), "user");
+    let last = part(data.romanized_last_names(), "name");
+    format!("{}.{}@{}", first, last, domain)
+}
+
+/// Lowercase ASCII letters and digits of a name, with common Latin
+/// diacritics folded (e.g., "Jürgen" becomes "juergen").
+pub(crate) fn ascii_slug(name: &str, fallback: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+    for c in name.chars().flat_map(char::to_lowercase) {
+        match c {
+            'a'..='z' | '0'..='9' => slug.push(c),
+            'ä' | 'æ' => slug.push_str("ae"),
+            'ö' | 'ø' | 'œ' => slug.push_str("oe"),
+            'ü' => slug.push_str("ue"),
+            'ß' => slug.push_str("ss"),
+            'à' | 'á' | 'â' | 'ã' | 'å' | 'ą' => slug.push('a'),
+            'ç' | 'ć' | 'č' => slug.push('c'),
+            'è' | 'é' | 'ê' | 'ë' | 'ę' => slug.push('e'),
+            'ì' | 'í' | 'î' | 'ï' => slug.push('i'),
+            'ł' => slug.push('l'),
+            'ñ' | 'ń' => slug.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' => slug.push('o'),
+            'ś' | 'š' => slug.push('s'),
+            'ù' | 'ú' | 'û' => slug.push('u'),
+            'ý' | 'ÿ' => slug.push('y'),
+            'ź' | 'ż' | 'ž' => slug.push('z'),
+            _ => {}
+        }
+    }
+    if slug.is_empty() {
+        fallback.to_string()
+    } else {
+        slug
+    }
 }
 
 /// Generate a batch of safe email addresses.
@@ -107,6 +640,137 @@ pub fn generate_free_email(rng: &mut ForgeryRng, locale: Locale) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ascii_slug() {
+        assert_eq!(ascii_slug("Jürgen", "user"), "juergen");
+        assert_eq!(ascii_slug("Étienne", "user"), "etienne");
+        assert_eq!(ascii_slug("Marc-André", "user"), "marcandre");
+        assert_eq!(ascii_slug("Łukasz", "user"), "lukasz");
+        assert_eq!(ascii_slug("陽翔", "user"), "user");
+    }
+
+    #[test]
+    fn test_username_styles() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let generate = |rng: &mut ForgeryRng, style: &str| {
+            let options = UsernameOptions {
+                style: style.parse().unwrap(),
+                ..UsernameOptions::default()
+            };
+            generate_usernames(rng, Locale::EnUS, &LocaleOverrides::EMPTY, 50, &options)
+        };
+        for name in generate(&mut rng, "first.last") {
+            let (first, last) = name.split_once('.').unwrap();
+            assert!(first.bytes().all(|b| b.is_ascii_lowercase()), "{}", name);
+            assert!(last.bytes().all(|b| b.is_ascii_lowercase()), "{}", name);
+        }
+        for name in generate(&mut rng, "first###") {
+            let digits = &name[name.len() - 3..];
+            assert!(digits.bytes().all(|b| b.is_ascii_digit()), "{}", name);
+        }
+        for name in generate(&mut rng, "flast") {
+            assert!(name.bytes().all(|b| b.is_ascii_lowercase()), "{}", name);
+        }
+        for name in generate(&mut rng, "first_l##") {
+            let (_, tail) = name.split_once('_').unwrap();
+            assert_eq!(tail.len(), 3, "{}", name);
+            assert!(tail[1..].bytes().all(|b| b.is_ascii_digit()), "{}", name);
+        }
+        let err = "first-last".parse::<UsernameStyle>().unwrap_err();
+        assert!(err.to_string().contains("'first-last'"));
+    }
+
+    #[test]
+    fn test_username_leet_and_case() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let options = UsernameOptions {
+            style: UsernameStyle::FirstLast,
+            leet: true,
+            mixed_case: true,
+        };
+        for name in generate_usernames(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            50,
+            &options,
+        ) {
+            assert!(!name.contains(['a', 'e', 'i', 'o', 's', 't']), "{}", name);
+            assert!(!name.contains(['A', 'E', 'I', 'O', 'S', 'T']), "{}", name);
+        }
+        let options = UsernameOptions {
+            style: UsernameStyle::FirstLast,
+            mixed_case: true,
+            ..UsernameOptions::default()
+        };
+        for name in generate_usernames(
+            &mut rng,
+            Locale::DeDE,
+            &LocaleOverrides::EMPTY,
+            50,
+            &options,
+        ) {
+            let (first, last) = name.split_once('.').unwrap();
+            assert!(
+                first.starts_with(|c: char| c.is_ascii_uppercase()),
+                "{}",
+                name
+            );
+            assert!(
+                last.starts_with(|c: char| c.is_ascii_uppercase()),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_styled_emails() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        assert_eq!(email_username_options(None, false, false), Ok(None));
+        let options = email_username_options(None, false, true).unwrap().unwrap();
+        assert_eq!(options.style, UsernameStyle::FirstNumber);
+        let options = email_username_options(Some("flast"), false, false)
+            .unwrap()
+            .unwrap();
+        let emails = generate_styled_emails(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            20,
+            Some("Corp.Example"),
+            &options,
+        )
+        .unwrap();
+        assert!(emails.iter().all(|e| e.ends_with("@corp.example")));
+        let emails = generate_styled_emails(
+            &mut rng,
+            Locale::JaJP,
+            &LocaleOverrides::EMPTY,
+            20,
+            None,
+            &options,
+        )
+        .unwrap();
+        for email in &emails {
+            let (local, domain) = email.split_once('@').unwrap();
+            assert!(local.bytes().all(|b| b.is_ascii_lowercase()), "{}", email);
+            assert!(EMAIL_DOMAINS.contains(&domain));
+        }
+        assert!(generate_styled_emails(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            1,
+            Some("bad domain"),
+            &options
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_generate_emails_count() {
         let mut rng = ForgeryRng::new();
@@ -384,6 +1048,11 @@ mod tests {
             Locale::EsES,
             Locale::ItIT,
             Locale::JaJP,
+            Locale::KoKR,
+            Locale::RuRU,
+            Locale::NlNL,
+            Locale::PlPL,
+            Locale::EnCA,
         ] {
             let email = generate_email(&mut rng, locale);
             assert!(email.contains('@'), "Email should have @ for {:?}", locale);
@@ -396,6 +1065,121 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_validate_email_domain() {
+        assert!(validate_email_domain("corp.example").is_ok());
+        assert!(validate_email_domain("mail.test-co.example").is_ok());
+        assert!(validate_email_domain("localhost").is_ok());
+        for domain in [
+            "",
+            "corp..example",
+            "-corp.example",
+            "corp-.example",
+            "co rp.example",
+        ] {
+            assert_eq!(
+                validate_email_domain(domain).unwrap_err().domain,
+                domain,
+                "{}",
+                domain
+            );
+        }
+        assert!(validate_email_domain(&format!("{}.example", "a".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn test_emails_with_domain() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let emails = generate_emails_with_domain(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+            "Corp.Example",
+        )
+        .unwrap();
+        for email in &emails {
+            let (local, domain) = email.split_once('@').unwrap();
+            assert_eq!(domain, "corp.example");
+            assert!(!local.is_empty());
+        }
+        assert!(generate_email_with_domain(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            "bad domain"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_company_emails() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let emails =
+            generate_company_emails(&mut rng, Locale::EnUS, 100, Some("corp.example"), None)
+                .unwrap();
+        for email in &emails {
+            let (local, domain) = email.split_once('@').unwrap();
+            assert_eq!(domain, "corp.example");
+            let (first, last) = local.split_once('.').expect(email);
+            assert!(!first.is_empty() && !last.is_empty(), "{}", email);
+            assert!(
+                local
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'.'),
+                "{}",
+                email
+            );
+        }
+
+        let emails = generate_company_emails(&mut rng, Locale::JaJP, 50, None, None).unwrap();
+        let domain = emails[0].split('@').nth(1).unwrap();
+        assert!(validate_email_domain(domain).is_ok());
+        assert!(emails.iter().all(|e| e.is_ascii() && e.ends_with(domain)));
+
+        assert!(
+            generate_company_email(&mut rng, Locale::EnUS, Some("corp..example"), None).is_err()
+        );
+    }
+
+    #[test]
+    fn test_company_domain() {
+        assert_eq!(company_domain("Acme Holdings LLC"), "acmeholdings.com");
+        assert_eq!(company_domain("Müller GmbH & Co. KG"), "mueller.com");
+        assert_eq!(company_domain("Nowak Sp. z o.o."), "nowak.com");
+        assert_eq!(company_domain("Banco Iberia S.A."), "bancoiberia.com");
+        assert_eq!(company_domain("Co"), "co.com");
+        assert_eq!(company_domain("株式会社"), "company.com");
+    }
+
+    #[test]
+    fn test_company_emails_from_company() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let emails =
+            generate_company_emails(&mut rng, Locale::EnUS, 20, None, Some("Acme Holdings LLC"))
+                .unwrap();
+        assert!(emails.iter().all(|e| e.ends_with("@acmeholdings.com")));
+
+        let email =
+            generate_company_email(&mut rng, Locale::EnUS, Some("corp.example"), Some("Acme"))
+                .unwrap();
+        assert!(email.ends_with("@corp.example"));
+
+        for locale in [Locale::EnUS, Locale::DeDE, Locale::JaJP, Locale::RuRU] {
+            let email = generate_company_email(&mut rng, locale, None, None).unwrap();
+            let domain = email.split_once('@').unwrap().1;
+            assert!(domain.ends_with(".com"), "{}", email);
+            assert_ne!(domain, "company.com", "{}", email);
+            assert!(validate_email_domain(domain).is_ok(), "{}", email);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -515,5 +1299,18 @@ mod proptest_tests {
                 prop_assert!(FREE_EMAIL_DOMAINS.contains(&domain));
             }
         }
+
+        #[test]
+        fn prop_email_with_domain(n in 0usize..100, seed in any::<u64>()) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+
+            let emails =
+                generate_emails_with_domain(&mut rng, Locale::EnUS, &LocaleOverrides::EMPTY, n, "corp.example").unwrap();
+            prop_assert_eq!(emails.len(), n);
+            for email in emails {
+                prop_assert!(email.ends_with("@corp.example"));
+            }
+        }
     }
 }
@@ -0,0 +1,104 @@
+//! Language generation provider.
+//!
+//! Generates ISO 639-1 two-letter language codes and English language names
+//! from an embedded list of widely spoken languages.
+
+use crate::data::en_us::LANGUAGES;
+use crate::rng::ForgeryRng;
+
+/// Generate a batch of ISO 639-1 language codes.
+pub fn generate_language_codes(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut codes = Vec::with_capacity(n);
+    for _ in 0..n {
+        codes.push(generate_language_code(rng));
+    }
+    codes
+}
+
+/// Generate a single ISO 639-1 language code (e.g., "de").
+#[inline]
+pub fn generate_language_code(rng: &mut ForgeryRng) -> String {
+    rng.choose(LANGUAGES).0.to_string()
+}
+
+/// Generate a batch of language names.
+pub fn generate_language_names(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_language_name(rng));
+    }
+    names
+}
+
+/// Generate a single English language name (e.g., "German").
+#[inline]
+pub fn generate_language_name(rng: &mut ForgeryRng) -> String {
+    rng.choose(LANGUAGES).1.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_data() {
+        for (code, name) in LANGUAGES {
+            assert_eq!(code.len(), 2, "{}", code);
+            assert!(code.bytes().all(|b| b.is_ascii_lowercase()), "{}", code);
+            assert!(!name.is_empty());
+        }
+        assert!(LANGUAGES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_generate_language_codes() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let codes = generate_language_codes(&mut rng, 200);
+        assert_eq!(codes.len(), 200);
+        assert!(codes.iter().all(|c| LANGUAGES.iter().any(|l| l.0 == c)));
+    }
+
+    #[test]
+    fn test_generate_language_names() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let names = generate_language_names(&mut rng, 200);
+        assert_eq!(names.len(), 200);
+        assert!(names.iter().all(|n| LANGUAGES.iter().any(|l| l.1 == n)));
+    }
+
+    #[test]
+    fn test_language_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_language_codes(&mut rng1, 50),
+            generate_language_codes(&mut rng2, 50)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_language_codes_known(seed in any::<u64>(), n in 0usize..100) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let codes = generate_language_codes(&mut rng, n);
+            prop_assert_eq!(codes.len(), n);
+            for code in codes {
+                prop_assert!(LANGUAGES.iter().any(|l| l.0 == code));
+            }
+        }
+    }
+}
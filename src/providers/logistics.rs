@@ -0,0 +1,285 @@
+//! Shipment tracking number generation provider.
+//!
+//! Generates tracking numbers in the formats of the major parcel carriers,
+//! each with the carrier's check digit.
+//!
+//! | Carrier | Example | Format |
+//! |---------|---------|--------|
+//! | UPS | `1Z5R89390357567127` | `1Z`, 6-character shipper, 2-digit service, 7-digit package, mod-10 check |
+//! | FedEx | `986967633643` | 12 digits (mod-11 check) or 15 digits (mod-10 check) |
+//! | USPS | `9400111202555842761025` | 22 digits with a 94xx/92xx/93xx prefix and mod-10 check |
+//! | DHL | `1234567891` | 10 digits, the last being the first nine modulo 7 |
+
+use crate::rng::ForgeryRng;
+use std::str::FromStr;
+
+/// Parcel carrier whose tracking number format to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Carrier {
+    /// UPS (`1Z` followed by 16 characters).
+    Ups,
+    /// FedEx (12 or 15 digits).
+    Fedex,
+    /// USPS (22-digit Intelligent Mail package barcode).
+    Usps,
+    /// DHL Express (10-digit waybill).
+    Dhl,
+}
+
+/// Accepted names for `Carrier`, in declaration order.
+pub const CARRIERS: &[&str] = &["ups", "fedex", "usps", "dhl"];
+
+/// Error for an unknown carrier name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CarrierError {
+    /// The unrecognized carrier name.
+    pub carrier: String,
+}
+
+impl std::fmt::Display for CarrierError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown carrier '{}'; expected one of: {}",
+            self.carrier,
+            CARRIERS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for CarrierError {}
+
+impl FromStr for Carrier {
+    type Err = CarrierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ups" => Ok(Carrier::Ups),
+            "fedex" => Ok(Carrier::Fedex),
+            "usps" => Ok(Carrier::Usps),
+            "dhl" => Ok(Carrier::Dhl),
+            _ => Err(CarrierError {
+                carrier: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Prefixes of USPS Intelligent Mail package barcodes (service type codes).
+const USPS_PREFIXES: &[&str] = &["9400", "9205", "9270", "9303", "9405", "9407"];
+
+/// Characters of a UPS shipper number.
+const UPS_SHIPPER_CHARS: &[u8] = b"0123456789ABCDEFGHJKLMNPRSTUVWXYZ";
+
+/// Random decimal digits as a string.
+fn random_digit_string(rng: &mut ForgeryRng, count: usize) -> String {
+    (0..count)
+        .map(|_| char::from(b'0' + rng.gen_range(0u8, 9)))
+        .collect()
+}
+
+/// Convert a check digit value to its character.
+#[inline]
+fn digit_char(value: u32) -> char {
+    char::from(b'0' + (value % 10) as u8)
+}
+
+/// UPS check digit over the 15 characters after `1Z`.
+///
+/// Letters count as `(code - 63) % 10` (A = 2, B = 3, ...); every second
+/// character is doubled.
+fn ups_check_digit(body: &str) -> char {
+    let sum: u32 = body
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| {
+            let value = if b.is_ascii_digit() {
+                u32::from(b - b'0')
+            } else {
+                u32::from(b - 63) % 10
+            };
+            if i % 2 == 1 {
+                value * 2
+            } else {
+                value
+            }
+        })
+        .sum();
+    digit_char((10 - sum % 10) % 10)
+}
+
+/// FedEx Express check digit over 11 digits (weights 1, 3, 7 from the right, mod 11).
+fn fedex_check_digit(body: &str) -> char {
+    let sum: u32 = body
+        .bytes()
+        .rev()
+        .zip([1, 3, 7].iter().cycle())
+        .map(|(b, w)| u32::from(b - b'0') * w)
+        .sum();
+    digit_char(sum % 11)
+}
+
+/// GS1 mod-10 check digit (weights 3, 1 from the right).
+fn mod10_check_digit(body: &str) -> char {
+    let sum: u32 = body
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| u32::from(b - b'0') * if i % 2 == 0 { 3 } else { 1 })
+        .sum();
+    digit_char((10 - sum % 10) % 10)
+}
+
+/// DHL Express check digit: the nine-digit body modulo 7.
+fn dhl_check_digit(body: &str) -> char {
+    let number: u32 = body.parse().expect("nine decimal digits");
+    digit_char(number % 7)
+}
+
+/// Generate a UPS tracking number (e.g., "1Z5R89390357567127").
+fn ups_tracking_number(rng: &mut ForgeryRng) -> String {
+    let shipper: String = (0..6)
+        .map(|_| char::from(*rng.choose(UPS_SHIPPER_CHARS)))
+        .collect();
+    let body = format!("{}{}", shipper, random_digit_string(rng, 9));
+    let check = ups_check_digit(&body);
+    format!("1Z{}{}", body, check)
+}
+
+/// Generate a 12-digit Express or 15-digit Ground FedEx tracking number.
+fn fedex_tracking_number(rng: &mut ForgeryRng) -> String {
+    if rng.gen_range(0u8, 1) == 0 {
+        let body = random_digit_string(rng, 11);
+        let check = fedex_check_digit(&body);
+        format!("{}{}", body, check)
+    } else {
+        let body = random_digit_string(rng, 14);
+        let check = mod10_check_digit(&body);
+        format!("{}{}", body, check)
+    }
+}
+
+/// Generate a 22-digit USPS tracking number.
+fn usps_tracking_number(rng: &mut ForgeryRng) -> String {
+    let prefix = *rng.choose(USPS_PREFIXES);
+    let body = format!("{}{}", prefix, random_digit_string(rng, 17));
+    let check = mod10_check_digit(&body);
+    format!("{}{}", body, check)
+}
+
+/// Generate a 10-digit DHL Express waybill number.
+fn dhl_tracking_number(rng: &mut ForgeryRng) -> String {
+    let body = random_digit_string(rng, 9);
+    let check = dhl_check_digit(&body);
+    format!("{}{}", body, check)
+}
+
+/// Generate a batch of tracking numbers.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of tracking numbers to generate
+/// * `carrier` - Carrier format to use; a random carrier per value if `None`
+pub fn generate_tracking_numbers(
+    rng: &mut ForgeryRng,
+    n: usize,
+    carrier: Option<Carrier>,
+) -> Vec<String> {
+    let mut numbers = Vec::with_capacity(n);
+    for _ in 0..n {
+        numbers.push(generate_tracking_number(rng, carrier));
+    }
+    numbers
+}
+
+/// Generate a single tracking number with a valid check digit.
+#[inline]
+pub fn generate_tracking_number(rng: &mut ForgeryRng, carrier: Option<Carrier>) -> String {
+    let carrier = carrier.unwrap_or_else(|| {
+        *rng.choose(&[Carrier::Ups, Carrier::Fedex, Carrier::Usps, Carrier::Dhl])
+    });
+    match carrier {
+        Carrier::Ups => ups_tracking_number(rng),
+        Carrier::Fedex => fedex_tracking_number(rng),
+        Carrier::Usps => usps_tracking_number(rng),
+        Carrier::Dhl => dhl_tracking_number(rng),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> ForgeryRng {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        rng
+    }
+
+    #[test]
+    fn test_check_digits_known_values() {
+        assert_eq!(ups_check_digit("5R8939035756712"), '7');
+        assert_eq!(ups_check_digit("999AA1012345678"), '4');
+        assert_eq!(fedex_check_digit("98696763364"), '3');
+        assert_eq!(dhl_check_digit("123456789"), '1');
+        assert_eq!(mod10_check_digit("940011120255584276102"), '5');
+    }
+
+    #[test]
+    fn test_ups_tracking_numbers() {
+        for number in generate_tracking_numbers(&mut seeded(), 200, Some(Carrier::Ups)) {
+            assert_eq!(number.len(), 18, "{}", number);
+            assert!(number.starts_with("1Z"), "{}", number);
+            assert!(number.ends_with(ups_check_digit(&number[2..17])));
+        }
+    }
+
+    #[test]
+    fn test_fedex_tracking_numbers() {
+        let numbers = generate_tracking_numbers(&mut seeded(), 200, Some(Carrier::Fedex));
+        for number in &numbers {
+            assert!(number.bytes().all(|b| b.is_ascii_digit()), "{}", number);
+            match number.len() {
+                12 => assert!(number.ends_with(fedex_check_digit(&number[..11]))),
+                15 => assert!(number.ends_with(mod10_check_digit(&number[..14]))),
+                other => panic!("unexpected length {}", other),
+            }
+        }
+        assert!(numbers.iter().any(|n| n.len() == 12));
+        assert!(numbers.iter().any(|n| n.len() == 15));
+    }
+
+    #[test]
+    fn test_usps_and_dhl_tracking_numbers() {
+        let mut rng = seeded();
+        for number in generate_tracking_numbers(&mut rng, 200, Some(Carrier::Usps)) {
+            assert_eq!(number.len(), 22, "{}", number);
+            assert!(USPS_PREFIXES.iter().any(|p| number.starts_with(p)));
+            assert!(number.ends_with(mod10_check_digit(&number[..21])));
+        }
+        for number in generate_tracking_numbers(&mut rng, 200, Some(Carrier::Dhl)) {
+            assert_eq!(number.len(), 10, "{}", number);
+            assert!(number.ends_with(dhl_check_digit(&number[..9])));
+        }
+    }
+
+    #[test]
+    fn test_carrier_from_str() {
+        for name in CARRIERS {
+            assert!(name.parse::<Carrier>().is_ok());
+        }
+        assert_eq!("UPS".parse::<Carrier>(), Ok(Carrier::Ups));
+        assert_eq!("FedEx".parse::<Carrier>(), Ok(Carrier::Fedex));
+        let err = "royal_mail".parse::<Carrier>().unwrap_err();
+        assert!(err.to_string().contains("'royal_mail'"));
+    }
+
+    #[test]
+    fn test_tracking_numbers_deterministic() {
+        assert_eq!(
+            generate_tracking_numbers(&mut seeded(), 50, None),
+            generate_tracking_numbers(&mut seeded(), 50, None)
+        );
+    }
+}
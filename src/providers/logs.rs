@@ -0,0 +1,694 @@
+//! Log line generation provider.
+//!
+//! Generates web server access log lines in the Apache/nginx combined log
+//! format and syslog messages, either as raw lines or as structured entries:
+//!
+//! ```text
+//! 203.0.113.42 - - [14/Mar/2024:09:26:53 +0000] "GET /api/v1/orders/4821 HTTP/1.1" 200 5120 "-" "Mozilla/5.0 ..."
+//! <86>1 2024-03-14T09:26:53.418Z web-03 sshd 4127 - - Accepted publickey for deploy from 198.51.100.7 port 52144 ssh2
+//! <86>Mar 14 09:26:53 web-03 sshd[4127]: Accepted publickey for deploy from 198.51.100.7 port 52144 ssh2
+//! ```
+//!
+//! Syslog lines follow RFC 5424 (the default) or the older BSD format of
+//! RFC 3164. Each message template carries its own facility and severity,
+//! so failed logins are `authpriv.warning` and cron runs `cron.info`.
+//!
+//! Timestamps fall within 2024 (UTC) so that seeded output does not depend
+//! on the current date.
+
+use crate::providers::network;
+use crate::rng::ForgeryRng;
+use chrono::{DateTime, Datelike, Timelike};
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Start of the log timestamp window: 2024-01-01T00:00:00Z.
+const LOG_WINDOW_START: i64 = 1_704_067_200;
+
+/// Length of the log timestamp window in seconds (the 366 days of 2024).
+const LOG_WINDOW_SECS: i64 = 366 * 86_400;
+
+/// English month abbreviations used in log timestamps.
+const MONTH_ABBREVIATIONS: &[&str] = &[
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// HTTP protocol versions with `HTTP/1.1` most common.
+const HTTP_PROTOCOLS: &[&str] = &["HTTP/1.1", "HTTP/1.1", "HTTP/1.1", "HTTP/2.0", "HTTP/1.0"];
+
+/// Fixed request paths.
+const STATIC_PATHS: &[&str] = &[
+    "/",
+    "/index.html",
+    "/favicon.ico",
+    "/robots.txt",
+    "/login",
+    "/logout",
+    "/health",
+    "/about",
+    "/contact",
+    "/cart",
+    "/checkout",
+];
+
+/// REST resources used in API paths.
+const API_RESOURCES: &[&str] = &["users", "orders", "products", "invoices", "sessions"];
+
+/// Search terms used in query strings.
+const SEARCH_TERMS: &[&str] = &[
+    "shoes",
+    "laptop",
+    "coffee",
+    "gift+card",
+    "headphones",
+    "desk",
+];
+
+/// Referrers other than `-`.
+const REFERRERS: &[&str] = &[
+    "https://www.google.com/",
+    "https://www.bing.com/",
+    "https://duckduckgo.com/",
+    "https://www.facebook.com/",
+    "https://t.co/",
+    "https://www.example.com/",
+];
+
+/// Browser, command-line and crawler user agents.
+const USER_AGENTS: &[&str] = &[
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/122.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.3 Safari/605.1.15",
+    "Mozilla/5.0 (X11; Linux x86_64; rv:123.0) Gecko/20100101 Firefox/123.0",
+    "Mozilla/5.0 (iPhone; CPU iPhone OS 17_3 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.3 Mobile/15E148 Safari/604.1",
+    "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/122.0.0.0 Mobile Safari/537.36",
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/122.0.0.0 Safari/537.36 Edg/122.0.0.0",
+    "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+    "Mozilla/5.0 (compatible; bingbot/2.0; +http://www.bing.com/bingbot.htm)",
+    "curl/8.4.0",
+    "python-requests/2.31.0",
+];
+
+/// A generated access log entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessLogEntry {
+    /// Client IP address
+    pub ip: String,
+    /// Request time in log format (e.g., "14/Mar/2024:09:26:53 +0000")
+    pub timestamp: String,
+    /// HTTP method
+    pub method: String,
+    /// Request path, possibly with a query string
+    pub path: String,
+    /// HTTP protocol version (e.g., "HTTP/1.1")
+    pub protocol: String,
+    /// Response status code
+    pub status: u16,
+    /// Response body size in bytes
+    pub size: u32,
+    /// Referrer URL, or "-" if none
+    pub referrer: String,
+    /// Client user agent
+    pub user_agent: String,
+}
+
+/// Access log entry field names, in the order they appear in records.
+pub const ACCESS_LOG_FIELDS: &[&str] = &[
+    "ip",
+    "timestamp",
+    "method",
+    "path",
+    "protocol",
+    "status",
+    "size",
+    "referrer",
+    "user_agent",
+];
+
+impl AccessLogEntry {
+    /// Field values as strings in `ACCESS_LOG_FIELDS` order.
+    pub fn into_values(self) -> [String; 9] {
+        [
+            self.ip,
+            self.timestamp,
+            self.method,
+            self.path,
+            self.protocol,
+            self.status.to_string(),
+            self.size.to_string(),
+            self.referrer,
+            self.user_agent,
+        ]
+    }
+
+    /// Format the entry as a combined log format line.
+    ///
+    /// Empty responses log their size as `-`, as Apache's `%b` does.
+    pub fn to_line(&self) -> String {
+        let mut line = String::with_capacity(96 + self.path.len() + self.user_agent.len());
+        let _ = write!(
+            line,
+            "{} - - [{}] \"{} {} {}\" {} ",
+            self.ip, self.timestamp, self.method, self.path, self.protocol, self.status
+        );
+        if self.size == 0 {
+            line.push('-');
+        } else {
+            let _ = write!(line, "{}", self.size);
+        }
+        let _ = write!(line, " \"{}\" \"{}\"", self.referrer, self.user_agent);
+        line
+    }
+}
+
+/// A random time within the log window.
+fn log_timestamp(rng: &mut ForgeryRng) -> DateTime<chrono::Utc> {
+    let secs = LOG_WINDOW_START + rng.gen_range(0, LOG_WINDOW_SECS - 1);
+    DateTime::from_timestamp(secs, 0).expect("timestamp within 2024")
+}
+
+/// Format a time as an access log timestamp (e.g., "14/Mar/2024:09:26:53 +0000").
+fn clf_timestamp(time: DateTime<chrono::Utc>) -> String {
+    format!(
+        "{:02}/{}/{}:{:02}:{:02}:{:02} +0000",
+        time.day(),
+        MONTH_ABBREVIATIONS[time.month0() as usize],
+        time.year(),
+        time.hour(),
+        time.minute(),
+        time.second()
+    )
+}
+
+/// Generate a request path: a page, an API resource, a static asset or a search.
+fn request_path(rng: &mut ForgeryRng) -> String {
+    match rng.gen_range(0u8, 9) {
+        0..=2 => rng.choose(STATIC_PATHS).to_string(),
+        3..=5 => {
+            let resource = *rng.choose(API_RESOURCES);
+            if rng.gen_range(0u8, 1) == 0 {
+                format!("/api/v1/{}", resource)
+            } else {
+                format!("/api/v1/{}/{}", resource, rng.gen_range(1u32, 99_999))
+            }
+        }
+        6 => format!("/static/js/app.{:08x}.js", rng.gen_range(0u32, u32::MAX)),
+        7 => format!("/static/css/main.{:08x}.css", rng.gen_range(0u32, u32::MAX)),
+        8 => format!("/images/{}.jpg", rng.gen_range(1u32, 9_999)),
+        _ => {
+            let term = *rng.choose(SEARCH_TERMS);
+            format!("/search?q={}&page={}", term, rng.gen_range(1u8, 5))
+        }
+    }
+}
+
+/// Response size in bytes for a status code: nothing for 204 and 304,
+/// small bodies for redirects and errors, up to 512 KiB otherwise.
+fn response_size(rng: &mut ForgeryRng, status: u16) -> u32 {
+    match status {
+        204 | 304 => 0,
+        300..=599 => rng.gen_range(150, 2_048),
+        _ => rng.gen_range(200, 524_288),
+    }
+}
+
+/// Generate a batch of structured access log entries.
+pub fn generate_access_log_entries(rng: &mut ForgeryRng, n: usize) -> Vec<AccessLogEntry> {
+    let mut entries = Vec::with_capacity(n);
+    for _ in 0..n {
+        entries.push(generate_access_log_entry(rng));
+    }
+    entries
+}
+
+/// Generate a single structured access log entry.
+pub fn generate_access_log_entry(rng: &mut ForgeryRng) -> AccessLogEntry {
+    let ip = network::generate_public_ipv4(rng);
+    let timestamp = clf_timestamp(log_timestamp(rng));
+    let method = rng.choose(network::HTTP_METHODS).to_string();
+    let path = request_path(rng);
+    let protocol = rng.choose(HTTP_PROTOCOLS).to_string();
+    let status = network::generate_http_status_code(rng, None);
+    let size = response_size(rng, status);
+    let referrer = if rng.gen_range(0u8, 1) == 0 {
+        "-".to_string()
+    } else {
+        rng.choose(REFERRERS).to_string()
+    };
+    let user_agent = rng.choose(USER_AGENTS).to_string();
+    AccessLogEntry {
+        ip,
+        timestamp,
+        method,
+        path,
+        protocol,
+        status,
+        size,
+        referrer,
+        user_agent,
+    }
+}
+
+/// Generate a batch of combined log format lines.
+pub fn generate_access_logs(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut lines = Vec::with_capacity(n);
+    for _ in 0..n {
+        lines.push(generate_access_log(rng));
+    }
+    lines
+}
+
+/// Generate a single combined log format line.
+#[inline]
+pub fn generate_access_log(rng: &mut ForgeryRng) -> String {
+    generate_access_log_entry(rng).to_line()
+}
+
+// === Syslog ===
+
+/// Syslog line format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SyslogFormat {
+    /// RFC 5424: `<PRI>1 TIMESTAMP HOSTNAME APP-NAME PROCID MSGID SD MSG`.
+    #[default]
+    Rfc5424,
+    /// RFC 3164 (BSD): `<PRI>Mmm dd hh:mm:ss HOSTNAME TAG[PID]: MSG`.
+    Rfc3164,
+}
+
+/// Accepted names for `SyslogFormat`, in declaration order.
+pub const SYSLOG_FORMATS: &[&str] = &["rfc5424", "rfc3164"];
+
+/// Error for an unknown syslog format name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyslogFormatError {
+    /// The unrecognized format name.
+    pub format: String,
+}
+
+impl std::fmt::Display for SyslogFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown syslog format '{}'; expected one of: {}",
+            self.format,
+            SYSLOG_FORMATS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for SyslogFormatError {}
+
+impl FromStr for SyslogFormat {
+    type Err = SyslogFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rfc5424" => Ok(SyslogFormat::Rfc5424),
+            "rfc3164" => Ok(SyslogFormat::Rfc3164),
+            _ => Err(SyslogFormatError {
+                format: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Syslog severity keywords, indexed by severity code.
+pub const SYSLOG_SEVERITIES: &[&str] = &[
+    "emerg", "alert", "crit", "err", "warning", "notice", "info", "debug",
+];
+
+/// Syslog facilities used by the message templates, with their codes.
+const SYSLOG_FACILITIES: &[(&str, u8)] = &[
+    ("kern", 0),
+    ("user", 1),
+    ("mail", 2),
+    ("daemon", 3),
+    ("auth", 4),
+    ("cron", 9),
+    ("authpriv", 10),
+    ("local0", 16),
+];
+
+/// A message template with its severity code. Templates may use `{user}`,
+/// `{ip}`, `{port}`, `{n}` and `{hex}` placeholders.
+type MessageTemplate = (&'static str, u8);
+
+/// Programs that log, with their facility and message templates.
+const SYSLOG_PROGRAMS: &[(&str, &str, &[MessageTemplate])] = &[
+    (
+        "sshd",
+        "authpriv",
+        &[
+            ("Accepted publickey for {user} from {ip} port {port} ssh2", 6),
+            ("Failed password for invalid user {user} from {ip} port {port} ssh2", 4),
+            ("Connection closed by {ip} port {port} [preauth]", 6),
+            ("pam_unix(sshd:session): session opened for user {user} by (uid=0)", 6),
+            ("error: maximum authentication attempts exceeded for {user} from {ip}", 3),
+        ],
+    ),
+    (
+        "sudo",
+        "authpriv",
+        &[
+            ("{user} : TTY=pts/{n} ; PWD=/home/{user} ; USER=root ; COMMAND=/usr/bin/systemctl restart nginx", 5),
+            ("pam_unix(sudo:auth): authentication failure; logname={user} uid=1000 euid=0 tty=/dev/pts/{n}", 5),
+        ],
+    ),
+    (
+        "CRON",
+        "cron",
+        &[
+            ("({user}) CMD (/usr/local/bin/backup.sh)", 6),
+            ("(root) CMD (run-parts /etc/cron.hourly)", 6),
+        ],
+    ),
+    (
+        "systemd",
+        "daemon",
+        &[
+            ("Started Session {n} of User {user}.", 6),
+            ("Starting Daily apt upgrade and clean activities...", 6),
+            ("nginx.service: Main process exited, code=exited, status=1/FAILURE", 3),
+            ("Reloading.", 7),
+        ],
+    ),
+    (
+        "dockerd",
+        "daemon",
+        &[
+            ("level=info msg=\"Container {hex} started\"", 6),
+            ("level=warning msg=\"Health check for container {hex} failed\"", 4),
+        ],
+    ),
+    (
+        "postfix/smtpd",
+        "mail",
+        &[
+            ("connect from unknown[{ip}]", 6),
+            ("disconnect from unknown[{ip}] ehlo=1 mail=1 rcpt=1 data=1 quit=1 commands=5", 6),
+            ("warning: hostname does not resolve to address {ip}", 4),
+        ],
+    ),
+    (
+        "app",
+        "local0",
+        &[
+            ("request completed in {n}ms", 7),
+            ("worker {n} out of memory, restarting", 2),
+            ("database connection pool exhausted", 1),
+            ("disk full on /var, shutting down", 0),
+        ],
+    ),
+];
+
+/// Hostname prefixes; hosts are numbered `web-01`, `db-02`, ...
+const SYSLOG_HOST_PREFIXES: &[&str] = &["web", "app", "db", "cache", "worker", "lb", "mail"];
+
+/// Login names used in messages.
+const SYSLOG_USERS: &[&str] = &[
+    "deploy", "ubuntu", "admin", "ec2-user", "jenkins", "backup", "git",
+];
+
+/// A generated syslog message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyslogEntry {
+    /// Message time in RFC 3339 form (e.g., "2024-03-14T09:26:53.418Z")
+    pub timestamp: String,
+    /// Host that sent the message
+    pub hostname: String,
+    /// Program name (APP-NAME in RFC 5424, TAG in RFC 3164)
+    pub app_name: String,
+    /// Process ID
+    pub pid: u32,
+    /// Facility keyword (e.g., "authpriv")
+    pub facility: String,
+    /// Severity keyword (e.g., "warning")
+    pub severity: String,
+    /// Free-form message text
+    pub message: String,
+    /// Priority value: facility code * 8 + severity code
+    pub priority: u8,
+}
+
+/// Syslog entry field names, in the order they appear in records.
+pub const SYSLOG_FIELDS: &[&str] = &[
+    "timestamp",
+    "hostname",
+    "app_name",
+    "pid",
+    "facility",
+    "severity",
+    "message",
+    "priority",
+];
+
+impl SyslogEntry {
+    /// Field values as strings in `SYSLOG_FIELDS` order.
+    pub fn into_values(self) -> [String; 8] {
+        [
+            self.timestamp,
+            self.hostname,
+            self.app_name,
+            self.pid.to_string(),
+            self.facility,
+            self.severity,
+            self.message,
+            self.priority.to_string(),
+        ]
+    }
+
+    /// Format the entry as a syslog line.
+    pub fn to_line(&self, format: SyslogFormat) -> String {
+        match format {
+            SyslogFormat::Rfc5424 => format!(
+                "<{}>1 {} {} {} {} - - {}",
+                self.priority, self.timestamp, self.hostname, self.app_name, self.pid, self.message
+            ),
+            SyslogFormat::Rfc3164 => format!(
+                "<{}>{} {} {}[{}]: {}",
+                self.priority,
+                bsd_timestamp(&self.timestamp),
+                self.hostname,
+                self.app_name,
+                self.pid,
+                self.message
+            ),
+        }
+    }
+}
+
+/// Convert an RFC 3339 timestamp ("2024-03-04T09:26:53.418Z") to the BSD
+/// syslog form ("Mar  4 09:26:53"), which pads single-digit days with a space.
+fn bsd_timestamp(timestamp: &str) -> String {
+    let month: usize = timestamp[5..7].parse().unwrap_or(1);
+    let day: u8 = timestamp[8..10].parse().unwrap_or(1);
+    format!(
+        "{} {:>2} {}",
+        MONTH_ABBREVIATIONS[month.clamp(1, 12) - 1],
+        day,
+        &timestamp[11..19]
+    )
+}
+
+/// Fill the placeholders of a message template.
+fn fill_template(rng: &mut ForgeryRng, template: &str) -> String {
+    let mut message = String::with_capacity(template.len() + 16);
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        message.push_str(&rest[..start]);
+        let end = start + rest[start..].find('}').expect("closed placeholder");
+        match &rest[start + 1..end] {
+            "user" => {
+                let user = *rng.choose(SYSLOG_USERS);
+                message.push_str(user);
+            }
+            "ip" => message.push_str(&network::generate_public_ipv4(rng)),
+            "port" => {
+                let _ = write!(message, "{}", rng.gen_range(1024u16, 65535));
+            }
+            "n" => {
+                let _ = write!(message, "{}", rng.gen_range(1u32, 9_999));
+            }
+            "hex" => {
+                let _ = write!(message, "{:012x}", rng.gen_range(0u64, (1 << 48) - 1));
+            }
+            other => unreachable!("unknown placeholder {{{}}}", other),
+        }
+        rest = &rest[end + 1..];
+    }
+    message.push_str(rest);
+    message
+}
+
+/// Generate a batch of structured syslog entries.
+pub fn generate_syslog_entries(rng: &mut ForgeryRng, n: usize) -> Vec<SyslogEntry> {
+    let mut entries = Vec::with_capacity(n);
+    for _ in 0..n {
+        entries.push(generate_syslog_entry(rng));
+    }
+    entries
+}
+
+/// Generate a single structured syslog entry.
+pub fn generate_syslog_entry(rng: &mut ForgeryRng) -> SyslogEntry {
+    let time = log_timestamp(rng);
+    let millis = rng.gen_range(0u32, 999);
+    let timestamp = format!("{}.{:03}Z", time.format("%Y-%m-%dT%H:%M:%S"), millis);
+    let hostname = format!(
+        "{}-{:02}",
+        rng.choose(SYSLOG_HOST_PREFIXES),
+        rng.gen_range(1u8, 12)
+    );
+    let &(app_name, facility, templates) = rng.choose(SYSLOG_PROGRAMS);
+    let &(template, severity) = rng.choose(templates);
+    let facility_code = SYSLOG_FACILITIES
+        .iter()
+        .find(|&&(name, _)| name == facility)
+        .map(|&(_, code)| code)
+        .expect("known facility");
+    SyslogEntry {
+        timestamp,
+        hostname,
+        app_name: app_name.to_string(),
+        pid: rng.gen_range(300, 65_535),
+        facility: facility.to_string(),
+        severity: SYSLOG_SEVERITIES[usize::from(severity)].to_string(),
+        message: fill_template(rng, template),
+        priority: facility_code * 8 + severity,
+    }
+}
+
+/// Generate a batch of syslog lines.
+pub fn generate_syslogs(rng: &mut ForgeryRng, n: usize, format: SyslogFormat) -> Vec<String> {
+    let mut lines = Vec::with_capacity(n);
+    for _ in 0..n {
+        lines.push(generate_syslog(rng, format));
+    }
+    lines
+}
+
+/// Generate a single syslog line.
+#[inline]
+pub fn generate_syslog(rng: &mut ForgeryRng, format: SyslogFormat) -> String {
+    generate_syslog_entry(rng).to_line(format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> ForgeryRng {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        rng
+    }
+
+    #[test]
+    fn test_access_log_line_format() {
+        let entry = AccessLogEntry {
+            ip: "203.0.113.42".to_string(),
+            timestamp: "14/Mar/2024:09:26:53 +0000".to_string(),
+            method: "GET".to_string(),
+            path: "/".to_string(),
+            protocol: "HTTP/1.1".to_string(),
+            status: 304,
+            size: 0,
+            referrer: "-".to_string(),
+            user_agent: "curl/8.4.0".to_string(),
+        };
+        assert_eq!(
+            entry.to_line(),
+            "203.0.113.42 - - [14/Mar/2024:09:26:53 +0000] \"GET / HTTP/1.1\" 304 - \"-\" \"curl/8.4.0\""
+        );
+    }
+
+    #[test]
+    fn test_access_log_entries() {
+        for entry in generate_access_log_entries(&mut seeded(), 500) {
+            assert!(entry.ip.parse::<std::net::Ipv4Addr>().is_ok());
+            assert!(entry.path.starts_with('/'), "{}", entry.path);
+            assert!(network::HTTP_METHODS.contains(&entry.method.as_str()));
+            if matches!(entry.status, 204 | 304) {
+                assert_eq!(entry.size, 0);
+            }
+            let time =
+                chrono::DateTime::parse_from_str(&entry.timestamp, "%d/%b/%Y:%H:%M:%S %z").unwrap();
+            assert_eq!(time.year(), 2024);
+        }
+    }
+
+    #[test]
+    fn test_access_log_lines_match_entries() {
+        let lines = generate_access_logs(&mut seeded(), 50);
+        let entries = generate_access_log_entries(&mut seeded(), 50);
+        for (line, entry) in lines.iter().zip(&entries) {
+            assert_eq!(line, &entry.to_line());
+            assert!(line.ends_with(&format!("\"{}\"", entry.user_agent)));
+        }
+    }
+
+    #[test]
+    fn test_syslog_line_formats() {
+        let mut rng = seeded();
+        let entry = generate_syslog_entry(&mut rng);
+        let rfc5424 = entry.to_line(SyslogFormat::Rfc5424);
+        let rfc3164 = entry.to_line(SyslogFormat::Rfc3164);
+        let pri = format!("<{}>", entry.priority);
+        assert!(rfc5424.starts_with(&format!("{}1 {} ", pri, entry.timestamp)));
+        assert!(rfc5424.ends_with(&format!(" {} - - {}", entry.pid, entry.message)));
+        assert!(rfc3164.starts_with(&pri));
+        assert!(rfc3164.ends_with(&format!(
+            " {} {}[{}]: {}",
+            entry.hostname, entry.app_name, entry.pid, entry.message
+        )));
+        assert_eq!(bsd_timestamp("2024-03-04T09:26:53.418Z"), "Mar  4 09:26:53");
+        assert_eq!(bsd_timestamp("2024-12-14T23:00:01.000Z"), "Dec 14 23:00:01");
+    }
+
+    #[test]
+    fn test_syslog_entries() {
+        for entry in generate_syslog_entries(&mut seeded(), 500) {
+            let facility = SYSLOG_FACILITIES
+                .iter()
+                .find(|&&(name, _)| name == entry.facility)
+                .unwrap()
+                .1;
+            let severity = SYSLOG_SEVERITIES
+                .iter()
+                .position(|&s| s == entry.severity)
+                .unwrap() as u8;
+            assert_eq!(entry.priority, facility * 8 + severity);
+            assert!(!entry.message.contains('{'), "{}", entry.message);
+            assert!(chrono::DateTime::parse_from_rfc3339(&entry.timestamp).is_ok());
+            assert!(entry.timestamp.ends_with('Z'));
+        }
+    }
+
+    #[test]
+    fn test_syslog_templates_use_known_facilities() {
+        for &(_, facility, templates) in SYSLOG_PROGRAMS {
+            assert!(SYSLOG_FACILITIES.iter().any(|&(name, _)| name == facility));
+            for &(_, severity) in templates {
+                assert!(usize::from(severity) < SYSLOG_SEVERITIES.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_syslog_format_from_str() {
+        for name in SYSLOG_FORMATS {
+            assert!(name.parse::<SyslogFormat>().is_ok());
+        }
+        let err = "gelf".parse::<SyslogFormat>().unwrap_err();
+        assert!(err.to_string().contains("'gelf'"));
+    }
+
+    #[test]
+    fn test_into_values_order() {
+        let entry = generate_access_log_entry(&mut seeded());
+        let status = entry.status.to_string();
+        let values = entry.into_values();
+        assert_eq!(values.len(), ACCESS_LOG_FIELDS.len());
+        assert_eq!(values[5], status);
+    }
+}
@@ -0,0 +1,459 @@
+//! Markdown and HTML generation provider.
+//!
+//! Builds a small document from the locale's lorem data (a title, paragraphs
+//! with inline links, subheadings, lists, code blocks and quotes) and renders
+//! it as Markdown or as an HTML fragment. HTML output escapes text content, so
+//! it is well-formed input for sanitizers and CMS importers.
+
+use crate::data::get_locale_data;
+use crate::locale::Locale;
+use crate::providers::text::{generate_sentence, generate_word};
+use crate::rng::ForgeryRng;
+
+/// Code block templates: language tag and lines, where `{a}` and `{b}` are
+/// replaced by lorem words.
+const CODE_TEMPLATES: &[(&str, &[&str])] = &[
+    ("python", &["def {a}({b}):", "    return {b}"]),
+    ("javascript", &["function {a}({b}) {", "  return {b};", "}"]),
+    ("rust", &["fn {a}({b}: u32) -> u32 {", "    {b} * 2", "}"]),
+    ("bash", &["{a} --{b} > {a}.log"]),
+];
+
+/// Inline content of a paragraph.
+enum Span {
+    Text(String),
+    Link { text: String, href: String },
+}
+
+/// Block-level element of a generated document.
+enum Block {
+    Heading {
+        level: u8,
+        text: String,
+    },
+    Paragraph(Vec<Span>),
+    List {
+        ordered: bool,
+        items: Vec<String>,
+    },
+    Code {
+        language: &'static str,
+        lines: Vec<String>,
+    },
+    Quote(String),
+}
+
+/// A sentence without its trailing period, for headings and list items.
+fn phrase(rng: &mut ForgeryRng, locale: Locale, min_words: usize, max_words: usize) -> String {
+    let word_count = rng.gen_range(min_words, max_words);
+    let mut sentence = generate_sentence(rng, locale, word_count);
+    sentence.pop();
+    sentence
+}
+
+/// A paragraph of 2-4 sentences; about half wrap the last sentence in a link.
+fn paragraph(rng: &mut ForgeryRng, locale: Locale) -> Block {
+    let format = get_locale_data(locale).text_format();
+    let sentence_count = rng.gen_range(2, 4);
+    let mut text = String::new();
+    for _ in 0..sentence_count - 1 {
+        let word_count = rng.gen_range(5, 12);
+        text.push_str(&generate_sentence(rng, locale, word_count));
+        text.push_str(format.separator);
+    }
+    if rng.gen_range(0, 1) == 1 {
+        let href = format!("https://example.com/posts/{}", rng.gen_range(1u32, 9999));
+        let link_text = phrase(rng, locale, 2, 5);
+        return Block::Paragraph(vec![
+            Span::Text(text),
+            Span::Link {
+                text: link_text,
+                href,
+            },
+            Span::Text(format.full_stop.to_string()),
+        ]);
+    }
+    let word_count = rng.gen_range(5, 12);
+    text.push_str(&generate_sentence(rng, locale, word_count));
+    Block::Paragraph(vec![Span::Text(text)])
+}
+
+/// A list, code block or quote to follow a paragraph.
+fn supplementary_block(rng: &mut ForgeryRng, locale: Locale) -> Block {
+    match rng.gen_range(0, 2) {
+        0 => {
+            let item_count = rng.gen_range(3, 5);
+            let items = (0..item_count).map(|_| phrase(rng, locale, 2, 6)).collect();
+            Block::List {
+                ordered: rng.gen_range(0, 2) == 0,
+                items,
+            }
+        }
+        1 => {
+            let (language, template) = *rng.choose(CODE_TEMPLATES);
+            let a = generate_word(rng, locale);
+            let b = generate_word(rng, locale);
+            let lines = template
+                .iter()
+                .map(|line| line.replace("{a}", &a).replace("{b}", &b))
+                .collect();
+            Block::Code { language, lines }
+        }
+        _ => {
+            let word_count = rng.gen_range(6, 14);
+            Block::Quote(generate_sentence(rng, locale, word_count))
+        }
+    }
+}
+
+/// Blocks of a Markdown document: a title, then each paragraph optionally
+/// preceded by a subheading and followed by a list, code block or quote.
+fn markdown_blocks(rng: &mut ForgeryRng, locale: Locale, paragraphs: usize) -> Vec<Block> {
+    let mut blocks = vec![Block::Heading {
+        level: 1,
+        text: phrase(rng, locale, 2, 6),
+    }];
+    for i in 0..paragraphs {
+        if i > 0 && rng.gen_range(0, 1) == 1 {
+            blocks.push(Block::Heading {
+                level: 2,
+                text: phrase(rng, locale, 2, 5),
+            });
+        }
+        blocks.push(paragraph(rng, locale));
+        if rng.gen_range(0, 1) == 1 {
+            blocks.push(supplementary_block(rng, locale));
+        }
+    }
+    blocks
+}
+
+/// Blocks of an HTML fragment: a title followed by `elements - 1` blocks,
+/// mostly paragraphs.
+fn html_blocks(rng: &mut ForgeryRng, locale: Locale, elements: usize) -> Vec<Block> {
+    let mut blocks = Vec::with_capacity(elements);
+    for i in 0..elements {
+        let block = if i == 0 {
+            Block::Heading {
+                level: 1,
+                text: phrase(rng, locale, 2, 6),
+            }
+        } else {
+            match rng.gen_range(0, 5) {
+                0 => Block::Heading {
+                    level: 2,
+                    text: phrase(rng, locale, 2, 5),
+                },
+                1 | 2 => paragraph(rng, locale),
+                _ => supplementary_block(rng, locale),
+            }
+        };
+        blocks.push(block);
+    }
+    blocks
+}
+
+/// Render blocks as Markdown, separated by blank lines.
+fn render_markdown(blocks: &[Block]) -> String {
+    let rendered: Vec<String> = blocks
+        .iter()
+        .map(|block| match block {
+            Block::Heading { level, text } => {
+                format!("{} {}", "#".repeat(usize::from(*level)), text)
+            }
+            Block::Paragraph(spans) => spans
+                .iter()
+                .map(|span| match span {
+                    Span::Text(text) => text.clone(),
+                    Span::Link { text, href } => format!("[{}]({})", text, href),
+                })
+                .collect(),
+            Block::List { ordered, items } => items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    if *ordered {
+                        format!("{}. {}", i + 1, item)
+                    } else {
+                        format!("- {}", item)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Block::Code { language, lines } => {
+                format!("```{}\n{}\n```", language, lines.join("\n"))
+            }
+            Block::Quote(text) => format!("> {}", text),
+        })
+        .collect();
+    rendered.join("\n\n")
+}
+
+/// Escape text for use in HTML content and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Render blocks as an HTML fragment, one top-level element per block.
+fn render_html(blocks: &[Block]) -> String {
+    let rendered: Vec<String> = blocks
+        .iter()
+        .map(|block| match block {
+            Block::Heading { level, text } => {
+                format!("<h{0}>{1}</h{0}>", level, escape_html(text))
+            }
+            Block::Paragraph(spans) => {
+                let content: String = spans
+                    .iter()
+                    .map(|span| match span {
+                        Span::Text(text) => escape_html(text),
+                        Span::Link { text, href } => format!(
+                            "<a href=\"{}\">{}</a>",
+                            escape_html(href),
+                            escape_html(text)
+                        ),
+                    })
+                    .collect();
+                format!("<p>{}</p>", content)
+            }
+            Block::List { ordered, items } => {
+                let tag = if *ordered { "ol" } else { "ul" };
+                let items: String = items
+                    .iter()
+                    .map(|item| format!("\n  <li>{}</li>", escape_html(item)))
+                    .collect();
+                format!("<{0}>{1}\n</{0}>", tag, items)
+            }
+            Block::Code { language, lines } => format!(
+                "<pre><code class=\"language-{}\">{}</code></pre>",
+                language,
+                escape_html(&lines.join("\n"))
+            ),
+            Block::Quote(text) => {
+                format!("<blockquote><p>{}</p></blockquote>", escape_html(text))
+            }
+        })
+        .collect();
+    rendered.join("\n")
+}
+
+/// Generate a batch of Markdown documents.
+pub fn generate_markdowns(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    paragraphs: usize,
+) -> Vec<String> {
+    let mut documents = Vec::with_capacity(n);
+    for _ in 0..n {
+        documents.push(generate_markdown(rng, locale, paragraphs));
+    }
+    documents
+}
+
+/// Generate a single Markdown document with a `#` title and `paragraphs`
+/// paragraphs, interspersed with subheadings, lists, code blocks and quotes.
+#[inline]
+pub fn generate_markdown(rng: &mut ForgeryRng, locale: Locale, paragraphs: usize) -> String {
+    render_markdown(&markdown_blocks(rng, locale, paragraphs))
+}
+
+/// Generate a batch of HTML fragments.
+pub fn generate_htmls(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    elements: usize,
+) -> Vec<String> {
+    let mut fragments = Vec::with_capacity(n);
+    for _ in 0..n {
+        fragments.push(generate_html(rng, locale, elements));
+    }
+    fragments
+}
+
+/// Generate a single HTML fragment of `elements` top-level elements: an `<h1>`
+/// followed by headings, paragraphs, lists, code blocks and quotes.
+#[inline]
+pub fn generate_html(rng: &mut ForgeryRng, locale: Locale, elements: usize) -> String {
+    render_html(&html_blocks(rng, locale, elements))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("a < b && \"c\" > 'd'"),
+            "a &lt; b &amp;&amp; &quot;c&quot; &gt; &#39;d&#39;"
+        );
+        assert_eq!(escape_html("lorem"), "lorem");
+    }
+
+    #[test]
+    fn test_markdown_structure() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let documents = generate_markdowns(&mut rng, Locale::EnUS, 100, 3);
+        assert_eq!(documents.len(), 100);
+        for doc in &documents {
+            assert!(doc.starts_with("# "), "{}", doc);
+            let paragraphs = doc
+                .split("\n\n")
+                .filter(|b| !b.starts_with(['#', '-', '>', '`']))
+                .filter(|b| !b.starts_with(|c: char| c.is_ascii_digit()))
+                .count();
+            assert_eq!(paragraphs, 3, "{}", doc);
+        }
+        let all = documents.join("\n\n");
+        assert!(all.contains("\n## "));
+        assert!(all.contains("\n- "));
+        assert!(all.contains("\n1. "));
+        assert!(all.contains("](https://example.com/posts/"));
+        assert!(all.contains("\n```"));
+        assert!(all.contains("\n> "));
+    }
+
+    #[test]
+    fn test_markdown_title_only() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let doc = generate_markdown(&mut rng, Locale::EnUS, 0);
+        assert!(doc.starts_with("# "));
+        assert!(!doc.contains('\n'));
+    }
+
+    #[test]
+    fn test_html_structure() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let fragments = generate_htmls(&mut rng, Locale::EnUS, 100, 5);
+        assert_eq!(fragments.len(), 100);
+        for html in &fragments {
+            assert!(html.starts_with("<h1>"), "{}", html);
+            // List items are indented and code lines never start with '<'
+            let top_level = html
+                .lines()
+                .filter(|l| l.starts_with('<') && !l.starts_with("</"))
+                .count();
+            assert_eq!(top_level, 5, "{}", html);
+            for tag in [
+                "h1",
+                "p",
+                "ul",
+                "ol",
+                "li",
+                "pre",
+                "code",
+                "blockquote",
+                "a",
+            ] {
+                assert_eq!(
+                    html.matches(&format!("<{}>", tag)).count()
+                        + html.matches(&format!("<{} ", tag)).count(),
+                    html.matches(&format!("</{}>", tag)).count(),
+                    "unbalanced <{}> in {}",
+                    tag,
+                    html
+                );
+            }
+        }
+        let all = fragments.join("\n");
+        for tag in [
+            "<h2>",
+            "<p>",
+            "<ul>",
+            "<ol>",
+            "<li>",
+            "<blockquote>",
+            "<a href=",
+        ] {
+            assert!(all.contains(tag), "missing {}", tag);
+        }
+        assert!(all.contains("<pre><code class=\"language-"));
+        // The Rust template's "->" must be escaped
+        assert!(!all.contains("->"));
+    }
+
+    #[test]
+    fn test_html_empty() {
+        let mut rng = ForgeryRng::new();
+        assert!(generate_html(&mut rng, Locale::EnUS, 0).is_empty());
+        assert_eq!(generate_html(&mut rng, Locale::EnUS, 1).lines().count(), 1);
+    }
+
+    #[test]
+    fn test_markup_all_locales() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for locale in [Locale::EnUS, Locale::DeDE, Locale::JaJP, Locale::RuRU] {
+            assert!(generate_markdown(&mut rng, locale, 2).starts_with("# "));
+            assert!(generate_html(&mut rng, locale, 3).starts_with("<h1>"));
+        }
+    }
+
+    #[test]
+    fn test_markup_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_markdowns(&mut rng1, Locale::EnUS, 20, 3),
+            generate_markdowns(&mut rng2, Locale::EnUS, 20, 3)
+        );
+        assert_eq!(
+            generate_htmls(&mut rng1, Locale::EnUS, 20, 5),
+            generate_htmls(&mut rng2, Locale::EnUS, 20, 5)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_markdown_batch(seed in any::<u64>(), n in 0usize..20, paragraphs in 0usize..6) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let documents = generate_markdowns(&mut rng, Locale::EnUS, n, paragraphs);
+            prop_assert_eq!(documents.len(), n);
+            for doc in documents {
+                prop_assert!(doc.starts_with("# "));
+            }
+        }
+
+        #[test]
+        fn prop_html_batch(seed in any::<u64>(), n in 0usize..20, elements in 1usize..10) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let fragments = generate_htmls(&mut rng, Locale::EnUS, n, elements);
+            prop_assert_eq!(fragments.len(), n);
+            for html in fragments {
+                prop_assert!(html.starts_with("<h1>"));
+                prop_assert_eq!(html.matches("<p>").count(), html.matches("</p>").count());
+            }
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! Medical data generation provider.
+//!
+//! Generates basic patient attributes for healthcare test data. Values are
+//! English (US) and do not depend on the locale.
+//!
+//! | Field | Example | Distribution |
+//! |-------|---------|--------------|
+//! | Blood type | `O+` | Weighted by US donor prevalence (O+ 37%, AB- 1%) |
+//! | Height | `172.4` | Adult centimetres, normal per sex, 140-210 |
+//! | Weight | `74.8` | Adult kilograms, normal per sex, 40-180 |
+//! | Allergy | `Penicillin` | Uniform |
+//! | Medication | `Metformin` | Uniform |
+//! | ICD-10 code | `E11.65` | Uniform chapter, then uniform category in the chapter's range |
+//! | NPI | `1234567893` | Uniform, with a valid check digit |
+//!
+//! ICD-10 codes follow the ICD-10-CM structure: a category (letter and two
+//! digits) within a real chapter range, optionally followed by a decimal
+//! extension. Injury codes (S, T) may carry the seventh-character encounter
+//! extension (`S52.521A`). Codes are structurally valid but not guaranteed
+//! to exist in the code set.
+//!
+//! National Provider Identifiers are 10 digits: an entity-type digit (1 for
+//! individuals, 2 for organizations), eight digits, and a Luhn check digit
+//! computed with the `80840` health-industry prefix prepended.
+
+use crate::data::en_us::{ALLERGIES, MEDICATIONS};
+use crate::providers::finance::{luhn_checksum, validate_luhn};
+use crate::providers::numbers::standard_normal;
+use crate::rng::ForgeryRng;
+
+/// ABO/Rh blood types with their prevalence in tenths of a percent.
+const BLOOD_TYPE_WEIGHTS: &[(&str, u16)] = &[
+    ("O+", 374),
+    ("A+", 357),
+    ("B+", 85),
+    ("AB+", 34),
+    ("O-", 66),
+    ("A-", 63),
+    ("B-", 15),
+    ("AB-", 6),
+];
+
+/// Adult height in cm: (male mean, female mean, standard deviation).
+const HEIGHT_CM: (f64, f64, f64) = (175.4, 161.7, 7.2);
+
+/// Adult weight in kg: (male mean, female mean, standard deviation).
+const WEIGHT_KG: (f64, f64, f64) = (82.0, 68.0, 13.0);
+
+/// ICD-10-CM chapter category ranges (first, last), inclusive.
+const ICD10_CHAPTERS: &[(&str, &str)] = &[
+    ("A00", "B99"), // Certain infectious and parasitic diseases
+    ("C00", "D49"), // Neoplasms
+    ("D50", "D89"), // Blood and immune disorders
+    ("E00", "E89"), // Endocrine, nutritional and metabolic
+    ("F01", "F99"), // Mental and behavioral
+    ("G00", "G99"), // Nervous system
+    ("H00", "H59"), // Eye and adnexa
+    ("H60", "H95"), // Ear and mastoid process
+    ("I00", "I99"), // Circulatory system
+    ("J00", "J99"), // Respiratory system
+    ("K00", "K95"), // Digestive system
+    ("L00", "L99"), // Skin and subcutaneous tissue
+    ("M00", "M99"), // Musculoskeletal system
+    ("N00", "N99"), // Genitourinary system
+    ("O00", "O99"), // Pregnancy and childbirth
+    ("P00", "P96"), // Perinatal conditions
+    ("Q00", "Q99"), // Congenital malformations
+    ("R00", "R99"), // Symptoms and abnormal findings
+    ("S00", "T88"), // Injury and poisoning
+    ("V00", "Y99"), // External causes of morbidity
+    ("Z00", "Z99"), // Factors influencing health status
+];
+
+/// Seventh-character encounter extensions of injury codes (initial,
+/// subsequent, sequela).
+const ICD10_ENCOUNTERS: &[u8] = b"ADS";
+
+/// Generate a batch of blood types.
+pub fn generate_blood_types(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut types = Vec::with_capacity(n);
+    for _ in 0..n {
+        types.push(generate_blood_type(rng));
+    }
+    types
+}
+
+/// Generate a single blood type (e.g., "O+"), weighted by prevalence.
+#[inline]
+pub fn generate_blood_type(rng: &mut ForgeryRng) -> String {
+    let mut roll = rng.gen_range(1u16, 1000);
+    for &(blood_type, weight) in BLOOD_TYPE_WEIGHTS {
+        if roll <= weight {
+            return blood_type.to_string();
+        }
+        roll -= weight;
+    }
+    "O+".to_string()
+}
+
+/// Draw an adult measurement of a random sex, clamped and rounded to 0.1.
+fn measurement(
+    rng: &mut ForgeryRng,
+    (male, female, sd): (f64, f64, f64),
+    min: f64,
+    max: f64,
+) -> f64 {
+    let mean = if rng.gen_range(0u8, 1) == 1 {
+        female
+    } else {
+        male
+    };
+    let value = (mean + sd * standard_normal(rng)).clamp(min, max);
+    (value * 10.0).round() / 10.0
+}
+
+/// Generate a batch of adult heights in centimetres.
+pub fn generate_heights(rng: &mut ForgeryRng, n: usize) -> Vec<f64> {
+    let mut heights = Vec::with_capacity(n);
+    for _ in 0..n {
+        heights.push(generate_height(rng));
+    }
+    heights
+}
+
+/// Generate a single adult height in centimetres (e.g., 172.4).
+#[inline]
+pub fn generate_height(rng: &mut ForgeryRng) -> f64 {
+    measurement(rng, HEIGHT_CM, 140.0, 210.0)
+}
+
+/// Generate a batch of adult weights in kilograms.
+pub fn generate_weights(rng: &mut ForgeryRng, n: usize) -> Vec<f64> {
+    let mut weights = Vec::with_capacity(n);
+    for _ in 0..n {
+        weights.push(generate_weight(rng));
+    }
+    weights
+}
+
+/// Generate a single adult weight in kilograms (e.g., 74.8).
+#[inline]
+pub fn generate_weight(rng: &mut ForgeryRng) -> f64 {
+    measurement(rng, WEIGHT_KG, 40.0, 180.0)
+}
+
+/// Generate a batch of allergies.
+pub fn generate_allergies(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut allergies = Vec::with_capacity(n);
+    for _ in 0..n {
+        allergies.push(generate_allergy(rng));
+    }
+    allergies
+}
+
+/// Generate a single allergy (e.g., "Penicillin").
+#[inline]
+pub fn generate_allergy(rng: &mut ForgeryRng) -> String {
+    rng.choose(ALLERGIES).to_string()
+}
+
+/// Generate a batch of medications.
+pub fn generate_medications(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut medications = Vec::with_capacity(n);
+    for _ in 0..n {
+        medications.push(generate_medication(rng));
+    }
+    medications
+}
+
+/// Generate a single medication generic name (e.g., "Metformin").
+#[inline]
+pub fn generate_medication(rng: &mut ForgeryRng) -> String {
+    rng.choose(MEDICATIONS).to_string()
+}
+
+/// Position of an ICD-10 category ("A00" is 0, "B00" is 100).
+fn icd10_index(category: &str) -> u32 {
+    let bytes = category.as_bytes();
+    u32::from(bytes[0] - b'A') * 100 + u32::from(bytes[1] - b'0') * 10 + u32::from(bytes[2] - b'0')
+}
+
+/// Generate a batch of ICD-10 diagnosis codes.
+pub fn generate_icd10_codes(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut codes = Vec::with_capacity(n);
+    for _ in 0..n {
+        codes.push(generate_icd10_code(rng));
+    }
+    codes
+}
+
+/// Generate a single ICD-10 diagnosis code (e.g., "E11.65", "I10", "S52.521A").
+#[inline]
+pub fn generate_icd10_code(rng: &mut ForgeryRng) -> String {
+    let &(first, last) = rng.choose(ICD10_CHAPTERS);
+    let index = rng.gen_range(icd10_index(first), icd10_index(last));
+    let letter = char::from(b'A' + (index / 100) as u8);
+    let mut code = format!("{}{:02}", letter, index % 100);
+    let injury = letter == 'S' || letter == 'T';
+    if injury && rng.gen_range(0u8, 1) == 0 {
+        code.push('.');
+        for _ in 0..3 {
+            code.push(char::from(b'0' + rng.gen_range(0u8, 9)));
+        }
+        code.push(char::from(*rng.choose(ICD10_ENCOUNTERS)));
+    } else {
+        let extension = rng.gen_range(0u8, 2);
+        if extension > 0 {
+            code.push('.');
+            for _ in 0..extension {
+                code.push(char::from(b'0' + rng.gen_range(0u8, 9)));
+            }
+        }
+    }
+    code
+}
+
+/// Card issuer prefix that NPI check digits are computed with (ISO 7812
+/// health industry, United States).
+const NPI_PREFIX: &str = "80840";
+
+/// Validate a National Provider Identifier: 10 digits starting with 1 or 2
+/// whose check digit passes Luhn with the `80840` prefix.
+pub fn validate_npi(npi: &str) -> bool {
+    npi.len() == 10
+        && npi.bytes().all(|b| b.is_ascii_digit())
+        && matches!(npi.as_bytes()[0], b'1' | b'2')
+        && validate_luhn(&format!("{}{}", NPI_PREFIX, npi))
+}
+
+/// Generate a batch of National Provider Identifiers.
+pub fn generate_npis(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut npis = Vec::with_capacity(n);
+    for _ in 0..n {
+        npis.push(generate_npi(rng));
+    }
+    npis
+}
+
+/// Generate a single National Provider Identifier (e.g., "1234567893").
+#[inline]
+pub fn generate_npi(rng: &mut ForgeryRng) -> String {
+    let mut npi = String::with_capacity(10);
+    npi.push(char::from(b'0' + rng.gen_range(1u8, 2)));
+    for _ in 0..8 {
+        npi.push(char::from(b'0' + rng.gen_range(0u8, 9)));
+    }
+    let check = luhn_checksum(&format!("{}{}", NPI_PREFIX, npi));
+    npi.push(char::from(b'0' + check));
+    npi
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> ForgeryRng {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        rng
+    }
+
+    #[test]
+    fn test_blood_type_weights() {
+        let total: u16 = BLOOD_TYPE_WEIGHTS.iter().map(|(_, w)| w).sum();
+        assert_eq!(total, 1000);
+
+        let types = generate_blood_types(&mut seeded(), 5000);
+        let count = |t: &str| types.iter().filter(|x| *x == t).count();
+        assert!(types
+            .iter()
+            .all(|t| BLOOD_TYPE_WEIGHTS.iter().any(|(b, _)| b == t)));
+        assert!(count("O+") > count("B+"));
+        assert!(count("A+") > count("AB-"));
+        assert!(count("O+") > 1500 && count("O+") < 2200, "{}", count("O+"));
+    }
+
+    #[test]
+    fn test_height_distribution() {
+        let heights = generate_heights(&mut seeded(), 5000);
+        assert!(heights.iter().all(|h| (140.0..=210.0).contains(h)));
+        let mean = heights.iter().sum::<f64>() / heights.len() as f64;
+        assert!((165.0..172.0).contains(&mean), "{}", mean);
+        assert!(heights
+            .iter()
+            .all(|h| (h * 10.0 - (h * 10.0).round()).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_weight_distribution() {
+        let weights = generate_weights(&mut seeded(), 5000);
+        assert!(weights.iter().all(|w| (40.0..=180.0).contains(w)));
+        let mean = weights.iter().sum::<f64>() / weights.len() as f64;
+        assert!((71.0..79.0).contains(&mean), "{}", mean);
+    }
+
+    #[test]
+    fn test_allergies_and_medications() {
+        let mut rng = seeded();
+        for allergy in generate_allergies(&mut rng, 50) {
+            assert!(ALLERGIES.contains(&allergy.as_str()));
+        }
+        for medication in generate_medications(&mut rng, 50) {
+            assert!(MEDICATIONS.contains(&medication.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_icd10_codes() {
+        let codes = generate_icd10_codes(&mut seeded(), 2000);
+        for code in &codes {
+            let (category, extension) = code.split_once('.').unwrap_or((code, ""));
+            let bytes = category.as_bytes();
+            assert_eq!(bytes.len(), 3, "{}", code);
+            assert!(
+                bytes[0].is_ascii_uppercase() && bytes[0] != b'U',
+                "{}",
+                code
+            );
+            assert!(bytes[1..].iter().all(u8::is_ascii_digit), "{}", code);
+            let index = icd10_index(category);
+            assert!(ICD10_CHAPTERS
+                .iter()
+                .any(|&(f, l)| (icd10_index(f)..=icd10_index(l)).contains(&index)));
+            match extension.len() {
+                0..=2 => assert!(extension.bytes().all(|b| b.is_ascii_digit()), "{}", code),
+                4 => {
+                    assert!(code.starts_with('S') || code.starts_with('T'), "{}", code);
+                    assert!(
+                        ICD10_ENCOUNTERS.contains(&extension.as_bytes()[3]),
+                        "{}",
+                        code
+                    );
+                }
+                _ => panic!("unexpected extension in {}", code),
+            }
+        }
+        assert!(codes.iter().any(|c| !c.contains('.')));
+        assert!(codes.iter().any(|c| c.len() == 8));
+    }
+
+    #[test]
+    fn test_npis() {
+        assert!(validate_npi("1234567893"));
+        assert!(!validate_npi("1234567890"));
+        assert!(!validate_npi("3234567890"));
+        assert!(!validate_npi("123456789"));
+
+        let npis = generate_npis(&mut seeded(), 500);
+        assert!(npis.iter().all(|npi| validate_npi(npi)), "{:?}", npis);
+        assert!(npis.iter().any(|npi| npi.starts_with('1')));
+        assert!(npis.iter().any(|npi| npi.starts_with('2')));
+    }
+
+    #[test]
+    fn test_medical_deterministic() {
+        assert_eq!(
+            generate_heights(&mut seeded(), 20),
+            generate_heights(&mut seeded(), 20)
+        );
+        assert_eq!(
+            generate_blood_types(&mut seeded(), 20),
+            generate_blood_types(&mut seeded(), 20)
+        );
+    }
+}
@@ -5,17 +5,41 @@
 
 pub mod address;
 pub mod async_records;
+pub mod auth;
+pub mod binary;
+pub mod cloud;
+pub mod codes;
 pub mod colors;
+pub mod commerce;
 pub mod company;
 pub mod custom;
 pub mod datetime;
+pub mod demographics;
+pub mod drivers_license;
+pub mod duration;
+pub mod filesystem;
 pub mod finance;
+pub mod geo;
 pub mod identifiers;
+pub mod insurance;
 pub mod internet;
+pub mod language;
+pub mod logistics;
+pub mod logs;
+pub mod markup;
+pub mod medical;
 pub mod names;
+pub mod national_id;
 pub mod network;
 pub mod numbers;
 pub mod password;
 pub mod phone;
+pub mod profile;
 pub mod records;
+pub mod software;
+pub mod stats;
+pub mod tax_id;
 pub mod text;
+pub mod timeseries;
+pub mod travel;
+pub mod vehicle;
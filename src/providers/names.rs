@@ -3,6 +3,7 @@
 //! Generates first names, last names, and full names using locale-specific data.
 
 use crate::data::get_locale_data;
+use crate::data::overrides::{choose_with_override, LocaleOverrides};
 use crate::locale::Locale;
 use crate::rng::ForgeryRng;
 
@@ -18,28 +19,19 @@ use crate::rng::ForgeryRng;
 ///
 /// A vector of full names
 pub fn generate_names(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
-    let data = get_locale_data(locale);
-    let first_names = data.first_names().unwrap_or(&[]);
-    let last_names = data.last_names().unwrap_or(&[]);
-    let family_first = locale.family_name_first();
+    generate_names_with_overrides(rng, locale, &LocaleOverrides::EMPTY, n)
+}
 
+/// Generate a batch of full names, drawing from user overrides where set.
+pub fn generate_names_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<String> {
     let mut names = Vec::with_capacity(n);
     for _ in 0..n {
-        let first = if first_names.is_empty() {
-            "Unknown"
-        } else {
-            rng.choose(first_names)
-        };
-        let last = if last_names.is_empty() {
-            "Unknown"
-        } else {
-            rng.choose(last_names)
-        };
-        if family_first {
-            names.push(format!("{} {}", last, first));
-        } else {
-            names.push(format!("{} {}", first, last));
-        }
+        names.push(generate_name_with_overrides(rng, locale, overrides));
     }
     names
 }
@@ -52,16 +44,19 @@ pub fn generate_names(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<Str
 /// * `locale` - The locale for generated names
 /// * `n` - Number of first names to generate
 pub fn generate_first_names(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
-    let data = get_locale_data(locale);
-    let first_names = data.first_names().unwrap_or(&[]);
+    generate_first_names_with_overrides(rng, locale, &LocaleOverrides::EMPTY, n)
+}
 
+/// Generate a batch of first names, drawing from user overrides where set.
+pub fn generate_first_names_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<String> {
     let mut names = Vec::with_capacity(n);
     for _ in 0..n {
-        names.push(if first_names.is_empty() {
-            "Unknown".to_string()
-        } else {
-            rng.choose(first_names).to_string()
-        });
+        names.push(generate_first_name_with_overrides(rng, locale, overrides));
     }
     names
 }
@@ -74,16 +69,19 @@ pub fn generate_first_names(rng: &mut ForgeryRng, locale: Locale, n: usize) -> V
 /// * `locale` - The locale for generated names
 /// * `n` - Number of last names to generate
 pub fn generate_last_names(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
-    let data = get_locale_data(locale);
-    let last_names = data.last_names().unwrap_or(&[]);
+    generate_last_names_with_overrides(rng, locale, &LocaleOverrides::EMPTY, n)
+}
 
+/// Generate a batch of last names, drawing from user overrides where set.
+pub fn generate_last_names_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<String> {
     let mut names = Vec::with_capacity(n);
     for _ in 0..n {
-        names.push(if last_names.is_empty() {
-            "Unknown".to_string()
-        } else {
-            rng.choose(last_names).to_string()
-        });
+        names.push(generate_last_name_with_overrides(rng, locale, overrides));
     }
     names
 }
@@ -93,20 +91,31 @@ pub fn generate_last_names(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Ve
 /// More efficient than `generate_names(rng, locale, 1)` as it avoids Vec allocation.
 #[inline]
 pub fn generate_name(rng: &mut ForgeryRng, locale: Locale) -> String {
-    let data = get_locale_data(locale);
-    let first_names = data.first_names().unwrap_or(&[]);
-    let last_names = data.last_names().unwrap_or(&[]);
+    generate_name_with_overrides(rng, locale, &LocaleOverrides::EMPTY)
+}
 
-    let first = if first_names.is_empty() {
-        "Unknown"
-    } else {
-        rng.choose(first_names)
-    };
-    let last = if last_names.is_empty() {
-        "Unknown"
-    } else {
-        rng.choose(last_names)
-    };
+/// Generate a single full name, drawing from user overrides where set.
+///
+/// The locale's name order (e.g., family name first for ja_JP) still applies.
+#[inline]
+pub fn generate_name_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> String {
+    let data = get_locale_data(locale);
+    let first = choose_with_override(
+        rng,
+        overrides.first_names.as_deref(),
+        data.first_names().unwrap_or(&[]),
+        "Unknown",
+    );
+    let last = choose_with_override(
+        rng,
+        overrides.last_names.as_deref(),
+        data.last_names().unwrap_or(&[]),
+        "Unknown",
+    );
 
     if locale.family_name_first() {
         format!("{} {}", last, first)
@@ -120,13 +129,24 @@ pub fn generate_name(rng: &mut ForgeryRng, locale: Locale) -> String {
 /// More efficient than `generate_first_names(rng, locale, 1)` as it avoids Vec allocation.
 #[inline]
 pub fn generate_first_name(rng: &mut ForgeryRng, locale: Locale) -> String {
+    generate_first_name_with_overrides(rng, locale, &LocaleOverrides::EMPTY)
+}
+
+/// Generate a single first name, drawing from user overrides where set.
+#[inline]
+pub fn generate_first_name_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> String {
     let data = get_locale_data(locale);
-    let first_names = data.first_names().unwrap_or(&[]);
-    if first_names.is_empty() {
-        "Unknown".to_string()
-    } else {
-        rng.choose(first_names).to_string()
-    }
+    choose_with_override(
+        rng,
+        overrides.first_names.as_deref(),
+        data.first_names().unwrap_or(&[]),
+        "Unknown",
+    )
+    .to_string()
 }
 
 /// Generate a single last name.
@@ -134,15 +154,344 @@ pub fn generate_first_name(rng: &mut ForgeryRng, locale: Locale) -> String {
 /// More efficient than `generate_last_names(rng, locale, 1)` as it avoids Vec allocation.
 #[inline]
 pub fn generate_last_name(rng: &mut ForgeryRng, locale: Locale) -> String {
+    generate_last_name_with_overrides(rng, locale, &LocaleOverrides::EMPTY)
+}
+
+/// Generate a single last name, drawing from user overrides where set.
+#[inline]
+pub fn generate_last_name_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> String {
+    let data = get_locale_data(locale);
+    choose_with_override(
+        rng,
+        overrides.last_names.as_deref(),
+        data.last_names().unwrap_or(&[]),
+        "Unknown",
+    )
+    .to_string()
+}
+
+/// Generate a batch of romanized full names (first + last).
+///
+/// Uses the locale's romanized name lists (ja_JP, ko_KR, ru_RU, and the
+/// ASCII-safe first names of fr_FR, es_ES, it_IT, pl_PL, en_CA). Locales
+/// without a romanized list fall back to their regular names.
+pub fn generate_names_romanized(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_name_romanized(rng, locale));
+    }
+    names
+}
+
+/// Generate a batch of romanized first names.
+pub fn generate_first_names_romanized(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_first_name_romanized(rng, locale));
+    }
+    names
+}
+
+/// Generate a batch of romanized last names.
+pub fn generate_last_names_romanized(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_last_name_romanized(rng, locale));
+    }
+    names
+}
+
+/// Generate a single romanized full name, in the locale's name order.
+#[inline]
+pub fn generate_name_romanized(rng: &mut ForgeryRng, locale: Locale) -> String {
+    let first = generate_first_name_romanized(rng, locale);
+    let last = generate_last_name_romanized(rng, locale);
+    if locale.family_name_first() {
+        format!("{} {}", last, first)
+    } else {
+        format!("{} {}", first, last)
+    }
+}
+
+/// Generate a single romanized first name.
+#[inline]
+pub fn generate_first_name_romanized(rng: &mut ForgeryRng, locale: Locale) -> String {
+    let data = get_locale_data(locale);
+    let names = data.romanized_first_names().unwrap_or(&[]);
+    choose_with_override(rng, None, names, "Unknown").to_string()
+}
+
+/// Generate a single romanized last name.
+#[inline]
+pub fn generate_last_name_romanized(rng: &mut ForgeryRng, locale: Locale) -> String {
     let data = get_locale_data(locale);
-    let last_names = data.last_names().unwrap_or(&[]);
-    if last_names.is_empty() {
-        "Unknown".to_string()
+    let names = data.romanized_last_names().unwrap_or(&[]);
+    choose_with_override(rng, None, names, "Unknown").to_string()
+}
+
+/// Generate a batch of name prefixes (e.g., "Mr.", "Dr.", "Frau").
+///
+/// Locales without prefixes (ja_JP, ko_KR) yield empty strings.
+pub fn generate_prefixes(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    let mut prefixes = Vec::with_capacity(n);
+    for _ in 0..n {
+        prefixes.push(generate_prefix(rng, locale));
+    }
+    prefixes
+}
+
+/// Generate a single name prefix.
+#[inline]
+pub fn generate_prefix(rng: &mut ForgeryRng, locale: Locale) -> String {
+    let data = get_locale_data(locale);
+    choose_with_override(rng, None, data.name_prefixes().unwrap_or(&[]), "").to_string()
+}
+
+/// Generate a batch of name suffixes (e.g., "Jr.", "PhD", "様").
+///
+/// Locales without suffixes (ru_RU) yield empty strings.
+pub fn generate_suffixes(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    let mut suffixes = Vec::with_capacity(n);
+    for _ in 0..n {
+        suffixes.push(generate_suffix(rng, locale));
+    }
+    suffixes
+}
+
+/// Generate a single name suffix.
+#[inline]
+pub fn generate_suffix(rng: &mut ForgeryRng, locale: Locale) -> String {
+    let data = get_locale_data(locale);
+    choose_with_override(rng, None, data.name_suffixes().unwrap_or(&[]), "").to_string()
+}
+
+/// Generate a batch of full names preceded by a prefix (e.g., "Dr. Jane Smith").
+pub fn generate_names_with_prefix(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_name_with_prefix(rng, locale, overrides));
+    }
+    names
+}
+
+/// Generate a single full name preceded by a prefix.
+///
+/// Falls back to the plain full name for locales without prefixes.
+#[inline]
+pub fn generate_name_with_prefix(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> String {
+    let prefix = generate_prefix(rng, locale);
+    let name = generate_name_with_overrides(rng, locale, overrides);
+    if prefix.is_empty() {
+        name
+    } else {
+        format!("{} {}", prefix, name)
+    }
+}
+
+/// Placeholders supported in `full_name_format` templates.
+pub const FULL_NAME_PLACEHOLDERS: &[&str] = &[
+    "prefix",
+    "first",
+    "middle",
+    "middle_initial",
+    "last",
+    "suffix",
+];
+
+/// Error for a `full_name_format` template with an unknown or unclosed placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameFormatError {
+    /// The offending placeholder text (without braces).
+    pub placeholder: String,
+}
+
+impl std::fmt::Display for NameFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown placeholder '{{{}}}' in name format; expected one of: {}",
+            self.placeholder,
+            FULL_NAME_PLACEHOLDERS
+                .iter()
+                .map(|p| format!("{{{}}}", p))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl std::error::Error for NameFormatError {}
+
+/// A parsed piece of a `full_name_format` template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NameToken<'a> {
+    Literal(&'a str),
+    Prefix,
+    First,
+    Middle,
+    MiddleInitial,
+    Last,
+    Suffix,
+}
+
+/// Parse a `full_name_format` template into tokens.
+fn parse_name_format(format: &str) -> Result<Vec<NameToken<'_>>, NameFormatError> {
+    let mut tokens = Vec::new();
+    let mut rest = format;
+    while let Some(start) = rest.find('{') {
+        if start > 0 {
+            tokens.push(NameToken::Literal(&rest[..start]));
+        }
+        let after = &rest[start + 1..];
+        let end = after.find('}').ok_or_else(|| NameFormatError {
+            placeholder: after.to_string(),
+        })?;
+        tokens.push(match &after[..end] {
+            "prefix" => NameToken::Prefix,
+            "first" => NameToken::First,
+            "middle" => NameToken::Middle,
+            "middle_initial" => NameToken::MiddleInitial,
+            "last" => NameToken::Last,
+            "suffix" => NameToken::Suffix,
+            other => {
+                return Err(NameFormatError {
+                    placeholder: other.to_string(),
+                })
+            }
+        });
+        rest = &after[end + 1..];
+    }
+    if !rest.is_empty() {
+        tokens.push(NameToken::Literal(rest));
+    }
+    Ok(tokens)
+}
+
+/// Check that a `full_name_format` template only uses supported placeholders.
+///
+/// # Errors
+///
+/// Returns `NameFormatError` for the first unknown or unclosed placeholder.
+pub fn validate_name_format(format: &str) -> Result<(), NameFormatError> {
+    parse_name_format(format).map(|_| ())
+}
+
+/// The default `full_name_format` for a locale ("{first} {last}" or "{last} {first}").
+pub fn default_name_format(locale: Locale) -> &'static str {
+    if locale.family_name_first() {
+        "{last} {first}"
     } else {
-        rng.choose(last_names).to_string()
+        "{first} {last}"
     }
 }
 
+/// Render one name from parsed tokens.
+///
+/// Components are drawn in template order. Runs of whitespace are collapsed so
+/// empty components (e.g., no prefix in ja_JP) don't leave gaps.
+fn render_full_name(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    tokens: &[NameToken<'_>],
+) -> String {
+    let data = get_locale_data(locale);
+    let first_names = data.first_names().unwrap_or(&[]);
+    let mut out = String::new();
+    for token in tokens {
+        match token {
+            NameToken::Literal(text) => out.push_str(text),
+            NameToken::Prefix => out.push_str(&generate_prefix(rng, locale)),
+            NameToken::Suffix => out.push_str(&generate_suffix(rng, locale)),
+            NameToken::First | NameToken::Middle => out.push_str(choose_with_override(
+                rng,
+                overrides.first_names.as_deref(),
+                first_names,
+                "Unknown",
+            )),
+            NameToken::MiddleInitial => {
+                // Romanized names keep initials in Latin script for every locale
+                let name = choose_with_override(
+                    rng,
+                    overrides.first_names.as_deref(),
+                    data.romanized_first_names().unwrap_or(&[]),
+                    "X",
+                );
+                if let Some(initial) = name.chars().next() {
+                    out.extend(initial.to_uppercase());
+                    out.push('.');
+                }
+            }
+            NameToken::Last => out.push_str(choose_with_override(
+                rng,
+                overrides.last_names.as_deref(),
+                data.last_names().unwrap_or(&[]),
+                "Unknown",
+            )),
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Generate a batch of full names from a `full_name_format` template.
+///
+/// Supported placeholders: `{prefix}`, `{first}`, `{middle}`, `{middle_initial}`,
+/// `{last}`, `{suffix}`. `None` uses the locale's default order.
+///
+/// # Errors
+///
+/// Returns `NameFormatError` if the template contains an unknown or unclosed placeholder.
+pub fn generate_full_names(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    format: Option<&str>,
+    n: usize,
+) -> Result<Vec<String>, NameFormatError> {
+    let tokens = parse_name_format(format.unwrap_or(default_name_format(locale)))?;
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(render_full_name(rng, locale, overrides, &tokens));
+    }
+    Ok(names)
+}
+
+/// Generate a single full name from a `full_name_format` template.
+///
+/// # Errors
+///
+/// Returns `NameFormatError` if the template contains an unknown or unclosed placeholder.
+pub fn generate_full_name(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    format: Option<&str>,
+) -> Result<String, NameFormatError> {
+    let tokens = parse_name_format(format.unwrap_or(default_name_format(locale)))?;
+    Ok(render_full_name(rng, locale, overrides, &tokens))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,6 +649,199 @@ mod tests {
         assert!(name.contains(' '), "Name should have a space");
     }
 
+    #[test]
+    fn test_names_with_overrides() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let overrides = LocaleOverrides {
+            first_names: Some(vec!["Ada".to_string(), "Grace".to_string()]),
+            ..Default::default()
+        };
+
+        let names = generate_names_with_overrides(&mut rng, Locale::EnUS, &overrides, 50);
+        for name in &names {
+            let (first, last) = name.split_once(' ').unwrap();
+            assert!(first == "Ada" || first == "Grace");
+            // Last names still come from the locale
+            assert!(LAST_NAMES.contains(&last));
+        }
+    }
+
+    #[test]
+    fn test_overrides_keep_locale_name_order() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let overrides = LocaleOverrides {
+            first_names: Some(vec!["Hana".to_string()]),
+            last_names: Some(vec!["Sato".to_string()]),
+            ..Default::default()
+        };
+
+        let name = generate_name_with_overrides(&mut rng, Locale::JaJP, &overrides);
+        assert_eq!(name, "Sato Hana");
+    }
+
+    #[test]
+    fn test_romanized_names_non_latin_locales() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for locale in [Locale::JaJP, Locale::KoKR, Locale::RuRU] {
+            let names = generate_names_romanized(&mut rng, locale, 50);
+            for name in &names {
+                assert!(
+                    name.is_ascii(),
+                    "{:?} produced non-ASCII name {}",
+                    locale,
+                    name
+                );
+                assert_eq!(name.split(' ').count(), 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_romanized_first_names_strip_diacritics() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let names = generate_first_names_romanized(&mut rng, Locale::FrFR, 200);
+        assert!(names.iter().all(|n| n.is_ascii()));
+    }
+
+    #[test]
+    fn test_romanized_falls_back_to_regular_names() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let names = generate_last_names_romanized(&mut rng, Locale::EnUS, 50);
+        for name in &names {
+            assert!(LAST_NAMES.contains(&name.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_prefixes_from_locale_data() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let prefixes = generate_prefixes(&mut rng, Locale::DeDE, 50);
+        for prefix in &prefixes {
+            assert!(crate::data::de_de::NAME_PREFIXES.contains(&prefix.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_prefix_empty_for_locales_without_prefixes() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        assert_eq!(generate_prefix(&mut rng, Locale::JaJP), "");
+        assert_eq!(generate_suffix(&mut rng, Locale::RuRU), "");
+        // Falls back to the plain name
+        let name = generate_name_with_prefix(&mut rng, Locale::JaJP, &LocaleOverrides::EMPTY);
+        assert_eq!(name.split(' ').count(), 2);
+    }
+
+    #[test]
+    fn test_names_with_prefix() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let names = generate_names_with_prefix(&mut rng, Locale::EnUS, &LocaleOverrides::EMPTY, 50);
+        for name in &names {
+            let prefix = name.split(' ').next().unwrap();
+            assert!(crate::data::en_us::NAME_PREFIXES.contains(&prefix));
+        }
+    }
+
+    #[test]
+    fn test_full_name_default_format_matches_locale_order() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let name =
+            generate_full_name(&mut rng, Locale::JaJP, &LocaleOverrides::EMPTY, None).unwrap();
+        let (last, first) = name.split_once(' ').unwrap();
+        assert!(crate::data::ja_jp::LAST_NAMES.contains(&last));
+        assert!(crate::data::ja_jp::FIRST_NAMES.contains(&first));
+    }
+
+    #[test]
+    fn test_full_name_middle_initial() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let names = generate_full_names(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            Some("{first} {middle_initial} {last}"),
+            50,
+        )
+        .unwrap();
+        for name in &names {
+            let parts: Vec<&str> = name.split(' ').collect();
+            assert_eq!(parts.len(), 3);
+            assert_eq!(parts[1].len(), 2);
+            assert!(parts[1].starts_with(|c: char| c.is_ascii_uppercase()));
+            assert!(parts[1].ends_with('.'));
+        }
+    }
+
+    #[test]
+    fn test_full_name_middle_initial_is_latin_for_non_latin_locales() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let names = generate_full_names(
+            &mut rng,
+            Locale::RuRU,
+            &LocaleOverrides::EMPTY,
+            Some("{middle_initial}"),
+            20,
+        )
+        .unwrap();
+        assert!(names.iter().all(|n| n.is_ascii()));
+    }
+
+    #[test]
+    fn test_full_name_collapses_empty_components() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let name = generate_full_name(
+            &mut rng,
+            Locale::JaJP,
+            &LocaleOverrides::EMPTY,
+            Some("{prefix} {last} {first}"),
+        )
+        .unwrap();
+        assert!(!name.starts_with(' '));
+        assert_eq!(name.split(' ').count(), 2);
+    }
+
+    #[test]
+    fn test_full_name_invalid_format() {
+        let mut rng = ForgeryRng::new();
+        let err = generate_full_name(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            Some("{nick}"),
+        )
+        .unwrap_err();
+        assert_eq!(err.placeholder, "nick");
+
+        assert!(generate_full_name(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            Some("{first")
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_german_names() {
         let mut rng = ForgeryRng::new();
@@ -322,6 +864,11 @@ mod tests {
             Locale::EsES,
             Locale::ItIT,
             Locale::JaJP,
+            Locale::KoKR,
+            Locale::RuRU,
+            Locale::NlNL,
+            Locale::PlPL,
+            Locale::EnCA,
         ] {
             let name = generate_name(&mut rng, locale);
             assert!(
@@ -0,0 +1,662 @@
+//! National identifier generation provider.
+//!
+//! Generates format-valid synthetic national ID numbers for each locale,
+//! including check digits where the real scheme defines one. The numbers
+//! are random and not issued to anyone.
+//!
+//! | Locale | Identifier | Example |
+//! |--------|------------|---------|
+//! | en_US | Social Security Number | `123-45-6789` |
+//! | en_GB | National Insurance Number | `AB123456C` |
+//! | en_CA | Social Insurance Number (Luhn) | `130 692 544` |
+//! | de_DE | Steuerliche Identifikationsnummer | `86095742719` |
+//! | fr_FR | Numéro INSEE / NIR | `184127645108946` |
+//! | es_ES | DNI or NIE | `12345678Z`, `X1234567L` |
+//! | it_IT | Codice fiscale | `RSSMRA85T10A562S` |
+//! | nl_NL | Burgerservicenummer (11-test) | `111222333` |
+//! | pl_PL | PESEL | `44051401359` |
+//! | ru_RU | SNILS | `112-233-445 95` |
+//! | ja_JP | My Number | `123456789018` |
+//! | ko_KR | Resident Registration Number | `850101-1234566` |
+
+use crate::data::get_locale_data;
+use crate::locale::Locale;
+use crate::providers::finance::luhn_checksum;
+use crate::rng::ForgeryRng;
+use chrono::{Datelike, NaiveDate};
+
+/// Earliest birth date used for IDs that encode one.
+const BIRTH_START: (i32, u32, u32) = (1940, 1, 1);
+
+/// Latest birth date used for IDs that encode one.
+const BIRTH_END: (i32, u32, u32) = (2005, 12, 31);
+
+/// Check letters for Spanish DNI/NIE, indexed by number mod 23.
+const DNI_LETTERS: &[u8] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+
+/// Codice fiscale month letters (January to December).
+const CF_MONTHS: &[u8] = b"ABCDEHLMPRST";
+
+/// Codice fiscale odd-position values for 0-9 and A-Z.
+const CF_ODD_VALUES: [u32; 26] = [
+    1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24, 23,
+];
+
+/// Cadastral (Belfiore) codes of large Italian municipalities.
+const CF_MUNICIPALITIES: &[&str] = &[
+    "H501", // Roma
+    "F205", // Milano
+    "F839", // Napoli
+    "L219", // Torino
+    "G273", // Palermo
+    "D969", // Genova
+    "A944", // Bologna
+    "D612", // Firenze
+    "A662", // Bari
+    "C351", // Catania
+    "L736", // Venezia
+    "L781", // Verona
+];
+
+/// Generate a batch of national ID numbers for the given locale.
+pub fn generate_national_ids(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    let mut ids = Vec::with_capacity(n);
+    for _ in 0..n {
+        ids.push(generate_national_id(rng, locale));
+    }
+    ids
+}
+
+/// Generate a single national ID number for the given locale.
+#[inline]
+pub fn generate_national_id(rng: &mut ForgeryRng, locale: Locale) -> String {
+    match locale {
+        Locale::EnUS => generate_ssn(rng),
+        Locale::EnGB => generate_nino(rng),
+        Locale::EnCA => generate_sin(rng),
+        Locale::DeDE => generate_steuer_id(rng),
+        Locale::FrFR => generate_insee(rng),
+        Locale::EsES => generate_dni(rng),
+        Locale::ItIT => generate_codice_fiscale(rng),
+        Locale::NlNL => generate_bsn(rng),
+        Locale::PlPL => generate_pesel(rng),
+        Locale::RuRU => generate_snils(rng),
+        Locale::JaJP => generate_my_number(rng),
+        Locale::KoKR => generate_rrn(rng),
+    }
+}
+
+/// Append `count` random digits to `out`.
+fn push_digits(rng: &mut ForgeryRng, out: &mut String, count: usize) {
+    for _ in 0..count {
+        out.push(char::from(b'0' + rng.gen_range(0u8, 9)));
+    }
+}
+
+/// Numeric values of the ASCII digits in `s`.
+fn digits_of(s: &str) -> Vec<u32> {
+    s.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+/// Pick a random birth date between `BIRTH_START` and `BIRTH_END`.
+fn random_birth_date(rng: &mut ForgeryRng) -> NaiveDate {
+    let (sy, sm, sd) = BIRTH_START;
+    let (ey, em, ed) = BIRTH_END;
+    let start = NaiveDate::from_ymd_opt(sy, sm, sd).expect("invalid birth start date");
+    let end = NaiveDate::from_ymd_opt(ey, em, ed).expect("invalid birth end date");
+    let days = rng.gen_range(start.num_days_from_ce(), end.num_days_from_ce());
+    NaiveDate::from_num_days_from_ce_opt(days).unwrap_or(start)
+}
+
+/// US Social Security Number (`AAA-GG-SSSS`).
+///
+/// Area 000, 666 and 900-999, group 00 and serial 0000 are never issued.
+fn generate_ssn(rng: &mut ForgeryRng) -> String {
+    let mut area: u16 = rng.gen_range(1, 899);
+    if area == 666 {
+        area = 665;
+    }
+    let group: u8 = rng.gen_range(1, 99);
+    let serial: u16 = rng.gen_range(1, 9999);
+    format!("{:03}-{:02}-{:04}", area, group, serial)
+}
+
+/// UK National Insurance Number (`AB123456C`).
+fn generate_nino(rng: &mut ForgeryRng) -> String {
+    const FIRST: &[u8] = b"ABCEGHJKLMNOPRSTWXYZ";
+    const SECOND: &[u8] = b"ABCEGHJKLMNPRSTWXYZ";
+    const INVALID_PREFIXES: &[&str] = &["BG", "GB", "KN", "NK", "NT", "TN", "ZZ"];
+
+    let prefix = loop {
+        let prefix: String = [*rng.choose(FIRST), *rng.choose(SECOND)]
+            .iter()
+            .map(|&b| char::from(b))
+            .collect();
+        if !INVALID_PREFIXES.contains(&prefix.as_str()) {
+            break prefix;
+        }
+    };
+    let mut id = prefix;
+    push_digits(rng, &mut id, 6);
+    id.push(char::from(*rng.choose(b"ABCD")));
+    id
+}
+
+/// Canadian Social Insurance Number (`### ### ###`, Luhn-valid).
+fn generate_sin(rng: &mut ForgeryRng) -> String {
+    let mut digits = char::from(b'0' + rng.gen_range(1u8, 7)).to_string();
+    push_digits(rng, &mut digits, 7);
+    digits.push(char::from(b'0' + luhn_checksum(&digits)));
+    format!("{} {} {}", &digits[0..3], &digits[3..6], &digits[6..9])
+}
+
+/// German tax identification number (11 digits).
+///
+/// In the first ten digits exactly one digit appears twice, one digit is
+/// missing, and the first digit is not zero. The last digit is an
+/// ISO 7064 MOD 11,10 check digit.
+fn generate_steuer_id(rng: &mut ForgeryRng) -> String {
+    let mut digits: Vec<u32> = loop {
+        let missing = rng.gen_range(0u32, 9);
+        let doubled = loop {
+            let d = rng.gen_range(0u32, 9);
+            if d != missing {
+                break d;
+            }
+        };
+        let mut digits: Vec<u32> = (0..10).filter(|&d| d != missing).collect();
+        digits.push(doubled);
+        // Fisher-Yates shuffle
+        for i in (1..digits.len()).rev() {
+            let j = rng.gen_range(0, i);
+            digits.swap(i, j);
+        }
+        if digits[0] != 0 {
+            break digits;
+        }
+    };
+
+    let check = mod_11_10_check_digit(&digits);
+    digits.push(check);
+    digits.iter().map(|d| d.to_string()).collect()
+}
+
+/// ISO 7064 MOD 11,10 check digit, used by the Steuer-ID and German VAT numbers.
+pub(crate) fn mod_11_10_check_digit(digits: &[u32]) -> u32 {
+    let mut product = 10;
+    for &d in digits {
+        let mut sum = (d + product) % 10;
+        if sum == 0 {
+            sum = 10;
+        }
+        product = (sum * 2) % 11;
+    }
+    (11 - product) % 10
+}
+
+/// French social security number (NIR, 15 digits).
+///
+/// Sex, birth year and month, département, commune and order number,
+/// followed by a two-digit key equal to 97 minus the number mod 97.
+fn generate_insee(rng: &mut ForgeryRng) -> String {
+    let birth = random_birth_date(rng);
+    let sex: u8 = rng.gen_range(1, 2);
+    let department: u8 = rng.gen_range(1, 95);
+    let commune: u16 = rng.gen_range(1, 990);
+    let order: u16 = rng.gen_range(1, 999);
+    let number = format!(
+        "{}{:02}{:02}{:02}{:03}{:03}",
+        sex,
+        birth.year() % 100,
+        birth.month(),
+        department,
+        commune,
+        order
+    );
+    let value: u64 = number.parse().expect("NIR digits");
+    format!("{}{:02}", number, 97 - value % 97)
+}
+
+/// Spanish DNI (`12345678Z`) or, one time in four, NIE (`X1234567L`).
+pub(crate) fn generate_dni(rng: &mut ForgeryRng) -> String {
+    if rng.gen_range(0u8, 3) == 0 {
+        let prefix = rng.gen_range(0u32, 2);
+        let number: u32 = rng.gen_range(0, 9_999_999);
+        let letter = DNI_LETTERS[((prefix * 10_000_000 + number) % 23) as usize];
+        format!(
+            "{}{:07}{}",
+            char::from(b"XYZ"[prefix as usize]),
+            number,
+            char::from(letter)
+        )
+    } else {
+        let number: u32 = rng.gen_range(0, 99_999_999);
+        format!(
+            "{:08}{}",
+            number,
+            char::from(DNI_LETTERS[(number % 23) as usize])
+        )
+    }
+}
+
+/// Three-letter codice fiscale code for a name.
+///
+/// Consonants first, then vowels, padded with `X`. First names with four or
+/// more consonants use the first, third and fourth.
+fn cf_name_code(name: &str, is_first_name: bool) -> String {
+    let letters: Vec<char> = name
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let (vowels, consonants): (Vec<char>, Vec<char>) =
+        letters.iter().partition(|c| "AEIOU".contains(**c));
+
+    let mut code: Vec<char> = if is_first_name && consonants.len() >= 4 {
+        vec![consonants[0], consonants[2], consonants[3]]
+    } else {
+        consonants
+    };
+    code.extend(vowels);
+    code.extend(['X'; 3]);
+    code.truncate(3);
+    code.into_iter().collect()
+}
+
+/// Italian codice fiscale (16 characters).
+///
+/// Derived from a random Italian name, birth date, sex and municipality,
+/// with the official check character.
+fn generate_codice_fiscale(rng: &mut ForgeryRng) -> String {
+    let data = get_locale_data(Locale::ItIT);
+    let last = data
+        .last_names()
+        .filter(|names| !names.is_empty())
+        .map_or("Rossi", |names| *rng.choose(names));
+    let first = data
+        .romanized_first_names()
+        .filter(|names| !names.is_empty())
+        .map_or("Mario", |names| *rng.choose(names));
+    let birth = random_birth_date(rng);
+    let female = rng.gen_range(0u8, 1) == 1;
+    let municipality = rng.choose(CF_MUNICIPALITIES);
+
+    let day = birth.day() + if female { 40 } else { 0 };
+    let mut code = format!(
+        "{}{}{:02}{}{:02}{}",
+        cf_name_code(last, false),
+        cf_name_code(first, true),
+        birth.year() % 100,
+        char::from(CF_MONTHS[birth.month0() as usize]),
+        day,
+        municipality
+    );
+    code.push(codice_fiscale_check(&code));
+    code
+}
+
+/// Check character for the first 15 characters of a codice fiscale.
+fn codice_fiscale_check(code: &str) -> char {
+    let sum: u32 = code
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| {
+            let idx = match b {
+                b'0'..=b'9' => (b - b'0') as usize,
+                _ => (b - b'A') as usize,
+            };
+            // Positions are 1-based in the spec, so index 0 is "odd"
+            if i % 2 == 0 {
+                CF_ODD_VALUES[idx]
+            } else {
+                idx as u32
+            }
+        })
+        .sum();
+    char::from(b'A' + (sum % 26) as u8)
+}
+
+/// Dutch citizen service number (9 digits passing the 11-test).
+fn generate_bsn(rng: &mut ForgeryRng) -> String {
+    loop {
+        let mut digits = char::from(b'0' + rng.gen_range(1u8, 9)).to_string();
+        push_digits(rng, &mut digits, 7);
+        let sum: u32 = digits_of(&digits)
+            .iter()
+            .zip((2..=9).rev())
+            .map(|(d, w)| d * w)
+            .sum();
+        let check = sum % 11;
+        if check < 10 {
+            digits.push(char::from_digit(check, 10).expect("digit"));
+            return digits;
+        }
+    }
+}
+
+/// Polish PESEL (11 digits).
+///
+/// Birth date with the century encoded in the month, a serial whose last
+/// digit is even for women, and a weighted check digit.
+fn generate_pesel(rng: &mut ForgeryRng) -> String {
+    const WEIGHTS: [u32; 10] = [1, 3, 7, 9, 1, 3, 7, 9, 1, 3];
+    let birth = random_birth_date(rng);
+    let month = birth.month() + if birth.year() >= 2000 { 20 } else { 0 };
+    let mut digits = format!("{:02}{:02}{:02}", birth.year() % 100, month, birth.day());
+    push_digits(rng, &mut digits, 4);
+    let sum: u32 = digits_of(&digits)
+        .iter()
+        .zip(WEIGHTS)
+        .map(|(d, w)| d * w)
+        .sum();
+    digits.push(char::from_digit((10 - sum % 10) % 10, 10).expect("digit"));
+    digits
+}
+
+/// Russian SNILS (`123-456-789 01`).
+fn generate_snils(rng: &mut ForgeryRng) -> String {
+    // Numbers up to 001-001-998 have no meaningful check digits
+    let number: u32 = rng.gen_range(1_001_999, 999_999_999);
+    let digits = format!("{:09}", number);
+    let sum: u32 = digits_of(&digits)
+        .iter()
+        .zip((1..=9).rev())
+        .map(|(d, w)| d * w)
+        .sum();
+    let check = match sum {
+        0..=99 => sum,
+        100 | 101 => 0,
+        _ => (sum % 101) % 100,
+    };
+    format!(
+        "{}-{}-{} {:02}",
+        &digits[0..3],
+        &digits[3..6],
+        &digits[6..9],
+        check
+    )
+}
+
+/// Japanese Individual Number "My Number" (12 digits).
+fn generate_my_number(rng: &mut ForgeryRng) -> String {
+    let mut digits = String::with_capacity(12);
+    push_digits(rng, &mut digits, 11);
+    let check = my_number_check_digit(&digits_of(&digits));
+    digits.push(char::from_digit(check, 10).expect("digit"));
+    digits
+}
+
+/// Check digit over the first eleven My Number digits.
+fn my_number_check_digit(digits: &[u32]) -> u32 {
+    // Weights run from the rightmost digit: 2-7, then 2-6
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            let n = i as u32 + 1;
+            d * if n <= 6 { n + 1 } else { n - 5 }
+        })
+        .sum();
+    let remainder = sum % 11;
+    if remainder <= 1 {
+        0
+    } else {
+        11 - remainder
+    }
+}
+
+/// Korean Resident Registration Number (`YYMMDD-GNNNNNC`).
+fn generate_rrn(rng: &mut ForgeryRng) -> String {
+    const WEIGHTS: [u32; 12] = [2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4, 5];
+    let birth = random_birth_date(rng);
+    let female = rng.gen_range(0u32, 1);
+    let century_base = if birth.year() >= 2000 { 3 } else { 1 };
+    let mut digits = format!(
+        "{:02}{:02}{:02}{}",
+        birth.year() % 100,
+        birth.month(),
+        birth.day(),
+        century_base + female
+    );
+    push_digits(rng, &mut digits, 5);
+    let sum: u32 = digits_of(&digits)
+        .iter()
+        .zip(WEIGHTS)
+        .map(|(d, w)| d * w)
+        .sum();
+    let check = (11 - sum % 11) % 10;
+    format!("{}-{}{}", &digits[0..6], &digits[6..], check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::finance::validate_luhn;
+
+    fn sample(locale: Locale, n: usize) -> Vec<String> {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        generate_national_ids(&mut rng, locale, n)
+    }
+
+    #[test]
+    fn test_generate_national_ids_count() {
+        for locale in Locale::ALL {
+            assert_eq!(sample(*locale, 50).len(), 50);
+        }
+    }
+
+    #[test]
+    fn test_national_id_deterministic() {
+        for locale in Locale::ALL {
+            assert_eq!(sample(*locale, 20), sample(*locale, 20));
+        }
+    }
+
+    #[test]
+    fn test_ssn_format() {
+        for ssn in sample(Locale::EnUS, 200) {
+            let parts: Vec<&str> = ssn.split('-').collect();
+            assert_eq!(parts.len(), 3, "{}", ssn);
+            assert_eq!((parts[0].len(), parts[1].len(), parts[2].len()), (3, 2, 4));
+            assert!(!["000", "666"].contains(&parts[0]) && !parts[0].starts_with('9'));
+            assert_ne!(parts[1], "00");
+            assert_ne!(parts[2], "0000");
+        }
+    }
+
+    #[test]
+    fn test_nino_format() {
+        for nino in sample(Locale::EnGB, 200) {
+            let bytes = nino.as_bytes();
+            assert_eq!(bytes.len(), 9, "{}", nino);
+            assert!(bytes[..2].iter().all(u8::is_ascii_uppercase));
+            assert!(bytes[2..8].iter().all(u8::is_ascii_digit));
+            assert!(b"ABCD".contains(&bytes[8]));
+            assert!(!nino.starts_with("GB") && !nino.starts_with("NK"));
+        }
+    }
+
+    #[test]
+    fn test_sin_luhn_valid() {
+        for sin in sample(Locale::EnCA, 200) {
+            assert_eq!(sin.len(), 11, "{}", sin);
+            assert!(validate_luhn(&sin), "{}", sin);
+        }
+    }
+
+    #[test]
+    fn test_steuer_id_valid() {
+        for id in sample(Locale::DeDE, 200) {
+            let digits = digits_of(&id);
+            assert_eq!(digits.len(), 11, "{}", id);
+            assert_ne!(digits[0], 0);
+            let mut counts = [0; 10];
+            for &d in &digits[..10] {
+                counts[d as usize] += 1;
+            }
+            assert_eq!(counts.iter().filter(|&&c| c == 2).count(), 1, "{}", id);
+            assert_eq!(counts.iter().filter(|&&c| c == 0).count(), 1, "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_mod_11_10_check_digit() {
+        // Published example number 86095742719
+        assert_eq!(mod_11_10_check_digit(&[8, 6, 0, 9, 5, 7, 4, 2, 7, 1]), 9);
+        for id in sample(Locale::DeDE, 50) {
+            let digits = digits_of(&id);
+            assert_eq!(mod_11_10_check_digit(&digits[..10]), digits[10], "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_insee_key() {
+        for nir in sample(Locale::FrFR, 200) {
+            assert_eq!(nir.len(), 15, "{}", nir);
+            let number: u64 = nir[..13].parse().unwrap();
+            let key: u64 = nir[13..].parse().unwrap();
+            assert_eq!(key, 97 - number % 97, "{}", nir);
+            assert!(nir.starts_with('1') || nir.starts_with('2'));
+        }
+    }
+
+    #[test]
+    fn test_dni_nie_letter() {
+        for id in sample(Locale::EsES, 200) {
+            assert_eq!(id.len(), 9, "{}", id);
+            let (body, letter) = id.split_at(8);
+            let numeric = body
+                .replacen('X', "0", 1)
+                .replacen('Y', "1", 1)
+                .replacen('Z', "2", 1);
+            let value: u32 = numeric.parse().unwrap();
+            assert_eq!(
+                letter.as_bytes()[0],
+                DNI_LETTERS[(value % 23) as usize],
+                "{}",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_codice_fiscale_check() {
+        // Well-known example: Mario Rossi, 1985-12-10, Acqui Terme (A052)
+        assert_eq!(cf_name_code("Rossi", false), "RSS");
+        assert_eq!(cf_name_code("Mario", true), "MRA");
+        assert_eq!(codice_fiscale_check("RSSMRA85T10A562"), 'S');
+
+        for cf in sample(Locale::ItIT, 200) {
+            assert_eq!(cf.len(), 16, "{}", cf);
+            assert_eq!(cf.chars().last().unwrap(), codice_fiscale_check(&cf[..15]));
+            assert!(CF_MONTHS.contains(&cf.as_bytes()[8]));
+        }
+    }
+
+    #[test]
+    fn test_cf_name_code_rules() {
+        // Four or more consonants: first, third and fourth
+        assert_eq!(cf_name_code("Gianfranco", true), "GFR");
+        // Short names are padded with vowels then X
+        assert_eq!(cf_name_code("Fo", false), "FOX");
+    }
+
+    #[test]
+    fn test_bsn_eleven_test() {
+        for bsn in sample(Locale::NlNL, 200) {
+            let digits = digits_of(&bsn);
+            assert_eq!(digits.len(), 9, "{}", bsn);
+            let sum: i64 = digits
+                .iter()
+                .zip([9, 8, 7, 6, 5, 4, 3, 2, -1])
+                .map(|(&d, w)| d as i64 * w)
+                .sum();
+            assert_eq!(sum % 11, 0, "{}", bsn);
+        }
+    }
+
+    #[test]
+    fn test_pesel_check_digit() {
+        for pesel in sample(Locale::PlPL, 200) {
+            let digits = digits_of(&pesel);
+            assert_eq!(digits.len(), 11, "{}", pesel);
+            let sum: u32 = digits
+                .iter()
+                .zip([1, 3, 7, 9, 1, 3, 7, 9, 1, 3, 1])
+                .map(|(d, w)| d * w)
+                .sum();
+            assert_eq!(sum % 10, 0, "{}", pesel);
+        }
+    }
+
+    #[test]
+    fn test_snils_format() {
+        for snils in sample(Locale::RuRU, 200) {
+            assert_eq!(snils.len(), 14, "{}", snils);
+            assert_eq!(&snils[3..4], "-");
+            assert_eq!(&snils[11..12], " ");
+        }
+        // Published example
+        let digits = digits_of("112-233-445");
+        let sum: u32 = digits.iter().zip((1..=9).rev()).map(|(d, w)| d * w).sum();
+        assert_eq!(sum, 95);
+    }
+
+    #[test]
+    fn test_my_number_check_digit() {
+        // Published example number 123456789018
+        assert_eq!(my_number_check_digit(&digits_of("12345678901")), 8);
+        for id in sample(Locale::JaJP, 200) {
+            let digits = digits_of(&id);
+            assert_eq!(digits.len(), 12, "{}", id);
+            assert_eq!(my_number_check_digit(&digits[..11]), digits[11], "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_rrn_format() {
+        for rrn in sample(Locale::KoKR, 200) {
+            assert_eq!(rrn.len(), 14, "{}", rrn);
+            assert_eq!(&rrn[6..7], "-");
+            assert!(['1', '2', '3', '4'].contains(&rrn.chars().nth(7).unwrap()));
+            let digits = digits_of(&rrn);
+            let sum: u32 = digits
+                .iter()
+                .zip([2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4, 5])
+                .map(|(d, w)| d * w)
+                .sum();
+            assert_eq!((11 - sum % 11) % 10, digits[12], "{}", rrn);
+        }
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_national_id_count(n in 0usize..200, locale_idx in 0usize..12) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(42);
+            let locale = Locale::ALL[locale_idx];
+            prop_assert_eq!(generate_national_ids(&mut rng, locale, n).len(), n);
+        }
+
+        #[test]
+        fn prop_national_id_seed_determinism(seed in any::<u64>(), locale_idx in 0usize..12) {
+            let locale = Locale::ALL[locale_idx];
+            let mut rng1 = ForgeryRng::new();
+            let mut rng2 = ForgeryRng::new();
+            rng1.seed(seed);
+            rng2.seed(seed);
+            prop_assert_eq!(
+                generate_national_ids(&mut rng1, locale, 10),
+                generate_national_ids(&mut rng2, locale, 10)
+            );
+        }
+    }
+}
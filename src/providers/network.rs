@@ -1,9 +1,30 @@
 //! Network-related data generation provider.
 //!
-//! Generates URLs, domain names, IP addresses, and MAC addresses.
+//! Generates URLs, domain names, IP addresses, MAC addresses, port numbers,
+//! and HTTP methods and status codes.
+//!
+//! Port kinds follow the IANA ranges:
+//!
+//! | Kind | Range |
+//! |------|-------|
+//! | `well_known` | 1-1023 |
+//! | `registered` | 1024-49151 |
+//! | `ephemeral` | 49152-65535 |
+//! | `any` | 1-65535 |
+//!
+//! IPv6 kinds:
+//!
+//! | Kind | Range |
+//! |------|-------|
+//! | `random` | All 128 bits random |
+//! | `global` | Global unicast, 2000::/3 |
+//! | `link_local` | fe80::/64 |
+//! | `ula` | Unique local, fd00::/8 |
 
 use crate::data::en_us::TLDS;
 use crate::rng::ForgeryRng;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
 
 /// Generate a batch of random domain names.
 pub fn generate_domain_names(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
@@ -37,19 +58,246 @@ pub fn generate_urls(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
 /// Generate a single random URL.
 #[inline]
 pub fn generate_url(rng: &mut ForgeryRng) -> String {
+    url_with_options(rng, &UrlOptions::default())
+}
+
+// === URL Options ===
+
+/// Path segments for URLs with an explicit path depth.
+const URL_PATH_SEGMENTS: &[&str] = &[
+    "about",
+    "account",
+    "api",
+    "assets",
+    "blog",
+    "category",
+    "docs",
+    "downloads",
+    "files",
+    "help",
+    "images",
+    "news",
+    "orders",
+    "products",
+    "search",
+    "services",
+    "static",
+    "users",
+    "v1",
+    "v2",
+];
+
+/// Query parameter names.
+const URL_QUERY_KEYS: &[&str] = &[
+    "q",
+    "page",
+    "id",
+    "sort",
+    "lang",
+    "ref",
+    "filter",
+    "limit",
+    "offset",
+    "utm_source",
+];
+
+/// Query values that need percent-decoding: spaces, reserved characters and
+/// multi-byte UTF-8.
+const URL_ENCODED_VALUES: &[&str] = &[
+    "hello%20world",
+    "a%2Bb%3Dc",
+    "caf%C3%A9",
+    "50%25",
+    "x%26y",
+    "%E6%97%A5%E6%9C%AC",
+];
+
+/// Fragment identifiers.
+const URL_FRAGMENTS: &[&str] = &[
+    "top",
+    "intro",
+    "overview",
+    "details",
+    "comments",
+    "faq",
+    "section-1",
+    "section-2",
+];
+
+/// Options controlling the shape of generated URLs.
+///
+/// The default reproduces `generate_url`: an `https` URL with at most one
+/// path segment and no query or fragment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UrlOptions<'a> {
+    /// URL scheme, e.g. "https", "http" or "ftp".
+    pub scheme: &'a str,
+    /// Number of path segments; `None` picks zero or one from a short list.
+    pub path_depth: Option<usize>,
+    /// Number of query parameters.
+    pub query_params: usize,
+    /// Append a `#fragment`.
+    pub fragment: bool,
+    /// File extension for the last path segment, e.g. "html" or ".pdf".
+    pub extension: Option<&'a str>,
+}
+
+impl Default for UrlOptions<'_> {
+    fn default() -> Self {
+        UrlOptions {
+            scheme: "https",
+            path_depth: None,
+            query_params: 0,
+            fragment: false,
+            extension: None,
+        }
+    }
+}
+
+impl UrlOptions<'_> {
+    /// Check the scheme against RFC 3986 and the extension for URL-safe characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UrlOptionsError` for an invalid scheme or extension.
+    pub fn validate(&self) -> Result<(), UrlOptionsError> {
+        let mut scheme = self.scheme.chars();
+        let valid_scheme = scheme.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && scheme.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+        if !valid_scheme {
+            return Err(UrlOptionsError::InvalidScheme(self.scheme.to_string()));
+        }
+        if let Some(extension) = self.extension {
+            let trimmed = extension.strip_prefix('.').unwrap_or(extension);
+            if trimmed.is_empty() || !trimmed.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(UrlOptionsError::InvalidExtension(extension.to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Errors for invalid URL options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlOptionsError {
+    /// Scheme does not match `ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )`.
+    InvalidScheme(String),
+    /// Extension is empty or contains characters other than ASCII letters and digits.
+    InvalidExtension(String),
+}
+
+impl std::fmt::Display for UrlOptionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidScheme(scheme) => write!(
+                f,
+                "invalid URL scheme '{}'; expected a letter followed by letters, digits, '+', '-' or '.'",
+                scheme
+            ),
+            Self::InvalidExtension(extension) => write!(
+                f,
+                "invalid URL file extension '{}'; expected ASCII letters and digits",
+                extension
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UrlOptionsError {}
+
+/// Generate a batch of URLs shaped by `options`.
+///
+/// # Errors
+///
+/// Returns `UrlOptionsError` if the options are invalid.
+pub fn generate_urls_with_options(
+    rng: &mut ForgeryRng,
+    n: usize,
+    options: &UrlOptions,
+) -> Result<Vec<String>, UrlOptionsError> {
+    options.validate()?;
+    let mut urls = Vec::with_capacity(n);
+    for _ in 0..n {
+        urls.push(url_with_options(rng, options));
+    }
+    Ok(urls)
+}
+
+/// Generate a single URL shaped by `options`
+/// (e.g., "http://demo.org/api/v2/4821/docs.html?page=3&q=hello%20world#faq").
+///
+/// # Errors
+///
+/// Returns `UrlOptionsError` if the options are invalid.
+#[inline]
+pub fn generate_url_with_options(
+    rng: &mut ForgeryRng,
+    options: &UrlOptions,
+) -> Result<String, UrlOptionsError> {
+    options.validate()?;
+    Ok(url_with_options(rng, options))
+}
+
+/// URL for options that are already validated.
+fn url_with_options(rng: &mut ForgeryRng, options: &UrlOptions) -> String {
     let domain = generate_domain_name(rng);
-    let paths = [
-        "",
-        "/about",
-        "/contact",
-        "/products",
-        "/services",
-        "/blog",
-        "/api",
-        "/docs",
-    ];
-    let path = rng.choose(&paths);
-    format!("https://{}{}", domain, path)
+    let mut url = format!("{}://{}", options.scheme, domain);
+
+    match options.path_depth {
+        None => {
+            let paths = [
+                "",
+                "/about",
+                "/contact",
+                "/products",
+                "/services",
+                "/blog",
+                "/api",
+                "/docs",
+            ];
+            let path = *rng.choose(&paths);
+            url.push_str(path);
+        }
+        Some(depth) => {
+            for _ in 0..depth {
+                url.push('/');
+                // About one segment in four is a numeric resource ID
+                if rng.gen_range(0, 3) == 0 {
+                    url.push_str(&rng.gen_range(1u32, 99999).to_string());
+                } else {
+                    let segment = *rng.choose(URL_PATH_SEGMENTS);
+                    url.push_str(segment);
+                }
+            }
+        }
+    }
+
+    if let Some(extension) = options.extension {
+        if url.ends_with(&domain) {
+            url.push_str("/index");
+        }
+        url.push('.');
+        url.push_str(extension.strip_prefix('.').unwrap_or(extension));
+    }
+
+    for i in 0..options.query_params {
+        url.push(if i == 0 { '?' } else { '&' });
+        let key = *rng.choose(URL_QUERY_KEYS);
+        url.push_str(key);
+        url.push('=');
+        match rng.gen_range(0, 2) {
+            0 => url.push_str(&rng.gen_range(1u32, 500).to_string()),
+            1 => url.push_str(rng.choose::<&str>(URL_PATH_SEGMENTS)),
+            _ => url.push_str(rng.choose::<&str>(URL_ENCODED_VALUES)),
+        }
+    }
+
+    if options.fragment {
+        url.push('#');
+        let fragment = *rng.choose(URL_FRAGMENTS);
+        url.push_str(fragment);
+    }
+    url
 }
 
 /// Generate a batch of random IPv4 addresses.
@@ -71,6 +319,333 @@ pub fn generate_ipv4(rng: &mut ForgeryRng) -> String {
     format!("{}.{}.{}.{}", a, b, c, d)
 }
 
+// === IPv4 Ranges ===
+
+/// RFC 1918 private networks as (network address, prefix length).
+pub const IPV4_PRIVATE_NETWORKS: &[(Ipv4Addr, u8)] = &[
+    (Ipv4Addr::new(10, 0, 0, 0), 8),
+    (Ipv4Addr::new(172, 16, 0, 0), 12),
+    (Ipv4Addr::new(192, 168, 0, 0), 16),
+];
+
+/// Special-purpose networks (RFC 6890) excluded from public addresses:
+/// "this network", private, shared, loopback, link-local, documentation,
+/// benchmarking, multicast and reserved ranges.
+pub const IPV4_RESERVED_NETWORKS: &[(Ipv4Addr, u8)] = &[
+    (Ipv4Addr::new(0, 0, 0, 0), 8),
+    (Ipv4Addr::new(10, 0, 0, 0), 8),
+    (Ipv4Addr::new(100, 64, 0, 0), 10),
+    (Ipv4Addr::new(127, 0, 0, 0), 8),
+    (Ipv4Addr::new(169, 254, 0, 0), 16),
+    (Ipv4Addr::new(172, 16, 0, 0), 12),
+    (Ipv4Addr::new(192, 0, 0, 0), 24),
+    (Ipv4Addr::new(192, 0, 2, 0), 24),
+    (Ipv4Addr::new(192, 88, 99, 0), 24),
+    (Ipv4Addr::new(192, 168, 0, 0), 16),
+    (Ipv4Addr::new(198, 18, 0, 0), 15),
+    (Ipv4Addr::new(198, 51, 100, 0), 24),
+    (Ipv4Addr::new(203, 0, 113, 0), 24),
+    (Ipv4Addr::new(224, 0, 0, 0), 4),
+    (Ipv4Addr::new(240, 0, 0, 0), 4),
+];
+
+/// Default CIDR prefix length range.
+pub const DEFAULT_CIDR_PREFIX_RANGE: (u8, u8) = (8, 30);
+
+/// Error for a CIDR prefix length range outside 0-32 or with min > max.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CidrPrefixError {
+    /// The invalid minimum prefix length.
+    pub min: u8,
+    /// The invalid maximum prefix length.
+    pub max: u8,
+}
+
+impl std::fmt::Display for CidrPrefixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid CIDR prefix range ({}, {}); expected min <= max <= 32",
+            self.min, self.max
+        )
+    }
+}
+
+impl std::error::Error for CidrPrefixError {}
+
+/// Netmask for a prefix length (0-32).
+fn prefix_mask(prefix: u8) -> u32 {
+    u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0)
+}
+
+/// Whether `addr` lies in any of `networks`.
+fn in_networks(addr: Ipv4Addr, networks: &[(Ipv4Addr, u8)]) -> bool {
+    networks.iter().any(|&(network, prefix)| {
+        let mask = prefix_mask(prefix);
+        u32::from(addr) & mask == u32::from(network)
+    })
+}
+
+/// Whether an IPv4 address is in an RFC 1918 private network.
+pub fn is_private_ipv4(addr: Ipv4Addr) -> bool {
+    in_networks(addr, IPV4_PRIVATE_NETWORKS)
+}
+
+/// Whether an IPv4 address is publicly routable (outside every reserved network).
+pub fn is_public_ipv4(addr: Ipv4Addr) -> bool {
+    !in_networks(addr, IPV4_RESERVED_NETWORKS)
+}
+
+/// A random host address whose last octet is 1-254, as in `generate_ipv4`.
+fn random_host(rng: &mut ForgeryRng, network: Ipv4Addr, prefix: u8) -> Ipv4Addr {
+    let mask = prefix_mask(prefix);
+    let host: u32 = rng.gen_range(0, u32::MAX) & !mask;
+    let last_octet = u32::from(rng.gen_range(1u8, 254));
+    Ipv4Addr::from((u32::from(network) & mask) | (host & !0xFF) | last_octet)
+}
+
+/// A random publicly routable address, drawn by rejection sampling.
+fn public_ipv4(rng: &mut ForgeryRng) -> Ipv4Addr {
+    loop {
+        let addr = random_host(rng, Ipv4Addr::UNSPECIFIED, 0);
+        if is_public_ipv4(addr) {
+            return addr;
+        }
+    }
+}
+
+/// Generate a batch of RFC 1918 private IPv4 addresses.
+pub fn generate_private_ipv4s(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut ips = Vec::with_capacity(n);
+    for _ in 0..n {
+        ips.push(generate_private_ipv4(rng));
+    }
+    ips
+}
+
+/// Generate a single RFC 1918 private IPv4 address (e.g., "10.42.7.19").
+///
+/// Each of 10.0.0.0/8, 172.16.0.0/12 and 192.168.0.0/16 is equally likely.
+#[inline]
+pub fn generate_private_ipv4(rng: &mut ForgeryRng) -> String {
+    let &(network, prefix) = rng.choose(IPV4_PRIVATE_NETWORKS);
+    random_host(rng, network, prefix).to_string()
+}
+
+/// Generate a batch of publicly routable IPv4 addresses.
+pub fn generate_public_ipv4s(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut ips = Vec::with_capacity(n);
+    for _ in 0..n {
+        ips.push(generate_public_ipv4(rng));
+    }
+    ips
+}
+
+/// Generate a single publicly routable IPv4 address, outside every
+/// special-purpose network in `IPV4_RESERVED_NETWORKS`.
+#[inline]
+pub fn generate_public_ipv4(rng: &mut ForgeryRng) -> String {
+    public_ipv4(rng).to_string()
+}
+
+/// Loopback network (RFC 1122).
+const IPV4_LOOPBACK_NETWORKS: &[(Ipv4Addr, u8)] = &[(Ipv4Addr::new(127, 0, 0, 0), 8)];
+
+/// Link-local network (RFC 3927).
+const IPV4_LINK_LOCAL_NETWORKS: &[(Ipv4Addr, u8)] = &[(Ipv4Addr::new(169, 254, 0, 0), 16)];
+
+/// Shared address space for carrier-grade NAT (RFC 6598).
+const IPV4_SHARED_NETWORKS: &[(Ipv4Addr, u8)] = &[(Ipv4Addr::new(100, 64, 0, 0), 10)];
+
+/// Documentation networks TEST-NET-1, -2 and -3 (RFC 5737).
+const IPV4_DOCUMENTATION_NETWORKS: &[(Ipv4Addr, u8)] = &[
+    (Ipv4Addr::new(192, 0, 2, 0), 24),
+    (Ipv4Addr::new(198, 51, 100, 0), 24),
+    (Ipv4Addr::new(203, 0, 113, 0), 24),
+];
+
+/// Multicast network (class D).
+const IPV4_MULTICAST_NETWORKS: &[(Ipv4Addr, u8)] = &[(Ipv4Addr::new(224, 0, 0, 0), 4)];
+
+/// Classful address blocks by leading bits: A is 0, B is 10, C is 110.
+const IPV4_CLASS_A_NETWORKS: &[(Ipv4Addr, u8)] = &[(Ipv4Addr::new(0, 0, 0, 0), 1)];
+const IPV4_CLASS_B_NETWORKS: &[(Ipv4Addr, u8)] = &[(Ipv4Addr::new(128, 0, 0, 0), 2)];
+const IPV4_CLASS_C_NETWORKS: &[(Ipv4Addr, u8)] = &[(Ipv4Addr::new(192, 0, 0, 0), 3)];
+
+/// Accepted range names for `Ipv4Range`, in declaration order.
+pub const IPV4_RANGES: &[&str] = &[
+    "private",
+    "public",
+    "loopback",
+    "link_local",
+    "shared",
+    "documentation",
+    "multicast",
+    "class_a",
+    "class_b",
+    "class_c",
+];
+
+/// Where generated IPv4 addresses come from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ipv4Range {
+    /// Publicly routable addresses, as in `generate_public_ipv4`.
+    Public,
+    /// Host addresses in one of these networks, picked uniformly per address.
+    Networks(Vec<(Ipv4Addr, u8)>),
+}
+
+/// Error for an IPv4 range that is neither a known name nor a CIDR block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ipv4RangeError {
+    /// The unrecognized range.
+    pub range: String,
+}
+
+impl std::fmt::Display for Ipv4RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown IPv4 network '{}'; expected a CIDR block such as 10.0.0.0/8 or one of: {}",
+            self.range,
+            IPV4_RANGES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for Ipv4RangeError {}
+
+impl FromStr for Ipv4Range {
+    type Err = Ipv4RangeError;
+
+    /// Parse a range name or a CIDR block; host bits of a CIDR block are
+    /// cleared, so "192.168.1.10/24" means 192.168.1.0/24.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let networks = match s.to_ascii_lowercase().as_str() {
+            "private" => IPV4_PRIVATE_NETWORKS,
+            "public" => return Ok(Ipv4Range::Public),
+            "loopback" => IPV4_LOOPBACK_NETWORKS,
+            "link_local" => IPV4_LINK_LOCAL_NETWORKS,
+            "shared" => IPV4_SHARED_NETWORKS,
+            "documentation" => IPV4_DOCUMENTATION_NETWORKS,
+            "multicast" => IPV4_MULTICAST_NETWORKS,
+            "class_a" => IPV4_CLASS_A_NETWORKS,
+            "class_b" => IPV4_CLASS_B_NETWORKS,
+            "class_c" => IPV4_CLASS_C_NETWORKS,
+            _ => {
+                return parse_ipv4_cidr(s)
+                    .map(|network| Ipv4Range::Networks(vec![network]))
+                    .ok_or_else(|| Ipv4RangeError {
+                        range: s.to_string(),
+                    })
+            }
+        };
+        Ok(Ipv4Range::Networks(networks.to_vec()))
+    }
+}
+
+/// Parse "a.b.c.d/prefix" into a network address and prefix length.
+fn parse_ipv4_cidr(s: &str) -> Option<(Ipv4Addr, u8)> {
+    let (addr, prefix) = s.split_once('/')?;
+    let addr: Ipv4Addr = addr.parse().ok()?;
+    let prefix: u8 = prefix.parse().ok().filter(|&p| p <= 32)?;
+    Some((
+        Ipv4Addr::from(u32::from(addr) & prefix_mask(prefix)),
+        prefix,
+    ))
+}
+
+/// A random host address in a network of any prefix length.
+///
+/// Up to /24 this is `random_host`. Smaller networks skip their network
+/// and broadcast addresses unless they are /31 or /32, which have none.
+fn host_in_network(rng: &mut ForgeryRng, network: Ipv4Addr, prefix: u8) -> Ipv4Addr {
+    if prefix <= 24 {
+        return random_host(rng, network, prefix);
+    }
+    let size = 1u32 << (32 - prefix);
+    let offset = if size >= 4 {
+        rng.gen_range(1, size - 2)
+    } else {
+        rng.gen_range(0, size - 1)
+    };
+    Ipv4Addr::from(u32::from(network) | offset)
+}
+
+/// Generate a batch of IPv4 addresses in `range`.
+pub fn generate_ipv4s_in(rng: &mut ForgeryRng, n: usize, range: &Ipv4Range) -> Vec<String> {
+    let mut ips = Vec::with_capacity(n);
+    for _ in 0..n {
+        ips.push(generate_ipv4_in(rng, range));
+    }
+    ips
+}
+
+/// Generate a single IPv4 address in `range` (e.g., "192.0.2.17" for
+/// "documentation").
+#[inline]
+pub fn generate_ipv4_in(rng: &mut ForgeryRng, range: &Ipv4Range) -> String {
+    match range {
+        Ipv4Range::Public => generate_public_ipv4(rng),
+        Ipv4Range::Networks(networks) => {
+            let &(network, prefix) = rng.choose(networks);
+            host_in_network(rng, network, prefix).to_string()
+        }
+    }
+}
+
+/// Validate a CIDR prefix length range.
+fn validate_prefix_range(min: u8, max: u8) -> Result<(), CidrPrefixError> {
+    if min > max || max > 32 {
+        return Err(CidrPrefixError { min, max });
+    }
+    Ok(())
+}
+
+/// Generate a batch of IPv4 CIDR blocks.
+///
+/// # Errors
+///
+/// Returns `CidrPrefixError` if `min_prefix > max_prefix` or `max_prefix > 32`.
+pub fn generate_cidrs(
+    rng: &mut ForgeryRng,
+    n: usize,
+    min_prefix: u8,
+    max_prefix: u8,
+) -> Result<Vec<String>, CidrPrefixError> {
+    validate_prefix_range(min_prefix, max_prefix)?;
+    let mut cidrs = Vec::with_capacity(n);
+    for _ in 0..n {
+        cidrs.push(cidr(rng, min_prefix, max_prefix));
+    }
+    Ok(cidrs)
+}
+
+/// Generate a single IPv4 CIDR block (e.g., "93.184.216.0/22").
+///
+/// The prefix length is drawn from `min_prefix..=max_prefix` and the network
+/// address is a public address with its host bits cleared.
+///
+/// # Errors
+///
+/// Returns `CidrPrefixError` if `min_prefix > max_prefix` or `max_prefix > 32`.
+#[inline]
+pub fn generate_cidr(
+    rng: &mut ForgeryRng,
+    min_prefix: u8,
+    max_prefix: u8,
+) -> Result<String, CidrPrefixError> {
+    validate_prefix_range(min_prefix, max_prefix)?;
+    Ok(cidr(rng, min_prefix, max_prefix))
+}
+
+/// CIDR block for a prefix range that is already validated.
+pub(crate) fn cidr(rng: &mut ForgeryRng, min_prefix: u8, max_prefix: u8) -> String {
+    let prefix = rng.gen_range(min_prefix, max_prefix);
+    let network = u32::from(public_ipv4(rng)) & prefix_mask(prefix);
+    format!("{}/{}", Ipv4Addr::from(network), prefix)
+}
+
 /// Generate a batch of random IPv6 addresses.
 pub fn generate_ipv6s(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
     let mut ips = Vec::with_capacity(n);
@@ -80,15 +655,120 @@ pub fn generate_ipv6s(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
     ips
 }
 
-/// Generate a single random IPv6 address.
+/// Generate a single random IPv6 address, fully expanded in lowercase.
 #[inline]
 pub fn generate_ipv6(rng: &mut ForgeryRng) -> String {
-    let mut groups = Vec::with_capacity(8);
-    for _ in 0..8 {
-        let group: u16 = rng.gen_range(0, 65535);
-        groups.push(format!("{:04x}", group));
+    generate_formatted_ipv6(rng, Ipv6Kind::Random, false, false)
+}
+
+// === IPv6 Options ===
+
+/// Address range an IPv6 address is drawn from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Ipv6Kind {
+    /// All 128 bits random.
+    #[default]
+    Random,
+    /// Global unicast (2000::/3).
+    Global,
+    /// Link-local (fe80::/64) with a random interface identifier.
+    LinkLocal,
+    /// Unique local (fd00::/8) with a random global ID, subnet and interface.
+    Ula,
+}
+
+/// Accepted names for `Ipv6Kind`, in declaration order.
+pub const IPV6_KINDS: &[&str] = &["random", "global", "link_local", "ula"];
+
+/// Error for an unknown IPv6 address kind name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ipv6KindError {
+    /// The unrecognized kind name.
+    pub kind: String,
+}
+
+impl std::fmt::Display for Ipv6KindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown IPv6 address kind '{}'; expected one of: {}",
+            self.kind,
+            IPV6_KINDS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for Ipv6KindError {}
+
+impl FromStr for Ipv6Kind {
+    type Err = Ipv6KindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "random" => Ok(Ipv6Kind::Random),
+            "global" => Ok(Ipv6Kind::Global),
+            "link_local" => Ok(Ipv6Kind::LinkLocal),
+            "ula" => Ok(Ipv6Kind::Ula),
+            _ => Err(Ipv6KindError {
+                kind: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Generate a batch of IPv6 addresses of `kind`.
+pub fn generate_formatted_ipv6s(
+    rng: &mut ForgeryRng,
+    n: usize,
+    kind: Ipv6Kind,
+    compressed: bool,
+    uppercase: bool,
+) -> Vec<String> {
+    let mut ips = Vec::with_capacity(n);
+    for _ in 0..n {
+        ips.push(generate_formatted_ipv6(rng, kind, compressed, uppercase));
+    }
+    ips
+}
+
+/// Generate a single IPv6 address of `kind`.
+///
+/// With `compressed` the address is in RFC 5952 canonical form: leading
+/// zeros dropped and the longest run of zero groups written as `::`
+/// (e.g., "fe80::1c2:ab:9f0e:4d"). Otherwise all eight groups are written
+/// as four hex digits (e.g., "fe80:0000:0000:0000:01c2:00ab:9f0e:004d").
+#[inline]
+pub fn generate_formatted_ipv6(
+    rng: &mut ForgeryRng,
+    kind: Ipv6Kind,
+    compressed: bool,
+    uppercase: bool,
+) -> String {
+    let mut groups = [0u16; 8];
+    for group in groups.iter_mut() {
+        *group = rng.gen_range(0, 65535);
+    }
+    match kind {
+        Ipv6Kind::Random => {}
+        Ipv6Kind::Global => groups[0] = 0x2000 | (groups[0] & 0x1fff),
+        Ipv6Kind::LinkLocal => groups[..4].copy_from_slice(&[0xfe80, 0, 0, 0]),
+        Ipv6Kind::Ula => groups[0] = 0xfd00 | (groups[0] & 0x00ff),
+    }
+
+    let ip = if compressed {
+        Ipv6Addr::from(groups).to_string()
+    } else {
+        groups
+            .iter()
+            .map(|g| format!("{:04x}", g))
+            .collect::<Vec<_>>()
+            .join(":")
+    };
+    if uppercase {
+        ip.to_ascii_uppercase()
+    } else {
+        ip
     }
-    groups.join(":")
 }
 
 /// Generate a batch of random MAC addresses.
@@ -103,12 +783,445 @@ pub fn generate_mac_addresses(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
 /// Generate a single random MAC address.
 #[inline]
 pub fn generate_mac_address(rng: &mut ForgeryRng) -> String {
+    generate_formatted_mac_address(rng, MacKind::Random, MacSeparator::Colon, false)
+}
+
+// === MAC Address Options ===
+
+/// Organizationally Unique Identifiers of common network hardware vendors.
+///
+/// All are universally administered unicast prefixes.
+pub const MAC_VENDOR_OUIS: &[[u8; 3]] = &[
+    [0x00, 0x00, 0x0c], // Cisco
+    [0x00, 0x25, 0x9c], // Cisco-Linksys
+    [0xfc, 0xfb, 0xfb], // Cisco
+    [0x00, 0x03, 0x93], // Apple
+    [0x00, 0x1c, 0xb3], // Apple
+    [0xf0, 0x18, 0x98], // Apple
+    [0x00, 0x1b, 0x21], // Intel
+    [0x00, 0x24, 0xd7], // Intel
+    [0x00, 0x14, 0x22], // Dell
+    [0x00, 0x1a, 0xa0], // Dell
+    [0x00, 0x50, 0xf2], // Microsoft
+    [0x00, 0x15, 0x5d], // Microsoft Hyper-V
+    [0x00, 0x50, 0x56], // VMware
+    [0x00, 0x0c, 0x29], // VMware
+    [0x08, 0x00, 0x27], // VirtualBox
+    [0x00, 0x16, 0x3e], // Xen
+    [0x00, 0x1c, 0x42], // Parallels
+    [0x00, 0xe0, 0x4c], // Realtek
+    [0x3c, 0x5a, 0xb4], // Google
+    [0xb8, 0x27, 0xeb], // Raspberry Pi
+    [0xdc, 0xa6, 0x32], // Raspberry Pi
+];
+
+/// Which bits of the address are random.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MacKind {
+    /// All 48 bits random, including the multicast and local bits.
+    #[default]
+    Random,
+    /// A real vendor OUI from `MAC_VENDOR_OUIS` with a random device part.
+    Vendor,
+    /// Locally administered unicast: the first octet has bit 1 set and bit 0 clear.
+    Local,
+}
+
+/// Accepted names for `MacKind`, in declaration order.
+pub const MAC_KINDS: &[&str] = &["random", "vendor", "local"];
+
+/// Error for an unknown MAC address kind name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacKindError {
+    /// The unrecognized kind name.
+    pub kind: String,
+}
+
+impl std::fmt::Display for MacKindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown MAC address kind '{}'; expected one of: {}",
+            self.kind,
+            MAC_KINDS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for MacKindError {}
+
+impl FromStr for MacKind {
+    type Err = MacKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "random" => Ok(MacKind::Random),
+            "vendor" => Ok(MacKind::Vendor),
+            "local" => Ok(MacKind::Local),
+            _ => Err(MacKindError {
+                kind: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// How the six octets are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MacSeparator {
+    /// `aa:bb:cc:dd:ee:ff` (IEEE 802, Linux, macOS).
+    #[default]
+    Colon,
+    /// `aa-bb-cc-dd-ee-ff` (Windows).
+    Dash,
+    /// `aabb.ccdd.eeff` (Cisco).
+    Dot,
+    /// `aabbccddeeff`.
+    Bare,
+}
+
+/// Accepted names for `MacSeparator`, in declaration order.
+pub const MAC_SEPARATORS: &[&str] = &["colon", "dash", "dot", "bare"];
+
+/// Error for an unknown MAC address separator name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacSeparatorError {
+    /// The unrecognized separator name.
+    pub separator: String,
+}
+
+impl std::fmt::Display for MacSeparatorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown MAC address separator '{}'; expected one of: {}",
+            self.separator,
+            MAC_SEPARATORS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for MacSeparatorError {}
+
+impl FromStr for MacSeparator {
+    type Err = MacSeparatorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "colon" => Ok(MacSeparator::Colon),
+            "dash" => Ok(MacSeparator::Dash),
+            "dot" => Ok(MacSeparator::Dot),
+            "bare" => Ok(MacSeparator::Bare),
+            _ => Err(MacSeparatorError {
+                separator: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Generate a batch of MAC addresses of `kind`, written with `separator`.
+pub fn generate_formatted_mac_addresses(
+    rng: &mut ForgeryRng,
+    n: usize,
+    kind: MacKind,
+    separator: MacSeparator,
+    uppercase: bool,
+) -> Vec<String> {
+    let mut macs = Vec::with_capacity(n);
+    for _ in 0..n {
+        macs.push(generate_formatted_mac_address(
+            rng, kind, separator, uppercase,
+        ));
+    }
+    macs
+}
+
+/// Generate a single MAC address of `kind`, written with `separator`
+/// (e.g., "00-50-56-A3-1F-9C" for a VMware address in Windows style).
+#[inline]
+pub fn generate_formatted_mac_address(
+    rng: &mut ForgeryRng,
+    kind: MacKind,
+    separator: MacSeparator,
+    uppercase: bool,
+) -> String {
     let mut bytes = [0u8; 6];
-    rng.fill_bytes(&mut bytes);
-    format!(
-        "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-        bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]
-    )
+    match kind {
+        MacKind::Random => rng.fill_bytes(&mut bytes),
+        MacKind::Vendor => {
+            let oui: &[u8; 3] = rng.choose(MAC_VENDOR_OUIS);
+            bytes[..3].copy_from_slice(oui);
+            rng.fill_bytes(&mut bytes[3..]);
+        }
+        MacKind::Local => {
+            rng.fill_bytes(&mut bytes);
+            bytes[0] = (bytes[0] | 0b10) & !0b01;
+        }
+    }
+
+    let hex: Vec<String> = bytes
+        .iter()
+        .map(|b| {
+            if uppercase {
+                format!("{:02X}", b)
+            } else {
+                format!("{:02x}", b)
+            }
+        })
+        .collect();
+    match separator {
+        MacSeparator::Colon => hex.join(":"),
+        MacSeparator::Dash => hex.join("-"),
+        MacSeparator::Dot => hex
+            .chunks(2)
+            .map(|c| c.concat())
+            .collect::<Vec<_>>()
+            .join("."),
+        MacSeparator::Bare => hex.concat(),
+    }
+}
+
+// === Ports ===
+
+/// IANA port range to draw from. Port 0 is reserved and never generated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PortKind {
+    /// System ports, 1-1023.
+    WellKnown,
+    /// User ports, 1024-49151.
+    Registered,
+    /// Dynamic/private ports, 49152-65535.
+    Ephemeral,
+    /// Any port, 1-65535.
+    #[default]
+    Any,
+}
+
+impl PortKind {
+    /// Inclusive bounds of the range.
+    pub fn range(self) -> (u16, u16) {
+        match self {
+            PortKind::WellKnown => (1, 1023),
+            PortKind::Registered => (1024, 49151),
+            PortKind::Ephemeral => (49152, 65535),
+            PortKind::Any => (1, 65535),
+        }
+    }
+}
+
+/// Accepted names for `PortKind`, in declaration order.
+pub const PORT_KINDS: &[&str] = &["well_known", "registered", "ephemeral", "any"];
+
+/// Error for an unknown port kind name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortKindError {
+    /// The unrecognized kind name.
+    pub kind: String,
+}
+
+impl std::fmt::Display for PortKindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown port kind '{}'; expected one of: {}",
+            self.kind,
+            PORT_KINDS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for PortKindError {}
+
+impl FromStr for PortKind {
+    type Err = PortKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "well_known" => Ok(PortKind::WellKnown),
+            "registered" => Ok(PortKind::Registered),
+            "ephemeral" => Ok(PortKind::Ephemeral),
+            "any" => Ok(PortKind::Any),
+            _ => Err(PortKindError {
+                kind: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Generate a batch of port numbers in the range of `kind`.
+pub fn generate_ports(rng: &mut ForgeryRng, n: usize, kind: PortKind) -> Vec<u16> {
+    let mut ports = Vec::with_capacity(n);
+    for _ in 0..n {
+        ports.push(generate_port(rng, kind));
+    }
+    ports
+}
+
+/// Generate a single port number in the range of `kind`.
+#[inline]
+pub fn generate_port(rng: &mut ForgeryRng, kind: PortKind) -> u16 {
+    let (min, max) = kind.range();
+    rng.gen_range(min, max)
+}
+
+// === HTTP ===
+
+/// HTTP methods, repeated to weight toward GET and POST as in typical API traffic.
+pub const HTTP_METHODS: &[&str] = &[
+    "GET", "GET", "GET", "GET", "GET", "GET", "GET", "GET", "GET", "GET", "GET", "GET", "POST",
+    "POST", "POST", "POST", "POST", "PUT", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS",
+];
+
+/// Informational status codes.
+const HTTP_1XX: &[u16] = &[100, 101, 103];
+
+/// Success status codes, weighted toward 200.
+const HTTP_2XX: &[u16] = &[
+    200, 200, 200, 200, 200, 200, 200, 200, 200, 200, 200, 200, 200, 200, 201, 201, 201, 202, 204,
+    204,
+];
+
+/// Redirection status codes, weighted toward 304 and 302.
+const HTTP_3XX: &[u16] = &[301, 301, 302, 302, 302, 304, 304, 304, 304, 307, 308];
+
+/// Client error status codes, weighted toward 404.
+const HTTP_4XX: &[u16] = &[
+    400, 400, 400, 401, 401, 401, 403, 403, 404, 404, 404, 404, 404, 404, 405, 409, 422, 422, 429,
+    429,
+];
+
+/// Server error status codes, weighted toward 500.
+const HTTP_5XX: &[u16] = &[500, 500, 500, 500, 500, 502, 502, 503, 503, 504];
+
+/// Status classes drawn when none is requested: mostly 2xx, then 4xx and 3xx.
+const HTTP_STATUS_CLASS_WEIGHTS: &[StatusClass] = &[
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Success,
+    StatusClass::Redirection,
+    StatusClass::Redirection,
+    StatusClass::ClientError,
+    StatusClass::ClientError,
+    StatusClass::ClientError,
+    StatusClass::ServerError,
+];
+
+/// HTTP status code class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusClass {
+    /// 1xx.
+    Informational,
+    /// 2xx.
+    Success,
+    /// 3xx.
+    Redirection,
+    /// 4xx.
+    ClientError,
+    /// 5xx.
+    ServerError,
+}
+
+impl StatusClass {
+    /// Status codes of the class, weighted by how often they occur.
+    pub fn codes(self) -> &'static [u16] {
+        match self {
+            StatusClass::Informational => HTTP_1XX,
+            StatusClass::Success => HTTP_2XX,
+            StatusClass::Redirection => HTTP_3XX,
+            StatusClass::ClientError => HTTP_4XX,
+            StatusClass::ServerError => HTTP_5XX,
+        }
+    }
+}
+
+/// Accepted names for `StatusClass`, in declaration order.
+pub const STATUS_CLASSES: &[&str] = &["1xx", "2xx", "3xx", "4xx", "5xx"];
+
+/// Error for an unknown HTTP status class name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusClassError {
+    /// The unrecognized class name.
+    pub class: String,
+}
+
+impl std::fmt::Display for StatusClassError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown HTTP status class '{}'; expected one of: {}",
+            self.class,
+            STATUS_CLASSES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for StatusClassError {}
+
+impl FromStr for StatusClass {
+    type Err = StatusClassError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "1xx" => Ok(StatusClass::Informational),
+            "2xx" => Ok(StatusClass::Success),
+            "3xx" => Ok(StatusClass::Redirection),
+            "4xx" => Ok(StatusClass::ClientError),
+            "5xx" => Ok(StatusClass::ServerError),
+            _ => Err(StatusClassError {
+                class: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Generate a batch of HTTP methods.
+pub fn generate_http_methods(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut methods = Vec::with_capacity(n);
+    for _ in 0..n {
+        methods.push(generate_http_method(rng));
+    }
+    methods
+}
+
+/// Generate a single HTTP method (e.g., "GET").
+#[inline]
+pub fn generate_http_method(rng: &mut ForgeryRng) -> String {
+    rng.choose(HTTP_METHODS).to_string()
+}
+
+/// Generate a batch of HTTP status codes.
+pub fn generate_http_status_codes(
+    rng: &mut ForgeryRng,
+    n: usize,
+    class: Option<StatusClass>,
+) -> Vec<u16> {
+    let mut codes = Vec::with_capacity(n);
+    for _ in 0..n {
+        codes.push(generate_http_status_code(rng, class));
+    }
+    codes
+}
+
+/// Generate a single HTTP status code (e.g., 200).
+///
+/// Without a class, codes are weighted toward 2xx, with 1xx never drawn.
+#[inline]
+pub fn generate_http_status_code(rng: &mut ForgeryRng, class: Option<StatusClass>) -> u16 {
+    let class = match class {
+        Some(class) => class,
+        None => *rng.choose(HTTP_STATUS_CLASS_WEIGHTS),
+    };
+    *rng.choose(class.codes())
 }
 
 #[cfg(test)]
@@ -188,6 +1301,47 @@ mod tests {
     }
 
     // IPv6 tests
+    #[test]
+    fn test_ipv6_kinds() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let parse = |ip: &String| ip.parse::<Ipv6Addr>().expect(ip).segments();
+        for ip in &generate_formatted_ipv6s(&mut rng, 200, Ipv6Kind::Global, true, false) {
+            assert_eq!(parse(ip)[0] >> 13, 0b001, "{}", ip);
+        }
+        for ip in &generate_formatted_ipv6s(&mut rng, 200, Ipv6Kind::LinkLocal, true, false) {
+            assert_eq!(parse(ip)[..4], [0xfe80, 0, 0, 0], "{}", ip);
+            assert!(ip.starts_with("fe80::"), "{}", ip);
+        }
+        for ip in &generate_formatted_ipv6s(&mut rng, 200, Ipv6Kind::Ula, false, false) {
+            assert_eq!(parse(ip)[0] >> 8, 0xfd, "{}", ip);
+        }
+    }
+
+    #[test]
+    fn test_ipv6_formatting() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let expanded = generate_formatted_ipv6(&mut rng, Ipv6Kind::LinkLocal, false, true);
+        assert!(expanded.starts_with("FE80:0000:0000:0000:"), "{}", expanded);
+        assert_eq!(expanded.len(), 39);
+        assert!(!expanded.bytes().any(|b| b.is_ascii_lowercase()));
+
+        for ip in generate_formatted_ipv6s(&mut rng, 200, Ipv6Kind::Random, true, false) {
+            let addr: Ipv6Addr = ip.parse().unwrap();
+            assert_eq!(ip, addr.to_string());
+            assert!(ip.split(':').all(|g| g.len() <= 4 && !g.starts_with("00")));
+        }
+
+        for name in IPV6_KINDS {
+            assert!(name.parse::<Ipv6Kind>().is_ok());
+        }
+        let err = "multicast".parse::<Ipv6Kind>().unwrap_err();
+        assert!(err.to_string().contains("'multicast'"));
+    }
+
     #[test]
     fn test_generate_ipv6s_count() {
         let mut rng = ForgeryRng::new();
@@ -243,6 +1397,117 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mac_separators_and_case() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let cases = [
+            (MacSeparator::Colon, 17, ':'),
+            (MacSeparator::Dash, 17, '-'),
+            (MacSeparator::Dot, 14, '.'),
+        ];
+        for (separator, len, sep) in cases {
+            for mac in
+                generate_formatted_mac_addresses(&mut rng, 50, MacKind::Random, separator, true)
+            {
+                assert_eq!(mac.len(), len, "{}", mac);
+                let hex: String = mac.chars().filter(|&c| c != sep).collect();
+                assert_eq!(hex.len(), 12, "{}", mac);
+                assert!(hex
+                    .bytes()
+                    .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase()));
+            }
+        }
+        for mac in generate_formatted_mac_addresses(
+            &mut rng,
+            50,
+            MacKind::Random,
+            MacSeparator::Bare,
+            false,
+        ) {
+            assert_eq!(mac.len(), 12, "{}", mac);
+            assert!(mac
+                .bytes()
+                .all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase()));
+        }
+        let dotted =
+            generate_formatted_mac_address(&mut rng, MacKind::Random, MacSeparator::Dot, false);
+        assert!(
+            dotted.split('.').all(|group| group.len() == 4),
+            "{}",
+            dotted
+        );
+    }
+
+    #[test]
+    fn test_mac_kinds() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for oui in MAC_VENDOR_OUIS {
+            assert_eq!(oui[0] & 0b11, 0, "{:02x?} must be universal unicast", oui);
+        }
+        let octets = |mac: &str| -> Vec<u8> {
+            mac.split(':')
+                .map(|p| u8::from_str_radix(p, 16).unwrap())
+                .collect()
+        };
+        for mac in generate_formatted_mac_addresses(
+            &mut rng,
+            200,
+            MacKind::Vendor,
+            MacSeparator::Colon,
+            false,
+        ) {
+            let bytes = octets(&mac);
+            assert!(
+                MAC_VENDOR_OUIS.iter().any(|oui| bytes[..3] == oui[..]),
+                "{}",
+                mac
+            );
+        }
+        for mac in generate_formatted_mac_addresses(
+            &mut rng,
+            200,
+            MacKind::Local,
+            MacSeparator::Colon,
+            false,
+        ) {
+            assert_eq!(octets(&mac)[0] & 0b11, 0b10, "{}", mac);
+        }
+    }
+
+    #[test]
+    fn test_mac_default_matches_formatted() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+
+        assert_eq!(
+            generate_mac_addresses(&mut rng1, 20),
+            generate_formatted_mac_addresses(
+                &mut rng2,
+                20,
+                MacKind::default(),
+                MacSeparator::default(),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn test_mac_option_parsing() {
+        assert_eq!("VENDOR".parse::<MacKind>().unwrap(), MacKind::Vendor);
+        assert_eq!("dot".parse::<MacSeparator>().unwrap(), MacSeparator::Dot);
+        assert_eq!(
+            "oui".parse::<MacKind>().unwrap_err().to_string(),
+            "unknown MAC address kind 'oui'; expected one of: random, vendor, local"
+        );
+        assert!("space".parse::<MacSeparator>().is_err());
+    }
+
     #[test]
     fn test_deterministic() {
         let mut rng1 = ForgeryRng::new();
@@ -278,6 +1543,360 @@ mod tests {
 
         assert_ne!(i1, i2, "Different seeds should produce different IPs");
     }
+
+    // Port tests
+    #[test]
+    fn test_port_kind_from_str() {
+        assert_eq!(
+            "well_known".parse::<PortKind>().unwrap(),
+            PortKind::WellKnown
+        );
+        assert_eq!(
+            "Ephemeral".parse::<PortKind>().unwrap(),
+            PortKind::Ephemeral
+        );
+        assert_eq!("any".parse::<PortKind>().unwrap(), PortKind::default());
+        let err = "private".parse::<PortKind>().unwrap_err();
+        assert!(err.to_string().contains("'private'"));
+    }
+
+    #[test]
+    fn test_ports_in_range() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for kind in [
+            PortKind::WellKnown,
+            PortKind::Registered,
+            PortKind::Ephemeral,
+            PortKind::Any,
+        ] {
+            let (min, max) = kind.range();
+            let ports = generate_ports(&mut rng, 500, kind);
+            assert_eq!(ports.len(), 500);
+            assert!(ports.iter().all(|p| (min..=max).contains(p)), "{:?}", kind);
+        }
+        assert!(!generate_ports(&mut rng, 1000, PortKind::Any).contains(&0));
+    }
+
+    #[test]
+    fn test_ports_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_ports(&mut rng1, 50, PortKind::Registered),
+            generate_ports(&mut rng2, 50, PortKind::Registered)
+        );
+    }
+
+    // IPv4 range tests
+    #[test]
+    fn test_ipv4_range_checks() {
+        assert!(is_private_ipv4(Ipv4Addr::new(10, 1, 2, 3)));
+        assert!(is_private_ipv4(Ipv4Addr::new(172, 31, 255, 254)));
+        assert!(!is_private_ipv4(Ipv4Addr::new(172, 32, 0, 1)));
+        assert!(is_private_ipv4(Ipv4Addr::new(192, 168, 1, 1)));
+        assert!(is_public_ipv4(Ipv4Addr::new(8, 8, 8, 8)));
+        assert!(!is_public_ipv4(Ipv4Addr::new(127, 0, 0, 1)));
+        assert!(!is_public_ipv4(Ipv4Addr::new(100, 127, 0, 1)));
+        assert!(!is_public_ipv4(Ipv4Addr::new(203, 0, 113, 9)));
+        assert!(!is_public_ipv4(Ipv4Addr::new(239, 1, 1, 1)));
+        assert_eq!(prefix_mask(0), 0);
+        assert_eq!(prefix_mask(12), 0xFFF0_0000);
+        assert_eq!(prefix_mask(32), u32::MAX);
+    }
+
+    #[test]
+    fn test_generate_private_ipv4s() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let ips = generate_private_ipv4s(&mut rng, 1000);
+        assert_eq!(ips.len(), 1000);
+        for ip in &ips {
+            let addr: Ipv4Addr = ip.parse().unwrap();
+            assert!(is_private_ipv4(addr), "{}", ip);
+            assert!((1..=254).contains(&addr.octets()[3]), "{}", ip);
+        }
+        for prefix in ["10.", "172.", "192.168."] {
+            assert!(ips.iter().any(|ip| ip.starts_with(prefix)), "{}", prefix);
+        }
+    }
+
+    #[test]
+    fn test_generate_public_ipv4s() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for ip in generate_public_ipv4s(&mut rng, 1000) {
+            let addr: Ipv4Addr = ip.parse().unwrap();
+            assert!(is_public_ipv4(addr), "{}", ip);
+            assert!(!is_private_ipv4(addr), "{}", ip);
+        }
+    }
+
+    #[test]
+    fn test_ipv4_ranges() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for name in IPV4_RANGES {
+            let range: Ipv4Range = name.parse().unwrap();
+            for ip in generate_ipv4s_in(&mut rng, 200, &range) {
+                let addr: Ipv4Addr = ip.parse().unwrap();
+                let ok = match *name {
+                    "private" => addr.is_private(),
+                    "public" => is_public_ipv4(addr),
+                    "loopback" => addr.is_loopback(),
+                    "link_local" => addr.is_link_local(),
+                    "shared" => in_networks(addr, IPV4_SHARED_NETWORKS),
+                    "documentation" => in_networks(addr, IPV4_DOCUMENTATION_NETWORKS),
+                    "multicast" => addr.is_multicast(),
+                    "class_a" => addr.octets()[0] < 128,
+                    "class_b" => (128..192).contains(&addr.octets()[0]),
+                    _ => (192..224).contains(&addr.octets()[0]),
+                };
+                assert!(ok, "{} not in {}", ip, name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ipv4_cidr_range() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let range: Ipv4Range = "192.168.1.77/24".parse().unwrap();
+        assert_eq!(
+            range,
+            Ipv4Range::Networks(vec![(Ipv4Addr::new(192, 168, 1, 0), 24)])
+        );
+        for ip in generate_ipv4s_in(&mut rng, 200, &range) {
+            assert!(ip.starts_with("192.168.1."), "{}", ip);
+        }
+
+        let small: Ipv4Range = "10.0.0.8/30".parse().unwrap();
+        let ips = generate_ipv4s_in(&mut rng, 200, &small);
+        assert!(ips.iter().all(|ip| ip == "10.0.0.9" || ip == "10.0.0.10"));
+        assert!(ips.iter().any(|ip| ip == "10.0.0.9"));
+        let host: Ipv4Range = "10.0.0.5/32".parse().unwrap();
+        assert_eq!(generate_ipv4_in(&mut rng, &host), "10.0.0.5");
+
+        for bad in ["10.0.0.0/33", "10.0.0/8", "intranet", "10.0.0.0"] {
+            let err = bad.parse::<Ipv4Range>().unwrap_err();
+            assert!(err.to_string().contains(&format!("'{}'", bad)));
+        }
+    }
+
+    #[test]
+    fn test_generate_cidrs() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let cidrs = generate_cidrs(&mut rng, 500, 8, 30).unwrap();
+        assert_eq!(cidrs.len(), 500);
+        for cidr in &cidrs {
+            let (network, prefix) = cidr.split_once('/').unwrap();
+            let network: Ipv4Addr = network.parse().unwrap();
+            let prefix: u8 = prefix.parse().unwrap();
+            assert!((8..=30).contains(&prefix), "{}", cidr);
+            assert_eq!(u32::from(network) & !prefix_mask(prefix), 0, "{}", cidr);
+        }
+        assert_eq!(
+            generate_cidr(&mut rng, 32, 32)
+                .unwrap()
+                .split_once('/')
+                .unwrap()
+                .1,
+            "32"
+        );
+        assert_eq!(generate_cidr(&mut rng, 0, 0).unwrap(), "0.0.0.0/0");
+    }
+
+    #[test]
+    fn test_cidr_invalid_prefix_range() {
+        let mut rng = ForgeryRng::new();
+        assert_eq!(
+            generate_cidr(&mut rng, 24, 16).unwrap_err(),
+            CidrPrefixError { min: 24, max: 16 }
+        );
+        assert!(generate_cidrs(&mut rng, 5, 8, 33).is_err());
+        assert!(generate_cidrs(&mut rng, 5, 8, 33)
+            .unwrap_err()
+            .to_string()
+            .contains("(8, 33)"));
+    }
+
+    // HTTP tests
+    #[test]
+    fn test_status_class_from_str() {
+        assert_eq!(
+            "4xx".parse::<StatusClass>().unwrap(),
+            StatusClass::ClientError
+        );
+        assert_eq!("2XX".parse::<StatusClass>().unwrap(), StatusClass::Success);
+        let err = "6xx".parse::<StatusClass>().unwrap_err();
+        assert!(err.to_string().contains("'6xx'"));
+    }
+
+    #[test]
+    fn test_status_class_codes() {
+        for (i, class) in [
+            StatusClass::Informational,
+            StatusClass::Success,
+            StatusClass::Redirection,
+            StatusClass::ClientError,
+            StatusClass::ServerError,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let hundreds = (i as u16 + 1) * 100;
+            assert!(class
+                .codes()
+                .iter()
+                .all(|c| (hundreds..hundreds + 100).contains(c)));
+        }
+    }
+
+    #[test]
+    fn test_http_methods_weighted() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let methods = generate_http_methods(&mut rng, 1000);
+        assert_eq!(methods.len(), 1000);
+        assert!(methods.iter().all(|m| HTTP_METHODS.contains(&m.as_str())));
+        let gets = methods.iter().filter(|m| *m == "GET").count();
+        let posts = methods.iter().filter(|m| *m == "POST").count();
+        assert!(gets > posts && posts > 100, "GET {} POST {}", gets, posts);
+    }
+
+    #[test]
+    fn test_http_status_codes_weighted() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let codes = generate_http_status_codes(&mut rng, 2000, None);
+        assert!(codes.iter().all(|c| (200..600).contains(c)));
+        let ok = codes.iter().filter(|&&c| c == 200).count();
+        assert!(ok > 800, "{} of 2000 are 200", ok);
+        assert!(codes.iter().any(|&c| c >= 500));
+
+        let client = generate_http_status_codes(&mut rng, 500, Some(StatusClass::ClientError));
+        assert!(client.iter().all(|c| (400..500).contains(c)));
+        assert!(client.contains(&404));
+    }
+
+    #[test]
+    fn test_http_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_http_methods(&mut rng1, 50),
+            generate_http_methods(&mut rng2, 50)
+        );
+        assert_eq!(
+            generate_http_status_codes(&mut rng1, 50, None),
+            generate_http_status_codes(&mut rng2, 50, None)
+        );
+    }
+
+    // URL option tests
+    #[test]
+    fn test_url_default_options_match_generate_url() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+
+        assert_eq!(
+            generate_urls(&mut rng1, 50),
+            generate_urls_with_options(&mut rng2, 50, &UrlOptions::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_url_options_shape() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let options = UrlOptions {
+            scheme: "http",
+            path_depth: Some(3),
+            query_params: 2,
+            fragment: true,
+            extension: Some(".html"),
+        };
+        let urls = generate_urls_with_options(&mut rng, 200, &options).unwrap();
+        for url in &urls {
+            let rest = url.strip_prefix("http://").expect(url);
+            let (before_fragment, fragment) = rest.split_once('#').expect(url);
+            assert!(URL_FRAGMENTS.contains(&fragment), "{}", url);
+            let (path, query) = before_fragment.split_once('?').expect(url);
+            assert_eq!(path.matches('/').count(), 3, "{}", url);
+            assert!(path.ends_with(".html"), "{}", url);
+            assert_eq!(query.split('&').count(), 2, "{}", url);
+            assert!(query.split('&').all(|p| p.contains('=')), "{}", url);
+        }
+        assert!(urls.iter().any(|u| u.contains('%')));
+        assert!(urls
+            .iter()
+            .any(|u| u.split('/').any(|s| s.bytes().all(|b| b.is_ascii_digit()))));
+    }
+
+    #[test]
+    fn test_url_extension_without_path() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let options = UrlOptions {
+            path_depth: Some(0),
+            extension: Some("pdf"),
+            ..UrlOptions::default()
+        };
+        for url in generate_urls_with_options(&mut rng, 20, &options).unwrap() {
+            assert!(url.ends_with("/index.pdf"), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_url_options_invalid() {
+        let mut rng = ForgeryRng::new();
+        for scheme in ["", "1http", "ht tp", "http:"] {
+            let options = UrlOptions {
+                scheme,
+                ..UrlOptions::default()
+            };
+            assert_eq!(
+                generate_url_with_options(&mut rng, &options).unwrap_err(),
+                UrlOptionsError::InvalidScheme(scheme.to_string())
+            );
+        }
+        assert!(generate_url_with_options(
+            &mut rng,
+            &UrlOptions {
+                scheme: "svn+ssh",
+                ..UrlOptions::default()
+            }
+        )
+        .unwrap()
+        .starts_with("svn+ssh://"));
+        for extension in ["", ".", "ht/ml", "tar.gz"] {
+            let options = UrlOptions {
+                extension: Some(extension),
+                ..UrlOptions::default()
+            };
+            let err = generate_urls_with_options(&mut rng, 1, &options).unwrap_err();
+            assert!(err.to_string().contains("extension"), "{}", extension);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -343,5 +1962,104 @@ mod proptest_tests {
 
             prop_assert_eq!(i1, i2);
         }
+
+        /// Property: ports stay within the range of their kind
+        #[test]
+        fn prop_ports_in_range(seed_val in any::<u64>(), n in 0usize..200) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed_val);
+
+            for kind in [PortKind::WellKnown, PortKind::Registered, PortKind::Ephemeral] {
+                let (min, max) = kind.range();
+                let ports = generate_ports(&mut rng, n, kind);
+                prop_assert_eq!(ports.len(), n);
+                prop_assert!(ports.iter().all(|p| (min..=max).contains(p)));
+            }
+        }
+
+        /// Property: private addresses stay in RFC 1918 networks
+        #[test]
+        fn prop_private_ipv4(seed_val in any::<u64>(), n in 0usize..200) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed_val);
+
+            for ip in generate_private_ipv4s(&mut rng, n) {
+                prop_assert!(is_private_ipv4(ip.parse().unwrap()), "{}", ip);
+            }
+        }
+
+        /// Property: MAC addresses always carry 12 hex digits, whatever the format
+        #[test]
+        fn prop_mac_formatted(
+            seed_val in any::<u64>(),
+            kind in 0usize..3,
+            separator in 0usize..4,
+            uppercase in any::<bool>(),
+        ) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed_val);
+            let kind: MacKind = MAC_KINDS[kind].parse().unwrap();
+            let separator: MacSeparator = MAC_SEPARATORS[separator].parse().unwrap();
+
+            for mac in generate_formatted_mac_addresses(&mut rng, 20, kind, separator, uppercase) {
+                let hex: String = mac.chars().filter(char::is_ascii_hexdigit).collect();
+                prop_assert_eq!(hex.len(), 12);
+                let wrong_case = if uppercase {
+                    hex.chars().any(|c| c.is_ascii_lowercase())
+                } else {
+                    hex.chars().any(|c| c.is_ascii_uppercase())
+                };
+                prop_assert!(!wrong_case, "{}", mac);
+                let first = u8::from_str_radix(&hex[..2], 16).unwrap();
+                if kind == MacKind::Local {
+                    prop_assert_eq!(first & 0b11, 0b10);
+                }
+            }
+        }
+
+        /// Property: CIDR prefixes stay in range with host bits cleared
+        #[test]
+        fn prop_cidr_valid(seed_val in any::<u64>(), min in 0u8..=32, span in 0u8..=32) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed_val);
+            let max = min.saturating_add(span).min(32);
+
+            for cidr in generate_cidrs(&mut rng, 20, min, max).unwrap() {
+                let (network, prefix) = cidr.split_once('/').unwrap();
+                let network: Ipv4Addr = network.parse().unwrap();
+                let prefix: u8 = prefix.parse().unwrap();
+                prop_assert!((min..=max).contains(&prefix));
+                prop_assert_eq!(u32::from(network) & !prefix_mask(prefix), 0);
+            }
+        }
+
+        /// Property: URLs have the requested number of path segments and query parameters
+        #[test]
+        fn prop_url_options_shape(
+            seed_val in any::<u64>(),
+            depth in 0usize..6,
+            query_params in 0usize..6,
+            fragment in any::<bool>(),
+        ) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed_val);
+            let options = UrlOptions {
+                scheme: "ftp",
+                path_depth: Some(depth),
+                query_params,
+                fragment,
+                extension: None,
+            };
+
+            for url in generate_urls_with_options(&mut rng, 20, &options).unwrap() {
+                let rest = url.strip_prefix("ftp://").unwrap();
+                prop_assert_eq!(rest.contains('#'), fragment);
+                let rest = rest.split('#').next().unwrap();
+                let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+                prop_assert_eq!(path.matches('/').count(), depth);
+                let params = if query.is_empty() { 0 } else { query.split('&').count() };
+                prop_assert_eq!(params, query_params);
+            }
+        }
     }
 }
@@ -182,6 +182,13 @@ pub fn generate_float(rng: &mut ForgeryRng, min: f64, max: f64) -> Result<f64, F
     Ok(rng.gen_range(min, max))
 }
 
+/// Draw from a standard normal distribution (Box-Muller).
+pub(crate) fn standard_normal(rng: &mut ForgeryRng) -> f64 {
+    let u1: f64 = rng.gen_range(f64::EPSILON, 1.0);
+    let u2: f64 = rng.gen_range(0.0, 1.0);
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
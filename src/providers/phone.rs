@@ -1,10 +1,156 @@
 //! Phone number generation provider.
 //!
-//! Generates locale-specific phone numbers.
+//! Generates locale-specific phone numbers, either in the locale's written
+//! patterns or normalized to E.164 (e.g., "+14155550123"), optionally
+//! restricted to mobile or landline ranges or to the ranges regulators
+//! reserve for fiction.
 
-use crate::data::get_locale_data;
+use crate::data::{get_locale_data, PhoneFormat};
 use crate::locale::Locale;
 use crate::rng::ForgeryRng;
+use std::str::FromStr;
+
+/// Output format for generated phone numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PhoneNumberFormat {
+    /// The locale's written patterns, e.g. "(415) 555-0123" or "+44 20 7946 0958".
+    #[default]
+    Formatted,
+    /// E.164: "+", country code and national number with no separators.
+    E164,
+}
+
+/// Accepted names for `PhoneNumberFormat`, in declaration order.
+pub const PHONE_NUMBER_FORMATS: &[&str] = &["formatted", "e164"];
+
+/// Error for an unknown phone number format name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumberFormatError {
+    /// The unrecognized format name.
+    pub format: String,
+}
+
+impl std::fmt::Display for PhoneNumberFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown phone number format '{}'; expected one of: {}",
+            self.format,
+            PHONE_NUMBER_FORMATS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for PhoneNumberFormatError {}
+
+impl FromStr for PhoneNumberFormat {
+    type Err = PhoneNumberFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "formatted" => Ok(PhoneNumberFormat::Formatted),
+            "e164" => Ok(PhoneNumberFormat::E164),
+            _ => Err(PhoneNumberFormatError {
+                format: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Number range a generated phone number is drawn from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PhoneKind {
+    /// Any of the locale's patterns.
+    #[default]
+    Any,
+    /// Mobile ranges (e.g., 07 in the UK, 06/07 in France, 015-017 in Germany).
+    Mobile,
+    /// Geographic landline ranges.
+    Landline,
+}
+
+/// Accepted names for `PhoneKind`, in declaration order.
+pub const PHONE_KINDS: &[&str] = &["any", "mobile", "landline"];
+
+/// Error for an unknown phone kind name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneKindError {
+    /// The unrecognized kind name.
+    pub kind: String,
+}
+
+impl std::fmt::Display for PhoneKindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown phone kind '{}'; expected one of: {}",
+            self.kind,
+            PHONE_KINDS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for PhoneKindError {}
+
+impl FromStr for PhoneKind {
+    type Err = PhoneKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "any" => Ok(PhoneKind::Any),
+            "mobile" => Ok(PhoneKind::Mobile),
+            "landline" => Ok(PhoneKind::Landline),
+            _ => Err(PhoneKindError {
+                kind: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Output format and number range for generated phone numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhoneOptions {
+    /// Written pattern or E.164.
+    pub format: PhoneNumberFormat,
+    /// Mobile, landline or either.
+    pub kind: PhoneKind,
+    /// Restrict output to ranges reserved for fiction (US 555-01xx, UK
+    /// 07700 900xxx, ...), which are never assigned to subscribers.
+    pub safe: bool,
+}
+
+/// Error when a locale has no reserved fictional range for the requested kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FictionalPhoneError {
+    /// The locale code, e.g. "es_ES".
+    pub locale: String,
+    /// The requested kind name.
+    pub kind: String,
+}
+
+impl std::fmt::Display for FictionalPhoneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let supported: Vec<&str> = Locale::ALL
+            .iter()
+            .filter(|locale| {
+                get_locale_data(**locale)
+                    .phone_format()
+                    .is_some_and(|format| !format.fictional_landline_patterns.is_empty())
+            })
+            .map(Locale::as_str)
+            .collect();
+        write!(
+            f,
+            "no reserved fictional {} phone range for locale '{}'; safe phone numbers \
+             are available for: {}",
+            self.kind,
+            self.locale,
+            supported.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for FictionalPhoneError {}
 
 /// Generate a batch of random phone numbers.
 pub fn generate_phone_numbers(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
@@ -29,6 +175,160 @@ pub fn generate_phone_number(rng: &mut ForgeryRng, locale: Locale) -> String {
     generate_us_phone_number(rng)
 }
 
+/// Generate a phone number in the locale's patterns with a fixed area code.
+///
+/// The first `###` of the chosen pattern is the area code, which holds for
+/// the NANP locales that provide `region_area_codes()`.
+pub(crate) fn generate_phone_number_with_area_code(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    area_code: &str,
+) -> String {
+    let data = get_locale_data(locale);
+    match data.phone_format() {
+        Some(format) if !format.patterns.is_empty() => {
+            let pattern = rng.choose(format.patterns).replacen("###", area_code, 1);
+            expand_pattern(rng, &pattern)
+        }
+        _ => generate_phone_number(rng, locale),
+    }
+}
+
+/// Fictional patterns for `kind`; `Any` is empty only if both kinds are.
+fn fictional_patterns(format: &PhoneFormat, kind: PhoneKind) -> &'static [&'static str] {
+    match kind {
+        PhoneKind::Mobile => format.fictional_mobile_patterns,
+        PhoneKind::Landline => format.fictional_landline_patterns,
+        PhoneKind::Any if format.fictional_mobile_patterns.is_empty() => {
+            format.fictional_landline_patterns
+        }
+        PhoneKind::Any => format.fictional_mobile_patterns,
+    }
+}
+
+/// Check that `options` can be satisfied for `locale`.
+///
+/// # Errors
+///
+/// Returns `FictionalPhoneError` if `safe` is set and the locale has no
+/// reserved fictional range for the requested kind.
+pub fn validate_phone_options(
+    locale: Locale,
+    options: &PhoneOptions,
+) -> Result<(), FictionalPhoneError> {
+    let supported = get_locale_data(locale)
+        .phone_format()
+        .is_some_and(|format| !fictional_patterns(&format, options.kind).is_empty());
+    if options.safe && !supported {
+        return Err(FictionalPhoneError {
+            locale: locale.as_str().to_string(),
+            kind: PHONE_KINDS[options.kind as usize].to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Generate a batch of phone numbers with the given format, kind and safety.
+///
+/// # Errors
+///
+/// Returns `FictionalPhoneError` if `safe` is set and the locale has no
+/// reserved fictional range for the requested kind.
+pub fn generate_phone_numbers_with_options(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    options: &PhoneOptions,
+) -> Result<Vec<String>, FictionalPhoneError> {
+    validate_phone_options(locale, options)?;
+    let mut numbers = Vec::with_capacity(n);
+    for _ in 0..n {
+        numbers.push(phone_with_options(rng, locale, options));
+    }
+    Ok(numbers)
+}
+
+/// Generate a single phone number with the given format, kind and safety.
+///
+/// The default options match `generate_phone_number` for the same seed;
+/// `E164` normalizes the number with the locale's country code. Locales
+/// without separate mobile ranges (such as the NANP ones) ignore `kind`.
+///
+/// # Errors
+///
+/// Returns `FictionalPhoneError` if `safe` is set and the locale has no
+/// reserved fictional range for the requested kind.
+#[inline]
+pub fn generate_phone_number_with_options(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    options: &PhoneOptions,
+) -> Result<String, FictionalPhoneError> {
+    validate_phone_options(locale, options)?;
+    Ok(phone_with_options(rng, locale, options))
+}
+
+/// Build one number for options already checked by `validate_phone_options`.
+pub(crate) fn phone_with_options(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    options: &PhoneOptions,
+) -> String {
+    let format = get_locale_data(locale).phone_format();
+    let patterns = match (format, options.kind) {
+        (Some(format), kind) if options.safe => {
+            // Mix both fictional kinds when either is acceptable
+            let kind = match kind {
+                PhoneKind::Any if rng.gen_range(0u8, 1) == 1 => PhoneKind::Landline,
+                kind => kind,
+            };
+            let patterns = fictional_patterns(&format, kind);
+            if patterns.is_empty() {
+                fictional_patterns(&format, PhoneKind::Any)
+            } else {
+                patterns
+            }
+        }
+        (Some(format), PhoneKind::Mobile) => format.mobile_patterns,
+        (Some(format), PhoneKind::Landline) => format.landline_patterns,
+        _ => &[],
+    };
+    let number = if patterns.is_empty() {
+        generate_phone_number(rng, locale)
+    } else {
+        let pattern = *rng.choose(patterns);
+        expand_pattern(rng, pattern)
+    };
+    match options.format {
+        PhoneNumberFormat::Formatted => number,
+        PhoneNumberFormat::E164 => to_e164(&number, locale),
+    }
+}
+
+/// Normalize a written number to E.164.
+///
+/// International patterns already carry the country code; national ones
+/// drop the locale's trunk prefix before it is added.
+fn to_e164(number: &str, locale: Locale) -> String {
+    let (country_code, trunk_prefix) = match get_locale_data(locale).phone_format() {
+        Some(format) => (&format.country_code[1..], format.trunk_prefix),
+        None => ("1", ""),
+    };
+    let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+    let national = if number.starts_with('+') {
+        digits.strip_prefix(country_code)
+    } else {
+        digits.strip_prefix(trunk_prefix)
+    }
+    .unwrap_or(&digits);
+
+    let mut e164 = String::with_capacity(1 + country_code.len() + national.len());
+    e164.push('+');
+    e164.push_str(country_code);
+    e164.push_str(national);
+    e164
+}
+
 /// Generate a US-format phone number.
 fn generate_us_phone_number(rng: &mut ForgeryRng) -> String {
     // Area code: first digit 2-9, next two digits 0-9
@@ -178,6 +478,11 @@ mod tests {
             Locale::EsES,
             Locale::ItIT,
             Locale::JaJP,
+            Locale::KoKR,
+            Locale::RuRU,
+            Locale::NlNL,
+            Locale::PlPL,
+            Locale::EnCA,
         ] {
             let number = generate_phone_number(&mut rng, locale);
             assert!(
@@ -187,6 +492,256 @@ mod tests {
             );
         }
     }
+
+    const E164: PhoneOptions = PhoneOptions {
+        format: PhoneNumberFormat::E164,
+        kind: PhoneKind::Any,
+        safe: false,
+    };
+
+    #[test]
+    fn test_e164_uses_locale_country_code() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for &locale in Locale::ALL {
+            let code = get_locale_data(locale).phone_format().unwrap().country_code;
+            for number in generate_phone_numbers_with_options(&mut rng, locale, 100, &E164).unwrap()
+            {
+                assert!(number.starts_with(code), "{:?}: {}", locale, number);
+                assert!(
+                    number[1..].bytes().all(|b| b.is_ascii_digit()),
+                    "{}",
+                    number
+                );
+                // E.164 allows at most 15 digits
+                assert!((9..=16).contains(&number.len()), "{:?}: {}", locale, number);
+            }
+        }
+    }
+
+    #[test]
+    fn test_e164_drops_trunk_prefix() {
+        assert_eq!(to_e164("030 1234567", Locale::DeDE), "+49301234567");
+        assert_eq!(to_e164("+49 30 1234567", Locale::DeDE), "+49301234567");
+        assert_eq!(to_e164("8 912 345-67-89", Locale::RuRU), "+79123456789");
+        assert_eq!(to_e164("(415) 555-0123", Locale::EnUS), "+14155550123");
+        assert_eq!(to_e164("+1 (415) 555-0123", Locale::EnUS), "+14155550123");
+        // Italian numbers keep their leading zero after the country code
+        assert_eq!(to_e164("06 1234 5678", Locale::ItIT), "+390612345678");
+    }
+
+    #[test]
+    fn test_formatted_matches_default_generator() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+
+        assert_eq!(
+            generate_phone_numbers(&mut rng1, Locale::FrFR, 50),
+            generate_phone_numbers_with_options(
+                &mut rng2,
+                Locale::FrFR,
+                50,
+                &PhoneOptions::default()
+            )
+            .unwrap()
+        );
+    }
+
+    fn e164_numbers(locale: Locale, kind: PhoneKind) -> Vec<String> {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let options = PhoneOptions {
+            format: PhoneNumberFormat::E164,
+            kind,
+            safe: false,
+        };
+        generate_phone_numbers_with_options(&mut rng, locale, 200, &options).unwrap()
+    }
+
+    #[test]
+    fn test_mobile_prefixes() {
+        for (locale, prefixes) in [
+            (Locale::EnGB, &["+447"][..]),
+            (Locale::FrFR, &["+336", "+337"][..]),
+            (Locale::DeDE, &["+4915", "+4916", "+4917"][..]),
+            (Locale::EsES, &["+346", "+347"][..]),
+            (Locale::ItIT, &["+393"][..]),
+            (Locale::JaJP, &["+8170", "+8180", "+8190"][..]),
+            (Locale::KoKR, &["+8210"][..]),
+            (Locale::NlNL, &["+316"][..]),
+            (Locale::RuRU, &["+79"][..]),
+        ] {
+            for number in e164_numbers(locale, PhoneKind::Mobile) {
+                assert!(
+                    prefixes.iter().any(|p| number.starts_with(p)),
+                    "{:?}: {}",
+                    locale,
+                    number
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_landline_avoids_mobile_ranges() {
+        for (locale, mobile) in [
+            (Locale::EnGB, "+447"),
+            (Locale::FrFR, "+336"),
+            (Locale::DeDE, "+491"),
+            (Locale::NlNL, "+316"),
+            (Locale::KoKR, "+8210"),
+            (Locale::RuRU, "+79"),
+        ] {
+            for number in e164_numbers(locale, PhoneKind::Landline) {
+                assert!(!number.starts_with(mobile), "{:?}: {}", locale, number);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kind_ignored_without_separate_ranges() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(3);
+        rng2.seed(3);
+        let mobile = PhoneOptions {
+            kind: PhoneKind::Mobile,
+            ..PhoneOptions::default()
+        };
+
+        assert_eq!(
+            generate_phone_numbers(&mut rng1, Locale::EnUS, 20),
+            generate_phone_numbers_with_options(&mut rng2, Locale::EnUS, 20, &mobile).unwrap()
+        );
+    }
+
+    fn safe_numbers(locale: Locale, kind: PhoneKind) -> Vec<String> {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let options = PhoneOptions {
+            format: PhoneNumberFormat::E164,
+            kind,
+            safe: true,
+        };
+        generate_phone_numbers_with_options(&mut rng, locale, 200, &options).unwrap()
+    }
+
+    #[test]
+    fn test_safe_nanp_uses_555_01() {
+        for locale in [Locale::EnUS, Locale::EnCA] {
+            for kind in [PhoneKind::Any, PhoneKind::Mobile, PhoneKind::Landline] {
+                for number in safe_numbers(locale, kind) {
+                    assert_eq!(number.len(), 12, "{}", number);
+                    assert!(number.starts_with("+1"), "{}", number);
+                    assert_eq!(&number[5..10], "55501", "{}", number);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_safe_uk_ranges() {
+        for number in safe_numbers(Locale::EnGB, PhoneKind::Mobile) {
+            assert!(number.starts_with("+447700900"), "{}", number);
+        }
+        for number in safe_numbers(Locale::EnGB, PhoneKind::Landline) {
+            assert!(
+                ["+442079460", "+441134960", "+441614960"]
+                    .iter()
+                    .any(|p| number.starts_with(p)),
+                "{}",
+                number
+            );
+        }
+        let any = safe_numbers(Locale::EnGB, PhoneKind::Any);
+        assert!(any.iter().any(|n| n.starts_with("+447700900")));
+        assert!(any.iter().any(|n| !n.starts_with("+447700900")));
+    }
+
+    #[test]
+    fn test_safe_de_fr_ranges() {
+        for number in safe_numbers(Locale::DeDE, PhoneKind::Landline) {
+            assert!(
+                [
+                    "+493023125",
+                    "+494066969",
+                    "+496990009",
+                    "+498999998",
+                    "+492214710"
+                ]
+                .iter()
+                .any(|p| number.starts_with(p)),
+                "{}",
+                number
+            );
+        }
+        for number in safe_numbers(Locale::FrFR, PhoneKind::Mobile) {
+            assert!(number.starts_with("+3363998"), "{}", number);
+        }
+        for number in safe_numbers(Locale::FrFR, PhoneKind::Landline) {
+            assert!(
+                ["+3319900", "+3326191", "+3335301", "+3346571", "+3353649"]
+                    .iter()
+                    .any(|p| number.starts_with(p)),
+                "{}",
+                number
+            );
+        }
+    }
+
+    #[test]
+    fn test_safe_unsupported_locale() {
+        let mut rng = ForgeryRng::new();
+        let options = PhoneOptions {
+            safe: true,
+            ..PhoneOptions::default()
+        };
+        let err = generate_phone_number_with_options(&mut rng, Locale::EsES, &options).unwrap_err();
+        assert_eq!(err.locale, "es_ES");
+        let message = err.to_string();
+        assert!(message.starts_with("no reserved fictional any phone range for locale 'es_ES'"));
+        assert!(
+            message.contains("en_US") && message.contains("en_GB"),
+            "{}",
+            message
+        );
+
+        // Without `safe` every locale still generates
+        assert!(generate_phone_numbers_with_options(
+            &mut rng,
+            Locale::EsES,
+            5,
+            &PhoneOptions::default()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_phone_kind_parsing() {
+        assert_eq!("Mobile".parse::<PhoneKind>().unwrap(), PhoneKind::Mobile);
+        assert_eq!(
+            "voip".parse::<PhoneKind>().unwrap_err().to_string(),
+            "unknown phone kind 'voip'; expected one of: any, mobile, landline"
+        );
+    }
+
+    #[test]
+    fn test_phone_number_format_parsing() {
+        assert_eq!(
+            "E164".parse::<PhoneNumberFormat>().unwrap(),
+            PhoneNumberFormat::E164
+        );
+        assert_eq!(
+            "rfc3966"
+                .parse::<PhoneNumberFormat>()
+                .unwrap_err()
+                .to_string(),
+            "unknown phone number format 'rfc3966'; expected one of: formatted, e164"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -217,5 +772,26 @@ mod proptest_tests {
 
             prop_assert_eq!(n1, n2);
         }
+
+        #[test]
+        fn prop_e164_digits_only(
+            seed_val in any::<u64>(),
+            locale_idx in 0usize..12,
+            kind_idx in 0usize..3,
+        ) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed_val);
+            let locale = Locale::ALL[locale_idx % Locale::ALL.len()];
+
+            let options = PhoneOptions {
+                format: PhoneNumberFormat::E164,
+                kind: PHONE_KINDS[kind_idx].parse().unwrap(),
+                safe: seed_val % 2 == 0 && locale == Locale::EnUS,
+            };
+
+            let number = generate_phone_number_with_options(&mut rng, locale, &options).unwrap();
+            prop_assert!(number.starts_with('+'));
+            prop_assert!(number[1..].bytes().all(|b| b.is_ascii_digit()));
+        }
     }
 }
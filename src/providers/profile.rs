@@ -0,0 +1,315 @@
+//! Person profile generation provider.
+//!
+//! Generates complete person records in one pass so that every field agrees
+//! with the others: the gender matches the first name (and the surname form
+//! in locales that inflect it), the age matches the date of birth, the email
+//! is built from the same name, in en_US/en_CA the phone area code belongs
+//! to the state or province of the address, and in en_US the ZIP code does
+//! too.
+//!
+//! | Field | Example |
+//! |-------|---------|
+//! | Name | `Jennifer Walker` |
+//! | Gender | `female` |
+//! | Date of birth / age | `1987-06-14` / `36` |
+//! | Email | `jennifer.walker@gmail.com` |
+//! | Phone | `(512) 555-0178` |
+//! | Address | `4821 Oak Avenue, Austin, TX 73301` |
+//!
+//! Ages are calculated relative to the fixed reference date 2024-01-01 so
+//! output is reproducible with a seed.
+
+use crate::data::get_locale_data;
+use crate::data::overrides::LocaleOverrides;
+use crate::locale::Locale;
+use crate::providers::datetime::{self, DateRangeError};
+use crate::providers::{address, internet, phone};
+use crate::rng::ForgeryRng;
+
+/// A generated person with internally consistent fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    /// Full name in the locale's name order
+    pub name: String,
+    /// Given name
+    pub first_name: String,
+    /// Family name
+    pub last_name: String,
+    /// "male" or "female", matching the first name
+    pub gender: String,
+    /// Date of birth (YYYY-MM-DD)
+    pub date_of_birth: String,
+    /// Age in whole years on the reference date
+    pub age: u32,
+    /// Personal email address derived from the name
+    pub email: String,
+    /// Phone number; NANP area codes match the state
+    pub phone: String,
+    /// Street address (e.g., "123 Main Street")
+    pub street_address: String,
+    /// City
+    pub city: String,
+    /// State, province or other region
+    pub state: String,
+    /// Postal code; US ZIP prefixes match the state
+    pub postal_code: String,
+    /// Full address in the locale's format
+    pub address: String,
+}
+
+/// Default minimum profile age.
+pub const DEFAULT_MIN_AGE: u32 = 18;
+
+/// Default maximum profile age.
+pub const DEFAULT_MAX_AGE: u32 = 80;
+
+/// Generate a batch of person profiles.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if min_age > max_age.
+pub fn generate_profiles(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n: usize,
+    min_age: u32,
+    max_age: u32,
+) -> Result<Vec<Profile>, DateRangeError> {
+    let mut profiles = Vec::with_capacity(n);
+    for _ in 0..n {
+        profiles.push(generate_profile(rng, locale, overrides, min_age, max_age)?);
+    }
+    Ok(profiles)
+}
+
+/// Generate a single person profile.
+///
+/// Street and city overrides are honored; name overrides are not, since
+/// they carry no gender.
+///
+/// # Errors
+///
+/// Returns `DateRangeError` if min_age > max_age.
+pub fn generate_profile(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    min_age: u32,
+    max_age: u32,
+) -> Result<Profile, DateRangeError> {
+    let data = get_locale_data(locale);
+
+    let first_names = data.first_names().unwrap_or(&[]);
+    let male_count = data
+        .male_first_name_count()
+        .unwrap_or(first_names.len() / 2);
+    let female = rng.gen_range(0u8, 1) == 1;
+    let first_index = if female {
+        rng.gen_range(male_count, first_names.len() - 1)
+    } else {
+        rng.gen_range(0, male_count - 1)
+    };
+
+    let last_names = data.last_names().unwrap_or(&[]);
+    let last_index = match data.masculine_last_name_count() {
+        Some(masculine) if female => rng.gen_range(masculine, last_names.len() - 1),
+        Some(masculine) => rng.gen_range(0, masculine - 1),
+        None => rng.gen_range(0, last_names.len() - 1),
+    };
+
+    let first_name = first_names[first_index];
+    let last_name = last_names[last_index];
+    let name = if locale.family_name_first() {
+        format!("{} {}", last_name, first_name)
+    } else {
+        format!("{} {}", first_name, last_name)
+    };
+
+    let (dob, age) = datetime::generate_birth_date_and_age(rng, min_age, max_age)?;
+
+    let email = personal_email(
+        rng,
+        locale,
+        data.romanized_first_names()
+            .and_then(|names| names.get(first_index))
+            .unwrap_or(&first_name),
+        data.romanized_last_names()
+            .and_then(|names| names.get(last_index))
+            .unwrap_or(&last_name),
+    );
+
+    let regions = data.regions().unwrap_or(&[]);
+    let region_index = rng.gen_range(0, regions.len().saturating_sub(1));
+    let state = regions.get(region_index).copied().unwrap_or_default();
+    let state_abbr = data
+        .region_abbrs()
+        .and_then(|abbrs| abbrs.get(region_index))
+        .copied()
+        .unwrap_or(state);
+
+    let phone = match data
+        .region_area_codes()
+        .and_then(|codes| codes.get(region_index))
+    {
+        Some(codes) => {
+            let area_code = *rng.choose(codes);
+            phone::generate_phone_number_with_area_code(rng, locale, area_code)
+        }
+        None => phone::generate_phone_number(rng, locale),
+    };
+
+    let street_address = address::generate_street_address_with_overrides(rng, locale, overrides);
+    let city = address::generate_city_with_overrides(rng, locale, overrides);
+    let postal_code = address::generate_zip_code_in_region(rng, locale, region_index);
+    let template = data
+        .address_format()
+        .map(|f| f.template)
+        .unwrap_or("{street}, {city}, {region_abbr} {postal}");
+    let full_address = template
+        .replace("{street}", &street_address)
+        .replace("{city}", &city)
+        .replace("{region}", state)
+        .replace("{region_abbr}", state_abbr)
+        .replace("{postal}", &postal_code);
+
+    Ok(Profile {
+        name,
+        first_name: first_name.to_string(),
+        last_name: last_name.to_string(),
+        gender: if female { "female" } else { "male" }.to_string(),
+        date_of_birth: dob.format("%Y-%m-%d").to_string(),
+        age,
+        email,
+        phone,
+        street_address,
+        city,
+        state: state.to_string(),
+        postal_code,
+        address: full_address,
+    })
+}
+
+/// Build a personal email address from romanized name parts.
+fn personal_email(rng: &mut ForgeryRng, locale: Locale, first: &str, last: &str) -> String {
+    let first = internet::ascii_slug(first, "user");
+    let last = internet::ascii_slug(last, "name");
+    let local = match rng.gen_range(0u8, 3) {
+        0 => format!("{}.{}", first, last),
+        1 => format!("{}_{}", first, last),
+        2 => format!("{}{}{}", &first[..1], last, rng.gen_range(1u8, 99)),
+        _ => format!("{}{}{}", first, last, rng.gen_range(1u8, 99)),
+    };
+    let domains = get_locale_data(locale)
+        .free_email_domains()
+        .filter(|domains| !domains.is_empty())
+        .unwrap_or(&["example.com"]);
+    format!("{}@{}", local, rng.choose(domains))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::en_us::{STATES, STATE_AREA_CODES, STATE_ZIP_PREFIXES};
+
+    fn profiles(locale: Locale, n: usize) -> Vec<Profile> {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        generate_profiles(
+            &mut rng,
+            locale,
+            &LocaleOverrides::EMPTY,
+            n,
+            DEFAULT_MIN_AGE,
+            DEFAULT_MAX_AGE,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_profiles_count_and_determinism() {
+        let a = profiles(Locale::EnUS, 50);
+        assert_eq!(a.len(), 50);
+        assert_eq!(a, profiles(Locale::EnUS, 50));
+    }
+
+    #[test]
+    fn test_profile_fields_agree() {
+        let data = get_locale_data(Locale::EnUS);
+        let first_names = data.first_names().unwrap();
+        let male_count = data.male_first_name_count().unwrap();
+        for p in profiles(Locale::EnUS, 200) {
+            assert_eq!(p.name, format!("{} {}", p.first_name, p.last_name));
+            let index = first_names.iter().position(|n| *n == p.first_name).unwrap();
+            let expected = if index < male_count { "male" } else { "female" };
+            assert_eq!(p.gender, expected, "{}", p.first_name);
+
+            assert!((18..=80).contains(&p.age), "{}", p.age);
+            let dob = chrono::NaiveDate::parse_from_str(&p.date_of_birth, "%Y-%m-%d").unwrap();
+            let reference = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+            assert_eq!(reference.years_since(dob), Some(p.age));
+
+            let local = p.email.split('@').next().unwrap();
+            assert!(local.contains(&p.last_name.to_lowercase()), "{}", p.email);
+
+            let state = STATES.iter().position(|s| *s == p.state).unwrap();
+            let digits: String = p.phone.chars().filter(char::is_ascii_digit).collect();
+            let area = &digits[digits.len() - 10..digits.len() - 7];
+            assert!(STATE_AREA_CODES[state].contains(&area), "{}", p.phone);
+            assert!(p.address.starts_with(&p.street_address));
+            assert!(p.address.contains(&p.city));
+        }
+    }
+
+    #[test]
+    fn test_profile_zip_code_matches_state() {
+        for p in profiles(Locale::EnUS, 500) {
+            let state = STATES.iter().position(|s| *s == p.state).unwrap();
+            let (min, max) = STATE_ZIP_PREFIXES[state];
+            let prefix: u16 = p.postal_code[..3].parse().unwrap();
+            assert!(
+                (min..=max).contains(&prefix),
+                "{} {}",
+                p.state,
+                p.postal_code
+            );
+            assert!(p.address.ends_with(&p.postal_code), "{}", p.address);
+        }
+    }
+
+    #[test]
+    fn test_profile_russian_surname_follows_gender() {
+        for p in profiles(Locale::RuRU, 100) {
+            let feminine = p.last_name.ends_with('а');
+            assert_eq!(feminine, p.gender == "female", "{}", p.name);
+        }
+    }
+
+    #[test]
+    fn test_profile_romanized_email() {
+        for locale in [Locale::JaJP, Locale::KoKR, Locale::RuRU, Locale::DeDE] {
+            for p in profiles(locale, 50) {
+                let (local, domain) = p.email.split_once('@').unwrap();
+                assert!(!local.is_empty() && !domain.is_empty(), "{}", p.email);
+                assert!(p.email.is_ascii(), "{}", p.email);
+            }
+        }
+    }
+
+    #[test]
+    fn test_profile_all_locales() {
+        for locale in Locale::ALL {
+            for p in profiles(*locale, 20) {
+                assert!(p.gender == "male" || p.gender == "female");
+                assert!(!p.phone.is_empty() && !p.address.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_profile_invalid_ages() {
+        let mut rng = ForgeryRng::new();
+        let result = generate_profile(&mut rng, Locale::EnUS, &LocaleOverrides::EMPTY, 60, 20);
+        assert!(result.is_err());
+    }
+}
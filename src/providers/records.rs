@@ -5,11 +5,14 @@
 //! This module provides the `records()` and `records_tuples()` functions
 //! for generating structured data based on a schema DSL.
 
+use crate::data::overrides::LocaleOverrides;
 use crate::locale::Locale;
 use crate::providers::custom::CustomProvider;
 use crate::providers::{
-    address, colors, company, datetime, finance, identifiers, internet, names, network, numbers,
-    phone, text,
+    address, auth, binary, cloud, codes, colors, commerce, company, datetime, demographics,
+    drivers_license, duration, filesystem, finance, geo, identifiers, insurance, internet,
+    language, logistics, logs, medical, names, national_id, network, numbers, phone, software,
+    tax_id, text, travel, vehicle,
 };
 use crate::rng::ForgeryRng;
 use std::collections::{BTreeMap, HashMap, HashSet};
@@ -63,8 +66,200 @@ pub enum FieldSpec {
         /// End date in YYYY-MM-DD format.
         end: String,
     },
+    /// Business date: "business_date" or ("business_date", start, end[, holidays])
+    BusinessDate {
+        /// Start date in YYYY-MM-DD format.
+        start: String,
+        /// End date in YYYY-MM-DD format.
+        end: String,
+        /// Dates in YYYY-MM-DD format to exclude.
+        holidays: Vec<String>,
+    },
+    /// Date of birth with its age: "date_of_birth_with_age" or
+    /// ("date_of_birth_with_age", min_age, max_age)
+    DateOfBirthWithAge {
+        /// Minimum age in years on the reference date.
+        min_age: u32,
+        /// Maximum age in years on the reference date.
+        max_age: u32,
+    },
+    /// Duration: "duration" or ("duration", min, max[, format])
+    Duration {
+        /// Minimum duration in seconds (inclusive).
+        min: u64,
+        /// Maximum duration in seconds (inclusive).
+        max: u64,
+        /// Output format; seconds produce integers.
+        format: duration::DurationFormat,
+    },
+    /// Demographic category: "gender", "pronouns", "marital_status" or
+    /// (name, {category: weight})
+    Demographic {
+        /// Category weights to draw from.
+        weights: demographics::DemographicWeights,
+    },
     /// Choice from options: ("choice", ["a", "b", "c"])
     Choice(Vec<String>),
+    /// Invoice number: "invoice_number" or ("invoice_number", prefix[, digits[, date]])
+    InvoiceNumber {
+        /// Leading segment; omitted if empty.
+        prefix: String,
+        /// Width of the zero-padded sequence number.
+        digits: u32,
+        /// Date component, if any.
+        date: Option<commerce::InvoiceDate>,
+    },
+    /// US county: "county" or ("county", state[, with_state])
+    County {
+        /// State name or abbreviation; a random state per value if `None`.
+        state: Option<String>,
+        /// Append the state abbreviation.
+        with_state: bool,
+    },
+    /// Insurance policy or claim number: "policy_number", "claim_number", or
+    /// (name, prefix[, digits])
+    InsuranceNumber {
+        /// Leading segment; omitted if empty.
+        prefix: String,
+        /// Width of the zero-padded sequence number.
+        digits: u32,
+    },
+    /// Container image: "docker_image" or ("docker_image", registry[, tag[, digest]])
+    DockerImage {
+        /// Prefix a registry host.
+        registry: bool,
+        /// Append a version tag.
+        tag: bool,
+        /// Append a content digest.
+        digest: bool,
+    },
+    /// Kubernetes resource name: "k8s_name" or ("k8s_name", kind)
+    K8sName {
+        /// Kind of resource to name.
+        kind: software::K8sResource,
+    },
+    /// ARN of one AWS service: ("arn", service)
+    ArnService {
+        /// Service whose ARN format to use.
+        service: cloud::ArnService,
+    },
+    /// Cloud region identifier: "cloud_region" or ("cloud_region", provider)
+    CloudRegion {
+        /// Provider whose regions to use.
+        provider: cloud::CloudProvider,
+    },
+    /// Syslog line: "syslog" or ("syslog", format)
+    Syslog {
+        /// RFC 5424 or RFC 3164 line format.
+        format: logs::SyslogFormat,
+    },
+    /// SKU from a pattern: ("sku", pattern)
+    SkuPattern {
+        /// Pattern where `#` is a digit and `?` an uppercase letter.
+        pattern: String,
+    },
+    /// Retail price: "retail_price" or ("retail_price", tier[, decimal])
+    RetailPrice {
+        /// Price band to draw from.
+        tier: commerce::PriceTier,
+        /// Produce fixed-point decimals instead of floats.
+        decimal: bool,
+    },
+    /// Price: "price" or ("price", min, max[, currency[, formatted]])
+    Price {
+        /// Minimum amount (inclusive).
+        min: f64,
+        /// Maximum amount (inclusive).
+        max: f64,
+        /// Currency used for rounding and formatting.
+        currency: finance::Currency,
+        /// Produce locale-formatted strings instead of numbers.
+        formatted: bool,
+    },
+    /// Credit card from one network: ("credit_card", network)
+    CreditCardNetwork {
+        /// Card network to draw prefixes from.
+        network: finance::CardNetwork,
+    },
+    /// Tracking number from one carrier: ("tracking_number", carrier)
+    TrackingNumberCarrier {
+        /// Carrier whose format to use.
+        carrier: logistics::Carrier,
+    },
+    /// Catch phrase in one style: ("catch_phrase", style)
+    CatchPhraseStyled {
+        /// Pattern to generate.
+        style: company::CatchPhraseStyle,
+    },
+    /// Country in a given format: ("country", format)
+    CountryFormatted {
+        /// Name or ISO 3166-1 code format.
+        format: address::CountryFormat,
+    },
+    /// VAT number: "vat_number" or ("vat_number", country)
+    VatNumber {
+        /// Country code; `None` follows the locale.
+        country: Option<String>,
+    },
+    /// Driver's license: "drivers_license" or ("drivers_license", region)
+    DriversLicense {
+        /// US state or Canadian province code; `None` follows the locale.
+        region: Option<String>,
+    },
+    /// Port number: "port" or ("port", kind)
+    Port {
+        /// IANA range to draw from.
+        kind: network::PortKind,
+    },
+    /// HTTP status code: "http_status_code" or ("http_status_code", class)
+    HttpStatusCode {
+        /// Status class; `None` draws a weighted mix.
+        class: Option<network::StatusClass>,
+    },
+    /// Email on a fixed domain: ("email", domain)
+    EmailWithDomain {
+        /// Domain for every address, e.g. "corp.example".
+        domain: String,
+    },
+    /// Password hash: "password_hash" or ("password_hash", algorithm)
+    PasswordHash {
+        /// Hashing scheme whose format is generated.
+        algorithm: auth::PasswordHashAlgorithm,
+    },
+    /// API key: "api_key" or ("api_key", prefix[, length[, alphabet]])
+    ApiKey {
+        /// Text before the random part.
+        prefix: String,
+        /// Number of random characters after the prefix.
+        length: usize,
+        /// Character set for the random part.
+        alphabet: auth::ApiKeyAlphabet,
+    },
+    /// Opaque token: "session_id", "oauth_access_token", "refresh_token", or
+    /// (name, format)
+    Token {
+        /// Kind of token, which sets its length and prefix.
+        kind: auth::TokenKind,
+        /// Encoding of the token.
+        format: auth::TokenFormat,
+    },
+    /// Random bytes: "bytes" or ("bytes", length)
+    Bytes {
+        /// Number of bytes in each value.
+        length: usize,
+    },
+    /// Base64 text: "base64" or ("base64", decoded_length[, url_safe])
+    Base64 {
+        /// Number of random bytes each string decodes to.
+        decoded_length: usize,
+        /// Use unpadded base64url instead of padded standard base64.
+        url_safe: bool,
+    },
+    /// MIME type: "mime_type" or ("mime_type", category)
+    MimeType {
+        /// Top-level MIME type; `None` picks a weighted category.
+        category: Option<String>,
+    },
     /// Name field type.
     Name,
     /// First name field type.
@@ -77,18 +272,34 @@ pub enum FieldSpec {
     SafeEmail,
     /// Free email field type.
     FreeEmail,
-    /// Phone field type.
-    Phone,
+    /// Phone field type: "phone" or ("phone", format[, kind[, safe]])
+    Phone {
+        /// Output format, mobile/landline range and fictional-only flag.
+        options: phone::PhoneOptions,
+    },
     /// UUID field type.
     Uuid,
     /// Integer with default range (0-100).
     Int,
     /// Float with default range (0.0-1.0).
     Float,
+    /// Boolean: "bool" or ("bool", true_rate)
+    Bool {
+        /// Probability that a value is true.
+        true_rate: f64,
+    },
     /// Date with default range.
     Date,
     /// DateTime field type.
     DateTime,
+    /// Time of day field type ("HH:MM:SS"; Arrow `Time64`).
+    Time,
+    /// Datetime relative to now: "recent", "past", "future", or a tuple
+    /// form such as ("past", years).
+    RelativeDateTime {
+        /// The window around the current UTC time.
+        range: datetime::RelativeRange,
+    },
     /// Street address field type.
     StreetAddress,
     /// City field type.
@@ -103,8 +314,11 @@ pub enum FieldSpec {
     Address,
     /// Company name field type.
     Company,
-    /// Job title field type.
-    Job,
+    /// Job title: "job" or ("job", level)
+    Job {
+        /// Seniority level; `None` picks from the locale's job titles.
+        level: Option<company::JobLevel>,
+    },
     /// Catch phrase field type.
     CatchPhrase,
     /// URL field type.
@@ -113,6 +327,11 @@ pub enum FieldSpec {
     DomainName,
     /// IPv4 address field type.
     Ipv4,
+    /// IPv4 address in a network: ("ipv4", network)
+    Ipv4Network {
+        /// CIDR block or named range the address is drawn from.
+        range: network::Ipv4Range,
+    },
     /// IPv6 address field type.
     Ipv6,
     /// MAC address field type.
@@ -131,12 +350,55 @@ pub enum FieldSpec {
     HexColor,
     /// RGB color field type.
     RgbColor,
+    /// HSL color field type (hue 0-359, saturation and lightness 0-100).
+    HslColor,
+    /// CMYK color field type (each component 0-100).
+    CmykColor,
+    /// CSS color string: "css_color" or ("css_color", format)
+    CssColor {
+        /// Syntax to use; `None` mixes all formats.
+        format: Option<colors::CssColorFormat>,
+    },
+    /// Latitude/longitude pair field type.
+    Coordinates,
+    /// Company profile field type (name, legal form, industry, ...).
+    CompanyProfile,
+    /// Address components: "address_parts" or ("address_parts", unit_rate)
+    AddressParts {
+        /// Probability that the unit component is filled in.
+        unit_rate: f64,
+    },
+    /// Structured access log entry (ip, timestamp, method, path, ...).
+    AccessLogEntry,
+    /// Structured syslog entry (timestamp, hostname, app name, ...).
+    SyslogEntry,
+    /// Adult height in centimetres.
+    Height,
+    /// Adult weight in kilograms.
+    Weight,
     /// MD5 hash field type.
     Md5,
     /// SHA256 hash field type.
     Sha256,
     /// Custom provider by name.
     Custom(String),
+    /// Another field that is null at the given rate: ("nullable", spec, null_rate)
+    Nullable {
+        /// The wrapped field specification.
+        inner: Box<FieldSpec>,
+        /// Probability that a value is null.
+        null_rate: f64,
+    },
+}
+
+impl FieldSpec {
+    /// The specification values are generated from, looking through `Nullable`.
+    fn value_spec(&self) -> &FieldSpec {
+        match self {
+            FieldSpec::Nullable { inner, .. } => inner.value_spec(),
+            spec => spec,
+        }
+    }
 }
 
 /// A generated value that can be various types.
@@ -148,8 +410,26 @@ pub enum Value {
     Int(i64),
     /// A floating-point value.
     Float(f64),
+    /// A boolean value.
+    Bool(bool),
     /// A tuple of three u8 values (for RGB colors).
     Tuple3U8(u8, u8, u8),
+    /// A tuple of three u16 values (for HSL colors).
+    Tuple3U16(u16, u16, u16),
+    /// A tuple of four u8 values (for CMYK colors).
+    Tuple4U8(u8, u8, u8, u8),
+    /// A tuple of two f64 values (for latitude/longitude coordinates).
+    Tuple2F64(f64, f64),
+    /// A string paired with a u32 (for a date of birth and its age).
+    TupleStrU32(String, u32),
+    /// Named string fields (for composite values such as company profiles).
+    Struct(Vec<(&'static str, String)>),
+    /// A fixed-point decimal as (unscaled value, scale), e.g. (1999, 2) for 19.99.
+    Decimal(i64, u32),
+    /// Raw bytes (for binary blob columns).
+    Bytes(Vec<u8>),
+    /// A missing value (for nullable fields).
+    Null,
 }
 
 impl Value {
@@ -159,7 +439,37 @@ impl Value {
             Value::String(s) => s.clone(),
             Value::Int(i) => i.to_string(),
             Value::Float(f) => f.to_string(),
+            Value::Bool(b) => b.to_string(),
             Value::Tuple3U8(r, g, b) => format!("({}, {}, {})", r, g, b),
+            Value::Tuple3U16(a, b, c) => format!("({}, {}, {})", a, b, c),
+            Value::Tuple4U8(a, b, c, d) => format!("({}, {}, {}, {})", a, b, c, d),
+            Value::Tuple2F64(a, b) => format!("({}, {})", a, b),
+            Value::TupleStrU32(s, i) => format!("({}, {})", s, i),
+            Value::Decimal(units, scale) => {
+                let divisor = 10i64.pow(*scale);
+                let sign = if *units < 0 { "-" } else { "" };
+                let (whole, frac) = (units.abs() / divisor, units.abs() % divisor);
+                if *scale == 0 {
+                    format!("{}{}", sign, whole)
+                } else {
+                    format!(
+                        "{}{}.{:0width$}",
+                        sign,
+                        whole,
+                        frac,
+                        width = *scale as usize
+                    )
+                }
+            }
+            Value::Struct(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect();
+                format!("{{{}}}", fields.join(", "))
+            }
+            Value::Bytes(bytes) => bytes.iter().map(|b| format!("{:02x}", b)).collect(),
+            Value::Null => String::new(),
         }
     }
 }
@@ -173,42 +483,240 @@ pub fn parse_simple_type(type_name: &str) -> Result<FieldSpec, SchemaError> {
         "email" => Ok(FieldSpec::Email),
         "safe_email" => Ok(FieldSpec::SafeEmail),
         "free_email" => Ok(FieldSpec::FreeEmail),
+        "company_email" => Ok(FieldSpec::Simple("company_email".to_string())),
+        "username" => Ok(FieldSpec::Simple("username".to_string())),
         "uuid" => Ok(FieldSpec::Uuid),
+        "uuid7" => Ok(FieldSpec::Simple("uuid7".to_string())),
         "int" => Ok(FieldSpec::Int),
         "float" => Ok(FieldSpec::Float),
-        "phone" => Ok(FieldSpec::Phone),
+        "bool" => Ok(FieldSpec::Bool { true_rate: 0.5 }),
+        "phone" => Ok(FieldSpec::Phone {
+            options: phone::PhoneOptions::default(),
+        }),
         "address" => Ok(FieldSpec::Address),
         "street_address" => Ok(FieldSpec::StreetAddress),
         "city" => Ok(FieldSpec::City),
         "state" => Ok(FieldSpec::State),
         "country" => Ok(FieldSpec::Country),
         "zip_code" => Ok(FieldSpec::ZipCode),
+        "county" => Ok(FieldSpec::County {
+            state: None,
+            with_state: false,
+        }),
+        "country_code" => Ok(FieldSpec::CountryFormatted {
+            format: address::CountryFormat::Alpha2,
+        }),
+        "street_name" => Ok(FieldSpec::Simple("street_name".to_string())),
+        "street_suffix" => Ok(FieldSpec::Simple("street_suffix".to_string())),
+        "building_number" => Ok(FieldSpec::Simple("building_number".to_string())),
+        "state_abbr" => Ok(FieldSpec::Simple("state_abbr".to_string())),
+        "secondary_unit" => Ok(FieldSpec::Simple("secondary_unit".to_string())),
+        "po_box" => Ok(FieldSpec::Simple("po_box".to_string())),
         "company" => Ok(FieldSpec::Company),
-        "job" => Ok(FieldSpec::Job),
+        "job" => Ok(FieldSpec::Job { level: None }),
+        "job_level" => Ok(FieldSpec::Simple("job_level".to_string())),
+        "job_area" => Ok(FieldSpec::Simple("job_area".to_string())),
+        "job_role" => Ok(FieldSpec::Simple("job_role".to_string())),
         "catch_phrase" => Ok(FieldSpec::CatchPhrase),
         "url" => Ok(FieldSpec::Url),
         "domain_name" => Ok(FieldSpec::DomainName),
         "ipv4" => Ok(FieldSpec::Ipv4),
         "ipv6" => Ok(FieldSpec::Ipv6),
         "mac_address" => Ok(FieldSpec::MacAddress),
+        "http_method" => Ok(FieldSpec::Simple("http_method".to_string())),
+        "http_status_code" => Ok(FieldSpec::HttpStatusCode { class: None }),
+        "ipv4_private" => Ok(FieldSpec::Simple("ipv4_private".to_string())),
+        "ipv4_public" => Ok(FieldSpec::Simple("ipv4_public".to_string())),
+        "cidr" => Ok(FieldSpec::Simple("cidr".to_string())),
+        "port" => Ok(FieldSpec::Port {
+            kind: network::PortKind::Any,
+        }),
         "color" => Ok(FieldSpec::Color),
         "hex_color" => Ok(FieldSpec::HexColor),
         "rgb_color" => Ok(FieldSpec::RgbColor),
+        "hsl_color" => Ok(FieldSpec::HslColor),
+        "cmyk_color" => Ok(FieldSpec::CmykColor),
+        "css_color" => Ok(FieldSpec::CssColor { format: None }),
+        "coordinates" => Ok(FieldSpec::Coordinates),
+        "company_profile" => Ok(FieldSpec::CompanyProfile),
+        "address_parts" => Ok(FieldSpec::AddressParts { unit_rate: 0.0 }),
+        "access_log_entry" => Ok(FieldSpec::AccessLogEntry),
+        "syslog_entry" => Ok(FieldSpec::SyslogEntry),
+        "height" => Ok(FieldSpec::Height),
+        "weight" => Ok(FieldSpec::Weight),
         "credit_card" => Ok(FieldSpec::CreditCard),
         "iban" => Ok(FieldSpec::Iban),
         "date" => Ok(FieldSpec::Date),
         "datetime" => Ok(FieldSpec::DateTime),
+        "time" => Ok(FieldSpec::Time),
+        "iso_week" => Ok(FieldSpec::Simple("iso_week".to_string())),
+        "quarter" => Ok(FieldSpec::Simple("quarter".to_string())),
+        "month" => Ok(FieldSpec::Simple("month".to_string())),
+        "business_date" => Ok(FieldSpec::BusinessDate {
+            start: "2000-01-01".to_string(),
+            end: "2030-12-31".to_string(),
+            holidays: Vec::new(),
+        }),
+        "date_of_birth_with_age" => Ok(FieldSpec::DateOfBirthWithAge {
+            min_age: 18,
+            max_age: 80,
+        }),
+        "duration" => Ok(FieldSpec::Duration {
+            min: duration::DEFAULT_MIN_DURATION,
+            max: duration::DEFAULT_MAX_DURATION,
+            format: duration::DurationFormat::Iso8601,
+        }),
+        "recent" => Ok(FieldSpec::RelativeDateTime {
+            range: datetime::RelativeRange::Recent(datetime::DEFAULT_RECENT_DAYS),
+        }),
+        "past" => Ok(FieldSpec::RelativeDateTime {
+            range: datetime::RelativeRange::Past(datetime::DEFAULT_PAST_YEARS),
+        }),
+        "future" => Ok(FieldSpec::RelativeDateTime {
+            range: datetime::RelativeRange::Future(datetime::DEFAULT_FUTURE_YEARS),
+        }),
         "md5" => Ok(FieldSpec::Md5),
         "sha256" => Ok(FieldSpec::Sha256),
+        "sha1" => Ok(FieldSpec::Simple("sha1".to_string())),
+        "sha512" => Ok(FieldSpec::Simple("sha512".to_string())),
+        "bytes" => Ok(FieldSpec::Bytes {
+            length: binary::DEFAULT_BYTES_LENGTH,
+        }),
+        "base64" => Ok(FieldSpec::Base64 {
+            decoded_length: binary::DEFAULT_BASE64_DECODED_LENGTH,
+            url_safe: false,
+        }),
         "sentence" => Ok(FieldSpec::Sentence),
         "paragraph" => Ok(FieldSpec::Paragraph),
         "text" => Ok(FieldSpec::Simple("text".to_string())),
+        "national_id" => Ok(FieldSpec::Simple("national_id".to_string())),
+        "vat_number" => Ok(FieldSpec::VatNumber { country: None }),
+        "drivers_license" => Ok(FieldSpec::DriversLicense { region: None }),
+        "routing_number" => Ok(FieldSpec::Simple("routing_number".to_string())),
+        "price" => Ok(FieldSpec::Price {
+            min: 1.0,
+            max: 1000.0,
+            currency: finance::Currency::USD,
+            formatted: true,
+        }),
+        "invoice_number" => Ok(FieldSpec::InvoiceNumber {
+            prefix: commerce::DEFAULT_INVOICE_PREFIX.to_string(),
+            digits: commerce::DEFAULT_INVOICE_DIGITS,
+            date: Some(commerce::InvoiceDate::Year),
+        }),
+        "policy_number" => Ok(FieldSpec::InsuranceNumber {
+            prefix: insurance::DEFAULT_POLICY_PREFIX.to_string(),
+            digits: insurance::DEFAULT_INSURANCE_DIGITS,
+        }),
+        "claim_number" => Ok(FieldSpec::InsuranceNumber {
+            prefix: insurance::DEFAULT_CLAIM_PREFIX.to_string(),
+            digits: insurance::DEFAULT_INSURANCE_DIGITS,
+        }),
+        "sku" => Ok(FieldSpec::Simple("sku".to_string())),
+        "retail_price" => Ok(FieldSpec::RetailPrice {
+            tier: commerce::PriceTier::Standard,
+            decimal: false,
+        }),
+        "product_name" => Ok(FieldSpec::Simple("product_name".to_string())),
+        "currency_code" => Ok(FieldSpec::Simple("currency_code".to_string())),
+        "currency_name" => Ok(FieldSpec::Simple("currency_name".to_string())),
+        "currency_symbol" => Ok(FieldSpec::Simple("currency_symbol".to_string())),
+        "ticker" => Ok(FieldSpec::Simple("ticker".to_string())),
+        "stock_exchange" => Ok(FieldSpec::Simple("stock_exchange".to_string())),
+        "isbn13" => Ok(FieldSpec::Simple("isbn13".to_string())),
+        "isbn10" => Ok(FieldSpec::Simple("isbn10".to_string())),
+        "imei" => Ok(FieldSpec::Simple("imei".to_string())),
+        "doi" => Ok(FieldSpec::Simple("doi".to_string())),
+        "orcid" => Ok(FieldSpec::Simple("orcid".to_string())),
+        "vin" => Ok(FieldSpec::Simple("vin".to_string())),
+        "vehicle_make" => Ok(FieldSpec::Simple("vehicle_make".to_string())),
+        "vehicle_model" => Ok(FieldSpec::Simple("vehicle_model".to_string())),
+        "age" => Ok(FieldSpec::IntRange {
+            min: demographics::DEFAULT_MIN_AGE.into(),
+            max: demographics::DEFAULT_MAX_AGE.into(),
+        }),
+        "gender" => Ok(demographic_spec(demographics::Demographic::Gender)),
+        "pronouns" => Ok(demographic_spec(demographics::Demographic::Pronouns)),
+        "marital_status" => Ok(demographic_spec(demographics::Demographic::MaritalStatus)),
+        "blood_type" => Ok(FieldSpec::Simple("blood_type".to_string())),
+        "allergy" => Ok(FieldSpec::Simple("allergy".to_string())),
+        "medication" => Ok(FieldSpec::Simple("medication".to_string())),
+        "icd10_code" => Ok(FieldSpec::Simple("icd10_code".to_string())),
+        "npi" => Ok(FieldSpec::Simple("npi".to_string())),
+        "airport_code" => Ok(FieldSpec::Simple("airport_code".to_string())),
+        "airport_icao_code" => Ok(FieldSpec::Simple("airport_icao_code".to_string())),
+        "airport_name" => Ok(FieldSpec::Simple("airport_name".to_string())),
+        "airport_city" => Ok(FieldSpec::Simple("airport_city".to_string())),
+        "flight_number" => Ok(FieldSpec::Simple("flight_number".to_string())),
+        "tracking_number" => Ok(FieldSpec::Simple("tracking_number".to_string())),
+        "language_code" => Ok(FieldSpec::Simple("language_code".to_string())),
+        "industry" => Ok(FieldSpec::Simple("industry".to_string())),
+        "naics_code" => Ok(FieldSpec::Simple("naics_code".to_string())),
+        "sic_code" => Ok(FieldSpec::Simple("sic_code".to_string())),
+        "language_name" => Ok(FieldSpec::Simple("language_name".to_string())),
+        "file_name" => Ok(FieldSpec::Simple("file_name".to_string())),
+        "file_extension" => Ok(FieldSpec::Simple("file_extension".to_string())),
+        "file_path" => Ok(FieldSpec::Simple("file_path".to_string())),
+        "mime_type" => Ok(FieldSpec::MimeType { category: None }),
+        "semver" => Ok(FieldSpec::Simple("semver".to_string())),
+        "git_sha" => Ok(FieldSpec::Simple("git_sha".to_string())),
+        "commit_message" => Ok(FieldSpec::Simple("commit_message".to_string())),
+        "docker_image" => Ok(FieldSpec::DockerImage {
+            registry: true,
+            tag: true,
+            digest: false,
+        }),
+        "k8s_name" => Ok(FieldSpec::K8sName {
+            kind: software::K8sResource::Pod,
+        }),
+        "arn" => Ok(FieldSpec::Simple("arn".to_string())),
+        "s3_uri" => Ok(FieldSpec::Simple("s3_uri".to_string())),
+        "access_log" => Ok(FieldSpec::Simple("access_log".to_string())),
+        "syslog" => Ok(FieldSpec::Syslog {
+            format: logs::SyslogFormat::Rfc5424,
+        }),
+        "cloud_region" => Ok(FieldSpec::CloudRegion {
+            provider: cloud::CloudProvider::Aws,
+        }),
+        "ein" => Ok(FieldSpec::Simple("ein".to_string())),
+        "company_tax_id" => Ok(FieldSpec::Simple("company_tax_id".to_string())),
+        "password_hash" => Ok(FieldSpec::PasswordHash {
+            algorithm: auth::PasswordHashAlgorithm::default(),
+        }),
+        "jwt" => Ok(FieldSpec::Simple("jwt".to_string())),
+        "api_key" => Ok(FieldSpec::ApiKey {
+            prefix: auth::DEFAULT_API_KEY_PREFIX.to_string(),
+            length: auth::DEFAULT_API_KEY_LENGTH,
+            alphabet: auth::ApiKeyAlphabet::default(),
+        }),
+        "session_id" => Ok(token_spec(auth::TokenKind::Session)),
+        "oauth_access_token" => Ok(token_spec(auth::TokenKind::AccessToken)),
+        "refresh_token" => Ok(token_spec(auth::TokenKind::RefreshToken)),
+        "totp_secret" => Ok(FieldSpec::Simple("totp_secret".to_string())),
+        "totp_uri" => Ok(FieldSpec::Simple("totp_uri".to_string())),
         _ => Err(SchemaError {
             message: format!("Unknown type: {}", type_name),
         }),
     }
 }
 
+/// A demographic field with its default weights.
+#[inline]
+fn demographic_spec(demographic: demographics::Demographic) -> FieldSpec {
+    FieldSpec::Demographic {
+        weights: demographics::DemographicWeights::default_for(demographic),
+    }
+}
+
+/// An opaque token field in its kind's default format.
+#[inline]
+fn token_spec(kind: auth::TokenKind) -> FieldSpec {
+    FieldSpec::Token {
+        kind,
+        format: kind.default_format(),
+    }
+}
+
 /// Parse a simple type name into a FieldSpec, with custom provider awareness.
 ///
 /// If the type name matches a built-in type, returns the corresponding FieldSpec.
@@ -260,6 +768,14 @@ pub fn validate_spec(spec: &FieldSpec) -> Result<(), SchemaError> {
             }
             Ok(())
         }
+        FieldSpec::Price { min, max, .. } => {
+            if !min.is_finite() || !max.is_finite() || min > max {
+                return Err(SchemaError {
+                    message: format!("Invalid price range: {} to {}", min, max),
+                });
+            }
+            Ok(())
+        }
         FieldSpec::Text {
             min_chars,
             max_chars,
@@ -276,6 +792,54 @@ pub fn validate_spec(spec: &FieldSpec) -> Result<(), SchemaError> {
             // We could add date format validation here if needed
             Ok(())
         }
+        FieldSpec::InvoiceNumber { digits, .. } => {
+            commerce::validate_invoice_digits(*digits).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::County {
+            state: Some(state), ..
+        } => {
+            address::validate_us_state(state).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::InsuranceNumber { prefix, digits } => {
+            insurance::validate_insurance_format(prefix, *digits).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::BusinessDate {
+            start,
+            end,
+            holidays,
+        } => {
+            datetime::validate_business_dates(start, end, holidays).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::RelativeDateTime { range } => {
+            range.bounds(datetime::utc_now()).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::DateOfBirthWithAge { min_age, max_age } => {
+            datetime::validate_birth_ages(*min_age, *max_age).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::Duration { min, max, .. } => {
+            duration::validate_duration_range(*min, *max).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
         FieldSpec::Choice(options) => {
             if options.is_empty() {
                 return Err(SchemaError {
@@ -284,6 +848,52 @@ pub fn validate_spec(spec: &FieldSpec) -> Result<(), SchemaError> {
             }
             Ok(())
         }
+        FieldSpec::VatNumber {
+            country: Some(country),
+        } => {
+            finance::validate_vat_country(country).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::DriversLicense {
+            region: Some(region),
+        } => {
+            drivers_license::validate_license_region(region).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::EmailWithDomain { domain } => {
+            internet::validate_email_domain(domain).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::MimeType {
+            category: Some(category),
+        } => {
+            filesystem::category_mime_types(category).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(())
+        }
+        FieldSpec::Bool { true_rate } => {
+            if !(0.0..=1.0).contains(true_rate) {
+                return Err(SchemaError {
+                    message: format!("true_rate must be between 0.0 and 1.0, got {}", true_rate),
+                });
+            }
+            Ok(())
+        }
+        FieldSpec::Nullable { inner, null_rate } => {
+            if !(0.0..=1.0).contains(null_rate) {
+                return Err(SchemaError {
+                    message: format!("null_rate must be between 0.0 and 1.0, got {}", null_rate),
+                });
+            }
+            validate_spec(inner)
+        }
         // Custom providers are validated by the Faker when generating
         // (we check the provider exists during generation)
         FieldSpec::Custom(_) => Ok(()),
@@ -319,7 +929,7 @@ pub fn validate_schema_with_custom(
         })?;
 
         // Additionally validate that custom providers exist
-        if let FieldSpec::Custom(provider_name) = spec {
+        if let FieldSpec::Custom(provider_name) = spec.value_spec() {
             if !custom_providers.contains_key(provider_name) {
                 return Err(SchemaError {
                     message: format!(
@@ -338,9 +948,20 @@ pub fn generate_value(
     rng: &mut ForgeryRng,
     locale: Locale,
     spec: &FieldSpec,
+) -> Result<Value, SchemaError> {
+    generate_value_with_overrides(rng, locale, &LocaleOverrides::EMPTY, spec)
+}
+
+/// Generate a value based on a field specification, drawing names, cities
+/// and streets from user overrides where set.
+pub fn generate_value_with_overrides(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    spec: &FieldSpec,
 ) -> Result<Value, SchemaError> {
     match spec {
-        FieldSpec::Simple(type_name) => generate_simple_value(rng, locale, type_name),
+        FieldSpec::Simple(type_name) => generate_simple_value(rng, locale, overrides, type_name),
         FieldSpec::IntRange { min, max } => {
             if min > max {
                 return Err(SchemaError {
@@ -385,14 +1006,173 @@ pub fn generate_value(
             let val = rng.choose(options).clone();
             Ok(Value::String(val))
         }
+        FieldSpec::Price {
+            min,
+            max,
+            currency,
+            formatted,
+        } => {
+            let val =
+                finance::generate_price(rng, *min, *max, *currency).map_err(|e| SchemaError {
+                    message: e.to_string(),
+                })?;
+            if *formatted {
+                Ok(Value::String(finance::format_price(val, *currency, locale)))
+            } else {
+                Ok(Value::Float(val))
+            }
+        }
+        FieldSpec::Demographic { weights } => Ok(Value::String(
+            demographics::generate_demographic(rng, weights),
+        )),
+        FieldSpec::InvoiceNumber {
+            prefix,
+            digits,
+            date,
+        } => {
+            let val =
+                commerce::generate_invoice_number(rng, prefix, *digits, *date).map_err(|e| {
+                    SchemaError {
+                        message: e.to_string(),
+                    }
+                })?;
+            Ok(Value::String(val))
+        }
+        FieldSpec::County { state, with_state } => {
+            let val =
+                address::generate_county(rng, state.as_deref(), *with_state).map_err(|e| {
+                    SchemaError {
+                        message: e.to_string(),
+                    }
+                })?;
+            Ok(Value::String(val))
+        }
+        FieldSpec::InsuranceNumber { prefix, digits } => {
+            let val = insurance::generate_insurance_number(rng, prefix, *digits).map_err(|e| {
+                SchemaError {
+                    message: e.to_string(),
+                }
+            })?;
+            Ok(Value::String(val))
+        }
+        FieldSpec::RetailPrice { tier, decimal } => {
+            let cents = commerce::generate_retail_price_cents(rng, *tier);
+            if *decimal {
+                Ok(Value::Decimal(cents, commerce::RETAIL_PRICE_SCALE))
+            } else {
+                Ok(Value::Float(cents as f64 / 100.0))
+            }
+        }
+        FieldSpec::DockerImage {
+            registry,
+            tag,
+            digest,
+        } => Ok(Value::String(software::generate_docker_image(
+            rng, *registry, *tag, *digest,
+        ))),
+        FieldSpec::K8sName { kind } => Ok(Value::String(software::generate_k8s_name(rng, *kind))),
+        FieldSpec::ArnService { service } => {
+            Ok(Value::String(cloud::generate_arn(rng, Some(*service))))
+        }
+        FieldSpec::CloudRegion { provider } => {
+            Ok(Value::String(cloud::generate_cloud_region(rng, *provider)))
+        }
+        FieldSpec::SkuPattern { pattern } => {
+            Ok(Value::String(commerce::generate_sku(rng, Some(pattern))))
+        }
+        FieldSpec::CatchPhraseStyled { style } => Ok(Value::String(
+            company::generate_catch_phrase_with_style(rng, locale, *style),
+        )),
+        FieldSpec::TrackingNumberCarrier { carrier } => Ok(Value::String(
+            logistics::generate_tracking_number(rng, Some(*carrier)),
+        )),
+        FieldSpec::CreditCardNetwork { network } => Ok(Value::String(
+            finance::generate_credit_card_for_network(rng, Some(*network)),
+        )),
+        FieldSpec::Port { kind } => Ok(Value::Int(i64::from(network::generate_port(rng, *kind)))),
+        FieldSpec::HttpStatusCode { class } => Ok(Value::Int(i64::from(
+            network::generate_http_status_code(rng, *class),
+        ))),
+        FieldSpec::CountryFormatted { format } => Ok(Value::String(
+            address::generate_country_with_format(rng, *format),
+        )),
+        FieldSpec::EmailWithDomain { domain } => {
+            let val = internet::generate_email_with_domain(rng, locale, overrides, domain)
+                .map_err(|e| SchemaError {
+                    message: e.to_string(),
+                })?;
+            Ok(Value::String(val))
+        }
+        FieldSpec::PasswordHash { algorithm } => {
+            Ok(Value::String(auth::generate_password_hash(rng, *algorithm)))
+        }
+        FieldSpec::ApiKey {
+            prefix,
+            length,
+            alphabet,
+        } => Ok(Value::String(auth::generate_api_key(
+            rng, prefix, *length, *alphabet,
+        ))),
+        FieldSpec::Token { kind, format } => {
+            Ok(Value::String(auth::generate_token(rng, *kind, *format)))
+        }
+        FieldSpec::Bytes { length } => Ok(Value::Bytes(binary::generate_byte_string(rng, *length))),
+        FieldSpec::Base64 {
+            decoded_length,
+            url_safe,
+        } => Ok(Value::String(binary::generate_base64_string(
+            rng,
+            *decoded_length,
+            *url_safe,
+        ))),
+        FieldSpec::MimeType { category } => {
+            let val = filesystem::generate_mime_type(rng, category.as_deref()).map_err(|e| {
+                SchemaError {
+                    message: e.to_string(),
+                }
+            })?;
+            Ok(Value::String(val))
+        }
+        FieldSpec::VatNumber { country } => {
+            let val =
+                finance::generate_vat_number(rng, locale, country.as_deref()).map_err(|e| {
+                    SchemaError {
+                        message: e.to_string(),
+                    }
+                })?;
+            Ok(Value::String(val))
+        }
+        FieldSpec::DriversLicense { region } => {
+            let val = drivers_license::generate_drivers_license(rng, locale, region.as_deref())
+                .map_err(|e| SchemaError {
+                    message: e.to_string(),
+                })?;
+            Ok(Value::String(val))
+        }
         // Direct type variants
-        FieldSpec::Name => Ok(Value::String(names::generate_name(rng, locale))),
-        FieldSpec::FirstName => Ok(Value::String(names::generate_first_name(rng, locale))),
-        FieldSpec::LastName => Ok(Value::String(names::generate_last_name(rng, locale))),
-        FieldSpec::Email => Ok(Value::String(internet::generate_email(rng, locale))),
+        FieldSpec::Name => Ok(Value::String(names::generate_name_with_overrides(
+            rng, locale, overrides,
+        ))),
+        FieldSpec::FirstName => Ok(Value::String(names::generate_first_name_with_overrides(
+            rng, locale, overrides,
+        ))),
+        FieldSpec::LastName => Ok(Value::String(names::generate_last_name_with_overrides(
+            rng, locale, overrides,
+        ))),
+        FieldSpec::Email => Ok(Value::String(internet::generate_email_with_overrides(
+            rng, locale, overrides,
+        ))),
         FieldSpec::SafeEmail => Ok(Value::String(internet::generate_safe_email(rng, locale))),
         FieldSpec::FreeEmail => Ok(Value::String(internet::generate_free_email(rng, locale))),
-        FieldSpec::Phone => Ok(Value::String(phone::generate_phone_number(rng, locale))),
+        FieldSpec::Phone { options } => {
+            let val =
+                phone::generate_phone_number_with_options(rng, locale, options).map_err(|e| {
+                    SchemaError {
+                        message: e.to_string(),
+                    }
+                })?;
+            Ok(Value::String(val))
+        }
         FieldSpec::Uuid => Ok(Value::String(identifiers::generate_uuid(rng))),
         FieldSpec::Int => Ok(Value::Int(numbers::generate_integer(rng, 0, 100).map_err(
             |e| SchemaError {
@@ -404,6 +1184,7 @@ pub fn generate_value(
                 message: e.to_string(),
             })?,
         )),
+        FieldSpec::Bool { true_rate } => Ok(Value::Bool(bool_value(rng, *true_rate))),
         FieldSpec::Date => {
             let val = datetime::generate_date(rng, "2000-01-01", "2030-12-31").map_err(|e| {
                 SchemaError {
@@ -421,20 +1202,71 @@ pub fn generate_value(
                 })?;
             Ok(Value::String(val))
         }
-        FieldSpec::StreetAddress => {
-            Ok(Value::String(address::generate_street_address(rng, locale)))
+        FieldSpec::Time => Ok(Value::String(
+            datetime::generate_time(rng, datetime::DEFAULT_TIME_FORMAT)
+                .expect("default time format is valid"),
+        )),
+        FieldSpec::BusinessDate {
+            start,
+            end,
+            holidays,
+        } => {
+            let val = datetime::generate_business_date(rng, start, end, holidays).map_err(|e| {
+                SchemaError {
+                    message: e.to_string(),
+                }
+            })?;
+            Ok(Value::String(val))
+        }
+        FieldSpec::RelativeDateTime { range } => {
+            let val = datetime::generate_relative_datetime(rng, *range, datetime::utc_now())
+                .map_err(|e| SchemaError {
+                    message: e.to_string(),
+                })?;
+            Ok(Value::String(val))
+        }
+        FieldSpec::DateOfBirthWithAge { min_age, max_age } => {
+            let (dob, age) = datetime::generate_date_of_birth_with_age(rng, *min_age, *max_age)
+                .map_err(|e| SchemaError {
+                    message: e.to_string(),
+                })?;
+            Ok(Value::TupleStrU32(dob, age))
+        }
+        FieldSpec::Duration { min, max, format } => {
+            let seconds =
+                duration::generate_duration(rng, *min, *max).map_err(|e| SchemaError {
+                    message: e.to_string(),
+                })?;
+            if *format == duration::DurationFormat::Seconds {
+                Ok(Value::Int(i64::try_from(seconds).unwrap_or(i64::MAX)))
+            } else {
+                Ok(Value::String(duration::format_duration(seconds, *format)))
+            }
         }
-        FieldSpec::City => Ok(Value::String(address::generate_city(rng, locale))),
+        FieldSpec::StreetAddress => Ok(Value::String(
+            address::generate_street_address_with_overrides(rng, locale, overrides),
+        )),
+        FieldSpec::City => Ok(Value::String(address::generate_city_with_overrides(
+            rng, locale, overrides,
+        ))),
         FieldSpec::State => Ok(Value::String(address::generate_state(rng, locale))),
         FieldSpec::Country => Ok(Value::String(address::generate_country(rng))),
         FieldSpec::ZipCode => Ok(Value::String(address::generate_zip_code(rng, locale))),
-        FieldSpec::Address => Ok(Value::String(address::generate_address(rng, locale))),
+        FieldSpec::Address => Ok(Value::String(address::generate_address_with_overrides(
+            rng, locale, overrides,
+        ))),
         FieldSpec::Company => Ok(Value::String(company::generate_company(rng, locale))),
-        FieldSpec::Job => Ok(Value::String(company::generate_job(rng, locale))),
+        FieldSpec::Job { level } => Ok(Value::String(match level {
+            Some(level) => company::generate_leveled_job(rng, *level),
+            None => company::generate_job(rng, locale),
+        })),
         FieldSpec::CatchPhrase => Ok(Value::String(company::generate_catch_phrase(rng, locale))),
         FieldSpec::Url => Ok(Value::String(network::generate_url(rng))),
         FieldSpec::DomainName => Ok(Value::String(network::generate_domain_name(rng))),
         FieldSpec::Ipv4 => Ok(Value::String(network::generate_ipv4(rng))),
+        FieldSpec::Ipv4Network { range } => {
+            Ok(Value::String(network::generate_ipv4_in(rng, range)))
+        }
         FieldSpec::Ipv6 => Ok(Value::String(network::generate_ipv6(rng))),
         FieldSpec::MacAddress => Ok(Value::String(network::generate_mac_address(rng))),
         FieldSpec::CreditCard => Ok(Value::String(finance::generate_credit_card(rng))),
@@ -447,8 +1279,36 @@ pub fn generate_value(
             let (r, g, b) = colors::generate_rgb_color(rng);
             Ok(Value::Tuple3U8(r, g, b))
         }
+        FieldSpec::HslColor => Ok(hsl_value(colors::generate_hsl_color(rng))),
+        FieldSpec::CssColor { format } => {
+            Ok(Value::String(colors::generate_css_color(rng, *format)))
+        }
+        FieldSpec::CmykColor => {
+            let (c, m, y, k) = colors::generate_cmyk_color(rng);
+            Ok(Value::Tuple4U8(c, m, y, k))
+        }
+        FieldSpec::Coordinates => {
+            let (lat, lon) = geo::generate_coordinate(rng, geo::DEFAULT_COORDINATE_PRECISION);
+            Ok(Value::Tuple2F64(lat, lon))
+        }
+        FieldSpec::CompanyProfile => Ok(company_profile_value(rng, locale, overrides)),
+        FieldSpec::AddressParts { unit_rate } => {
+            Ok(address_parts_value(rng, locale, overrides, *unit_rate))
+        }
+        FieldSpec::AccessLogEntry => Ok(access_log_entry_value(rng)),
+        FieldSpec::SyslogEntry => Ok(syslog_entry_value(rng)),
+        FieldSpec::Syslog { format } => Ok(Value::String(logs::generate_syslog(rng, *format))),
+        FieldSpec::Height => Ok(Value::Float(medical::generate_height(rng))),
+        FieldSpec::Weight => Ok(Value::Float(medical::generate_weight(rng))),
         FieldSpec::Md5 => Ok(Value::String(identifiers::generate_md5(rng))),
         FieldSpec::Sha256 => Ok(Value::String(identifiers::generate_sha256(rng))),
+        FieldSpec::Nullable { inner, null_rate } => {
+            if is_null(rng, *null_rate) {
+                Ok(Value::Null)
+            } else {
+                generate_value_with_overrides(rng, locale, overrides, inner)
+            }
+        }
         FieldSpec::Custom(name) => {
             // This should not be reached when calling generate_value directly
             // Use generate_value_with_custom for custom provider support
@@ -462,23 +1322,117 @@ pub fn generate_value(
     }
 }
 
-/// Generate a value based on a field specification, with custom provider support.
+/// Draw a boolean that is true with probability `true_rate`.
 ///
-/// This variant of generate_value() can handle FieldSpec::Custom variants
-/// by looking up the provider in the provided custom_providers map.
-pub fn generate_value_with_custom(
-    rng: &mut ForgeryRng,
-    locale: Locale,
-    spec: &FieldSpec,
+/// `gen_range` can return 1.0, so a rate of 1.0 is always true without drawing.
+#[inline]
+fn bool_value(rng: &mut ForgeryRng, true_rate: f64) -> bool {
+    true_rate >= 1.0 || rng.gen_range(0.0, 1.0) < true_rate
+}
+
+/// Decide whether a nullable value is null, drawing nothing if it never or
+/// always is (`gen_range` can return 1.0, so 1.0 needs its own case).
+#[inline]
+fn is_null(rng: &mut ForgeryRng, null_rate: f64) -> bool {
+    null_rate >= 1.0 || (null_rate > 0.0 && rng.gen_range(0.0, 1.0) < null_rate)
+}
+
+/// Widen an HSL color into a tuple value.
+#[inline]
+fn hsl_value((h, s, l): (u16, u8, u8)) -> Value {
+    Value::Tuple3U16(h, u16::from(s), u16::from(l))
+}
+
+/// Generate a reporting period over the default date range.
+#[inline]
+fn period_value(rng: &mut ForgeryRng, period: datetime::ReportingPeriod) -> String {
+    datetime::generate_period(rng, "2000-01-01", "2030-12-31", period)
+        .expect("default date range is valid")
+}
+
+/// Generate a company profile as a struct value.
+fn company_profile_value(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+) -> Value {
+    let profile = company::generate_company_profile(rng, locale, overrides);
+    Value::Struct(
+        company::COMPANY_PROFILE_FIELDS
+            .iter()
+            .copied()
+            .zip(profile.into_values())
+            .collect(),
+    )
+}
+
+/// Generate address components as a struct value.
+fn address_parts_value(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    unit_rate: f64,
+) -> Value {
+    let parts = address::address_parts(rng, locale, overrides, unit_rate);
+    Value::Struct(
+        address::ADDRESS_PARTS_FIELDS
+            .iter()
+            .copied()
+            .zip(parts.into_values())
+            .collect(),
+    )
+}
+
+/// Generate an access log entry as a struct value.
+fn access_log_entry_value(rng: &mut ForgeryRng) -> Value {
+    let entry = logs::generate_access_log_entry(rng);
+    Value::Struct(
+        logs::ACCESS_LOG_FIELDS
+            .iter()
+            .copied()
+            .zip(entry.into_values())
+            .collect(),
+    )
+}
+
+/// Generate a syslog entry as a struct value.
+fn syslog_entry_value(rng: &mut ForgeryRng) -> Value {
+    let entry = logs::generate_syslog_entry(rng);
+    Value::Struct(
+        logs::SYSLOG_FIELDS
+            .iter()
+            .copied()
+            .zip(entry.into_values())
+            .collect(),
+    )
+}
+
+/// Generate a value based on a field specification, with custom provider support.
+///
+/// This variant of generate_value() can handle FieldSpec::Custom variants
+/// by looking up the provider in the provided custom_providers map.
+pub fn generate_value_with_custom(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    spec: &FieldSpec,
     custom_providers: &HashMap<String, CustomProvider>,
 ) -> Result<Value, SchemaError> {
-    if let FieldSpec::Custom(name) = spec {
-        let provider = custom_providers.get(name).ok_or_else(|| SchemaError {
-            message: format!("Custom provider '{}' not found", name),
-        })?;
-        Ok(Value::String(provider.generate(rng)))
-    } else {
-        generate_value(rng, locale, spec)
+    match spec {
+        FieldSpec::Custom(name) => {
+            let provider = custom_providers.get(name).ok_or_else(|| SchemaError {
+                message: format!("Custom provider '{}' not found", name),
+            })?;
+            Ok(Value::String(provider.generate(rng)))
+        }
+        FieldSpec::Nullable { inner, null_rate } => {
+            if is_null(rng, *null_rate) {
+                Ok(Value::Null)
+            } else {
+                generate_value_with_custom(rng, locale, overrides, inner, custom_providers)
+            }
+        }
+        _ => generate_value_with_overrides(rng, locale, overrides, spec),
     }
 }
 
@@ -486,23 +1440,85 @@ pub fn generate_value_with_custom(
 fn generate_simple_value(
     rng: &mut ForgeryRng,
     locale: Locale,
+    overrides: &LocaleOverrides,
     type_name: &str,
 ) -> Result<Value, SchemaError> {
     match type_name {
         // Names
-        "name" => Ok(Value::String(names::generate_name(rng, locale))),
-        "first_name" => Ok(Value::String(names::generate_first_name(rng, locale))),
-        "last_name" => Ok(Value::String(names::generate_last_name(rng, locale))),
+        "name" => Ok(Value::String(names::generate_name_with_overrides(
+            rng, locale, overrides,
+        ))),
+        "first_name" => Ok(Value::String(names::generate_first_name_with_overrides(
+            rng, locale, overrides,
+        ))),
+        "last_name" => Ok(Value::String(names::generate_last_name_with_overrides(
+            rng, locale, overrides,
+        ))),
 
         // Internet
-        "email" => Ok(Value::String(internet::generate_email(rng, locale))),
+        "email" => Ok(Value::String(internet::generate_email_with_overrides(
+            rng, locale, overrides,
+        ))),
         "safe_email" => Ok(Value::String(internet::generate_safe_email(rng, locale))),
         "free_email" => Ok(Value::String(internet::generate_free_email(rng, locale))),
+        "company_email" => {
+            let val = internet::generate_company_email(rng, locale, None, None).map_err(|e| {
+                SchemaError {
+                    message: e.to_string(),
+                }
+            })?;
+            Ok(Value::String(val))
+        }
+        "username" => Ok(Value::String(internet::generate_username(
+            rng,
+            locale,
+            overrides,
+            &internet::UsernameOptions::default(),
+        ))),
 
         // Identifiers
         "uuid" => Ok(Value::String(identifiers::generate_uuid(rng))),
+        "uuid7" => {
+            let val = identifiers::generate_uuid7(
+                rng,
+                identifiers::DEFAULT_UUID7_START,
+                identifiers::DEFAULT_UUID7_END,
+            )
+            .map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?;
+            Ok(Value::String(val))
+        }
         "md5" => Ok(Value::String(identifiers::generate_md5(rng))),
         "sha256" => Ok(Value::String(identifiers::generate_sha256(rng))),
+        "sha1" => Ok(Value::String(identifiers::generate_sha1(rng))),
+        "sha512" => Ok(Value::String(identifiers::generate_sha512(rng))),
+
+        // Authentication
+        "jwt" => {
+            let val =
+                auth::generate_jwt(rng, &auth::JwtOptions::default()).map_err(|e| SchemaError {
+                    message: e.to_string(),
+                })?;
+            Ok(Value::String(val))
+        }
+        "totp_secret" => {
+            let val =
+                auth::generate_totp_secret(rng, auth::DEFAULT_TOTP_SECRET_LENGTH).map_err(|e| {
+                    SchemaError {
+                        message: e.to_string(),
+                    }
+                })?;
+            Ok(Value::String(val))
+        }
+        "totp_uri" => {
+            let val =
+                auth::generate_totp_uri(rng, locale, overrides, auth::DEFAULT_TOTP_SECRET_LENGTH)
+                    .map_err(|e| SchemaError {
+                    message: e.to_string(),
+                })?;
+            Ok(Value::String(val))
+        }
 
         // Numbers (defaults)
         "int" => Ok(Value::Int(numbers::generate_integer(rng, 0, 100).map_err(
@@ -515,22 +1531,95 @@ fn generate_simple_value(
                 message: e.to_string(),
             })?,
         )),
+        "bool" => Ok(Value::Bool(bool_value(rng, 0.5))),
 
         // Phone
         "phone" => Ok(Value::String(phone::generate_phone_number(rng, locale))),
 
+        // National ID
+        "national_id" => Ok(Value::String(national_id::generate_national_id(
+            rng, locale,
+        ))),
+
         // Address
-        "address" => Ok(Value::String(address::generate_address(rng, locale))),
-        "street_address" => Ok(Value::String(address::generate_street_address(rng, locale))),
-        "city" => Ok(Value::String(address::generate_city(rng, locale))),
+        "address" => Ok(Value::String(address::generate_address_with_overrides(
+            rng, locale, overrides,
+        ))),
+        "street_address" => Ok(Value::String(
+            address::generate_street_address_with_overrides(rng, locale, overrides),
+        )),
+        "city" => Ok(Value::String(address::generate_city_with_overrides(
+            rng, locale, overrides,
+        ))),
         "state" => Ok(Value::String(address::generate_state(rng, locale))),
         "country" => Ok(Value::String(address::generate_country(rng))),
+        "country_code" => Ok(Value::String(address::generate_country_with_format(
+            rng,
+            address::CountryFormat::Alpha2,
+        ))),
         "zip_code" => Ok(Value::String(address::generate_zip_code(rng, locale))),
+        "street_name" => Ok(Value::String(address::generate_street_name_with_overrides(
+            rng, locale, overrides,
+        ))),
+        "street_suffix" => Ok(Value::String(address::generate_street_suffix(rng, locale))),
+        "building_number" => Ok(Value::String(address::generate_building_number(rng))),
+        "state_abbr" => Ok(Value::String(address::generate_state_abbr(rng, locale))),
+        "secondary_unit" => Ok(Value::String(address::generate_secondary_unit(rng, locale))),
+        "po_box" => Ok(Value::String(address::generate_po_box(rng, locale))),
+
+        // Travel
+        "airport_code" => Ok(Value::String(travel::generate_airport_code(rng))),
+        "airport_icao_code" => Ok(Value::String(travel::generate_airport_icao_code(rng))),
+        "airport_name" => Ok(Value::String(travel::generate_airport_name(rng))),
+        "airport_city" => Ok(Value::String(travel::generate_airport_city(rng))),
+
+        // Language
+        "flight_number" => Ok(Value::String(travel::generate_flight_number(rng))),
+        "tracking_number" => Ok(Value::String(logistics::generate_tracking_number(
+            rng, None,
+        ))),
+        "language_code" => Ok(Value::String(language::generate_language_code(rng))),
+        "language_name" => Ok(Value::String(language::generate_language_name(rng))),
+
+        // File system
+        "file_name" => Ok(Value::String(filesystem::file_name_for(rng, None))),
+        "file_extension" => Ok(Value::String(
+            filesystem::extension_for(rng, None).to_string(),
+        )),
+        "file_path" => Ok(Value::String(filesystem::file_path_for(
+            rng,
+            filesystem::DEFAULT_PATH_DEPTH,
+            filesystem::PathStyle::Unix,
+            None,
+        ))),
+        "mime_type" => Ok(Value::String(
+            filesystem::mime_type_for(rng, None).to_string(),
+        )),
+
+        // Software
+        "semver" => Ok(Value::String(software::generate_semver(rng, false, false))),
+        "git_sha" => Ok(Value::String(software::generate_git_sha(rng, false))),
+        "commit_message" => Ok(Value::String(software::generate_commit_message(rng))),
+
+        // Cloud
+        "arn" => Ok(Value::String(cloud::generate_arn(rng, None))),
+        "s3_uri" => Ok(Value::String(cloud::generate_s3_uri(rng))),
+
+        // Logs
+        "access_log" => Ok(Value::String(logs::generate_access_log(rng))),
 
         // Company
         "company" => Ok(Value::String(company::generate_company(rng, locale))),
         "job" => Ok(Value::String(company::generate_job(rng, locale))),
         "catch_phrase" => Ok(Value::String(company::generate_catch_phrase(rng, locale))),
+        "ein" => Ok(Value::String(tax_id::generate_ein(rng))),
+        "company_tax_id" => Ok(Value::String(tax_id::generate_company_tax_id(rng, locale))),
+        "job_level" => Ok(Value::String(company::generate_job_level(rng))),
+        "job_area" => Ok(Value::String(company::generate_job_area(rng))),
+        "job_role" => Ok(Value::String(company::generate_job_role(rng))),
+        "industry" => Ok(Value::String(company::generate_industry(rng))),
+        "naics_code" => Ok(Value::String(company::generate_naics_code(rng))),
+        "sic_code" => Ok(Value::String(company::generate_sic_code(rng))),
 
         // Network
         "url" => Ok(Value::String(network::generate_url(rng))),
@@ -538,6 +1627,13 @@ fn generate_simple_value(
         "ipv4" => Ok(Value::String(network::generate_ipv4(rng))),
         "ipv6" => Ok(Value::String(network::generate_ipv6(rng))),
         "mac_address" => Ok(Value::String(network::generate_mac_address(rng))),
+        "http_method" => Ok(Value::String(network::generate_http_method(rng))),
+        "ipv4_private" => Ok(Value::String(network::generate_private_ipv4(rng))),
+        "ipv4_public" => Ok(Value::String(network::generate_public_ipv4(rng))),
+        "cidr" => {
+            let (min, max) = network::DEFAULT_CIDR_PREFIX_RANGE;
+            Ok(Value::String(network::cidr(rng, min, max)))
+        }
 
         // Colors
         "color" => Ok(Value::String(colors::generate_color(rng, locale))),
@@ -546,10 +1642,56 @@ fn generate_simple_value(
             let (r, g, b) = colors::generate_rgb_color(rng);
             Ok(Value::Tuple3U8(r, g, b))
         }
+        "hsl_color" => Ok(hsl_value(colors::generate_hsl_color(rng))),
+        "cmyk_color" => {
+            let (c, m, y, k) = colors::generate_cmyk_color(rng);
+            Ok(Value::Tuple4U8(c, m, y, k))
+        }
+        "coordinates" => {
+            let (lat, lon) = geo::generate_coordinate(rng, geo::DEFAULT_COORDINATE_PRECISION);
+            Ok(Value::Tuple2F64(lat, lon))
+        }
+        "company_profile" => Ok(company_profile_value(rng, locale, overrides)),
+        "address_parts" => Ok(address_parts_value(rng, locale, overrides, 0.0)),
+        "access_log_entry" => Ok(access_log_entry_value(rng)),
+        "syslog_entry" => Ok(syslog_entry_value(rng)),
 
         // Finance
         "credit_card" => Ok(Value::String(finance::generate_credit_card(rng))),
         "iban" => Ok(Value::String(finance::generate_iban(rng))),
+        "routing_number" => Ok(Value::String(finance::generate_routing_number(rng))),
+        "sku" => Ok(Value::String(commerce::generate_sku(rng, None))),
+        "product_name" => Ok(Value::String(commerce::generate_product_name(rng))),
+        "currency_code" => Ok(Value::String(finance::generate_currency_code(rng))),
+        "currency_name" => Ok(Value::String(finance::generate_currency_name(rng))),
+        "currency_symbol" => Ok(Value::String(finance::generate_currency_symbol(rng))),
+        "ticker" => Ok(Value::String(finance::generate_ticker(rng))),
+        "stock_exchange" => Ok(Value::String(finance::generate_stock_exchange(rng))),
+        "isbn13" => Ok(Value::String(codes::generate_isbn13(rng))),
+        "isbn10" => Ok(Value::String(codes::generate_isbn10(rng))),
+        "imei" => Ok(Value::String(codes::generate_imei(rng))),
+        "doi" => Ok(Value::String(codes::generate_doi(rng))),
+        "orcid" => Ok(Value::String(codes::generate_orcid(rng))),
+        "vin" => Ok(Value::String(vehicle::generate_vin(rng))),
+        "vehicle_make" => Ok(Value::String(vehicle::generate_vehicle_make(rng))),
+        "vehicle_model" => Ok(Value::String(vehicle::generate_vehicle_model(rng))),
+        "blood_type" => Ok(Value::String(medical::generate_blood_type(rng))),
+        "allergy" => Ok(Value::String(medical::generate_allergy(rng))),
+        "medication" => Ok(Value::String(medical::generate_medication(rng))),
+        "icd10_code" => Ok(Value::String(medical::generate_icd10_code(rng))),
+        "npi" => Ok(Value::String(medical::generate_npi(rng))),
+        "vat_number" => Ok(Value::String(
+            finance::generate_vat_number(rng, locale, None).map_err(|e| SchemaError {
+                message: e.to_string(),
+            })?,
+        )),
+        "drivers_license" => Ok(Value::String(
+            drivers_license::generate_drivers_license(rng, locale, None).map_err(|e| {
+                SchemaError {
+                    message: e.to_string(),
+                }
+            })?,
+        )),
 
         // DateTime (defaults)
         "date" => {
@@ -569,6 +1711,18 @@ fn generate_simple_value(
                 })?;
             Ok(Value::String(val))
         }
+        "iso_week" => Ok(Value::String(period_value(
+            rng,
+            datetime::ReportingPeriod::IsoWeek,
+        ))),
+        "quarter" => Ok(Value::String(period_value(
+            rng,
+            datetime::ReportingPeriod::Quarter,
+        ))),
+        "month" => Ok(Value::String(period_value(
+            rng,
+            datetime::ReportingPeriod::Month,
+        ))),
 
         // Text (defaults)
         "sentence" => Ok(Value::String(text::generate_sentence(rng, locale, 10))),
@@ -597,7 +1751,14 @@ pub fn generate_records(
     schema: &BTreeMap<String, FieldSpec>,
 ) -> Result<Vec<BTreeMap<String, Value>>, SchemaError> {
     // Delegate to the custom-aware version with empty providers map
-    generate_records_with_custom(rng, locale, n, schema, &HashMap::new())
+    generate_records_with_custom(
+        rng,
+        locale,
+        &LocaleOverrides::EMPTY,
+        n,
+        schema,
+        &HashMap::new(),
+    )
 }
 
 /// Generate records as tuples based on a schema.
@@ -612,16 +1773,26 @@ pub fn generate_records_tuples(
     field_order: &[String],
 ) -> Result<Vec<Vec<Value>>, SchemaError> {
     // Delegate to the custom-aware version with empty providers map
-    generate_records_tuples_with_custom(rng, locale, n, schema, field_order, &HashMap::new())
+    generate_records_tuples_with_custom(
+        rng,
+        locale,
+        &LocaleOverrides::EMPTY,
+        n,
+        schema,
+        field_order,
+        &HashMap::new(),
+    )
 }
 
 /// Generate records based on a schema, with custom provider support.
 ///
 /// This variant of generate_records() can handle FieldSpec::Custom variants
-/// by looking up providers in the provided custom_providers map.
+/// by looking up providers in the provided custom_providers map, and draws
+/// names, cities and streets from `overrides` where set.
 pub fn generate_records_with_custom(
     rng: &mut ForgeryRng,
     locale: Locale,
+    overrides: &LocaleOverrides,
     n: usize,
     schema: &BTreeMap<String, FieldSpec>,
     custom_providers: &HashMap<String, CustomProvider>,
@@ -634,7 +1805,7 @@ pub fn generate_records_with_custom(
     for _ in 0..n {
         let mut record = BTreeMap::new();
         for (field_name, spec) in schema {
-            let value = generate_value_with_custom(rng, locale, spec, custom_providers)?;
+            let value = generate_value_with_custom(rng, locale, overrides, spec, custom_providers)?;
             record.insert(field_name.clone(), value);
         }
         records.push(record);
@@ -645,10 +1816,12 @@ pub fn generate_records_with_custom(
 
 /// Generate records as tuples based on a schema, with custom provider support.
 ///
-/// This variant of generate_records_tuples() can handle FieldSpec::Custom variants.
+/// This variant of generate_records_tuples() can handle FieldSpec::Custom variants
+/// and draws names, cities and streets from `overrides` where set.
 pub fn generate_records_tuples_with_custom(
     rng: &mut ForgeryRng,
     locale: Locale,
+    overrides: &LocaleOverrides,
     n: usize,
     schema: &BTreeMap<String, FieldSpec>,
     field_order: &[String],
@@ -696,7 +1869,7 @@ pub fn generate_records_tuples_with_custom(
             let spec = schema
                 .get(field_name)
                 .expect("field_name was validated to exist in schema");
-            let value = generate_value_with_custom(rng, locale, spec, custom_providers)?;
+            let value = generate_value_with_custom(rng, locale, overrides, spec, custom_providers)?;
             record.push(value);
         }
         records.push(record);
@@ -710,23 +1883,56 @@ pub fn generate_records_tuples_with_custom(
 // ============================================================================
 
 use arrow_array::{
-    ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray, StructArray, UInt8Array,
+    make_array, Array, ArrayRef, BinaryArray, BooleanArray, Decimal128Array, Float64Array,
+    Int64Array, RecordBatch, StringArray, StructArray, Time64MicrosecondArray, UInt16Array,
+    UInt32Array, UInt64Array, UInt8Array,
 };
 use arrow_buffer::NullBuffer;
-use arrow_schema::{DataType, Field, Schema};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
 use std::sync::Arc;
 
+/// Decimal precision of retail price columns (up to 9999.99 with room to spare).
+const RETAIL_PRICE_PRECISION: u8 = 10;
+
 /// Determine the Arrow DataType for a given FieldSpec.
 ///
 /// Most field types map to Utf8 (strings), but integers and floats
 /// have their own types, and RGB colors are stored as a Struct.
 pub fn field_spec_to_arrow_type(spec: &FieldSpec) -> DataType {
     match spec {
+        // Nullable fields keep the type of the wrapped field
+        FieldSpec::Nullable { inner, .. } => field_spec_to_arrow_type(inner),
+
         // Integer types
         FieldSpec::Int | FieldSpec::IntRange { .. } => DataType::Int64,
+        FieldSpec::Port { .. } | FieldSpec::HttpStatusCode { .. } => DataType::UInt16,
+        FieldSpec::Duration {
+            format: duration::DurationFormat::Seconds,
+            ..
+        } => DataType::UInt64,
+
+        // Booleans have their own type
+        FieldSpec::Bool { .. } => DataType::Boolean,
+
+        // Times of day are microseconds since midnight
+        FieldSpec::Time => DataType::Time64(TimeUnit::Microsecond),
+
+        // Random bytes are stored as variable-length binary
+        FieldSpec::Bytes { .. } => DataType::Binary,
 
         // Float types
-        FieldSpec::Float | FieldSpec::FloatRange { .. } => DataType::Float64,
+        FieldSpec::Float | FieldSpec::FloatRange { .. } | FieldSpec::Height | FieldSpec::Weight => {
+            DataType::Float64
+        }
+        FieldSpec::Price {
+            formatted: false, ..
+        } => DataType::Float64,
+        FieldSpec::RetailPrice { decimal: false, .. } => DataType::Float64,
+
+        // Decimal retail prices keep exact cents
+        FieldSpec::RetailPrice { decimal: true, .. } => {
+            DataType::Decimal128(RETAIL_PRICE_PRECISION, commerce::RETAIL_PRICE_SCALE as i8)
+        }
 
         // RGB colors are stored as a struct with r, g, b uint8 fields
         FieldSpec::RgbColor => DataType::Struct(
@@ -738,11 +1944,100 @@ pub fn field_spec_to_arrow_type(spec: &FieldSpec) -> DataType {
             .into(),
         ),
 
+        // Coordinates are stored as a struct with lat, lon float64 fields
+        FieldSpec::Coordinates => DataType::Struct(
+            vec![
+                Field::new("lat", DataType::Float64, false),
+                Field::new("lon", DataType::Float64, false),
+            ]
+            .into(),
+        ),
+
+        // HSL and CMYK colors are stored as structs of their components
+        FieldSpec::HslColor => DataType::Struct(hsl_arrow_fields().into()),
+        FieldSpec::CmykColor => DataType::Struct(cmyk_arrow_fields().into()),
+
+        // Dates of birth with age are stored as a struct of the date string and age
+        FieldSpec::DateOfBirthWithAge { .. } => {
+            DataType::Struct(date_of_birth_with_age_arrow_fields().into())
+        }
+
+        // Company profiles are stored as a struct of string fields
+        FieldSpec::CompanyProfile => DataType::Struct(company_profile_arrow_fields().into()),
+
+        // Address parts are stored as a struct of string fields
+        FieldSpec::AddressParts { .. } => DataType::Struct(address_parts_arrow_fields().into()),
+
+        // Access log entries are stored as a struct of string fields
+        FieldSpec::AccessLogEntry => DataType::Struct(access_log_arrow_fields().into()),
+
+        // Syslog entries are stored as a struct of string fields
+        FieldSpec::SyslogEntry => DataType::Struct(syslog_arrow_fields().into()),
+
         // All other types produce strings
         _ => DataType::Utf8,
     }
 }
 
+/// Arrow struct fields of an HSL color column.
+fn hsl_arrow_fields() -> Vec<Field> {
+    vec![
+        Field::new("h", DataType::UInt16, false),
+        Field::new("s", DataType::UInt8, false),
+        Field::new("l", DataType::UInt8, false),
+    ]
+}
+
+/// Arrow struct fields of a CMYK color column.
+fn cmyk_arrow_fields() -> Vec<Field> {
+    vec![
+        Field::new("c", DataType::UInt8, false),
+        Field::new("m", DataType::UInt8, false),
+        Field::new("y", DataType::UInt8, false),
+        Field::new("k", DataType::UInt8, false),
+    ]
+}
+
+/// Arrow struct fields of a date of birth with age column.
+fn date_of_birth_with_age_arrow_fields() -> Vec<Field> {
+    vec![
+        Field::new("date_of_birth", DataType::Utf8, false),
+        Field::new("age", DataType::UInt32, false),
+    ]
+}
+
+/// Arrow struct fields of a company profile column.
+fn company_profile_arrow_fields() -> Vec<Field> {
+    company::COMPANY_PROFILE_FIELDS
+        .iter()
+        .map(|name| Field::new(*name, DataType::Utf8, false))
+        .collect()
+}
+
+/// Arrow struct fields of an address parts column.
+fn address_parts_arrow_fields() -> Vec<Field> {
+    address::ADDRESS_PARTS_FIELDS
+        .iter()
+        .map(|name| Field::new(*name, DataType::Utf8, false))
+        .collect()
+}
+
+/// Arrow struct fields of an access log entry column.
+fn access_log_arrow_fields() -> Vec<Field> {
+    logs::ACCESS_LOG_FIELDS
+        .iter()
+        .map(|name| Field::new(*name, DataType::Utf8, false))
+        .collect()
+}
+
+/// Arrow struct fields of a syslog entry column.
+fn syslog_arrow_fields() -> Vec<Field> {
+    logs::SYSLOG_FIELDS
+        .iter()
+        .map(|name| Field::new(*name, DataType::Utf8, false))
+        .collect()
+}
+
 /// Generate records as an Arrow RecordBatch.
 ///
 /// This is the high-performance path for generating structured data
@@ -760,16 +2055,25 @@ pub fn generate_records_arrow(
     schema: &BTreeMap<String, FieldSpec>,
 ) -> Result<RecordBatch, SchemaError> {
     // Delegate to the custom-aware version with empty providers map
-    generate_records_arrow_with_custom(rng, locale, n, schema, &HashMap::new())
+    generate_records_arrow_with_custom(
+        rng,
+        locale,
+        &LocaleOverrides::EMPTY,
+        n,
+        schema,
+        &HashMap::new(),
+    )
 }
 
 /// Generate records as an Arrow RecordBatch, with custom provider support.
 ///
 /// This variant of generate_records_arrow() can handle FieldSpec::Custom variants
-/// by looking up providers in the provided custom_providers map.
+/// by looking up providers in the provided custom_providers map, and draws
+/// names, cities and streets from `overrides` where set.
 pub fn generate_records_arrow_with_custom(
     rng: &mut ForgeryRng,
     locale: Locale,
+    overrides: &LocaleOverrides,
     n: usize,
     schema: &BTreeMap<String, FieldSpec>,
     custom_providers: &HashMap<String, CustomProvider>,
@@ -783,7 +2087,8 @@ pub fn generate_records_arrow_with_custom(
 
     for (name, spec) in schema.iter() {
         let arrow_type = field_spec_to_arrow_type(spec);
-        arrow_fields.push(Field::new(name, arrow_type, false));
+        let nullable = matches!(spec, FieldSpec::Nullable { .. });
+        arrow_fields.push(Field::new(name, arrow_type, nullable));
         field_specs.push(spec);
     }
 
@@ -793,7 +2098,7 @@ pub fn generate_records_arrow_with_custom(
     let mut columns: Vec<ArrayRef> = Vec::with_capacity(schema.len());
 
     for spec in field_specs.iter() {
-        let column = generate_arrow_column(rng, locale, n, spec, custom_providers)?;
+        let column = generate_arrow_column(rng, locale, overrides, n, spec, custom_providers)?;
         columns.push(column);
     }
 
@@ -807,6 +2112,7 @@ pub fn generate_records_arrow_with_custom(
 fn generate_arrow_column(
     rng: &mut ForgeryRng,
     locale: Locale,
+    overrides: &LocaleOverrides,
     n: usize,
     spec: &FieldSpec,
     custom_providers: &HashMap<String, CustomProvider>,
@@ -832,6 +2138,20 @@ fn generate_arrow_column(
                 .collect();
             Ok(Arc::new(Int64Array::from(values)))
         }
+        FieldSpec::Port { kind } => Ok(Arc::new(UInt16Array::from(network::generate_ports(
+            rng, n, *kind,
+        )))),
+        FieldSpec::HttpStatusCode { class } => Ok(Arc::new(UInt16Array::from(
+            network::generate_http_status_codes(rng, n, *class),
+        ))),
+        FieldSpec::Duration {
+            min,
+            max,
+            format: duration::DurationFormat::Seconds,
+        } => Ok(Arc::new(UInt64Array::from(
+            duration::generate_durations(rng, n, *min, *max)
+                .expect("range validated in validate_spec"),
+        ))),
 
         // Float types -> Float64Array
         // Note: Ranges are validated in validate_spec() before generation, so these can't fail
@@ -853,6 +2173,53 @@ fn generate_arrow_column(
                 .collect();
             Ok(Arc::new(Float64Array::from(values)))
         }
+        FieldSpec::Price {
+            min,
+            max,
+            currency,
+            formatted: false,
+        } => {
+            let values = finance::generate_prices(rng, n, *min, *max, *currency)
+                .expect("range validated in validate_spec");
+            Ok(Arc::new(Float64Array::from(values)))
+        }
+        FieldSpec::RetailPrice { tier, decimal } => {
+            if *decimal {
+                let values: Vec<i128> = (0..n)
+                    .map(|_| i128::from(commerce::generate_retail_price_cents(rng, *tier)))
+                    .collect();
+                let array = Decimal128Array::from(values)
+                    .with_precision_and_scale(
+                        RETAIL_PRICE_PRECISION,
+                        commerce::RETAIL_PRICE_SCALE as i8,
+                    )
+                    .expect("retail prices fit the decimal precision");
+                Ok(Arc::new(array))
+            } else {
+                Ok(Arc::new(Float64Array::from(
+                    commerce::generate_retail_prices(rng, n, *tier),
+                )))
+            }
+        }
+        FieldSpec::Bool { true_rate } => {
+            let values: Vec<bool> = (0..n).map(|_| bool_value(rng, *true_rate)).collect();
+            Ok(Arc::new(BooleanArray::from(values)))
+        }
+        FieldSpec::Time => {
+            let values: Vec<i64> = (0..n)
+                .map(|_| datetime::generate_time_micros(rng))
+                .collect();
+            Ok(Arc::new(Time64MicrosecondArray::from(values)))
+        }
+        FieldSpec::Bytes { length } => Ok(Arc::new(BinaryArray::from_iter_values(
+            binary::generate_byte_strings(rng, n, *length),
+        ))),
+        FieldSpec::Height => Ok(Arc::new(Float64Array::from(medical::generate_heights(
+            rng, n,
+        )))),
+        FieldSpec::Weight => Ok(Arc::new(Float64Array::from(medical::generate_weights(
+            rng, n,
+        )))),
 
         // RGB color -> Struct with r, g, b UInt8 fields
         FieldSpec::RgbColor => {
@@ -886,11 +2253,203 @@ fn generate_arrow_column(
             Ok(Arc::new(struct_array))
         }
 
+        // HSL color -> Struct with h UInt16 and s, l UInt8 fields
+        FieldSpec::HslColor => {
+            let colors = colors::generate_hsl_colors(rng, n);
+            let h_array = Arc::new(UInt16Array::from_iter_values(colors.iter().map(|c| c.0)));
+            let s_array = Arc::new(UInt8Array::from_iter_values(colors.iter().map(|c| c.1)));
+            let l_array = Arc::new(UInt8Array::from_iter_values(colors.iter().map(|c| c.2)));
+            Ok(Arc::new(StructArray::new(
+                hsl_arrow_fields().into(),
+                vec![h_array, s_array, l_array],
+                None::<NullBuffer>,
+            )))
+        }
+
+        // CMYK color -> Struct with c, m, y, k UInt8 fields
+        FieldSpec::CmykColor => {
+            let colors = colors::generate_cmyk_colors(rng, n);
+            let c_array = Arc::new(UInt8Array::from_iter_values(colors.iter().map(|c| c.0)));
+            let m_array = Arc::new(UInt8Array::from_iter_values(colors.iter().map(|c| c.1)));
+            let y_array = Arc::new(UInt8Array::from_iter_values(colors.iter().map(|c| c.2)));
+            let k_array = Arc::new(UInt8Array::from_iter_values(colors.iter().map(|c| c.3)));
+            Ok(Arc::new(StructArray::new(
+                cmyk_arrow_fields().into(),
+                vec![c_array, m_array, y_array, k_array],
+                None::<NullBuffer>,
+            )))
+        }
+
+        // Coordinates -> Struct with lat, lon Float64 fields
+        FieldSpec::Coordinates => {
+            let mut lat_values: Vec<f64> = Vec::with_capacity(n);
+            let mut lon_values: Vec<f64> = Vec::with_capacity(n);
+
+            for _ in 0..n {
+                let (lat, lon) = geo::generate_coordinate(rng, geo::DEFAULT_COORDINATE_PRECISION);
+                lat_values.push(lat);
+                lon_values.push(lon);
+            }
+
+            let lat_array = Arc::new(Float64Array::from(lat_values)) as ArrayRef;
+            let lon_array = Arc::new(Float64Array::from(lon_values)) as ArrayRef;
+
+            let struct_fields: Vec<Field> = vec![
+                Field::new("lat", DataType::Float64, false),
+                Field::new("lon", DataType::Float64, false),
+            ];
+
+            let struct_array = StructArray::new(
+                struct_fields.into(),
+                vec![lat_array, lon_array],
+                None::<NullBuffer>,
+            );
+
+            Ok(Arc::new(struct_array))
+        }
+
+        // Date of birth with age -> Struct with date_of_birth Utf8 and age UInt32 fields
+        FieldSpec::DateOfBirthWithAge { min_age, max_age } => {
+            let pairs = datetime::generate_dates_of_birth_with_age(rng, n, *min_age, *max_age)
+                .expect("age range validated in validate_spec");
+            let dob_array =
+                Arc::new(StringArray::from_iter_values(pairs.iter().map(|p| &p.0))) as ArrayRef;
+            let age_array = Arc::new(UInt32Array::from_iter_values(pairs.iter().map(|p| p.1)));
+            Ok(Arc::new(StructArray::new(
+                date_of_birth_with_age_arrow_fields().into(),
+                vec![dob_array, age_array],
+                None::<NullBuffer>,
+            )))
+        }
+
+        // Company profile -> Struct with one Utf8 field per profile field
+        FieldSpec::CompanyProfile => {
+            let mut columns: Vec<Vec<String>> = company::COMPANY_PROFILE_FIELDS
+                .iter()
+                .map(|_| Vec::with_capacity(n))
+                .collect();
+
+            for _ in 0..n {
+                let profile = company::generate_company_profile(rng, locale, overrides);
+                for (column, value) in columns.iter_mut().zip(profile.into_values()) {
+                    column.push(value);
+                }
+            }
+
+            let arrays: Vec<ArrayRef> = columns
+                .into_iter()
+                .map(|values| Arc::new(StringArray::from(values)) as ArrayRef)
+                .collect();
+
+            let struct_array = StructArray::new(
+                company_profile_arrow_fields().into(),
+                arrays,
+                None::<NullBuffer>,
+            );
+
+            Ok(Arc::new(struct_array))
+        }
+
+        // Address parts -> Struct with one Utf8 field per component
+        FieldSpec::AddressParts { unit_rate } => {
+            let mut columns: Vec<Vec<String>> = address::ADDRESS_PARTS_FIELDS
+                .iter()
+                .map(|_| Vec::with_capacity(n))
+                .collect();
+
+            for _ in 0..n {
+                let parts = address::address_parts(rng, locale, overrides, *unit_rate);
+                for (column, value) in columns.iter_mut().zip(parts.into_values()) {
+                    column.push(value);
+                }
+            }
+
+            let arrays: Vec<ArrayRef> = columns
+                .into_iter()
+                .map(|values| Arc::new(StringArray::from(values)) as ArrayRef)
+                .collect();
+
+            Ok(Arc::new(StructArray::new(
+                address_parts_arrow_fields().into(),
+                arrays,
+                None::<NullBuffer>,
+            )))
+        }
+
+        // Access log entry -> Struct with one Utf8 field per entry field
+        FieldSpec::AccessLogEntry => {
+            let mut columns: Vec<Vec<String>> = logs::ACCESS_LOG_FIELDS
+                .iter()
+                .map(|_| Vec::with_capacity(n))
+                .collect();
+
+            for _ in 0..n {
+                let entry = logs::generate_access_log_entry(rng);
+                for (column, value) in columns.iter_mut().zip(entry.into_values()) {
+                    column.push(value);
+                }
+            }
+
+            let arrays: Vec<ArrayRef> = columns
+                .into_iter()
+                .map(|values| Arc::new(StringArray::from(values)) as ArrayRef)
+                .collect();
+
+            let struct_array =
+                StructArray::new(access_log_arrow_fields().into(), arrays, None::<NullBuffer>);
+
+            Ok(Arc::new(struct_array))
+        }
+
+        // Syslog entry -> Struct with one Utf8 field per entry field
+        FieldSpec::SyslogEntry => {
+            let mut columns: Vec<Vec<String>> = logs::SYSLOG_FIELDS
+                .iter()
+                .map(|_| Vec::with_capacity(n))
+                .collect();
+
+            for _ in 0..n {
+                let entry = logs::generate_syslog_entry(rng);
+                for (column, value) in columns.iter_mut().zip(entry.into_values()) {
+                    column.push(value);
+                }
+            }
+
+            let arrays: Vec<ArrayRef> = columns
+                .into_iter()
+                .map(|values| Arc::new(StringArray::from(values)) as ArrayRef)
+                .collect();
+
+            let struct_array =
+                StructArray::new(syslog_arrow_fields().into(), arrays, None::<NullBuffer>);
+
+            Ok(Arc::new(struct_array))
+        }
+
+        // Nullable -> the wrapped column with a validity bitmap
+        FieldSpec::Nullable { inner, null_rate } => {
+            let column = generate_arrow_column(rng, locale, overrides, n, inner, custom_providers)?;
+            if *null_rate <= 0.0 {
+                return Ok(column);
+            }
+            let validity: NullBuffer = (0..n).map(|_| !is_null(rng, *null_rate)).collect();
+            let validity = NullBuffer::union(column.nulls(), Some(&validity));
+            let data = column
+                .to_data()
+                .into_builder()
+                .nulls(validity)
+                .build()
+                .map_err(|e| SchemaError {
+                    message: format!("Failed to apply nulls: {}", e),
+                })?;
+            Ok(make_array(data))
+        }
+
         // All other types produce string arrays
         _ => {
             let values: Result<Vec<String>, SchemaError> = (0..n)
                 .map(|_| {
-                    generate_value_with_custom(rng, locale, spec, custom_providers)
+                    generate_value_with_custom(rng, locale, overrides, spec, custom_providers)
                         .map(|v| v.as_string())
                 })
                 .collect();
@@ -1050,7 +2609,10 @@ mod tests {
             "email",
             "safe_email",
             "free_email",
+            "company_email",
+            "username",
             "uuid",
+            "uuid7",
             "phone",
             "address",
             "street_address",
@@ -1058,6 +2620,14 @@ mod tests {
             "state",
             "country",
             "zip_code",
+            "country_code",
+            "street_name",
+            "street_suffix",
+            "building_number",
+            "state_abbr",
+            "secondary_unit",
+            "po_box",
+            "county",
             "company",
             "job",
             "catch_phrase",
@@ -1066,6 +2636,12 @@ mod tests {
             "ipv4",
             "ipv6",
             "mac_address",
+            "ipv4_private",
+            "ipv4_public",
+            "cidr",
+            "port",
+            "http_method",
+            "http_status_code",
             "color",
             "hex_color",
             "credit_card",
@@ -1074,9 +2650,105 @@ mod tests {
             "datetime",
             "md5",
             "sha256",
+            "sha1",
+            "sha512",
             "sentence",
             "paragraph",
             "text",
+            "national_id",
+            "vat_number",
+            "drivers_license",
+            "routing_number",
+            "price",
+            "invoice_number",
+            "policy_number",
+            "claim_number",
+            "sku",
+            "product_name",
+            "retail_price",
+            "currency_code",
+            "currency_name",
+            "currency_symbol",
+            "ticker",
+            "stock_exchange",
+            "isbn13",
+            "isbn10",
+            "imei",
+            "doi",
+            "orcid",
+            "vin",
+            "airport_code",
+            "airport_icao_code",
+            "airport_name",
+            "airport_city",
+            "flight_number",
+            "tracking_number",
+            "language_code",
+            "language_name",
+            "file_name",
+            "file_extension",
+            "file_path",
+            "mime_type",
+            "semver",
+            "git_sha",
+            "commit_message",
+            "docker_image",
+            "k8s_name",
+            "arn",
+            "s3_uri",
+            "cloud_region",
+            "access_log",
+            "access_log_entry",
+            "syslog",
+            "syslog_entry",
+            "coordinates",
+            "ein",
+            "company_tax_id",
+            "password_hash",
+            "jwt",
+            "api_key",
+            "session_id",
+            "oauth_access_token",
+            "refresh_token",
+            "totp_secret",
+            "totp_uri",
+            "bytes",
+            "base64",
+            "company_profile",
+            "address_parts",
+            "industry",
+            "naics_code",
+            "sic_code",
+            "job_level",
+            "job_area",
+            "job_role",
+            "age",
+            "gender",
+            "pronouns",
+            "marital_status",
+            "blood_type",
+            "height",
+            "weight",
+            "allergy",
+            "medication",
+            "icd10_code",
+            "npi",
+            "vehicle_make",
+            "vehicle_model",
+            "time",
+            "date_of_birth_with_age",
+            "iso_week",
+            "quarter",
+            "month",
+            "duration",
+            "hsl_color",
+            "cmyk_color",
+            "css_color",
+            "business_date",
+            "recent",
+            "past",
+            "future",
+            "bool",
         ];
 
         for type_name in types {
@@ -1144,6 +2816,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_business_date_spec() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = FieldSpec::BusinessDate {
+            start: "2024-07-01".to_string(),
+            end: "2024-07-07".to_string(),
+            holidays: vec!["2024-07-04".to_string()],
+        };
+        assert!(validate_spec(&spec).is_ok());
+        for _ in 0..50 {
+            match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+                Value::String(date) => {
+                    assert!(["2024-07-01", "2024-07-02", "2024-07-03", "2024-07-05"]
+                        .contains(&date.as_str()))
+                }
+                other => panic!("expected string, got {:?}", other),
+            }
+        }
+
+        let empty = FieldSpec::BusinessDate {
+            start: "2024-07-06".to_string(),
+            end: "2024-07-07".to_string(),
+            holidays: Vec::new(),
+        };
+        assert!(validate_spec(&empty).is_err());
+    }
+
     #[test]
     fn test_choice() {
         let mut rng = ForgeryRng::new();
@@ -1370,6 +3071,36 @@ mod tests {
         assert_eq!(arrow_type, DataType::Utf8);
     }
 
+    #[test]
+    fn test_price_spec_numeric_and_formatted() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let mut schema = BTreeMap::new();
+        schema.insert(
+            "amount".to_string(),
+            FieldSpec::Price {
+                min: 1.0,
+                max: 10.0,
+                currency: finance::Currency::USD,
+                formatted: false,
+            },
+        );
+        schema.insert("label".to_string(), parse_simple_type("price").unwrap());
+
+        assert_eq!(
+            field_spec_to_arrow_type(&schema["amount"]),
+            DataType::Float64
+        );
+        assert_eq!(field_spec_to_arrow_type(&schema["label"]), DataType::Utf8);
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 20, &schema).unwrap();
+        assert_eq!(*batch.column(0).data_type(), DataType::Float64);
+        for record in generate_records(&mut rng, Locale::EnUS, 20, &schema).unwrap() {
+            assert!(matches!(record["amount"], Value::Float(v) if (1.0..=10.0).contains(&v)));
+            assert!(matches!(&record["label"], Value::String(s) if s.starts_with('$')));
+        }
+    }
+
     #[test]
     fn test_field_spec_to_arrow_type_rgb() {
         let spec = FieldSpec::RgbColor;
@@ -1418,31 +3149,171 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_arrow_column_float() {
+    fn test_port_type() {
         let mut rng = ForgeryRng::new();
         rng.seed(42);
 
-        let mut schema = BTreeMap::new();
-        schema.insert("value".to_string(), FieldSpec::Float);
-
-        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 10, &schema).unwrap();
-        assert_eq!(batch.num_rows(), 10);
+        let spec = FieldSpec::Port {
+            kind: network::PortKind::WellKnown,
+        };
+        for _ in 0..100 {
+            assert!(matches!(
+                generate_value(&mut rng, Locale::EnUS, &spec).unwrap(),
+                Value::Int(p) if (1..=1023).contains(&p)
+            ));
+        }
 
-        let column = batch.column(0);
-        assert_eq!(*column.data_type(), DataType::Float64);
+        let mut schema = BTreeMap::new();
+        schema.insert("port".to_string(), parse_simple_type("port").unwrap());
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 50, &schema).unwrap();
+        assert_eq!(*batch.column(0).data_type(), DataType::UInt16);
+        let column = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<UInt16Array>()
+            .unwrap();
+        assert!(column.values().iter().all(|&p| p != 0));
     }
 
     #[test]
-    fn test_generate_arrow_column_float_range() {
+    fn test_http_status_code_type() {
         let mut rng = ForgeryRng::new();
         rng.seed(42);
 
+        let spec = FieldSpec::HttpStatusCode {
+            class: Some(network::StatusClass::ServerError),
+        };
+        for _ in 0..100 {
+            assert!(matches!(
+                generate_value(&mut rng, Locale::EnUS, &spec).unwrap(),
+                Value::Int(c) if (500..600).contains(&c)
+            ));
+        }
+
         let mut schema = BTreeMap::new();
         schema.insert(
-            "value".to_string(),
-            FieldSpec::FloatRange {
-                min: 1.0,
-                max: 10.0,
+            "status".to_string(),
+            parse_simple_type("http_status_code").unwrap(),
+        );
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 50, &schema).unwrap();
+        assert_eq!(*batch.column(0).data_type(), DataType::UInt16);
+    }
+
+    #[test]
+    fn test_job_with_level_type() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = FieldSpec::Job {
+            level: Some(company::JobLevel::Senior),
+        };
+        for _ in 0..50 {
+            match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+                Value::String(job) => assert!(job.starts_with("Senior "), "{}", job),
+                other => panic!("expected string, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_time_type() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert("at".to_string(), parse_simple_type("time").unwrap());
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 20, &schema).unwrap();
+        assert_eq!(
+            *batch.column(0).data_type(),
+            DataType::Time64(TimeUnit::Microsecond)
+        );
+
+        let records = generate_records(&mut rng, Locale::EnUS, 20, &schema).unwrap();
+        for record in &records {
+            let time = record["at"].as_string();
+            assert!(chrono::NaiveTime::parse_from_str(&time, "%H:%M:%S").is_ok());
+        }
+    }
+
+    #[test]
+    fn test_relative_datetime_types() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let before = datetime::utc_now();
+        let mut schema = BTreeMap::new();
+        schema.insert("seen".to_string(), parse_simple_type("recent").unwrap());
+        schema.insert("due".to_string(), parse_simple_type("future").unwrap());
+        let records = generate_records(&mut rng, Locale::EnUS, 50, &schema).unwrap();
+        let after = datetime::utc_now();
+        for record in &records {
+            let seen = chrono::NaiveDateTime::parse_from_str(
+                &record["seen"].as_string(),
+                "%Y-%m-%dT%H:%M:%S",
+            )
+            .unwrap();
+            let due = chrono::NaiveDateTime::parse_from_str(
+                &record["due"].as_string(),
+                "%Y-%m-%dT%H:%M:%S",
+            )
+            .unwrap();
+            assert!(seen <= after && seen >= before - chrono::TimeDelta::days(7));
+            assert!(due >= before);
+        }
+    }
+
+    #[test]
+    fn test_relative_datetime_out_of_range_rejected() {
+        let spec = FieldSpec::RelativeDateTime {
+            range: datetime::RelativeRange::Past(u32::MAX),
+        };
+        assert!(validate_spec(&spec).is_err());
+    }
+
+    #[test]
+    fn test_height_weight_are_float64() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert("height".to_string(), parse_simple_type("height").unwrap());
+        schema.insert("weight".to_string(), parse_simple_type("weight").unwrap());
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 20, &schema).unwrap();
+        assert_eq!(*batch.column(0).data_type(), DataType::Float64);
+        assert_eq!(*batch.column(1).data_type(), DataType::Float64);
+
+        let records = generate_records(&mut rng, Locale::EnUS, 20, &schema).unwrap();
+        assert!(records
+            .iter()
+            .all(|r| matches!(r["height"], Value::Float(h) if (140.0..=210.0).contains(&h))));
+    }
+
+    #[test]
+    fn test_generate_arrow_column_float() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert("value".to_string(), FieldSpec::Float);
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 10, &schema).unwrap();
+        assert_eq!(batch.num_rows(), 10);
+
+        let column = batch.column(0);
+        assert_eq!(*column.data_type(), DataType::Float64);
+    }
+
+    #[test]
+    fn test_generate_arrow_column_float_range() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert(
+            "value".to_string(),
+            FieldSpec::FloatRange {
+                min: 1.0,
+                max: 10.0,
             },
         );
 
@@ -1475,6 +3346,467 @@ mod tests {
         assert!(matches!(column.data_type(), DataType::Struct(_)));
     }
 
+    #[test]
+    fn test_hsl_cmyk_color_types() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let hsl = parse_simple_type("hsl_color").unwrap();
+        let cmyk = parse_simple_type("cmyk_color").unwrap();
+        for _ in 0..100 {
+            match generate_value(&mut rng, Locale::EnUS, &hsl).unwrap() {
+                Value::Tuple3U16(h, s, l) => assert!(h < 360 && s <= 100 && l <= 100),
+                other => panic!("expected Tuple3U16, got {:?}", other),
+            }
+            match generate_value(&mut rng, Locale::EnUS, &cmyk).unwrap() {
+                Value::Tuple4U8(c, m, y, k) => assert!(c.max(m).max(y).max(k) <= 100),
+                other => panic!("expected Tuple4U8, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_css_color_spec() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = FieldSpec::CssColor {
+            format: Some(colors::CssColorFormat::Hsl),
+        };
+        for _ in 0..20 {
+            match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+                Value::String(color) => assert!(color.starts_with("hsl("), "{}", color),
+                other => panic!("expected string, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_of_birth_with_age_type() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = FieldSpec::DateOfBirthWithAge {
+            min_age: 30,
+            max_age: 35,
+        };
+        for _ in 0..50 {
+            match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+                Value::TupleStrU32(dob, age) => {
+                    assert!((30..=35).contains(&age));
+                    assert_eq!(dob.len(), 10);
+                }
+                other => panic!("expected TupleStrU32, got {:?}", other),
+            }
+        }
+
+        let invalid = FieldSpec::DateOfBirthWithAge {
+            min_age: 35,
+            max_age: 30,
+        };
+        assert!(validate_spec(&invalid).is_err());
+
+        let mut schema = BTreeMap::new();
+        schema.insert("dob".to_string(), spec);
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 5, &schema).unwrap();
+        match batch.column(0).data_type() {
+            DataType::Struct(fields) => {
+                assert_eq!(fields[0].name(), "date_of_birth");
+                assert_eq!(*fields[1].data_type(), DataType::UInt32);
+            }
+            other => panic!("expected struct, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_duration_type() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = parse_simple_type("duration").unwrap();
+        match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+            Value::String(s) => assert!(s.starts_with('P'), "got {}", s),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        let seconds = FieldSpec::Duration {
+            min: 60,
+            max: 120,
+            format: duration::DurationFormat::Seconds,
+        };
+        match generate_value(&mut rng, Locale::EnUS, &seconds).unwrap() {
+            Value::Int(s) => assert!((60..=120).contains(&s)),
+            other => panic!("expected int, got {:?}", other),
+        }
+        assert_eq!(field_spec_to_arrow_type(&seconds), DataType::UInt64);
+
+        let invalid = FieldSpec::Duration {
+            min: 120,
+            max: 60,
+            format: duration::DurationFormat::Human,
+        };
+        assert!(validate_spec(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_demographic_types() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        match generate_value(&mut rng, Locale::EnUS, &parse_simple_type("age").unwrap()).unwrap() {
+            Value::Int(age) => assert!((18..=80).contains(&age)),
+            other => panic!("expected int, got {:?}", other),
+        }
+
+        let spec = FieldSpec::Demographic {
+            weights: demographics::DemographicWeights::new(
+                demographics::Demographic::MaritalStatus,
+                &[("widowed".to_string(), 1)],
+            )
+            .unwrap(),
+        };
+        for _ in 0..20 {
+            assert_eq!(
+                generate_value(&mut rng, Locale::EnUS, &spec).unwrap(),
+                Value::String("widowed".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_invoice_number_type() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = parse_simple_type("invoice_number").unwrap();
+        match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+            Value::String(number) => assert!(number.starts_with("INV-"), "{}", number),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        let spec = FieldSpec::InvoiceNumber {
+            prefix: "ORD".to_string(),
+            digits: 4,
+            date: None,
+        };
+        assert!(validate_spec(&spec).is_ok());
+        match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+            Value::String(number) => assert_eq!(number.len(), 8, "{}", number),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        let spec = FieldSpec::InvoiceNumber {
+            prefix: "ORD".to_string(),
+            digits: 0,
+            date: None,
+        };
+        assert!(validate_spec(&spec).is_err());
+    }
+
+    #[test]
+    fn test_county_spec() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = FieldSpec::County {
+            state: Some("OR".to_string()),
+            with_state: true,
+        };
+        assert!(validate_spec(&spec).is_ok());
+        match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+            Value::String(county) => assert!(county.ends_with(" County, OR"), "{}", county),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        let spec = FieldSpec::County {
+            state: Some("Atlantis".to_string()),
+            with_state: false,
+        };
+        assert!(validate_spec(&spec).is_err());
+    }
+
+    #[test]
+    fn test_insurance_number_types() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for (name, prefix) in [("policy_number", "POL-"), ("claim_number", "CLM-")] {
+            let spec = parse_simple_type(name).unwrap();
+            match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+                Value::String(number) => {
+                    assert!(number.starts_with(prefix), "{}", number);
+                    assert!(insurance::validate_insurance_number(&number), "{}", number);
+                }
+                other => panic!("expected string, got {:?}", other),
+            }
+        }
+
+        let spec = FieldSpec::InsuranceNumber {
+            prefix: "pol".to_string(),
+            digits: 8,
+        };
+        assert!(validate_spec(&spec).is_err());
+    }
+
+    #[test]
+    fn test_sku_types() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = parse_simple_type("sku").unwrap();
+        match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+            Value::String(sku) => assert_eq!(sku.split('-').count(), 4, "{}", sku),
+            other => panic!("expected string, got {:?}", other),
+        }
+
+        let spec = FieldSpec::SkuPattern {
+            pattern: "SKU-####".to_string(),
+        };
+        match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+            Value::String(sku) => {
+                assert!(sku.starts_with("SKU-"), "{}", sku);
+                assert_eq!(sku.len(), 8, "{}", sku);
+            }
+            other => panic!("expected string, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_retail_price_types() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = parse_simple_type("retail_price").unwrap();
+        match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+            Value::Float(price) => assert!((20.0..100.0).contains(&price), "{}", price),
+            other => panic!("expected float, got {:?}", other),
+        }
+
+        let spec = FieldSpec::RetailPrice {
+            tier: commerce::PriceTier::Luxury,
+            decimal: true,
+        };
+        match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+            Value::Decimal(cents, 2) => assert!(cents >= 100_900, "{}", cents),
+            other => panic!("expected decimal, got {:?}", other),
+        }
+        assert_eq!(Value::Decimal(199_999, 2).as_string(), "1999.99");
+        assert_eq!(Value::Decimal(-5, 2).as_string(), "-0.05");
+
+        let mut schema = BTreeMap::new();
+        schema.insert("price".to_string(), spec);
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 10, &schema).unwrap();
+        assert_eq!(*batch.column(0).data_type(), DataType::Decimal128(10, 2));
+    }
+
+    #[test]
+    fn test_bytes_type() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = parse_simple_type("bytes").unwrap();
+        match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+            Value::Bytes(bytes) => assert_eq!(bytes.len(), binary::DEFAULT_BYTES_LENGTH),
+            other => panic!("expected bytes, got {:?}", other),
+        }
+        assert_eq!(Value::Bytes(vec![0x00, 0xab, 0x7f]).as_string(), "00ab7f");
+
+        let mut schema = BTreeMap::new();
+        schema.insert("blob".to_string(), FieldSpec::Bytes { length: 5 });
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 10, &schema).unwrap();
+        let column = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<BinaryArray>()
+            .unwrap();
+        assert_eq!(column.iter().count(), 10);
+        assert!(column.iter().all(|v| v.unwrap().len() == 5));
+    }
+
+    #[test]
+    fn test_generate_arrow_column_hsl_cmyk_color() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert("cmyk".to_string(), FieldSpec::CmykColor);
+        schema.insert("hsl".to_string(), FieldSpec::HslColor);
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 10, &schema).unwrap();
+        assert_eq!(batch.num_rows(), 10);
+        match batch.column(0).data_type() {
+            DataType::Struct(fields) => {
+                let names: Vec<&str> = fields.iter().map(|f| f.name().as_str()).collect();
+                assert_eq!(names, ["c", "m", "y", "k"]);
+            }
+            other => panic!("expected struct, got {:?}", other),
+        }
+        match batch.column(1).data_type() {
+            DataType::Struct(fields) => {
+                assert_eq!(*fields[0].data_type(), DataType::UInt16);
+                assert_eq!(fields[0].name(), "h");
+            }
+            other => panic!("expected struct, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_coordinates_type() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let spec = parse_simple_type("coordinates").unwrap();
+        for _ in 0..100 {
+            match generate_value(&mut rng, Locale::EnUS, &spec).unwrap() {
+                Value::Tuple2F64(lat, lon) => {
+                    assert!((-90.0..=90.0).contains(&lat));
+                    assert!((-180.0..=180.0).contains(&lon));
+                }
+                other => panic!("expected Tuple2F64, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_arrow_column_coordinates() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert("location".to_string(), FieldSpec::Coordinates);
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 10, &schema).unwrap();
+        assert_eq!(batch.num_rows(), 10);
+
+        let column = batch.column(0);
+        assert_eq!(
+            *column.data_type(),
+            field_spec_to_arrow_type(&FieldSpec::Coordinates)
+        );
+        let lat = column
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap()
+            .column_by_name("lat")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap()
+            .value(0);
+        assert!((-90.0..=90.0).contains(&lat));
+    }
+
+    #[test]
+    fn test_generate_arrow_column_access_log_entry() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert(
+            "request".to_string(),
+            parse_simple_type("access_log_entry").unwrap(),
+        );
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 10, &schema).unwrap();
+        let column = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(column.num_columns(), logs::ACCESS_LOG_FIELDS.len());
+        let statuses = column
+            .column_by_name("status")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        for i in 0..10 {
+            assert!(statuses.value(i).parse::<u16>().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_generate_arrow_column_address_parts() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert(
+            "address".to_string(),
+            FieldSpec::AddressParts { unit_rate: 1.0 },
+        );
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 10, &schema).unwrap();
+        let column = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(column.num_columns(), address::ADDRESS_PARTS_FIELDS.len());
+        let units = column
+            .column_by_name("unit")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        for i in 0..10 {
+            assert!(!units.value(i).is_empty());
+        }
+
+        let records = generate_records(&mut rng, Locale::EnUS, 3, &schema).unwrap();
+        match &records[0]["address"] {
+            Value::Struct(fields) => {
+                let keys: Vec<&str> = fields.iter().map(|(k, _)| *k).collect();
+                assert_eq!(keys, address::ADDRESS_PARTS_FIELDS);
+            }
+            other => panic!("expected struct, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_generate_arrow_column_company_profile() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert(
+            "company".to_string(),
+            parse_simple_type("company_profile").unwrap(),
+        );
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 10, &schema).unwrap();
+        let column = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(column.num_columns(), company::COMPANY_PROFILE_FIELDS.len());
+        let names = column
+            .column_by_name("name")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let forms = column
+            .column_by_name("legal_form")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        for i in 0..10 {
+            assert!(names.value(i).ends_with(forms.value(i)));
+        }
+
+        let records = generate_records(&mut rng, Locale::EnUS, 3, &schema).unwrap();
+        match &records[0]["company"] {
+            Value::Struct(fields) => {
+                let keys: Vec<&str> = fields.iter().map(|(k, _)| *k).collect();
+                assert_eq!(keys, company::COMPANY_PROFILE_FIELDS);
+            }
+            other => panic!("expected struct, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_generate_arrow_column_string_types() {
         let mut rng = ForgeryRng::new();
@@ -1516,6 +3848,7 @@ mod tests {
         let batch = generate_records_arrow_with_custom(
             &mut rng,
             Locale::EnUS,
+            &LocaleOverrides::EMPTY,
             10,
             &schema,
             &custom_providers,
@@ -1572,6 +3905,226 @@ mod tests {
             );
         }
     }
+
+    fn nullable(inner: FieldSpec, null_rate: f64) -> FieldSpec {
+        FieldSpec::Nullable {
+            inner: Box::new(inner),
+            null_rate,
+        }
+    }
+
+    #[test]
+    fn test_generate_records_nullable() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert(
+            "email".to_string(),
+            nullable(FieldSpec::Simple("email".to_string()), 0.3),
+        );
+
+        let records = generate_records(&mut rng, Locale::EnUS, 1000, &schema).unwrap();
+        let nulls = records.iter().filter(|r| r["email"] == Value::Null).count();
+        assert!((200..400).contains(&nulls), "{} nulls", nulls);
+        for record in records.iter().filter(|r| r["email"] != Value::Null) {
+            assert!(record["email"].as_string().contains('@'));
+        }
+    }
+
+    #[test]
+    fn test_nullable_zero_rate_matches_inner() {
+        let inner = FieldSpec::IntRange { min: 0, max: 1000 };
+        let mut plain = BTreeMap::new();
+        plain.insert("x".to_string(), inner.clone());
+        let mut wrapped = BTreeMap::new();
+        wrapped.insert("x".to_string(), nullable(inner, 0.0));
+
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+        assert_eq!(
+            generate_records(&mut rng1, Locale::EnUS, 50, &plain).unwrap(),
+            generate_records(&mut rng2, Locale::EnUS, 50, &wrapped).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_nullable_full_rate_always_null() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let mut schema = BTreeMap::new();
+        schema.insert(
+            "x".to_string(),
+            nullable(FieldSpec::IntRange { min: 0, max: 9 }, 1.0),
+        );
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 100_000, &schema).unwrap();
+        assert_eq!(batch.column(0).null_count(), 100_000);
+        let records = generate_records(&mut rng, Locale::EnUS, 10_000, &schema).unwrap();
+        assert!(records.iter().all(|r| r["x"] == Value::Null));
+    }
+
+    #[test]
+    fn test_nullable_invalid_rate() {
+        let mut schema = BTreeMap::new();
+        schema.insert("x".to_string(), nullable(FieldSpec::Int, 1.5));
+        let err = validate_schema(&schema).unwrap_err();
+        assert!(err.message.contains("null_rate"), "{}", err.message);
+
+        schema.insert(
+            "x".to_string(),
+            nullable(FieldSpec::IntRange { min: 5, max: 1 }, 0.5),
+        );
+        assert!(validate_schema(&schema).is_err());
+    }
+
+    #[test]
+    fn test_generate_arrow_nullable_columns() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert("age".to_string(), nullable(FieldSpec::Int, 0.5));
+        schema.insert("color".to_string(), nullable(FieldSpec::RgbColor, 1.0));
+        schema.insert("name".to_string(), nullable(FieldSpec::Name, 0.0));
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 200, &schema).unwrap();
+        let arrow_schema = batch.schema();
+        for field in arrow_schema.fields() {
+            assert!(field.is_nullable(), "{}", field.name());
+        }
+        assert_eq!(arrow_schema.field(0).data_type(), &DataType::Int64);
+
+        let ages = batch.column(0);
+        assert!(
+            (50..150).contains(&ages.null_count()),
+            "{}",
+            ages.null_count()
+        );
+        assert_eq!(batch.column(1).null_count(), 200);
+        assert!(batch.column(1).as_any().is::<StructArray>());
+        assert_eq!(batch.column(2).null_count(), 0);
+    }
+
+    #[test]
+    fn test_nullable_custom_provider() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut custom_providers = HashMap::new();
+        custom_providers.insert(
+            "fruit".to_string(),
+            CustomProvider::Uniform(vec!["apple".to_string()]),
+        );
+        let mut schema = BTreeMap::new();
+        schema.insert(
+            "fruit".to_string(),
+            nullable(FieldSpec::Custom("fruit".to_string()), 0.5),
+        );
+
+        let records = generate_records_with_custom(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+            &schema,
+            &custom_providers,
+        )
+        .unwrap();
+        assert!(records.iter().any(|r| r["fruit"] == Value::Null));
+        assert!(records
+            .iter()
+            .any(|r| r["fruit"] == Value::String("apple".to_string())));
+
+        let batch = generate_records_arrow_with_custom(
+            &mut rng,
+            Locale::EnUS,
+            &LocaleOverrides::EMPTY,
+            100,
+            &schema,
+            &custom_providers,
+        )
+        .unwrap();
+        assert!(batch.column(0).null_count() > 0);
+
+        let missing = generate_records(&mut rng, Locale::EnUS, 1, &schema);
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn test_generate_records_bool() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert("active".to_string(), parse_simple_type("bool").unwrap());
+        schema.insert("always".to_string(), FieldSpec::Bool { true_rate: 1.0 });
+        schema.insert("never".to_string(), FieldSpec::Bool { true_rate: 0.0 });
+
+        let records = generate_records(&mut rng, Locale::EnUS, 1000, &schema).unwrap();
+        let trues = records
+            .iter()
+            .filter(|r| r["active"] == Value::Bool(true))
+            .count();
+        assert!((400..600).contains(&trues), "{} trues", trues);
+        for record in &records {
+            assert_eq!(record["always"], Value::Bool(true));
+            assert_eq!(record["never"], Value::Bool(false));
+        }
+    }
+
+    #[test]
+    fn test_bool_full_rate_always_true() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let mut schema = BTreeMap::new();
+        schema.insert("always".to_string(), FieldSpec::Bool { true_rate: 1.0 });
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 100_000, &schema).unwrap();
+        let flags = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        assert_eq!(flags.true_count(), 100_000);
+    }
+
+    #[test]
+    fn test_bool_invalid_rate() {
+        let mut schema = BTreeMap::new();
+        schema.insert("x".to_string(), FieldSpec::Bool { true_rate: -0.1 });
+        let err = validate_schema(&schema).unwrap_err();
+        assert!(err.message.contains("true_rate"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_generate_arrow_column_bool() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert("flag".to_string(), FieldSpec::Bool { true_rate: 0.2 });
+        schema.insert(
+            "maybe".to_string(),
+            nullable(FieldSpec::Bool { true_rate: 0.5 }, 0.5),
+        );
+
+        let batch = generate_records_arrow(&mut rng, Locale::EnUS, 1000, &schema).unwrap();
+        assert_eq!(batch.schema().field(0).data_type(), &DataType::Boolean);
+        let flags = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        assert!(
+            (100..300).contains(&flags.true_count()),
+            "{}",
+            flags.true_count()
+        );
+        assert!(batch.column(1).null_count() > 0);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,615 @@
+//! Software metadata generation provider.
+//!
+//! Generates [Semantic Versioning 2.0.0](https://semver.org) version strings.
+//! Major versions lean toward small numbers, as most packages never reach
+//! double-digit majors.
+//!
+//! | Form | Example |
+//! |------|---------|
+//! | Plain | `2.14.3` |
+//! | Pre-release | `1.0.0-rc.2` |
+//! | Build metadata | `3.2.1+build.1187` |
+//! | Both | `0.9.4-beta.1+5f3c9ab` |
+//!
+//! Also generates git commit SHAs,
+//! [Conventional Commits](https://www.conventionalcommits.org) subject lines
+//! such as `fix(parser): handle empty input`, and container image references
+//! such as `ghcr.io/payments/api:1.4.2@sha256:<digest>`.
+//!
+//! Kubernetes resource names are DNS-1123 labels built the way controllers
+//! build them: a deployment `payments-api` owns the replica set
+//! `payments-api-7d9f8b6c4`, which owns the pod `payments-api-7d9f8b6c4-x2lqn`.
+
+use crate::providers::identifiers;
+use crate::rng::ForgeryRng;
+use std::str::FromStr;
+
+/// Pre-release identifiers, appended with a numeric suffix (e.g. `rc.2`).
+pub const SEMVER_PRERELEASE_TAGS: &[&str] = &["alpha", "beta", "rc"];
+
+/// Highest generated major, minor and patch numbers.
+const SEMVER_MAX_MAJOR: u32 = 20;
+const SEMVER_MAX_MINOR: u32 = 30;
+const SEMVER_MAX_PATCH: u32 = 50;
+
+/// Validate a version string against the Semantic Versioning 2.0.0 grammar.
+pub fn validate_semver(version: &str) -> bool {
+    let (rest, build) = match version.split_once('+') {
+        Some((rest, build)) => (rest, Some(build)),
+        None => (version, None),
+    };
+    let (core, prerelease) = match rest.split_once('-') {
+        Some((core, prerelease)) => (core, Some(prerelease)),
+        None => (rest, None),
+    };
+
+    let numeric = |s: &str| {
+        !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) && (s == "0" || !s.starts_with('0'))
+    };
+    let identifier =
+        |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-');
+
+    let parts: Vec<&str> = core.split('.').collect();
+    if parts.len() != 3 || !parts.iter().all(|p| numeric(p)) {
+        return false;
+    }
+    if let Some(prerelease) = prerelease {
+        let valid = prerelease
+            .split('.')
+            .all(|id| identifier(id) && (!id.bytes().all(|b| b.is_ascii_digit()) || numeric(id)));
+        if !valid {
+            return false;
+        }
+    }
+    build.is_none_or(|build| build.split('.').all(identifier))
+}
+
+/// Generate a batch of semantic versions.
+pub fn generate_semvers(
+    rng: &mut ForgeryRng,
+    n: usize,
+    prerelease: bool,
+    build: bool,
+) -> Vec<String> {
+    let mut versions = Vec::with_capacity(n);
+    for _ in 0..n {
+        versions.push(generate_semver(rng, prerelease, build));
+    }
+    versions
+}
+
+/// Generate a single semantic version (e.g., "2.14.3").
+///
+/// With `prerelease`, a tag such as `-beta.2` is appended; with `build`, build
+/// metadata such as `+build.1187` or a short commit hash.
+#[inline]
+pub fn generate_semver(rng: &mut ForgeryRng, prerelease: bool, build: bool) -> String {
+    // The smaller of two draws skews majors toward low numbers
+    let major = rng
+        .gen_range(0, SEMVER_MAX_MAJOR)
+        .min(rng.gen_range(0, SEMVER_MAX_MAJOR));
+    let minor = rng.gen_range(0, SEMVER_MAX_MINOR);
+    let patch = rng.gen_range(0, SEMVER_MAX_PATCH);
+    let mut version = format!("{}.{}.{}", major, minor, patch);
+
+    if prerelease {
+        let tag = *rng.choose(SEMVER_PRERELEASE_TAGS);
+        let number = rng.gen_range(1u32, 5);
+        version.push_str(&format!("-{}.{}", tag, number));
+    }
+    if build {
+        if rng.gen_range(0, 1) == 1 {
+            let number = rng.gen_range(1u32, 9999);
+            version.push_str(&format!("+build.{}", number));
+        } else {
+            let hash: String = (0..7)
+                .map(|_| char::from(*rng.choose(b"0123456789abcdef")))
+                .collect();
+            version.push('+');
+            version.push_str(&hash);
+        }
+    }
+    version
+}
+
+// === Git ===
+
+/// Length of an abbreviated commit SHA, as shown by `git log --oneline`.
+pub const GIT_SHORT_SHA_LEN: usize = 7;
+
+/// Conventional commit types with the verbs that open their descriptions.
+const COMMIT_TYPES: &[(&str, &[&str])] = &[
+    ("feat", &["add", "support", "introduce", "implement"]),
+    ("fix", &["handle", "fix", "prevent panic on", "correct"]),
+    ("docs", &["document", "clarify", "update docs for"]),
+    (
+        "refactor",
+        &["simplify", "extract", "clean up", "restructure"],
+    ),
+    ("perf", &["speed up", "optimize", "cache"]),
+    ("test", &["add tests for", "cover", "stabilize tests for"]),
+    ("chore", &["update", "tidy", "bump deps for"]),
+    ("ci", &["run checks for", "cache builds for"]),
+];
+
+/// Scopes placed in parentheses after the commit type.
+const COMMIT_SCOPES: &[&str] = &[
+    "api", "auth", "cache", "cli", "config", "core", "db", "deps", "http", "parser", "ui", "worker",
+];
+
+/// Objects that complete a commit description.
+const COMMIT_OBJECTS: &[&str] = &[
+    "empty input",
+    "retry logic",
+    "pagination",
+    "timezone handling",
+    "config loading",
+    "error messages",
+    "connection pooling",
+    "rate limiting",
+    "unicode filenames",
+    "log formatting",
+    "session expiry",
+    "batch uploads",
+    "startup time",
+    "dark mode",
+    "CSV export",
+    "webhook delivery",
+];
+
+/// Generate a batch of git commit SHAs.
+pub fn generate_git_shas(rng: &mut ForgeryRng, n: usize, short: bool) -> Vec<String> {
+    let mut shas = Vec::with_capacity(n);
+    for _ in 0..n {
+        shas.push(generate_git_sha(rng, short));
+    }
+    shas
+}
+
+/// Generate a single git commit SHA: 40 lowercase hex characters, or the
+/// first `GIT_SHORT_SHA_LEN` with `short`.
+#[inline]
+pub fn generate_git_sha(rng: &mut ForgeryRng, short: bool) -> String {
+    let mut sha = identifiers::generate_sha1(rng);
+    if short {
+        sha.truncate(GIT_SHORT_SHA_LEN);
+    }
+    sha
+}
+
+/// Generate a batch of conventional commit messages.
+pub fn generate_commit_messages(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut messages = Vec::with_capacity(n);
+    for _ in 0..n {
+        messages.push(generate_commit_message(rng));
+    }
+    messages
+}
+
+/// Generate a single conventional commit message (e.g., "fix(parser): handle empty input").
+///
+/// About three in four messages carry a scope.
+#[inline]
+pub fn generate_commit_message(rng: &mut ForgeryRng) -> String {
+    let &(kind, verbs) = rng.choose(COMMIT_TYPES);
+    let verb = *rng.choose(verbs);
+    let object = *rng.choose(COMMIT_OBJECTS);
+    if rng.gen_range(0u8, 3) == 0 {
+        format!("{}: {} {}", kind, verb, object)
+    } else {
+        let scope = *rng.choose(COMMIT_SCOPES);
+        format!("{}({}): {} {}", kind, scope, verb, object)
+    }
+}
+
+// === Container images ===
+
+/// Container registry hosts.
+pub const IMAGE_REGISTRIES: &[&str] = &[
+    "docker.io",
+    "ghcr.io",
+    "quay.io",
+    "gcr.io",
+    "public.ecr.aws",
+    "registry.gitlab.com",
+    "registry.example.com",
+];
+
+/// Team namespaces that own services.
+pub(crate) const SERVICE_TEAMS: &[&str] = &[
+    "platform", "payments", "data", "infra", "identity", "search", "growth", "ml",
+];
+
+/// Service names used for images and workloads.
+pub(crate) const SERVICE_NAMES: &[&str] = &[
+    "api",
+    "web",
+    "worker",
+    "gateway",
+    "auth",
+    "billing",
+    "scheduler",
+    "notifications",
+    "frontend",
+    "ingest",
+    "reports",
+    "cache-warmer",
+];
+
+/// Generate a batch of container image references.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator to use
+/// * `n` - Number of references to generate
+/// * `registry` - Prefix a registry host
+/// * `tag` - Append a semantic version tag
+/// * `digest` - Append an `@sha256:` content digest
+pub fn generate_docker_images(
+    rng: &mut ForgeryRng,
+    n: usize,
+    registry: bool,
+    tag: bool,
+    digest: bool,
+) -> Vec<String> {
+    let mut images = Vec::with_capacity(n);
+    for _ in 0..n {
+        images.push(generate_docker_image(rng, registry, tag, digest));
+    }
+    images
+}
+
+/// Generate a single container image reference
+/// (e.g., "registry.example.com/payments/api:1.4.2").
+#[inline]
+pub fn generate_docker_image(
+    rng: &mut ForgeryRng,
+    registry: bool,
+    tag: bool,
+    digest: bool,
+) -> String {
+    let mut image = String::new();
+    if registry {
+        let host = *rng.choose(IMAGE_REGISTRIES);
+        image.push_str(host);
+        image.push('/');
+    }
+    let team = *rng.choose(SERVICE_TEAMS);
+    let service = *rng.choose(SERVICE_NAMES);
+    image.push_str(&format!("{}/{}", team, service));
+    if tag {
+        image.push(':');
+        image.push_str(&generate_semver(rng, false, false));
+    }
+    if digest {
+        image.push_str("@sha256:");
+        image.push_str(&identifiers::generate_sha256(rng));
+    }
+    image
+}
+
+// === Kubernetes ===
+
+/// Characters of generated name suffixes: Kubernetes' `SafeEncodeString`
+/// alphabet, which leaves out vowels and easily confused characters.
+const K8S_SUFFIX_CHARS: &[u8] = b"bcdfghjklmnpqrstvwxz2456789";
+
+/// Environment suffixes of namespace names.
+const K8S_ENVIRONMENTS: &[&str] = &["dev", "staging", "prod"];
+
+/// Maximum length of a DNS-1123 label.
+pub const DNS_LABEL_MAX_LEN: usize = 63;
+
+/// Kind of Kubernetes resource to name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum K8sResource {
+    /// A namespace (e.g. `payments-prod`).
+    Namespace,
+    /// A deployment (e.g. `payments-api`).
+    Deployment,
+    /// A replica set: deployment name plus pod template hash.
+    ReplicaSet,
+    /// A pod: replica set name plus a five-character suffix.
+    #[default]
+    Pod,
+}
+
+/// Accepted names for `K8sResource`, in declaration order.
+pub const K8S_RESOURCES: &[&str] = &["namespace", "deployment", "replicaset", "pod"];
+
+/// Error for an unknown Kubernetes resource kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct K8sResourceError {
+    /// The unrecognized kind.
+    pub kind: String,
+}
+
+impl std::fmt::Display for K8sResourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown Kubernetes resource kind '{}'; expected one of: {}",
+            self.kind,
+            K8S_RESOURCES.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for K8sResourceError {}
+
+impl FromStr for K8sResource {
+    type Err = K8sResourceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "namespace" => Ok(K8sResource::Namespace),
+            "deployment" => Ok(K8sResource::Deployment),
+            "replicaset" => Ok(K8sResource::ReplicaSet),
+            "pod" => Ok(K8sResource::Pod),
+            _ => Err(K8sResourceError {
+                kind: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Validate a DNS-1123 label: at most 63 lowercase alphanumerics or `-`,
+/// starting and ending with an alphanumeric.
+pub fn validate_dns_label(name: &str) -> bool {
+    let valid_char = |b: u8| b.is_ascii_lowercase() || b.is_ascii_digit();
+    !name.is_empty()
+        && name.len() <= DNS_LABEL_MAX_LEN
+        && name.bytes().all(|b| valid_char(b) || b == b'-')
+        && name.bytes().next().is_some_and(valid_char)
+        && name.bytes().last().is_some_and(valid_char)
+}
+
+/// Random characters from `K8S_SUFFIX_CHARS`.
+fn k8s_suffix(rng: &mut ForgeryRng, len: usize) -> String {
+    (0..len)
+        .map(|_| char::from(*rng.choose(K8S_SUFFIX_CHARS)))
+        .collect()
+}
+
+/// Generate a batch of Kubernetes resource names.
+pub fn generate_k8s_names(rng: &mut ForgeryRng, n: usize, kind: K8sResource) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_k8s_name(rng, kind));
+    }
+    names
+}
+
+/// Generate a single DNS-1123 compliant Kubernetes resource name
+/// (e.g., "payments-api-7d9f8b6c4-x2lqn" for a pod).
+#[inline]
+pub fn generate_k8s_name(rng: &mut ForgeryRng, kind: K8sResource) -> String {
+    let team = *rng.choose(SERVICE_TEAMS);
+    if kind == K8sResource::Namespace {
+        let env = *rng.choose(K8S_ENVIRONMENTS);
+        return format!("{}-{}", team, env);
+    }
+    let service = *rng.choose(SERVICE_NAMES);
+    let mut name = format!("{}-{}", team, service);
+    if matches!(kind, K8sResource::ReplicaSet | K8sResource::Pod) {
+        // Pod template hashes are 8 to 10 characters long
+        let hash_len = rng.gen_range(8, 10);
+        name.push('-');
+        name.push_str(&k8s_suffix(rng, hash_len));
+    }
+    if kind == K8sResource::Pod {
+        name.push('-');
+        name.push_str(&k8s_suffix(rng, 5));
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_semver() {
+        assert!(validate_semver("0.0.0"));
+        assert!(validate_semver("2.14.3"));
+        assert!(validate_semver("1.0.0-rc.2"));
+        assert!(validate_semver("1.0.0-alpha-1.x"));
+        assert!(validate_semver("3.2.1+build.1187"));
+        assert!(validate_semver("0.9.4-beta.1+5f3c9ab"));
+        assert!(!validate_semver("1.2"));
+        assert!(!validate_semver("01.2.3"));
+        assert!(!validate_semver("1.2.3-"));
+        assert!(!validate_semver("1.2.3-beta.01"));
+        assert!(!validate_semver("1.2.3+"));
+        assert!(!validate_semver("1.2.3+build..1"));
+        assert!(!validate_semver("v1.2.3"));
+    }
+
+    #[test]
+    fn test_generate_semvers_plain() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let versions = generate_semvers(&mut rng, 500, false, false);
+        assert_eq!(versions.len(), 500);
+        for version in &versions {
+            assert!(validate_semver(version), "{}", version);
+            assert!(!version.contains(['-', '+']), "{}", version);
+        }
+        let majors: Vec<u32> = versions
+            .iter()
+            .map(|v| v.split('.').next().unwrap().parse().unwrap())
+            .collect();
+        // About 29% of uniform draws would be <= 5; the skew roughly doubles that
+        let small = majors.iter().filter(|&&m| m <= 5).count();
+        assert!(small > 200, "{} of 500 majors <= 5", small);
+    }
+
+    #[test]
+    fn test_generate_semvers_with_metadata() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let versions = generate_semvers(&mut rng, 500, true, true);
+        for version in &versions {
+            assert!(validate_semver(version), "{}", version);
+            let (rest, _) = version.split_once('+').expect("build metadata");
+            let (_, prerelease) = rest.split_once('-').expect("pre-release");
+            let (tag, _) = prerelease.split_once('.').unwrap();
+            assert!(SEMVER_PRERELEASE_TAGS.contains(&tag), "{}", version);
+        }
+        assert!(versions.iter().any(|v| v.contains("+build.")));
+        assert!(versions.iter().any(|v| !v.contains("+build.")));
+
+        let builds = generate_semvers(&mut rng, 100, false, true);
+        assert!(builds.iter().all(|v| !v.contains('-') && v.contains('+')));
+    }
+
+    #[test]
+    fn test_generate_git_shas() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for sha in generate_git_shas(&mut rng, 100, false) {
+            assert_eq!(sha.len(), 40);
+            assert!(sha
+                .bytes()
+                .all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase()));
+        }
+        for sha in generate_git_shas(&mut rng, 100, true) {
+            assert_eq!(sha.len(), GIT_SHORT_SHA_LEN);
+            assert!(sha.bytes().all(|b| b.is_ascii_hexdigit()));
+        }
+    }
+
+    #[test]
+    fn test_generate_commit_messages() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let messages = generate_commit_messages(&mut rng, 300);
+        for message in &messages {
+            let (header, description) = message.split_once(": ").unwrap();
+            let kind = header.split('(').next().unwrap();
+            assert!(COMMIT_TYPES.iter().any(|(t, _)| *t == kind), "{}", message);
+            if let Some(scope) = header.strip_prefix(kind).unwrap().strip_prefix('(') {
+                let scope = scope.strip_suffix(')').unwrap();
+                assert!(COMMIT_SCOPES.contains(&scope), "{}", message);
+            }
+            assert!(
+                COMMIT_OBJECTS.iter().any(|o| description.ends_with(o)),
+                "{}",
+                message
+            );
+        }
+        assert!(messages.iter().any(|m| m.contains('(')));
+        assert!(messages.iter().any(|m| !m.contains('(')));
+    }
+
+    #[test]
+    fn test_generate_docker_images() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for image in generate_docker_images(&mut rng, 100, true, true, true) {
+            let (name, digest) = image.split_once("@sha256:").unwrap();
+            assert_eq!(digest.len(), 64, "{}", image);
+            let (path, tag) = name.rsplit_once(':').unwrap();
+            assert!(validate_semver(tag), "{}", image);
+            let parts: Vec<&str> = path.split('/').collect();
+            assert_eq!(parts.len(), 3, "{}", image);
+            assert!(IMAGE_REGISTRIES.contains(&parts[0]), "{}", image);
+        }
+        for image in generate_docker_images(&mut rng, 100, false, false, false) {
+            assert_eq!(image.split('/').count(), 2, "{}", image);
+            assert!(!image.contains([':', '@']), "{}", image);
+        }
+    }
+
+    #[test]
+    fn test_validate_dns_label() {
+        assert!(validate_dns_label("payments-api-7d9f8b6c4-x2lqn"));
+        assert!(validate_dns_label("a"));
+        assert!(!validate_dns_label(""));
+        assert!(!validate_dns_label("-api"));
+        assert!(!validate_dns_label("api-"));
+        assert!(!validate_dns_label("Payments"));
+        assert!(!validate_dns_label("payments_api"));
+        assert!(!validate_dns_label(&"a".repeat(64)));
+    }
+
+    #[test]
+    fn test_generate_k8s_names() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for (kind, dashes) in [
+            (K8sResource::Namespace, 1),
+            (K8sResource::Deployment, 1),
+            (K8sResource::ReplicaSet, 2),
+            (K8sResource::Pod, 3),
+        ] {
+            for name in generate_k8s_names(&mut rng, 200, kind) {
+                assert!(validate_dns_label(&name), "{}", name);
+                // Only "cache-warmer" adds a dash of its own
+                let extra = usize::from(name.contains("cache-warmer"));
+                assert_eq!(name.matches('-').count(), dashes + extra, "{}", name);
+            }
+        }
+
+        let pod = generate_k8s_name(&mut rng, K8sResource::Pod);
+        let (replica_set, suffix) = pod.rsplit_once('-').unwrap();
+        assert_eq!(suffix.len(), 5);
+        let hash = replica_set.rsplit('-').next().unwrap();
+        assert!((8..=10).contains(&hash.len()), "{}", pod);
+        assert!(hash.bytes().all(|b| K8S_SUFFIX_CHARS.contains(&b)));
+    }
+
+    #[test]
+    fn test_k8s_resource_from_str() {
+        for name in K8S_RESOURCES {
+            assert!(name.parse::<K8sResource>().is_ok());
+        }
+        let err = "service".parse::<K8sResource>().unwrap_err();
+        assert!(err.to_string().contains("'service'"));
+    }
+
+    #[test]
+    fn test_semver_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_semvers(&mut rng1, 50, true, true),
+            generate_semvers(&mut rng2, 50, true, true)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_semver_valid(
+            seed in any::<u64>(),
+            n in 0usize..100,
+            prerelease in any::<bool>(),
+            build in any::<bool>(),
+        ) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let versions = generate_semvers(&mut rng, n, prerelease, build);
+            prop_assert_eq!(versions.len(), n);
+            for version in versions {
+                prop_assert!(validate_semver(&version), "{}", version);
+                prop_assert_eq!(version.contains('-'), prerelease);
+                prop_assert_eq!(version.contains('+'), build);
+            }
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Summary statistics for generated records.
+//!
+//! Used by `describe()` to sanity-check a schema on a small sample before
+//! committing to a large generation run.
+
+use crate::data::overrides::LocaleOverrides;
+use crate::locale::Locale;
+use crate::providers::custom::CustomProvider;
+use crate::providers::records::{
+    generate_records_tuples_with_custom, FieldSpec, SchemaError, Value,
+};
+use crate::rng::ForgeryRng;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Statistics for a single field, computed over a sample of generated values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldStats {
+    /// Number of sampled values (including nulls).
+    pub count: usize,
+    /// Number of null values in the sample.
+    pub null_count: usize,
+    /// Number of distinct non-null values.
+    pub cardinality: usize,
+    /// Smallest non-null value (numeric order for numbers, lexicographic for strings).
+    pub min: Option<Value>,
+    /// Largest non-null value (numeric order for numbers, lexicographic for strings).
+    pub max: Option<Value>,
+    /// Shortest string length in characters (string fields only).
+    pub min_length: Option<usize>,
+    /// Longest string length in characters (string fields only).
+    pub max_length: Option<usize>,
+    /// Mean string length in characters (string fields only).
+    pub mean_length: Option<f64>,
+}
+
+impl FieldStats {
+    /// Fraction of sampled values that were null (0.0 for an empty sample).
+    pub fn null_rate(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.null_count as f64 / self.count as f64
+        }
+    }
+}
+
+/// Order two values for min/max tracking.
+///
+/// Values of the same kind compare naturally; mixed kinds (which a single
+/// schema field never produces) fall back to their string representation.
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Int(x), Value::Int(y)) => x.cmp(y),
+        (Value::Float(x), Value::Float(y)) => x.total_cmp(y),
+        (Value::Bool(x), Value::Bool(y)) => x.cmp(y),
+        (Value::Decimal(x, s), Value::Decimal(y, t)) if s == t => x.cmp(y),
+        (Value::String(x), Value::String(y)) => x.cmp(y),
+        (Value::Tuple3U8(r1, g1, b1), Value::Tuple3U8(r2, g2, b2)) => {
+            (r1, g1, b1).cmp(&(r2, g2, b2))
+        }
+        (Value::Tuple3U16(a1, b1, c1), Value::Tuple3U16(a2, b2, c2)) => {
+            (a1, b1, c1).cmp(&(a2, b2, c2))
+        }
+        (Value::Tuple4U8(a1, b1, c1, d1), Value::Tuple4U8(a2, b2, c2, d2)) => {
+            (a1, b1, c1, d1).cmp(&(a2, b2, c2, d2))
+        }
+        (Value::Tuple2F64(a1, b1), Value::Tuple2F64(a2, b2)) => {
+            a1.total_cmp(a2).then(b1.total_cmp(b2))
+        }
+        _ => a.as_string().cmp(&b.as_string()),
+    }
+}
+
+/// Compute statistics for a column of values.
+pub fn describe_values(values: &[Value]) -> FieldStats {
+    let mut distinct: HashSet<String> = HashSet::with_capacity(values.len());
+    let mut min: Option<&Value> = None;
+    let mut max: Option<&Value> = None;
+    let mut min_length: Option<usize> = None;
+    let mut max_length: Option<usize> = None;
+    let mut total_length: usize = 0;
+    let mut string_count: usize = 0;
+    let mut null_count: usize = 0;
+
+    for value in values {
+        if *value == Value::Null {
+            null_count += 1;
+            continue;
+        }
+        distinct.insert(value.as_string());
+
+        if min.is_none_or(|m| compare_values(value, m) == Ordering::Less) {
+            min = Some(value);
+        }
+        if max.is_none_or(|m| compare_values(value, m) == Ordering::Greater) {
+            max = Some(value);
+        }
+
+        if let Value::String(s) = value {
+            let len = s.chars().count();
+            min_length = Some(min_length.map_or(len, |m| m.min(len)));
+            max_length = Some(max_length.map_or(len, |m| m.max(len)));
+            total_length += len;
+            string_count += 1;
+        }
+    }
+
+    let mean_length = if string_count > 0 {
+        Some(total_length as f64 / string_count as f64)
+    } else {
+        None
+    };
+
+    FieldStats {
+        count: values.len(),
+        null_count,
+        cardinality: distinct.len(),
+        min: min.cloned(),
+        max: max.cloned(),
+        min_length,
+        max_length,
+        mean_length,
+    }
+}
+
+/// Generate a sample of records and compute statistics for every field.
+///
+/// Returns a map from field name to its statistics, in schema (alphabetical) order.
+pub fn describe_schema(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n_sample: usize,
+    schema: &BTreeMap<String, FieldSpec>,
+) -> Result<BTreeMap<String, FieldStats>, SchemaError> {
+    // Delegate to the custom-aware version with empty providers map
+    describe_schema_with_custom(
+        rng,
+        locale,
+        &LocaleOverrides::EMPTY,
+        n_sample,
+        schema,
+        &HashMap::new(),
+    )
+}
+
+/// Generate a sample of records and compute statistics, with custom provider
+/// support and locale data overrides.
+pub fn describe_schema_with_custom(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    overrides: &LocaleOverrides,
+    n_sample: usize,
+    schema: &BTreeMap<String, FieldSpec>,
+    custom_providers: &HashMap<String, CustomProvider>,
+) -> Result<BTreeMap<String, FieldStats>, SchemaError> {
+    let field_order: Vec<String> = schema.keys().cloned().collect();
+    let rows = generate_records_tuples_with_custom(
+        rng,
+        locale,
+        overrides,
+        n_sample,
+        schema,
+        &field_order,
+        custom_providers,
+    )?;
+
+    // Transpose rows into columns so each field is described independently
+    let mut columns: Vec<Vec<Value>> = vec![Vec::with_capacity(n_sample); field_order.len()];
+    for row in rows {
+        for (column, value) in columns.iter_mut().zip(row) {
+            column.push(value);
+        }
+    }
+
+    Ok(field_order
+        .into_iter()
+        .zip(columns)
+        .map(|(name, column)| (name, describe_values(&column)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_values_empty() {
+        let stats = describe_values(&[]);
+        assert_eq!(stats.count, 0);
+        assert_eq!(stats.cardinality, 0);
+        assert!(stats.min.is_none());
+        assert!(stats.max.is_none());
+        assert!(stats.mean_length.is_none());
+        assert_eq!(stats.null_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_describe_values_ints() {
+        let values = vec![Value::Int(5), Value::Int(-2), Value::Int(5), Value::Int(10)];
+        let stats = describe_values(&values);
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.cardinality, 3);
+        assert_eq!(stats.min, Some(Value::Int(-2)));
+        assert_eq!(stats.max, Some(Value::Int(10)));
+        assert!(stats.min_length.is_none());
+    }
+
+    #[test]
+    fn test_describe_values_strings() {
+        let values = vec![
+            Value::String("bb".to_string()),
+            Value::String("a".to_string()),
+            Value::String("cccc".to_string()),
+        ];
+        let stats = describe_values(&values);
+        assert_eq!(stats.cardinality, 3);
+        assert_eq!(stats.min, Some(Value::String("a".to_string())));
+        assert_eq!(stats.max, Some(Value::String("cccc".to_string())));
+        assert_eq!(stats.min_length, Some(1));
+        assert_eq!(stats.max_length, Some(4));
+        assert!((stats.mean_length.unwrap() - 7.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_describe_values_nulls() {
+        let values = vec![Value::Null, Value::Int(3), Value::Null, Value::Int(1)];
+        let stats = describe_values(&values);
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.null_count, 2);
+        assert_eq!(stats.cardinality, 2);
+        assert_eq!(stats.min, Some(Value::Int(1)));
+        assert_eq!(stats.null_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_describe_values_counts_chars_not_bytes() {
+        let values = vec![Value::String("東京".to_string())];
+        let stats = describe_values(&values);
+        assert_eq!(stats.min_length, Some(2));
+    }
+
+    #[test]
+    fn test_describe_schema() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let mut schema = BTreeMap::new();
+        schema.insert("age".to_string(), FieldSpec::IntRange { min: 18, max: 65 });
+        schema.insert(
+            "status".to_string(),
+            FieldSpec::Choice(vec!["a".to_string(), "b".to_string()]),
+        );
+        schema.insert("id".to_string(), FieldSpec::Uuid);
+
+        let stats = describe_schema(&mut rng, Locale::EnUS, 500, &schema).unwrap();
+        assert_eq!(stats.len(), 3);
+
+        let age = &stats["age"];
+        assert_eq!(age.count, 500);
+        match (&age.min, &age.max) {
+            (Some(Value::Int(min)), Some(Value::Int(max))) => {
+                assert!(*min >= 18 && *max <= 65);
+            }
+            other => panic!("unexpected age bounds: {:?}", other),
+        }
+
+        assert!(stats["status"].cardinality <= 2);
+        assert_eq!(stats["id"].cardinality, 500);
+        assert_eq!(stats["id"].min_length, Some(36));
+        assert_eq!(stats["id"].max_length, Some(36));
+    }
+
+    #[test]
+    fn test_describe_schema_deterministic() {
+        let mut schema = BTreeMap::new();
+        schema.insert("name".to_string(), FieldSpec::Name);
+
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+
+        let s1 = describe_schema(&mut rng1, Locale::EnUS, 100, &schema).unwrap();
+        let s2 = describe_schema(&mut rng2, Locale::EnUS, 100, &schema).unwrap();
+        assert_eq!(s1, s2);
+    }
+
+    #[test]
+    fn test_describe_schema_invalid() {
+        let mut rng = ForgeryRng::new();
+        let mut schema = BTreeMap::new();
+        schema.insert("x".to_string(), FieldSpec::IntRange { min: 10, max: 1 });
+
+        assert!(describe_schema(&mut rng, Locale::EnUS, 10, &schema).is_err());
+    }
+}
@@ -0,0 +1,314 @@
+//! Company tax identifier generation provider.
+//!
+//! Generates format-valid synthetic tax IDs for businesses, the company-side
+//! counterpart to [`national_id`](super::national_id). Check digits are
+//! computed where the real scheme defines one. The numbers are random and
+//! not issued to anyone.
+//!
+//! | Locale | Identifier | Example |
+//! |--------|------------|---------|
+//! | en_US | Employer Identification Number | `12-3456789` |
+//! | en_CA | Business Number + program account (Luhn) | `123456782RT0001` |
+//! | en_GB, de_DE, fr_FR, es_ES, it_IT, nl_NL, pl_PL | VAT number | `DE136695976` |
+//! | ru_RU | INN for legal entities | `7707083893` |
+//! | ja_JP | Corporate Number | `7000012050002` |
+//! | ko_KR | Business Registration Number | `220-81-62517` |
+
+use crate::locale::Locale;
+use crate::providers::finance::{luhn_checksum, vat_number_for};
+use crate::rng::ForgeryRng;
+
+/// EIN prefixes assigned by the IRS to its campuses and online issuance.
+pub const EIN_PREFIXES: &[u8] = &[
+    1, 2, 3, 4, 5, 6, 10, 11, 12, 13, 14, 15, 16, 20, 21, 22, 23, 24, 25, 26, 27, 30, 31, 32, 33,
+    34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 50, 51, 52, 53, 54, 55, 56, 57, 58,
+    59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 71, 72, 73, 74, 75, 76, 77, 80, 81, 82, 83, 84, 85, 86,
+    87, 88, 90, 91, 92, 93, 94, 95, 98, 99,
+];
+
+/// Generate a batch of US Employer Identification Numbers.
+pub fn generate_eins(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut eins = Vec::with_capacity(n);
+    for _ in 0..n {
+        eins.push(generate_ein(rng));
+    }
+    eins
+}
+
+/// Generate a single US Employer Identification Number (`XX-XXXXXXX`).
+///
+/// The two-digit prefix is always one the IRS assigns.
+#[inline]
+pub fn generate_ein(rng: &mut ForgeryRng) -> String {
+    let prefix = rng.choose(EIN_PREFIXES);
+    let serial = rng.gen_range(0u32, 9_999_999);
+    format!("{:02}-{:07}", prefix, serial)
+}
+
+/// Generate a batch of company tax IDs for the given locale.
+pub fn generate_company_tax_ids(rng: &mut ForgeryRng, locale: Locale, n: usize) -> Vec<String> {
+    let mut ids = Vec::with_capacity(n);
+    for _ in 0..n {
+        ids.push(generate_company_tax_id(rng, locale));
+    }
+    ids
+}
+
+/// Generate a single company tax ID for the given locale.
+#[inline]
+pub fn generate_company_tax_id(rng: &mut ForgeryRng, locale: Locale) -> String {
+    match locale {
+        Locale::EnUS => generate_ein(rng),
+        Locale::EnCA => generate_business_number(rng),
+        Locale::RuRU => generate_inn(rng),
+        Locale::JaJP => generate_corporate_number(rng),
+        Locale::KoKR => generate_brn(rng),
+        Locale::EnGB
+        | Locale::DeDE
+        | Locale::FrFR
+        | Locale::EsES
+        | Locale::ItIT
+        | Locale::NlNL
+        | Locale::PlPL => vat_number_for(rng, locale, None),
+    }
+}
+
+/// Random decimal digits (each 0-9).
+fn random_digits(rng: &mut ForgeryRng, count: usize) -> Vec<u32> {
+    (0..count).map(|_| rng.gen_range(0u32, 9)).collect()
+}
+
+/// Join digits into a string.
+fn digits_to_string(digits: &[u32]) -> String {
+    digits
+        .iter()
+        .map(|&d| char::from_digit(d, 10).expect("digit"))
+        .collect()
+}
+
+/// Canadian Business Number with a GST/HST (`RT`) program account.
+///
+/// The nine-digit BN ends in a Luhn check digit.
+fn generate_business_number(rng: &mut ForgeryRng) -> String {
+    let mut bn = digits_to_string(&random_digits(rng, 8));
+    bn.push(char::from(b'0' + luhn_checksum(&bn)));
+    format!("{}RT0001", bn)
+}
+
+/// Russian INN of a legal entity (10 digits).
+fn generate_inn(rng: &mut ForgeryRng) -> String {
+    let mut digits = random_digits(rng, 9);
+    if digits[0] == 0 {
+        digits[0] = 7;
+    }
+    digits.push(inn_check_digit(&digits));
+    digits_to_string(&digits)
+}
+
+/// Check digit over the first nine digits of a legal-entity INN.
+fn inn_check_digit(digits: &[u32]) -> u32 {
+    const WEIGHTS: [u32; 9] = [2, 4, 10, 3, 5, 9, 4, 6, 8];
+    let sum: u32 = digits.iter().zip(WEIGHTS).map(|(d, w)| d * w).sum();
+    sum % 11 % 10
+}
+
+/// Japanese Corporate Number (13 digits, check digit first).
+fn generate_corporate_number(rng: &mut ForgeryRng) -> String {
+    let body = random_digits(rng, 12);
+    let mut digits = vec![corporate_number_check_digit(&body)];
+    digits.extend(body);
+    digits_to_string(&digits)
+}
+
+/// Check digit over the twelve base digits of a Corporate Number.
+fn corporate_number_check_digit(digits: &[u32]) -> u32 {
+    // Weights alternate 1, 2 starting from the rightmost digit
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| if i % 2 == 0 { *d } else { d * 2 })
+        .sum();
+    9 - sum % 9
+}
+
+/// Korean Business Registration Number (`XXX-XX-XXXXX`).
+fn generate_brn(rng: &mut ForgeryRng) -> String {
+    let mut digits = random_digits(rng, 9);
+    if digits[0] == 0 {
+        digits[0] = 1;
+    }
+    digits.push(brn_check_digit(&digits));
+    let s = digits_to_string(&digits);
+    format!("{}-{}-{}", &s[0..3], &s[3..5], &s[5..])
+}
+
+/// Check digit over the first nine Business Registration Number digits.
+fn brn_check_digit(digits: &[u32]) -> u32 {
+    const WEIGHTS: [u32; 9] = [1, 3, 7, 1, 3, 7, 1, 3, 5];
+    let sum: u32 = digits.iter().zip(WEIGHTS).map(|(d, w)| d * w).sum::<u32>() + digits[8] * 5 / 10;
+    (10 - sum % 10) % 10
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::finance::validate_luhn;
+
+    fn sample(locale: Locale, n: usize) -> Vec<String> {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        generate_company_tax_ids(&mut rng, locale, n)
+    }
+
+    fn digits_of(s: &str) -> Vec<u32> {
+        s.chars().filter_map(|c| c.to_digit(10)).collect()
+    }
+
+    #[test]
+    fn test_generate_eins_count() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        assert_eq!(generate_eins(&mut rng, 100).len(), 100);
+    }
+
+    #[test]
+    fn test_ein_format_and_prefix() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        for ein in generate_eins(&mut rng, 500) {
+            assert_eq!(ein.len(), 10, "{}", ein);
+            assert_eq!(&ein[2..3], "-");
+            assert!(ein[..2]
+                .chars()
+                .chain(ein[3..].chars())
+                .all(|c| c.is_ascii_digit()));
+            let prefix: u8 = ein[..2].parse().unwrap();
+            assert!(EIN_PREFIXES.contains(&prefix), "{}", ein);
+        }
+    }
+
+    #[test]
+    fn test_ein_never_uses_unassigned_prefix() {
+        for unassigned in [
+            0u8, 7, 8, 9, 17, 18, 19, 28, 29, 49, 69, 70, 78, 79, 89, 96, 97,
+        ] {
+            assert!(!EIN_PREFIXES.contains(&unassigned));
+        }
+    }
+
+    #[test]
+    fn test_company_tax_ids_count_and_determinism() {
+        for locale in Locale::ALL {
+            assert_eq!(sample(*locale, 50).len(), 50);
+            assert_eq!(sample(*locale, 20), sample(*locale, 20));
+        }
+    }
+
+    #[test]
+    fn test_us_company_tax_id_is_ein() {
+        for id in sample(Locale::EnUS, 50) {
+            let prefix: u8 = id[..2].parse().unwrap();
+            assert!(EIN_PREFIXES.contains(&prefix), "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_business_number_luhn() {
+        for bn in sample(Locale::EnCA, 200) {
+            assert_eq!(bn.len(), 15, "{}", bn);
+            assert!(bn.ends_with("RT0001"));
+            assert!(validate_luhn(&bn[..9]), "{}", bn);
+        }
+    }
+
+    #[test]
+    fn test_vat_locales_use_vat_numbers() {
+        for (locale, prefix) in [
+            (Locale::EnGB, "GB"),
+            (Locale::DeDE, "DE"),
+            (Locale::FrFR, "FR"),
+            (Locale::EsES, "ES"),
+            (Locale::ItIT, "IT"),
+            (Locale::NlNL, "NL"),
+            (Locale::PlPL, "PL"),
+        ] {
+            for id in sample(locale, 20) {
+                assert!(id.starts_with(prefix), "{}", id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inn_check_digit() {
+        // Published INN 7707083893
+        assert_eq!(inn_check_digit(&[7, 7, 0, 7, 0, 8, 3, 8, 9]), 3);
+        for inn in sample(Locale::RuRU, 200) {
+            let digits = digits_of(&inn);
+            assert_eq!(digits.len(), 10, "{}", inn);
+            assert_eq!(inn_check_digit(&digits[..9]), digits[9], "{}", inn);
+        }
+    }
+
+    #[test]
+    fn test_corporate_number_check_digit() {
+        // Published Corporate Number 7000012050002
+        assert_eq!(
+            corporate_number_check_digit(&[0, 0, 0, 0, 1, 2, 0, 5, 0, 0, 0, 2]),
+            7
+        );
+        for id in sample(Locale::JaJP, 200) {
+            let digits = digits_of(&id);
+            assert_eq!(digits.len(), 13, "{}", id);
+            assert_eq!(corporate_number_check_digit(&digits[1..]), digits[0]);
+        }
+    }
+
+    #[test]
+    fn test_brn_check_digit() {
+        // Published Business Registration Number 220-81-62517
+        assert_eq!(brn_check_digit(&[2, 2, 0, 8, 1, 6, 2, 5, 1]), 7);
+        for brn in sample(Locale::KoKR, 200) {
+            assert_eq!(brn.len(), 12, "{}", brn);
+            assert_eq!((&brn[3..4], &brn[6..7]), ("-", "-"));
+            let digits = digits_of(&brn);
+            assert_eq!(brn_check_digit(&digits[..9]), digits[9], "{}", brn);
+        }
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_ein_batch_size(n in 0usize..500) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(42);
+            prop_assert_eq!(generate_eins(&mut rng, n).len(), n);
+        }
+
+        #[test]
+        fn prop_company_tax_id_count(n in 0usize..200, locale_idx in 0usize..12) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(42);
+            let locale = Locale::ALL[locale_idx];
+            prop_assert_eq!(generate_company_tax_ids(&mut rng, locale, n).len(), n);
+        }
+
+        #[test]
+        fn prop_company_tax_id_seed_determinism(seed in any::<u64>(), locale_idx in 0usize..12) {
+            let locale = Locale::ALL[locale_idx];
+            let mut rng1 = ForgeryRng::new();
+            let mut rng2 = ForgeryRng::new();
+            rng1.seed(seed);
+            rng2.seed(seed);
+            prop_assert_eq!(
+                generate_company_tax_ids(&mut rng1, locale, 10),
+                generate_company_tax_ids(&mut rng2, locale, 10)
+            );
+        }
+    }
+}
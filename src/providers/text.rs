@@ -1,6 +1,10 @@
 //! Text generation provider.
 //!
-//! Generates sentences, paragraphs, and text blocks.
+//! Generates words, sentences, paragraphs, and text blocks.
+//!
+//! Words are joined and punctuated per the locale's `TextFormat`: ja_JP
+//! text has no spaces and uses "、" and "。", and text lengths are counted
+//! in characters rather than bytes.
 
 use crate::data::get_locale_data;
 use crate::locale::Locale;
@@ -12,6 +16,125 @@ const MIN_WORDS_PER_SENTENCE: usize = 5;
 /// Maximum number of words per sentence in paragraph generation.
 const MAX_WORDS_PER_SENTENCE: usize = 15;
 
+/// Shape of generated sentences and paragraphs.
+///
+/// Counts are inclusive `(min, max)` ranges drawn per sentence or paragraph,
+/// so output varies in length instead of looking uniformly machine-made.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextShape {
+    /// Words per sentence.
+    pub word_count: (usize, usize),
+    /// Sentences per paragraph.
+    pub sentence_count: (usize, usize),
+    /// Probability of a comma after each word but the last of a sentence,
+    /// from 0.0 to 1.0.
+    pub comma_rate: f64,
+}
+
+impl Default for TextShape {
+    fn default() -> Self {
+        Self {
+            word_count: (MIN_WORDS_PER_SENTENCE, MAX_WORDS_PER_SENTENCE),
+            sentence_count: (5, 5),
+            comma_rate: 0.0,
+        }
+    }
+}
+
+impl TextShape {
+    /// Check that both ranges are ordered and the comma rate is between 0 and 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TextShapeError` for a reversed range or an out-of-range rate.
+    pub fn validate(&self) -> Result<(), TextShapeError> {
+        for (name, (min, max)) in [
+            ("word_count", self.word_count),
+            ("sentence_count", self.sentence_count),
+        ] {
+            if min > max {
+                return Err(TextShapeError::Range { name, min, max });
+            }
+        }
+        if !(0.0..=1.0).contains(&self.comma_rate) {
+            return Err(TextShapeError::CommaRate(self.comma_rate));
+        }
+        Ok(())
+    }
+}
+
+/// Errors for an invalid text shape.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextShapeError {
+    /// A count range has its minimum above its maximum.
+    Range {
+        /// The option name.
+        name: &'static str,
+        /// The range minimum.
+        min: usize,
+        /// The range maximum.
+        max: usize,
+    },
+    /// The comma rate is NaN or outside 0.0-1.0.
+    CommaRate(f64),
+}
+
+impl std::fmt::Display for TextShapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Range { name, min, max } => write!(
+                f,
+                "{} range minimum ({}) must not exceed maximum ({})",
+                name, min, max
+            ),
+            Self::CommaRate(rate) => {
+                write!(f, "comma_rate must be between 0.0 and 1.0, got {}", rate)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TextShapeError {}
+
+/// Draw a count from an inclusive range without touching the RNG for a fixed count.
+#[inline]
+fn count_in(rng: &mut ForgeryRng, (min, max): (usize, usize)) -> usize {
+    if min >= max {
+        min
+    } else {
+        rng.gen_range(min, max)
+    }
+}
+
+/// Generate a batch of word lists, each holding `count` words.
+pub fn generate_words(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    count: usize,
+) -> Vec<Vec<String>> {
+    let mut lists = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut words = Vec::with_capacity(count);
+        for _ in 0..count {
+            words.push(generate_word(rng, locale));
+        }
+        lists.push(words);
+    }
+    lists
+}
+
+/// Generate a single lorem word, without capitalization or punctuation.
+#[inline]
+pub fn generate_word(rng: &mut ForgeryRng, locale: Locale) -> String {
+    let data = get_locale_data(locale);
+    let lorem_words = data.text_words().unwrap_or(&[]);
+    if lorem_words.is_empty() {
+        return "lorem".to_string();
+    }
+    rng.choose(lorem_words).to_string()
+}
+
 /// Generate a batch of random sentences.
 pub fn generate_sentences(
     rng: &mut ForgeryRng,
@@ -28,9 +151,17 @@ pub fn generate_sentences(
 
 /// Generate a single random sentence.
 ///
-/// The sentence starts with a capital letter and ends with a period.
+/// The sentence starts with a capital letter and ends with the locale's
+/// full stop ("." or "。").
 #[inline]
 pub fn generate_sentence(rng: &mut ForgeryRng, locale: Locale, word_count: usize) -> String {
+    sentence(rng, locale, word_count, 0.0)
+}
+
+/// Sentence of `word_count` words with a comma after each word but the
+/// last with probability `comma_rate` (always at 1.0, since `gen_range` can
+/// return 1.0).
+fn sentence(rng: &mut ForgeryRng, locale: Locale, word_count: usize, comma_rate: f64) -> String {
     if word_count == 0 {
         return String::new();
     }
@@ -41,6 +172,7 @@ pub fn generate_sentence(rng: &mut ForgeryRng, locale: Locale, word_count: usize
         return "Lorem ipsum.".to_string();
     }
 
+    let format = data.text_format();
     let mut words = Vec::with_capacity(word_count);
     for _ in 0..word_count {
         words.push(*rng.choose(lorem_words));
@@ -56,16 +188,53 @@ pub fn generate_sentence(rng: &mut ForgeryRng, locale: Locale, word_count: usize
         }
     }
 
-    // Add remaining words
+    // Add remaining words, each after an optional comma
     for word in words.iter().skip(1) {
-        sentence.push(' ');
+        if comma_rate >= 1.0 || (comma_rate > 0.0 && rng.gen_range(0.0, 1.0) < comma_rate) {
+            sentence.push_str(format.comma);
+        }
+        sentence.push_str(format.separator);
         sentence.push_str(word);
     }
 
-    sentence.push('.');
+    sentence.push_str(format.full_stop);
     sentence
 }
 
+/// Generate a batch of sentences with word counts and commas drawn per `shape`.
+///
+/// # Errors
+///
+/// Returns `TextShapeError` if the shape is invalid.
+pub fn generate_shaped_sentences(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    shape: &TextShape,
+) -> Result<Vec<String>, TextShapeError> {
+    shape.validate()?;
+    let mut sentences = Vec::with_capacity(n);
+    for _ in 0..n {
+        let word_count = count_in(rng, shape.word_count);
+        sentences.push(sentence(rng, locale, word_count, shape.comma_rate));
+    }
+    Ok(sentences)
+}
+
+/// Generate a single sentence shaped by `shape`; `sentence_count` is ignored.
+///
+/// # Errors
+///
+/// Returns `TextShapeError` if the shape is invalid.
+#[inline]
+pub fn generate_shaped_sentence(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    shape: &TextShape,
+) -> Result<String, TextShapeError> {
+    Ok(generate_shaped_sentences(rng, locale, 1, shape)?.remove(0))
+}
+
 /// Generate a batch of random paragraphs.
 pub fn generate_paragraphs(
     rng: &mut ForgeryRng,
@@ -85,17 +254,61 @@ pub fn generate_paragraphs(
 /// Each paragraph contains the specified number of sentences.
 #[inline]
 pub fn generate_paragraph(rng: &mut ForgeryRng, locale: Locale, sentence_count: usize) -> String {
+    let shape = TextShape {
+        sentence_count: (sentence_count, sentence_count),
+        ..TextShape::default()
+    };
+    paragraph(rng, locale, &shape)
+}
+
+/// Paragraph shaped by an already validated `shape`.
+fn paragraph(rng: &mut ForgeryRng, locale: Locale, shape: &TextShape) -> String {
+    let sentence_count = count_in(rng, shape.sentence_count);
     if sentence_count == 0 {
         return String::new();
     }
 
     let mut sentences = Vec::with_capacity(sentence_count);
     for _ in 0..sentence_count {
-        let word_count: usize = rng.gen_range(MIN_WORDS_PER_SENTENCE, MAX_WORDS_PER_SENTENCE);
-        sentences.push(generate_sentence(rng, locale, word_count));
+        let word_count = count_in(rng, shape.word_count);
+        sentences.push(sentence(rng, locale, word_count, shape.comma_rate));
+    }
+
+    sentences.join(get_locale_data(locale).text_format().separator)
+}
+
+/// Generate a batch of paragraphs with sentence and word counts drawn per `shape`.
+///
+/// # Errors
+///
+/// Returns `TextShapeError` if the shape is invalid.
+pub fn generate_shaped_paragraphs(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    n: usize,
+    shape: &TextShape,
+) -> Result<Vec<String>, TextShapeError> {
+    shape.validate()?;
+    let mut paragraphs = Vec::with_capacity(n);
+    for _ in 0..n {
+        paragraphs.push(paragraph(rng, locale, shape));
     }
+    Ok(paragraphs)
+}
 
-    sentences.join(" ")
+/// Generate a single paragraph shaped by `shape`.
+///
+/// # Errors
+///
+/// Returns `TextShapeError` if the shape is invalid.
+#[inline]
+pub fn generate_shaped_paragraph(
+    rng: &mut ForgeryRng,
+    locale: Locale,
+    shape: &TextShape,
+) -> Result<String, TextShapeError> {
+    shape.validate()?;
+    Ok(paragraph(rng, locale, shape))
 }
 
 /// Generate a batch of random text blocks with character limits.
@@ -126,25 +339,17 @@ fn capitalize_word(word: &str) -> String {
     }
 }
 
-/// Truncate a string to max_chars, respecting UTF-8 character boundaries.
+/// Truncate a string to at most `max_chars` characters.
 #[inline]
-fn truncate_to_char_boundary(text: &mut String, max_chars: usize) {
-    if text.len() <= max_chars {
-        return;
-    }
-    // Find the last valid character boundary before max_chars
-    let truncate_at = text
-        .char_indices()
-        .take_while(|(i, _)| *i <= max_chars)
-        .last()
-        .map(|(i, _)| i)
-        .unwrap_or(0);
-    text.truncate(truncate_at);
+fn truncate_chars(text: &mut String, max_chars: usize) {
+    if let Some((end, _)) = text.char_indices().nth(max_chars) {
+        text.truncate(end);
+    }
 }
 
 /// Generate a single random text block with character limits.
 ///
-/// The text will be between min_chars and max_chars in length.
+/// The text will be between min_chars and max_chars characters long.
 #[inline]
 pub fn generate_text(
     rng: &mut ForgeryRng,
@@ -168,20 +373,22 @@ pub fn generate_text(
         rng.gen_range(min_chars, max_chars)
     };
 
-    let mut text = String::new();
+    let separator = data.text_format().separator;
 
     // First word - capitalize it
     let first_word = *rng.choose(lorem_words);
-    text.push_str(&capitalize_word(first_word));
+    let mut text = capitalize_word(first_word);
+    let mut len = text.chars().count();
 
     // Remaining words
-    while text.len() < target_len {
-        text.push(' ');
+    while len < target_len {
         let word = rng.choose(lorem_words);
+        text.push_str(separator);
         text.push_str(word);
+        len += separator.chars().count() + word.chars().count();
     }
 
-    truncate_to_char_boundary(&mut text, max_chars);
+    truncate_chars(&mut text, max_chars);
     text
 }
 
@@ -189,6 +396,53 @@ pub fn generate_text(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_generate_words() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let lorem_words = get_locale_data(Locale::EnUS).text_words().unwrap();
+        let lists = generate_words(&mut rng, Locale::EnUS, 50, 3);
+        assert_eq!(lists.len(), 50);
+        for words in &lists {
+            assert_eq!(words.len(), 3);
+            for word in words {
+                assert!(lorem_words.contains(&word.as_str()), "{}", word);
+            }
+        }
+        assert!(generate_words(&mut rng, Locale::EnUS, 5, 0)
+            .iter()
+            .all(|w| w.is_empty()));
+    }
+
+    #[test]
+    fn test_word_is_raw() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for locale in [Locale::EnUS, Locale::DeDE, Locale::JaJP, Locale::RuRU] {
+            let lorem_words = get_locale_data(locale).text_words().unwrap();
+            for _ in 0..50 {
+                let word = generate_word(&mut rng, locale);
+                assert!(lorem_words.contains(&word.as_str()), "{}", word);
+                assert!(!word.contains(['.', ' ']), "{}", word);
+            }
+        }
+    }
+
+    #[test]
+    fn test_words_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(12345);
+        rng2.seed(12345);
+
+        assert_eq!(
+            generate_words(&mut rng1, Locale::EnUS, 20, 4),
+            generate_words(&mut rng2, Locale::EnUS, 20, 4)
+        );
+    }
+
     #[test]
     fn test_generate_sentences_count() {
         let mut rng = ForgeryRng::new();
@@ -324,6 +578,139 @@ mod tests {
         assert_eq!(p1, p2);
     }
 
+    #[test]
+    fn test_shaped_sentences() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let shape = TextShape {
+            word_count: (4, 12),
+            comma_rate: 0.3,
+            ..TextShape::default()
+        };
+        let sentences = generate_shaped_sentences(&mut rng, Locale::EnUS, 200, &shape).unwrap();
+        let mut counts = std::collections::HashSet::new();
+        for sentence in &sentences {
+            let words = sentence.split_whitespace().count();
+            assert!((4..=12).contains(&words), "{}", sentence);
+            assert!(!sentence.contains(",."), "{}", sentence);
+            counts.insert(words);
+        }
+        assert!(counts.len() > 5);
+        assert!(sentences.iter().any(|s| s.contains(", ")));
+
+        let plain = generate_shaped_sentences(&mut rng, Locale::EnUS, 50, &TextShape::default());
+        assert!(plain.unwrap().iter().all(|s| !s.contains(',')));
+    }
+
+    #[test]
+    fn test_full_comma_rate_always_adds_commas() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let shape = TextShape {
+            word_count: (5, 5),
+            comma_rate: 1.0,
+            ..TextShape::default()
+        };
+        for sentence in generate_shaped_sentences(&mut rng, Locale::EnUS, 10_000, &shape).unwrap() {
+            let words: Vec<&str> = sentence.split(' ').collect();
+            assert_eq!(words.len(), 5, "{}", sentence);
+            assert!(words[..4].iter().all(|w| w.ends_with(',')), "{}", sentence);
+            assert!(
+                words[4].ends_with('.') && !words[4].contains(','),
+                "{}",
+                sentence
+            );
+        }
+    }
+
+    #[test]
+    fn test_shaped_paragraphs() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let shape = TextShape {
+            word_count: (3, 3),
+            sentence_count: (2, 6),
+            comma_rate: 0.0,
+        };
+        for paragraph in generate_shaped_paragraphs(&mut rng, Locale::EnUS, 100, &shape).unwrap() {
+            let sentences = paragraph.matches('.').count();
+            assert!((2..=6).contains(&sentences), "{}", paragraph);
+            assert_eq!(paragraph.split_whitespace().count(), sentences * 3);
+        }
+    }
+
+    #[test]
+    fn test_default_shape_matches_paragraph() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+
+        assert_eq!(
+            generate_paragraphs(&mut rng1, Locale::EnUS, 10, 5),
+            generate_shaped_paragraphs(&mut rng2, Locale::EnUS, 10, &TextShape::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_shape() {
+        let mut rng = ForgeryRng::new();
+        let shape = TextShape {
+            word_count: (12, 4),
+            ..TextShape::default()
+        };
+        assert_eq!(
+            generate_shaped_sentence(&mut rng, Locale::EnUS, &shape),
+            Err(TextShapeError::Range {
+                name: "word_count",
+                min: 12,
+                max: 4
+            })
+        );
+        let shape = TextShape {
+            comma_rate: 1.5,
+            ..TextShape::default()
+        };
+        let err = generate_shaped_paragraph(&mut rng, Locale::EnUS, &shape).unwrap_err();
+        assert!(err.to_string().contains("comma_rate"));
+    }
+
+    #[test]
+    fn test_cjk_text() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let shape = TextShape {
+            comma_rate: 0.5,
+            ..TextShape::default()
+        };
+        for sentence in generate_shaped_sentences(&mut rng, Locale::JaJP, 50, &shape).unwrap() {
+            assert!(sentence.ends_with('。'), "{}", sentence);
+            assert!(!sentence.contains([' ', '.', ',']), "{}", sentence);
+        }
+        let paragraph = generate_paragraph(&mut rng, Locale::JaJP, 3);
+        assert_eq!(paragraph.matches('。').count(), 3, "{}", paragraph);
+        assert!(!paragraph.contains(' '), "{}", paragraph);
+
+        for text in generate_texts(&mut rng, Locale::JaJP, 50, 20, 40) {
+            let chars = text.chars().count();
+            assert!((20..=40).contains(&chars), "{} chars: {}", chars, text);
+            assert!(!text.contains(' '), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        let mut text = "日本語テキスト".to_string();
+        truncate_chars(&mut text, 3);
+        assert_eq!(text, "日本語");
+        truncate_chars(&mut text, 10);
+        assert_eq!(text, "日本語");
+    }
+
     #[test]
     fn test_generate_texts_count() {
         let mut rng = ForgeryRng::new();
@@ -441,6 +828,11 @@ mod tests {
             Locale::EsES,
             Locale::ItIT,
             Locale::JaJP,
+            Locale::KoKR,
+            Locale::RuRU,
+            Locale::NlNL,
+            Locale::PlPL,
+            Locale::EnCA,
         ] {
             let sentence = generate_sentence(&mut rng, locale, 5);
             assert!(
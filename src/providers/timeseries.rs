@@ -0,0 +1,847 @@
+//! Time series generation provider.
+//!
+//! Generates regularly spaced `(timestamp, value)` pairs as an Arrow
+//! `RecordBatch`, which is far faster than building series row by row in
+//! Python.
+//!
+//! | Model | Value at step `i` |
+//! |-------|-------------------|
+//! | `random_walk` | `base` at step 0, then previous + `drift` + noise |
+//! | `seasonal` | `base` + `drift * i` + sine wave of `amplitude` over `period` + noise |
+//!
+//! Noise is normal with standard deviation `jitter`.
+//!
+//! Sensor readings are long-format IoT telemetry rows (`device_id`,
+//! `timestamp`, `metric`, `value`, `unit`) from a fleet of devices. Each
+//! device measures one metric around its own baseline, wandering back toward
+//! it with noise, and rows are ordered by timestamp.
+
+use crate::providers::datetime::{unix_millis_range, DateRangeError};
+use crate::providers::numbers::standard_normal;
+use crate::rng::ForgeryRng;
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray, TimestampMicrosecondArray};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use chrono::DateTime;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Value model for a time series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesModel {
+    /// Cumulative drift plus normal noise.
+    RandomWalk,
+    /// Linear trend plus a sine wave plus normal noise.
+    Seasonal,
+}
+
+/// Accepted series model names.
+pub const SERIES_MODELS: &[&str] = &["random_walk", "seasonal"];
+
+impl FromStr for SeriesModel {
+    type Err = TimeSeriesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "random_walk" => Ok(SeriesModel::RandomWalk),
+            "seasonal" => Ok(SeriesModel::Seasonal),
+            _ => Err(TimeSeriesError::UnknownModel(s.to_string())),
+        }
+    }
+}
+
+/// Options controlling the spacing and shape of a time series.
+///
+/// The default is an hourly random walk starting at 100 with unit noise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeSeriesOptions<'a> {
+    /// Step between timestamps, e.g. "30s", "15min", "1h", "1d" or "1w".
+    pub freq: &'a str,
+    /// Value model.
+    pub model: SeriesModel,
+    /// Starting level.
+    pub base: f64,
+    /// Change in level per step.
+    pub drift: f64,
+    /// Standard deviation of the per-step noise.
+    pub jitter: f64,
+    /// Peak deviation of the seasonal wave.
+    pub amplitude: f64,
+    /// Length of one seasonal cycle, in the same units as `freq`.
+    pub period: &'a str,
+}
+
+impl Default for TimeSeriesOptions<'_> {
+    fn default() -> Self {
+        TimeSeriesOptions {
+            freq: "1h",
+            model: SeriesModel::RandomWalk,
+            base: 100.0,
+            drift: 0.0,
+            jitter: 1.0,
+            amplitude: 10.0,
+            period: "1d",
+        }
+    }
+}
+
+impl TimeSeriesOptions<'_> {
+    /// Check the numeric parameters and parse `freq` and `period` into
+    /// microseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TimeSeriesError` for an unparseable frequency or period, or
+    /// a non-finite or negative parameter.
+    fn validate(&self) -> Result<(i64, i64), TimeSeriesError> {
+        for (name, value) in [
+            ("base", self.base),
+            ("drift", self.drift),
+            ("jitter", self.jitter),
+            ("amplitude", self.amplitude),
+        ] {
+            if !value.is_finite() {
+                return Err(TimeSeriesError::InvalidParameter { name, value });
+            }
+        }
+        if self.jitter < 0.0 {
+            return Err(TimeSeriesError::InvalidParameter {
+                name: "jitter",
+                value: self.jitter,
+            });
+        }
+        Ok((parse_frequency(self.freq)?, parse_frequency(self.period)?))
+    }
+}
+
+/// Errors for invalid time series requests.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeSeriesError {
+    /// Frequency or period is not a positive count and unit.
+    InvalidFrequency(String),
+    /// Unknown value model name.
+    UnknownModel(String),
+    /// Numeric parameter is non-finite, or `jitter` is negative.
+    InvalidParameter {
+        /// Parameter name.
+        name: &'static str,
+        /// Rejected value.
+        value: f64,
+    },
+    /// Start or end date is invalid.
+    DateRange(DateRangeError),
+    /// `n` steps at `freq` run past the end date.
+    DoesNotFit {
+        /// Number of points requested.
+        n: usize,
+        /// Step between points.
+        freq: String,
+        /// End date.
+        end: String,
+    },
+}
+
+impl std::fmt::Display for TimeSeriesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFrequency(freq) => write!(
+                f,
+                "invalid frequency '{}'; expected a positive count and unit such as \
+                 '30s', '15min', '1h', '1d' or '1w'",
+                freq
+            ),
+            Self::UnknownModel(model) => write!(
+                f,
+                "unknown series model '{}'; expected one of: {}",
+                model,
+                SERIES_MODELS.join(", ")
+            ),
+            Self::InvalidParameter { name, value } => {
+                write!(f, "invalid time series {} {}", name, value)
+            }
+            Self::DateRange(e) => write!(f, "{}", e),
+            Self::DoesNotFit { n, freq, end } => write!(
+                f,
+                "{} points at '{}' intervals run past end date '{}'",
+                n, freq, end
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TimeSeriesError {}
+
+/// Microseconds per frequency unit.
+const FREQUENCY_UNITS: &[(&str, i64)] = &[
+    ("s", 1_000_000),
+    ("min", 60_000_000),
+    ("h", 3_600_000_000),
+    ("d", 86_400_000_000),
+    ("w", 604_800_000_000),
+];
+
+/// Parse a frequency such as "15min" or "1h" into microseconds.
+///
+/// The count defaults to 1 and units are case-insensitive.
+fn parse_frequency(freq: &str) -> Result<i64, TimeSeriesError> {
+    let invalid = || TimeSeriesError::InvalidFrequency(freq.to_string());
+    let trimmed = freq.trim();
+    let split = trimmed
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (count, unit) = trimmed.split_at(split);
+    let count: i64 = if count.is_empty() {
+        1
+    } else {
+        count.parse().map_err(|_| invalid())?
+    };
+    let unit = unit.to_ascii_lowercase();
+    let micros = FREQUENCY_UNITS
+        .iter()
+        .find(|(name, _)| *name == unit)
+        .map(|&(_, micros)| micros)
+        .ok_or_else(invalid)?;
+    match count.checked_mul(micros) {
+        Some(step) if step > 0 => Ok(step),
+        _ => Err(invalid()),
+    }
+}
+
+/// Generate the raw timestamps (Unix microseconds) and values of a series.
+///
+/// # Errors
+///
+/// Returns `TimeSeriesError` for invalid options or dates, or if the series
+/// does not fit between `start` and `end`.
+pub fn generate_time_series_points(
+    rng: &mut ForgeryRng,
+    n: usize,
+    start: &str,
+    end: &str,
+    options: &TimeSeriesOptions,
+) -> Result<(Vec<i64>, Vec<f64>), TimeSeriesError> {
+    let (step, period) = options.validate()?;
+    let (start_ms, end_ms) = unix_millis_range(start, end).map_err(TimeSeriesError::DateRange)?;
+    let start_us = start_ms * 1000;
+    let end_us = end_ms * 1000 + 999;
+
+    let fits = n == 0
+        || i64::try_from(n - 1)
+            .ok()
+            .and_then(|steps| steps.checked_mul(step))
+            .and_then(|offset| start_us.checked_add(offset))
+            .is_some_and(|last| last <= end_us);
+    if !fits {
+        return Err(TimeSeriesError::DoesNotFit {
+            n,
+            freq: options.freq.to_string(),
+            end: end.to_string(),
+        });
+    }
+
+    let mut timestamps = Vec::with_capacity(n);
+    let mut values = Vec::with_capacity(n);
+    let mut level = options.base;
+    for i in 0..n {
+        let offset = i as i64 * step;
+        timestamps.push(start_us + offset);
+        let value = match options.model {
+            SeriesModel::RandomWalk => {
+                if i > 0 {
+                    level += options.drift + options.jitter * standard_normal(rng);
+                }
+                level
+            }
+            SeriesModel::Seasonal => {
+                let phase = (offset % period) as f64 / period as f64;
+                options.base
+                    + options.drift * i as f64
+                    + options.amplitude * (std::f64::consts::TAU * phase).sin()
+                    + options.jitter * standard_normal(rng)
+            }
+        };
+        values.push(value);
+    }
+    Ok((timestamps, values))
+}
+
+/// Generate a time series as an Arrow `RecordBatch` with a `timestamp`
+/// column (microseconds, no time zone) and a `float64` `value` column.
+///
+/// # Errors
+///
+/// Returns `TimeSeriesError` for invalid options or dates, or if the series
+/// does not fit between `start` and `end`.
+pub fn generate_time_series(
+    rng: &mut ForgeryRng,
+    n: usize,
+    start: &str,
+    end: &str,
+    options: &TimeSeriesOptions,
+) -> Result<RecordBatch, TimeSeriesError> {
+    let (timestamps, values) = generate_time_series_points(rng, n, start, end, options)?;
+    let schema = Arc::new(Schema::new(vec![
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            false,
+        ),
+        Field::new("value", DataType::Float64, false),
+    ]));
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(TimestampMicrosecondArray::from(timestamps)),
+        Arc::new(Float64Array::from(values)),
+    ];
+    Ok(RecordBatch::try_new(schema, columns).expect("columns match schema"))
+}
+
+// === Sensor readings ===
+
+/// Default number of devices reporting sensor readings.
+pub const DEFAULT_SENSORS: usize = 10;
+
+/// A sensor metric: name, unit, typical level, spread of device baselines,
+/// per-reading noise and the physical (min, max) range.
+struct SensorMetric {
+    name: &'static str,
+    unit: &'static str,
+    level: f64,
+    spread: f64,
+    noise: f64,
+    range: (f64, f64),
+}
+
+/// Metrics sensors can report.
+const SENSOR_METRICS: &[SensorMetric] = &[
+    SensorMetric {
+        name: "temperature",
+        unit: "celsius",
+        level: 21.0,
+        spread: 3.0,
+        noise: 0.3,
+        range: (-40.0, 85.0),
+    },
+    SensorMetric {
+        name: "humidity",
+        unit: "percent",
+        level: 45.0,
+        spread: 10.0,
+        noise: 1.0,
+        range: (0.0, 100.0),
+    },
+    SensorMetric {
+        name: "pressure",
+        unit: "hPa",
+        level: 1013.25,
+        spread: 5.0,
+        noise: 0.5,
+        range: (870.0, 1085.0),
+    },
+    SensorMetric {
+        name: "co2",
+        unit: "ppm",
+        level: 600.0,
+        spread: 150.0,
+        noise: 15.0,
+        range: (350.0, 5000.0),
+    },
+    SensorMetric {
+        name: "vibration",
+        unit: "mm/s",
+        level: 2.5,
+        spread: 1.0,
+        noise: 0.4,
+        range: (0.0, 50.0),
+    },
+    SensorMetric {
+        name: "battery",
+        unit: "percent",
+        level: 80.0,
+        spread: 15.0,
+        noise: 0.5,
+        range: (0.0, 100.0),
+    },
+];
+
+/// Names of the metrics in `SENSOR_METRICS`.
+pub const SENSOR_METRIC_NAMES: &[&str] = &[
+    "temperature",
+    "humidity",
+    "pressure",
+    "co2",
+    "vibration",
+    "battery",
+];
+
+/// Fraction of the distance back to its baseline a device moves per reading.
+const SENSOR_REVERSION: f64 = 0.2;
+
+/// Device ids are 24-bit hex numbers.
+const DEVICE_ID_MASK: u32 = 0xFF_FFFF;
+
+/// Odd step between consecutive device ids.
+const DEVICE_ID_STRIDE: u32 = 0x9E_3779;
+
+/// Errors for invalid sensor reading requests.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SensorError {
+    /// Unknown metric name.
+    UnknownMetric(String),
+    /// Readings were requested from zero sensors.
+    NoSensors,
+    /// Start or end date is invalid.
+    DateRange(DateRangeError),
+}
+
+impl std::fmt::Display for SensorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownMetric(metric) => write!(
+                f,
+                "unknown sensor metric '{}'; expected one of: {}",
+                metric,
+                SENSOR_METRIC_NAMES.join(", ")
+            ),
+            Self::NoSensors => write!(f, "sensors must be at least 1"),
+            Self::DateRange(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SensorError {}
+
+/// One telemetry row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorReading {
+    /// Device that took the reading (e.g., "sensor-3fa9c1")
+    pub device_id: String,
+    /// Reading time as Unix microseconds (whole seconds)
+    pub timestamp: i64,
+    /// Metric name (e.g., "temperature")
+    pub metric: &'static str,
+    /// Measured value, rounded to two decimals
+    pub value: f64,
+    /// Unit of the value (e.g., "celsius")
+    pub unit: &'static str,
+}
+
+impl SensorReading {
+    /// The timestamp in ISO 8601 form (e.g., "2024-01-01T13:45:07").
+    pub fn timestamp_iso(&self) -> String {
+        DateTime::from_timestamp_micros(self.timestamp)
+            .expect("timestamp within chrono range")
+            .format("%Y-%m-%dT%H:%M:%S")
+            .to_string()
+    }
+}
+
+/// A device's metric, baseline and current level.
+struct Device {
+    id: String,
+    metric: &'static SensorMetric,
+    baseline: f64,
+    level: f64,
+}
+
+/// Generate `n` sensor readings from `sensors` devices between the start of
+/// `start` and the end of `end`, ordered by timestamp.
+///
+/// Devices are assigned a metric from `metrics` (all metrics if empty) in
+/// turn, so every requested metric is covered once there are enough devices.
+///
+/// # Errors
+///
+/// Returns `SensorError` for an unknown metric, zero sensors or an invalid
+/// date range.
+pub fn generate_sensor_readings(
+    rng: &mut ForgeryRng,
+    n: usize,
+    start: &str,
+    end: &str,
+    sensors: usize,
+    metrics: &[String],
+) -> Result<Vec<SensorReading>, SensorError> {
+    let chosen: Vec<&'static SensorMetric> = if metrics.is_empty() {
+        SENSOR_METRICS.iter().collect()
+    } else {
+        metrics
+            .iter()
+            .map(|name| {
+                SENSOR_METRICS
+                    .iter()
+                    .find(|m| m.name == name)
+                    .ok_or_else(|| SensorError::UnknownMetric(name.clone()))
+            })
+            .collect::<Result<_, _>>()?
+    };
+    if sensors == 0 {
+        return Err(SensorError::NoSensors);
+    }
+    let (start_ms, end_ms) = unix_millis_range(start, end).map_err(SensorError::DateRange)?;
+
+    // An odd multiplier is a bijection modulo 2^24, so ids stay distinct for
+    // up to 2^24 devices while still looking scattered.
+    let id_base = rng.gen_range(0u32, DEVICE_ID_MASK);
+    let mut devices: Vec<Device> = (0..sensors)
+        .map(|i| {
+            let metric = chosen[i % chosen.len()];
+            let baseline = (metric.level + metric.spread * standard_normal(rng))
+                .clamp(metric.range.0, metric.range.1);
+            Device {
+                id: format!(
+                    "sensor-{:06x}",
+                    id_base.wrapping_add((i as u32).wrapping_mul(DEVICE_ID_STRIDE))
+                        & DEVICE_ID_MASK
+                ),
+                metric,
+                baseline,
+                level: baseline,
+            }
+        })
+        .collect();
+
+    let mut seconds: Vec<i64> = (0..n)
+        .map(|_| rng.gen_range(start_ms / 1000, end_ms / 1000))
+        .collect();
+    seconds.sort_unstable();
+
+    let mut readings = Vec::with_capacity(n);
+    for second in seconds {
+        let device = &mut devices[rng.gen_range(0, sensors - 1)];
+        let metric = device.metric;
+        device.level += SENSOR_REVERSION * (device.baseline - device.level)
+            + metric.noise * standard_normal(rng);
+        device.level = device.level.clamp(metric.range.0, metric.range.1);
+        readings.push(SensorReading {
+            device_id: device.id.clone(),
+            timestamp: second * 1_000_000,
+            metric: metric.name,
+            value: (device.level * 100.0).round() / 100.0,
+            unit: metric.unit,
+        });
+    }
+    Ok(readings)
+}
+
+/// Arrow schema of sensor reading batches.
+fn sensor_readings_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("device_id", DataType::Utf8, false),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            false,
+        ),
+        Field::new("metric", DataType::Utf8, false),
+        Field::new("value", DataType::Float64, false),
+        Field::new("unit", DataType::Utf8, false),
+    ])
+}
+
+/// Convert sensor readings to an Arrow `RecordBatch` with `device_id`,
+/// `timestamp` (microseconds, no time zone), `metric`, `value` and `unit`
+/// columns.
+pub fn sensor_readings_to_record_batch(readings: &[SensorReading]) -> RecordBatch {
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(
+            readings.iter().map(|r| r.device_id.as_str()),
+        )),
+        Arc::new(TimestampMicrosecondArray::from_iter_values(
+            readings.iter().map(|r| r.timestamp),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            readings.iter().map(|r| r.metric),
+        )),
+        Arc::new(Float64Array::from_iter_values(
+            readings.iter().map(|r| r.value),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            readings.iter().map(|r| r.unit),
+        )),
+    ];
+    RecordBatch::try_new(Arc::new(sensor_readings_schema()), columns).expect("columns match schema")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000_000;
+
+    #[test]
+    fn test_parse_frequency() {
+        assert_eq!(parse_frequency("1h").unwrap(), HOUR);
+        assert_eq!(parse_frequency("15min").unwrap(), 15 * 60_000_000);
+        assert_eq!(parse_frequency("D").unwrap(), 24 * HOUR);
+        assert_eq!(parse_frequency("2W").unwrap(), 14 * 24 * HOUR);
+        for bad in ["", "0h", "h1", "5m", "1y", "99999999999999999999s"] {
+            assert!(parse_frequency(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_series_model_from_str() {
+        assert_eq!(
+            "Random_Walk".parse::<SeriesModel>().unwrap(),
+            SeriesModel::RandomWalk
+        );
+        assert_eq!(
+            "seasonal".parse::<SeriesModel>().unwrap(),
+            SeriesModel::Seasonal
+        );
+        let err = "trend".parse::<SeriesModel>().unwrap_err();
+        assert!(err.to_string().contains("random_walk, seasonal"));
+    }
+
+    #[test]
+    fn test_timestamps_regular() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let (timestamps, values) = generate_time_series_points(
+            &mut rng,
+            48,
+            "2024-01-01",
+            "2024-01-02",
+            &TimeSeriesOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(timestamps.len(), 48);
+        assert_eq!(values.len(), 48);
+        assert_eq!(timestamps[0], 1_704_067_200_000_000);
+        assert!(timestamps.windows(2).all(|w| w[1] - w[0] == HOUR));
+        assert_eq!(values[0], 100.0);
+    }
+
+    #[test]
+    fn test_random_walk_drift() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let options = TimeSeriesOptions {
+            drift: 1.0,
+            jitter: 0.0,
+            ..TimeSeriesOptions::default()
+        };
+        let (_, values) =
+            generate_time_series_points(&mut rng, 10, "2024-01-01", "2024-01-01", &options)
+                .unwrap();
+        assert_eq!(
+            values,
+            (0..10).map(|i| 100.0 + f64::from(i)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_seasonal_cycle() {
+        let mut rng = ForgeryRng::new();
+        let options = TimeSeriesOptions {
+            model: SeriesModel::Seasonal,
+            jitter: 0.0,
+            period: "4h",
+            ..TimeSeriesOptions::default()
+        };
+        let (_, values) =
+            generate_time_series_points(&mut rng, 5, "2024-01-01", "2024-01-01", &options).unwrap();
+        let expected = [100.0, 110.0, 100.0, 90.0, 100.0];
+        for (value, expected) in values.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9, "{value} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_does_not_fit() {
+        let mut rng = ForgeryRng::new();
+        let err = generate_time_series_points(
+            &mut rng,
+            25,
+            "2024-01-01",
+            "2024-01-01",
+            &TimeSeriesOptions::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, TimeSeriesError::DoesNotFit { n: 25, .. }));
+        assert!(generate_time_series_points(
+            &mut rng,
+            0,
+            "2024-01-01",
+            "2024-01-01",
+            &TimeSeriesOptions::default()
+        )
+        .unwrap()
+        .0
+        .is_empty());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let mut rng = ForgeryRng::new();
+        let options = TimeSeriesOptions {
+            jitter: -1.0,
+            ..TimeSeriesOptions::default()
+        };
+        let err =
+            generate_time_series(&mut rng, 1, "2024-01-01", "2024-01-02", &options).unwrap_err();
+        assert!(matches!(
+            err,
+            TimeSeriesError::InvalidParameter { name: "jitter", .. }
+        ));
+
+        let err = generate_time_series(
+            &mut rng,
+            1,
+            "2024-01-02",
+            "2024-01-01",
+            &TimeSeriesOptions::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, TimeSeriesError::DateRange(_)));
+    }
+
+    #[test]
+    fn test_record_batch_schema() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let batch = generate_time_series(
+            &mut rng,
+            24,
+            "2024-01-01",
+            "2024-01-01",
+            &TimeSeriesOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(batch.num_rows(), 24);
+        assert_eq!(
+            *batch.schema().field(0).data_type(),
+            DataType::Timestamp(TimeUnit::Microsecond, None)
+        );
+        assert_eq!(*batch.schema().field(1).data_type(), DataType::Float64);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let options = TimeSeriesOptions {
+            model: SeriesModel::Seasonal,
+            ..TimeSeriesOptions::default()
+        };
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(7);
+        rng2.seed(7);
+        assert_eq!(
+            generate_time_series_points(&mut rng1, 100, "2024-01-01", "2024-01-31", &options)
+                .unwrap(),
+            generate_time_series_points(&mut rng2, 100, "2024-01-01", "2024-01-31", &options)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sensor_metric_names_match_table() {
+        let names: Vec<&str> = SENSOR_METRICS.iter().map(|m| m.name).collect();
+        assert_eq!(names, SENSOR_METRIC_NAMES);
+    }
+
+    #[test]
+    fn test_sensor_readings() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let readings =
+            generate_sensor_readings(&mut rng, 1000, "2024-01-01", "2024-01-02", 4, &[]).unwrap();
+        assert_eq!(readings.len(), 1000);
+        assert!(readings
+            .windows(2)
+            .all(|w| w[0].timestamp <= w[1].timestamp));
+        let first = 1_704_067_200_000_000;
+        assert!(readings
+            .iter()
+            .all(|r| (first..first + 2 * 86_400_000_000).contains(&r.timestamp)));
+        for r in &readings {
+            let metric = SENSOR_METRICS.iter().find(|m| m.name == r.metric).unwrap();
+            assert_eq!(r.unit, metric.unit);
+            assert!((metric.range.0..=metric.range.1).contains(&r.value));
+            assert!(r.device_id.starts_with("sensor-"));
+        }
+        // Four devices cover the first four metrics, one each
+        let devices: std::collections::HashSet<(&str, &str)> = readings
+            .iter()
+            .map(|r| (r.device_id.as_str(), r.metric))
+            .collect();
+        assert_eq!(devices.len(), 4);
+        assert_eq!(readings[0].timestamp_iso().len(), 19);
+    }
+
+    #[test]
+    fn test_sensor_device_ids_distinct() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(7);
+        let readings =
+            generate_sensor_readings(&mut rng, 50_000, "2024-01-01", "2024-01-02", 5000, &[])
+                .unwrap();
+        let ids: std::collections::HashSet<&str> =
+            readings.iter().map(|r| r.device_id.as_str()).collect();
+        assert!(ids.len() > 4900);
+        assert!(ids.iter().all(|id| id.len() == "sensor-".len() + 6));
+
+        // Every device gets its own metric series, so an id never mixes metrics.
+        let mut metric_of = std::collections::HashMap::new();
+        for r in &readings {
+            assert_eq!(
+                *metric_of.entry(r.device_id.as_str()).or_insert(r.metric),
+                r.metric
+            );
+        }
+    }
+
+    #[test]
+    fn test_sensor_readings_metrics_and_errors() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let readings = generate_sensor_readings(
+            &mut rng,
+            200,
+            "2024-01-01",
+            "2024-01-01",
+            3,
+            &["co2".to_string()],
+        )
+        .unwrap();
+        assert!(readings
+            .iter()
+            .all(|r| r.metric == "co2" && r.unit == "ppm"));
+
+        assert_eq!(
+            generate_sensor_readings(
+                &mut rng,
+                1,
+                "2024-01-01",
+                "2024-01-01",
+                3,
+                &["rain".to_string()]
+            ),
+            Err(SensorError::UnknownMetric("rain".to_string()))
+        );
+        assert_eq!(
+            generate_sensor_readings(&mut rng, 1, "2024-01-01", "2024-01-01", 0, &[]),
+            Err(SensorError::NoSensors)
+        );
+        assert!(matches!(
+            generate_sensor_readings(&mut rng, 1, "2024-01-02", "2024-01-01", 1, &[]),
+            Err(SensorError::DateRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_sensor_readings_record_batch() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+        let readings =
+            generate_sensor_readings(&mut rng, 50, "2024-01-01", "2024-01-07", 5, &[]).unwrap();
+        let batch = sensor_readings_to_record_batch(&readings);
+        assert_eq!(batch.num_rows(), 50);
+        assert_eq!(batch.schema().field(1).name(), "timestamp");
+        let values = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(values.value(0), readings[0].value);
+    }
+}
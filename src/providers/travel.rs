@@ -0,0 +1,266 @@
+//! Travel data generation provider.
+//!
+//! Generates airports from an embedded list of major international airports,
+//! so codes, names and cities always belong to the same real airport, and
+//! flight numbers from an embedded list of airline IATA carrier codes.
+//!
+//! | Field | Example |
+//! |-------|---------|
+//! | IATA code | `LHR` |
+//! | ICAO code | `EGLL` |
+//! | Name | `London Heathrow Airport` |
+//! | City | `London` |
+//! | Country | `GB` |
+//! | Flight number | `BA283` |
+
+use crate::data::en_us::{AIRLINES, AIRPORTS};
+use crate::rng::ForgeryRng;
+
+/// An airport with its codes and location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Airport {
+    /// Three-letter IATA code (e.g., "LHR")
+    pub iata: String,
+    /// Four-letter ICAO code (e.g., "EGLL")
+    pub icao: String,
+    /// Airport name
+    pub name: String,
+    /// City the airport serves
+    pub city: String,
+    /// ISO 3166-1 alpha-2 country code
+    pub country: String,
+}
+
+/// An (IATA, ICAO, name, city, country) entry of the airport data.
+type AirportEntry = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+);
+
+/// Pick a random airport entry.
+#[inline]
+fn choose_airport(rng: &mut ForgeryRng) -> &'static AirportEntry {
+    rng.choose(AIRPORTS)
+}
+
+/// Generate a batch of IATA airport codes.
+pub fn generate_airport_codes(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut codes = Vec::with_capacity(n);
+    for _ in 0..n {
+        codes.push(generate_airport_code(rng));
+    }
+    codes
+}
+
+/// Generate a single IATA airport code (e.g., "LHR").
+#[inline]
+pub fn generate_airport_code(rng: &mut ForgeryRng) -> String {
+    choose_airport(rng).0.to_string()
+}
+
+/// Generate a batch of ICAO airport codes.
+pub fn generate_airport_icao_codes(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut codes = Vec::with_capacity(n);
+    for _ in 0..n {
+        codes.push(generate_airport_icao_code(rng));
+    }
+    codes
+}
+
+/// Generate a single ICAO airport code (e.g., "EGLL").
+#[inline]
+pub fn generate_airport_icao_code(rng: &mut ForgeryRng) -> String {
+    choose_airport(rng).1.to_string()
+}
+
+/// Generate a batch of airport names.
+pub fn generate_airport_names(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut names = Vec::with_capacity(n);
+    for _ in 0..n {
+        names.push(generate_airport_name(rng));
+    }
+    names
+}
+
+/// Generate a single airport name (e.g., "London Heathrow Airport").
+#[inline]
+pub fn generate_airport_name(rng: &mut ForgeryRng) -> String {
+    choose_airport(rng).2.to_string()
+}
+
+/// Generate a batch of cities served by an airport in the dataset.
+pub fn generate_airport_cities(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut cities = Vec::with_capacity(n);
+    for _ in 0..n {
+        cities.push(generate_airport_city(rng));
+    }
+    cities
+}
+
+/// Generate a single airport city (e.g., "London").
+#[inline]
+pub fn generate_airport_city(rng: &mut ForgeryRng) -> String {
+    choose_airport(rng).3.to_string()
+}
+
+/// Generate a batch of airports.
+pub fn generate_airports(rng: &mut ForgeryRng, n: usize) -> Vec<Airport> {
+    let mut airports = Vec::with_capacity(n);
+    for _ in 0..n {
+        airports.push(generate_airport(rng));
+    }
+    airports
+}
+
+/// Generate a single airport with matching codes, name, city and country.
+#[inline]
+pub fn generate_airport(rng: &mut ForgeryRng) -> Airport {
+    let &(iata, icao, name, city, country) = choose_airport(rng);
+    Airport {
+        iata: iata.to_string(),
+        icao: icao.to_string(),
+        name: name.to_string(),
+        city: city.to_string(),
+        country: country.to_string(),
+    }
+}
+
+/// Generate a batch of flight numbers.
+pub fn generate_flight_numbers(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut numbers = Vec::with_capacity(n);
+    for _ in 0..n {
+        numbers.push(generate_flight_number(rng));
+    }
+    numbers
+}
+
+/// Generate a single flight number (e.g., "BA283").
+///
+/// An airline's IATA carrier code followed by 1-4 digits without a leading
+/// zero; each digit count is equally likely.
+#[inline]
+pub fn generate_flight_number(rng: &mut ForgeryRng) -> String {
+    let (carrier, _) = rng.choose(AIRLINES);
+    let digits = rng.gen_range(1u32, 4);
+    let number = rng.gen_range(10u32.pow(digits - 1), 10u32.pow(digits) - 1);
+    format!("{}{}", carrier, number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_airport_data() {
+        for &(iata, icao, name, city, country) in AIRPORTS {
+            assert_eq!(iata.len(), 3, "{}", iata);
+            assert!(iata.bytes().all(|b| b.is_ascii_uppercase()), "{}", iata);
+            assert_eq!(icao.len(), 4, "{}", icao);
+            assert!(icao.bytes().all(|b| b.is_ascii_uppercase()), "{}", icao);
+            assert!(!name.is_empty() && !city.is_empty());
+            assert_eq!(country.len(), 2, "{}", country);
+        }
+        assert!(AIRPORTS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_airline_data() {
+        for &(code, name) in AIRLINES {
+            assert_eq!(code.len(), 2, "{}", code);
+            assert!(
+                code.bytes()
+                    .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()),
+                "{}",
+                code
+            );
+            assert!(!name.is_empty());
+        }
+        assert!(AIRLINES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_generate_flight_numbers() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let numbers = generate_flight_numbers(&mut rng, 500);
+        assert_eq!(numbers.len(), 500);
+        for number in &numbers {
+            let (carrier, digits) = number.split_at(2);
+            assert!(AIRLINES.iter().any(|a| a.0 == carrier), "{}", number);
+            assert!((1..=4).contains(&digits.len()), "{}", number);
+            assert!(digits.bytes().all(|b| b.is_ascii_digit()), "{}", number);
+            assert!(!digits.starts_with('0'), "{}", number);
+        }
+        assert!(numbers.iter().any(|n| n.len() == 3));
+        assert!(numbers.iter().any(|n| n.len() == 6));
+    }
+
+    #[test]
+    fn test_generate_airport_codes() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let codes = generate_airport_codes(&mut rng, 200);
+        assert_eq!(codes.len(), 200);
+        assert!(codes.iter().all(|c| AIRPORTS.iter().any(|a| a.0 == c)));
+
+        let icao = generate_airport_icao_codes(&mut rng, 200);
+        assert!(icao.iter().all(|c| AIRPORTS.iter().any(|a| a.1 == c)));
+
+        let names = generate_airport_names(&mut rng, 200);
+        assert!(names.iter().all(|n| AIRPORTS.iter().any(|a| a.2 == n)));
+
+        let cities = generate_airport_cities(&mut rng, 200);
+        assert!(cities.iter().all(|c| AIRPORTS.iter().any(|a| a.3 == c)));
+    }
+
+    #[test]
+    fn test_generate_airports_consistent() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for airport in generate_airports(&mut rng, 200) {
+            let entry = AIRPORTS.iter().find(|a| a.0 == airport.iata).unwrap();
+            assert_eq!(entry.1, airport.icao);
+            assert_eq!(entry.2, airport.name);
+            assert_eq!(entry.3, airport.city);
+            assert_eq!(entry.4, airport.country);
+        }
+    }
+
+    #[test]
+    fn test_airports_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(
+            generate_airports(&mut rng1, 50),
+            generate_airports(&mut rng2, 50)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_airport_codes_known(seed in any::<u64>(), n in 0usize..100) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let codes = generate_airport_codes(&mut rng, n);
+            prop_assert_eq!(codes.len(), n);
+            for code in codes {
+                prop_assert!(AIRPORTS.iter().any(|a| a.0 == code));
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+//! Vehicle data generation provider.
+//!
+//! Generates 17-character Vehicle Identification Numbers (ISO 3779 / FMVSS 115)
+//! with a valid position-9 check digit. The letters I, O and Q are never used.
+//!
+//! | Positions | Meaning | Example |
+//! |-----------|---------|---------|
+//! | 1-3 | World Manufacturer Identifier | `1HG` |
+//! | 4-8 | Vehicle descriptor | `CM826` |
+//! | 9 | Check digit | `3` |
+//! | 10 | Model year | `A` |
+//! | 11 | Plant code | `0` |
+//! | 12-17 | Serial number | `04352` |
+//!
+//! Also generates makes, models and whole vehicles from an embedded dataset.
+//! A vehicle's VIN starts with a WMI of its make and encodes its model year,
+//! and the year is never earlier than the model's introduction.
+
+use crate::data::en_us::{VehicleMake, VEHICLE_MAKES};
+use crate::rng::ForgeryRng;
+
+/// Characters allowed in a VIN (no I, O or Q).
+pub const VIN_CHARS: &[u8] = b"ABCDEFGHJKLMNPRSTUVWXYZ0123456789";
+
+/// Model year codes for position 10, excluding U, Z and 0 as well.
+const VIN_YEAR_CODES: &[u8] = b"ABCDEFGHJKLMNPRSTVWXY123456789";
+
+/// World Manufacturer Identifiers of common makes.
+pub const VIN_WMIS: &[&str] = &[
+    "1FA", "1FT", "1G1", "1GC", "1HG", "1N4", "2HG", "2T1", "3FA", "3VW", "4T1", "5YJ", "JHM",
+    "JN1", "JTD", "KMH", "KNA", "SAJ", "SAL", "VF1", "WAU", "WBA", "WDD", "WVW", "YV1", "ZFA",
+];
+
+/// Check digit weights for each of the 17 positions.
+const VIN_WEIGHTS: [u32; 17] = [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Numeric value of a VIN character for the check digit calculation.
+fn transliterate(c: u8) -> Option<u32> {
+    let value = match c {
+        b'0'..=b'9' => c - b'0',
+        b'A' | b'J' => 1,
+        b'B' | b'K' | b'S' => 2,
+        b'C' | b'L' | b'T' => 3,
+        b'D' | b'M' | b'U' => 4,
+        b'E' | b'N' | b'V' => 5,
+        b'F' | b'W' => 6,
+        b'G' | b'P' | b'X' => 7,
+        b'H' | b'Y' => 8,
+        b'R' | b'Z' => 9,
+        _ => return None,
+    };
+    Some(u32::from(value))
+}
+
+/// Check digit for a 17-character VIN; position 9 is ignored (weight 0).
+fn vin_check_digit(vin: &[u8]) -> Option<u8> {
+    let mut sum = 0;
+    for (&c, w) in vin.iter().zip(VIN_WEIGHTS) {
+        sum += transliterate(c)? * w;
+    }
+    Some(match sum % 11 {
+        10 => b'X',
+        d => b'0' + d as u8,
+    })
+}
+
+/// Validate a VIN: 17 allowed characters with a correct check digit.
+pub fn validate_vin(vin: &str) -> bool {
+    let bytes = vin.as_bytes();
+    if bytes.len() != 17 || !bytes.iter().all(|b| VIN_CHARS.contains(b)) {
+        return false;
+    }
+    vin_check_digit(bytes) == Some(bytes[8])
+}
+
+/// Generate a batch of VINs with valid check digits.
+pub fn generate_vins(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut vins = Vec::with_capacity(n);
+    for _ in 0..n {
+        vins.push(generate_vin(rng));
+    }
+    vins
+}
+
+/// Generate a single 17-character VIN (e.g., "1HGCM82633A004352").
+#[inline]
+pub fn generate_vin(rng: &mut ForgeryRng) -> String {
+    let wmi = *rng.choose(VIN_WMIS);
+    build_vin(rng, wmi, None)
+}
+
+/// Build a VIN from a WMI, drawing the model year code if not given.
+fn build_vin(rng: &mut ForgeryRng, wmi: &str, year_code: Option<u8>) -> String {
+    let mut vin = Vec::with_capacity(17);
+    vin.extend_from_slice(wmi.as_bytes());
+    for _ in 0..5 {
+        vin.push(*rng.choose(VIN_CHARS));
+    }
+    // Placeholder; weight 0 keeps it out of the sum
+    vin.push(b'0');
+    vin.push(year_code.unwrap_or_else(|| *rng.choose(VIN_YEAR_CODES)));
+    vin.push(*rng.choose(VIN_CHARS));
+    for _ in 0..6 {
+        vin.push(b'0' + rng.gen_range(0u8, 9));
+    }
+    vin[8] = vin_check_digit(&vin).expect("VIN characters are valid");
+    String::from_utf8(vin).expect("VIN is ASCII")
+}
+
+/// Earliest model year generated for a vehicle.
+const VEHICLE_MIN_YEAR: u16 = 1995;
+
+/// Latest model year generated for a vehicle.
+const VEHICLE_MAX_YEAR: u16 = 2024;
+
+/// A vehicle with a VIN matching its make and model year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vehicle {
+    /// Manufacturer (e.g., "Toyota")
+    pub make: String,
+    /// Model (e.g., "Camry")
+    pub model: String,
+    /// Model year
+    pub year: u16,
+    /// 17-character VIN with the make's WMI and the year's code
+    pub vin: String,
+}
+
+/// VIN position 10 code for a model year; the codes repeat every 30 years.
+fn vin_year_code(year: u16) -> u8 {
+    VIN_YEAR_CODES[usize::from((year - 1980) % 30)]
+}
+
+/// Pick a random make entry.
+#[inline]
+fn choose_make(rng: &mut ForgeryRng) -> &'static VehicleMake {
+    rng.choose(VEHICLE_MAKES)
+}
+
+/// Generate a batch of vehicle makes.
+pub fn generate_vehicle_makes(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut makes = Vec::with_capacity(n);
+    for _ in 0..n {
+        makes.push(generate_vehicle_make(rng));
+    }
+    makes
+}
+
+/// Generate a single vehicle make (e.g., "Toyota").
+#[inline]
+pub fn generate_vehicle_make(rng: &mut ForgeryRng) -> String {
+    choose_make(rng).0.to_string()
+}
+
+/// Generate a batch of vehicle models.
+pub fn generate_vehicle_models(rng: &mut ForgeryRng, n: usize) -> Vec<String> {
+    let mut models = Vec::with_capacity(n);
+    for _ in 0..n {
+        models.push(generate_vehicle_model(rng));
+    }
+    models
+}
+
+/// Generate a single vehicle model (e.g., "Camry").
+#[inline]
+pub fn generate_vehicle_model(rng: &mut ForgeryRng) -> String {
+    let models = choose_make(rng).2;
+    rng.choose(models).0.to_string()
+}
+
+/// Generate a batch of vehicles.
+pub fn generate_vehicles(rng: &mut ForgeryRng, n: usize) -> Vec<Vehicle> {
+    let mut vehicles = Vec::with_capacity(n);
+    for _ in 0..n {
+        vehicles.push(generate_vehicle(rng));
+    }
+    vehicles
+}
+
+/// Generate a single vehicle with consistent make, model, year and VIN.
+pub fn generate_vehicle(rng: &mut ForgeryRng) -> Vehicle {
+    let &(make, wmis, models) = choose_make(rng);
+    let (model, first_year) = *rng.choose(models);
+    let year = rng.gen_range(first_year.max(VEHICLE_MIN_YEAR), VEHICLE_MAX_YEAR);
+    let wmi = *rng.choose(wmis);
+    Vehicle {
+        make: make.to_string(),
+        model: model.to_string(),
+        year,
+        vin: build_vin(rng, wmi, Some(vin_year_code(year))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_vin_known_values() {
+        assert!(validate_vin("1HGCM82633A004352"));
+        assert!(validate_vin("1M8GDM9AXKP042788"));
+        assert!(validate_vin("11111111111111111"));
+        assert!(!validate_vin("1HGCM82643A004352"));
+        assert!(!validate_vin("1HGCM82633A00435"));
+        assert!(!validate_vin("1HGCM8263IA004352"));
+    }
+
+    #[test]
+    fn test_generate_vins() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        let vins = generate_vins(&mut rng, 500);
+        assert_eq!(vins.len(), 500);
+        for vin in &vins {
+            assert!(validate_vin(vin), "{}", vin);
+            assert!(!vin.contains(['I', 'O', 'Q']), "{}", vin);
+            assert!(VIN_WMIS.contains(&&vin[..3]), "{}", vin);
+            assert!(VIN_YEAR_CODES.contains(&vin.as_bytes()[9]), "{}", vin);
+            assert!(vin[11..].bytes().all(|b| b.is_ascii_digit()), "{}", vin);
+        }
+        assert!(vins.iter().any(|v| &v[8..9] == "X"));
+    }
+
+    #[test]
+    fn test_vehicle_data_wmis() {
+        for (make, wmis, models) in VEHICLE_MAKES {
+            assert!(!wmis.is_empty() && !models.is_empty(), "{}", make);
+            for wmi in *wmis {
+                assert!(VIN_WMIS.contains(wmi), "{} {}", make, wmi);
+            }
+            for (_, first_year) in *models {
+                assert!(*first_year <= VEHICLE_MAX_YEAR, "{}", make);
+            }
+        }
+        for wmi in VIN_WMIS {
+            assert!(
+                VEHICLE_MAKES.iter().any(|(_, wmis, _)| wmis.contains(wmi)),
+                "{}",
+                wmi
+            );
+        }
+    }
+
+    #[test]
+    fn test_vin_year_code() {
+        assert_eq!(vin_year_code(2010), b'A');
+        assert_eq!(vin_year_code(2001), b'1');
+        assert_eq!(vin_year_code(2024), b'R');
+        assert_eq!(vin_year_code(1995), b'S');
+    }
+
+    #[test]
+    fn test_generate_vehicles_consistent() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for v in generate_vehicles(&mut rng, 300) {
+            let (_, wmis, models) = VEHICLE_MAKES.iter().find(|m| m.0 == v.make).unwrap();
+            let (_, first_year) = models.iter().find(|m| m.0 == v.model).unwrap();
+            assert!(v.year >= (*first_year).max(VEHICLE_MIN_YEAR), "{:?}", v);
+            assert!(v.year <= VEHICLE_MAX_YEAR, "{:?}", v);
+            assert!(validate_vin(&v.vin), "{}", v.vin);
+            assert!(wmis.contains(&&v.vin[..3]), "{:?}", v);
+            assert_eq!(v.vin.as_bytes()[9], vin_year_code(v.year), "{:?}", v);
+        }
+    }
+
+    #[test]
+    fn test_vehicle_makes_and_models() {
+        let mut rng = ForgeryRng::new();
+        rng.seed(42);
+
+        for make in generate_vehicle_makes(&mut rng, 50) {
+            assert!(VEHICLE_MAKES.iter().any(|m| m.0 == make));
+        }
+        for model in generate_vehicle_models(&mut rng, 50) {
+            assert!(VEHICLE_MAKES
+                .iter()
+                .any(|m| m.2.iter().any(|(name, _)| *name == model)));
+        }
+    }
+
+    #[test]
+    fn test_vin_deterministic() {
+        let mut rng1 = ForgeryRng::new();
+        let mut rng2 = ForgeryRng::new();
+        rng1.seed(99);
+        rng2.seed(99);
+
+        assert_eq!(generate_vins(&mut rng1, 50), generate_vins(&mut rng2, 50));
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_vin_valid(seed in any::<u64>(), n in 0usize..100) {
+            let mut rng = ForgeryRng::new();
+            rng.seed(seed);
+            let vins = generate_vins(&mut rng, n);
+            prop_assert_eq!(vins.len(), n);
+            for vin in vins {
+                prop_assert!(validate_vin(&vin), "{}", vin);
+            }
+        }
+    }
+}
@@ -365,6 +365,45 @@ class TestCreditCardValidation:
         mc_cards = [c for c in cards if c[:2] in ("51", "52", "53", "54", "55")]
         assert len(mc_cards) > 0, "Should generate some Mastercard cards"
 
+    @pytest.mark.parametrize(
+        ("network", "prefixes", "length"),
+        [
+            ("visa", ("4",), 16),
+            ("mastercard", ("51", "52", "53", "54", "55"), 16),
+            ("amex", ("34", "37"), 15),
+            ("discover", ("6011", "65"), 16),
+        ],
+    )
+    def test_credit_cards_network_filter(
+        self, network: str, prefixes: tuple[str, ...], length: int
+    ) -> None:
+        """Test that a network restricts cards to its IIN family."""
+        f = Faker()
+        f.seed(42)
+        for card in f.credit_cards(200, network=network):
+            assert card.startswith(prefixes), f"{network}: {card}"
+            assert len(card) == length
+            assert self._validate_luhn(card)
+        assert f.credit_card(network).startswith(prefixes)
+
+    def test_credit_card_unknown_network(self):
+        """Test that unknown networks raise ValueError."""
+        f = Faker()
+        with pytest.raises(ValueError, match="unknown card network"):
+            f.credit_cards(10, network="diners")
+        with pytest.raises(ValueError, match="unknown card network"):
+            f.credit_card("VISA")
+
+    def test_credit_card_network_schema(self):
+        """Test the ("credit_card", network) schema spec."""
+        f = Faker()
+        f.seed(42)
+        rows = f.records(50, {"card": ("credit_card", "amex")})
+        for row in rows:
+            assert row["card"][:2] in ("34", "37")
+        with pytest.raises(ValueError, match="unknown card network"):
+            f.records(1, {"card": ("credit_card", "diners")})
+
 
 class TestIBANValidation:
     """Test IBAN format and checksum validation from Python."""
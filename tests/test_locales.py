@@ -1,10 +1,12 @@
 """Tests for locale support across all providers."""
 
+import re
+
 import pytest
 
 from forgery import Faker
 
-SUPPORTED_LOCALES = ["en_US", "en_GB", "de_DE", "fr_FR", "es_ES", "it_IT", "ja_JP"]
+SUPPORTED_LOCALES = ["en_US", "en_GB", "de_DE", "fr_FR", "es_ES", "it_IT", "ja_JP", "ko_KR", "ru_RU", "nl_NL", "pl_PL", "en_CA"]
 
 
 class TestLocaleInstantiation:
@@ -140,6 +142,61 @@ class TestLocalePostalCodeFormats:
             assert len(z) == 8
             assert z[3] == "-"
 
+    def test_korean_postal_format(self) -> None:
+        """Korean postal codes should be 5 digits."""
+        fake = Faker("ko_KR")
+        zips = fake.zip_codes(100)
+        for z in zips:
+            assert len(z) == 5
+            assert z.isdigit()
+
+    def test_russian_postal_format(self) -> None:
+        """Russian postal codes should be 6 digits."""
+        fake = Faker("ru_RU")
+        zips = fake.zip_codes(100)
+        for z in zips:
+            assert len(z) == 6
+            assert z.isdigit()
+
+    def test_dutch_postal_format(self) -> None:
+        """Dutch postal codes should be '1234 AB' format."""
+        fake = Faker("nl_NL")
+        zips = fake.zip_codes(100)
+        for z in zips:
+            digits, letters = z.split(" ")
+            assert len(digits) == 4 and digits.isdigit()
+            assert len(letters) == 2 and letters.isalpha() and letters.isupper()
+
+    def test_polish_postal_format(self) -> None:
+        """Polish postal codes should be '00-950' format."""
+        fake = Faker("pl_PL")
+        zips = fake.zip_codes(100)
+        for z in zips:
+            assert len(z) == 6
+            assert z[2] == "-"
+            assert z.replace("-", "").isdigit()
+
+    def test_canadian_postal_format(self) -> None:
+        """Canadian postal codes should be 'A1A 1A1' format."""
+        fake = Faker("en_CA")
+        zips = fake.zip_codes(100)
+        for z in zips:
+            assert len(z) == 7
+            assert z[3] == " "
+            chars = z.replace(" ", "")
+            assert chars[0::2].isalpha() and chars[0::2].isupper()
+            assert chars[1::2].isdigit()
+
+    def test_canadian_address_format(self) -> None:
+        """Canadian addresses should end with 'City PR  A1A 1A1'."""
+        fake = Faker("en_CA")
+        for address in fake.addresses(50):
+            last_line = address.split("\n")[-1]
+            assert "  " in last_line
+            city_province, postal = last_line.rsplit("  ", 1)
+            assert len(postal) == 7
+            assert len(city_province.rsplit(" ", 1)[1]) == 2
+
 
 class TestLocalePhoneNumbers:
     """Test phone number generation for all locales."""
@@ -223,8 +280,9 @@ class TestLocaleText:
         sentences = fake.sentences(10)
         assert len(sentences) == 10
         assert all(isinstance(s, str) for s in sentences)
-        # All should end with period
-        assert all(s.endswith(".") for s in sentences)
+        # All should end with the locale's full stop
+        full_stop = "。" if locale == "ja_JP" else "."
+        assert all(s.endswith(full_stop) for s in sentences)
 
     @pytest.mark.parametrize("locale", SUPPORTED_LOCALES)
     def test_paragraphs_generation(self, locale: str) -> None:
@@ -234,6 +292,27 @@ class TestLocaleText:
         assert len(paragraphs) == 5
         assert all(isinstance(p, str) for p in paragraphs)
 
+    @pytest.mark.parametrize("locale", SUPPORTED_LOCALES)
+    def test_words_generation(self, locale: str) -> None:
+        """Raw words should be generated for all locales."""
+        fake = Faker(locale)
+        lists = fake.words(5, count=4)
+        assert all(len(words) == 4 for words in lists)
+        assert all(w and not w.endswith(".") for words in lists for w in words)
+
+    def test_japanese_text_has_no_spaces(self) -> None:
+        """ja_JP text joins words without spaces and uses Japanese punctuation."""
+        fake = Faker("ja_JP")
+        fake.seed(42)
+        for s in fake.sentences(20, comma_rate=0.5):
+            assert s.endswith("。")
+            assert " " not in s and "," not in s
+        assert any("、" in s for s in fake.sentences(20, comma_rate=0.5))
+        assert fake.paragraph(sentence_count=3).count("。") == 3
+        for t in fake.texts(20, min_chars=20, max_chars=40):
+            assert 20 <= len(t) <= 40
+            assert " " not in t
+
 
 class TestLocaleDeterminism:
     """Test that seeding produces deterministic results per locale."""
@@ -362,6 +441,20 @@ class TestLocaleAddressFormats:
             # Postal code follows (format: XXX-XXXX)
             assert "-" in addr[:12], f"Japanese address should have postal code: {addr}"
 
+    def test_korean_full_address_format(self) -> None:
+        """Korean full addresses should go from postal code down to the road."""
+        fake = Faker("ko_KR")
+        fake.seed(42)
+        addresses = fake.addresses(50)
+        for addr in addresses:
+            # Korean template is "{postal} {region} {city} {street}"
+            parts = addr.split()
+            assert parts[0].isdigit() and len(parts[0]) == 5, (
+                f"Korean address should start with 5-digit postal: {addr}"
+            )
+            # Road number comes last
+            assert parts[-1].isdigit(), f"Korean address should end with road number: {addr}"
+
     def test_us_full_address_format(self) -> None:
         """US full addresses should use US template format."""
         fake = Faker("en_US")
@@ -395,3 +488,386 @@ class TestLocaleRecords:
             assert "email" in record
             assert "city" in record
             assert "company" in record
+
+
+class TestLocaleDataOverrides:
+    """Test user-supplied overrides layered on top of locale data."""
+
+    def test_override_first_names(self) -> None:
+        """Overridden first names should replace the locale's list."""
+        fake = Faker("de_DE")
+        fake.seed(42)
+        fake.override_locale_data(first_names=["Ada", "Grace"])
+        for name in fake.first_names(50):
+            assert name in ("Ada", "Grace")
+
+    def test_override_keeps_other_locale_data(self) -> None:
+        """Fields that are not overridden should still use the locale's data."""
+        baseline = Faker("de_DE")
+        baseline.seed(42)
+        fake = Faker("de_DE")
+        fake.seed(42)
+        fake.override_locale_data(cities=["Entenhausen"])
+        assert fake.last_names(20) == baseline.last_names(20)
+        assert fake.cities(5) == ["Entenhausen"] * 5
+
+    def test_override_keeps_locale_formats(self) -> None:
+        """Overrides should slot into the locale's name order and address format."""
+        fake = Faker("ja_JP")
+        fake.override_locale_data(first_names=["花子"], last_names=["佐藤"])
+        assert fake.name() == "佐藤 花子"
+
+        fake = Faker("nl_NL")
+        fake.override_locale_data(cities=["Duckstad"])
+        postal_city = fake.address().split("\n")[-1]
+        assert postal_city.endswith(" Duckstad")
+
+    def test_override_applies_to_records_and_emails(self) -> None:
+        """Schema fields and email local parts should draw from overrides."""
+        fake = Faker("de_DE")
+        fake.seed(42)
+        fake.override_locale_data(first_names=["Ada"], cities=["Entenhausen"])
+        for record in fake.records(10, {"first": "first_name", "city": "city"}):
+            assert record == {"first": "Ada", "city": "Entenhausen"}
+        batch = fake.records_arrow(10, {"first": "first_name"})
+        assert batch.column("first").to_pylist() == ["Ada"] * 10
+        assert fake.email().startswith("ada")
+
+    def test_overrides_layer_across_calls(self) -> None:
+        """Later calls should only replace the lists they pass."""
+        fake = Faker()
+        fake.override_locale_data(first_names=["Ada"])
+        fake.override_locale_data(last_names=["Lovelace"])
+        assert fake.name() == "Ada Lovelace"
+
+    def test_clear_locale_overrides(self) -> None:
+        """Clearing overrides should restore the built-in data."""
+        baseline = Faker()
+        baseline.seed(1)
+        fake = Faker()
+        fake.override_locale_data(first_names=["Ada"])
+        fake.clear_locale_overrides()
+        fake.seed(1)
+        assert fake.first_names(20) == baseline.first_names(20)
+
+    def test_override_empty_list_raises(self) -> None:
+        """Empty override lists should be rejected."""
+        fake = Faker()
+        with pytest.raises(ValueError, match="cannot be empty"):
+            fake.override_locale_data(cities=[])
+
+    def test_module_level_override(self) -> None:
+        """Module-level functions should override the default instance."""
+        from forgery import city, clear_locale_overrides, override_locale_data
+
+        override_locale_data(cities=["Springfield"])
+        try:
+            assert city() == "Springfield"
+        finally:
+            clear_locale_overrides()
+        assert city() != "Springfield"
+
+
+class TestRomanizedNames:
+    """Test romanized name accessors."""
+
+    @pytest.mark.parametrize("locale", ["ja_JP", "ko_KR", "ru_RU"])
+    def test_romanized_names_are_ascii(self, locale: str) -> None:
+        """Non-Latin locales should produce ASCII romanized names."""
+        fake = Faker(locale)
+        fake.seed(42)
+        for name in fake.names_romanized(50):
+            assert name.isascii(), f"{locale} produced non-ASCII name: {name}"
+        assert fake.first_name_romanized().isascii()
+        assert fake.last_name_romanized().isascii()
+
+    def test_french_romanized_first_names(self) -> None:
+        """French romanized first names should have diacritics stripped."""
+        fake = Faker("fr_FR")
+        fake.seed(42)
+        assert all(name.isascii() for name in fake.first_names_romanized(200))
+
+    def test_english_falls_back_to_regular_names(self) -> None:
+        """Locales without romanized data should return their regular names."""
+        romanized = Faker("en_US")
+        regular = Faker("en_US")
+        romanized.seed(7)
+        regular.seed(7)
+        assert romanized.last_names_romanized(20) == regular.last_names(20)
+
+    def test_unique_romanized_names(self) -> None:
+        """Unique romanized batches should not repeat."""
+        fake = Faker("ja_JP")
+        fake.seed(42)
+        result = fake.last_names_romanized(20, unique=True)
+        assert len(set(result)) == 20
+
+    def test_module_level_romanized(self) -> None:
+        """Module-level functions should use the default instance."""
+        from forgery import first_names_romanized, name_romanized
+
+        assert isinstance(name_romanized(), str)
+        assert len(first_names_romanized(5)) == 5
+
+
+class TestLocalizedDates:
+    """Test locale-aware date and datetime formatting."""
+
+    @pytest.mark.parametrize(
+        ("locale", "pattern"),
+        [
+            ("en_US", r"^\d{2}/\d{2}/2024$"),
+            ("en_GB", r"^\d{2}/\d{2}/2024$"),
+            ("de_DE", r"^\d{2}\.\d{2}\.2024$"),
+            ("ja_JP", r"^2024年\d{1,2}月\d{1,2}日$"),
+            ("ko_KR", r"^2024\. \d{1,2}\. \d{1,2}\.$"),
+            ("ru_RU", r"^\d{2}\.\d{2}\.2024$"),
+        ],
+    )
+    def test_locale_date_format(self, locale: str, pattern: str) -> None:
+        """format="locale" should use the locale's numeric date format."""
+        fake = Faker(locale)
+        fake.seed(42)
+        for value in fake.dates(50, "2024-01-01", "2024-12-31", format="locale"):
+            assert re.match(pattern, value), f"{locale}: {value}"
+
+    def test_locale_long_uses_month_names(self) -> None:
+        """format="locale_long" should spell out month and weekday names."""
+        fake = Faker("de_DE")
+        fake.seed(42)
+        value = fake.date("2024-12-31", "2024-12-31", format="locale_long")
+        assert value == "Dienstag, 31. Dezember 2024"
+
+    def test_locale_datetime_format(self) -> None:
+        """Locale datetimes should append the locale's time format."""
+        fake = Faker("en_US")
+        fake.seed(42)
+        value = fake.datetime("2024-12-31", "2024-12-31", format="locale")
+        assert re.match(r"^12/31/2024 \d{1,2}:\d{2}:\d{2} (AM|PM)$", value)
+
+        fake = Faker("de_DE")
+        value = fake.datetime("2024-12-31", "2024-12-31", format="locale")
+        assert re.match(r"^31\.12\.2024 \d{2}:\d{2}:\d{2}$", value)
+
+    def test_iso_is_default(self) -> None:
+        """ISO output should be the default regardless of locale."""
+        fake = Faker("ja_JP")
+        fake.seed(42)
+        assert fake.date("2024-12-31", "2024-12-31") == "2024-12-31"
+        assert fake.date("2024-12-31", "2024-12-31", format="iso") == "2024-12-31"
+
+    def test_unknown_format_raises(self) -> None:
+        """Unknown format names should raise ValueError."""
+        fake = Faker()
+        with pytest.raises(ValueError, match="unknown date format"):
+            fake.dates(5, format="rfc")
+
+
+class TestNationalIds:
+    """Test locale-aware national ID generation."""
+
+    @pytest.mark.parametrize(
+        ("locale", "pattern"),
+        [
+            ("en_US", r"^\d{3}-\d{2}-\d{4}$"),
+            ("en_GB", r"^[A-Z]{2}\d{6}[A-D]$"),
+            ("en_CA", r"^\d{3} \d{3} \d{3}$"),
+            ("de_DE", r"^[1-9]\d{10}$"),
+            ("fr_FR", r"^[12]\d{14}$"),
+            ("es_ES", r"^([XYZ]\d{7}|\d{8})[A-Z]$"),
+            ("it_IT", r"^[A-Z]{6}\d{2}[A-EHLMPRST]\d{2}[A-Z]\d{3}[A-Z]$"),
+            ("nl_NL", r"^\d{9}$"),
+            ("pl_PL", r"^\d{11}$"),
+            ("ru_RU", r"^\d{3}-\d{3}-\d{3} \d{2}$"),
+            ("ja_JP", r"^\d{12}$"),
+            ("ko_KR", r"^\d{6}-[1-4]\d{6}$"),
+        ],
+    )
+    def test_national_id_format(self, locale: str, pattern: str) -> None:
+        """Each locale should produce its own identifier format."""
+        fake = Faker(locale)
+        fake.seed(42)
+        for value in fake.national_ids(50):
+            assert re.match(pattern, value), f"{locale}: {value}"
+
+    def test_dni_check_letter(self) -> None:
+        """Spanish DNI/NIE numbers should carry the correct check letter."""
+        letters = "TRWAGMYFPDXBNJZSQVHLCKE"
+        fake = Faker("es_ES")
+        fake.seed(42)
+        for value in fake.national_ids(100):
+            number = int(value[:-1].replace("X", "0").replace("Y", "1").replace("Z", "2"))
+            assert value[-1] == letters[number % 23]
+
+    def test_unique_national_ids(self) -> None:
+        """Unique batches should not repeat."""
+        fake = Faker("it_IT")
+        fake.seed(42)
+        result = fake.national_ids(100, unique=True)
+        assert len(set(result)) == 100
+
+    def test_national_id_schema_type(self) -> None:
+        """national_id should be usable as a records schema type."""
+        fake = Faker("en_GB")
+        fake.seed(42)
+        records = fake.records(5, {"nino": "national_id"})
+        for record in records:
+            assert re.match(r"^[A-Z]{2}\d{6}[A-D]$", record["nino"])
+
+    def test_module_level_national_id(self) -> None:
+        """Module-level functions should use the default instance."""
+        from forgery import national_id, national_ids
+
+        assert re.match(r"^\d{3}-\d{2}-\d{4}$", national_id())
+        assert len(national_ids(5)) == 5
+
+
+class TestCompanyTaxIds:
+    """Test EIN and locale-aware company tax ID generation."""
+
+    EIN_PREFIXES = {
+        *range(1, 7),
+        *range(10, 17),
+        *range(20, 28),
+        *range(30, 49),
+        *range(50, 69),
+        *range(71, 78),
+        *range(80, 89),
+        *range(90, 96),
+        98,
+        99,
+    }
+
+    def test_ein_format(self) -> None:
+        """EINs should use XX-XXXXXXX with an IRS-assigned prefix."""
+        fake = Faker()
+        fake.seed(42)
+        for value in fake.eins(200):
+            assert re.match(r"^\d{2}-\d{7}$", value)
+            assert int(value[:2]) in self.EIN_PREFIXES
+
+    def test_ein_ignores_locale(self) -> None:
+        """EINs are US-only and should not depend on the locale."""
+        fake = Faker("ja_JP")
+        fake.seed(42)
+        assert re.match(r"^\d{2}-\d{7}$", fake.ein())
+
+    @pytest.mark.parametrize(
+        ("locale", "pattern"),
+        [
+            ("en_US", r"^\d{2}-\d{7}$"),
+            ("en_CA", r"^\d{9}RT0001$"),
+            ("en_GB", r"^GB\d{9}$"),
+            ("de_DE", r"^DE\d{9}$"),
+            ("fr_FR", r"^FR\d{11}$"),
+            ("ru_RU", r"^\d{10}$"),
+            ("ja_JP", r"^\d{13}$"),
+            ("ko_KR", r"^\d{3}-\d{2}-\d{5}$"),
+        ],
+    )
+    def test_company_tax_id_format(self, locale: str, pattern: str) -> None:
+        """Each locale should produce its own company identifier format."""
+        fake = Faker(locale)
+        fake.seed(42)
+        for value in fake.company_tax_ids(50):
+            assert re.match(pattern, value), f"{locale}: {value}"
+
+    def test_unique_eins(self) -> None:
+        """Unique batches should not repeat."""
+        fake = Faker()
+        fake.seed(42)
+        assert len(set(fake.eins(500, unique=True))) == 500
+
+    def test_tax_id_schema_types(self) -> None:
+        """ein and company_tax_id should be usable as records schema types."""
+        fake = Faker("ru_RU")
+        fake.seed(42)
+        records = fake.records(5, {"ein": "ein", "inn": "company_tax_id"})
+        for record in records:
+            assert re.match(r"^\d{2}-\d{7}$", record["ein"])
+            assert re.match(r"^\d{10}$", record["inn"])
+
+    def test_module_level_tax_ids(self) -> None:
+        """Module-level functions should use the default instance."""
+        from forgery import company_tax_id, company_tax_ids, ein, eins
+
+        assert re.match(r"^\d{2}-\d{7}$", ein())
+        assert len(eins(5)) == 5
+        assert re.match(r"^\d{2}-\d{7}$", company_tax_id())
+        assert len(company_tax_ids(5)) == 5
+
+
+class TestDriversLicenses:
+    """Test locale- and region-aware driver's license generation."""
+
+    @pytest.mark.parametrize(
+        ("region", "pattern"),
+        [
+            ("CA", r"^[A-Z]\d{7}$"),
+            ("NY", r"^\d{9}$"),
+            ("TX", r"^\d{8}$"),
+            ("FL", r"^[A-Z]\d{12}$"),
+            ("WA", r"^WDL[A-Z0-9]{9}$"),
+            ("ON", r"^[A-Z]\d{4}-\d{5}-\d{5}$"),
+            ("QC", r"^[A-Z]\d{4}-\d{6}-\d{2}$"),
+        ],
+    )
+    def test_region_format(self, region: str, pattern: str) -> None:
+        """A region should pin the state or province format."""
+        fake = Faker()
+        fake.seed(42)
+        for value in fake.drivers_licenses(50, region):
+            assert re.match(pattern, value), f"{region}: {value}"
+
+    @pytest.mark.parametrize(
+        ("locale", "pattern"),
+        [
+            ("en_GB", r"^[A-Z9]{5}\d{6}[A-Z][A-Z9]9[A-Z]{2}$"),
+            ("fr_FR", r"^\d{12}$"),
+            ("es_ES", r"^([XYZ]\d{7}|\d{8})[A-Z]$"),
+            ("it_IT", r"^[A-Z]{2}\d{7}[A-Z]$"),
+            ("pl_PL", r"^\d{5}/\d{2}/\d{4}$"),
+            ("ko_KR", r"^\d{2}-\d{2}-\d{6}-\d{2}$"),
+        ],
+    )
+    def test_locale_format(self, locale: str, pattern: str) -> None:
+        """Without a region each locale should use its national format."""
+        fake = Faker(locale)
+        fake.seed(42)
+        for value in fake.drivers_licenses(50):
+            assert re.match(pattern, value), f"{locale}: {value}"
+
+    def test_region_case_insensitive(self) -> None:
+        """Region codes should be accepted in any case."""
+        fake = Faker()
+        assert re.match(r"^[A-Z]\d{7}$", fake.drivers_license("ca"))
+
+    def test_unknown_region(self) -> None:
+        """Unknown regions should raise ValueError."""
+        fake = Faker()
+        with pytest.raises(ValueError, match="region 'ZZ'"):
+            fake.drivers_license("ZZ")
+        with pytest.raises(ValueError, match="region 'ZZ'"):
+            fake.drivers_licenses(5, "ZZ")
+
+    def test_drivers_license_schema_types(self) -> None:
+        """drivers_license should work as a simple and a parameterized schema type."""
+        fake = Faker("fr_FR")
+        fake.seed(42)
+        schema = {"neph": "drivers_license", "ca": ("drivers_license", "CA")}
+        for record in fake.records(5, schema):
+            assert re.match(r"^\d{12}$", record["neph"])
+            assert re.match(r"^[A-Z]\d{7}$", record["ca"])
+
+    def test_schema_unknown_region(self) -> None:
+        """Unknown schema regions should raise ValueError."""
+        fake = Faker()
+        with pytest.raises(ValueError, match="region 'ZZ'"):
+            fake.records(1, {"dl": ("drivers_license", "ZZ")})
+
+    def test_module_level_drivers_license(self) -> None:
+        """Module-level functions should use the default instance."""
+        from forgery import drivers_license, drivers_licenses
+
+        assert drivers_license()
+        assert len(drivers_licenses(5, "NY")) == 5
@@ -1,24 +1,73 @@
 """Tests for new features: passwords, banking, unique values, and UK banking."""
 
+import base64
+import json
+import re
+
 import pytest
 
 from forgery import (
     Faker,
+    api_key,
+    api_keys,
     bank_account,
     bank_accounts,
     bank_name,
     bank_names,
     bic,
     bics,
+    currency_code,
+    currency_codes,
+    currency_name,
+    currency_names,
+    currency_symbol,
+    currency_symbols,
+    doi,
+    dois,
+    imei,
+    imeis,
+    isbn10,
+    isbn10s,
+    isbn13,
+    isbn13s,
+    jwt,
+    jwts,
+    oauth_access_token,
+    oauth_access_tokens,
+    orcid,
+    orcids,
     password,
+    password_hash,
+    password_hashes,
     passwords,
+    price,
+    prices,
+    refresh_token,
+    refresh_tokens,
+    routing_number,
+    routing_numbers,
+    session_id,
+    session_ids,
     sort_code,
     sort_codes,
+    stock_exchange,
+    stock_exchanges,
+    stocks,
+    ticker,
+    tickers,
+    totp_secret,
+    totp_secrets,
+    totp_uri,
+    totp_uris,
     transaction_amount,
     transaction_amounts,
     transactions,
     uk_account_number,
     uk_account_numbers,
+    vat_number,
+    vat_numbers,
+    vin,
+    vins,
 )
 
 
@@ -77,6 +126,290 @@ class TestPasswordGeneration:
         assert pwds1 == pwds2
 
 
+class TestPasswordHashGeneration:
+    """Tests for password hash string generation."""
+
+    BCRYPT = re.compile(r"^\$2b\$12\$[./A-Za-z0-9]{53}$")
+    ARGON2ID = re.compile(
+        r"^\$argon2id\$v=19\$m=65536,t=3,p=4\$[A-Za-z0-9+/]{22}\$[A-Za-z0-9+/]{43}$"
+    )
+    PBKDF2 = re.compile(r"^pbkdf2_sha256\$600000\$[A-Za-z0-9]{22}\$[A-Za-z0-9+/]{43}=$")
+
+    def test_password_hash_default_bcrypt(self) -> None:
+        """Test the default algorithm is bcrypt."""
+        assert self.BCRYPT.match(password_hash())
+
+    @pytest.mark.parametrize("algorithm", ["bcrypt", "argon2id", "pbkdf2_sha256"])
+    def test_password_hashes_format(self, algorithm: str) -> None:
+        """Test each algorithm produces its own encoding."""
+        pattern = {
+            "bcrypt": self.BCRYPT,
+            "argon2id": self.ARGON2ID,
+            "pbkdf2_sha256": self.PBKDF2,
+        }[algorithm]
+        hashes = password_hashes(100, algorithm)
+        assert len(hashes) == 100
+        assert all(pattern.match(h) for h in hashes)
+        assert len(set(hashes)) == 100
+
+    def test_password_hash_invalid_algorithm(self) -> None:
+        """Test that an unknown algorithm raises error."""
+        with pytest.raises(ValueError, match="unknown password hash algorithm 'md5crypt'"):
+            password_hash("md5crypt")
+
+    def test_password_hashes_deterministic(self) -> None:
+        """Test password hash generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+
+        assert fake1.password_hashes(10, "argon2id") == fake2.password_hashes(10, "argon2id")
+
+    def test_password_hash_schema_types(self) -> None:
+        """Test the simple type and the algorithm tuple spec."""
+        fake = Faker()
+        fake.seed(42)
+        records = fake.records(
+            20,
+            {
+                "default": "password_hash",
+                "django": ("password_hash", "pbkdf2_sha256"),
+            },
+        )
+        for record in records:
+            assert self.BCRYPT.match(record["default"])
+            assert self.PBKDF2.match(record["django"])
+
+        with pytest.raises(ValueError, match="unknown password hash algorithm"):
+            fake.records(1, {"h": ("password_hash", "sha1")})
+
+
+def _decode_jwt_part(part: str) -> bytes:
+    return base64.urlsafe_b64decode(part + "=" * (-len(part) % 4))
+
+
+class TestJwtGeneration:
+    """Tests for JSON Web Token generation."""
+
+    def test_jwt_default_structure(self) -> None:
+        """Test the default token decodes to an HS256 header and UUID subject."""
+        header, payload, signature = jwt().split(".")
+        assert json.loads(_decode_jwt_part(header)) == {"alg": "HS256", "typ": "JWT"}
+        claims = json.loads(_decode_jwt_part(payload))
+        assert set(claims) == {"sub", "iat", "exp"}
+        assert re.match(r"^[0-9a-f]{8}-[0-9a-f]{4}-4", claims["sub"])
+        assert claims["exp"] - claims["iat"] == 3600
+        assert len(_decode_jwt_part(signature)) == 32
+
+    def test_jwts_claims(self) -> None:
+        """Test configured claims and the issued-at range."""
+        tokens = jwts(
+            50,
+            subject='user "42"',
+            issuer="https://auth.example.com",
+            start="2024-01-01",
+            end="2024-12-31",
+            ttl=900,
+        )
+        assert len(tokens) == 50
+        for token in tokens:
+            assert "=" not in token
+            claims = json.loads(_decode_jwt_part(token.split(".")[1]))
+            assert claims["sub"] == 'user "42"'
+            assert claims["iss"] == "https://auth.example.com"
+            assert 1704067200 <= claims["iat"] < 1735689600
+            assert claims["exp"] == claims["iat"] + 900
+
+    @pytest.mark.parametrize(
+        ("algorithm", "length"),
+        [("HS256", 32), ("HS384", 48), ("HS512", 64), ("RS256", 256), ("ES256", 64)],
+    )
+    def test_jwt_algorithm(self, algorithm: str, length: int) -> None:
+        """Test the header names the algorithm and the signature matches its size."""
+        header, _, signature = jwt(algorithm=algorithm.lower()).split(".")
+        assert json.loads(_decode_jwt_part(header))["alg"] == algorithm
+        assert len(_decode_jwt_part(signature)) == length
+
+    def test_jwt_errors(self) -> None:
+        """Test unknown algorithms and invalid ranges raise ValueError."""
+        with pytest.raises(ValueError, match="unknown JWT algorithm 'none'"):
+            jwt(algorithm="none")
+        with pytest.raises(ValueError, match="1970-01-01"):
+            jwts(1, start="1969-01-01")
+
+    def test_jwts_deterministic(self) -> None:
+        """Test JWT generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+
+        assert fake1.jwts(10) == fake2.jwts(10)
+
+    def test_jwt_schema_type(self) -> None:
+        """Test the "jwt" simple schema type."""
+        fake = Faker()
+        fake.seed(42)
+        for record in fake.records(10, {"token": "jwt"}):
+            assert record["token"].count(".") == 2
+
+
+class TestApiKeyGeneration:
+    """Tests for API key generation."""
+
+    def test_api_key_default(self) -> None:
+        """Test the default sk_live_ prefix and 32 base62 characters."""
+        assert re.match(r"^sk_live_[A-Za-z0-9]{32}$", api_key())
+
+    @pytest.mark.parametrize(
+        ("alphabet", "pattern"),
+        [
+            ("base62", r"[A-Za-z0-9]"),
+            ("hex", r"[0-9a-f]"),
+            ("base32", r"[A-Z2-7]"),
+            ("base64url", r"[A-Za-z0-9_-]"),
+        ],
+    )
+    def test_api_keys_alphabet(self, alphabet: str, pattern: str) -> None:
+        """Test each alphabet restricts the random part."""
+        keys = api_keys(100, prefix="pk_test_", length=24, alphabet=alphabet)
+        assert len(keys) == 100
+        assert all(re.match(rf"^pk_test_{pattern}{{24}}$", k) for k in keys)
+
+    def test_api_key_invalid_alphabet(self) -> None:
+        """Test that an unknown alphabet raises error."""
+        with pytest.raises(ValueError, match="unknown API key alphabet 'base58'"):
+            api_key(alphabet="base58")
+
+    def test_api_keys_deterministic(self) -> None:
+        """Test API key generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+
+        assert fake1.api_keys(10) == fake2.api_keys(10)
+
+    def test_api_key_schema(self) -> None:
+        """Test the simple type and the prefix/length/alphabet spec."""
+        fake = Faker()
+        fake.seed(42)
+        records = fake.records(
+            20,
+            {
+                "default": "api_key",
+                "prefixed": ("api_key", "ak_"),
+                "custom": ("api_key", "rk_", 16, "hex"),
+            },
+        )
+        for record in records:
+            assert re.match(r"^sk_live_[A-Za-z0-9]{32}$", record["default"])
+            assert re.match(r"^ak_[A-Za-z0-9]{32}$", record["prefixed"])
+            assert re.match(r"^rk_[0-9a-f]{16}$", record["custom"])
+
+        with pytest.raises(ValueError, match="api_key specification"):
+            fake.records(1, {"k": ("api_key", "x_", 8, "hex", True)})
+
+
+class TestTokenGeneration:
+    """Tests for session ID and OAuth token generation."""
+
+    def test_defaults(self) -> None:
+        """Test session IDs default to hex and OAuth tokens to prefixed base62."""
+        assert re.match(r"^[0-9a-f]{32}$", session_id())
+        assert re.match(r"^gho_[A-Za-z0-9]{36}$", oauth_access_token())
+        assert re.match(r"^ghr_[A-Za-z0-9]{76}$", refresh_token())
+
+    @pytest.mark.parametrize(
+        ("format", "session", "access", "refresh"),
+        [
+            ("hex", r"[0-9a-f]{32}", r"[0-9a-f]{64}", r"[0-9a-f]{128}"),
+            ("base64url", r"[A-Za-z0-9_-]{22}", r"[A-Za-z0-9_-]{43}", r"[A-Za-z0-9_-]{86}"),
+            ("prefixed", r"sess_[A-Za-z0-9]{32}", r"gho_[A-Za-z0-9]{36}", r"ghr_[A-Za-z0-9]{76}"),
+        ],
+    )
+    def test_formats(self, format: str, session: str, access: str, refresh: str) -> None:
+        """Test each format's alphabet and length."""
+        assert all(re.match(rf"^{session}$", t) for t in session_ids(50, format))
+        assert all(re.match(rf"^{access}$", t) for t in oauth_access_tokens(50, format))
+        assert all(re.match(rf"^{refresh}$", t) for t in refresh_tokens(50, format))
+
+    def test_invalid_format(self) -> None:
+        """Test that an unknown format raises error."""
+        with pytest.raises(ValueError, match="unknown token format 'jwt'"):
+            session_id("jwt")
+
+    def test_tokens_deterministic(self) -> None:
+        """Test token generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+
+        assert fake1.refresh_tokens(10) == fake2.refresh_tokens(10)
+
+    def test_token_schema(self) -> None:
+        """Test the simple types and the (name, format) spec."""
+        fake = Faker()
+        fake.seed(42)
+        records = fake.records(
+            10,
+            {
+                "sid": "session_id",
+                "access": ("oauth_access_token", "hex"),
+                "refresh": ("refresh_token", "base64url"),
+            },
+        )
+        for record in records:
+            assert re.match(r"^[0-9a-f]{32}$", record["sid"])
+            assert re.match(r"^[0-9a-f]{64}$", record["access"])
+            assert re.match(r"^[A-Za-z0-9_-]{86}$", record["refresh"])
+
+
+class TestTotpGeneration:
+    """Tests for TOTP secret and otpauth URI generation."""
+
+    def test_totp_secret_lengths(self) -> None:
+        """Test secrets are base32 of the requested length."""
+        assert re.match(r"^[A-Z2-7]{32}$", totp_secret())
+        assert all(re.match(r"^[A-Z2-7]{16}$", s) for s in totp_secrets(100, length=16))
+
+    def test_totp_secret_invalid_length(self) -> None:
+        """Test that an unsupported length raises error."""
+        with pytest.raises(ValueError, match="unsupported TOTP secret length 20"):
+            totp_secret(20)
+
+    def test_totp_uri_format(self) -> None:
+        """Test the URI label, secret and parameters."""
+        pattern = re.compile(
+            r"^otpauth://totp/([^:?\s]+):([^?\s]+@[^?\s]+)\?secret=([A-Z2-7]{16})"
+            r"&issuer=([^&\s]+)&algorithm=SHA1&digits=6&period=30$"
+        )
+        for uri in totp_uris(50, length=16):
+            match = pattern.match(uri)
+            assert match, uri
+            assert match.group(1) == match.group(4)
+        assert totp_uri().count("&") == 4
+
+    def test_totp_deterministic(self) -> None:
+        """Test TOTP generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+
+        assert fake1.totp_uris(10) == fake2.totp_uris(10)
+
+    def test_totp_schema(self) -> None:
+        """Test the totp_secret and totp_uri schema types."""
+        fake = Faker()
+        fake.seed(42)
+        for record in fake.records(10, {"secret": "totp_secret", "uri": "totp_uri"}):
+            assert re.match(r"^[A-Z2-7]{32}$", record["secret"])
+            assert record["uri"].startswith("otpauth://totp/")
+
+
 class TestBICGeneration:
     """Tests for BIC/SWIFT code generation."""
 
@@ -108,6 +441,432 @@ class TestBICGeneration:
         assert codes1 == codes2
 
 
+ABA_PREFIXES = {*range(1, 13), *range(21, 33), *range(61, 73), 80}
+
+
+def _aba_checksum_valid(number: str) -> bool:
+    weights = [3, 7, 1] * 3
+    return sum(int(d) * w for d, w in zip(number, weights, strict=True)) % 10 == 0
+
+
+class TestCurrencyGeneration:
+    """Tests for ISO 4217 currency generation."""
+
+    def test_currency_code_format(self) -> None:
+        """Test currency codes are three uppercase letters."""
+        assert re.match(r"^[A-Z]{3}$", currency_code())
+        for code in currency_codes(100):
+            assert re.match(r"^[A-Z]{3}$", code)
+
+    def test_currency_names_and_symbols(self) -> None:
+        """Test names and symbols are non-empty."""
+        assert currency_name()
+        assert currency_symbol()
+        assert all(currency_names(50))
+        assert all(currency_symbols(50))
+
+    def test_currency_codes_unique(self) -> None:
+        """Test unique currency code batches."""
+        fake = Faker()
+        fake.seed(42)
+        codes = fake.currency_codes(20, unique=True)
+        assert len(set(codes)) == 20
+
+    def test_currency_codes_deterministic(self) -> None:
+        """Test currency generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+        assert fake1.currency_codes(10) == fake2.currency_codes(10)
+
+    def test_currency_schema_types(self) -> None:
+        """Test currency schema types."""
+        fake = Faker()
+        fake.seed(42)
+        schema = {"code": "currency_code", "name": "currency_name", "symbol": "currency_symbol"}
+        for row in fake.records(10, schema):
+            assert re.match(r"^[A-Z]{3}$", row["code"])
+            assert row["name"]
+            assert row["symbol"]
+
+
+class TestStockTickers:
+    """Tests for stock ticker and exchange generation."""
+
+    def test_ticker_format(self) -> None:
+        """Test tickers are 1-5 uppercase letters."""
+        assert re.match(r"^[A-Z]{1,5}$", ticker())
+        for symbol in tickers(200):
+            assert re.match(r"^[A-Z]{1,5}$", symbol)
+
+    def test_tickers_unique(self) -> None:
+        """Test unique ticker batches."""
+        fake = Faker()
+        fake.seed(42)
+        symbols = fake.tickers(500, unique=True)
+        assert len(set(symbols)) == 500
+
+    def test_stock_exchanges(self) -> None:
+        """Test exchanges come from the supported list."""
+        assert stock_exchange() in {"NYSE", "NASDAQ", "LSE"}
+        assert set(stock_exchanges(100)) <= {"NYSE", "NASDAQ", "LSE"}
+
+    def test_stocks_structure(self) -> None:
+        """Test listings pair a company with an exchange-sized ticker."""
+        lengths = {"NYSE": (1, 4), "NASDAQ": (4, 5), "LSE": (3, 4)}
+        listings = stocks(100)
+        assert len(listings) == 100
+        for listing in listings:
+            assert set(listing) == {"ticker", "company", "exchange"}
+            low, high = lengths[listing["exchange"]]
+            assert low <= len(listing["ticker"]) <= high
+            assert listing["ticker"].isupper()
+            assert listing["company"]
+
+    def test_stocks_ticker_from_company(self) -> None:
+        """Test tickers are derived from the company name."""
+        fake = Faker("en_US")
+        fake.seed(42)
+        for listing in fake.stocks(50):
+            letters = "".join(c for c in listing["company"] if c.isascii() and c.isalpha())
+            assert letters.upper().startswith(listing["ticker"])
+
+    def test_stocks_deterministic(self) -> None:
+        """Test stock listings are deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+        assert fake1.stocks(10) == fake2.stocks(10)
+
+    def test_stock_schema_types(self) -> None:
+        """Test ticker and stock_exchange schema types."""
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(10, {"symbol": "ticker", "exchange": "stock_exchange"}):
+            assert re.match(r"^[A-Z]{1,5}$", row["symbol"])
+            assert row["exchange"] in {"NYSE", "NASDAQ", "LSE"}
+
+
+class TestPriceGeneration:
+    """Tests for formatted price generation."""
+
+    def test_price_default_formatted_usd(self) -> None:
+        """Test prices default to USD-formatted strings."""
+        value = price(1, 5000)
+        assert re.match(r"^\$\d{1,3}(,\d{3})*\.\d{2}$", value), value
+
+    @pytest.mark.parametrize(
+        ("locale", "currency", "pattern"),
+        [
+            ("en_US", "USD", r"^\$\d{1,3}(,\d{3})*\.\d{2}$"),
+            ("de_DE", "EUR", r"^\d{1,3}(\.\d{3})*,\d{2} €$"),
+            ("nl_NL", "EUR", r"^€ \d{1,3}(\.\d{3})*,\d{2}$"),
+            ("fr_FR", "EUR", r"^\d{1,3}( \d{3})*,\d{2} €$"),
+            ("ja_JP", "JPY", r"^¥\d{1,3}(,\d{3})*$"),
+        ],
+    )
+    def test_prices_locale_format(self, locale: str, currency: str, pattern: str) -> None:
+        """Test separators and symbol placement follow the locale."""
+        fake = Faker(locale)
+        fake.seed(42)
+        for value in fake.prices(100, 1, 100000, currency):
+            assert re.match(pattern, value), f"{locale}: {value}"
+
+    def test_prices_unformatted(self) -> None:
+        """Test formatted=False returns rounded numbers in range."""
+        values = prices(200, 10, 20, formatted=False)
+        for value in values:
+            assert isinstance(value, float)
+            assert 10 <= value <= 20
+            assert round(value, 2) == value
+
+    def test_prices_zero_decimal_currency(self) -> None:
+        """Test zero-decimal currencies round to whole numbers."""
+        values = prices(100, 100, 10000, currency="KRW", formatted=False)
+        assert all(v == int(v) for v in values)
+
+    def test_prices_matches_formatted(self) -> None:
+        """Test the same seed gives the same amounts formatted or not."""
+        fake1 = Faker()
+        fake1.seed(7)
+        fake2 = Faker()
+        fake2.seed(7)
+        numbers = fake1.prices(10, 1, 999, formatted=False)
+        strings = fake2.prices(10, 1, 999)
+        assert strings == [f"${n:,.2f}" for n in numbers]
+
+    def test_prices_errors(self) -> None:
+        """Test invalid currencies and ranges raise ValueError."""
+        with pytest.raises(ValueError, match="unknown currency"):
+            prices(10, 1, 10, currency="XYZ")
+        with pytest.raises(ValueError):
+            price(10, 1)
+
+    def test_price_schema(self) -> None:
+        """Test the price schema type and tuple spec."""
+        fake = Faker("de_DE")
+        fake.seed(42)
+        schema = {
+            "label": "price",
+            "eur": ("price", 5, 500, "EUR"),
+            "amount": ("price", 5, 500, "EUR", False),
+        }
+        for row in fake.records(20, schema):
+            assert row["label"].endswith(" $")
+            assert row["eur"].endswith(" €")
+            assert 5 <= row["amount"] <= 500
+
+
+class TestRoutingNumberGeneration:
+    """Tests for ABA routing number generation."""
+
+    def test_routing_number_format(self) -> None:
+        """Test routing numbers are 9 digits with a valid checksum."""
+        number = routing_number()
+        assert len(number) == 9
+        assert number.isdigit()
+        assert _aba_checksum_valid(number)
+
+    def test_routing_numbers_batch(self) -> None:
+        """Test batch routing numbers all pass the checksum and prefix rules."""
+        numbers = routing_numbers(500)
+        assert len(numbers) == 500
+        for number in numbers:
+            assert _aba_checksum_valid(number), number
+            assert int(number[:2]) in ABA_PREFIXES, number
+
+    def test_routing_numbers_unique(self) -> None:
+        """Test unique routing number batches."""
+        fake = Faker()
+        fake.seed(42)
+        numbers = fake.routing_numbers(200, unique=True)
+        assert len(set(numbers)) == 200
+
+    def test_routing_numbers_deterministic(self) -> None:
+        """Test routing number generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+        assert fake1.routing_numbers(10) == fake2.routing_numbers(10)
+
+    def test_routing_number_schema(self) -> None:
+        """Test routing_number works as a records schema type."""
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(10, {"routing": "routing_number"}):
+            assert _aba_checksum_valid(row["routing"])
+
+
+def _isbn13_valid(isbn: str) -> bool:
+    digits = [int(c) for c in isbn.replace("-", "")]
+    weighted = sum(d * (1 if i % 2 == 0 else 3) for i, d in enumerate(digits))
+    return len(digits) == 13 and weighted % 10 == 0
+
+
+def _isbn10_valid(isbn: str) -> bool:
+    chars = isbn.replace("-", "")
+    digits = [10 if c == "X" else int(c) for c in chars]
+    return len(digits) == 10 and sum(d * (10 - i) for i, d in enumerate(digits)) % 11 == 0
+
+
+class TestISBNGeneration:
+    """Tests for ISBN-13 and ISBN-10 generation."""
+
+    def test_isbn13_valid(self) -> None:
+        """Test ISBN-13s have a 978/979 prefix and a valid check digit."""
+        for isbn in [isbn13(), *isbn13s(200)]:
+            assert re.match(r"^97[89]-\d{1,2}-\d+-\d+-\d$", isbn)
+            assert _isbn13_valid(isbn), isbn
+
+    def test_isbn10_valid(self) -> None:
+        """Test ISBN-10s have a valid check character."""
+        for isbn in [isbn10(), *isbn10s(200)]:
+            assert re.match(r"^\d{1,2}-\d+-\d+-[\dX]$", isbn)
+            assert _isbn10_valid(isbn), isbn
+
+    def test_isbns_unique(self) -> None:
+        """Test unique ISBN batches."""
+        fake = Faker()
+        fake.seed(42)
+        assert len(set(fake.isbn13s(500, unique=True))) == 500
+        assert len(set(fake.isbn10s(500, unique=True))) == 500
+
+    def test_isbns_deterministic(self) -> None:
+        """Test ISBN generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+        assert fake1.isbn13s(10) == fake2.isbn13s(10)
+        assert fake1.isbn10s(10) == fake2.isbn10s(10)
+
+    def test_isbn_schema_types(self) -> None:
+        """Test isbn13 and isbn10 schema types."""
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(20, {"isbn13": "isbn13", "isbn10": "isbn10"}):
+            assert _isbn13_valid(row["isbn13"])
+            assert _isbn10_valid(row["isbn10"])
+
+
+def _luhn_valid(number: str) -> bool:
+    total = 0
+    for i, c in enumerate(reversed(number)):
+        d = int(c) * (2 if i % 2 else 1)
+        total += d - 9 if d > 9 else d
+    return total % 10 == 0
+
+
+class TestIMEIGeneration:
+    """Tests for IMEI generation."""
+
+    def test_imei_valid(self) -> None:
+        """Test IMEIs are 15 digits with a known TAC prefix and valid Luhn."""
+        for value in [imei(), *imeis(200)]:
+            assert re.match(r"^\d{15}$", value)
+            assert value[:2] in {"01", "35", "86", "99"}
+            assert _luhn_valid(value), value
+
+    def test_imeis_unique(self) -> None:
+        """Test unique IMEI batches."""
+        fake = Faker()
+        fake.seed(42)
+        assert len(set(fake.imeis(500, unique=True))) == 500
+
+    def test_imeis_deterministic(self) -> None:
+        """Test IMEI generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+        assert fake1.imeis(10) == fake2.imeis(10)
+
+    def test_imei_schema_type(self) -> None:
+        """Test imei schema type."""
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(20, {"device": "imei"}):
+            assert _luhn_valid(row["device"])
+
+
+class TestDOIGeneration:
+    """Tests for DOI generation."""
+
+    def test_doi_format(self) -> None:
+        """Test DOIs have a registrant prefix and a non-empty suffix."""
+        for value in [doi(), *dois(200)]:
+            assert re.match(r"^10\.\d{4,5}/\S+$", value), value
+            assert not set(value) & {"#", "?", "{", "}"}
+
+    def test_dois_unique(self) -> None:
+        """Test unique DOI batches."""
+        fake = Faker()
+        fake.seed(42)
+        assert len(set(fake.dois(500, unique=True))) == 500
+
+    def test_dois_deterministic(self) -> None:
+        """Test DOI generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+        assert fake1.dois(10) == fake2.dois(10)
+
+    def test_doi_schema_type(self) -> None:
+        """Test doi schema type."""
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(20, {"doi": "doi"}):
+            assert row["doi"].startswith("10.")
+
+
+def _orcid_valid(value: str) -> bool:
+    total = 0
+    for c in value.replace("-", "")[:15]:
+        total = (total + int(c)) * 2
+    check = (12 - total % 11) % 11
+    return value[-1] == ("X" if check == 10 else str(check))
+
+
+class TestORCIDGeneration:
+    """Tests for ORCID iD generation."""
+
+    def test_orcid_valid(self) -> None:
+        """Test ORCID iDs are grouped 16-character iDs with a valid check character."""
+        values = [orcid(), *orcids(300)]
+        for value in values:
+            assert re.match(r"^0000-000[1-3]-\d{4}-\d{3}[\dX]$", value), value
+            assert _orcid_valid(value), value
+        assert any(v.endswith("X") for v in values)
+
+    def test_orcids_unique_and_deterministic(self) -> None:
+        """Test unique and seeded ORCID batches."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+        assert fake1.orcids(10) == fake2.orcids(10)
+        assert len(set(fake1.orcids(500, unique=True))) == 500
+
+    def test_orcid_schema_type(self) -> None:
+        """Test orcid schema type."""
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(20, {"author": "orcid"}):
+            assert _orcid_valid(row["author"])
+
+
+VIN_VALUES = {
+    **dict(zip("ABCDEFGH", range(1, 9))),
+    **dict(zip("JKLMN", range(1, 6))),
+    "P": 7,
+    "R": 9,
+    **dict(zip("STUVWXYZ", range(2, 10))),
+}
+VIN_WEIGHTS = [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2]
+
+
+def _vin_check_digit(value: str) -> str:
+    total = sum(
+        (int(c) if c.isdigit() else VIN_VALUES[c]) * w for c, w in zip(value, VIN_WEIGHTS)
+    )
+    return "X" if total % 11 == 10 else str(total % 11)
+
+
+class TestVINGeneration:
+    """Tests for vehicle identification number generation."""
+
+    def test_vin_format(self) -> None:
+        """Test VINs are 17 characters without I, O or Q."""
+        for value in [vin(), *vins(200)]:
+            assert re.match(r"^[A-HJ-NPR-Z0-9]{17}$", value)
+            assert value[8] == _vin_check_digit(value), value
+
+    def test_vins_unique(self) -> None:
+        """Test unique VIN batches."""
+        fake = Faker()
+        fake.seed(42)
+        assert len(set(fake.vins(500, unique=True))) == 500
+
+    def test_vins_deterministic(self) -> None:
+        """Test VIN generation is deterministic with seed."""
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+        assert fake1.vins(10) == fake2.vins(10)
+
+    def test_vin_schema_type(self) -> None:
+        """Test vin schema type."""
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(20, {"vin": "vin"}):
+            assert row["vin"][8] == _vin_check_digit(row["vin"])
+
+
 class TestBankAccountGeneration:
     """Tests for bank account number generation."""
 
@@ -181,6 +940,66 @@ class TestBankNameGeneration:
         assert de_bank is not None
 
 
+class TestVatNumberGeneration:
+    """Tests for EU VAT number generation."""
+
+    @pytest.mark.parametrize(
+        "country,pattern",
+        [
+            ("AT", r"^ATU\d{8}$"),
+            ("BE", r"^BE[01]\d{9}$"),
+            ("DE", r"^DE\d{9}$"),
+            ("ES", r"^ES[0-9A-Z]\d{7}[0-9A-Z]$"),
+            ("FR", r"^FR\d{11}$"),
+            ("GB", r"^GB\d{9}$"),
+            ("IT", r"^IT\d{11}$"),
+            ("NL", r"^NL\d{9}B\d{2}$"),
+            ("PL", r"^PL\d{10}$"),
+            ("PT", r"^PT\d{9}$"),
+        ],
+    )
+    def test_vat_number_format(self, country: str, pattern: str) -> None:
+        """Test each country's VAT number format."""
+        for vat in vat_numbers(50, country):
+            assert re.match(pattern, vat), vat
+
+    def test_vat_number_defaults_to_locale(self) -> None:
+        """Test the VAT country follows the locale."""
+        assert Faker("fr_FR").vat_number().startswith("FR")
+        assert Faker("nl_NL").vat_number().startswith("NL")
+
+    def test_vat_number_country_case_insensitive(self) -> None:
+        """Test country codes are case-insensitive."""
+        assert vat_number("de").startswith("DE")
+
+    def test_vat_number_unknown_country(self) -> None:
+        """Test unsupported countries raise ValueError."""
+        with pytest.raises(ValueError, match="unsupported VAT country"):
+            vat_number("US")
+
+    def test_vat_numbers_deterministic(self) -> None:
+        """Test VAT generation is deterministic with seed."""
+        fake1 = Faker("de_DE")
+        fake1.seed(42)
+        fake2 = Faker("de_DE")
+        fake2.seed(42)
+        assert fake1.vat_numbers(10) == fake2.vat_numbers(10)
+
+    def test_vat_number_schema(self) -> None:
+        """Test VAT numbers in records schemas."""
+        fake = Faker("it_IT")
+        fake.seed(1)
+        rows = fake.records(5, {"vat": "vat_number", "fr_vat": ("vat_number", "FR")})
+        for row in rows:
+            assert row["vat"].startswith("IT")
+            assert row["fr_vat"].startswith("FR")
+
+    def test_vat_number_schema_unknown_country(self) -> None:
+        """Test schema validation rejects unsupported countries."""
+        with pytest.raises(ValueError):
+            Faker().records(1, {"vat": ("vat_number", "XX")})
+
+
 class TestUniqueGeneration:
     """Tests for unique value generation."""
 
@@ -1,10 +1,27 @@
 """Tests for Phase 2 providers."""
 
+import base64
+import ipaddress
 import re
+from datetime import UTC, date, datetime, timedelta
+from decimal import Decimal
+from html.parser import HTMLParser
+from urllib.parse import parse_qsl, unquote, urlsplit
+
+import pytest
 
 import forgery
 from forgery import Faker
 
+try:
+    import pyarrow as pa
+
+    HAS_PYARROW = True
+except ImportError:
+    HAS_PYARROW = False
+
+RFC1918 = ("10.0.0.0/8", "172.16.0.0/12", "192.168.0.0/16")
+
 
 class TestFloatGeneration:
     """Tests for float generation."""
@@ -38,7 +55,7 @@ class TestFloatGeneration:
 
 
 class TestHashGeneration:
-    """Tests for MD5 and SHA256 generation."""
+    """Tests for MD5, SHA1, SHA256 and SHA512 generation."""
 
     def test_md5_single(self):
         fake = Faker()
@@ -77,6 +94,82 @@ class TestHashGeneration:
         assert len(forgery.md5s(5)) == 5
         assert len(forgery.sha256s(5)) == 5
 
+    def test_sha1_and_sha512(self):
+        fake = Faker()
+        fake.seed(42)
+        for vals, length in [(fake.sha1s(100), 40), (fake.sha512s(100), 128)]:
+            assert len(vals) == 100
+            for v in vals:
+                assert re.fullmatch(f"[0-9a-f]{{{length}}}", v)
+        assert len(fake.sha1()) == 40
+        assert len(fake.sha512()) == 128
+        forgery.seed(42)
+        assert len(forgery.sha1()) == 40
+        assert len(forgery.sha512s(3)[0]) == 128
+
+    def test_sha1_and_sha512_records(self):
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(10, {"commit": "sha1", "digest": "sha512"}):
+            assert len(row["commit"]) == 40
+            assert len(row["digest"]) == 128
+
+
+class TestBytesGeneration:
+    """Tests for random byte string and base64 generation."""
+
+    def test_bytes_batch(self):
+        fake = Faker()
+        fake.seed(42)
+        vals = fake.bytes(100, 24)
+        assert len(vals) == 100
+        for v in vals:
+            assert isinstance(v, bytes)
+            assert len(v) == 24
+        assert len(fake.bytes(3)[0]) == 16
+
+    def test_bytes_convenience(self):
+        forgery.seed(42)
+        first = forgery.bytes_(5, 8)
+        forgery.seed(42)
+        assert forgery.bytes_(5, 8) == first
+        assert all(len(v) == 8 for v in first)
+
+    def test_bytes_records(self):
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(10, {"blob": "bytes", "key": ("bytes", 32)}):
+            assert isinstance(row["blob"], bytes)
+            assert len(row["blob"]) == 16
+            assert len(row["key"]) == 32
+        with pytest.raises(ValueError, match="bytes specification"):
+            fake.records(1, {"blob": ("bytes", 8, 16)})
+
+    def test_base64_decoded_length(self):
+        fake = Faker()
+        fake.seed(42)
+        for v in fake.base64(100, 20):
+            assert len(v) == 28
+            assert len(base64.b64decode(v, validate=True)) == 20
+        for v in forgery.base64_(100, 20, url_safe=True):
+            assert re.fullmatch(r"[A-Za-z0-9_-]{27}", v)
+            assert len(base64.urlsafe_b64decode(v + "=")) == 20
+
+    def test_base64_records(self):
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(10, {"payload": "base64", "token": ("base64", 16, True)}):
+            assert len(row["payload"]) == 44
+            assert re.fullmatch(r"[A-Za-z0-9_-]{22}", row["token"])
+
+    @pytest.mark.skipif(not HAS_PYARROW, reason="pyarrow not installed")
+    def test_bytes_arrow(self):
+        fake = Faker()
+        fake.seed(42)
+        batch = fake.records_arrow(10, {"blob": ("bytes", 12)})
+        assert batch.schema.field("blob").type == pa.binary()
+        assert all(len(v) == 12 for v in batch.column("blob").to_pylist())
+
 
 class TestColorGeneration:
     """Tests for color generation."""
@@ -105,6 +198,54 @@ class TestColorGeneration:
         for c in val:
             assert 0 <= c <= 255
 
+    def test_hsl_cmyk_color_ranges(self):
+        fake = Faker()
+        fake.seed(42)
+        for h, s, lightness in fake.hsl_colors(200):
+            assert 0 <= h <= 359
+            assert 0 <= s <= 100
+            assert 0 <= lightness <= 100
+        for color in fake.cmyk_colors(200):
+            assert len(color) == 4
+            assert all(0 <= c <= 100 for c in color)
+
+    def test_css_color_formats(self):
+        fake = Faker()
+        fake.seed(42)
+        values = fake.css_colors(500)
+        assert any(v.startswith("#") for v in values)
+        assert any(v.startswith("rgb(") for v in values)
+        assert any(v.startswith("rgba(") for v in values)
+        assert any(v.startswith("hsl(") for v in values)
+        assert any(v.isalpha() for v in values)
+        assert re.fullmatch(
+            r"rgba\(\d{1,3}, \d{1,3}, \d{1,3}, (0(\.\d+)?|1)\)", fake.css_color("rgba")
+        )
+        assert all(v.isalpha() and v.islower() for v in fake.css_colors(20, format="named"))
+        with pytest.raises(ValueError, match="unknown CSS color format"):
+            fake.css_color("hwb")
+
+    def test_color_palettes(self):
+        fake = Faker()
+        fake.seed(42)
+        palette = fake.color_palette()
+        assert len(palette) == 5
+        assert all(re.fullmatch(r"#[0-9a-f]{6}", c) for c in palette)
+        palettes = fake.color_palettes(10, size=4, scheme="complementary")
+        assert len(palettes) == 10
+        assert all(len(p) == 4 for p in palettes)
+        assert len(forgery.color_palette(3, "random")) == 3
+        with pytest.raises(ValueError, match="unknown palette scheme"):
+            fake.color_palette(scheme="triadic")
+
+    def test_css_color_schema(self):
+        forgery.seed(42)
+        rows = forgery.records(20, {"any": "css_color", "hsl": ("css_color", "hsl")})
+        assert all(row["hsl"].startswith("hsl(") for row in rows)
+        assert len(forgery.css_colors(3)) == 3
+        with pytest.raises(ValueError, match="unknown CSS color format"):
+            forgery.records(1, {"c": ("css_color", "lab")})
+
     def test_color_batch(self):
         fake = Faker()
         fake.seed(42)
@@ -120,6 +261,8 @@ class TestColorGeneration:
         assert len(forgery.colors(5)) == 5
         assert len(forgery.hex_colors(5)) == 5
         assert len(forgery.rgb_colors(5)) == 5
+        assert len(forgery.hsl_color()) == 3
+        assert len(forgery.cmyk_colors(5)) == 5
 
 
 class TestDateTimeGeneration:
@@ -146,6 +289,17 @@ class TestDateTimeGeneration:
         val = fake.date_of_birth(18, 65)
         assert re.match(r"\d{4}-\d{2}-\d{2}", val)
 
+    def test_dates_of_birth_with_age_consistent(self):
+        fake = Faker()
+        fake.seed(42)
+        for dob, age in fake.dates_of_birth_with_age(200, 20, 30):
+            assert 20 <= age <= 30
+            year, month, day = (int(part) for part in dob.split("-"))
+            # Age on the 2024-01-01 reference date
+            assert age == 2023 - year + (1 if (month, day) == (1, 1) else 0)
+        with pytest.raises(ValueError):
+            fake.date_of_birth_with_age(30, 20)
+
     def test_datetime_single(self):
         fake = Faker()
         fake.seed(42)
@@ -159,9 +313,247 @@ class TestDateTimeGeneration:
         assert len(forgery.dates(5)) == 5
         assert forgery.date_of_birth().count("-") == 2
         assert len(forgery.dates_of_birth(5)) == 5
+        assert len(forgery.date_of_birth_with_age()) == 2
+        assert len(forgery.dates_of_birth_with_age(5)) == 5
         assert "T" in forgery.datetime_()
         assert len(forgery.datetimes(5)) == 5
 
+    def test_reporting_periods(self):
+        fake = Faker()
+        fake.seed(42)
+        for week in fake.iso_weeks(50, "2024-09-09", "2024-09-22"):
+            assert week in ("2024-W37", "2024-W38")
+        for q in fake.quarters(50, "2024-01-01", "2024-12-31"):
+            assert re.fullmatch(r"2024-Q[1-4]", q)
+        for m in fake.months(50, "2024-11-15", "2025-01-15"):
+            assert m in ("2024-11", "2024-12", "2025-01")
+        assert fake.iso_week("2021-01-01", "2021-01-01") == "2020-W53"
+        with pytest.raises(ValueError):
+            fake.quarter("2024-12-31", "2024-01-01")
+
+    def test_reporting_period_convenience(self):
+        forgery.seed(42)
+        assert re.fullmatch(r"\d{4}-W\d{2}", forgery.iso_week())
+        assert re.fullmatch(r"\d{4}-Q[1-4]", forgery.quarter())
+        assert re.fullmatch(r"\d{4}-\d{2}", forgery.month())
+        assert len(forgery.iso_weeks(5)) == 5
+        assert len(forgery.quarters(5)) == 5
+        assert len(forgery.months(5)) == 5
+
+    def test_durations(self):
+        fake = Faker()
+        fake.seed(42)
+        for value in fake.durations(100, 60, 7200):
+            assert re.fullmatch(r"PT(\d+H)?(\d+M)?(\d+S)?", value)
+        assert fake.duration(8100, 8100) == "PT2H15M"
+        assert fake.duration(8100, 8100, format="human") == "2h 15m"
+        seconds = fake.durations(100, 60, 120, format="seconds")
+        assert all(isinstance(s, int) and 60 <= s <= 120 for s in seconds)
+        with pytest.raises(ValueError):
+            fake.durations(5, format="minutes")
+        with pytest.raises(ValueError):
+            fake.duration(120, 60)
+
+    def test_duration_convenience(self):
+        forgery.seed(42)
+        assert forgery.duration().startswith("P")
+        assert isinstance(forgery.duration(format="seconds"), int)
+        assert len(forgery.durations(5)) == 5
+
+    def test_business_dates_skip_weekends_and_holidays(self):
+        fake = Faker()
+        fake.seed(42)
+        holidays = ["2024-12-25", "2024-12-26"]
+        values = fake.business_dates(200, "2024-12-20", "2024-12-31", holidays=holidays)
+        assert set(values) == {
+            "2024-12-20",
+            "2024-12-23",
+            "2024-12-24",
+            "2024-12-27",
+            "2024-12-30",
+            "2024-12-31",
+        }
+        assert date.fromisoformat(fake.business_date()).weekday() < 5
+
+    def test_business_date_errors(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="no business days"):
+            fake.business_date("2024-01-06", "2024-01-07")
+        with pytest.raises(ValueError, match="invalid holiday"):
+            fake.business_dates(3, holidays=["25/12/2024"])
+
+    def test_business_date_schema_and_convenience(self):
+        forgery.seed(42)
+        assert len(forgery.business_dates(5)) == 5
+        rows = forgery.records(
+            50,
+            {
+                "any": "business_date",
+                "invoiced": ("business_date", "2024-07-01", "2024-07-05", ["2024-07-04"]),
+            },
+        )
+        for row in rows:
+            assert date.fromisoformat(row["any"]).weekday() < 5
+            assert row["invoiced"] in {"2024-07-01", "2024-07-02", "2024-07-03", "2024-07-05"}
+        with pytest.raises(ValueError, match="no business days"):
+            forgery.records(1, {"d": ("business_date", "2024-07-06", "2024-07-07")})
+
+    def test_times_default_format(self):
+        fake = Faker()
+        fake.seed(42)
+        for value in fake.times(100):
+            assert re.fullmatch(r"([01]\d|2[0-3]):[0-5]\d:[0-5]\d", value)
+
+    def test_time_custom_format(self):
+        fake = Faker()
+        fake.seed(42)
+        assert re.fullmatch(r"(0[1-9]|1[0-2]):[0-5]\d (AM|PM)", fake.time("%I:%M %p"))
+        assert all(len(t) == 5 for t in fake.times(10, format="%H:%M"))
+
+    def test_time_rejects_date_specifiers(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="invalid time format"):
+            fake.time("%Y-%m-%d")
+        with pytest.raises(ValueError, match="invalid time format"):
+            fake.times(3, "%Q")
+
+    def test_time_schema_and_convenience(self):
+        forgery.seed(42)
+        assert forgery.time_().count(":") == 2
+        assert len(forgery.times(3)) == 3
+        rows = forgery.records(5, {"at": "time"})
+        assert all(row["at"].count(":") == 2 for row in rows)
+
+    def test_relative_datetimes_in_window(self):
+        fake = Faker()
+        fake.seed(42)
+        before = datetime.now(UTC).replace(tzinfo=None, microsecond=0)
+        recent = [datetime.fromisoformat(v) for v in fake.recent_datetimes(100)]
+        past = [datetime.fromisoformat(v) for v in fake.past_datetimes(100, years=2)]
+        future = [datetime.fromisoformat(v) for v in fake.future_datetimes(100)]
+        after = datetime.now(UTC).replace(tzinfo=None)
+        assert all(before - timedelta(days=7) <= v <= after for v in recent)
+        assert all(before - timedelta(days=732) <= v <= after for v in past)
+        assert all(before <= v <= after + timedelta(days=366) for v in future)
+
+    def test_relative_zero_window(self):
+        fake = Faker()
+        now = datetime.now(UTC).replace(tzinfo=None, microsecond=0)
+        assert abs(datetime.fromisoformat(fake.recent(days=0)) - now) <= timedelta(seconds=2)
+
+    def test_relative_out_of_range(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="invalid date range"):
+            fake.future(years=2**32 - 1)
+
+    def test_relative_schema_and_convenience(self):
+        forgery.seed(42)
+        assert "T" in forgery.recent()
+        assert "T" in forgery.past()
+        assert "T" in forgery.future()
+        assert len(forgery.past_datetimes(3)) == 3
+        now = datetime.now(UTC).replace(tzinfo=None)
+        rows = forgery.records(
+            20, {"seen": "recent", "joined": ("past", 10), "due": ("future", 2)}
+        )
+        for row in rows:
+            assert datetime.fromisoformat(row["seen"]) <= now + timedelta(seconds=1)
+            assert datetime.fromisoformat(row["joined"]) >= now - timedelta(days=3660)
+            assert datetime.fromisoformat(row["due"]) >= now - timedelta(seconds=1)
+        with pytest.raises(ValueError, match="recent specification"):
+            forgery.records(1, {"seen": ("recent", 7, 1)})
+
+
+class TestTimeSeries:
+    """Tests for time series generation."""
+
+    @pytest.mark.skipif(not HAS_PYARROW, reason="pyarrow not installed")
+    def test_hourly_random_walk(self):
+        fake = Faker()
+        fake.seed(42)
+        batch = fake.time_series(48, "2024-01-01", "2024-01-02")
+        assert batch.num_rows == 48
+        assert batch.schema.field("timestamp").type == pa.timestamp("us")
+        assert batch.schema.field("value").type == pa.float64()
+        stamps = batch.column("timestamp").to_pylist()
+        assert stamps[0] == datetime(2024, 1, 1)
+        assert all(b - a == timedelta(hours=1) for a, b in zip(stamps, stamps[1:]))
+        assert batch.column("value").to_pylist()[0] == 100.0
+
+    @pytest.mark.skipif(not HAS_PYARROW, reason="pyarrow not installed")
+    def test_seasonal_without_noise(self):
+        batch = forgery.time_series(
+            5, "2024-01-01", "2024-01-01", value="seasonal", jitter=0.0, period="4h"
+        )
+        values = batch.column("value").to_pylist()
+        assert values == pytest.approx([100.0, 110.0, 100.0, 90.0, 100.0])
+
+    @pytest.mark.skipif(not HAS_PYARROW, reason="pyarrow not installed")
+    def test_deterministic(self):
+        fake1 = Faker()
+        fake2 = Faker()
+        fake1.seed(7)
+        fake2.seed(7)
+        a = fake1.time_series(100, "2024-01-01", "2024-01-31", freq="15min")
+        b = fake2.time_series(100, "2024-01-01", "2024-01-31", freq="15min")
+        assert a.equals(b)
+
+    def test_invalid_options(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="invalid frequency"):
+            fake.time_series(10, "2024-01-01", "2024-01-02", freq="5m")
+        with pytest.raises(ValueError, match="unknown series model"):
+            fake.time_series(10, "2024-01-01", "2024-01-02", value="trend")
+        with pytest.raises(ValueError, match="jitter"):
+            fake.time_series(10, "2024-01-01", "2024-01-02", jitter=-1.0)
+        with pytest.raises(ValueError, match="run past end date"):
+            fake.time_series(25, "2024-01-01", "2024-01-01")
+        with pytest.raises(ValueError, match="invalid date range"):
+            fake.time_series(1, "2024-01-02", "2024-01-01")
+
+
+class TestSensorReadings:
+    """Tests for IoT sensor reading generation."""
+
+    def test_rows(self):
+        fake = Faker()
+        fake.seed(42)
+        rows = fake.sensor_readings(500, "2024-01-01", "2024-01-02", sensors=3)
+        assert len(rows) == 500
+        assert set(rows[0]) == {"device_id", "timestamp", "metric", "value", "unit"}
+        stamps = [datetime.fromisoformat(r["timestamp"]) for r in rows]
+        assert stamps == sorted(stamps)
+        assert all(datetime(2024, 1, 1) <= s < datetime(2024, 1, 3) for s in stamps)
+        assert len({r["device_id"] for r in rows}) == 3
+        assert {r["metric"] for r in rows} == {"temperature", "humidity", "pressure"}
+        assert all(isinstance(r["value"], float) for r in rows)
+
+    def test_metrics(self):
+        rows = forgery.sensor_readings(100, "2024-01-01", "2024-01-01", metrics=["co2"])
+        assert all(r["metric"] == "co2" and r["unit"] == "ppm" for r in rows)
+
+    @pytest.mark.skipif(not HAS_PYARROW, reason="pyarrow not installed")
+    def test_arrow(self):
+        fake1 = Faker()
+        fake2 = Faker()
+        fake1.seed(7)
+        fake2.seed(7)
+        batch = fake1.sensor_readings_arrow(100, "2024-01-01", "2024-01-31")
+        rows = fake2.sensor_readings(100, "2024-01-01", "2024-01-31")
+        assert batch.num_rows == 100
+        assert batch.schema.field("timestamp").type == pa.timestamp("us")
+        assert batch.schema.field("value").type == pa.float64()
+        assert batch.column("value").to_pylist() == [r["value"] for r in rows]
+
+    def test_invalid_options(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="unknown sensor metric"):
+            fake.sensor_readings(1, "2024-01-01", "2024-01-01", metrics=["rain"])
+        with pytest.raises(ValueError, match="sensors"):
+            fake.sensor_readings(1, "2024-01-01", "2024-01-01", sensors=0)
+        with pytest.raises(ValueError, match="invalid date range"):
+            fake.sensor_readings(1, "2024-01-02", "2024-01-01")
+
 
 class TestTextGeneration:
     """Tests for text generation."""
@@ -203,6 +595,100 @@ class TestTextGeneration:
         assert isinstance(forgery.text(), str)
         assert len(forgery.texts(5)) == 5
 
+    def test_sentence_shape(self):
+        fake = Faker()
+        fake.seed(42)
+        sentences = fake.sentences(200, word_count=(4, 12), comma_rate=0.3)
+        lengths = {len(s.split()) for s in sentences}
+        assert min(lengths) >= 4 and max(lengths) <= 12
+        assert len(lengths) > 5
+        assert any(", " in s for s in sentences)
+        assert len(fake.sentence(word_count=(3, 3)).split()) == 3
+        assert "," not in fake.sentence(8)
+        with pytest.raises(ValueError, match="word_count"):
+            fake.sentence(word_count=(12, 4))
+        with pytest.raises(ValueError, match="comma_rate"):
+            fake.sentences(5, comma_rate=1.5)
+        with pytest.raises(ValueError, match="word_count"):
+            fake.sentence(word_count="long")
+
+    def test_paragraph_shape(self):
+        fake = Faker()
+        fake.seed(42)
+        for p in fake.paragraphs(50, sentence_count=(2, 4), word_count=3):
+            assert 2 <= p.count(".") <= 4
+            assert len(p.split()) == 3 * p.count(".")
+        assert forgery.paragraph(sentence_count=1, word_count=(4, 4)).count(" ") == 3
+        with pytest.raises(ValueError, match="sentence_count"):
+            fake.paragraph(sentence_count=(5, 1))
+
+    def test_words(self):
+        fake = Faker()
+        fake.seed(42)
+        lists = fake.words(20)
+        assert len(lists) == 20
+        assert all(len(words) == 3 for words in lists)
+        for words in lists:
+            for w in words:
+                assert w and " " not in w and "." not in w
+        assert [len(w) for w in fake.words(3, count=5)] == [5, 5, 5]
+        assert fake.words(2, count=0) == [[], []]
+        word = fake.word()
+        assert word == word.lower() and "." not in word
+
+    def test_words_convenience(self):
+        forgery.seed(42)
+        assert isinstance(forgery.word(), str)
+        assert len(forgery.words(4, 2)) == 4
+
+    def test_markdown(self):
+        fake = Faker()
+        fake.seed(42)
+        docs = fake.markdowns(50, paragraphs=4)
+        assert len(docs) == 50
+        assert all(doc.startswith("# ") for doc in docs)
+        combined = "\n\n".join(docs)
+        for marker in ("\n## ", "\n- ", "\n1. ", "](https://", "\n```", "\n> "):
+            assert marker in combined, marker
+        assert fake.markdown(paragraphs=0).count("\n") == 0
+
+    def test_html(self):
+        class TagCounter(HTMLParser):
+            def __init__(self):
+                super().__init__()
+                self.depth = 0
+                self.top_level = 0
+
+            def handle_starttag(self, tag, attrs):
+                if self.depth == 0:
+                    self.top_level += 1
+                self.depth += 1
+
+            def handle_endtag(self, tag):
+                self.depth -= 1
+
+        fake = Faker()
+        fake.seed(42)
+        fragments = fake.htmls(50, elements=6)
+        for fragment in fragments:
+            assert fragment.startswith("<h1>")
+            parser = TagCounter()
+            parser.feed(fragment)
+            assert parser.depth == 0, fragment
+            assert parser.top_level == 6, fragment
+        combined = "\n".join(fragments)
+        tags = ("<h2>", "<p>", "<ul>", "<ol>", "<li>", "<a href=", "<pre><code", "<blockquote>")
+        for tag in tags:
+            assert tag in combined, tag
+        assert fake.html(elements=0) == ""
+
+    def test_markup_convenience(self):
+        forgery.seed(42)
+        assert forgery.markdown().startswith("# ")
+        assert len(forgery.markdowns(3, 1)) == 3
+        assert forgery.html().startswith("<h1>")
+        assert len(forgery.htmls(3, 2)) == 3
+
 
 class TestAddressGeneration:
     """Tests for address generation."""
@@ -277,6 +763,804 @@ class TestAddressGeneration:
         assert len(forgery.zip_codes(5)) == 5
         assert len(forgery.addresses(5)) == 5
 
+    def test_street_components(self):
+        fake = Faker()
+        fake.seed(42)
+        assert all(re.match(r"^[A-Z]{2}$", a) for a in fake.state_abbrs(50))
+        assert all(1 <= int(b) <= 9999 for b in fake.building_numbers(50))
+        assert len(set(fake.street_suffixes(10, unique=True))) == 10
+        assert all(name and name[0].isupper() for name in fake.street_names(50))
+        assert isinstance(forgery.street_suffix(), str)
+        assert len(forgery.state_abbrs(3)) == 3
+        schema = {
+            "name": "street_name",
+            "suffix": "street_suffix",
+            "number": "building_number",
+            "abbr": "state_abbr",
+        }
+        for row in fake.records(5, schema):
+            assert row["number"].isdigit()
+            assert len(row["abbr"]) == 2
+
+    def test_secondary_units_and_po_boxes(self):
+        fake = Faker()
+        fake.seed(42)
+        units = fake.secondary_units(200)
+        assert all(re.match(r"^(Apt \d{1,3}[A-F]?|Suite \d{3}|Unit \d{1,2})$", u) for u in units)
+        assert {u.split()[0] for u in units} == {"Apt", "Suite", "Unit"}
+        assert all(re.match(r"^PO Box \d{1,5}$", b) for b in fake.po_boxes(50))
+        assert forgery.po_box().startswith("PO Box ")
+        assert len(forgery.secondary_units(3)) == 3
+        for row in fake.records(5, {"unit": "secondary_unit", "box": "po_box"}):
+            assert row["box"].startswith("PO Box ")
+
+    def test_secondary_units_and_po_boxes_follow_locale(self):
+        units = Faker("ko_KR").secondary_units(20)
+        assert all(re.match(r"^\d{3,4}호$", u) for u in units)
+        assert Faker("de_DE").po_box().startswith("Postfach ")
+        address = Faker("en_GB").address(unit_rate=1.0)
+        assert re.match(r"^(Flat|Apartment|Unit) \w+, ", address)
+
+    def test_address_unit_and_po_box_rates(self):
+        fake = Faker()
+        fake.seed(42)
+        plain = fake.addresses(20)
+        fake.seed(42)
+        assert fake.addresses(20, unit_rate=0.0, po_box_rate=0.0) == plain
+
+        boxes = fake.addresses(20, po_box_rate=1.0)
+        assert all(a.startswith("PO Box ") for a in boxes)
+        with_units = fake.addresses(20, unit_rate=1.0)
+        assert all(re.search(r" (Apt|Suite|Unit) \w+,", a) for a in with_units)
+        assert fake.address(po_box_rate=1.0).startswith("PO Box ")
+        assert len(set(fake.addresses(50, unique=True, unit_rate=0.5))) == 50
+
+    def test_address_rates_invalid(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="unit_rate must be between 0.0 and 1.0"):
+            fake.address(unit_rate=1.5)
+        with pytest.raises(ValueError, match="at most 1.0"):
+            fake.addresses(5, unit_rate=0.6, po_box_rate=0.6)
+
+    def test_county(self):
+        fake = Faker()
+        fake.seed(42)
+        assert all(
+            re.search(r" (County|Parish|Borough|Municipality)$|^Carson City$", c)
+            for c in fake.counties(100)
+        )
+        for c in fake.counties(50, state="tx", with_state=True):
+            assert c.endswith(" County, TX"), c
+        assert fake.county("Louisiana").endswith(" Parish")
+        assert re.match(r"^.+, [A-Z]{2}$", forgery.county(with_state=True))
+        assert len(forgery.counties(3)) == 3
+        with pytest.raises(ValueError, match="unknown US state 'Ontario'"):
+            fake.county("Ontario")
+
+    def test_county_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        rows = fake.records(10, {"c": "county", "wa": ("county", "WA", True)})
+        assert all(row["wa"].endswith(", WA") for row in rows)
+        with pytest.raises(ValueError, match="unknown US state"):
+            fake.records(1, {"c": ("county", "Narnia")})
+
+    ADDRESS_PARTS_KEYS = {
+        "building_number",
+        "street_name",
+        "street_suffix",
+        "unit",
+        "city",
+        "region",
+        "region_abbr",
+        "postal_code",
+        "country",
+    }
+
+    def test_address_parts(self):
+        fake = Faker()
+        fake.seed(42)
+        parts = fake.address_parts()
+        assert set(parts) == self.ADDRESS_PARTS_KEYS
+        assert parts["building_number"].isdigit()
+        assert parts["unit"] == ""
+        assert parts["country"] == "United States"
+        batch = fake.address_parts_batch(50, unit_rate=1.0)
+        assert all(p["unit"] for p in batch)
+        assert all(len(p["region_abbr"]) == 2 for p in batch)
+        assert Faker("de_DE").address_parts()["country"] == "Germany"
+        assert set(forgery.address_parts()) == self.ADDRESS_PARTS_KEYS
+        assert len(forgery.address_parts_batch(3)) == 3
+        with pytest.raises(ValueError, match="unit_rate must be between"):
+            fake.address_parts(unit_rate=-0.5)
+
+    def test_address_parts_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        rows = fake.records(5, {"a": "address_parts", "b": ("address_parts", 1.0)})
+        assert all(set(row["a"]) == self.ADDRESS_PARTS_KEYS for row in rows)
+        assert all(row["b"]["unit"] for row in rows)
+        with pytest.raises(ValueError, match="unit_rate"):
+            fake.records(1, {"a": ("address_parts", 2.0)})
+
+    @pytest.mark.skipif(not HAS_PYARROW, reason="pyarrow not installed")
+    def test_address_parts_arrow(self):
+        fake = Faker()
+        fake.seed(42)
+        batch = fake.records_arrow(10, {"addr": "address_parts"})
+        field_type = batch.schema.field("addr").type
+        assert pa.types.is_struct(field_type)
+        assert {field_type.field(i).name for i in range(field_type.num_fields)} == (
+            self.ADDRESS_PARTS_KEYS
+        )
+
+    def test_country_formats(self):
+        fake = Faker()
+        results = {}
+        for fmt in ["name", "alpha2", "alpha3", "numeric"]:
+            fake.seed(42)
+            results[fmt] = fake.countries(50, format=fmt)
+        fake.seed(42)
+        assert fake.countries(50) == results["name"]
+        assert all(re.match(r"^[A-Z]{2}$", c) for c in results["alpha2"])
+        assert all(re.match(r"^[A-Z]{3}$", c) for c in results["alpha3"])
+        assert all(re.match(r"^\d{3}$", c) for c in results["numeric"])
+        pairs = set(zip(results["name"], results["alpha2"], results["alpha3"], results["numeric"]))
+        if any(p[0] == "Germany" for p in pairs):
+            assert ("Germany", "DE", "DEU", "276") in pairs
+
+    def test_country_code(self):
+        fake = Faker()
+        fake.seed(42)
+        assert re.match(r"^[A-Z]{2}$", fake.country_code())
+        assert re.match(r"^[A-Z]{3}$", fake.country_code("alpha3"))
+        assert re.match(r"^\d{3}$", fake.country("numeric"))
+        codes = fake.country_codes(100, unique=True)
+        assert len(set(codes)) == 100
+        assert all(len(c) == 2 for c in codes)
+
+    def test_country_format_invalid(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="unknown country format 'iso'"):
+            fake.country("iso")
+        with pytest.raises(ValueError, match="unknown country format"):
+            fake.country_codes(5, format="alpha4")
+
+    def test_country_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {
+            "code": "country_code",
+            "alpha3": ("country", "alpha3"),
+            "name": ("country", "name"),
+        }
+        for row in fake.records(20, schema):
+            assert re.match(r"^[A-Z]{2}$", row["code"])
+            assert re.match(r"^[A-Z]{3}$", row["alpha3"])
+            assert len(row["name"]) > 3
+        with pytest.raises(ValueError, match="unknown country format"):
+            fake.records(1, {"c": ("country", "iso")})
+
+    def test_country_code_convenience(self):
+        forgery.seed(42)
+        assert len(forgery.country_code()) == 2
+        assert len(forgery.country("alpha3")) == 3
+        assert len(forgery.country_codes(5, format="numeric")) == 5
+        assert all(len(c) == 2 for c in forgery.countries(5, format="alpha2"))
+
+
+class TestGeoGeneration:
+    """Tests for latitude/longitude generation."""
+
+    def test_coordinate_ranges(self):
+        fake = Faker()
+        fake.seed(42)
+        for lat, lon in fake.coordinates(500):
+            assert -90 <= lat <= 90
+            assert -180 <= lon <= 180
+        assert all(-90 <= v <= 90 for v in fake.latitudes(100))
+        assert all(-180 <= v <= 180 for v in fake.longitudes(100))
+
+    def test_precision(self):
+        fake = Faker()
+        fake.seed(42)
+        for lat, lon in fake.coordinates(100, precision=2):
+            assert lat == round(lat, 2)
+            assert lon == round(lon, 2)
+        assert all(v == int(v) for v in fake.latitudes(50, precision=0))
+
+    def test_single_values(self):
+        fake = Faker()
+        fake.seed(42)
+        lat, lon = fake.coordinate()
+        assert isinstance(lat, float)
+        assert isinstance(lon, float)
+        assert isinstance(fake.latitude(3), float)
+        assert isinstance(fake.longitude(3), float)
+
+    def test_deterministic(self):
+        fake1 = Faker()
+        fake1.seed(42)
+        fake2 = Faker()
+        fake2.seed(42)
+        assert fake1.coordinates(10) == fake2.coordinates(10)
+
+    def test_geo_convenience(self):
+        forgery.seed(42)
+        assert len(forgery.coordinates(5)) == 5
+        assert len(forgery.latitudes(5)) == 5
+        assert len(forgery.longitudes(5, precision=1)) == 5
+        assert isinstance(forgery.coordinate(), tuple)
+        assert isinstance(forgery.latitude(), float)
+        assert isinstance(forgery.longitude(), float)
+
+    def test_coordinates_in_bbox(self):
+        fake = Faker()
+        fake.seed(42)
+        points = fake.coordinates_in_bbox(500, 40.5, 40.9, -74.3, -73.7)
+        assert len(points) == 500
+        for lat, lon in points:
+            assert 40.5 <= lat <= 40.9
+            assert -74.3 <= lon <= -73.7
+        lat, lon = fake.coordinate_in_bbox(10.0, 10.0, 20.0, 20.0)
+        assert (lat, lon) == (10.0, 20.0)
+
+    def test_coordinates_in_bbox_invalid(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="bounding box"):
+            fake.coordinates_in_bbox(5, 41.0, 40.0, 0.0, 1.0)
+        with pytest.raises(ValueError, match="bounding box"):
+            fake.coordinate_in_bbox(-95.0, 0.0, 0.0, 1.0)
+
+    def test_coordinates_in_country(self):
+        fake = Faker()
+        fake.seed(42)
+        for lat, lon in fake.coordinates_in_country(500, "DE"):
+            assert 47.3 <= lat <= 55.1
+            assert 5.9 <= lon <= 15.0
+        lat, lon = fake.coordinate_in_country("au")
+        assert lat < 0 and lon > 100
+        with pytest.raises(ValueError, match="unsupported country 'XX'"):
+            fake.coordinates_in_country(5, "XX")
+
+    def test_bounded_convenience(self):
+        forgery.seed(42)
+        assert len(forgery.coordinates_in_bbox(5, 0.0, 1.0, 0.0, 1.0)) == 5
+        assert len(forgery.coordinates_in_country(5, "JP", precision=2)) == 5
+        assert isinstance(forgery.coordinate_in_country("US"), tuple)
+        assert isinstance(forgery.coordinate_in_bbox(0.0, 1.0, 0.0, 1.0), tuple)
+
+
+class TestGpsTrack:
+    """Tests for GPS track generation."""
+
+    def test_route(self):
+        fake = Faker()
+        fake.seed(42)
+        track = fake.gps_track(200, start_coord=(51.5074, -0.1278), max_speed_kmh=60)
+        assert len(track) == 200
+        assert (track[0]["latitude"], track[0]["longitude"]) == (51.5074, -0.1278)
+        stamps = [datetime.fromisoformat(p["timestamp"]) for p in track]
+        assert all(b - a == timedelta(seconds=10) for a, b in zip(stamps, stamps[1:]))
+        for a, b in zip(track, track[1:]):
+            # ~0.17 km per 10 s at 60 km/h; a degree of latitude is ~111 km
+            assert abs(b["latitude"] - a["latitude"]) < 0.002
+            assert abs(b["longitude"] - a["longitude"]) < 0.003
+        assert all(0 <= p["speed_kmh"] <= 60 for p in track)
+        assert all(0 <= p["heading"] < 360 for p in track)
+
+    def test_defaults_and_determinism(self):
+        fake1 = Faker()
+        fake2 = Faker()
+        fake1.seed(7)
+        fake2.seed(7)
+        assert fake1.gps_track(20) == fake2.gps_track(20)
+        assert len(forgery.gps_track(5)) == 5
+
+    def test_invalid_options(self):
+        with pytest.raises(ValueError, match="invalid start coordinate"):
+            forgery.gps_track(5, start_coord=(95.0, 0.0))
+        with pytest.raises(ValueError, match="max_speed_kmh"):
+            forgery.gps_track(5, max_speed_kmh=-1.0)
+
+
+class TestAirportGeneration:
+    """Tests for airport generation."""
+
+    def test_airport_code_formats(self):
+        fake = Faker()
+        fake.seed(42)
+        assert all(re.match(r"^[A-Z]{3}$", c) for c in fake.airport_codes(200))
+        assert all(re.match(r"^[A-Z]{4}$", c) for c in fake.airport_icao_codes(200))
+        assert all(name for name in fake.airport_names(50))
+        assert all(city for city in fake.airport_cities(50))
+
+    def test_airports_consistent(self):
+        fake = Faker()
+        fake.seed(42)
+        airports = fake.airports(300)
+        assert len(airports) == 300
+        by_iata = {}
+        for airport in airports:
+            assert set(airport) == {"iata", "icao", "name", "city", "country"}
+            assert by_iata.setdefault(airport["iata"], airport) == airport
+        heathrow = by_iata.get("LHR")
+        if heathrow is not None:
+            assert heathrow["icao"] == "EGLL"
+            assert heathrow["city"] == "London"
+
+    def test_airport_codes_unique(self):
+        fake = Faker()
+        fake.seed(42)
+        codes = fake.airport_codes(20, unique=True)
+        assert len(set(codes)) == 20
+        with pytest.raises(ValueError):
+            fake.airport_codes(1000, unique=True)
+
+    def test_airport_schema_types(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {
+            "code": "airport_code",
+            "icao": "airport_icao_code",
+            "name": "airport_name",
+            "city": "airport_city",
+        }
+        for row in fake.records(20, schema):
+            assert len(row["code"]) == 3
+            assert len(row["icao"]) == 4
+            assert row["name"]
+            assert row["city"]
+
+    def test_airport_convenience(self):
+        forgery.seed(42)
+        assert len(forgery.airport_code()) == 3
+        assert len(forgery.airport_icao_code()) == 4
+        assert isinstance(forgery.airport_name(), str)
+        assert isinstance(forgery.airport_city(), str)
+        assert len(forgery.airport_codes(5)) == 5
+        assert len(forgery.airports(5)) == 5
+
+    def test_flight_numbers(self):
+        fake = Faker()
+        fake.seed(42)
+        numbers = fake.flight_numbers(300)
+        assert all(re.match(r"^[A-Z0-9]{2}[1-9]\d{0,3}$", n) for n in numbers)
+        assert len(set(fake.flight_numbers(100, unique=True))) == 100
+        assert re.match(r"^[A-Z0-9]{2}\d{1,4}$", forgery.flight_number())
+        assert fake.records(5, {"flight": "flight_number"})[0]["flight"][:2].isalnum()
+
+
+class TestTrackingNumberGeneration:
+    """Tests for shipment tracking number generation."""
+
+    PATTERNS = {
+        "ups": r"^1Z[0-9A-Z]{6}\d{10}$",
+        "fedex": r"^(\d{12}|\d{15})$",
+        "usps": r"^9[234]\d{20}$",
+        "dhl": r"^\d{10}$",
+    }
+
+    def test_carrier_formats(self):
+        fake = Faker()
+        fake.seed(42)
+        for carrier, pattern in self.PATTERNS.items():
+            for number in fake.tracking_numbers(100, carrier=carrier):
+                assert re.match(pattern, number), (carrier, number)
+
+    def test_dhl_check_digit(self):
+        fake = Faker()
+        fake.seed(42)
+        for number in fake.tracking_numbers(100, carrier="dhl"):
+            assert int(number[:9]) % 7 == int(number[9])
+
+    def test_any_carrier_and_errors(self):
+        fake = Faker()
+        fake.seed(42)
+        patterns = list(self.PATTERNS.values())
+        for number in fake.tracking_numbers(100):
+            assert any(re.match(p, number) for p in patterns), number
+        with pytest.raises(ValueError, match="unknown carrier 'royal_mail'"):
+            fake.tracking_number("royal_mail")
+
+    def test_tracking_number_schema_and_convenience(self):
+        forgery.seed(42)
+        assert forgery.tracking_number("ups").startswith("1Z")
+        assert len(forgery.tracking_numbers(5)) == 5
+        rows = forgery.records(10, {"any": "tracking_number", "dhl": ("tracking_number", "dhl")})
+        assert all(len(row["dhl"]) == 10 for row in rows)
+
+
+class TestLanguageGeneration:
+    """Tests for language code and name generation."""
+
+    def test_language_code_format(self):
+        fake = Faker()
+        fake.seed(42)
+        codes = fake.language_codes(200)
+        assert len(codes) == 200
+        assert all(re.match(r"^[a-z]{2}$", c) for c in codes)
+        assert re.match(r"^[a-z]{2}$", fake.language_code())
+
+    def test_language_names(self):
+        fake = Faker()
+        fake.seed(42)
+        names = fake.language_names(200)
+        assert all(name[0].isupper() for name in names)
+        assert isinstance(fake.language_name(), str)
+
+    def test_language_unique(self):
+        fake = Faker()
+        fake.seed(42)
+        assert len(set(fake.language_codes(30, unique=True))) == 30
+        with pytest.raises(ValueError):
+            fake.language_codes(500, unique=True)
+
+    def test_language_schema_types(self):
+        fake = Faker()
+        fake.seed(42)
+        rows = fake.records(20, {"code": "language_code", "name": "language_name"})
+        for row in rows:
+            assert re.match(r"^[a-z]{2}$", row["code"])
+            assert row["name"]
+
+    def test_language_convenience(self):
+        forgery.seed(42)
+        assert len(forgery.language_code()) == 2
+        assert isinstance(forgery.language_name(), str)
+        assert len(forgery.language_codes(5)) == 5
+        assert len(forgery.language_names(5)) == 5
+
+
+class TestFileSystemGeneration:
+    """Tests for file name, extension and path generation."""
+
+    def test_file_names(self):
+        fake = Faker()
+        fake.seed(42)
+        names = fake.file_names(200, category="image")
+        assert len(names) == 200
+        for name in names:
+            assert re.match(r"^[a-z]+(_[a-z]+)?\.(png|jpg|jpeg|gif|bmp|svg|webp|tiff)$", name)
+        assert "." in fake.file_name()
+
+    def test_file_extensions(self):
+        fake = Faker()
+        fake.seed(42)
+        audio = {"mp3", "wav", "flac", "ogg", "m4a"}
+        assert set(fake.file_extensions(100, "audio")) <= audio
+        assert not fake.file_extension().startswith(".")
+
+    def test_file_paths(self):
+        fake = Faker()
+        fake.seed(42)
+        for path in fake.file_paths(50, depth=3):
+            assert path.startswith("/")
+            assert path.count("/") == 4
+        for path in fake.file_paths(50, depth=1, os="windows", category="document"):
+            assert path.startswith("C:\\")
+            assert path.count("\\") == 2
+            assert "/" not in path
+        assert fake.file_path(depth=0).count("/") == 1
+
+    def test_invalid_arguments(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="unsupported file category 'spreadsheet'"):
+            fake.file_name("spreadsheet")
+        with pytest.raises(ValueError, match="unknown path os 'macos'"):
+            fake.file_path(os="macos")
+
+    def test_file_schema_types(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {"name": "file_name", "ext": "file_extension", "path": "file_path"}
+        for row in fake.records(20, schema):
+            assert "." in row["name"]
+            assert "." not in row["ext"]
+            assert row["path"].startswith("/")
+            assert row["path"].count("/") == 3
+
+    def test_file_convenience(self):
+        forgery.seed(42)
+        assert isinstance(forgery.file_name("code"), str)
+        assert isinstance(forgery.file_extension(), str)
+        assert forgery.file_path(os="windows").startswith("C:\\")
+        assert len(forgery.file_names(5)) == 5
+        assert len(forgery.file_extensions(5)) == 5
+        assert len(forgery.file_paths(5, depth=4)) == 5
+
+    def test_mime_types(self):
+        fake = Faker()
+        fake.seed(42)
+        mimes = fake.mime_types(500)
+        assert len(mimes) == 500
+        assert all("/" in m for m in mimes)
+        tops = [m.split("/")[0] for m in mimes]
+        assert tops.count("application") > tops.count("audio")
+        assert tops.count("image") > tops.count("video")
+        assert all(m.startswith("image/") for m in fake.mime_types(50, category="image"))
+        assert fake.mime_type("TEXT").startswith("text/")
+        with pytest.raises(ValueError, match="unsupported MIME category 'font'"):
+            fake.mime_type("font")
+
+    def test_mime_type_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {"any": "mime_type", "video": ("mime_type", "video")}
+        for row in fake.records(20, schema):
+            assert "/" in row["any"]
+            assert row["video"].startswith("video/")
+        with pytest.raises(ValueError, match="unsupported MIME category"):
+            fake.records(1, {"m": ("mime_type", "font")})
+
+    def test_mime_type_convenience(self):
+        forgery.seed(42)
+        assert "/" in forgery.mime_type()
+        assert len(forgery.mime_types(5, "audio")) == 5
+
+
+SEMVER_RE = re.compile(
+    r"^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)"
+    r"(-(alpha|beta|rc)\.[1-9]\d*)?(\+[0-9A-Za-z.-]+)?$"
+)
+
+
+class TestSoftwareGeneration:
+    """Tests for semantic version, git, container image and Kubernetes name generation."""
+
+    def test_semvers_plain(self):
+        fake = Faker()
+        fake.seed(42)
+        versions = fake.semvers(200)
+        assert len(versions) == 200
+        for version in versions:
+            assert SEMVER_RE.match(version), version
+            assert "-" not in version and "+" not in version
+
+    def test_semvers_with_metadata(self):
+        fake = Faker()
+        fake.seed(42)
+        for version in fake.semvers(100, prerelease=True, build=True):
+            assert SEMVER_RE.match(version), version
+            assert "-" in version and "+" in version
+        assert "+" in fake.semver(build=True)
+        assert "-" in fake.semver(prerelease=True)
+
+    def test_semver_deterministic(self):
+        fake1, fake2 = Faker(), Faker()
+        fake1.seed(7)
+        fake2.seed(7)
+        assert fake1.semvers(20, True, True) == fake2.semvers(20, True, True)
+
+    def test_semver_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(20, {"version": "semver"}):
+            assert SEMVER_RE.match(row["version"])
+            assert "-" not in row["version"]
+
+    def test_semver_convenience(self):
+        forgery.seed(42)
+        assert SEMVER_RE.match(forgery.semver())
+        assert len(forgery.semvers(5, build=True)) == 5
+
+    def test_git_shas(self):
+        fake = Faker()
+        fake.seed(42)
+        for sha in fake.git_shas(50):
+            assert re.match(r"^[0-9a-f]{40}$", sha)
+        for sha in fake.git_shas(50, short=True):
+            assert re.match(r"^[0-9a-f]{7}$", sha)
+        assert len(forgery.git_sha(short=True)) == 7
+
+    def test_commit_messages(self):
+        fake = Faker()
+        fake.seed(42)
+        pattern = re.compile(r"^(feat|fix|docs|refactor|perf|test|chore|ci)(\([a-z]+\))?: [a-z].+$")
+        for message in fake.commit_messages(200):
+            assert pattern.match(message), message
+        assert pattern.match(forgery.commit_message())
+        row = fake.records(1, {"sha": "git_sha", "msg": "commit_message"})[0]
+        assert len(row["sha"]) == 40
+        assert pattern.match(row["msg"])
+
+    def test_docker_images(self):
+        fake = Faker()
+        fake.seed(42)
+        for image in fake.docker_images(50, digest=True):
+            name, digest = image.split("@sha256:")
+            assert re.match(r"^[0-9a-f]{64}$", digest)
+            path, tag = name.rsplit(":", 1)
+            assert SEMVER_RE.match(tag), image
+            assert path.count("/") == 2
+        for image in fake.docker_images(50, registry=False, tag=False):
+            assert re.match(r"^[a-z]+/[a-z-]+$", image), image
+        assert forgery.docker_image().count("/") == 2
+        rows = fake.records(5, {"a": "docker_image", "b": ("docker_image", False, False, True)})
+        for row in rows:
+            assert ":" in row["a"] and "@" not in row["a"]
+            assert re.match(r"^[a-z]+/[a-z-]+@sha256:[0-9a-f]{64}$", row["b"]), row["b"]
+
+    def test_k8s_names(self):
+        fake = Faker()
+        fake.seed(42)
+        dns_label = re.compile(r"^[a-z0-9]([-a-z0-9]{0,61}[a-z0-9])?$")
+        suffix = "[bcdfghjklmnpqrstvwxz2456789]"
+        for name in fake.k8s_names(100):
+            assert dns_label.match(name), name
+            assert re.search(rf"-{suffix}{{8,10}}-{suffix}{{5}}$", name), name
+        for name in fake.k8s_names(50, kind="replicaset"):
+            assert re.search(rf"-{suffix}{{8,10}}$", name), name
+        for name in fake.k8s_names(50, kind="namespace"):
+            assert re.match(r"^[a-z]+-(dev|staging|prod)$", name), name
+        assert dns_label.match(forgery.k8s_name("deployment"))
+        with pytest.raises(ValueError, match="unknown Kubernetes resource kind"):
+            fake.k8s_name("service")
+        row = fake.records(1, {"pod": "k8s_name", "ns": ("k8s_name", "namespace")})[0]
+        assert row["pod"].count("-") >= 3
+        assert re.match(r"^[a-z]+-(dev|staging|prod)$", row["ns"])
+
+
+class TestCloudGeneration:
+    """Tests for AWS ARN, S3 URI and cloud region generation."""
+
+    PATTERNS = {
+        "s3": r"^arn:aws(-cn|-us-gov)?:s3:::[a-z0-9-]{3,63}(/\S+)?$",
+        "iam": r"^arn:aws(-cn|-us-gov)?:iam::\d{12}:(role|user|policy)/[A-Za-z0-9-]+$",
+        "lambda": r"^arn:aws(-cn|-us-gov)?:lambda:[a-z]{2}(-gov)?-[a-z]+-\d:\d{12}:function:"
+        r"[a-z-]+(:[a-z]+)?$",
+    }
+
+    def test_service_formats(self):
+        fake = Faker()
+        fake.seed(42)
+        for service, pattern in self.PATTERNS.items():
+            for arn in fake.arns(100, service=service):
+                assert re.match(pattern, arn), (service, arn)
+
+    def test_any_service_and_errors(self):
+        fake = Faker()
+        fake.seed(42)
+        patterns = list(self.PATTERNS.values())
+        for arn in fake.arns(100):
+            assert any(re.match(p, arn) for p in patterns), arn
+        with pytest.raises(ValueError, match="unknown ARN service 'ec2'"):
+            fake.arn("ec2")
+
+    def test_s3_uris(self):
+        fake = Faker()
+        fake.seed(42)
+        for uri in fake.s3_uris(100):
+            assert re.match(r"^s3://[a-z0-9-]{3,63}/\S+$", uri), uri
+
+    def test_cloud_schema_and_convenience(self):
+        forgery.seed(42)
+        assert forgery.arn("lambda").startswith("arn:aws")
+        assert forgery.s3_uri().startswith("s3://")
+        assert len(forgery.arns(5)) == len(forgery.s3_uris(5)) == 5
+        rows = forgery.records(10, {"arn": ("arn", "iam"), "uri": "s3_uri", "any": "arn"})
+        assert all(":iam::" in row["arn"] for row in rows)
+        assert all(row["uri"].startswith("s3://") for row in rows)
+
+    def test_cloud_regions(self):
+        fake = Faker()
+        fake.seed(42)
+        shapes = {
+            "aws": r"^[a-z]{2}-[a-z]+-\d$",
+            "gcp": r"^[a-z]+-[a-z]+\d$",
+            "azure": r"^[a-z]+\d?$",
+        }
+        for provider, pattern in shapes.items():
+            regions = fake.cloud_regions(500, provider=provider)
+            assert all(re.match(pattern, r) for r in regions), provider
+        regions = fake.cloud_regions(2000)
+        assert regions.count("us-east-1") > regions.count("af-south-1") * 5
+        assert forgery.cloud_region("gcp") != ""
+        with pytest.raises(ValueError, match="unknown cloud provider 'oracle'"):
+            fake.cloud_region("oracle")
+
+    def test_cloud_region_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        rows = fake.records(20, {"aws": "cloud_region", "az": ("cloud_region", "azure")})
+        assert all(re.match(r"^[a-z]{2}-[a-z]+-\d$", row["aws"]) for row in rows)
+        assert all("-" not in row["az"] for row in rows)
+
+
+class TestLogGeneration:
+    """Tests for access log and syslog line generation."""
+
+    COMBINED = re.compile(
+        r'^(\d{1,3}(?:\.\d{1,3}){3}) - - \[(\d{2}/[A-Z][a-z]{2}/2024:\d{2}:\d{2}:\d{2} \+0000)\] '
+        r'"([A-Z]+) (/\S*) (HTTP/\d\.\d)" (\d{3}) (\d+|-) "([^"]*)" "([^"]*)"$'
+    )
+
+    def test_access_log_lines(self):
+        fake = Faker()
+        fake.seed(42)
+        for line in fake.access_logs(500):
+            match = self.COMBINED.match(line)
+            assert match, line
+            if match.group(6) in ("204", "304"):
+                assert match.group(7) == "-"
+
+    def test_access_log_entries(self):
+        fake = Faker()
+        fake.seed(42)
+        entries = fake.access_log_entries(100)
+        for entry in entries:
+            assert set(entry) == {
+                "ip",
+                "timestamp",
+                "method",
+                "path",
+                "protocol",
+                "status",
+                "size",
+                "referrer",
+                "user_agent",
+            }
+            assert isinstance(entry["status"], int)
+            assert isinstance(entry["size"], int)
+            assert entry["path"].startswith("/")
+        fake.seed(42)
+        lines = fake.access_logs(100)
+        assert [line.split(" ")[0] for line in lines] == [e["ip"] for e in entries]
+
+    def test_access_log_schema_and_convenience(self):
+        forgery.seed(42)
+        assert self.COMBINED.match(forgery.access_log())
+        assert len(forgery.access_log_entries(3)) == 3
+        rows = forgery.records(5, {"line": "access_log", "entry": "access_log_entry"})
+        for row in rows:
+            assert self.COMBINED.match(row["line"])
+            assert row["entry"]["status"].isdigit()
+
+    RFC5424 = re.compile(
+        r"^<(\d{1,3})>1 (2024-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}\.\d{3}Z) ([a-z]+-\d{2}) (\S+) "
+        r"(\d+) - - (.+)$"
+    )
+    RFC3164 = re.compile(
+        r"^<(\d{1,3})>[A-Z][a-z]{2} [ \d]\d \d{2}:\d{2}:\d{2} ([a-z]+-\d{2}) (\S+)\[(\d+)\]: (.+)$"
+    )
+
+    def test_syslog_lines(self):
+        fake = Faker()
+        fake.seed(42)
+        for line in fake.syslogs(200):
+            assert self.RFC5424.match(line), line
+        for line in fake.syslogs(200, format="rfc3164"):
+            assert self.RFC3164.match(line), line
+        with pytest.raises(ValueError, match="unknown syslog format 'gelf'"):
+            fake.syslog("gelf")
+
+    def test_syslog_entries(self):
+        fake = Faker()
+        fake.seed(42)
+        severities = ["emerg", "alert", "crit", "err", "warning", "notice", "info", "debug"]
+        for entry in fake.syslog_entries(200):
+            assert entry["severity"] in severities
+            assert entry["priority"] % 8 == severities.index(entry["severity"])
+            assert isinstance(entry["pid"], int)
+        fake.seed(42)
+        entry = fake.syslog_entry()
+        fake.seed(42)
+        line = fake.syslog()
+        assert line == (
+            f"<{entry['priority']}>1 {entry['timestamp']} {entry['hostname']} "
+            f"{entry['app_name']} {entry['pid']} - - {entry['message']}"
+        )
+
+    def test_syslog_schema(self):
+        forgery.seed(42)
+        rows = forgery.records(
+            5, {"line": "syslog", "bsd": ("syslog", "rfc3164"), "entry": "syslog_entry"}
+        )
+        for row in rows:
+            assert self.RFC5424.match(row["line"])
+            assert self.RFC3164.match(row["bsd"])
+            assert row["entry"]["pid"].isdigit()
+
 
 class TestPhoneGeneration:
     """Tests for phone number generation."""
@@ -300,6 +1584,235 @@ class TestPhoneGeneration:
         assert "(" in forgery.phone_number()
         assert len(forgery.phone_numbers(5)) == 5
 
+    @pytest.mark.parametrize(
+        ("locale", "code"),
+        [("en_US", "+1"), ("en_GB", "+44"), ("de_DE", "+49"), ("ru_RU", "+7"), ("ja_JP", "+81")],
+    )
+    def test_phone_e164(self, locale, code):
+        fake = Faker(locale)
+        fake.seed(42)
+        for val in fake.phone_numbers(200, format="e164"):
+            assert re.fullmatch(r"\+\d{8,15}", val), val
+            assert val.startswith(code)
+
+    def test_phone_e164_normalizes_formatted(self):
+        fake1 = Faker("de_DE")
+        fake1.seed(7)
+        fake2 = Faker("de_DE")
+        fake2.seed(7)
+        for written, e164 in zip(
+            fake1.phone_numbers(50), fake2.phone_numbers(50, format="e164"), strict=True
+        ):
+            digits = re.sub(r"\D", "", written)
+            national = digits[2:] if written.startswith("+") else digits[1:]
+            assert e164 == "+49" + national
+
+    def test_phone_e164_convenience_and_schema(self):
+        forgery.seed(42)
+        assert forgery.phone_number("E164").startswith("+1")
+        assert len(forgery.phone_numbers(5, format="e164")) == 5
+
+        fake = Faker()
+        fake.seed(42)
+        for record in fake.records(20, {"p": ("phone", "e164"), "q": "phone"}):
+            assert re.fullmatch(r"\+1\d{10}", record["p"])
+            assert "(" in record["q"] or "-" in record["q"]
+
+    @pytest.mark.parametrize(
+        ("locale", "mobile", "landline"),
+        [
+            ("en_GB", r"\+447", r"\+44[12]"),
+            ("fr_FR", r"\+33[67]", r"\+33[1-5]"),
+            ("de_DE", r"\+491[5-7]", r"\+49[2-9]"),
+            ("nl_NL", r"\+316", r"\+31[1-57]"),
+        ],
+    )
+    def test_phone_kind(self, locale, mobile, landline):
+        fake = Faker(locale)
+        fake.seed(42)
+        for val in fake.phone_numbers(100, format="e164", kind="mobile"):
+            assert re.match(mobile, val), val
+        for val in fake.phone_numbers(100, format="e164", kind="landline"):
+            assert re.match(landline, val), val
+
+    def test_phone_kind_uk_written(self):
+        fake = Faker("en_GB")
+        fake.seed(42)
+        for val in fake.phone_numbers(50, kind="mobile"):
+            assert val.startswith(("07", "+44 7")), val
+
+    def test_phone_kind_convenience_and_schema(self):
+        forgery.seed(42)
+        assert len(forgery.phone_numbers(5, kind="landline")) == 5
+        assert forgery.phone_number(kind="mobile")
+
+        fake = Faker("fr_FR")
+        fake.seed(42)
+        for record in fake.records(20, {"m": ("phone", "e164", "mobile")}):
+            assert re.match(r"\+33[67]\d{8}$", record["m"])
+
+        with pytest.raises(ValueError, match="unknown phone kind 'voip'"):
+            fake.phone_number(kind="voip")
+        with pytest.raises(ValueError, match="unknown phone kind"):
+            fake.records(1, {"p": ("phone", "e164", "fax")})
+
+    def test_phone_safe_reserved_ranges(self):
+        fake = Faker()
+        fake.seed(42)
+        for val in fake.phone_numbers(100, format="e164", safe=True):
+            assert re.match(r"\+1\d{3}55501\d{2}$", val), val
+        for val in fake.phone_numbers(100, safe=True):
+            assert "555-01" in val, val
+
+        fake = Faker("en_GB")
+        fake.seed(42)
+        for val in fake.phone_numbers(100, kind="mobile", safe=True, unique=True):
+            assert val.startswith(("07700 900", "+44 7700 900")), val
+
+    def test_phone_safe_convenience_and_schema(self):
+        forgery.seed(42)
+        assert len(forgery.phone_numbers(5, safe=True)) == 5
+        assert "555-01" in forgery.phone_number(safe=True)
+
+        fake = Faker("fr_FR")
+        fake.seed(42)
+        schema = {"p": ("phone", "e164", "mobile", True)}
+        for record in fake.records(20, schema):
+            assert record["p"].startswith("+3363998"), record["p"]
+
+        fake = Faker("es_ES")
+        with pytest.raises(ValueError, match="no reserved fictional"):
+            fake.phone_number(safe=True)
+        with pytest.raises(ValueError, match="no reserved fictional"):
+            fake.records(1, {"p": ("phone", "formatted", "any", True)})
+
+    def test_phone_invalid_format(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="unknown phone number format 'rfc3966'"):
+            fake.phone_number("rfc3966")
+        with pytest.raises(ValueError, match="unknown phone number format"):
+            fake.records(1, {"p": ("phone", "intl")})
+
+
+class TestProfileGeneration:
+    """Tests for person profile generation."""
+
+    KEYS = {
+        "name",
+        "first_name",
+        "last_name",
+        "gender",
+        "date_of_birth",
+        "age",
+        "email",
+        "phone",
+        "street_address",
+        "city",
+        "state",
+        "postal_code",
+        "address",
+    }
+
+    def test_profile_keys(self):
+        fake = Faker()
+        fake.seed(42)
+        profile = fake.profile()
+        assert set(profile) == self.KEYS
+        assert isinstance(profile["age"], int)
+
+    def test_profiles_consistent(self):
+        fake = Faker()
+        fake.seed(42)
+        reference = date(2024, 1, 1)
+        for p in fake.profiles(200, min_age=30, max_age=40):
+            assert p["name"] == f"{p['first_name']} {p['last_name']}"
+            assert p["gender"] in ("male", "female")
+            born = date.fromisoformat(p["date_of_birth"])
+            had_birthday = (reference.month, reference.day) >= (born.month, born.day)
+            assert p["age"] == reference.year - born.year - (0 if had_birthday else 1)
+            assert 30 <= p["age"] <= 40
+            assert p["last_name"].lower() in p["email"]
+            assert p["address"].startswith(p["street_address"])
+            assert p["city"] in p["address"]
+
+    def test_profile_gender_follows_name(self):
+        fake = Faker("ru_RU")
+        fake.seed(7)
+        genders = {}
+        for p in fake.profiles(300):
+            assert genders.setdefault(p["first_name"], p["gender"]) == p["gender"]
+            assert p["last_name"].endswith("а") == (p["gender"] == "female")
+        assert set(genders.values()) == {"male", "female"}
+
+    def test_profile_area_code_matches_state(self):
+        fake = Faker()
+        fake.seed(42)
+        for p in fake.profiles(100):
+            if p["state"] == "Texas":
+                digits = re.sub(r"\D", "", p["phone"])[-10:]
+                assert digits[:3] in {"214", "281", "512", "713", "817", "915", "956", "972"}
+
+    def test_profile_deterministic(self):
+        a, b = Faker("ja_JP"), Faker("ja_JP")
+        a.seed(1)
+        b.seed(1)
+        assert a.profiles(20) == b.profiles(20)
+        for p in a.profiles(20):
+            assert p["email"].isascii()
+
+    def test_profile_convenience_and_errors(self):
+        forgery.seed(42)
+        assert set(forgery.profile()) == self.KEYS
+        assert len(forgery.profiles(3)) == 3
+        with pytest.raises(ValueError, match="min_age must be less than or equal to max_age"):
+            forgery.profile(min_age=50, max_age=20)
+
+
+class TestDemographicGeneration:
+    """Tests for age, gender, pronoun and marital status generation."""
+
+    def test_ages_in_range(self):
+        fake = Faker()
+        fake.seed(42)
+        assert all(21 <= a <= 30 for a in fake.ages(200, 21, 30))
+        assert fake.age(40, 40) == 40
+        with pytest.raises(ValueError):
+            fake.ages(5, 30, 21)
+
+    def test_default_categories(self):
+        fake = Faker()
+        fake.seed(42)
+        assert set(fake.genders(500)) == {"female", "male", "non-binary"}
+        assert set(fake.pronouns(500)) == {"she/her", "he/him", "they/them"}
+        assert set(fake.marital_statuses(1000)) == {
+            "married",
+            "single",
+            "divorced",
+            "widowed",
+            "separated",
+        }
+
+    def test_custom_weights(self):
+        fake = Faker()
+        fake.seed(42)
+        assert set(fake.genders(100, weights={"female": 1, "male": 1})) == {"female", "male"}
+        assert fake.marital_status(weights={"widowed": 5}) == "widowed"
+        with pytest.raises(ValueError, match="unknown pronouns category 'xe/xem'"):
+            fake.pronoun(weights={"xe/xem": 1})
+        with pytest.raises(ValueError, match="at least one weight must be positive"):
+            fake.genders(5, weights={"male": 0})
+
+    def test_demographic_convenience(self):
+        forgery.seed(42)
+        assert 18 <= forgery.age() <= 80
+        assert len(forgery.ages(5)) == 5
+        assert forgery.gender() in ("female", "male", "non-binary")
+        assert len(forgery.genders(5)) == 5
+        assert "/" in forgery.pronoun()
+        assert len(forgery.pronouns(5)) == 5
+        assert isinstance(forgery.marital_status(), str)
+        assert len(forgery.marital_statuses(5)) == 5
+
 
 class TestCompanyGeneration:
     """Tests for company generation."""
@@ -341,6 +1854,265 @@ class TestCompanyGeneration:
         assert len(forgery.jobs(5)) == 5
         assert len(forgery.catch_phrases(5)) == 5
 
+    def test_catch_phrase_styles(self):
+        fake = Faker()
+        fake.seed(42)
+        assert all(" for " in p for p in fake.catch_phrases(50, style="startup"))
+        assert all(p.endswith(".") for p in fake.catch_phrases(50, style="slogan"))
+        assert all(len(p.split()) >= 3 for p in fake.catch_phrases(50, style="buzzword"))
+        assert isinstance(forgery.catch_phrase(style="buzzword"), str)
+        assert len(forgery.catch_phrases(5, style="slogan")) == 5
+        assert len(set(fake.catch_phrases(100, unique=True, style="startup"))) == 100
+        with pytest.raises(ValueError, match="catch phrase style"):
+            fake.catch_phrase(style="haiku")
+        rows = fake.records(20, {"tagline": ("catch_phrase", "slogan")})
+        assert all(row["tagline"].endswith(".") for row in rows)
+        with pytest.raises(ValueError, match="catch phrase style"):
+            fake.records(1, {"tagline": ("catch_phrase", "haiku")})
+
+    def test_job_with_level(self):
+        fake = Faker()
+        fake.seed(42)
+        assert all(j.startswith("Senior ") for j in fake.jobs(20, level="senior"))
+        assert fake.job(level="director").startswith("Director of ")
+        assert "Chief" in fake.job(level="EXECUTIVE")
+        with pytest.raises(ValueError, match="unknown job level 'ceo'"):
+            fake.job(level="ceo")
+
+    def test_job_components(self):
+        fake = Faker()
+        fake.seed(42)
+        levels = fake.job_levels(500)
+        for level in set(levels):
+            assert isinstance(fake.job(level=level), str)
+        assert levels.count("mid") > levels.count("executive")
+        assert all(isinstance(a, str) and a for a in fake.job_areas(20))
+        assert all(isinstance(r, str) and r for r in fake.job_roles(20))
+
+    def test_job_level_in_records(self):
+        fake = Faker()
+        fake.seed(42)
+        rows = fake.records(10, {"title": ("job", "lead"), "level": "job_level"})
+        assert all(row["title"].startswith("Lead ") for row in rows)
+        with pytest.raises(ValueError):
+            fake.records(1, {"title": ("job", "boss")})
+
+    def test_job_component_convenience(self):
+        forgery.seed(42)
+        assert forgery.job("junior").startswith("Junior ")
+        assert len(forgery.jobs(3, level="vp")) == 3
+        assert isinstance(forgery.job_level(), str)
+        assert isinstance(forgery.job_area(), str)
+        assert isinstance(forgery.job_role(), str)
+        assert len(forgery.job_roles(4)) == 4
+
+    def test_department_and_industry(self):
+        fake = Faker()
+        fake.seed(42)
+        assert all(isinstance(d, str) and d for d in fake.departments(50))
+        assert all(isinstance(i, str) and i for i in fake.industries(50))
+        assert len(fake.industries(20, unique=True)) == 20
+
+    def test_naics_and_sic_codes(self):
+        fake = Faker()
+        fake.seed(42)
+        for code in fake.naics_codes(200):
+            assert re.fullmatch(r"\d{6}", code)
+            assert code[2] != "0"
+        for code in fake.sic_codes(200):
+            assert re.fullmatch(r"\d{4}", code)
+            assert code[2] != "0"
+
+    def test_classification_codes_in_records(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {"industry": "industry", "naics": "naics_code", "sic": "sic_code"}
+        rows = fake.records(10, schema)
+        assert all(len(row["naics"]) == 6 and len(row["sic"]) == 4 for row in rows)
+
+    def test_department_convenience(self):
+        forgery.seed(42)
+        assert isinstance(forgery.department(), str)
+        assert isinstance(forgery.industry(), str)
+        assert len(forgery.naics_code()) == 6
+        assert len(forgery.sic_code()) == 4
+        assert len(forgery.departments(5)) == 5
+        assert len(forgery.sic_codes(5)) == 5
+
+    def test_company_profile_keys(self):
+        fake = Faker()
+        fake.seed(42)
+        profile = fake.company_profile()
+        assert set(profile) == {
+            "name",
+            "legal_form",
+            "industry",
+            "tax_id",
+            "domain",
+            "address",
+            "catch_phrase",
+        }
+        assert all(isinstance(v, str) and v for v in profile.values())
+
+    def test_company_profile_name_uses_legal_form(self):
+        fake = Faker()
+        fake.seed(42)
+        for profile in fake.company_profiles(50):
+            assert profile["name"].endswith(" " + profile["legal_form"])
+        ru = Faker("ru_RU")
+        ru.seed(42)
+        for profile in ru.company_profiles(20):
+            assert profile["name"].startswith(profile["legal_form"] + " «")
+
+    def test_company_profile_ascii_domain(self):
+        fake = Faker("ja_JP")
+        fake.seed(42)
+        for profile in fake.company_profiles(20):
+            assert profile["domain"].isascii()
+            assert "." in profile["domain"]
+
+    def test_company_profile_deterministic(self):
+        fake = Faker()
+        fake.seed(7)
+        first = fake.company_profiles(10)
+        fake.seed(7)
+        assert fake.company_profiles(10) == first
+
+    def test_company_profile_in_records(self):
+        fake = Faker()
+        fake.seed(42)
+        rows = fake.records(5, {"c": "company_profile"})
+        assert all(isinstance(row["c"], dict) for row in rows)
+        assert all("tax_id" in row["c"] for row in rows)
+
+    def test_company_profile_convenience(self):
+        forgery.seed(42)
+        assert isinstance(forgery.company_profile(), dict)
+        assert len(forgery.company_profiles(3)) == 3
+
+
+class TestVehicleGeneration:
+    """Tests for vehicle make/model generation."""
+
+    VIN_YEAR_CODES = "ABCDEFGHJKLMNPRSTVWXY123456789"
+
+    def test_vehicle_keys_and_types(self):
+        fake = Faker()
+        fake.seed(42)
+        vehicle = fake.vehicle()
+        assert set(vehicle) == {"make", "model", "year", "vin"}
+        assert isinstance(vehicle["year"], int)
+        assert len(vehicle["vin"]) == 17
+
+    def test_vehicle_vin_matches_year(self):
+        fake = Faker()
+        fake.seed(42)
+        for vehicle in fake.vehicles(100):
+            assert 1995 <= vehicle["year"] <= 2024
+            code = self.VIN_YEAR_CODES[(vehicle["year"] - 1980) % 30]
+            assert vehicle["vin"][9] == code
+
+    def test_vehicle_make_consistent_with_vin(self):
+        fake = Faker()
+        fake.seed(42)
+        for vehicle in fake.vehicles(100):
+            if vehicle["make"] == "Tesla":
+                assert vehicle["vin"].startswith("5YJ")
+                assert vehicle["year"] >= 2012
+
+    def test_vehicle_make_and_model(self):
+        fake = Faker()
+        fake.seed(42)
+        assert all(isinstance(m, str) and m for m in fake.vehicle_makes(20))
+        assert all(isinstance(m, str) and m for m in fake.vehicle_models(20))
+        rows = fake.records(5, {"make": "vehicle_make", "model": "vehicle_model"})
+        assert all(row["make"] and row["model"] for row in rows)
+
+    def test_vehicle_convenience(self):
+        forgery.seed(42)
+        assert isinstance(forgery.vehicle(), dict)
+        assert len(forgery.vehicles(3)) == 3
+        assert isinstance(forgery.vehicle_make(), str)
+        assert isinstance(forgery.vehicle_model(), str)
+
+
+class TestMedicalGeneration:
+    """Tests for medical attribute generation."""
+
+    BLOOD_TYPES = {"O+", "A+", "B+", "AB+", "O-", "A-", "B-", "AB-"}
+
+    def test_blood_type_prevalence(self):
+        fake = Faker()
+        fake.seed(42)
+        types = fake.blood_types(2000)
+        assert set(types) <= self.BLOOD_TYPES
+        assert types.count("O+") > types.count("B+") > types.count("AB-")
+        assert sorted(fake.blood_types(8, unique=True)) == sorted(self.BLOOD_TYPES)
+
+    def test_height_and_weight(self):
+        fake = Faker()
+        fake.seed(42)
+        heights = fake.heights(1000)
+        weights = fake.weights(1000)
+        assert all(140 <= h <= 210 for h in heights)
+        assert all(40 <= w <= 180 for w in weights)
+        assert 160 < sum(heights) / len(heights) < 175
+        assert isinstance(fake.height(), float)
+
+    def test_allergies_and_medications(self):
+        fake = Faker()
+        fake.seed(42)
+        assert all(isinstance(a, str) and a for a in fake.allergies(20))
+        assert all(isinstance(m, str) and m for m in fake.medications(20))
+
+    def test_icd10_codes(self):
+        fake = Faker()
+        fake.seed(42)
+        codes = fake.icd10_codes(500)
+        pattern = re.compile(r"^[A-TV-Z]\d{2}(\.\d{1,2}|\.\d{3}[ADS])?$")
+        assert all(pattern.match(c) for c in codes), codes
+        assert all(c[0] in "ST" for c in codes if len(c) == 8)
+        assert len(fake.icd10_codes(50, unique=True)) == 50
+        rows = fake.records(5, {"dx": "icd10_code"})
+        assert all(pattern.match(row["dx"]) for row in rows)
+
+    @staticmethod
+    def _npi_valid(npi):
+        digits = [int(d) for d in "80840" + npi]
+        total = 0
+        for i, d in enumerate(reversed(digits)):
+            if i % 2 == 1:
+                d = d * 2 - 9 if d > 4 else d * 2
+            total += d
+        return total % 10 == 0
+
+    def test_npis(self):
+        fake = Faker()
+        fake.seed(42)
+        assert self._npi_valid("1234567893")
+        npis = fake.npis(200)
+        assert all(len(n) == 10 and n.isdigit() and n[0] in "12" for n in npis)
+        assert all(self._npi_valid(n) for n in npis)
+        assert self._npi_valid(forgery.npi())
+        rows = fake.records(5, {"provider": "npi"})
+        assert all(self._npi_valid(row["provider"]) for row in rows)
+
+    def test_medical_in_records(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {"blood": "blood_type", "height": "height", "weight": "weight"}
+        rows = fake.records(10, schema)
+        assert all(row["blood"] in self.BLOOD_TYPES for row in rows)
+        assert all(isinstance(row["height"], float) for row in rows)
+
+    def test_medical_convenience(self):
+        forgery.seed(42)
+        assert forgery.blood_type() in self.BLOOD_TYPES
+        assert isinstance(forgery.weight(), float)
+        assert isinstance(forgery.allergy(), str)
+        assert isinstance(forgery.medication(), str)
+        assert len(forgery.heights(3)) == 3
+
 
 class TestNetworkGeneration:
     """Tests for network-related generation."""
@@ -406,6 +2178,239 @@ class TestNetworkGeneration:
         assert len(forgery.ipv6s(5)) == 5
         assert len(forgery.mac_addresses(5)) == 5
 
+    def test_mac_address_formats(self):
+        fake = Faker()
+        fake.seed(42)
+        assert re.fullmatch(r"([0-9A-F]{2}-){5}[0-9A-F]{2}", fake.mac_address("dash", True))
+        assert re.fullmatch(r"[0-9a-f]{4}\.[0-9a-f]{4}\.[0-9a-f]{4}", fake.mac_address("dot"))
+        assert re.fullmatch(r"[0-9a-f]{12}", fake.mac_address(separator="bare"))
+        for mac in fake.mac_addresses(50, separator="colon", uppercase=True):
+            assert re.fullmatch(r"([0-9A-F]{2}:){5}[0-9A-F]{2}", mac)
+
+    def test_mac_address_kinds(self):
+        fake = Faker()
+        fake.seed(42)
+        vmware = {"00:50:56", "00:0c:29"}
+        vendor = fake.mac_addresses(500, kind="vendor")
+        assert any(mac[:8] in vmware for mac in vendor)
+        for mac in vendor:
+            assert int(mac[:2], 16) & 0b11 == 0
+        for mac in fake.mac_addresses(200, kind="local"):
+            assert int(mac[:2], 16) & 0b11 == 0b10
+
+    def test_ipv6_options(self):
+        fake = Faker()
+        fake.seed(42)
+        for ip in fake.ipv6s(100, compressed=True, kind="link_local"):
+            assert ip.startswith("fe80::")
+            assert str(ipaddress.IPv6Address(ip)) == ip
+            assert ipaddress.IPv6Address(ip).is_link_local
+        for ip in fake.ipv6s(100, kind="ula"):
+            assert ipaddress.IPv6Address(ip) in ipaddress.IPv6Network("fd00::/8")
+        for ip in fake.ipv6s(100, kind="global", uppercase=True):
+            assert re.fullmatch(r"[23][0-9A-F]{3}(:[0-9A-F]{4}){7}", ip)
+        assert ipaddress.IPv6Address(forgery.ipv6(compressed=True)).exploded
+        with pytest.raises(ValueError, match="unknown IPv6 address kind 'multicast'"):
+            fake.ipv6(kind="multicast")
+
+    def test_mac_address_invalid_options(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="separator"):
+            fake.mac_address(separator="space")
+        with pytest.raises(ValueError, match="kind"):
+            fake.mac_addresses(5, kind="oui")
+
+    def test_url_options(self):
+        fake = Faker()
+        fake.seed(42)
+        urls = fake.urls(
+            200, scheme="http", path_depth=3, query_params=2, fragment=True, extension="html"
+        )
+        for u in urls:
+            parts = urlsplit(u)
+            assert parts.scheme == "http"
+            assert parts.path.count("/") == 3
+            assert parts.path.endswith(".html")
+            assert len(parse_qsl(parts.query)) == 2
+            assert parts.fragment
+        queries = [urlsplit(u).query for u in urls]
+        assert any(unquote(q) != q for q in queries)
+
+    def test_url_extension_without_path(self):
+        fake = Faker()
+        fake.seed(42)
+        val = fake.url(path_depth=0, extension=".pdf")
+        assert urlsplit(val).path == "/index.pdf"
+
+    def test_url_default_unchanged(self):
+        fake1 = Faker()
+        fake2 = Faker()
+        fake1.seed(7)
+        fake2.seed(7)
+        assert fake1.urls(20) == fake2.urls(20, scheme="https")
+        assert all("?" not in u and "#" not in u for u in fake1.urls(50))
+
+    def test_url_options_invalid(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="scheme"):
+            fake.url(scheme="1http")
+        with pytest.raises(ValueError, match="extension"):
+            fake.urls(5, extension="tar.gz")
+
+    def test_url_options_convenience(self):
+        forgery.seed(42)
+        assert forgery.url(scheme="ftp").startswith("ftp://")
+        assert all("#" in u for u in forgery.urls(5, fragment=True))
+
+    def test_ipv4_private(self):
+        fake = Faker()
+        fake.seed(42)
+        for ip in fake.ipv4s(500, private=True):
+            addr = ipaddress.ip_address(ip)
+            assert addr.is_private, ip
+            assert any(addr in ipaddress.ip_network(net) for net in RFC1918), ip
+        assert ipaddress.ip_address(fake.ipv4(private=True)).is_private
+
+    def test_ipv4_network(self):
+        fake = Faker()
+        fake.seed(42)
+        subnet = ipaddress.ip_network("10.20.0.0/16")
+        for ip in fake.ipv4s(200, network="10.20.0.0/16"):
+            assert ipaddress.ip_address(ip) in subnet
+        for name, check in [
+            ("loopback", lambda a: a.is_loopback),
+            ("link_local", lambda a: a.is_link_local),
+            ("multicast", lambda a: a.is_multicast),
+            ("documentation", lambda a: not a.is_global and a.is_private),
+        ]:
+            for ip in fake.ipv4s(100, network=name):
+                assert check(ipaddress.ip_address(ip)), (name, ip)
+        assert set(fake.ipv4s(100, network="192.168.1.4/30")) == {"192.168.1.5", "192.168.1.6"}
+        assert fake.ipv4(True, network="loopback").startswith("127.")
+        with pytest.raises(ValueError, match="unknown IPv4 network 'intranet'"):
+            fake.ipv4(network="intranet")
+
+    def test_ipv4_network_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(50, {"ip": ("ipv4", "172.16.5.0/24")}):
+            assert row["ip"].startswith("172.16.5.")
+        with pytest.raises(ValueError, match="unknown IPv4 network"):
+            fake.records(1, {"ip": ("ipv4", "300.0.0.0/8")})
+
+    def test_ipv4_public(self):
+        fake = Faker()
+        fake.seed(42)
+        for ip in fake.ipv4_publics(500):
+            assert ipaddress.ip_address(ip).is_global, ip
+        assert ipaddress.ip_address(fake.ipv4_public()).is_global
+
+    def test_cidr(self):
+        fake = Faker()
+        fake.seed(42)
+        for block in fake.cidrs(200):
+            network = ipaddress.ip_network(block)  # strict: host bits must be zero
+            assert 8 <= network.prefixlen <= 30
+        assert ipaddress.ip_network(fake.cidr((24, 24))).prefixlen == 24
+        with pytest.raises(ValueError, match="invalid CIDR prefix range"):
+            fake.cidr((24, 16))
+        with pytest.raises(ValueError, match="invalid CIDR prefix range"):
+            fake.cidrs(5, (8, 33))
+
+    def test_ip_range_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {"private": "ipv4_private", "public": "ipv4_public", "block": "cidr"}
+        for row in fake.records(50, schema):
+            assert ipaddress.ip_address(row["private"]).is_private
+            assert ipaddress.ip_address(row["public"]).is_global
+            assert ipaddress.ip_network(row["block"])
+
+    def test_ip_range_convenience(self):
+        forgery.seed(42)
+        assert ipaddress.ip_address(forgery.ipv4(private=True)).is_private
+        assert len(forgery.ipv4s(5, private=True)) == 5
+        assert ipaddress.ip_address(forgery.ipv4_public()).is_global
+        assert len(forgery.ipv4_publics(5)) == 5
+        assert "/" in forgery.cidr()
+        assert len(forgery.cidrs(5, (16, 24))) == 5
+
+    def test_http_methods(self):
+        fake = Faker()
+        fake.seed(42)
+        methods = fake.http_methods(1000)
+        assert set(methods) <= {"GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS"}
+        assert methods.count("GET") > methods.count("POST") > methods.count("DELETE")
+        assert fake.http_method() in methods
+
+    def test_http_status_codes(self):
+        fake = Faker()
+        fake.seed(42)
+        codes = fake.http_status_codes(1000)
+        assert all(isinstance(c, int) and 200 <= c < 600 for c in codes)
+        assert codes.count(200) > len(codes) // 3
+        for class_ in ("1xx", "2xx", "3xx", "4xx", "5xx"):
+            low = int(class_[0]) * 100
+            assert all(low <= c < low + 100 for c in fake.http_status_codes(50, class_))
+        assert 400 <= fake.http_status_code(class_="4xx") < 500
+        with pytest.raises(ValueError, match="unknown HTTP status class '6xx'"):
+            fake.http_status_code("6xx")
+
+    def test_http_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {
+            "method": "http_method",
+            "status": "http_status_code",
+            "error": ("http_status_code", "5xx"),
+        }
+        for row in fake.records(50, schema):
+            assert row["method"].isupper()
+            assert isinstance(row["status"], int)
+            assert 500 <= row["error"] < 600
+        with pytest.raises(ValueError, match="unknown HTTP status class"):
+            fake.records(1, {"s": ("http_status_code", "teapot")})
+
+    def test_http_convenience(self):
+        forgery.seed(42)
+        assert isinstance(forgery.http_method(), str)
+        assert len(forgery.http_methods(5)) == 5
+        assert isinstance(forgery.http_status_code(), int)
+        assert len(forgery.http_status_codes(5, "3xx")) == 5
+
+    def test_ports(self):
+        fake = Faker()
+        fake.seed(42)
+        ranges = {
+            "well_known": (1, 1023),
+            "registered": (1024, 49151),
+            "ephemeral": (49152, 65535),
+            "any": (1, 65535),
+        }
+        for kind, (low, high) in ranges.items():
+            ports = fake.ports(200, kind=kind)
+            assert len(ports) == 200
+            assert all(isinstance(p, int) and low <= p <= high for p in ports), kind
+        assert 1 <= fake.port() <= 65535
+        assert fake.port("EPHEMERAL") >= 49152
+        with pytest.raises(ValueError, match="unknown port kind 'private'"):
+            fake.port("private")
+
+    def test_port_schema(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {"src": "port", "dst": ("port", "well_known")}
+        for row in fake.records(50, schema):
+            assert isinstance(row["src"], int)
+            assert 1 <= row["dst"] <= 1023
+        with pytest.raises(ValueError, match="unknown port kind"):
+            fake.records(1, {"p": ("port", "private")})
+
+    def test_port_convenience(self):
+        forgery.seed(42)
+        assert isinstance(forgery.port(), int)
+        assert len(forgery.ports(5, "registered")) == 5
+
 
 class TestEmailVariants:
     """Tests for safe and free email generation."""
@@ -450,6 +2455,99 @@ class TestEmailVariants:
         assert len(forgery.safe_emails(5)) == 5
         assert len(forgery.free_emails(5)) == 5
 
+    def test_email_with_domain(self):
+        fake = Faker()
+        fake.seed(42)
+        assert fake.email(domain="corp.example").endswith("@corp.example")
+        emails = fake.emails(100, domain="corp.example")
+        assert all(e.endswith("@corp.example") for e in emails)
+        unique = fake.emails(200, unique=True, domain="corp.example")
+        assert len(set(unique)) == 200
+        assert all(e.endswith("@corp.example") for e in unique)
+
+    def test_email_invalid_domain(self):
+        fake = Faker()
+        with pytest.raises(ValueError, match="corp..example"):
+            fake.email(domain="corp..example")
+        with pytest.raises(ValueError, match="domain"):
+            fake.emails(5, domain="bad domain")
+
+    def test_company_email(self):
+        fake = Faker()
+        fake.seed(42)
+        emails = fake.company_emails(100, domain="corp.example")
+        for e in emails:
+            local, domain = e.split("@")
+            assert domain == "corp.example"
+            first, last = local.split(".")
+            assert first and last
+        generated = fake.company_emails(20)
+        assert len({e.split("@")[1] for e in generated}) == 1
+        assert "." in fake.company_email().split("@")[0]
+
+    def test_company_email_from_company(self):
+        fake = Faker()
+        fake.seed(42)
+        emails = fake.company_emails(20, company="Acme Holdings LLC")
+        assert all(e.endswith("@acmeholdings.com") for e in emails)
+        assert fake.company_email(company="Globex Inc.").endswith("@globex.com")
+        assert fake.company_email("corp.example", "Acme").endswith("@corp.example")
+        domain = fake.company_email().split("@")[1]
+        assert domain.endswith(".com")
+        assert domain[:-4].isalnum()
+
+    def test_email_domain_convenience(self):
+        forgery.seed(42)
+        assert forgery.email(domain="corp.example").endswith("@corp.example")
+        assert all(e.endswith("@corp.example") for e in forgery.emails(5, domain="corp.example"))
+        assert forgery.company_email("corp.example").endswith("@corp.example")
+        assert len(forgery.company_emails(5)) == 5
+
+    def test_email_domain_records(self):
+        fake = Faker()
+        fake.seed(42)
+        schema = {"work": ("email", "corp.example"), "contact": "company_email"}
+        for row in fake.records(50, schema):
+            assert row["work"].endswith("@corp.example")
+            assert row["contact"].count("@") == 1
+        with pytest.raises(ValueError, match="email domain"):
+            fake.records(1, {"e": ("email", "-corp.example")})
+
+    def test_username_styles(self):
+        fake = Faker()
+        fake.seed(42)
+        for name in fake.usernames(50, style="first.last"):
+            first, last = name.split(".")
+            assert first.isalpha() and first.islower()
+            assert last.isalpha() and last.islower()
+        for name in fake.usernames(50, style="flast", mixed_case=True):
+            assert name[0].isupper() and name.isalpha()
+        for name in fake.usernames(50, style="first_l##"):
+            assert name[-2:].isdigit() and name[-4] == "_"
+        for name in fake.usernames(50, style="first.last", leet=True):
+            assert not set(name) & set("aeiost")
+        assert len(set(fake.usernames(200, unique=True))) == 200
+        with pytest.raises(ValueError, match="username style"):
+            fake.username(style="first-last")
+
+    def test_email_styles(self):
+        fake = Faker()
+        fake.seed(42)
+        emails = fake.emails(50, domain="corp.example", style="first.last", mixed_case=True)
+        for e in emails:
+            local, domain = e.split("@")
+            assert domain == "corp.example"
+            first, last = local.split(".")
+            assert first[0].isupper() and last[0].isupper()
+        local = fake.email(leet=True).split("@")[0]
+        assert local[-3:].isdigit() and not set(local) & set("aeiost")
+        assert forgery.username(style="flast").isalpha()
+        assert len(forgery.emails(5, style="any")) == 5
+        with pytest.raises(ValueError, match="username style"):
+            fake.emails(5, style="nope")
+        rows = fake.records(20, {"login": "username"})
+        assert all(row["login"] for row in rows)
+
 
 class TestFinanceGeneration:
     """Tests for finance-related generation."""
@@ -498,6 +2596,136 @@ class TestFinanceGeneration:
         assert len(forgery.ibans(5)) == 5
 
 
+class TestCommerceGeneration:
+    """Tests for invoice number, SKU, product name and retail price generation."""
+
+    def test_invoice_number_default_format(self):
+        fake = Faker()
+        fake.seed(42)
+        for number in fake.invoice_numbers(100):
+            assert re.match(r"^INV-20(2[0-4])-\d{6}$", number), number
+
+    def test_invoice_number_options(self):
+        fake = Faker()
+        fake.seed(42)
+        assert re.match(r"^ORD-\d{8}$", fake.invoice_number("ORD", digits=8, date=None))
+        assert re.match(r"^\d{8}-\d{4}$", fake.invoice_number("", digits=4, date="date"))
+        assert re.match(r"^PO-\d{6}-\d{6}$", fake.invoice_number("PO", date="year_month"))
+        with pytest.raises(ValueError, match="digits must be between 1 and 12"):
+            fake.invoice_number(digits=0)
+        with pytest.raises(ValueError, match="unknown invoice date component"):
+            fake.invoice_numbers(5, date="quarter")
+
+    def test_invoice_number_schema_and_convenience(self):
+        forgery.seed(42)
+        assert forgery.invoice_number().startswith("INV-")
+        assert len(forgery.invoice_numbers(5)) == 5
+        schema = {"inv": "invoice_number", "order": ("invoice_number", "ORD", 5, None)}
+        for row in forgery.records(10, schema):
+            assert row["inv"].startswith("INV-")
+            assert re.match(r"^ORD-\d{5}$", row["order"])
+
+    def test_sku_default_format(self):
+        fake = Faker()
+        fake.seed(42)
+        for value in fake.skus(100):
+            assert re.match(r"^[A-Z]{3}-[A-Z]{3}-(XS|S|M|L|XL|XXL)-\d{4}$", value), value
+
+    def test_sku_pattern(self):
+        fake = Faker()
+        fake.seed(42)
+        for value in fake.skus(50, pattern="SKU-??-####"):
+            assert re.match(r"^SKU-[A-Z]{2}-\d{4}$", value), value
+        forgery.seed(42)
+        assert len(forgery.skus(3)) == 3
+        rows = forgery.records(5, {"a": "sku", "b": ("sku", "X-###")})
+        for row in rows:
+            assert row["a"].count("-") == 3
+            assert re.match(r"^X-\d{3}$", row["b"])
+
+    def test_retail_prices(self):
+        fake = Faker()
+        fake.seed(42)
+        prices = fake.retail_prices(500)
+        assert all(20.0 <= p < 100.0 for p in prices)
+        endings = {round(p * 100) % 100 for p in prices}
+        assert endings <= {99, 95, 0, 49}
+        assert sum(1 for p in prices if round(p * 100) % 100 == 99) > 200
+        for price in fake.retail_prices(100, tier="premium"):
+            assert 109.0 <= price <= 999.99
+            assert int(price) % 10 == 9
+        with pytest.raises(ValueError, match="unknown price tier"):
+            fake.retail_price("bargain")
+
+    def test_retail_price_decimal(self):
+        fake = Faker()
+        fake.seed(42)
+        price = fake.retail_price("luxury", decimal=True)
+        assert isinstance(price, Decimal)
+        assert price.as_tuple().exponent == -2
+        assert all(isinstance(p, Decimal) for p in forgery.retail_prices(5, decimal=True))
+        rows = fake.records(5, {"a": "retail_price", "b": ("retail_price", "budget", True)})
+        for row in rows:
+            assert isinstance(row["a"], float)
+            assert isinstance(row["b"], Decimal)
+            assert Decimal("1.49") <= row["b"] <= Decimal("19.99")
+
+    def test_product_names(self):
+        fake = Faker()
+        fake.seed(42)
+        names = fake.product_names(100)
+        assert all(len(name.split(" ")) >= 3 for name in names)
+        assert all(name[0].isupper() for name in names)
+        assert len(set(fake.product_names(200, unique=True))) == 200
+        assert forgery.product_name().count(" ") >= 2
+        row = fake.records(1, {"product": "product_name"})[0]
+        assert isinstance(row["product"], str)
+
+
+def _luhn36_valid(number):
+    """Check a Luhn mod 36 check character, ignoring hyphens."""
+    alphabet = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"
+    total = 0
+    for i, ch in enumerate(reversed(number.replace("-", ""))):
+        value = alphabet.index(ch) * (2 if i % 2 else 1)
+        total += value // 36 + value % 36
+    return total % 36 == 0
+
+
+class TestInsuranceGeneration:
+    """Tests for insurance policy and claim number generation."""
+
+    def test_default_formats(self):
+        fake = Faker()
+        fake.seed(42)
+        for number in fake.policy_numbers(100):
+            assert re.match(r"^POL-202[0-4]-\d{8}[0-9A-Z]$", number), number
+            assert _luhn36_valid(number), number
+        for number in fake.claim_numbers(100):
+            assert re.match(r"^CLM-202[0-4]-\d{8}[0-9A-Z]$", number), number
+            assert _luhn36_valid(number), number
+
+    def test_options(self):
+        fake = Faker()
+        fake.seed(42)
+        assert re.match(r"^HO3-202[0-4]-\d{5}[0-9A-Z]$", fake.policy_number("HO3", digits=5))
+        assert re.match(r"^202[0-4]-\d{4}[0-9A-Z]$", fake.claim_number("", digits=4))
+        with pytest.raises(ValueError, match="digits must be between 1 and 12"):
+            fake.policy_number(digits=0)
+        with pytest.raises(ValueError, match="invalid insurance number prefix"):
+            fake.claim_numbers(5, prefix="clm")
+
+    def test_schema_and_convenience(self):
+        rows = forgery.records(
+            10, {"policy": "policy_number", "claim": ("claim_number", "CL", 10)}
+        )
+        for row in rows:
+            assert row["policy"].startswith("POL-") and _luhn36_valid(row["policy"])
+            assert re.match(r"^CL-202[0-4]-\d{10}[0-9A-Z]$", row["claim"])
+        assert _luhn36_valid(forgery.policy_number())
+        assert _luhn36_valid(forgery.claim_number())
+
+
 class TestDeterminism:
     """Tests for deterministic generation across new providers."""
 
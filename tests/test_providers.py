@@ -1,6 +1,8 @@
 """Tests for forgery data providers."""
 
 import re
+import uuid as uuid_module
+from datetime import datetime, timezone
 
 import pytest
 
@@ -10,14 +12,22 @@ from forgery import (
     emails,
     first_name,
     first_names,
+    full_name,
+    full_names,
     integer,
     integers,
     last_name,
     last_names,
     name,
+    name_with_prefix,
     names,
+    prefix,
+    prefixes,
     seed,
+    suffix,
     uuid,
+    uuid7,
+    uuid7s,
     uuids,
 )
 
@@ -73,6 +83,73 @@ class TestNames:
         assert len(result) == 50
 
 
+class TestNamePrefixesAndFormats:
+    """Tests for name prefixes, suffixes and full name formats."""
+
+    def test_prefix_returns_string(self) -> None:
+        """Single prefix should be a known US title."""
+        assert prefix() in ("Mr.", "Mrs.", "Ms.", "Miss", "Dr.", "Prof.")
+
+    def test_prefixes_batch(self) -> None:
+        """Batch prefixes should work."""
+        assert len(prefixes(50)) == 50
+
+    def test_suffix_returns_string(self) -> None:
+        """Single suffix should be non-empty for en_US."""
+        assert len(suffix()) > 0
+
+    def test_locale_prefixes(self) -> None:
+        """Prefixes should come from the locale's data."""
+        fake = Faker("de_DE")
+        for p in fake.prefixes(50):
+            assert p in ("Herr", "Frau", "Dr.", "Prof.", "Prof. Dr.", "Dipl.-Ing.")
+
+    def test_prefix_empty_for_japanese(self) -> None:
+        """Japanese has no name prefixes; honorifics are suffixes."""
+        fake = Faker("ja_JP")
+        assert fake.prefix() == ""
+        assert fake.suffix() in ("様", "さん", "先生", "殿")
+
+    def test_name_with_prefix(self) -> None:
+        """Name with prefix should have prefix, first and last parts."""
+        seed(42)
+        result = name_with_prefix()
+        assert len(result.split()) == 3
+
+    def test_full_name_default_matches_name_shape(self) -> None:
+        """Default format should follow the locale's name order."""
+        assert len(full_name().split()) == 2
+
+    def test_full_name_middle_initial(self) -> None:
+        """Middle initial placeholder should render as 'X.'."""
+        for result in full_names(50, "{first} {middle_initial} {last}"):
+            middle = result.split()[1]
+            assert re.fullmatch(r"[A-Z]\.", middle)
+
+    def test_full_name_all_placeholders(self) -> None:
+        """All placeholders should be accepted."""
+        result = full_name("{prefix} {first} {middle} {middle_initial} {last} {suffix}")
+        assert len(result.split()) >= 5
+
+    def test_full_name_invalid_placeholder(self) -> None:
+        """Unknown placeholders should raise ValueError."""
+        with pytest.raises(ValueError, match="unknown placeholder"):
+            full_name("{nickname}")
+
+    def test_full_names_unique(self) -> None:
+        """Unique full names should not repeat."""
+        fake = Faker()
+        fake.seed(1)
+        result = fake.full_names(100, "{first} {middle_initial} {last}", unique=True)
+        assert len(set(result)) == 100
+
+    def test_full_names_unique_invalid_placeholder(self) -> None:
+        """Invalid formats should be rejected before unique generation."""
+        fake = Faker()
+        with pytest.raises(ValueError, match="unknown placeholder"):
+            fake.full_names(10, "{bogus}", unique=True)
+
+
 class TestEmails:
     """Tests for email generation."""
 
@@ -173,6 +250,56 @@ class TestUuids:
         assert len(set(result)) == len(result)
 
 
+class TestUuid7:
+    """Tests for time-ordered UUIDv7 generation."""
+
+    UUID7_PATTERN = re.compile(
+        r"^[0-9a-f]{8}-[0-9a-f]{4}-7[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$"
+    )
+
+    @staticmethod
+    def timestamp(value: str) -> datetime:
+        millis = uuid_module.UUID(value).int >> 80
+        return datetime.fromtimestamp(millis / 1000, tz=timezone.utc)
+
+    def test_uuid7_format(self) -> None:
+        """UUIDv7 should have version 7 and the RFC 4122 variant."""
+        seed(42)
+        result = uuid7()
+        assert self.UUID7_PATTERN.match(result), f"UUID format invalid: {result}"
+        assert uuid_module.UUID(result).version == 7
+
+    def test_uuid7s_sorted_and_in_range(self) -> None:
+        """A batch should be sorted, unique, and inside the date range."""
+        seed(42)
+        result = uuid7s(500, start="2024-03-01", end="2024-03-31")
+        assert result == sorted(result)
+        assert len(set(result)) == 500
+        for u in result:
+            assert self.UUID7_PATTERN.match(u), f"UUID format invalid: {u}"
+            ts = self.timestamp(u)
+            assert (ts.year, ts.month) == (2024, 3), u
+
+    def test_uuid7_default_range(self) -> None:
+        """Default timestamps fall between 2020 and 2030."""
+        for u in Faker().uuid7s(100):
+            assert 2020 <= self.timestamp(u).year <= 2030
+
+    def test_uuid7_invalid_range_raises(self) -> None:
+        """Invalid or pre-1970 ranges should raise ValueError."""
+        with pytest.raises(ValueError, match="invalid date range"):
+            uuid7(start="2024-12-31", end="2024-01-01")
+        with pytest.raises(ValueError, match="1970"):
+            uuid7s(5, start="1969-01-01", end="2024-01-01")
+
+    def test_uuid7_records(self) -> None:
+        """The uuid7 schema type should produce version 7 UUIDs."""
+        fake = Faker()
+        fake.seed(42)
+        for row in fake.records(20, {"id": "uuid7"}):
+            assert uuid_module.UUID(row["id"]).version == 7
+
+
 class TestFakerClass:
     """Tests for the Faker class."""
 
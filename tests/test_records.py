@@ -2,7 +2,7 @@
 
 import pytest
 
-from forgery import Faker, records, records_arrow, records_tuples, seed
+from forgery import Faker, describe, records, records_arrow, records_tuples, seed
 
 # Check if pyarrow is available for arrow tests
 try:
@@ -151,6 +151,59 @@ class TestRecords:
                 assert isinstance(component, int)
                 assert 0 <= component <= 255
 
+    def test_records_hsl_cmyk_color(self) -> None:
+        """HSL and CMYK colors return 3- and 4-tuples."""
+        seed(42)
+        result = records(10, {"hsl": "hsl_color", "cmyk": "cmyk_color"})
+        for row in result:
+            assert len(row["hsl"]) == 3
+            assert row["hsl"][0] <= 359
+            assert len(row["cmyk"]) == 4
+            assert all(0 <= c <= 100 for c in row["cmyk"])
+
+    def test_records_date_of_birth_with_age(self) -> None:
+        """Date of birth with age returns (dob, age) tuples within the age range."""
+        seed(42)
+        result = records(20, {"dob": ("date_of_birth_with_age", 30, 40)})
+        for row in result:
+            dob, age = row["dob"]
+            assert isinstance(dob, str)
+            assert 30 <= age <= 40
+
+    def test_records_demographics(self) -> None:
+        """Demographic types draw from their categories, honoring custom weights."""
+        seed(42)
+        schema = {
+            "age": ("age", 21, 30),
+            "gender": "gender",
+            "pronouns": ("pronouns", {"they/them": 1}),
+            "marital_status": "marital_status",
+        }
+        for row in records(50, schema):
+            assert 21 <= row["age"] <= 30
+            assert row["gender"] in ("female", "male", "non-binary")
+            assert row["pronouns"] == "they/them"
+            assert row["marital_status"] in (
+                "married",
+                "single",
+                "divorced",
+                "widowed",
+                "separated",
+            )
+        with pytest.raises(ValueError, match="unknown gender category"):
+            records(1, {"gender": ("gender", {"other": 1})})
+
+    def test_records_coordinates(self) -> None:
+        """Test coordinates return (lat, lon) float tuples."""
+        seed(42)
+        result = records(10, {"location": "coordinates"})
+        for row in result:
+            lat, lon = row["location"]
+            assert isinstance(lat, float)
+            assert isinstance(lon, float)
+            assert -90 <= lat <= 90
+            assert -180 <= lon <= 180
+
     def test_records_catch_phrase(self) -> None:
         """Test catch phrase generation."""
         seed(42)
@@ -471,6 +524,14 @@ class TestRecordsArrow:
         for age in ages:
             assert 18 <= age <= 65
 
+    def test_records_arrow_port_type(self) -> None:
+        """Port fields should produce UInt16 columns."""
+        seed(42)
+        result = records_arrow(10, {"port": ("port", "ephemeral")})
+        assert result.schema.field("port").type == pa.uint16()
+        for port in result.column("port").to_pylist():
+            assert 49152 <= port <= 65535
+
     def test_records_arrow_float_type(self) -> None:
         """Float fields should produce Float64 columns."""
         seed(42)
@@ -506,6 +567,66 @@ class TestRecordsArrow:
         assert color_type.field(1).type == pa.uint8()
         assert color_type.field(2).type == pa.uint8()
 
+    def test_records_arrow_hsl_cmyk_color_types(self) -> None:
+        """HSL and CMYK color fields should produce Struct columns of their components."""
+        seed(42)
+        result = records_arrow(10, {"hsl": "hsl_color", "cmyk": "cmyk_color"})
+
+        hsl_type = result.schema.field("hsl").type
+        assert [hsl_type.field(i).name for i in range(3)] == ["h", "s", "l"]
+        assert hsl_type.field(0).type == pa.uint16()
+        assert hsl_type.field(1).type == pa.uint8()
+
+        cmyk_type = result.schema.field("cmyk").type
+        assert [cmyk_type.field(i).name for i in range(4)] == ["c", "m", "y", "k"]
+        assert all(cmyk_type.field(i).type == pa.uint8() for i in range(4))
+
+    def test_records_arrow_date_of_birth_with_age_type(self) -> None:
+        """Date of birth with age should produce a struct of date string and uint32 age."""
+        seed(42)
+        result = records_arrow(10, {"dob": "date_of_birth_with_age"})
+
+        dob_type = result.schema.field("dob").type
+        assert dob_type.field(0).name == "date_of_birth"
+        assert dob_type.field(0).type == pa.string()
+        assert dob_type.field(1).name == "age"
+        assert dob_type.field(1).type == pa.uint32()
+
+    def test_records_arrow_time_type(self) -> None:
+        """Time should produce a time64[us] column."""
+        seed(42)
+        result = records_arrow(10, {"at": "time"})
+
+        assert result.schema.field("at").type == pa.time64("us")
+        for value in result.column("at").to_pylist():
+            assert value.microsecond == 0
+
+    def test_records_arrow_coordinates_type(self) -> None:
+        """Coordinates should produce Struct columns with lat, lon float64 fields."""
+        seed(42)
+        result = records_arrow(10, {"location": "coordinates"})
+
+        location_type = result.schema.field("location").type
+        assert pa.types.is_struct(location_type)
+        assert location_type.field(0).name == "lat"
+        assert location_type.field(1).name == "lon"
+        assert location_type.field(0).type == pa.float64()
+        assert location_type.field(1).type == pa.float64()
+        for point in result.column("location").to_pylist():
+            assert -90 <= point["lat"] <= 90
+
+    def test_records_arrow_company_profile_type(self) -> None:
+        """company_profile should produce a Struct column of string fields."""
+        seed(42)
+        result = records_arrow(5, {"company": "company_profile"})
+
+        company_type = result.schema.field("company").type
+        assert pa.types.is_struct(company_type)
+        assert company_type.field(0).name == "name"
+        assert all(company_type.field(i).type == pa.string() for i in range(7))
+        for row in result.column("company").to_pylist():
+            assert row["name"].endswith(row["legal_form"])
+
     def test_records_arrow_all_simple_types(self) -> None:
         """Test all simple type specifications work with Arrow."""
         seed(42)
@@ -720,3 +841,160 @@ class TestSchemaSize:
         result = records(1, schema)
         assert len(result) == 1
         assert len(result[0]) == 100
+
+
+class TestDescribe:
+    """Tests for describe() schema statistics."""
+
+    def test_describe_returns_all_fields(self) -> None:
+        """Every schema field should have statistics."""
+        stats = describe({"id": "uuid", "name": "name", "age": ("int", 18, 65)}, 100)
+        assert set(stats.keys()) == {"id", "name", "age"}
+        for field_stats in stats.values():
+            assert field_stats["count"] == 100
+            assert field_stats["null_count"] == 0
+            assert field_stats["null_rate"] == 0.0
+
+    def test_describe_int_bounds(self) -> None:
+        """Integer min/max should respect the range."""
+        stats = describe({"age": ("int", 18, 65)}, 500)
+        assert 18 <= stats["age"]["min"] <= stats["age"]["max"] <= 65
+        assert stats["age"]["min_length"] is None
+        assert stats["age"]["mean_length"] is None
+
+    def test_describe_string_lengths(self) -> None:
+        """UUID length statistics should all be 36."""
+        stats = describe({"id": "uuid"}, 50)
+        assert stats["id"]["min_length"] == 36
+        assert stats["id"]["max_length"] == 36
+        assert stats["id"]["mean_length"] == 36.0
+        assert stats["id"]["cardinality"] == 50
+
+    def test_describe_choice_cardinality(self) -> None:
+        """Choice cardinality should not exceed the number of options."""
+        stats = describe({"status": ("choice", ["a", "b", "c"])}, 200)
+        assert stats["status"]["cardinality"] <= 3
+
+    def test_describe_default_sample_size(self) -> None:
+        """Default sample size should be 1000."""
+        stats = describe({"name": "name"})
+        assert stats["name"]["count"] == 1000
+
+    def test_describe_deterministic(self) -> None:
+        """Same seed should produce the same statistics."""
+        f1 = Faker()
+        f1.seed(42)
+        f2 = Faker()
+        f2.seed(42)
+        schema = {"name": "name", "score": ("float", 0.0, 1.0)}
+        assert f1.describe(schema, 100) == f2.describe(schema, 100)
+
+    def test_describe_invalid_schema(self) -> None:
+        """Invalid schema should raise ValueError."""
+        with pytest.raises(ValueError):
+            describe({"age": ("int", 65, 18)}, 10)
+
+    def test_describe_exceeds_batch_limit(self) -> None:
+        """Sample size above the batch limit should raise ValueError."""
+        with pytest.raises(ValueError, match="exceeds maximum"):
+            describe({"id": "uuid"}, 10_000_001)
+
+
+class TestNullable:
+    """Tests for ("nullable", spec, null_rate) fields."""
+
+    def test_nullable_records(self) -> None:
+        """Nullable fields should be None at roughly the given rate."""
+        seed(42)
+        rows = records(1000, {"email": ("nullable", "email", 0.3)})
+        nulls = sum(row["email"] is None for row in rows)
+        assert 200 < nulls < 400
+        assert all("@" in row["email"] for row in rows if row["email"] is not None)
+
+    def test_nullable_tuples_wrap_parameterized_spec(self) -> None:
+        """The wrapped spec may itself be a tuple."""
+        seed(42)
+        rows = records_tuples(200, {"age": ("nullable", ("int", 18, 65), 0.5)})
+        ages = [row[0] for row in rows]
+        assert None in ages
+        assert all(18 <= age <= 65 for age in ages if age is not None)
+
+    def test_nullable_zero_rate_matches_inner(self) -> None:
+        """A zero null rate should produce the same values as the bare spec."""
+        seed(7)
+        plain = records(20, {"name": "name"})
+        seed(7)
+        wrapped = records(20, {"name": ("nullable", "name", 0.0)})
+        assert plain == wrapped
+
+    def test_nullable_custom_provider(self) -> None:
+        """Custom providers can be wrapped."""
+        fake = Faker()
+        fake.seed(42)
+        fake.add_provider("fruit", ["apple"])
+        rows = fake.records(100, {"fruit": ("nullable", "fruit", 0.5)})
+        assert {row["fruit"] for row in rows} == {"apple", None}
+
+    def test_nullable_describe(self) -> None:
+        """describe() should count the nulls."""
+        seed(42)
+        stats = describe({"email": ("nullable", "email", 1.0)}, 50)
+        assert stats["email"]["null_count"] == 50
+        assert stats["email"]["null_rate"] == 1.0
+
+    def test_nullable_invalid(self) -> None:
+        """Bad rates and malformed specs should raise ValueError."""
+        with pytest.raises(ValueError, match="null_rate"):
+            records(1, {"email": ("nullable", "email", 1.5)})
+        with pytest.raises(ValueError, match="nullable"):
+            records(1, {"email": ("nullable", "email")})
+        with pytest.raises(ValueError, match="Invalid int range"):
+            records(1, {"age": ("nullable", ("int", 65, 18), 0.1)})
+
+    @pytest.mark.skipif(not HAS_PYARROW, reason="pyarrow not installed")
+    def test_nullable_arrow(self) -> None:
+        """Arrow columns should be nullable with a validity bitmap."""
+        seed(42)
+        batch = records_arrow(
+            200,
+            {"age": ("nullable", ("int", 18, 65), 0.5), "color": ("nullable", "rgb_color", 0.2)},
+        )
+        age_field = batch.schema.field("age")
+        assert age_field.nullable
+        assert age_field.type == pa.int64()
+        assert 0 < batch.column("age").null_count < 200
+        assert pa.types.is_struct(batch.schema.field("color").type)
+        assert batch.column("color").null_count > 0
+
+
+class TestBool:
+    """Tests for "bool" and ("bool", true_rate) fields."""
+
+    def test_bool_records(self) -> None:
+        """Bool fields should produce Python bools at even odds."""
+        seed(42)
+        rows = records(1000, {"active": "bool"})
+        assert all(type(row["active"]) is bool for row in rows)
+        trues = sum(row["active"] for row in rows)
+        assert 400 < trues < 600
+
+    def test_bool_true_rate(self) -> None:
+        """The true rate should bound the values."""
+        seed(42)
+        rows = records_tuples(100, {"always": ("bool", 1.0), "never": ("bool", 0.0)})
+        assert all(row == (True, False) for row in rows)
+
+    def test_bool_invalid_rate(self) -> None:
+        """A true rate outside 0.0-1.0 should raise ValueError."""
+        with pytest.raises(ValueError, match="true_rate"):
+            records(1, {"flag": ("bool", 2.0)})
+        with pytest.raises(ValueError, match="bool"):
+            records(1, {"flag": ("bool", 0.5, 1)})
+
+    @pytest.mark.skipif(not HAS_PYARROW, reason="pyarrow not installed")
+    def test_bool_arrow(self) -> None:
+        """Arrow columns should be boolean."""
+        seed(42)
+        batch = records_arrow(100, {"flag": ("bool", 0.3)})
+        assert batch.schema.field("flag").type == pa.bool_()
+        assert all(isinstance(v, bool) for v in batch.column("flag").to_pylist())